@@ -9,6 +9,14 @@ pub struct Args {
     #[command(subcommand)]
     pub command: ProgramCommand,
 
+    #[arg(
+        long,
+        global = true,
+        env = "CLI_CONFIG",
+        help = "TOML file with the CLI settings, flags and environment variables take precedence over it"
+    )]
+    pub config: Option<String>,
+
     #[arg(
         long,
         global = true,
@@ -18,6 +26,15 @@ pub struct Args {
     )]
     pub rpc_url: String,
 
+    #[arg(
+        long,
+        global = true,
+        env = "FALLBACK_RPC_URLS",
+        value_delimiter = ',',
+        help = "Comma separated RPC URLs the requests fail over to, in order, when the RPC in use does not answer"
+    )]
+    pub fallback_rpc_urls: Vec<String>,
+
     #[arg(
         long,
         global = true,
@@ -27,6 +44,24 @@ pub struct Args {
     )]
     pub commitment: String,
 
+    #[arg(
+        long,
+        global = true,
+        env = "RPC_REQUESTS_PER_SECOND",
+        default_value_t = 0,
+        help = "Requests per second sent to the RPC at most, 0 for no limit"
+    )]
+    pub rpc_requests_per_second: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "RPC_BURST",
+        default_value_t = 10,
+        help = "Requests sent to the RPC at once before `--rpc-requests-per-second` applies"
+    )]
+    pub rpc_burst: u64,
+
     #[arg(
         long,
         global = true,
@@ -36,6 +71,23 @@ pub struct Args {
     )]
     pub priority_fee_micro_lamports: u64,
 
+    #[arg(
+        long,
+        global = true,
+        env = "COMPUTE_UNIT_MARGIN",
+        default_value_t = 20,
+        help = "Percent added to the compute units a transaction consumes in simulation to set its compute unit limit"
+    )]
+    pub compute_unit_margin: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "SKIP_COMPUTE_UNIT_ESTIMATE",
+        help = "Send the transactions with the compute unit limits of the instructions instead of simulating them first"
+    )]
+    pub skip_compute_unit_estimate: bool,
+
     #[arg(
         long,
         global = true,
@@ -45,6 +97,86 @@ pub struct Args {
     )]
     pub transaction_retries: u64,
 
+    #[arg(
+        long,
+        global = true,
+        env = "RETRY_BASE_DELAY_MS",
+        default_value_t = 1_000,
+        help = "Time in milliseconds before the first retry of a transaction"
+    )]
+    pub retry_base_delay_ms: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "RETRY_BACKOFF_FACTOR",
+        default_value_t = 2.0,
+        help = "Factor the time before a retry grows by with every retry"
+    )]
+    pub retry_backoff_factor: f64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "RETRY_MAX_DELAY_MS",
+        default_value_t = 30_000,
+        help = "Maximum time in milliseconds before a retry"
+    )]
+    pub retry_max_delay_ms: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "RETRY_PROGRAM_ERRORS",
+        help = "Also retry transactions failing in an instruction, which fail the same way unless the accounts change"
+    )]
+    pub retry_program_errors: bool,
+
+    #[arg(
+        long,
+        global = true,
+        env = "PRIORITY_FEE_ESCALATION_FACTOR",
+        default_value_t = 1.0,
+        help = "Factor the priority fee grows by with every retry of a transaction that did not land"
+    )]
+    pub priority_fee_escalation_factor: f64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "MAX_PRIORITY_FEE_MICRO_LAMPORTS",
+        default_value_t = 1_000_000,
+        help = "Maximum priority fee in micro lamports the retries grow to"
+    )]
+    pub max_priority_fee_micro_lamports: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "COMPUTE_UNIT_ESCALATION_FACTOR",
+        default_value_t = 1.0,
+        help = "Factor the compute unit limit grows by with every retry of a transaction that exhausted its compute units, which are only retried above 1"
+    )]
+    pub compute_unit_escalation_factor: f64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "MAX_COMPUTE_UNIT_LIMIT",
+        default_value_t = 1_400_000,
+        help = "Maximum compute unit limit the retries grow to"
+    )]
+    pub max_compute_unit_limit: u32,
+
+    #[arg(
+        long,
+        global = true,
+        env = "MAX_IN_FLIGHT_TRANSACTIONS",
+        default_value_t = 8,
+        help = "Transactions the snapshot crank keeps in flight at once"
+    )]
+    pub max_in_flight_transactions: usize,
+
     #[arg(
         long,
         global = true,
@@ -95,6 +227,161 @@ pub struct Args {
     #[arg(long, global = true, env = "KEYPAIR_PATH", help = "keypair path")]
     pub keypair_path: Option<String>,
 
+    #[arg(
+        long,
+        global = true,
+        env = "REMOTE_SIGNER_URL",
+        conflicts_with = "keypair_path",
+        help = "URL of a remote signing service signing the transactions instead of the keypair"
+    )]
+    pub remote_signer_url: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "REMOTE_SIGNER_PUBKEY",
+        help = "Public key the remote signer signs with - asked to the service when not set"
+    )]
+    pub remote_signer_pubkey: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "REMOTE_SIGNER_TOKEN",
+        hide_env_values = true,
+        help = "Bearer token authenticating the CLI to the remote signer"
+    )]
+    pub remote_signer_token: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "ADMIN_APPROVER_KEYPAIR_PATHS",
+        value_delimiter = ',',
+        help = "Comma separated keypair paths of the admin approvers signing admin instructions next to the keypair"
+    )]
+    pub admin_approver_keypair_paths: Vec<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "SQUADS_MULTISIG",
+        help = "Squads multisig whose vault is the NCN admin, admin instructions are proposed to it instead of sent"
+    )]
+    pub squads_multisig: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "SQUADS_VAULT_INDEX",
+        default_value_t = 0,
+        help = "Index of the Squads vault acting as the NCN admin"
+    )]
+    pub squads_vault_index: u8,
+
+    #[arg(
+        long,
+        global = true,
+        env = "AS_PROPOSAL",
+        help = "Print admin instructions as a proposal payload for spl-governance or Squads instead of sending them"
+    )]
+    pub as_proposal: bool,
+
+    #[arg(
+        long,
+        global = true,
+        env = "PROPOSAL_AUTHORITY",
+        requires = "as_proposal",
+        help = "Governance or multisig vault that is the NCN admin, signing the proposed admin instructions"
+    )]
+    pub proposal_authority: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "ADDRESS_LOOKUP_TABLES",
+        value_delimiter = ',',
+        help = "Comma separated address lookup tables of the sent transactions, to fit the instructions referencing many accounts"
+    )]
+    pub address_lookup_tables: Vec<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "JITO_BLOCK_ENGINE_URL",
+        help = "Jito block engine URL, transactions are submitted to it as bundles instead of sent to the RPC"
+    )]
+    pub jito_block_engine_url: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "JITO_TIP_LAMPORTS",
+        default_value_t = 10_000,
+        help = "Tip paid to Jito in lamports with every bundle"
+    )]
+    pub jito_tip_lamports: u64,
+
+    #[arg(
+        long,
+        global = true,
+        env = "DRY_RUN",
+        help = "Simulate the transactions instead of sending them, logging their compute units and account changes"
+    )]
+    pub dry_run: bool,
+
+    #[arg(
+        long,
+        global = true,
+        env = "NONCE_ACCOUNT",
+        help = "Durable nonce account advanced by the keypair, transactions use its nonce instead of a recent blockhash and do not expire"
+    )]
+    pub nonce_account: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "NONCE_HASH",
+        help = "Current nonce of the nonce account, to sign without fetching it from the RPC"
+    )]
+    pub nonce_hash: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "SIGN_ONLY",
+        help = "Print the signed transactions in base64 instead of sending them, requires a nonce account"
+    )]
+    pub sign_only: bool,
+
+    #[arg(
+        long,
+        global = true,
+        env = "JOURNAL",
+        help = "SQLite database every transaction sent is recorded in, listed with `history`"
+    )]
+    pub journal: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "SNAPSHOT_PROGRESS_FILE",
+        help = "JSON file the snapshot crank persists the vaults updated and delegations snapshotted to, a restarted crank resumes from the first delegation left"
+    )]
+    pub snapshot_progress_file: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        env = "VOTE_JOURNAL",
+        help = "JSON file every vote is recorded in before it is sent, a vote for another ballot in an epoch already voted is refused"
+    )]
+    pub vote_journal: Option<String>,
+
+    /// The subcommand run, recorded with the transactions in the journal
+    #[arg(skip)]
+    pub command_name: Option<String>,
+
     #[arg(long, global = true, help = "Verbose mode")]
     pub verbose: bool,
 
@@ -128,6 +415,97 @@ pub enum ProgramCommand {
             help = "Timeout in milliseconds when an error occurs before retrying"
         )]
         error_timeout_ms: u64,
+        #[arg(
+            long,
+            env,
+            default_value_t = 0,
+            help = "Minimum time in milliseconds between the start of two keeper loop iterations"
+        )]
+        loop_interval_ms: u64,
+        #[arg(
+            long,
+            env = "KEEPER_STAGES",
+            value_delimiter = ',',
+            default_values_t = KeeperStage::ALL.to_vec(),
+            help = "Comma separated stages this keeper runs"
+        )]
+        stages: Vec<KeeperStage>,
+        #[arg(
+            long,
+            env = "KEEPER_SKIP_STAGES",
+            value_delimiter = ',',
+            help = "Comma separated stages left to other keepers"
+        )]
+        skip_stages: Vec<KeeperStage>,
+        #[arg(
+            long,
+            env = "KEEPER_STAGE_MIN_SLOTS",
+            value_delimiter = ',',
+            help = "Comma separated minimum slots into the current epoch before a stage runs, e.g. snapshot=1000,close=200000"
+        )]
+        stage_min_slots: Vec<String>,
+        #[arg(
+            long,
+            env = "KEEPER_REGISTER_ST_MINT_WEIGHT",
+            help = "Weight the st mints of newly discovered vaults are registered with before the vaults, when the keypair is the NCN program admin"
+        )]
+        register_st_mint_weight: Option<u128>,
+        #[arg(
+            long,
+            env = "WEBSOCKET_URL",
+            help = "RPC websocket URL, the keeper cranks as soon as the epoch accounts change instead of waiting for the next loop"
+        )]
+        websocket_url: Option<String>,
+        #[arg(
+            long,
+            env = "KEEPER_STATE_FILE",
+            help = "JSON file the keeper progress is persisted to, a restarted keeper resumes from it"
+        )]
+        state_file: Option<String>,
+        #[arg(
+            long,
+            env = "KEEPER_ACCOUNT_PAYER_MIN_BALANCE",
+            help = "Account payer balance in SOL under which the keeper tops it up, or alerts without --account-payer-top-up"
+        )]
+        account_payer_min_balance: Option<f64>,
+        #[arg(
+            long,
+            env = "KEEPER_ACCOUNT_PAYER_TOP_UP",
+            default_value_t = 0.0,
+            help = "SOL transferred from the keeper wallet to the account payer when it is under --account-payer-min-balance, 0 to only alert"
+        )]
+        account_payer_top_up: f64,
+        #[arg(
+            long,
+            env = "KEEPER_ALERT_WEBHOOKS",
+            value_delimiter = ',',
+            help = "Comma separated webhooks alerted on voting stalls, consensus and failing cranks: slack=<url>, discord=<url> or pagerduty=<routing key>"
+        )]
+        alert_webhooks: Vec<String>,
+        #[arg(
+            long,
+            env = "KEEPER_ALERT_FAILURE_THRESHOLD",
+            default_value_t = 3,
+            help = "Consecutive failures of a crank before it is alerted"
+        )]
+        alert_failure_threshold: u64,
+        #[arg(
+            long,
+            env = "KEEPER_LEASE_SLOTS",
+            help = "Slots of the keeper lease, only the instance holding it cranks while the others signing with the same keypair stand by"
+        )]
+        lease_slots: Option<u64>,
+        #[arg(
+            long,
+            env = "KEEPER_LEASE_HOLDER",
+            help = "Address identifying this instance in the keeper lease, a random one by default"
+        )]
+        lease_holder: Option<String>,
+        #[arg(
+            long,
+            help = "Make a single pass over the epochs and exit with 0 when there was nothing to do, 2 when transactions were sent and 1 on errors"
+        )]
+        once: bool,
     },
 
     /// Operator Keeper
@@ -148,14 +526,119 @@ pub enum ProgramCommand {
             help = "Timeout in milliseconds when an error occurs before retrying"
         )]
         error_timeout_ms: u64,
+        #[arg(
+            long,
+            help = "Make a single pass over the epochs and exit with 0 when there was nothing to do, 2 when transactions were sent and 1 on errors"
+        )]
+        once: bool,
+        #[arg(
+            long,
+            value_enum,
+            env = "VOTE_SOURCE",
+            default_value_t = VoteSourceKind::OpenWeather,
+            help = "Source of the weather status voted in each epoch"
+        )]
+        vote_source: VoteSourceKind,
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Weather status voted by the constant vote source (0:Sunny, 1:Cloudy, 2:Rainy)"
+        )]
+        vote_weather_status: u8,
+        #[arg(
+            long,
+            env = "VOTE_FILE",
+            help = "File holding the weather status voted by the file vote source, read again in every epoch"
+        )]
+        vote_file: Option<String>,
+        #[arg(
+            long,
+            env = "VOTE_URL",
+            help = "URL the HTTP vote source gets the weather status from, `{epoch}` is replaced by the epoch"
+        )]
+        vote_url: Option<String>,
+        #[arg(
+            long,
+            env = "VOTE_COMMAND",
+            help = "Command the command vote source runs with the epoch as argument, printing the weather status or exiting with it"
+        )]
+        vote_command: Option<String>,
+        #[arg(
+            long,
+            env = "VOTE_COMMAND_TIMEOUT_MS",
+            default_value_t = 30_000, // 30 seconds
+            help = "Time in milliseconds the vote command has to decide the vote before it is killed"
+        )]
+        vote_command_timeout_ms: u64,
+        #[arg(
+            long,
+            env = "VOTE_SERVER_ADDRESS",
+            help = "Address the vote server listens on for the votes pushed with POST /vote, e.g. 0.0.0.0:8080"
+        )]
+        vote_server_address: Option<String>,
+        #[arg(
+            long,
+            env = "VOTE_SERVER_TOKEN",
+            help = "Bearer token the requests to the vote server are authenticated with"
+        )]
+        vote_server_token: Option<String>,
+        #[arg(
+            long,
+            env = "PROMETHEUS_ADDRESS",
+            help = "Address the operator metrics are served on for Prometheus, on GET /metrics, e.g. 0.0.0.0:9090"
+        )]
+        prometheus_address: Option<String>,
+        #[arg(
+            long,
+            env = "OPERATOR_STATE_FILE",
+            help = "JSON file the operator progress is persisted to, a restarted operator resumes at its epoch with the vote it decided"
+        )]
+        state_file: Option<String>,
+        #[arg(
+            long,
+            env = "OPERATOR_NCNS",
+            value_delimiter = ',',
+            help = "Comma separated NCNs the operator votes for along --ncn, as <NCN> or <NCN>:<NCN_PROGRAM_ID>"
+        )]
+        ncns: Vec<String>,
     },
     /// Crank Functions
     CrankUpdateAllVaults {},
-    CrankRegisterVaults {},
+    CrankRegisterVaults {
+        #[arg(
+            long,
+            help = "Weight the st mints of the new vaults are registered with before the vaults, when the keypair is the NCN program admin"
+        )]
+        register_st_mint_weight: Option<u128>,
+    },
     CrankSnapshot {},
-    CrankDistribute {},
-    CrankCloseEpochAccounts {},
+    /// Runs the reward waterfall of the epoch until its reward receivers are empty
+    CrankDistribute {
+        #[arg(
+            long,
+            default_value_t = 10,
+            help = "Passes over the reward waterfall before giving up on rewards left in the receivers"
+        )]
+        max_passes: u64,
+    },
+    /// Closes the epoch accounts, the epoch state last once every other account is closed
+    CrankCloseEpochAccounts {
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Comma separated account types to close, every type by default"
+        )]
+        only: Vec<EpochAccountType>,
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Comma separated account types to keep open, the epoch state is then kept as well"
+        )]
+        keep: Vec<EpochAccountType>,
+    },
     SetEpochWeights {},
+    SetWeightsFromSwitchboard {},
+    CopyPreviousEpochWeights {},
 
     /// Admin
     AdminCreateConfig {
@@ -184,12 +667,42 @@ pub enum ProgramCommand {
         weight: Option<u128>,
     },
 
+    AdminSetStMint {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(long, help = "Weight")]
+        weight: Option<u128>,
+        #[arg(
+            long,
+            help = "Switchboard feed address, the default pubkey removes the feed"
+        )]
+        switchboard_feed: Option<String>,
+    },
+
+    AdminSetDefaultWeight {
+        #[arg(
+            long,
+            help = "Default weight for st mints without a weight, 0 removes it"
+        )]
+        default_weight: u128,
+    },
+
     AdminSetWeight {
         #[arg(long, help = "Vault address")]
         vault: String,
         #[arg(long, help = "Weight value")]
         weight: u128,
     },
+    AdminSetWeights {
+        #[arg(long, value_delimiter = ',', help = "Vault addresses")]
+        vaults: Vec<String>,
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Weight values, in the same order as the vaults"
+        )]
+        weights: Vec<u128>,
+    },
     AdminSetTieBreaker {
         #[arg(long, help = "tie breaker for voting")]
         weather_status: u8,
@@ -203,6 +716,40 @@ pub enum ProgramCommand {
         valid_slots_after_consensus: Option<u64>,
         #[arg(long, help = "Starting valid epoch")]
         starting_valid_epoch: Option<u64>,
+        #[arg(
+            long,
+            help = "Share of routed SOL rewards paid to crankers in basis points"
+        )]
+        cranker_fee_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Slots after voting opens in which votes get the timeliness bonus"
+        )]
+        timeliness_bonus_slots: Option<u64>,
+        #[arg(long, help = "Stake weight bonus for timely votes in basis points")]
+        timeliness_bonus_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Epochs a vault can go without an update before its weight decays"
+        )]
+        weight_decay_epochs: Option<u64>,
+        #[arg(long, help = "Weight decay for stale vaults in basis points")]
+        weight_decay_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Largest share of the total stake weight a vault can hold in basis points"
+        )]
+        max_vault_stake_weight_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Lets anyone register vaults, registering their st mint with the default weight"
+        )]
+        permissionless_vault_registration: Option<bool>,
+        #[arg(
+            long,
+            help = "Slots parameter changes are queued for before they can be applied, 0 applies them right away"
+        )]
+        parameters_timelock_slots: Option<u64>,
     },
     AdminSetNewAdmin {
         #[arg(long, help = "New admin address")]
@@ -210,6 +757,80 @@ pub enum ProgramCommand {
         #[arg(long, help = "Set tie breaker admin")]
         set_tie_breaker_admin: bool,
     },
+    AdminSetAdminMultisig {
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Comma separated addresses that approve admin instructions"
+        )]
+        approvers: Vec<String>,
+        #[arg(
+            long,
+            help = "Number of approvers that have to sign admin instructions, 0 without approvers removes the multisig"
+        )]
+        threshold: u8,
+    },
+    AdminSetAdminGovernance {
+        #[arg(
+            long,
+            default_value = "GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw",
+            help = "spl-governance program of the realm"
+        )]
+        governance_program: String,
+        #[arg(
+            long,
+            help = "Realm whose governances approve admin instructions, omitting it removes the admin governance"
+        )]
+        realm: Option<String>,
+    },
+    AdminSetVaultRewardRecipient {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(
+            long,
+            help = "Reward recipient address, a wallet or wrapped SOL token account. Reward mint rewards are sent to its token accounts, or to the ones of the owner of the wrapped SOL account. Pass the vault to reset"
+        )]
+        reward_recipient: String,
+    },
+    AdminSetVaultMetadata {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(
+            long,
+            help = "Display name of the vault, up to 32 bytes. Pass an empty name to clear it"
+        )]
+        name: String,
+        #[arg(
+            long,
+            help = "URI with more information about the vault, only its hash is stored"
+        )]
+        uri: Option<String>,
+    },
+    AdminSetRewardMint {
+        #[arg(long, help = "Reward mint slot in the config")]
+        index: u8,
+        #[arg(
+            long,
+            help = "Reward mint address. Pass the default pubkey to clear the slot"
+        )]
+        mint: String,
+    },
+    AdminSetFeeGroup {
+        #[arg(long, help = "Fee group slot in the config")]
+        group: u8,
+        #[arg(long, help = "Display name of the fee group, up to 32 bytes")]
+        name: String,
+        #[arg(
+            long,
+            help = "Wallet receiving the fees of the group, takes effect immediately"
+        )]
+        wallet: String,
+        #[arg(
+            long,
+            help = "Fee of the group in basis points, takes effect in the next epoch"
+        )]
+        fee_bps: Option<u16>,
+    },
     AdminFundAccountPayer {
         #[arg(long, help = "Amount of SOL to fund")]
         amount_in_sol: f64,
@@ -223,8 +844,14 @@ pub enum ProgramCommand {
         vault: String,
     },
 
+    ApplyParameters,
+
     CreateEpochState,
 
+    CreateEpochAccounts,
+
+    MarkEpochSkipped,
+
     CreateWeightTable,
 
     CreateEpochSnapshot,
@@ -250,6 +877,20 @@ pub enum ProgramCommand {
         weather_status: u8,
     },
 
+    OperatorSetMetadata {
+        #[arg(long, help = "Operator address")]
+        operator: String,
+        #[arg(long, help = "Display name of the operator, up to 32 bytes")]
+        name: String,
+        #[arg(long, help = "Website of the operator, up to 64 bytes")]
+        website: Option<String>,
+        #[arg(
+            long,
+            help = "Contact information of the operator, only its hash is stored"
+        )]
+        contact: Option<String>,
+    },
+
     CreateNCNRewardRouter,
 
     CreateOperatorVaultRewardRouter {
@@ -259,6 +900,15 @@ pub enum ProgramCommand {
 
     RouteNCNRewards,
 
+    RouteAndDistributeNCNRewards {
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Operators whose vault reward routes are distributed in the same call"
+        )]
+        operators: Vec<String>,
+    },
+
     RouteOperatorVaultRewards {
         #[arg(long, help = "Operator address")]
         operator: String,
@@ -269,6 +919,8 @@ pub enum ProgramCommand {
         operator: String,
     },
 
+    ShrinkNCNRewardRouter,
+
     /// Getters
     GetNcn,
     GetNcnOperatorState {
@@ -301,9 +953,32 @@ pub enum ProgramCommand {
         #[arg(long, env = "OPERATOR", help = "Operator Account Address")]
         operator: String,
     },
+    GetOperatorMetadata {
+        #[arg(long, env = "OPERATOR", help = "Operator Account Address")]
+        operator: String,
+    },
     GetBallotBox,
     GetAccountPayer,
+    GetKeeperLease {
+        #[arg(
+            long,
+            help = "Keypair address the keeper instances sign with, the CLI keypair by default"
+        )]
+        authority: Option<String>,
+    },
     GetTotalEpochRentCost,
+    /// Estimates the rent of a full epoch and the account payer balance to fund before launch
+    EstimateRent {
+        #[arg(long, help = "Operators expected in the NCN")]
+        operators: u64,
+        #[arg(long, help = "Vaults expected in the NCN")]
+        vaults: u64,
+        #[arg(
+            long,
+            help = "Epochs whose accounts are open at once, defaults to the epochs after consensus before close of the config plus one"
+        )]
+        open_epochs: Option<u64>,
+    },
     GetConsensusResult,
 
     GetOperatorStakes,
@@ -317,6 +992,121 @@ pub enum ProgramCommand {
         operator: String,
     },
     GetAllOperatorVaultRewardRouters,
+    /// Decodes any NCN program account from its discriminator
+    GetAccount {
+        #[arg(long, help = "Account address")]
+        address: String,
+    },
+    /// Lists every account of the epoch with its lamports, size and owner, and whether it can be
+    /// closed yet
+    ListEpochAccounts {},
+    /// Closes every closable account of past epochs and reports the rent reclaimed
+    Gc {
+        #[arg(long, help = "First epoch to close the accounts of")]
+        from_epoch: u64,
+        #[arg(long, help = "Last epoch to close the accounts of, included")]
+        to_epoch: u64,
+    },
+    /// Recovers the lamports left in the reward receivers of past epochs, emptying the receivers
+    /// of closed routers into the NCN fee wallet and routing the others
+    RecoverRewardReceivers {
+        #[arg(long, help = "First epoch to recover the reward receivers of")]
+        from_epoch: u64,
+        #[arg(long, help = "Last epoch to recover the reward receivers of, included")]
+        to_epoch: u64,
+    },
+    /// Compares the vaults, st mint weights and operator stake weights snapshotted in two epochs
+    DiffEpochs {
+        #[arg(long, help = "Epoch to compare from")]
+        from_epoch: u64,
+        #[arg(long, help = "Epoch to compare to")]
+        to_epoch: u64,
+    },
+    /// Compares the config of the NCN with the expected one, failing when any setting drifted
+    VerifyConfig {
+        #[arg(
+            long,
+            help = "TOML file of the expected settings, the ones left out are not checked"
+        )]
+        expected: String,
+    },
+    /// Replays the votes of the epoch and checks the consensus on chain matches their tally,
+    /// flagging tie breaker interventions
+    Audit {},
+    /// Reports on the operators of the NCN
+    Report {
+        #[command(subcommand)]
+        report: ReportCommand,
+    },
+    /// Sums up the consensus latency, participation and rewards routed of the last epochs up
+    /// to the epoch, with the rewards of every operator
+    Stats {
+        #[arg(
+            long,
+            default_value_t = 10,
+            help = "Epochs summed up, the epoch included"
+        )]
+        last: u64,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Table, help = "Output format")]
+        format: ReportFormat,
+    },
+    /// Renders a live dashboard of the epoch in the terminal, with its state, the votes, the
+    /// stake toward consensus and the routing and distribution of its rewards
+    Watch {
+        #[arg(
+            long,
+            default_value_t = 2_000,
+            help = "Time in milliseconds between two refreshes of the dashboard"
+        )]
+        refresh_ms: u64,
+    },
+    /// Lists the transactions recorded in the `--journal`, the most recent first
+    History {
+        #[arg(long, default_value_t = 20, help = "Transactions listed")]
+        limit: u64,
+        #[arg(
+            long,
+            help = "Only the transactions sent by this command, e.g. run-keeper"
+        )]
+        command: Option<String>,
+        #[arg(long, help = "Only the transactions that failed")]
+        failed: bool,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Table, help = "Output format")]
+        format: ReportFormat,
+    },
+    /// Exports the operator votes and reward routes of the epoch to CSV files
+    Export {
+        #[arg(
+            long,
+            default_value = ".",
+            help = "Directory the CSV files are written to"
+        )]
+        output_dir: String,
+    },
+    /// Simulates every keeper stage of the epoch against the current accounts without sending
+    /// anything, reporting the transactions that would fail and the expected reward split
+    SimulateEpoch {
+        #[arg(
+            long,
+            help = "Rewards to split in lamports, defaults to the rewards in the NCN reward receiver"
+        )]
+        rewards: Option<u64>,
+    },
+    /// Manages the address lookup table of the epoch accounts, loaded by the batched cranks
+    Alt {
+        #[command(subcommand)]
+        alt: AltCommand,
+    },
+    /// Throwaway NCNs to exercise the keeper and operator flow on devnet
+    TestEnv {
+        #[command(subcommand)]
+        test_env: TestEnvCommand,
+    },
+    /// Sends a transaction signed with `--sign-only`
+    SendSignedTransaction {
+        #[arg(long, help = "Base64 encoded signed transaction")]
+        transaction: String,
+    },
 
     // GetAllOptedInValidators,
     FullUpdateVaults {
@@ -325,6 +1115,70 @@ pub enum ProgramCommand {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ReportCommand {
+    /// Which operators voted in the epoch, when and with how much stake, and which did not
+    Participation {
+        #[arg(
+            long,
+            help = "Sums up the participation over the last epochs up to the epoch instead"
+        )]
+        last: Option<u64>,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Table, help = "Output format")]
+        format: ReportFormat,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AltCommand {
+    /// Creates the lookup table of the epoch states, snapshots, routers and receivers of the epoch
+    Create,
+    /// Adds the epoch accounts missing from the lookup table, e.g. of operators that joined since
+    Extend,
+}
+
+#[derive(Subcommand)]
+pub enum TestEnvCommand {
+    /// Creates an NCN with operators and vaults delegating to them, all administered by the
+    /// keypair, registers them with the NCN program and funds the account payer
+    Setup {
+        #[arg(long, default_value_t = 3, help = "Operators of the NCN")]
+        operators: u64,
+        #[arg(
+            long,
+            default_value_t = 2,
+            help = "Vaults of the NCN, each with its own st mint"
+        )]
+        vaults: u64,
+        #[arg(
+            long,
+            default_value_t = 1_000.0,
+            help = "Tokens deposited in every vault for each operator and delegated to it"
+        )]
+        delegation: f64,
+        #[arg(
+            long,
+            default_value_t = 1,
+            help = "Weight the st mints are registered with"
+        )]
+        st_mint_weight: u128,
+        #[arg(long, default_value_t = 100, help = "Ncn Fee bps")]
+        ncn_fee_bps: u16,
+        #[arg(
+            long,
+            default_value_t = 10.0,
+            help = "SOL the account payer is funded with for the rent of the epoch accounts"
+        )]
+        account_payer_sol: f64,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
 #[rustfmt::skip]
 impl fmt::Display for Args {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -333,8 +1187,12 @@ impl fmt::Display for Args {
 
         // Network Configuration
         writeln!(f, "\n📡 Network Settings:")?;
+        writeln!(f, "  • Config File: {}", self.config.as_deref().unwrap_or("Not Set"))?;
         writeln!(f, "  • RPC URL:     {}", self.rpc_url)?;
+        writeln!(f, "  • Fallback RPC URLs: {}", if self.fallback_rpc_urls.is_empty() { "None".to_string() } else { self.fallback_rpc_urls.join(", ") })?;
         writeln!(f, "  • Commitment:  {}", self.commitment)?;
+        writeln!(f, "  • RPC Rate Limit: {}", if self.rpc_requests_per_second > 0 { format!("{} requests/s, burst {}", self.rpc_requests_per_second, self.rpc_burst) } else { "None".to_string() })?;
+        writeln!(f, "  • Jito Block Engine: {}", self.jito_block_engine_url.as_deref().unwrap_or("Not Set"))?;
 
         // Program IDs
         writeln!(f, "\n🔑 Program IDs:")?;
@@ -346,11 +1204,16 @@ impl fmt::Display for Args {
         // Solana Settings
         writeln!(f, "\n◎  Solana Settings:")?;
         writeln!(f, "  • Keypair Path:  {}", self.keypair_path.as_deref().unwrap_or("Not Set"))?;
+        writeln!(f, "  • Remote Signer: {}", self.remote_signer_url.as_deref().unwrap_or("Not Set"))?;
         writeln!(f, "  • NCN:  {}", self.ncn.as_deref().unwrap_or("Not Set"))?;
         writeln!(f, "  • Epoch: {}", if self.epoch.is_some() { format!("{}", self.epoch.unwrap()) } else { "Current".to_string() })?;
 
         // Optional Settings
         writeln!(f, "\n⚙️  Additional Settings:")?;
+        writeln!(f, "  • Dry Run:       {}", if self.dry_run { "Enabled" } else { "Disabled" })?;
+        writeln!(f, "  • Journal:       {}", self.journal.as_deref().unwrap_or("Not Set"))?;
+        writeln!(f, "  • Snapshot Progress File: {}", self.snapshot_progress_file.as_deref().unwrap_or("Not Set"))?;
+        writeln!(f, "  • Vote Journal:  {}", self.vote_journal.as_deref().unwrap_or("Not Set"))?;
         writeln!(f, "  • Verbose Mode:  {}", if self.verbose { "Enabled" } else { "Disabled" })?;
         writeln!(f, "  • Markdown Help: {}", if self.markdown_help { "Enabled" } else { "Disabled" })?;
 
@@ -360,6 +1223,23 @@ impl fmt::Display for Args {
     }
 }
 
+/// Sources of the weather status `run-operator` votes
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteSourceKind {
+    /// The current weather in Solana Beach, from the OpenWeather API
+    OpenWeather,
+    /// The same weather status in every epoch, `--vote-weather-status`
+    Constant,
+    /// The weather status written in `--vote-file`
+    File,
+    /// The weather status answered by `--vote-url`
+    Http,
+    /// The weather status decided by `--vote-command`
+    Command,
+    /// The weather status pushed to the vote server of `--vote-server-address`
+    Server,
+}
+
 #[derive(ValueEnum, Debug, Clone)]
 pub enum Cluster {
     Mainnet,
@@ -376,3 +1256,82 @@ impl fmt::Display for Cluster {
         }
     }
 }
+
+/// Types of the epoch accounts closed by `crank-close-epoch-accounts`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpochAccountType {
+    BallotBox,
+    OperatorSnapshot,
+    EpochSnapshot,
+    WeightTable,
+    /// Closed last, closing it marks the epoch closed for good and leaves the other accounts open
+    EpochState,
+    /// Never closed by the program, it stays the record of the epoch consensus
+    ConsensusResult,
+}
+
+impl EpochAccountType {
+    pub const ALL: [Self; 6] = [
+        Self::BallotBox,
+        Self::OperatorSnapshot,
+        Self::EpochSnapshot,
+        Self::WeightTable,
+        Self::EpochState,
+        Self::ConsensusResult,
+    ];
+
+    /// The types to close, the `only` ones or else every type, less the `keep` ones
+    pub fn selected(only: &[Self], keep: &[Self]) -> Vec<Self> {
+        let only = if only.is_empty() {
+            &Self::ALL[..]
+        } else {
+            only
+        };
+
+        only.iter()
+            .filter(|account_type| !keep.contains(account_type))
+            .copied()
+            .collect()
+    }
+}
+
+/// Stages of the NCN keeper loop, teams splitting the keeper duties enable a subset on each keeper
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeeperStage {
+    /// Registers the vaults of the NCN
+    Register,
+    /// Creates the epoch accounts, sets the weights and snapshots the operators and vaults
+    Snapshot,
+    /// Waits for the operator votes and logs the consensus result
+    Vote,
+    /// Routes the rewards received by the reward routers
+    Route,
+    /// Distributes the routed rewards
+    Distribute,
+    /// Closes the epoch accounts
+    Close,
+}
+
+impl KeeperStage {
+    pub const ALL: [Self; 6] = [
+        Self::Register,
+        Self::Snapshot,
+        Self::Vote,
+        Self::Route,
+        Self::Distribute,
+        Self::Close,
+    ];
+}
+
+impl fmt::Display for KeeperStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Register => write!(f, "register"),
+            Self::Snapshot => write!(f, "snapshot"),
+            Self::Vote => write!(f, "vote"),
+            Self::Route => write!(f, "route"),
+            Self::Distribute => write!(f, "distribute"),
+            Self::Close => write!(f, "close"),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use ncn_program_core::ballot_box::BallotBox;
+
+use crate::{
+    getters::{get_ballot_box, get_consensus_result, get_epoch_snapshot, get_ncn_program_config},
+    handler::CliHandler,
+};
+
+/// Replays the votes of the epoch through the tally of the program and compares the outcome with
+/// the ballot box and consensus result on chain, failing on any mismatch. Winners set by the tie
+/// breaker admin are flagged, as the votes alone did not pick them.
+pub async fn audit_consensus(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ballot_box = get_ballot_box(handler, epoch).await.map_err(|e| {
+        anyhow!(
+            "No ballot box for epoch {}, closed or never created: {}",
+            epoch,
+            e
+        )
+    })?;
+    let epoch_snapshot = get_epoch_snapshot(handler, epoch).await?;
+    let config = get_ncn_program_config(handler).await?;
+    // Checked against the replay when it exists
+    let consensus_result = get_consensus_result(handler, epoch).await.ok();
+
+    let total_stake_weights = *epoch_snapshot.stake_weights();
+    let total_stake_weight = total_stake_weights.stake_weight();
+    let replay = ballot_box
+        .replay_votes(&total_stake_weights, config.valid_slots_after_consensus())
+        .map_err(|e| anyhow!("Could not replay the votes of epoch {}: {:?}", epoch, e))?;
+
+    let mut report = format!(
+        "\n\n---------- Consensus Audit ----------\nNCN: {}\nEpoch: {}\nOperators Voted: {}\nTotal Stake Weight: {}",
+        handler.ncn()?,
+        epoch,
+        ballot_box.operators_voted(),
+        total_stake_weight
+    );
+    let mut mismatches = check_tallies(&ballot_box, &replay, total_stake_weight, &mut report);
+
+    if ballot_box.tie_breaker_set() {
+        let winning_ballot = ballot_box.get_winning_ballot()?;
+        report.push_str(&format!(
+            "\n\nWinner: {} set by the tie breaker admin {}",
+            winning_ballot, config.tie_breaker_admin
+        ));
+        warn!(
+            "Tie breaker intervention in epoch {}, {} was set without consensus of the votes",
+            epoch, winning_ballot
+        );
+
+        if let Ok(replayed_ballot) = replay.get_winning_ballot() {
+            mismatches.push(format!(
+                "the votes reach consensus on {} at slot {}, yet the tie breaker set {}",
+                replayed_ballot,
+                replay.slot_consensus_reached(),
+                winning_ballot
+            ));
+        }
+    } else if let Ok(winning_ballot) = ballot_box.get_winning_ballot() {
+        report.push_str(&format!(
+            "\n\nWinner: {} at slot {}",
+            winning_ballot,
+            ballot_box.slot_consensus_reached()
+        ));
+
+        match replay.get_winning_ballot() {
+            Ok(replayed_ballot) => {
+                if replayed_ballot.ne(winning_ballot) {
+                    mismatches.push(format!(
+                        "winner {} on chain, {} replayed",
+                        winning_ballot, replayed_ballot
+                    ));
+                }
+                if replay.slot_consensus_reached() != ballot_box.slot_consensus_reached() {
+                    mismatches.push(format!(
+                        "consensus reached at slot {} on chain, {} replayed",
+                        ballot_box.slot_consensus_reached(),
+                        replay.slot_consensus_reached()
+                    ));
+                }
+            }
+            Err(_) => mismatches.push(format!(
+                "winner {} on chain, the votes do not reach consensus",
+                winning_ballot
+            )),
+        }
+    } else {
+        report.push_str("\n\nWinner: none, consensus not reached");
+
+        if let Ok(replayed_ballot) = replay.get_winning_ballot() {
+            mismatches.push(format!(
+                "no winner on chain, the votes reach consensus on {} at slot {}",
+                replayed_ballot,
+                replay.slot_consensus_reached()
+            ));
+        }
+    }
+
+    // The tie breaker does not record the consensus result, only the votes do
+    if let Some(consensus_result) = consensus_result {
+        if let Ok(winning_tally) = replay.get_winning_ballot_tally() {
+            let expected = [
+                (
+                    "weather status",
+                    winning_tally.ballot().weather_status() as u64,
+                    consensus_result.weather_status() as u64,
+                ),
+                (
+                    "vote weight",
+                    winning_tally.stake_weights().stake_weight() as u64,
+                    consensus_result.vote_weight(),
+                ),
+                (
+                    "total vote weight",
+                    total_stake_weight as u64,
+                    consensus_result.total_vote_weight(),
+                ),
+                (
+                    "consensus slot",
+                    replay.slot_consensus_reached(),
+                    consensus_result.consensus_slot(),
+                ),
+            ];
+
+            for (field, replayed, on_chain) in expected {
+                if replayed != on_chain {
+                    mismatches.push(format!(
+                        "consensus result {} is {}, {} replayed",
+                        field, on_chain, replayed
+                    ));
+                }
+            }
+        } else if consensus_result.is_consensus_reached() {
+            mismatches.push(format!(
+                "consensus result records {} at slot {}, the votes do not reach consensus",
+                consensus_result.weather_status(),
+                consensus_result.consensus_slot()
+            ));
+        }
+    }
+
+    if mismatches.is_empty() {
+        info!(
+            "{}\n\nThe replayed votes match the outcome on chain\n",
+            report
+        );
+        return Ok(());
+    }
+
+    for mismatch in mismatches.iter() {
+        report.push_str(&format!("\n  Mismatch: {}", mismatch));
+    }
+    error!("{}\n", report);
+
+    Err(anyhow!(
+        "{} mismatches between the replayed votes and the consensus of epoch {}",
+        mismatches.len(),
+        epoch
+    ))
+}
+
+/// Adds the tally of every ballot to the report
+///
+/// # Returns
+/// The ballots tallied differently by the replay
+fn check_tallies(
+    ballot_box: &BallotBox,
+    replay: &BallotBox,
+    total_stake_weight: u128,
+    report: &mut String,
+) -> Vec<String> {
+    let mut mismatches = vec![];
+
+    report.push_str("\n\nBallots:");
+    for tally in ballot_box
+        .ballot_tallies()
+        .iter()
+        .filter(|tally| tally.is_valid())
+    {
+        let stake_weight = tally.stake_weights().stake_weight();
+        report.push_str(&format!(
+            "\n  {}: {} votes, {} stake weight ({:.2}%)",
+            tally.ballot(),
+            tally.tally(),
+            stake_weight,
+            stake_weight as f64 / total_stake_weight.max(1) as f64 * 100.0
+        ));
+
+        let (replayed_votes, replayed_stake_weight) = replay
+            .ballot_tallies()
+            .iter()
+            .find(|replayed| replayed.ballot().eq(tally.ballot()))
+            .map_or((0, 0), |replayed| {
+                (replayed.tally(), replayed.stake_weights().stake_weight())
+            });
+
+        if replayed_votes != tally.tally() || replayed_stake_weight != stake_weight {
+            mismatches.push(format!(
+                "{} tallied {} votes with {} stake weight on chain, {} with {} replayed",
+                tally.ballot(),
+                tally.tally(),
+                stake_weight,
+                replayed_votes,
+                replayed_stake_weight
+            ));
+        }
+    }
+
+    mismatches
+}
@@ -1,18 +1,23 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
 use clap_markdown::MarkdownOptions;
 use dotenv::dotenv;
 
 use log::info;
-use ncn_program_cli::{args::Args, handler::CliHandler, log::init_logger};
+use ncn_program_cli::{
+    args::Args, handler::CliHandler, log::init_logger, settings::load_settings_file,
+};
 
 #[tokio::main]
 #[allow(clippy::large_stack_frames)]
 async fn main() -> Result<()> {
     dotenv().ok();
     init_logger();
+    load_settings_file(&std::env::args().collect::<Vec<_>>())?;
 
-    let args: Args = Args::parse();
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    args.command_name = matches.subcommand_name().map(str::to_string);
 
     if args.markdown_help {
         let markdown = clap_markdown::help_markdown_custom::<Args>(
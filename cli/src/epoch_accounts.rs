@@ -0,0 +1,512 @@
+use std::{collections::HashSet, fmt};
+
+use anyhow::{anyhow, Result};
+use log::info;
+use ncn_program_core::{epoch_state::EpochState, pdas::NCNProgramPda};
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
+use solana_sdk::{account::Account, native_token::lamports_to_sol, pubkey::Pubkey};
+
+use crate::{
+    getters::{
+        get_all_operators_in_ncn, get_current_slot, get_epoch_state, get_ncn_program_config,
+        get_receiver_rewards,
+    },
+    handler::CliHandler,
+    instructions::{
+        close_all_epoch_accounts, close_epoch_account, close_reward_receiver, close_reward_router,
+        crank_route_and_distribute,
+    },
+};
+
+/// One account of an epoch, as it is on chain
+#[derive(Debug, Clone)]
+pub struct EpochAccount {
+    pub pda: NCNProgramPda,
+    pub address: Pubkey,
+    /// `None` when the account does not exist
+    pub account: Option<Account>,
+}
+
+/// When the epoch accounts can be closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochCloseWindow {
+    /// The epoch state exists, `CloseEpochAccount` needs it
+    pub has_epoch_state: bool,
+    /// The epoch marker exists, the epoch is closed for good
+    pub is_closed: bool,
+    /// The first epoch the accounts can be closed in, `None` until consensus is reached
+    pub closable_from: Option<u64>,
+    pub current_epoch: u64,
+}
+
+/// Whether `CloseEpochAccount` would close an account now, or why not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseStatus {
+    DoesNotExist,
+    /// Reward receivers are closed along with their reward router
+    ClosedWithRouter,
+    /// The consensus result and epoch marker are never closed
+    Kept,
+    /// The epoch marker exists, accounts left are not closable anymore
+    EpochClosed,
+    NoEpochState,
+    NoConsensus,
+    ClosableFrom(u64),
+    /// The epoch state is closed last
+    AfterOtherAccounts,
+    Closable,
+}
+
+impl fmt::Display for CloseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DoesNotExist => write!(f, "Does not exist"),
+            Self::ClosedWithRouter => write!(f, "Closed with its reward router"),
+            Self::Kept => write!(f, "Kept for good"),
+            Self::EpochClosed => write!(f, "Not closable, the epoch marker exists"),
+            Self::NoEpochState => write!(f, "Not closable, the epoch state does not exist"),
+            Self::NoConsensus => write!(f, "Not closable, no consensus yet"),
+            Self::ClosableFrom(epoch) => write!(f, "Closable from epoch {}", epoch),
+            Self::AfterOtherAccounts => write!(f, "Closable once the other accounts are closed"),
+            Self::Closable => write!(f, "Closable"),
+        }
+    }
+}
+
+impl EpochAccount {
+    pub fn exists(&self) -> bool {
+        self.lamports() > 0
+    }
+
+    pub fn lamports(&self) -> u64 {
+        self.account.as_ref().map_or(0, |account| account.lamports)
+    }
+
+    pub fn close_status(&self, window: &EpochCloseWindow, accounts: &[Self]) -> CloseStatus {
+        if !self.exists() {
+            return CloseStatus::DoesNotExist;
+        }
+
+        match self.pda {
+            NCNProgramPda::NCNRewardReceiver { .. }
+            | NCNProgramPda::OperatorVaultRewardReceiver { .. } => {
+                return CloseStatus::ClosedWithRouter;
+            }
+            NCNProgramPda::ConsensusResult { .. } | NCNProgramPda::EpochMarker { .. } => {
+                return CloseStatus::Kept;
+            }
+            _ => {}
+        }
+
+        if window.is_closed {
+            return CloseStatus::EpochClosed;
+        }
+        if !window.has_epoch_state {
+            return CloseStatus::NoEpochState;
+        }
+
+        let Some(closable_from) = window.closable_from else {
+            return CloseStatus::NoConsensus;
+        };
+        if window.current_epoch < closable_from {
+            return CloseStatus::ClosableFrom(closable_from);
+        }
+
+        let others_open = accounts.iter().any(|account| {
+            account.pda.is_closed_with_epoch() && !account.is_epoch_state() && account.exists()
+        });
+        if self.is_epoch_state() && others_open {
+            return CloseStatus::AfterOtherAccounts;
+        }
+
+        CloseStatus::Closable
+    }
+
+    fn is_epoch_state(&self) -> bool {
+        matches!(self.pda, NCNProgramPda::EpochState { .. })
+    }
+
+    fn is_reward_receiver(&self) -> bool {
+        matches!(
+            self.pda,
+            NCNProgramPda::NCNRewardReceiver { .. }
+                | NCNProgramPda::OperatorVaultRewardReceiver { .. }
+        )
+    }
+}
+
+/// Fetches every account of the epoch, the per operator accounts of the operators currently in
+/// the NCN included
+pub async fn get_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<Vec<EpochAccount>> {
+    let operators = get_all_operators_in_ncn(handler).await?;
+    fetch_epoch_accounts(handler, &operators, epoch).await
+}
+
+async fn fetch_epoch_accounts(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<Vec<EpochAccount>> {
+    let client = handler.rpc_client();
+
+    let pdas = NCNProgramPda::epoch_accounts(handler.ncn()?, operators, epoch);
+    let addresses: Vec<Pubkey> = pdas
+        .iter()
+        .map(|pda| pda.address(&handler.ncn_program_id))
+        .collect();
+
+    let mut accounts = vec![];
+    for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        accounts.extend(
+            client
+                .get_multiple_accounts_with_commitment(chunk, handler.commitment)
+                .await?
+                .value,
+        );
+    }
+
+    Ok(pdas
+        .into_iter()
+        .zip(addresses)
+        .zip(accounts)
+        .map(|((pda, address), account)| EpochAccount {
+            pda,
+            address,
+            account,
+        })
+        .collect())
+}
+
+pub async fn get_epoch_close_window(
+    handler: &CliHandler,
+    epoch: u64,
+    accounts: &[EpochAccount],
+) -> Result<EpochCloseWindow> {
+    let epoch_schedule = handler.rpc_client().get_epoch_schedule().await?;
+    let current_epoch = epoch_schedule.get_epoch(get_current_slot(handler).await?);
+
+    let is_closed = accounts.iter().any(|account| {
+        matches!(account.pda, NCNProgramPda::EpochMarker { .. }) && account.exists()
+    });
+
+    let epoch_state: Option<EpochState> = get_epoch_state(handler, epoch).await.ok();
+    let closable_from = match &epoch_state {
+        Some(epoch_state) => {
+            let config = get_ncn_program_config(handler).await?;
+            epoch_state
+                .get_epoch_consensus_reached(&epoch_schedule)
+                .ok()
+                .map(|consensus_epoch| {
+                    consensus_epoch.saturating_add(config.epochs_after_consensus_before_close())
+                })
+        }
+        None => None,
+    };
+
+    Ok(EpochCloseWindow {
+        has_epoch_state: epoch_state.is_some(),
+        is_closed,
+        closable_from,
+        current_epoch,
+    })
+}
+
+/// Logs every account of the epoch with its lamports, size and owner, and whether it can be
+/// closed yet
+pub async fn list_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let accounts = get_epoch_accounts(handler, epoch).await?;
+    let window = get_epoch_close_window(handler, epoch, &accounts).await?;
+
+    let mut log_message = format!("\n\n--- Accounts of epoch {} ---", epoch);
+    let mut open_lamports: u64 = 0;
+
+    for account in accounts.iter() {
+        let name = match account.pda.operator() {
+            Some(operator) => format!("{} ({})", account.pda.name(), operator),
+            None => account.pda.name().to_string(),
+        };
+        log_message.push_str(&format!("\n{}\n  Address: {}", name, account.address));
+
+        if let Some(on_chain) = account.account.as_ref().filter(|_| account.exists()) {
+            open_lamports = open_lamports.saturating_add(on_chain.lamports);
+            log_message.push_str(&format!(
+                "\n  Lamports: {}\n  Size: {}\n  Owner: {}",
+                on_chain.lamports,
+                on_chain.data.len(),
+                on_chain.owner
+            ));
+        }
+
+        log_message.push_str(&format!(
+            "\n  Close: {}",
+            account.close_status(&window, &accounts)
+        ));
+    }
+
+    log_message.push_str(&format!(
+        "\nOpen Accounts: {} of {}\nTotal Lamports: {}\n",
+        accounts.iter().filter(|account| account.exists()).count(),
+        accounts.len(),
+        open_lamports
+    ));
+    info!("{}", log_message);
+
+    Ok(())
+}
+
+/// Closes every closable account of the epochs from `from_epoch` to `to_epoch` included, reward
+/// routers along with their receivers and the epoch states last, then logs the rent reclaimed
+///
+/// Epochs failing to close are logged and left for a later run.
+pub async fn collect_garbage(handler: &CliHandler, from_epoch: u64, to_epoch: u64) -> Result<()> {
+    if from_epoch > to_epoch {
+        return Err(anyhow!(
+            "From epoch {} is after to epoch {}",
+            from_epoch,
+            to_epoch
+        ));
+    }
+
+    let operators = get_all_operators_in_ncn(handler).await?;
+    let mut total_reclaimed: u64 = 0;
+    let mut epochs_closed = 0;
+
+    for epoch in from_epoch..=to_epoch {
+        match collect_epoch_garbage(handler, &operators, epoch).await {
+            Ok(0) => {}
+            Ok(reclaimed) => {
+                info!("Epoch {}: reclaimed {} lamports", epoch, reclaimed);
+                total_reclaimed = total_reclaimed.saturating_add(reclaimed);
+                epochs_closed += 1;
+            }
+            Err(e) => log::error!("Could not close the accounts of epoch {}: {:?}", epoch, e),
+        }
+    }
+
+    info!(
+        "\n\n--- Garbage Collection of epochs {} to {} ---\nEpochs With Accounts Closed: {}\nRent Reclaimed: {}\n",
+        from_epoch,
+        to_epoch,
+        epochs_closed,
+        lamports_to_sol(total_reclaimed)
+    );
+
+    Ok(())
+}
+
+/// Closes the closable accounts of one epoch
+///
+/// # Returns
+/// The lamports of the accounts closed, less the rent of the epoch marker created when the epoch
+/// state is closed
+async fn collect_epoch_garbage(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<u64> {
+    let ncn = *handler.ncn()?;
+
+    let accounts = fetch_epoch_accounts(handler, operators, epoch).await?;
+    if !accounts
+        .iter()
+        .any(|account| account.is_epoch_state() && account.exists())
+    {
+        return Ok(0);
+    }
+
+    let window = get_epoch_close_window(handler, epoch, &accounts).await?;
+    let closable: Vec<&EpochAccount> = accounts
+        .iter()
+        .filter(|account| account.close_status(&window, &accounts) == CloseStatus::Closable)
+        .collect();
+    let epoch_state_closable = accounts.iter().any(|account| {
+        account.is_epoch_state()
+            && matches!(
+                account.close_status(&window, &accounts),
+                CloseStatus::Closable | CloseStatus::AfterOtherAccounts
+            )
+    });
+    if !epoch_state_closable {
+        return Ok(0);
+    }
+
+    let open_before: u64 = accounts.iter().map(EpochAccount::lamports).sum();
+
+    // Reward routers need their receiver, the other accounts are closed in batches
+    let mut batch = vec![];
+    for account in closable.iter().filter(|account| !account.is_epoch_state()) {
+        let reward_receiver = match account.pda {
+            NCNProgramPda::NCNRewardRouter { .. } => {
+                Some(NCNProgramPda::NCNRewardReceiver { ncn, epoch })
+            }
+            NCNProgramPda::OperatorVaultRewardRouter { operator, .. } => {
+                Some(NCNProgramPda::OperatorVaultRewardReceiver {
+                    operator,
+                    ncn,
+                    epoch,
+                })
+            }
+            _ => None,
+        };
+
+        match reward_receiver {
+            Some(reward_receiver) => {
+                let result = close_reward_router(
+                    handler,
+                    ncn,
+                    epoch,
+                    account.address,
+                    reward_receiver.address(&handler.ncn_program_id),
+                )
+                .await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to close reward router: {:?} in epoch: {:?} with error: {:?}",
+                        account.address,
+                        epoch,
+                        err
+                    );
+                }
+            }
+            None => batch.push(account.address),
+        }
+    }
+    close_all_epoch_accounts(handler, ncn, epoch, &batch).await?;
+
+    let epoch_state = NCNProgramPda::EpochState { ncn, epoch }.address(&handler.ncn_program_id);
+    close_epoch_account(handler, ncn, epoch, epoch_state).await?;
+
+    let open_after: u64 = fetch_epoch_accounts(handler, operators, epoch)
+        .await?
+        .iter()
+        .map(EpochAccount::lamports)
+        .sum();
+
+    Ok(open_before.saturating_sub(open_after))
+}
+
+/// Finds the reward receivers of the epochs from `from_epoch` to `to_epoch` included still holding
+/// lamports and recovers them: receivers whose router was closed are emptied into the NCN fee
+/// wallet, the rewards of the others are routed and distributed.
+///
+/// Epochs failing to recover are logged and left for a later run.
+pub async fn recover_reward_receivers(
+    handler: &CliHandler,
+    from_epoch: u64,
+    to_epoch: u64,
+) -> Result<()> {
+    if from_epoch > to_epoch {
+        return Err(anyhow!(
+            "From epoch {} is after to epoch {}",
+            from_epoch,
+            to_epoch
+        ));
+    }
+
+    let operators = get_all_operators_in_ncn(handler).await?;
+    let mut total_recovered: u64 = 0;
+    let mut receivers_recovered = 0;
+
+    for epoch in from_epoch..=to_epoch {
+        match recover_epoch_reward_receivers(handler, &operators, epoch).await {
+            Ok((0, _)) => {}
+            Ok((receivers, recovered)) => {
+                info!(
+                    "Epoch {}: recovered {} lamports from {} reward receivers",
+                    epoch, recovered, receivers
+                );
+                total_recovered = total_recovered.saturating_add(recovered);
+                receivers_recovered += receivers;
+            }
+            Err(e) => log::error!(
+                "Could not recover the reward receivers of epoch {}: {:?}",
+                epoch,
+                e
+            ),
+        }
+    }
+
+    info!(
+        "\n\n--- Reward Receiver Recovery of epochs {} to {} ---\nReceivers Recovered: {}\nLamports Recovered: {}\n",
+        from_epoch,
+        to_epoch,
+        receivers_recovered,
+        lamports_to_sol(total_recovered)
+    );
+
+    Ok(())
+}
+
+/// Recovers the reward receivers of one epoch holding lamports
+///
+/// # Returns
+/// The receivers recovered and the lamports they held
+async fn recover_epoch_reward_receivers(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<(usize, u64)> {
+    let ncn = *handler.ncn()?;
+
+    let accounts = fetch_epoch_accounts(handler, operators, epoch).await?;
+    if !accounts
+        .iter()
+        .any(|account| account.is_reward_receiver() && account.exists())
+    {
+        return Ok((0, 0));
+    }
+
+    // Routers can not be created anymore once the epoch is closing, a missing router was closed
+    let is_closing = accounts.iter().any(|account| {
+        matches!(account.pda, NCNProgramPda::EpochMarker { .. }) && account.exists()
+    }) || get_epoch_state(handler, epoch)
+        .await
+        .is_ok_and(|epoch_state| epoch_state.is_closing());
+
+    let mut receivers = 0;
+    let mut recovered: u64 = 0;
+    let mut to_route: u64 = 0;
+
+    for receiver in accounts
+        .iter()
+        .filter(|account| account.is_reward_receiver() && account.exists())
+    {
+        let (router, operator) = match receiver.pda {
+            NCNProgramPda::OperatorVaultRewardReceiver { operator, .. } => (
+                NCNProgramPda::OperatorVaultRewardRouter {
+                    operator,
+                    ncn,
+                    epoch,
+                },
+                Some(operator),
+            ),
+            _ => (NCNProgramPda::NCNRewardRouter { ncn, epoch }, None),
+        };
+        let router_address = router.address(&handler.ncn_program_id);
+        let router_exists = accounts
+            .iter()
+            .any(|account| account.address == router_address && account.exists());
+
+        if is_closing && !router_exists {
+            close_reward_receiver(handler, ncn, epoch, operator).await?;
+            receivers += 1;
+            recovered = recovered.saturating_add(receiver.lamports());
+            continue;
+        }
+
+        // Open receivers keep their rent, only the rewards above it are stuck
+        let rewards = get_receiver_rewards(handler, &receiver.address).await?;
+        if rewards > 0 {
+            receivers += 1;
+            to_route = to_route.saturating_add(rewards);
+        }
+    }
+
+    if to_route > 0 {
+        crank_route_and_distribute(handler, epoch, true, true, &HashSet::new()).await?;
+        recovered = recovered.saturating_add(to_route);
+    }
+
+    Ok((receivers, recovered))
+}
@@ -0,0 +1,98 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use log::info;
+use ncn_program_core::{ballot_box::BallotBox, ncn_reward_router::NCNRewardRouter};
+
+use crate::{
+    getters::{get_ballot_box, get_ncn_reward_router},
+    handler::CliHandler,
+};
+
+/// Writes the operator votes and the operator reward routes of an epoch to
+/// `votes_<epoch>.csv` and `reward_routes_<epoch>.csv` in `output_dir`
+///
+/// The reward routes file is only written once the NCN reward router of the epoch exists
+///
+/// # Arguments
+/// * `handler` - CLI handler containing RPC client and configuration
+/// * `epoch` - The epoch to export
+/// * `output_dir` - Directory the files are written to, created if needed
+pub async fn export_epoch(handler: &CliHandler, epoch: u64, output_dir: &str) -> Result<()> {
+    let output_dir = Path::new(output_dir);
+    fs::create_dir_all(output_dir)
+        .map_err(|e| anyhow!("Could not create {}: {}", output_dir.display(), e))?;
+
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+    let votes_path = output_dir.join(format!("votes_{}.csv", epoch));
+    fs::write(&votes_path, votes_csv(&ballot_box))?;
+    info!(
+        "Exported votes of epoch {} to {}",
+        epoch,
+        votes_path.display()
+    );
+
+    match get_ncn_reward_router(handler, epoch).await {
+        Ok(ncn_reward_router) => {
+            let routes_path = output_dir.join(format!("reward_routes_{}.csv", epoch));
+            fs::write(&routes_path, reward_routes_csv(&ncn_reward_router)?)?;
+            info!(
+                "Exported reward routes of epoch {} to {}",
+                epoch,
+                routes_path.display()
+            );
+        }
+        Err(e) => info!(
+            "No reward routes exported for epoch {}, the NCN reward router could not be fetched: {}",
+            epoch, e
+        ),
+    }
+
+    Ok(())
+}
+
+/// One row per operator vote: the operator, the weather status it voted for, its stake weight and
+/// the slot it voted in
+pub fn votes_csv(ballot_box: &BallotBox) -> String {
+    let mut csv = String::from("epoch,operator,ballot,stake_weight,slot_voted\n");
+
+    for vote in ballot_box.iter_votes() {
+        let ballot = ballot_box
+            .ballot_tallies()
+            .get(vote.ballot_index() as usize)
+            .map(|tally| *tally.ballot());
+        let ballot = ballot.map_or("Unknown".to_string(), |ballot| {
+            ballot.status().map_or_else(
+                || ballot.weather_status().to_string(),
+                |status| status.to_string(),
+            )
+        });
+
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            ballot_box.epoch(),
+            vote.operator(),
+            ballot,
+            vote.stake_weights().stake_weight(),
+            vote.slot_voted()
+        ));
+    }
+
+    csv
+}
+
+/// One row per operator reward route: the operator and the rewards routed to it so far
+pub fn reward_routes_csv(ncn_reward_router: &NCNRewardRouter) -> Result<String> {
+    let mut csv = String::from("epoch,operator,rewards\n");
+
+    for route in ncn_reward_router.iter_routes() {
+        csv.push_str(&format!(
+            "{},{},{}\n",
+            ncn_reward_router.epoch(),
+            route.operator(),
+            route.rewards()?
+        ));
+    }
+
+    Ok(csv)
+}
@@ -22,23 +22,38 @@ use ncn_program_core::operator_vault_reward_router::{
     OperatorVaultRewardReceiver, OperatorVaultRewardRouter,
 };
 use ncn_program_core::{
-    account_payer::AccountPayer,
     ballot_box::BallotBox,
     config::Config as NCNProgramConfig,
     consensus_result::ConsensusResult,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    keeper_lease::KeeperLease,
+    operator_metadata::OperatorMetadata,
     vault_registry::VaultRegistry,
     weight_table::WeightTable,
 };
+use ncn_program_sdk::fetch::{
+    fetch_account_payer, fetch_ballot_box, fetch_config, fetch_consensus_result,
+    fetch_epoch_marker, fetch_epoch_snapshot, fetch_epoch_state, fetch_is_epoch_completed,
+    fetch_keeper_lease, fetch_ncn_reward_router, fetch_operator_metadata, fetch_operator_snapshot,
+    fetch_operator_vault_reward_router, fetch_receiver_rewards, fetch_vault_registry,
+    fetch_weight_table,
+};
+use ncn_program_sdk::lookup_tables::epoch_lookup_table_filters;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_client::{
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::clock::DEFAULT_SLOTS_PER_EPOCH;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{
+    account::Account,
+    address_lookup_table::{self, state::AddressLookupTable, AddressLookupTableAccount},
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar,
+};
 use tokio::time::sleep;
 
 // ---------------------- HELPERS ----------------------
@@ -52,6 +67,73 @@ pub async fn get_account(handler: &CliHandler, account: &Pubkey) -> Result<Optio
     Ok(account.value)
 }
 
+pub async fn get_address_lookup_table(
+    handler: &CliHandler,
+    address: &Pubkey,
+) -> Result<AddressLookupTableAccount> {
+    let account = get_account(handler, address)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Address lookup table {} not found", address))?;
+    if account.owner != address_lookup_table::program::id() {
+        return Err(anyhow::anyhow!(
+            "{} is not an address lookup table",
+            address
+        ));
+    }
+
+    let lookup_table = AddressLookupTable::deserialize(&account.data)
+        .map_err(|e| anyhow::anyhow!("Invalid address lookup table {}: {}", address, e))?;
+
+    Ok(AddressLookupTableAccount {
+        key: *address,
+        addresses: lookup_table.addresses.to_vec(),
+    })
+}
+
+/// The lookup table of the epoch accounts created by `authority` with `alt create`, `None` when
+/// there is none or it was deactivated
+pub async fn get_epoch_lookup_table(
+    handler: &CliHandler,
+    authority: &Pubkey,
+    epoch: u64,
+) -> Result<Option<AddressLookupTableAccount>> {
+    let client = handler.rpc_client();
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(epoch_lookup_table_filters(
+            &handler.ncn_program_id,
+            handler.ncn()?,
+            epoch,
+            authority,
+        )),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(handler.commitment),
+            ..RpcAccountInfoConfig::default()
+        },
+        with_context: Some(false),
+        sort_results: None,
+    };
+
+    let results = client
+        .get_program_accounts_with_config(&address_lookup_table::program::id(), config)
+        .await?;
+
+    // The most complete table of the active ones, should several have been created
+    let lookup_table = results
+        .iter()
+        .filter_map(|(address, account)| {
+            let lookup_table = AddressLookupTable::deserialize(&account.data).ok()?;
+            (lookup_table.meta.deactivation_slot == u64::MAX).then(|| AddressLookupTableAccount {
+                key: *address,
+                addresses: lookup_table.addresses.to_vec(),
+            })
+        })
+        .max_by_key(|lookup_table| lookup_table.addresses.len());
+
+    Ok(lookup_table)
+}
+
 pub async fn get_current_epoch(handler: &CliHandler) -> Result<u64> {
     let client = handler.rpc_client();
     let epoch = client.get_epoch_info().await?.epoch;
@@ -64,6 +146,13 @@ pub async fn get_current_slot(handler: &CliHandler) -> Result<u64> {
     Ok(slot)
 }
 
+/// The rent of the cluster, read from the rent sysvar
+pub async fn get_rent(handler: &CliHandler) -> Result<Rent> {
+    let client = handler.rpc_client();
+    let account = client.get_account(&sysvar::rent::id()).await?;
+    Ok(bincode::deserialize(&account.data)?)
+}
+
 pub async fn get_current_epoch_and_slot(handler: &CliHandler) -> Result<(u64, u64)> {
     let epoch = get_current_epoch(handler).await?;
     let slot = get_current_slot(handler).await?;
@@ -97,33 +186,21 @@ pub async fn get_guaranteed_epoch_and_slot(handler: &CliHandler) -> (u64, u64) {
 
 // ---------------------- NCN Program ----------------------
 pub async fn get_ncn_program_config(handler: &CliHandler) -> Result<NCNProgramConfig> {
-    let (address, _, _) =
-        NCNProgramConfig::find_program_address(&handler.ncn_program_id, handler.ncn()?);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = NCNProgramConfig::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_config(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+    )
+    .await
 }
 
 pub async fn get_vault_registry(handler: &CliHandler) -> Result<VaultRegistry> {
-    let (address, _, _) =
-        VaultRegistry::find_program_address(&handler.ncn_program_id, handler.ncn()?);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("VR Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = VaultRegistry::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_vault_registry(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+    )
+    .await
 }
 
 pub async fn get_or_create_vault_registry(handler: &CliHandler) -> Result<VaultRegistry> {
@@ -167,48 +244,67 @@ pub async fn get_or_create_vault_registry(handler: &CliHandler) -> Result<VaultR
 }
 
 pub async fn get_epoch_state(handler: &CliHandler, epoch: u64) -> Result<EpochState> {
-    let (address, _, _) =
-        EpochState::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = EpochState::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_epoch_state(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_weight_table(handler: &CliHandler, epoch: u64) -> Result<WeightTable> {
-    let (address, _, _) =
-        WeightTable::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = WeightTable::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_weight_table(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<EpochSnapshot> {
-    let (address, _, _) =
-        EpochSnapshot::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
+    fetch_epoch_snapshot(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
+}
 
-    let account = get_account(handler, &address).await?;
+pub async fn get_operator_metadata(
+    handler: &CliHandler,
+    operator: &Pubkey,
+) -> Result<OperatorMetadata> {
+    fetch_operator_metadata(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        operator,
+        handler.ncn()?,
+    )
+    .await
+}
 
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
+/// The keeper lease of the keeper instances signing with `authority`
+pub async fn get_keeper_lease(handler: &CliHandler, authority: &Pubkey) -> Result<KeeperLease> {
+    fetch_keeper_lease(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        authority,
+    )
+    .await
+}
 
-    let account = EpochSnapshot::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+/// The operator's published name followed by its address, only the address if it has no name
+pub async fn get_operator_display_name(handler: &CliHandler, operator: &Pubkey) -> String {
+    match get_operator_metadata(handler, operator).await {
+        Ok(operator_metadata) if !operator_metadata.name_str().is_empty() => {
+            format!("{} ({})", operator_metadata.name_str(), operator)
+        }
+        _ => operator.to_string(),
+    }
 }
 
 pub async fn get_operator_snapshot(
@@ -216,90 +312,63 @@ pub async fn get_operator_snapshot(
     operator: &Pubkey,
     epoch: u64,
 ) -> Result<OperatorSnapshot> {
-    let (address, _, _) = OperatorSnapshot::find_program_address(
+    fetch_operator_snapshot(
+        handler.rpc_client(),
         &handler.ncn_program_id,
         operator,
         handler.ncn()?,
         epoch,
-    );
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = OperatorSnapshot::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    )
+    .await
 }
 
 pub async fn get_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBox> {
-    let (address, _, _) =
-        BallotBox::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = BallotBox::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_ballot_box(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_consensus_result(handler: &CliHandler, epoch: u64) -> Result<ConsensusResult> {
-    let (address, _, _) =
-        ConsensusResult::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = ConsensusResult::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_consensus_result(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_account_payer(handler: &CliHandler) -> Result<Account> {
-    let (address, _, _) =
-        AccountPayer::find_program_address(&handler.ncn_program_id, handler.ncn()?);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    Ok(account)
+    fetch_account_payer(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+    )
+    .await
 }
 
 pub async fn get_epoch_marker(handler: &CliHandler, epoch: u64) -> Result<EpochMarker> {
-    let (address, _, _) =
-        EpochMarker::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = EpochMarker::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_epoch_marker(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_is_epoch_completed(handler: &CliHandler, epoch: u64) -> Result<bool> {
-    let (address, _, _) =
-        EpochMarker::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    Ok(account.is_some())
+    fetch_is_epoch_completed(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 // ---------------------- RESTAKING ----------------------
@@ -702,18 +771,13 @@ pub async fn get_all_tickets(handler: &CliHandler) -> Result<Vec<NcnTickets>> {
 }
 
 pub async fn get_ncn_reward_router(handler: &CliHandler, epoch: u64) -> Result<NCNRewardRouter> {
-    let (address, _, _) =
-        NCNRewardRouter::find_program_address(&handler.ncn_program_id, handler.ncn()?, epoch);
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = NCNRewardRouter::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    fetch_ncn_reward_router(
+        handler.rpc_client(),
+        &handler.ncn_program_id,
+        handler.ncn()?,
+        epoch,
+    )
+    .await
 }
 
 pub async fn get_ncn_reward_receiver(
@@ -738,22 +802,14 @@ pub async fn get_operator_vault_reward_router(
     operator: &Pubkey,
     epoch: u64,
 ) -> Result<OperatorVaultRewardRouter> {
-    let (address, _, _) = OperatorVaultRewardRouter::find_program_address(
+    fetch_operator_vault_reward_router(
+        handler.rpc_client(),
         &handler.ncn_program_id,
         operator,
         handler.ncn()?,
         epoch,
-    );
-
-    let account = get_account(handler, &address).await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    let account = OperatorVaultRewardRouter::try_from_slice_unchecked(account.data.as_slice())?;
-    Ok(*account)
+    )
+    .await
 }
 
 pub async fn get_operator_vault_reward_receiver(
@@ -779,19 +835,7 @@ pub async fn get_operator_vault_reward_receiver(
 }
 
 pub async fn get_receiver_rewards(handler: &CliHandler, address: &Pubkey) -> Result<u64> {
-    let account = get_account(handler, address).await?;
-
-    let rent = handler
-        .rpc_client()
-        .get_minimum_balance_for_rent_exemption(0)
-        .await?;
-
-    if account.is_none() {
-        return Err(anyhow::anyhow!("Account not found"));
-    }
-    let account = account.unwrap();
-
-    Ok(account.lamports - rent)
+    fetch_receiver_rewards(handler.rpc_client(), address).await
 }
 
 pub async fn get_ncn_reward_receiver_rewards(handler: &CliHandler, epoch: u64) -> Result<u64> {
@@ -816,8 +860,7 @@ pub async fn get_total_rewards_to_be_distributed(handler: &CliHandler, epoch: u6
         let winning_ballot_index = winning_ballot.index();
 
         ballot_box
-            .operator_votes()
-            .iter()
+            .iter_votes()
             .filter_map(|vote| {
                 if vote.ballot_index() == winning_ballot_index {
                     Some(*vote.operator())
@@ -1,60 +1,157 @@
 #![allow(clippy::integer_division)]
-use std::{collections::HashMap, mem::size_of, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    mem::size_of,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
 
 use crate::{
-    args::{Args, ProgramCommand},
+    args::{
+        AltCommand, Args, EpochAccountType, KeeperStage, ProgramCommand, ReportCommand,
+        TestEnvCommand,
+    },
+    audit::audit_consensus,
+    epoch_accounts::{collect_garbage, list_epoch_accounts, recover_reward_receivers},
+    export::export_epoch,
     getters::{
-        get_account_payer, get_all_operators_in_ncn, get_all_tickets, get_all_vaults,
-        get_all_vaults_in_ncn, get_ballot_box, get_consensus_result, get_current_slot,
-        get_epoch_snapshot, get_epoch_state, get_is_epoch_completed, get_ncn,
-        get_ncn_operator_state, get_ncn_program_config, get_ncn_reward_receiver,
-        get_ncn_reward_router, get_ncn_vault_ticket, get_operator_snapshot,
-        get_operator_vault_reward_router, get_total_epoch_rent_cost, get_vault_ncn_ticket,
-        get_vault_operator_delegation, get_vault_registry, get_weight_table,
+        get_account, get_account_payer, get_address_lookup_table, get_all_operators_in_ncn,
+        get_all_tickets, get_all_vaults, get_all_vaults_in_ncn, get_ballot_box,
+        get_consensus_result, get_current_slot, get_epoch_snapshot, get_epoch_state,
+        get_is_epoch_completed, get_keeper_lease, get_ncn, get_ncn_operator_state,
+        get_ncn_program_config, get_ncn_reward_receiver, get_ncn_reward_router,
+        get_ncn_vault_ticket, get_operator_display_name, get_operator_metadata,
+        get_operator_snapshot, get_operator_vault_reward_router, get_rent,
+        get_total_epoch_rent_cost, get_vault_ncn_ticket, get_vault_operator_delegation,
+        get_vault_registry, get_weight_table,
     },
     instructions::{
-        admin_create_config, admin_fund_account_payer, admin_register_st_mint, admin_set_new_admin,
-        admin_set_parameters, admin_set_tie_breaker, admin_set_weight, crank_close_epoch_accounts,
-        crank_distribute, crank_register_vaults, crank_snapshot, create_ballot_box,
+        admin_create_config, admin_fund_account_payer, admin_register_st_mint,
+        admin_set_admin_governance, admin_set_admin_multisig, admin_set_default_weight,
+        admin_set_fee_group, admin_set_new_admin, admin_set_parameters, admin_set_reward_mint,
+        admin_set_st_mint, admin_set_tie_breaker, admin_set_vault_metadata,
+        admin_set_vault_reward_recipient, admin_set_weight, admin_set_weights, apply_parameters,
+        copy_previous_epoch_weights, crank_close_epoch_accounts, crank_distribute_until_empty,
+        crank_register_vaults, crank_snapshot, create_ballot_box, create_epoch_accounts,
         create_epoch_snapshot, create_epoch_state, create_ncn_reward_router,
         create_operator_snapshot, create_operator_vault_reward_router, create_vault_registry,
         create_weight_table, distribute_operator_vault_rewards, full_vault_update,
-        operator_cast_vote, register_vault, route_ncn_rewards, route_operator_vault_rewards,
-        set_epoch_weights, snapshot_vault_operator_delegation, update_all_vaults_in_network,
+        mark_epoch_skipped, operator_cast_vote, operator_set_metadata, register_vault,
+        route_and_distribute_ncn_rewards, route_ncn_rewards, route_operator_vault_rewards,
+        send_signed_transaction, set_epoch_weights, set_weights_from_switchboard,
+        shrink_ncn_reward_router, snapshot_vault_operator_delegation, update_all_vaults_in_network,
+    },
+    journal::{report_history, HistoryFilter, Journal},
+    keeper::{
+        keeper_account_payer::AccountPayerTopUp,
+        keeper_alerts::{AlertWebhook, KeeperAlerts},
+        keeper_lease::KeeperLeader,
+        keeper_loop::startup_ncn_keeper,
+        keeper_schedule::StageSchedule,
+    },
+    lookup_tables::{create_epoch_lookup_table, extend_epoch_lookup_table},
+    operator::{
+        operator_loop::startup_operator_loop,
+        operator_prometheus::{serve_prometheus_metrics, OperatorPrometheusMetrics},
+        vote_server::{run_vote_server, PushedVotes},
+        vote_source::vote_source,
     },
-    keeper::keeper_loop::startup_ncn_keeper,
-    operator::operator_loop::startup_operator_loop,
+    rate_limit::RateLimitedSender,
+    report::{epoch_set_diff_report, get_epoch_set, report_participation},
+    retry::RetryPolicy,
+    rpc_failover::FailoverSender,
+    run_once::PassOutcome,
+    signer::{CliSigner, RemoteSigner},
+    simulate_epoch::{simulate_epoch, SimulatedTransaction},
+    stats::report_stats,
+    test_env::setup_test_env,
+    verify_config::verify_config,
+    vote_journal::VoteJournal,
+    watch::watch_epoch,
 };
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine};
+use futures::future::try_join_all;
 use log::info;
-use ncn_program_core::account_payer::AccountPayer;
+use ncn_program_core::{
+    account_payer::AccountPayer,
+    account_type::decode_any,
+    constants::{MAX_OPERATORS, MAX_VAULTS, MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE},
+    rent::EpochRentBudget,
+};
+use ncn_program_sdk::squads;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_client::{
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
-use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::RpcClientConfig, rpc_sender::RpcSender,
+};
 use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
     commitment_config::CommitmentConfig,
-    native_token::lamports_to_sol,
+    hash::Hash,
+    native_token::{lamports_to_sol, sol_to_lamports},
     pubkey::Pubkey,
     signature::{read_keypair_file, Keypair},
+    signer::Signer,
 };
 
 pub struct CliHandler {
     pub rpc_url: String,
     pub commitment: CommitmentConfig,
-    pub keypair: Option<Keypair>,
+    pub keypair: Option<Arc<CliSigner>>,
+    pub admin_approvers: Arc<Vec<Keypair>>,
+    pub squads_multisig: Option<Pubkey>,
+    pub squads_vault_index: u8,
+    pub as_proposal: bool,
+    /// Admin of the proposed instructions with `--as-proposal`
+    pub proposal_authority: Option<Pubkey>,
     pub restaking_program_id: Pubkey,
     pub vault_program_id: Pubkey,
     pub ncn_program_id: Pubkey,
     pub token_program_id: Pubkey,
-    pub ncn: Option<Pubkey>,
+    /// Set once by `test-env setup` for the NCN it creates when not passed with `--ncn`
+    pub ncn: OnceLock<Pubkey>,
     pub epoch: u64,
-    pub rpc_client: RpcClient,
-    pub retries: u64,
+    /// Shared with the handlers of the NCNs, so they send through the same rate limiter
+    pub rpc_client: Arc<RpcClient>,
+    pub retry_policy: RetryPolicy,
+    pub max_in_flight_transactions: usize,
     pub priority_fee_micro_lamports: u64,
+    /// Percent added to the simulated compute units, `None` to keep the limits of the instructions
+    pub compute_unit_margin: Option<u64>,
+    /// Lookup tables of the v0 transactions, fetched once at startup
+    pub address_lookup_tables: Vec<AddressLookupTableAccount>,
+    /// Lookup tables of the epoch accounts loaded by the batched cranks, `None` for the epochs
+    /// without one
+    pub epoch_lookup_tables: Mutex<HashMap<u64, Option<AddressLookupTableAccount>>>,
+    pub jito_block_engine_url: Option<String>,
+    pub jito_tip_lamports: u64,
+    pub dry_run: bool,
+    /// Transactions simulated so far with `--dry-run`
+    pub simulated_transactions: Mutex<Vec<SimulatedTransaction>>,
+    pub nonce_account: Option<Pubkey>,
+    pub nonce_hash: Option<Hash>,
+    pub sign_only: bool,
+    /// Set once a transaction is signed over the nonce with `--sign-only`
+    pub nonce_signed: AtomicBool,
+    /// Transactions landed so far, telling `--once` passes whether they did any work
+    pub transactions_sent: AtomicU64,
+    pub journal: Option<Arc<Journal>>,
+    /// File the snapshot crank persists its progress to, `None` to keep it in memory
+    pub snapshot_progress_file: Option<String>,
+    /// Votes cast from this host, refusing conflicting votes, shared with the handlers of the NCNs
+    pub vote_journal: Option<Arc<VoteJournal>>,
+    /// Handlers of the other NCNs `run-operator --ncns` votes for, sharing the signer, the RPC
+    /// client and the journals of this one
+    pub ncn_handlers: Vec<CliHandler>,
+    /// The subcommand run, recorded with the transactions in the journal
+    pub command_name: String,
     pub open_weather_api_key: Option<String>,
 }
 
@@ -65,14 +162,77 @@ impl CliHandler {
 
         let commitment = CommitmentConfig::from_str(&args.commitment)?;
 
-        let keypair = match &args.keypair_path {
-            Some(path) => Some(
+        let keypair = match (&args.keypair_path, &args.remote_signer_url) {
+            (Some(path), _) => Some(Arc::new(CliSigner::Keypair(
                 read_keypair_file(path)
                     .map_err(|e| anyhow!("Failed to read keypair file: {}", e))?,
-            ),
-            None => None,
+            ))),
+            (None, Some(url)) => {
+                let pubkey = args
+                    .remote_signer_pubkey
+                    .as_ref()
+                    .map(|pubkey| Pubkey::from_str(pubkey))
+                    .transpose()
+                    .map_err(|e| anyhow!("Error parsing remote signer pubkey: {}", e))?;
+
+                let remote_signer =
+                    RemoteSigner::connect(url, args.remote_signer_token.clone(), pubkey)
+                        .await
+                        .map_err(|e| anyhow!("Failed to connect to the remote signer: {}", e))?;
+                Some(Arc::new(CliSigner::Remote(remote_signer)))
+            }
+            (None, None) => None,
         };
 
+        let admin_approvers = args
+            .admin_approver_keypair_paths
+            .iter()
+            .map(|path| {
+                read_keypair_file(path)
+                    .map_err(|e| anyhow!("Failed to read admin approver keypair file: {}", e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let squads_multisig = args
+            .squads_multisig
+            .as_ref()
+            .map(|multisig| Pubkey::from_str(multisig))
+            .transpose()
+            .map_err(|e| anyhow!("Error parsing Squads multisig: {}", e))?;
+
+        let proposal_authority = args
+            .proposal_authority
+            .as_ref()
+            .map(|authority| Pubkey::from_str(authority))
+            .transpose()
+            .map_err(|e| anyhow!("Error parsing proposal authority: {}", e))?;
+
+        let address_lookup_table_addresses = args
+            .address_lookup_tables
+            .iter()
+            .map(|address| Pubkey::from_str(address))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Error parsing address lookup table: {}", e))?;
+
+        let nonce_account = args
+            .nonce_account
+            .as_ref()
+            .map(|nonce_account| Pubkey::from_str(nonce_account))
+            .transpose()
+            .map_err(|e| anyhow!("Error parsing nonce account: {}", e))?;
+
+        let nonce_hash = args
+            .nonce_hash
+            .as_ref()
+            .map(|nonce_hash| Hash::from_str(nonce_hash))
+            .transpose()
+            .map_err(|e| anyhow!("Error parsing nonce hash: {}", e))?;
+
+        // A recent blockhash expires before an offline signed transaction is sent
+        if args.sign_only && nonce_account.is_none() {
+            return Err(anyhow!("--sign-only requires a --nonce-account"));
+        }
+
         let restaking_program_id = Pubkey::from_str(&args.restaking_program_id)?;
 
         let vault_program_id = Pubkey::from_str(&args.vault_program_id)?;
@@ -87,23 +247,100 @@ impl CliHandler {
             .ncn
             .clone()
             .map(|id| Pubkey::from_str(&id))
-            .transpose()?;
+            .transpose()?
+            .map(OnceLock::from)
+            .unwrap_or_default();
+
+        // The fallback RPCs are limited on their own, each provider enforcing its own quota
+        let rpc_sender = |url: String| -> Box<dyn RpcSender + Send + Sync> {
+            Box::new(RateLimitedSender::new(
+                url,
+                args.rpc_requests_per_second,
+                args.rpc_burst,
+            ))
+        };
 
-        let rpc_client = RpcClient::new_with_commitment(rpc_url.clone(), commitment);
+        let rpc_client = if !args.fallback_rpc_urls.is_empty() {
+            let rpc_senders = std::iter::once(rpc_url.clone())
+                .chain(args.fallback_rpc_urls.iter().cloned())
+                .map(rpc_sender)
+                .collect();
+            RpcClient::new_sender(
+                FailoverSender::new(rpc_senders),
+                RpcClientConfig::with_commitment(commitment),
+            )
+        } else {
+            RpcClient::new_sender(
+                RateLimitedSender::new(
+                    rpc_url.clone(),
+                    args.rpc_requests_per_second,
+                    args.rpc_burst,
+                ),
+                RpcClientConfig::with_commitment(commitment),
+            )
+        };
 
         let mut handler = Self {
             rpc_url,
             commitment,
             keypair,
+            admin_approvers: Arc::new(admin_approvers),
+            squads_multisig,
+            squads_vault_index: args.squads_vault_index,
+            as_proposal: args.as_proposal,
+            proposal_authority,
             restaking_program_id,
             vault_program_id,
             ncn_program_id,
             token_program_id,
             ncn,
             epoch: u64::MAX,
-            rpc_client,
-            retries: args.transaction_retries,
+            rpc_client: Arc::new(rpc_client),
+            retry_policy: RetryPolicy {
+                max_retries: args.transaction_retries,
+                base_delay_ms: args.retry_base_delay_ms,
+                backoff_factor: args.retry_backoff_factor,
+                max_delay_ms: args.retry_max_delay_ms,
+                retry_program_errors: args.retry_program_errors,
+                priority_fee_escalation_factor: args.priority_fee_escalation_factor,
+                max_priority_fee_micro_lamports: args.max_priority_fee_micro_lamports,
+                compute_unit_escalation_factor: args.compute_unit_escalation_factor,
+                max_compute_unit_limit: args.max_compute_unit_limit,
+            },
+            max_in_flight_transactions: args.max_in_flight_transactions.max(1),
             priority_fee_micro_lamports: args.priority_fee_micro_lamports,
+            compute_unit_margin: (!args.skip_compute_unit_estimate)
+                .then_some(args.compute_unit_margin),
+            address_lookup_tables: vec![],
+            epoch_lookup_tables: Mutex::new(HashMap::new()),
+            jito_block_engine_url: args
+                .jito_block_engine_url
+                .clone()
+                .filter(|url| !url.is_empty()),
+            jito_tip_lamports: args.jito_tip_lamports,
+            // Simulating the epoch never sends anything
+            dry_run: args.dry_run || matches!(args.command, ProgramCommand::SimulateEpoch { .. }),
+            simulated_transactions: Mutex::new(vec![]),
+            nonce_account,
+            nonce_hash,
+            sign_only: args.sign_only,
+            nonce_signed: AtomicBool::new(false),
+            transactions_sent: AtomicU64::new(0),
+            journal: args
+                .journal
+                .as_deref()
+                .map(Journal::open)
+                .transpose()?
+                .map(Arc::new),
+            snapshot_progress_file: args.snapshot_progress_file.clone(),
+            vote_journal: args
+                .vote_journal
+                .as_deref()
+                .map(VoteJournal::open)
+                .transpose()?
+                .map(Arc::new),
+            ncn_handlers: vec![],
+            command_name: args.command_name.clone().unwrap_or_default(),
             open_weather_api_key,
         };
 
@@ -117,10 +354,79 @@ impl CliHandler {
             }
         };
 
+        for address in address_lookup_table_addresses.iter() {
+            let lookup_table = get_address_lookup_table(&handler, address).await?;
+            handler.address_lookup_tables.push(lookup_table);
+        }
+
+        if let ProgramCommand::RunOperator { ncns, .. } = &args.command {
+            for ncn in ncns.iter() {
+                let ncn_handler = handler.ncn_handler(ncn)?;
+                handler.ncn_handlers.push(ncn_handler);
+            }
+        }
+
         Ok(handler)
     }
 
-    pub const fn rpc_client(&self) -> &RpcClient {
+    /// The handler of an NCN of `run-operator --ncns`, given as `<NCN>` or
+    /// `<NCN>:<NCN_PROGRAM_ID>`. Only the NCN and its program differ from this handler, the
+    /// signer, the RPC client and its rate limiter and the journals are shared.
+    fn ncn_handler(&self, ncn: &str) -> Result<Self> {
+        let (ncn, ncn_program_id) = match ncn.split_once(':') {
+            Some((ncn, ncn_program_id)) => (ncn, Some(ncn_program_id)),
+            None => (ncn, None),
+        };
+        let ncn = Pubkey::from_str(ncn).map_err(|e| anyhow!("Error parsing NCN {}: {}", ncn, e))?;
+        let ncn_program_id = ncn_program_id
+            .map(|ncn_program_id| {
+                Pubkey::from_str(ncn_program_id)
+                    .map_err(|e| anyhow!("Error parsing NCN program ID {}: {}", ncn_program_id, e))
+            })
+            .transpose()?
+            .unwrap_or(self.ncn_program_id);
+
+        Ok(Self {
+            rpc_url: self.rpc_url.clone(),
+            commitment: self.commitment,
+            keypair: self.keypair.clone(),
+            admin_approvers: self.admin_approvers.clone(),
+            squads_multisig: self.squads_multisig,
+            squads_vault_index: self.squads_vault_index,
+            as_proposal: self.as_proposal,
+            proposal_authority: self.proposal_authority,
+            restaking_program_id: self.restaking_program_id,
+            vault_program_id: self.vault_program_id,
+            ncn_program_id,
+            token_program_id: self.token_program_id,
+            ncn: OnceLock::from(ncn),
+            epoch: self.epoch,
+            rpc_client: self.rpc_client.clone(),
+            retry_policy: self.retry_policy,
+            max_in_flight_transactions: self.max_in_flight_transactions,
+            priority_fee_micro_lamports: self.priority_fee_micro_lamports,
+            compute_unit_margin: self.compute_unit_margin,
+            address_lookup_tables: self.address_lookup_tables.clone(),
+            epoch_lookup_tables: Mutex::new(HashMap::new()),
+            jito_block_engine_url: self.jito_block_engine_url.clone(),
+            jito_tip_lamports: self.jito_tip_lamports,
+            dry_run: self.dry_run,
+            simulated_transactions: Mutex::new(vec![]),
+            nonce_account: self.nonce_account,
+            nonce_hash: self.nonce_hash,
+            sign_only: self.sign_only,
+            nonce_signed: AtomicBool::new(false),
+            transactions_sent: AtomicU64::new(0),
+            journal: self.journal.clone(),
+            snapshot_progress_file: self.snapshot_progress_file.clone(),
+            vote_journal: self.vote_journal.clone(),
+            ncn_handlers: vec![],
+            command_name: self.command_name.clone(),
+            open_weather_api_key: self.open_weather_api_key.clone(),
+        })
+    }
+
+    pub fn rpc_client(&self) -> &RpcClient {
         &self.rpc_client
     }
 
@@ -160,12 +466,35 @@ impl CliHandler {
         })
     }
 
-    pub fn keypair(&self) -> Result<&Keypair> {
-        self.keypair.as_ref().ok_or_else(|| anyhow!("No keypair"))
+    /// The signer paying for and signing the transactions, a keypair or a remote signer
+    pub fn keypair(&self) -> Result<&CliSigner> {
+        self.keypair.as_deref().ok_or_else(|| anyhow!("No keypair"))
+    }
+
+    /// The signer of admin instructions, the proposal authority or the Squads vault when the NCN
+    /// admin is a governance or a Squads multisig
+    pub fn admin(&self) -> Result<Pubkey> {
+        if let Some(proposal_authority) = self.proposal_authority {
+            return Ok(proposal_authority);
+        }
+
+        match self.squads_multisig {
+            Some(multisig) => Ok(squads::vault_address(&multisig, self.squads_vault_index)),
+            None => Ok(self.keypair()?.pubkey()),
+        }
+    }
+
+    /// The lookup tables of `--address-lookup-tables` and of the epochs loaded so far
+    pub fn lookup_tables(&self) -> Vec<AddressLookupTableAccount> {
+        let mut lookup_tables = self.address_lookup_tables.clone();
+        if let Ok(epoch_lookup_tables) = self.epoch_lookup_tables.lock() {
+            lookup_tables.extend(epoch_lookup_tables.values().flatten().cloned());
+        }
+        lookup_tables
     }
 
     pub fn ncn(&self) -> Result<&Pubkey> {
-        self.ncn.as_ref().ok_or_else(|| anyhow!("No NCN address"))
+        self.ncn.get().ok_or_else(|| anyhow!("No NCN address"))
     }
 
     #[allow(clippy::large_stack_frames)]
@@ -176,29 +505,217 @@ impl CliHandler {
             ProgramCommand::RunKeeper {
                 loop_timeout_ms,
                 error_timeout_ms,
-            } => startup_ncn_keeper(self, loop_timeout_ms, error_timeout_ms).await,
+                loop_interval_ms,
+                stages,
+                skip_stages,
+                stage_min_slots,
+                register_st_mint_weight,
+                websocket_url,
+                state_file,
+                account_payer_min_balance,
+                account_payer_top_up,
+                alert_webhooks,
+                alert_failure_threshold,
+                lease_slots,
+                lease_holder,
+                once,
+            } => {
+                let stages: Vec<KeeperStage> = stages
+                    .into_iter()
+                    .filter(|stage| !skip_stages.contains(stage))
+                    .collect();
+                let stage_schedule = StageSchedule::parse(&stage_min_slots)?;
+                let account_payer_top_up =
+                    account_payer_min_balance.map(|min_balance| AccountPayerTopUp {
+                        min_balance: sol_to_lamports(min_balance),
+                        amount: sol_to_lamports(account_payer_top_up),
+                    });
+                let alert_webhooks = alert_webhooks
+                    .iter()
+                    .filter(|webhook| !webhook.is_empty())
+                    .map(|webhook| webhook.parse())
+                    .collect::<Result<Vec<AlertWebhook>>>()?;
+                let alerts =
+                    KeeperAlerts::new(alert_webhooks, alert_failure_threshold, *self.ncn()?);
+                let leader = match lease_slots {
+                    Some(lease_slots) => {
+                        let holder = match lease_holder.filter(|holder| !holder.is_empty()) {
+                            Some(holder) => Pubkey::from_str(&holder)
+                                .map_err(|e| anyhow!("Error parsing lease holder: {}", e))?,
+                            None => Keypair::new().pubkey(),
+                        };
+                        Some(KeeperLeader::new(holder, lease_slots))
+                    }
+                    None => None,
+                };
+                startup_ncn_keeper(
+                    self,
+                    loop_timeout_ms,
+                    error_timeout_ms,
+                    loop_interval_ms,
+                    &stages,
+                    &stage_schedule,
+                    register_st_mint_weight,
+                    websocket_url.filter(|url| !url.is_empty()),
+                    state_file.filter(|path| !path.is_empty()),
+                    account_payer_top_up,
+                    alerts,
+                    leader,
+                    once,
+                )
+                .await?
+                .exit(self.transactions_sent.load(Ordering::Relaxed))
+            }
 
             // Operator Keeper
             ProgramCommand::RunOperator {
                 loop_timeout_ms,
                 error_timeout_ms,
                 operator,
+                once,
+                vote_source: vote_source_kind,
+                vote_weather_status,
+                vote_file,
+                vote_url,
+                vote_command,
+                vote_command_timeout_ms,
+                vote_server_address,
+                vote_server_token,
+                prometheus_address,
+                state_file,
+                ncns: _,
             } => {
                 let operator = Pubkey::from_str(&operator)
                     .map_err(|e| anyhow!("Error parsing operator: {}", e))?;
-                startup_operator_loop(self, loop_timeout_ms, error_timeout_ms, operator).await
+                let state_file = state_file.filter(|path| !path.is_empty());
+                let vote_server_settings = match vote_server_address {
+                    Some(address) => {
+                        let token = vote_server_token.ok_or_else(|| {
+                            anyhow!("The vote server needs a --vote-server-token")
+                        })?;
+                        Some((address, token))
+                    }
+                    None => None,
+                };
+
+                // The NCN of --ncn then the ones of --ncns, each voted for by its own operator loop
+                // with its own vote source, pushed votes, metrics and state file
+                let ncn_handlers = std::iter::once(self)
+                    .chain(self.ncn_handlers.iter())
+                    .collect::<Vec<_>>();
+                let mut operator_loops = Vec::with_capacity(ncn_handlers.len());
+                for (index, handler) in ncn_handlers.iter().enumerate() {
+                    let ncn = *handler.ncn()?;
+                    let pushed_votes = vote_server_settings
+                        .as_ref()
+                        .map(|_| PushedVotes::default());
+                    let vote_source = vote_source(
+                        handler,
+                        vote_source_kind,
+                        vote_weather_status,
+                        vote_file.clone(),
+                        vote_url.clone(),
+                        vote_command.clone(),
+                        vote_command_timeout_ms,
+                        pushed_votes.as_ref(),
+                    )?;
+                    let prometheus_metrics =
+                        Arc::new(OperatorPrometheusMetrics::new(&ncn, &operator));
+                    // The state file of --ncn keeps its name, the others are suffixed with their NCN
+                    let state_file = match index {
+                        0 => state_file.clone(),
+                        _ => state_file.as_ref().map(|path| format!("{}.{}", path, ncn)),
+                    };
+
+                    operator_loops.push((
+                        *handler,
+                        vote_source,
+                        pushed_votes,
+                        prometheus_metrics,
+                        state_file,
+                    ));
+                }
+
+                // The servers are shared by the operator loops, run along them and stop with them
+                let operator_loop = try_join_all(operator_loops.iter().map(
+                    |(handler, vote_source, _, prometheus_metrics, state_file)| {
+                        startup_operator_loop(
+                            handler,
+                            loop_timeout_ms,
+                            error_timeout_ms,
+                            operator,
+                            vote_source.as_ref(),
+                            prometheus_metrics,
+                            state_file.clone(),
+                            once,
+                        )
+                    },
+                ));
+                let vote_server = async {
+                    match vote_server_settings {
+                        Some((address, token)) => {
+                            let voters = operator_loops
+                                .iter()
+                                .filter_map(|(handler, _, pushed_votes, _, _)| {
+                                    pushed_votes
+                                        .clone()
+                                        .map(|pushed_votes| (*handler, pushed_votes))
+                                })
+                                .collect();
+                            run_vote_server(voters, operator, &address, token).await
+                        }
+                        None => std::future::pending().await,
+                    }
+                };
+                let prometheus_server = async {
+                    match prometheus_address {
+                        Some(address) => {
+                            let prometheus_metrics = operator_loops
+                                .iter()
+                                .map(|(_, _, _, prometheus_metrics, _)| prometheus_metrics.clone())
+                                .collect();
+                            serve_prometheus_metrics(&address, prometheus_metrics).await
+                        }
+                        None => std::future::pending().await,
+                    }
+                };
+
+                let outcome = tokio::select! {
+                    outcomes = operator_loop => PassOutcome::combine(outcomes?),
+                    result = vote_server => return result,
+                    result = prometheus_server => return result,
+                };
+                outcome.exit(
+                    ncn_handlers
+                        .iter()
+                        .map(|handler| handler.transactions_sent.load(Ordering::Relaxed))
+                        .sum(),
+                )
             }
             // Cranks
-            ProgramCommand::CrankRegisterVaults {} => crank_register_vaults(self).await,
+            ProgramCommand::CrankRegisterVaults {
+                register_st_mint_weight,
+            } => crank_register_vaults(self, register_st_mint_weight).await,
             ProgramCommand::CrankUpdateAllVaults {} => update_all_vaults_in_network(self).await,
-            ProgramCommand::CrankDistribute {} => crank_distribute(self, self.epoch).await,
+            ProgramCommand::CrankDistribute { max_passes } => {
+                crank_distribute_until_empty(self, self.epoch, max_passes).await
+            }
 
-            ProgramCommand::CrankSnapshot {} => crank_snapshot(self, self.epoch).await,
-            ProgramCommand::CrankCloseEpochAccounts {} => {
-                crank_close_epoch_accounts(self, self.epoch).await
+            ProgramCommand::CrankSnapshot {} => crank_snapshot(self, self.epoch, &HashSet::new())
+                .await
+                .map(|_| ()),
+            ProgramCommand::CrankCloseEpochAccounts { only, keep } => {
+                let account_types = EpochAccountType::selected(only, keep);
+                crank_close_epoch_accounts(self, self.epoch, &account_types).await
             }
 
             ProgramCommand::SetEpochWeights {} => set_epoch_weights(self, self.epoch).await,
+            ProgramCommand::SetWeightsFromSwitchboard {} => {
+                set_weights_from_switchboard(self, self.epoch).await
+            }
+            ProgramCommand::CopyPreviousEpochWeights {} => {
+                copy_previous_epoch_weights(self, self.epoch).await
+            }
 
             // Admin
             ProgramCommand::AdminCreateConfig {
@@ -237,11 +754,45 @@ impl CliHandler {
                     Pubkey::from_str(&vault).map_err(|e| anyhow!("Error parsing vault: {}", e))?;
                 admin_register_st_mint(self, &vault, weight).await
             }
+            ProgramCommand::AdminSetStMint {
+                vault,
+                weight,
+                switchboard_feed,
+            } => {
+                let vault =
+                    Pubkey::from_str(&vault).map_err(|e| anyhow!("Error parsing vault: {}", e))?;
+                let switchboard_feed = switchboard_feed
+                    .map(|switchboard_feed| Pubkey::from_str(&switchboard_feed))
+                    .transpose()
+                    .map_err(|e| anyhow!("Error parsing switchboard feed: {}", e))?;
+                admin_set_st_mint(self, &vault, weight, switchboard_feed).await
+            }
+            ProgramCommand::AdminSetDefaultWeight { default_weight } => {
+                admin_set_default_weight(self, default_weight).await
+            }
             ProgramCommand::AdminSetWeight { vault, weight } => {
                 let vault =
                     Pubkey::from_str(&vault).map_err(|e| anyhow!("Error parsing vault: {}", e))?;
                 admin_set_weight(self, &vault, self.epoch, weight).await
             }
+            ProgramCommand::AdminSetWeights { vaults, weights } => {
+                if vaults.len() != weights.len() {
+                    return Err(anyhow!(
+                        "Expected one weight per vault, got {} vaults and {} weights",
+                        vaults.len(),
+                        weights.len()
+                    ));
+                }
+
+                let vaults = vaults
+                    .iter()
+                    .map(|vault| {
+                        Pubkey::from_str(vault).map_err(|e| anyhow!("Error parsing vault: {}", e))
+                    })
+                    .collect::<Result<Vec<Pubkey>>>()?;
+                let vault_weights: Vec<(Pubkey, u128)> = vaults.into_iter().zip(weights).collect();
+                admin_set_weights(self, &vault_weights, self.epoch).await
+            }
             ProgramCommand::AdminSetTieBreaker { weather_status } => {
                 admin_set_tie_breaker(self, self.epoch, weather_status).await
             }
@@ -250,6 +801,14 @@ impl CliHandler {
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
                 starting_valid_epoch,
+                cranker_fee_bps,
+                timeliness_bonus_slots,
+                timeliness_bonus_bps,
+                weight_decay_epochs,
+                weight_decay_bps,
+                max_vault_stake_weight_bps,
+                permissionless_vault_registration,
+                parameters_timelock_slots,
             } => {
                 admin_set_parameters(
                     self,
@@ -257,14 +816,34 @@ impl CliHandler {
                     epochs_after_consensus_before_close,
                     valid_slots_after_consensus,
                     starting_valid_epoch,
+                    cranker_fee_bps,
+                    timeliness_bonus_slots,
+                    timeliness_bonus_bps,
+                    weight_decay_epochs,
+                    weight_decay_bps,
+                    max_vault_stake_weight_bps,
+                    permissionless_vault_registration,
+                    parameters_timelock_slots,
                 )
                 .await?;
                 let config = get_ncn_program_config(self).await?;
-                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\n",
+                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\ncranker_fee_bps: {}\ntimeliness_bonus_slots: {}\ntimeliness_bonus_bps: {}\nweight_decay_epochs: {}\nweight_decay_bps: {}\nmax_vault_stake_weight_bps: {}\npermissionless_vault_registration: {}\nparameters_timelock_slots: {}\npending_parameters: {:?}\n",
                     config.epochs_before_stall(),
                     config.epochs_after_consensus_before_close(),
                     config.valid_slots_after_consensus(),
-                    config.starting_valid_epoch()
+                    config.starting_valid_epoch(),
+                    config.cranker_fee_bps(),
+                    config.timeliness_bonus_slots(),
+                    config.timeliness_bonus_bps(),
+                    config.weight_decay_epochs(),
+                    config.weight_decay_bps(),
+                    config.max_vault_stake_weight_bps(),
+                    config.permissionless_vault_registration(),
+                    config.parameters_timelock_slots(),
+                    config
+                        .pending_parameters()
+                        .is_pending()
+                        .then(|| config.pending_parameters().changes())
                 );
 
                 Ok(())
@@ -277,6 +856,64 @@ impl CliHandler {
                     .map_err(|e| anyhow!("Error parsing new admin: {}", e))?;
                 admin_set_new_admin(self, &new_admin, set_tie_breaker_admin).await
             }
+            ProgramCommand::AdminSetAdminMultisig {
+                approvers,
+                threshold,
+            } => {
+                let approvers = approvers
+                    .iter()
+                    .map(|approver| {
+                        Pubkey::from_str(approver)
+                            .map_err(|e| anyhow!("Error parsing approver: {}", e))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                admin_set_admin_multisig(self, &approvers, threshold).await
+            }
+            ProgramCommand::AdminSetAdminGovernance {
+                governance_program,
+                realm,
+            } => {
+                let (governance_program, realm) = match realm {
+                    Some(realm) => (
+                        Pubkey::from_str(&governance_program)
+                            .map_err(|e| anyhow!("Error parsing governance program: {}", e))?,
+                        Pubkey::from_str(&realm)
+                            .map_err(|e| anyhow!("Error parsing realm: {}", e))?,
+                    ),
+                    None => (Pubkey::default(), Pubkey::default()),
+                };
+                admin_set_admin_governance(self, &governance_program, &realm).await
+            }
+            ProgramCommand::AdminSetVaultRewardRecipient {
+                vault,
+                reward_recipient,
+            } => {
+                let vault =
+                    Pubkey::from_str(&vault).map_err(|e| anyhow!("Error parsing vault: {}", e))?;
+                let reward_recipient = Pubkey::from_str(&reward_recipient)
+                    .map_err(|e| anyhow!("Error parsing reward recipient: {}", e))?;
+                admin_set_vault_reward_recipient(self, &vault, &reward_recipient).await
+            }
+            ProgramCommand::AdminSetVaultMetadata { vault, name, uri } => {
+                let vault =
+                    Pubkey::from_str(&vault).map_err(|e| anyhow!("Error parsing vault: {}", e))?;
+                admin_set_vault_metadata(self, &vault, &name, uri.as_deref()).await
+            }
+            ProgramCommand::AdminSetRewardMint { index, mint } => {
+                let mint =
+                    Pubkey::from_str(&mint).map_err(|e| anyhow!("Error parsing mint: {}", e))?;
+                admin_set_reward_mint(self, index, &mint).await
+            }
+            ProgramCommand::AdminSetFeeGroup {
+                group,
+                name,
+                wallet,
+                fee_bps,
+            } => {
+                let wallet = Pubkey::from_str(&wallet)
+                    .map_err(|e| anyhow!("Error parsing wallet: {}", e))?;
+                admin_set_fee_group(self, group, &name, &wallet, fee_bps).await
+            }
             ProgramCommand::AdminFundAccountPayer { amount_in_sol } => {
                 admin_fund_account_payer(self, amount_in_sol).await
             }
@@ -290,8 +927,14 @@ impl CliHandler {
                 register_vault(self, &vault).await
             }
 
+            ProgramCommand::ApplyParameters {} => apply_parameters(self).await,
+
             ProgramCommand::CreateEpochState {} => create_epoch_state(self, self.epoch).await,
 
+            ProgramCommand::CreateEpochAccounts {} => create_epoch_accounts(self, self.epoch).await,
+
+            ProgramCommand::MarkEpochSkipped {} => mark_epoch_skipped(self, self.epoch).await,
+
             ProgramCommand::CreateWeightTable {} => create_weight_table(self, self.epoch).await,
 
             ProgramCommand::CreateEpochSnapshot {} => create_epoch_snapshot(self, self.epoch).await,
@@ -318,6 +961,24 @@ impl CliHandler {
 
                 operator_cast_vote(self, &operator, self.epoch, weather_status).await
             }
+            ProgramCommand::OperatorSetMetadata {
+                operator,
+                name,
+                website,
+                contact,
+            } => {
+                let operator = Pubkey::from_str(&operator)
+                    .map_err(|e| anyhow!("Error parsing operator: {}", e))?;
+
+                operator_set_metadata(
+                    self,
+                    &operator,
+                    &name,
+                    website.as_deref(),
+                    contact.as_deref(),
+                )
+                .await
+            }
 
             // Getters
             ProgramCommand::GetNcn {} => {
@@ -448,6 +1109,23 @@ impl CliHandler {
                 info!("{}", operator_snapshot);
                 Ok(())
             }
+            ProgramCommand::GetOperatorMetadata { operator } => {
+                let operator = Pubkey::from_str(&operator)
+                    .map_err(|e| anyhow!("Error parsing operator: {}", e))?;
+                let operator_metadata = get_operator_metadata(self, &operator).await?;
+                info!("{}", operator_metadata);
+                Ok(())
+            }
+            ProgramCommand::GetKeeperLease { authority } => {
+                let authority = match authority {
+                    Some(authority) => Pubkey::from_str(&authority)
+                        .map_err(|e| anyhow!("Error parsing authority: {}", e))?,
+                    None => self.keypair()?.pubkey(),
+                };
+                let keeper_lease = get_keeper_lease(self, &authority).await?;
+                info!("{}", keeper_lease);
+                Ok(())
+            }
             ProgramCommand::GetBallotBox {} => {
                 let ballot_box = get_ballot_box(self, self.epoch).await?;
                 info!("{}", ballot_box);
@@ -472,6 +1150,65 @@ impl CliHandler {
                 );
                 Ok(())
             }
+            ProgramCommand::EstimateRent {
+                operators,
+                vaults,
+                open_epochs,
+            } => {
+                if operators > MAX_OPERATORS as u64 || vaults > MAX_VAULTS as u64 {
+                    return Err(anyhow!(
+                        "The NCN holds at most {} operators and {} vaults",
+                        MAX_OPERATORS,
+                        MAX_VAULTS
+                    ));
+                }
+
+                // Epoch accounts are sized for the maximum vaults, their count does not change
+                // the rent
+                let rent = get_rent(self).await?;
+                let budget = EpochRentBudget::new(&rent, operators)
+                    .map_err(|e| anyhow!("Could not estimate the epoch rent: {:?}", e))?;
+                let total = budget
+                    .total()
+                    .map_err(|e| anyhow!("Could not estimate the epoch rent: {:?}", e))?;
+                let retained = budget
+                    .retained()
+                    .map_err(|e| anyhow!("Could not estimate the epoch rent: {:?}", e))?;
+
+                // Before launch there is no config to read the close delay from
+                let open_epochs = match open_epochs {
+                    Some(open_epochs) => open_epochs,
+                    None => get_ncn_program_config(self)
+                        .await
+                        .map_or(MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE + 1, |config| {
+                            config.epochs_after_consensus_before_close() + 1
+                        }),
+                }
+                .max(1);
+                let recommended = total.saturating_mul(open_epochs);
+
+                info!(
+                    "\n\n--- Estimated Epoch Rent for {} operators and {} vaults ---\nEpoch State: {}\nWeight Table: {}\nEpoch Snapshot: {}\nOperator Snapshots: {}\nBallot Box: {}\nConsensus Result: {}\nNCN Reward Router: {}\nNCN Reward Receiver: {}\nOperator Vault Reward Routers: {}\nOperator Vault Reward Receivers: {}\nEpoch Marker: {}\nTotal: {}\nKept After Close: {}\nRecommended Account Payer Balance: {} ({} open epochs)\n",
+                    operators,
+                    vaults,
+                    lamports_to_sol(budget.epoch_state),
+                    lamports_to_sol(budget.weight_table),
+                    lamports_to_sol(budget.epoch_snapshot),
+                    lamports_to_sol(budget.operator_snapshots),
+                    lamports_to_sol(budget.ballot_box),
+                    lamports_to_sol(budget.consensus_result),
+                    lamports_to_sol(budget.ncn_reward_router),
+                    lamports_to_sol(budget.ncn_reward_receiver),
+                    lamports_to_sol(budget.operator_vault_reward_routers),
+                    lamports_to_sol(budget.operator_vault_reward_receivers),
+                    lamports_to_sol(budget.epoch_marker),
+                    lamports_to_sol(total),
+                    lamports_to_sol(retained),
+                    lamports_to_sol(recommended),
+                    open_epochs
+                );
+                Ok(())
+            }
             ProgramCommand::GetConsensusResult {} => {
                 let result = get_consensus_result(self, self.epoch).await?;
 
@@ -505,7 +1242,7 @@ impl CliHandler {
                 for (operator, stake_weight) in operator_stakes.iter() {
                     println!(
                         "Operator: {}, Stake Weight: {}.{:02}%",
-                        operator,
+                        get_operator_display_name(self, operator).await,
                         stake_weight * 10000 / epoch_snapshot.stake_weights().stake_weight() / 100,
                         stake_weight * 10000 / epoch_snapshot.stake_weights().stake_weight() % 100
                     );
@@ -662,6 +1399,17 @@ impl CliHandler {
 
             ProgramCommand::RouteNCNRewards {} => route_ncn_rewards(self, self.epoch).await,
 
+            ProgramCommand::RouteAndDistributeNCNRewards { operators } => {
+                let operators = operators
+                    .iter()
+                    .map(|operator| {
+                        Pubkey::from_str(operator)
+                            .map_err(|e| anyhow!("Error parsing operator: {}", e))
+                    })
+                    .collect::<Result<Vec<Pubkey>>>()?;
+                route_and_distribute_ncn_rewards(self, &operators, self.epoch).await
+            }
+
             ProgramCommand::RouteOperatorVaultRewards { operator } => {
                 let operator = Pubkey::from_str(&operator)
                     .map_err(|e| anyhow!("Error parsing operator: {}", e))?;
@@ -674,6 +1422,10 @@ impl CliHandler {
                 distribute_operator_vault_rewards(self, &operator, self.epoch).await
             }
 
+            ProgramCommand::ShrinkNCNRewardRouter {} => {
+                shrink_ncn_reward_router(self, self.epoch).await
+            }
+
             ProgramCommand::GetNCNRewardRouter {} => {
                 let ncn_reward_router = get_ncn_reward_router(self, self.epoch).await?;
                 info!("{}", ncn_reward_router);
@@ -698,7 +1450,11 @@ impl CliHandler {
                 let operators = get_all_operators_in_ncn(self).await?;
                 for operator in operators {
                     match get_operator_vault_reward_router(self, &operator, self.epoch).await {
-                        Ok(router) => info!("Operator: {}, Router: {}", operator, router),
+                        Ok(router) => info!(
+                            "Operator: {}, Router: {}",
+                            get_operator_display_name(self, &operator).await,
+                            router
+                        ),
                         Err(e) => info!(
                             "Failed to get operator vault reward router for {:?}: {:?}",
                             operator, e
@@ -707,6 +1463,109 @@ impl CliHandler {
                 }
                 Ok(())
             }
+            ProgramCommand::GetAccount { address } => {
+                let address = Pubkey::from_str(&address)
+                    .map_err(|e| anyhow!("Error parsing address: {}", e))?;
+                let account = get_account(self, &address)
+                    .await?
+                    .ok_or_else(|| anyhow!("Account {} not found", address))?;
+
+                if account.owner.ne(&self.ncn_program_id) {
+                    return Err(anyhow!(
+                        "Account {} is not owned by the NCN program",
+                        address
+                    ));
+                }
+
+                let decoded = decode_any(&account.data)
+                    .map_err(|e| anyhow!("Could not decode account {}: {}", address, e))?;
+                info!(
+                    "\n\n--- {} {} ---\n{}",
+                    decoded.account_type().name(),
+                    address,
+                    decoded
+                );
+                Ok(())
+            }
+            ProgramCommand::ListEpochAccounts {} => list_epoch_accounts(self, self.epoch).await,
+            ProgramCommand::Gc {
+                from_epoch,
+                to_epoch,
+            } => collect_garbage(self, from_epoch, to_epoch).await,
+            ProgramCommand::RecoverRewardReceivers {
+                from_epoch,
+                to_epoch,
+            } => recover_reward_receivers(self, from_epoch, to_epoch).await,
+            ProgramCommand::DiffEpochs {
+                from_epoch,
+                to_epoch,
+            } => {
+                let operators = get_all_operators_in_ncn(self).await?;
+                let before = get_epoch_set(self, &operators, from_epoch).await?;
+                let after = get_epoch_set(self, &operators, to_epoch).await?;
+
+                info!("{}", epoch_set_diff_report(&before.diff(&after)));
+                Ok(())
+            }
+            ProgramCommand::VerifyConfig { expected } => verify_config(self, &expected).await,
+            ProgramCommand::Audit {} => audit_consensus(self, self.epoch).await,
+            ProgramCommand::Report { report } => match report {
+                ReportCommand::Participation { last, format } => {
+                    report_participation(self, self.epoch, last, format).await
+                }
+            },
+            ProgramCommand::Stats { last, format } => {
+                report_stats(self, self.epoch, last, format).await
+            }
+            ProgramCommand::Watch { refresh_ms } => watch_epoch(self, self.epoch, refresh_ms).await,
+            ProgramCommand::History {
+                limit,
+                command,
+                failed,
+                format,
+            } => report_history(
+                self,
+                &HistoryFilter {
+                    limit,
+                    command,
+                    failed_only: failed,
+                },
+                format,
+            ),
+            ProgramCommand::Export { output_dir } => {
+                export_epoch(self, self.epoch, &output_dir).await
+            }
+            ProgramCommand::SimulateEpoch { rewards } => {
+                simulate_epoch(self, self.epoch, rewards).await
+            }
+            ProgramCommand::Alt { alt } => match alt {
+                AltCommand::Create => create_epoch_lookup_table(self, self.epoch).await,
+                AltCommand::Extend => extend_epoch_lookup_table(self, self.epoch).await,
+            },
+            ProgramCommand::TestEnv { test_env } => match test_env {
+                TestEnvCommand::Setup {
+                    operators,
+                    vaults,
+                    delegation,
+                    st_mint_weight,
+                    ncn_fee_bps,
+                    account_payer_sol,
+                } => {
+                    setup_test_env(
+                        self,
+                        operators,
+                        vaults,
+                        delegation,
+                        st_mint_weight,
+                        ncn_fee_bps,
+                        account_payer_sol,
+                    )
+                    .await
+                }
+            },
+            ProgramCommand::SendSignedTransaction { transaction } => {
+                send_signed_transaction(self, &transaction).await
+            }
         }
     }
 }
@@ -1,19 +1,29 @@
-use std::time::Duration;
+use std::{collections::HashSet, sync::atomic::Ordering, time::Duration};
 
 use crate::{
+    args::EpochAccountType,
     getters::{
         get_account, get_all_operators_in_ncn, get_all_sorted_operators_for_vault, get_all_vaults,
         get_all_vaults_in_ncn, get_ballot_box, get_consensus_result, get_current_slot,
-        get_epoch_snapshot, get_ncn_program_config, get_ncn_reward_receiver_rewards,
-        get_ncn_reward_router, get_operator, get_operator_snapshot,
-        get_operator_vault_reward_receiver_rewards, get_operator_vault_reward_router,
-        get_or_create_vault_registry, get_vault, get_vault_config, get_vault_registry,
+        get_epoch_snapshot, get_guaranteed_epoch_and_slot, get_ncn, get_ncn_program_config,
+        get_ncn_reward_receiver_rewards, get_ncn_reward_router, get_operator,
+        get_operator_snapshot, get_operator_vault_reward_receiver_rewards,
+        get_operator_vault_reward_router, get_or_create_vault_registry,
+        get_total_rewards_to_be_distributed, get_vault, get_vault_config, get_vault_registry,
         get_vault_update_state_tracker, get_weight_table,
     },
     handler::CliHandler,
+    journal::{record_transaction, JournalResult},
     log::boring_progress_bar,
+    lookup_tables::load_epoch_lookup_table,
+    proposal::AdminProposal,
+    signer::CliSigner,
+    simulate_epoch::SimulatedTransaction,
+    snapshot_progress::SnapshotProgress,
 };
 use anyhow::{anyhow, Ok, Result};
+use base64::{engine::general_purpose, Engine};
+use futures::{stream, StreamExt};
 use jito_restaking_core::{
     config::Config as RestakingConfig, ncn_operator_state::NcnOperatorState,
     ncn_vault_ticket::NcnVaultTicket,
@@ -26,28 +36,34 @@ use jito_vault_client::{
     types::WithdrawalAllocationMethod,
 };
 use jito_vault_core::{
-    config::Config as VaultConfig, vault_ncn_ticket::VaultNcnTicket,
-    vault_operator_delegation::VaultOperatorDelegation,
+    config::Config as VaultConfig, vault_operator_delegation::VaultOperatorDelegation,
     vault_update_state_tracker::VaultUpdateStateTracker,
 };
-use log::info;
+use log::{info, warn};
 use ncn_program_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetNewAdminBuilder, AdminSetParametersBuilder,
-        AdminSetTieBreakerBuilder, AdminSetWeightBuilder, CastVoteBuilder,
-        CloseEpochAccountBuilder, DistributeNCNRewardsBuilder, DistributeOperatorRewardsBuilder,
+        AdminRegisterStMintBuilder, AdminSetAdminGovernanceBuilder, AdminSetAdminMultisigBuilder,
+        AdminSetDefaultWeightBuilder, AdminSetFeeGroupBuilder, AdminSetNewAdminBuilder,
+        AdminSetParametersBuilder, AdminSetRewardMintBuilder, AdminSetStMintBuilder,
+        AdminSetTieBreakerBuilder, AdminSetVaultMetadataBuilder,
+        AdminSetVaultRewardRecipientBuilder, AdminSetWeightBuilder, AdminSetWeightsBuilder,
+        ApplyParametersBuilder, BatchInitializeOperatorSnapshotBuilder,
+        CloseAllEpochAccountsBuilder, CloseEpochAccountBuilder, CloseRewardReceiverBuilder,
+        CopyPreviousEpochWeightsBuilder, DistributeFeeGroupRewardsBuilder,
+        DistributeNCNRewardsBuilder, DistributeOperatorRewardsBuilder,
         DistributeOperatorVaultRewardRouteBuilder, DistributeProtocolRewardsBuilder,
         DistributeVaultRewardsBuilder, InitializeBallotBoxBuilder,
         InitializeConfigBuilder as InitializeNCNProgramConfigBuilder,
-        InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNCNRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeOperatorVaultRewardRouterBuilder, InitializeVaultRegistryBuilder,
-        InitializeWeightTableBuilder, ReallocBallotBoxBuilder, ReallocNCNRewardRouterBuilder,
-        ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
-        RouteNCNRewardsBuilder, RouteOperatorVaultRewardsBuilder, SetEpochWeightsBuilder,
-        SnapshotVaultOperatorDelegationBuilder,
+        InitializeEpochAccountsBuilder, InitializeEpochSnapshotBuilder,
+        InitializeEpochStateBuilder, InitializeNCNRewardRouterBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeOperatorVaultRewardRouterBuilder,
+        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, MarkEpochSkippedBuilder,
+        ReallocBallotBoxBuilder, ReallocNCNRewardRouterBuilder, ReallocVaultRegistryBuilder,
+        ReallocWeightTableBuilder, RegisterVaultBuilder, RouteAndDistributeNCNRewardsBuilder,
+        RouteOperatorVaultRewardsBuilder, SetEpochWeightsBuilder, SetOperatorMetadataBuilder,
+        SetWeightFromSwitchboardBuilder, ShrinkNCNRewardRouterBuilder,
     },
-    types::ConfigAdminRole,
+    types::{ConfigAdminRole, StMintWeight},
 };
 use ncn_program_core::{
     account_payer::AccountPayer,
@@ -58,24 +74,43 @@ use ncn_program_core::{
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    fees::FeeGroup,
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+    operator_metadata::OperatorMetadata,
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
-    vault_registry::VaultRegistry,
+    vault_registry::{VaultEntry, VaultRegistry},
     weight_table::WeightTable,
 };
-use solana_client::rpc_config::RpcSendTransactionConfig;
+use ncn_program_sdk::{
+    errors::{already_processed_error, describe_client_error, NCNProgramError},
+    instructions as sdk_instructions,
+    packing::{BudgetedInstruction, TransactionPacker, MAX_COMPUTE_UNITS_PER_TRANSACTION},
+    squads,
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{
+        RpcSendTransactionConfig, RpcSimulateTransactionAccountsConfig,
+        RpcSimulateTransactionConfig,
+    },
+};
 
-use serde::Deserialize;
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    instruction::Instruction,
+    account::Account,
+    compute_budget::{self, ComputeBudgetInstruction},
+    hash::{hash, Hash},
+    instruction::{AccountMeta, Instruction},
+    message::{v0, VersionedMessage},
     native_token::sol_to_lamports,
+    nonce, pubkey,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
-    system_instruction::transfer,
+    system_instruction::{advance_nonce_account, transfer},
     system_program,
-    transaction::Transaction,
+    transaction::{Transaction, VersionedTransaction},
 };
 use tokio::time::sleep;
 
@@ -90,7 +125,7 @@ pub async fn admin_create_config(
     valid_slots_after_consensus: u64,
     epochs_after_consensus_before_close: u64,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
     let client = handler.rpc_client();
 
     let ncn = *handler.ncn()?;
@@ -100,11 +135,11 @@ pub async fn admin_create_config(
     let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
     println!("Account Payer: {}", account_payer.to_string());
 
-    let tie_breaker_admin = tie_breaker_admin.unwrap_or_else(|| keypair.pubkey());
+    let tie_breaker_admin = tie_breaker_admin.unwrap_or(admin);
 
     let initialize_config_ix = InitializeNCNProgramConfigBuilder::new()
         .config(config)
-        .ncn_admin(keypair.pubkey())
+        .ncn_admin(admin)
         .ncn(ncn)
         .account_payer(account_payer)
         .ncn_fee_wallet(ncn_fee_wallet)
@@ -113,7 +148,7 @@ pub async fn admin_create_config(
         .valid_slots_after_consensus(valid_slots_after_consensus)
         .epochs_after_consensus_before_close(epochs_after_consensus_before_close)
         .tie_breaker_admin(tie_breaker_admin)
-        .ncn_admin(keypair.pubkey())
+        .ncn_admin(admin)
         .instruction();
 
     let program = client.get_account(&handler.ncn_program_id).await?;
@@ -123,14 +158,14 @@ pub async fn admin_create_config(
         &handler.ncn_program_id, program, &initialize_config_ix
     );
 
-    send_and_log_transaction(
+    send_and_log_admin_transaction(
         handler,
         &[initialize_config_ix],
         &[],
         "Created NCN Program Config",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Ncn Admin: {:?}", keypair.pubkey()),
+            format!("Ncn Admin: {:?}", admin),
             format!("Tie Breaker Admin: {:?}", tie_breaker_admin),
             format!(
                 "Valid Slots After Consensus: {:?}",
@@ -148,7 +183,7 @@ pub async fn admin_register_st_mint(
     vault: &Pubkey,
     weight: Option<u128>,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
 
     let ncn = *handler.ncn()?;
 
@@ -162,10 +197,11 @@ pub async fn admin_register_st_mint(
 
     register_st_mint_builder
         .config(config)
-        .admin(keypair.pubkey())
+        .admin(admin)
         .vault_registry(vault_registry)
         .ncn(ncn)
-        .st_mint(vault_account.supported_mint);
+        .st_mint(vault_account.supported_mint)
+        .add_remaining_accounts(&admin_approver_metas(handler));
 
     if let Some(weight) = weight {
         register_st_mint_builder.weight(weight);
@@ -173,10 +209,10 @@ pub async fn admin_register_st_mint(
 
     let register_st_mint_ix = register_st_mint_builder.instruction();
 
-    send_and_log_transaction(
+    send_and_log_admin_transaction(
         handler,
         &[register_st_mint_ix],
-        &[],
+        &admin_approver_signers(handler),
         "Registered ST Mint",
         &[
             format!("NCN: {:?}", ncn),
@@ -189,6 +225,92 @@ pub async fn admin_register_st_mint(
     Ok(())
 }
 
+pub async fn admin_set_st_mint(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    weight: Option<u128>,
+    switchboard_feed: Option<Pubkey>,
+) -> Result<()> {
+    let admin = handler.admin()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let vault_account = get_vault(handler, vault).await?;
+
+    let mut set_st_mint_builder = AdminSetStMintBuilder::new();
+
+    set_st_mint_builder
+        .config(config)
+        .admin(admin)
+        .vault_registry(vault_registry)
+        .ncn(ncn)
+        .st_mint(vault_account.supported_mint)
+        .add_remaining_accounts(&admin_approver_metas(handler));
+
+    if let Some(weight) = weight {
+        set_st_mint_builder.weight(weight);
+    }
+
+    if let Some(switchboard_feed) = switchboard_feed {
+        set_st_mint_builder.switchboard_feed(switchboard_feed);
+    }
+
+    let set_st_mint_ix = set_st_mint_builder.instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[set_st_mint_ix],
+        &admin_approver_signers(handler),
+        "Set ST Mint",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("ST Mint: {:?}", vault_account.supported_mint),
+            format!("Weight: {:?}", weight),
+            format!("Switchboard Feed: {:?}", switchboard_feed),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_set_default_weight(handler: &CliHandler, default_weight: u128) -> Result<()> {
+    let admin = handler.admin()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let set_default_weight_ix = AdminSetDefaultWeightBuilder::new()
+        .config(config)
+        .admin(admin)
+        .vault_registry(vault_registry)
+        .ncn(ncn)
+        .default_weight(default_weight)
+        .add_remaining_accounts(&admin_approver_metas(handler))
+        .instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[set_default_weight_ix],
+        &admin_approver_signers(handler),
+        "Set Default Weight",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Default Weight: {:?}", default_weight),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn admin_set_weight(
     handler: &CliHandler,
     vault: &Pubkey,
@@ -206,7 +328,7 @@ pub async fn admin_set_weight_with_st_mint(
     epoch: u64,
     weight: u128,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
 
     let ncn = *handler.ncn()?;
 
@@ -220,13 +342,13 @@ pub async fn admin_set_weight_with_st_mint(
         .ncn(ncn)
         .weight_table(weight_table)
         .epoch_state(epoch_state)
-        .weight_table_admin(keypair.pubkey())
+        .weight_table_admin(admin)
         .st_mint(*st_mint)
         .weight(weight)
         .epoch(epoch)
         .instruction();
 
-    send_and_log_transaction(
+    send_and_log_admin_transaction(
         handler,
         &[admin_set_weight_ix],
         &[],
@@ -243,12 +365,71 @@ pub async fn admin_set_weight_with_st_mint(
     Ok(())
 }
 
+pub async fn admin_set_weights(
+    handler: &CliHandler,
+    vault_weights: &[(Pubkey, u128)],
+    epoch: u64,
+) -> Result<()> {
+    let admin = handler.admin()?;
+
+    let ncn = *handler.ncn()?;
+
+    let mut weights = Vec::with_capacity(vault_weights.len());
+    for (vault, weight) in vault_weights {
+        let vault_account = get_vault(handler, vault).await?;
+        weights.push(StMintWeight {
+            st_mint: vault_account.supported_mint,
+            weight: *weight,
+        });
+    }
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let log_weights: Vec<String> = weights
+        .iter()
+        .map(|st_mint_weight| {
+            format!(
+                "ST Mint: {:?}, Weight: {:?}",
+                st_mint_weight.st_mint, st_mint_weight.weight
+            )
+        })
+        .collect();
+
+    let admin_set_weights_ix = AdminSetWeightsBuilder::new()
+        .ncn(ncn)
+        .weight_table(weight_table)
+        .epoch_state(epoch_state)
+        .weight_table_admin(admin)
+        .weights(weights)
+        .epoch(epoch)
+        .instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[admin_set_weights_ix],
+        &[],
+        "Set Weights",
+        &[
+            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+            log_weights.as_slice(),
+        ]
+        .concat(),
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn admin_set_tie_breaker(
     handler: &CliHandler,
     epoch: u64,
     weather_status: u8,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
 
     let ncn = *handler.ncn()?;
 
@@ -264,12 +445,12 @@ pub async fn admin_set_tie_breaker(
         .config(ncn_config)
         .ballot_box(ballot_box)
         .ncn(ncn)
-        .tie_breaker_admin(keypair.pubkey())
+        .tie_breaker_admin(admin)
         .weather_status(weather_status)
         .epoch(epoch)
         .instruction();
 
-    send_and_log_transaction(
+    send_and_log_admin_transaction(
         handler,
         &[set_tie_breaker_ix],
         &[],
@@ -285,12 +466,97 @@ pub async fn admin_set_tie_breaker(
     Ok(())
 }
 
+/// Signing remaining accounts for the admin approvers passed to the CLI
+fn admin_approver_metas(handler: &CliHandler) -> Vec<AccountMeta> {
+    handler
+        .admin_approvers
+        .iter()
+        .map(|approver| AccountMeta::new_readonly(approver.pubkey(), true))
+        .collect()
+}
+
+fn admin_approver_signers(handler: &CliHandler) -> Vec<&Keypair> {
+    handler.admin_approvers.iter().collect()
+}
+
+pub async fn admin_set_admin_multisig(
+    handler: &CliHandler,
+    approvers: &[Pubkey],
+    threshold: u8,
+) -> Result<()> {
+    let admin = handler.admin()?;
+    let ncn = *handler.ncn()?;
+
+    let config_pda = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn).0;
+
+    let set_admin_multisig_ix = AdminSetAdminMultisigBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(admin)
+        .approvers(approvers.to_vec())
+        .threshold(threshold)
+        .add_remaining_accounts(&admin_approver_metas(handler))
+        .instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[set_admin_multisig_ix],
+        &admin_approver_signers(handler),
+        "Set Admin Multisig",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Approvers: {:?}", approvers),
+            format!("Threshold: {:?}", threshold),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Hands the admin role to the governances of `realm`, both keys default hand it back. Once set,
+/// admin instructions have to be executed from proposals of the realm.
+pub async fn admin_set_admin_governance(
+    handler: &CliHandler,
+    governance_program: &Pubkey,
+    realm: &Pubkey,
+) -> Result<()> {
+    let admin = handler.admin()?;
+    let ncn = *handler.ncn()?;
+
+    let config_pda = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn).0;
+
+    let set_admin_governance_ix = AdminSetAdminGovernanceBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(admin)
+        .governance_program(*governance_program)
+        .realm(*realm)
+        .add_remaining_accounts(&admin_approver_metas(handler))
+        .instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[set_admin_governance_ix],
+        &admin_approver_signers(handler),
+        "Set Admin Governance",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Governance Program: {:?}", governance_program),
+            format!("Realm: {:?}", realm),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn admin_set_new_admin(
     handler: &CliHandler,
     new_admin: &Pubkey,
     set_tie_breaker_admin: bool,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
     let ncn = *handler.ncn()?;
 
     let config_pda = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn).0;
@@ -305,14 +571,15 @@ pub async fn admin_set_new_admin(
         let mut ix = AdminSetNewAdminBuilder::new();
         ix.config(config_pda)
             .ncn(ncn)
-            .ncn_admin(keypair.pubkey())
+            .ncn_admin(admin)
             .new_admin(*new_admin)
-            .role(*role);
+            .role(*role)
+            .add_remaining_accounts(&admin_approver_metas(handler));
 
-        send_and_log_transaction(
+        send_and_log_admin_transaction(
             handler,
             &[ix.instruction()],
-            &[],
+            &admin_approver_signers(handler),
             "Admin Set New Admin",
             &[
                 format!("NCN: {:?}", ncn),
@@ -326,20 +593,29 @@ pub async fn admin_set_new_admin(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn admin_set_parameters(
     handler: &CliHandler,
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
     starting_valid_epoch: Option<u64>,
+    cranker_fee_bps: Option<u16>,
+    timeliness_bonus_slots: Option<u64>,
+    timeliness_bonus_bps: Option<u16>,
+    weight_decay_epochs: Option<u64>,
+    weight_decay_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    permissionless_vault_registration: Option<bool>,
+    parameters_timelock_slots: Option<u64>,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
+    let admin = handler.admin()?;
     let ncn = *handler.ncn()?;
 
     let config_pda = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn).0;
 
     let mut ix = AdminSetParametersBuilder::new();
-    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
+    ix.config(config_pda).ncn(ncn).ncn_admin(admin);
 
     if let Some(epochs) = epochs_before_stall {
         ix.epochs_before_stall(epochs);
@@ -357,10 +633,44 @@ pub async fn admin_set_parameters(
         ix.starting_valid_epoch(epoch);
     }
 
-    send_and_log_transaction(
+    if let Some(fee_bps) = cranker_fee_bps {
+        ix.cranker_fee_bps(fee_bps);
+    }
+
+    if let Some(slots) = timeliness_bonus_slots {
+        ix.timeliness_bonus_slots(slots);
+    }
+
+    if let Some(bonus_bps) = timeliness_bonus_bps {
+        ix.timeliness_bonus_bps(bonus_bps);
+    }
+
+    if let Some(epochs) = weight_decay_epochs {
+        ix.weight_decay_epochs(epochs);
+    }
+
+    if let Some(decay_bps) = weight_decay_bps {
+        ix.weight_decay_bps(decay_bps);
+    }
+
+    if let Some(cap_bps) = max_vault_stake_weight_bps {
+        ix.max_vault_stake_weight_bps(cap_bps);
+    }
+
+    if let Some(permissionless) = permissionless_vault_registration {
+        ix.permissionless_vault_registration(permissionless);
+    }
+
+    if let Some(slots) = parameters_timelock_slots {
+        ix.parameters_timelock_slots(slots);
+    }
+
+    ix.add_remaining_accounts(&admin_approver_metas(handler));
+
+    send_and_log_admin_transaction(
         handler,
         &[ix.instruction()],
-        &[],
+        &admin_approver_signers(handler),
         "Set Parameters",
         &[
             format!("NCN: {:?}", ncn),
@@ -373,6 +683,20 @@ pub async fn admin_set_parameters(
                 "Valid Slots After Consensus: {:?}",
                 valid_slots_after_consensus
             ),
+            format!("Cranker Fee Bps: {:?}", cranker_fee_bps),
+            format!("Timeliness Bonus Slots: {:?}", timeliness_bonus_slots),
+            format!("Timeliness Bonus Bps: {:?}", timeliness_bonus_bps),
+            format!("Weight Decay Epochs: {:?}", weight_decay_epochs),
+            format!("Weight Decay Bps: {:?}", weight_decay_bps),
+            format!(
+                "Max Vault Stake Weight Bps: {:?}",
+                max_vault_stake_weight_bps
+            ),
+            format!(
+                "Permissionless Vault Registration: {:?}",
+                permissionless_vault_registration
+            ),
+            format!("Parameters Timelock Slots: {:?}", parameters_timelock_slots),
         ],
     )
     .await?;
@@ -380,22 +704,31 @@ pub async fn admin_set_parameters(
     Ok(())
 }
 
-pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
-    let keypair = handler.keypair()?;
+pub async fn admin_set_reward_mint(handler: &CliHandler, index: u8, mint: &Pubkey) -> Result<()> {
+    let admin = handler.admin()?;
+
     let ncn = *handler.ncn()?;
 
-    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
-    let transfer_ix = transfer(&keypair.pubkey(), &account_payer, sol_to_lamports(amount));
+    let set_reward_mint_ix = AdminSetRewardMintBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .ncn_admin(admin)
+        .index(index)
+        .mint(*mint)
+        .add_remaining_accounts(&admin_approver_metas(handler))
+        .instruction();
 
-    send_and_log_transaction(
+    send_and_log_admin_transaction(
         handler,
-        &[transfer_ix],
-        &[],
-        "Fund Account Payer",
+        &[set_reward_mint_ix],
+        &admin_approver_signers(handler),
+        "Set Reward Mint",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Amount: {:?} SOL", amount),
+            format!("Index: {:?}", index),
+            format!("Mint: {:?}", mint),
         ],
     )
     .await?;
@@ -403,66 +736,86 @@ pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Resu
     Ok(())
 }
 
-// --------------------- NCN Program ------------------------------
-
-// ----------------------- Keeper ---------------------------------
+pub async fn admin_set_fee_group(
+    handler: &CliHandler,
+    group: u8,
+    name: &str,
+    wallet: &Pubkey,
+    fee_bps: Option<u16>,
+) -> Result<()> {
+    let admin = handler.admin()?;
 
-pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
     let ncn = *handler.ncn()?;
 
     let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
-    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+    let name_bytes = zero_padded::<{ FeeGroup::NAME_LEN }>("Fee group name", name)?;
 
-    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+    let mut set_fee_group_builder = AdminSetFeeGroupBuilder::new();
+    set_fee_group_builder
+        .config(config)
+        .ncn(ncn)
+        .fee_wallet(*wallet)
+        .ncn_admin(admin)
+        .group(group)
+        .name(name_bytes)
+        .add_remaining_accounts(&admin_approver_metas(handler));
+
+    if let Some(fee_bps) = fee_bps {
+        set_fee_group_builder.fee_bps(fee_bps);
+    }
 
-    let vault_registry_account = get_account(handler, &vault_registry).await?;
+    let set_fee_group_ix = set_fee_group_builder.instruction();
 
-    // Skip if vault registry already exists
-    if vault_registry_account.is_none() {
-        let initialize_vault_registry_ix = InitializeVaultRegistryBuilder::new()
-            .config(config)
-            .account_payer(account_payer)
-            .ncn(ncn)
-            .vault_registry(vault_registry)
-            .instruction();
+    send_and_log_admin_transaction(
+        handler,
+        &[set_fee_group_ix],
+        &admin_approver_signers(handler),
+        "Set Fee Group",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Group: {:?}", group),
+            format!("Name: {}", name),
+            format!("Wallet: {:?}", wallet),
+            format!("Fee Bps: {:?}", fee_bps),
+        ],
+    )
+    .await?;
 
-        send_and_log_transaction(
-            handler,
-            &[initialize_vault_registry_ix],
-            &[],
-            "Created Vault Registry",
-            &[format!("NCN: {:?}", ncn)],
-        )
-        .await?;
-    }
+    Ok(())
+}
 
-    // Number of reallocations needed based on VaultRegistry::SIZE
-    let num_reallocs =
-        ((VaultRegistry::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1).max(1);
+pub async fn admin_set_vault_reward_recipient(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    reward_recipient: &Pubkey,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
 
-    let realloc_vault_registry_ix = ReallocVaultRegistryBuilder::new()
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let set_vault_reward_recipient_ix = AdminSetVaultRewardRecipientBuilder::new()
         .config(config)
-        .vault_registry(vault_registry)
         .ncn(ncn)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
+        .vault_registry(vault_registry)
+        .vault(*vault)
+        .reward_recipient(*reward_recipient)
+        .vault_admin(keypair.pubkey())
         .instruction();
 
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_vault_registry_ix.clone());
-    }
-
     send_and_log_transaction(
         handler,
-        &realloc_ixs,
+        &[set_vault_reward_recipient_ix],
         &[],
-        "Reallocated Vault Registry",
+        "Set Vault Reward Recipient",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Number of reallocations: {:?}", num_reallocs),
+            format!("Vault: {:?}", vault),
+            format!("Reward Recipient: {:?}", reward_recipient),
         ],
     )
     .await?;
@@ -470,9 +823,218 @@ pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
     Ok(())
 }
 
-pub async fn register_vault(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
-    let ncn = *handler.ncn()?;
-    let vault = *vault;
+/// Pads a string with zeros to the size of an on-chain metadata field
+fn zero_padded<const N: usize>(field: &str, value: &str) -> Result<[u8; N]> {
+    if value.len() > N {
+        return Err(anyhow!(
+            "{} is {} bytes, at most {} are allowed",
+            field,
+            value.len(),
+            N
+        ));
+    }
+
+    let mut padded = [0; N];
+    padded[..value.len()].copy_from_slice(value.as_bytes());
+    Ok(padded)
+}
+
+pub async fn admin_set_vault_metadata(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    name: &str,
+    uri: Option<&str>,
+) -> Result<()> {
+    let admin = handler.admin()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let name_bytes = zero_padded::<{ VaultEntry::NAME_LEN }>("Vault name", name)?;
+
+    let uri_hash = uri.map_or([0; VaultEntry::URI_HASH_LEN], |uri| {
+        hash(uri.as_bytes()).to_bytes()
+    });
+
+    let set_vault_metadata_ix = AdminSetVaultMetadataBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .vault(*vault)
+        .admin(admin)
+        .name(name_bytes)
+        .uri_hash(uri_hash)
+        .add_remaining_accounts(&admin_approver_metas(handler))
+        .instruction();
+
+    send_and_log_admin_transaction(
+        handler,
+        &[set_vault_metadata_ix],
+        &admin_approver_signers(handler),
+        "Set Vault Metadata",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Name: {}", name),
+            format!("URI: {}", uri.unwrap_or("None")),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let transfer_ix = transfer(&keypair.pubkey(), &account_payer, sol_to_lamports(amount));
+
+    send_and_log_transaction(
+        handler,
+        &[transfer_ix],
+        &[],
+        "Fund Account Payer",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Amount: {:?} SOL", amount),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+// --------------------- NCN Program ------------------------------
+
+// ----------------------- Keeper ---------------------------------
+
+/// Takes or renews the keeper lease of the keeper wallet for `holder`, `0` lease slots release it
+pub async fn acquire_keeper_lease(
+    handler: &CliHandler,
+    holder: &Pubkey,
+    lease_slots: u64,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let acquire_keeper_lease_ix = sdk_instructions::acquire_keeper_lease(
+        &handler.ncn_program_id,
+        &ncn,
+        &keypair.pubkey(),
+        holder,
+        lease_slots,
+    );
+
+    send_and_log_transaction(
+        handler,
+        &[acquire_keeper_lease_ix],
+        &[],
+        "Acquire Keeper Lease",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Holder: {:?}", holder),
+            format!("Lease Slots: {}", lease_slots),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn apply_parameters(handler: &CliHandler) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let apply_parameters_ix = ApplyParametersBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[apply_parameters_ix],
+        &[],
+        "Applied Parameters",
+        &[format!("NCN: {:?}", ncn)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let vault_registry_account = get_account(handler, &vault_registry).await?;
+
+    // Skip if vault registry already exists
+    if vault_registry_account.is_none() {
+        let initialize_vault_registry_ix = InitializeVaultRegistryBuilder::new()
+            .config(config)
+            .account_payer(account_payer)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .instruction();
+
+        send_and_log_transaction(
+            handler,
+            &[initialize_vault_registry_ix],
+            &[],
+            "Created Vault Registry",
+            &[format!("NCN: {:?}", ncn)],
+        )
+        .await?;
+    }
+
+    // Number of reallocations needed based on VaultRegistry::SIZE
+    let num_reallocs =
+        ((VaultRegistry::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1).max(1);
+
+    let realloc_vault_registry_ix = ReallocVaultRegistryBuilder::new()
+        .config(config)
+        .vault_registry(vault_registry)
+        .ncn(ncn)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
+
+    let realloc_ixs =
+        vec![
+            BudgetedInstruction::new(realloc_vault_registry_ix, REALLOC_COMPUTE_UNITS);
+            num_reallocs as usize
+        ];
+
+    send_packed_transactions(
+        handler,
+        &realloc_ixs,
+        &[],
+        "Reallocated Vault Registry",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Number of reallocations: {:?}", num_reallocs),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn register_vault(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
+    let ncn = *handler.ncn()?;
+    let vault = *vault;
 
     let (ncn_program_config, _, _) =
         NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
@@ -482,13 +1044,19 @@ pub async fn register_vault(handler: &CliHandler, vault: &Pubkey) -> Result<()>
     let (ncn_vault_ticket, _, _) =
         NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
 
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+
+    let st_mint = get_vault(handler, &vault).await?.supported_mint;
+
     let register_vault_ix = RegisterVaultBuilder::new()
         .config(ncn_program_config)
         .vault_registry(vault_registry)
         .vault(vault)
         .ncn(ncn)
         .ncn_vault_ticket(ncn_vault_ticket)
-        .vault_registry(vault_registry)
+        .restaking_config(restaking_config)
+        .st_mint(st_mint)
         .instruction();
 
     send_and_log_transaction(
@@ -525,6 +1093,7 @@ pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()>
             .epoch_state(epoch_state)
             .ncn(ncn)
             .epoch(epoch)
+            .init_if_needed(true)
             .account_payer(account_payer)
             .system_program(system_program::id())
             .instruction();
@@ -542,6 +1111,109 @@ pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()>
     Ok(())
 }
 
+pub async fn mark_epoch_skipped(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let mark_epoch_skipped_ix = MarkEpochSkippedBuilder::new()
+        .epoch_marker(epoch_marker)
+        .epoch_state(epoch_state)
+        .config(config)
+        .ncn(ncn)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[mark_epoch_skipped_ix],
+        &[],
+        "Marked Epoch Skipped",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Creates the epoch state, weight table, ballot box and NCN reward router of the epoch in one
+/// transaction, then reallocs the accounts to their full size
+pub async fn create_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (ballot_box, _, _) = BallotBox::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (consensus_result, _, _) =
+        ConsensusResult::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (ncn_reward_router, _, _) =
+        NCNRewardRouter::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (ncn_reward_receiver, _, _) =
+        NCNRewardReceiver::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+    let (epoch_marker, _, _) = EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
+
+    let epoch_state_account = get_account(handler, &epoch_state).await?;
+
+    // Skip if the epoch has already been bootstrapped, the accounts are created one by one below
+    if epoch_state_account.is_none() {
+        let initialize_epoch_accounts_ix = InitializeEpochAccountsBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .vault_registry(vault_registry)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .ballot_box(ballot_box)
+            .consensus_result(consensus_result)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+        send_and_log_transaction(
+            handler,
+            &[cul_ix, initialize_epoch_accounts_ix],
+            &[],
+            "Initialized Epoch Accounts",
+            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+        )
+        .await?;
+    }
+
+    // Skips the already created accounts and reallocs them
+    create_weight_table(handler, epoch).await?;
+    create_ballot_box(handler, epoch).await?;
+    create_ncn_reward_router(handler, epoch).await?;
+
+    Ok(())
+}
+
 pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
@@ -572,6 +1244,7 @@ pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()>
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(true)
             .instruction();
 
         send_and_log_transaction(
@@ -599,13 +1272,12 @@ pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()>
         .system_program(system_program::id())
         .instruction();
 
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_weight_table_ix.clone());
-    }
+    let realloc_ixs = vec![
+        BudgetedInstruction::new(realloc_weight_table_ix, REALLOC_COMPUTE_UNITS);
+        num_reallocs as usize
+    ];
 
-    send_and_log_transaction(
+    send_packed_transactions(
         handler,
         &realloc_ixs,
         &[],
@@ -632,12 +1304,35 @@ pub async fn set_epoch_weights(handler: &CliHandler, epoch: u64) -> Result<()> {
 
     let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
 
-    let set_epoch_weights_ix = SetEpochWeightsBuilder::new()
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+
+    // Vaults are only needed to check their last update when weight decay is enabled
+    let vault_accounts: Vec<AccountMeta> = if get_ncn_program_config(handler)
+        .await?
+        .weight_decay_enabled()
+    {
+        get_vault_registry(handler)
+            .await?
+            .get_valid_vault_entries()
+            .iter()
+            .map(|vault_entry| AccountMeta::new_readonly(*vault_entry.vault(), false))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let set_epoch_weights_ix = SetEpochWeightsBuilder::new()
         .ncn(ncn)
         .weight_table(weight_table)
         .epoch_state(epoch_state)
         .vault_registry(vault_registry)
+        .config(config)
+        .restaking_config(restaking_config)
         .epoch(epoch)
+        .add_remaining_accounts(&vault_accounts)
         .instruction();
 
     send_and_log_transaction(
@@ -658,6 +1353,97 @@ pub async fn set_epoch_weights(handler: &CliHandler, epoch: u64) -> Result<()> {
     Ok(())
 }
 
+/// Seeds the weight table with the weights of the previous epoch, fails if the vault registry
+/// changed in between
+pub async fn copy_previous_epoch_weights(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let previous_epoch = epoch
+        .checked_sub(1)
+        .ok_or_else(|| anyhow!("No previous epoch to copy weights from"))?;
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (previous_weight_table, _, _) =
+        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, previous_epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let copy_previous_epoch_weights_ix = CopyPreviousEpochWeightsBuilder::new()
+        .epoch_state(epoch_state)
+        .ncn(ncn)
+        .weight_table(weight_table)
+        .previous_weight_table(previous_weight_table)
+        .epoch(epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[copy_previous_epoch_weights_ix],
+        &[],
+        "Copy Previous Epoch Weights",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epoch: {:?}", epoch),
+            format!("Weight Table: {:?}", weight_table),
+            format!("Previous Weight Table: {:?}", previous_weight_table),
+            format!("Epoch State: {:?}", epoch_state),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Sets the weights of all st mints with a switchboard feed, one transaction per mint
+pub async fn set_weights_from_switchboard(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let vault_registry_account = get_vault_registry(handler).await?;
+
+    for mint_entry in vault_registry_account
+        .get_valid_mint_entries()
+        .iter()
+        .filter(|mint_entry| mint_entry.has_switchboard_feed())
+    {
+        let set_weight_from_switchboard_ix = SetWeightFromSwitchboardBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .weight_table(weight_table)
+            .switchboard_feed(*mint_entry.switchboard_feed())
+            .st_mint(*mint_entry.st_mint())
+            .epoch(epoch)
+            .instruction();
+
+        send_and_log_transaction(
+            handler,
+            &[set_weight_from_switchboard_ix],
+            &[],
+            "Set Weight From Switchboard",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("ST Mint: {:?}", mint_entry.st_mint()),
+                format!("Switchboard Feed: {:?}", mint_entry.switchboard_feed()),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
@@ -675,6 +1461,21 @@ pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<(
     let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
     let (epoch_marker, _, _) = EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
 
+    // Vaults are only needed to compute the stake weight cap
+    let vault_accounts: Vec<AccountMeta> = if get_ncn_program_config(handler)
+        .await?
+        .vault_stake_weight_cap_enabled()
+    {
+        get_weight_table(handler, epoch)
+            .await?
+            .vault_entries()
+            .iter()
+            .map(|vault_entry| AccountMeta::new_readonly(*vault_entry.vault(), false))
+            .collect()
+    } else {
+        vec![]
+    };
+
     let initialize_epoch_snapshot_ix = InitializeEpochSnapshotBuilder::new()
         .epoch_marker(epoch_marker)
         .config(config)
@@ -685,6 +1486,8 @@ pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<(
         .account_payer(account_payer)
         .system_program(system_program::id())
         .epoch(epoch)
+        .init_if_needed(true)
+        .add_remaining_accounts(&vault_accounts)
         .instruction();
 
     send_and_log_transaction(
@@ -745,6 +1548,7 @@ pub async fn create_operator_snapshot(
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(true)
             .instruction();
 
         send_and_log_transaction(
@@ -764,58 +1568,124 @@ pub async fn create_operator_snapshot(
     Ok(())
 }
 
-pub async fn snapshot_vault_operator_delegation(
+/// Operators whose snapshots are created in a single `BatchInitializeOperatorSnapshot` transaction
+pub const OPERATOR_SNAPSHOTS_PER_TRANSACTION: usize = 8;
+
+/// Compute units budgeted for one reallocation when packing them into transactions
+pub const REALLOC_COMPUTE_UNITS: u32 = 50_000;
+
+/// Compute units budgeted for one `SnapshotVaultOperatorDelegation` when packing them into
+/// transactions
+pub const SNAPSHOT_VAULT_OPERATOR_DELEGATION_COMPUTE_UNITS: u32 = 200_000;
+
+pub async fn create_operator_snapshots(
     handler: &CliHandler,
-    vault: &Pubkey,
-    operator: &Pubkey,
+    operators: &[Pubkey],
     epoch: u64,
 ) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let vault = *vault;
-    let operator = *operator;
-
     let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let (restaking_config, _, _) =
-        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let (vault_ncn_ticket, _, _) =
-        VaultNcnTicket::find_program_address(&handler.vault_program_id, &vault, &ncn);
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+    let (epoch_marker, _, _) = EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
 
-    let (ncn_vault_ticket, _, _) =
-        NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
+    let mut operators_to_snapshot = vec![];
+    for operator in operators.iter() {
+        let (operator_snapshot, _, _) =
+            OperatorSnapshot::find_program_address(&handler.ncn_program_id, operator, &ncn, epoch);
 
-    let (vault_operator_delegation, _, _) =
-        VaultOperatorDelegation::find_program_address(&handler.vault_program_id, &vault, &operator);
+        // Skip if operator snapshot already exists
+        if get_account(handler, &operator_snapshot).await?.is_none() {
+            operators_to_snapshot.push(*operator);
+        }
+    }
 
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    for operators in operators_to_snapshot.chunks(OPERATOR_SNAPSHOTS_PER_TRANSACTION) {
+        let operator_snapshot_accounts: Vec<AccountMeta> = operators
+            .iter()
+            .flat_map(|operator| {
+                let (ncn_operator_state, _, _) = NcnOperatorState::find_program_address(
+                    &handler.restaking_program_id,
+                    &ncn,
+                    operator,
+                );
+                let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+                    &handler.ncn_program_id,
+                    operator,
+                    &ncn,
+                    epoch,
+                );
 
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+                [
+                    AccountMeta::new_readonly(*operator, false),
+                    AccountMeta::new_readonly(ncn_operator_state, false),
+                    AccountMeta::new(operator_snapshot, false),
+                ]
+            })
+            .collect();
 
-    let (operator_snapshot, _, _) =
-        OperatorSnapshot::find_program_address(&handler.ncn_program_id, &operator, &ncn, epoch);
+        let batch_initialize_operator_snapshot_ix = BatchInitializeOperatorSnapshotBuilder::new()
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .restaking_config(
+                RestakingConfig::find_program_address(&handler.restaking_program_id).0,
+            )
+            .ncn(ncn)
+            .epoch_state(epoch_state)
+            .epoch_snapshot(epoch_snapshot)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .add_remaining_accounts(&operator_snapshot_accounts)
+            .instruction();
 
-    let snapshot_vault_operator_delegation_ix = SnapshotVaultOperatorDelegationBuilder::new()
-        .config(config)
-        .epoch_state(epoch_state)
-        .restaking_config(restaking_config)
-        .ncn(ncn)
-        .operator(operator)
-        .vault(vault)
-        .vault_ncn_ticket(vault_ncn_ticket)
-        .ncn_vault_ticket(ncn_vault_ticket)
-        .vault_operator_delegation(vault_operator_delegation)
-        .weight_table(weight_table)
-        .epoch_snapshot(epoch_snapshot)
-        .operator_snapshot(operator_snapshot)
-        .epoch(epoch)
-        .instruction();
+        let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+        send_and_log_transaction(
+            handler,
+            &[cul_ix, batch_initialize_operator_snapshot_ix],
+            &[],
+            "Initialized Operator Snapshots",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operators: {:?}", operators),
+                format!("Epoch: {:?}", epoch),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn snapshot_vault_operator_delegation(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let vault = *vault;
+    let operator = *operator;
+
+    let snapshot_vault_operator_delegation_ix =
+        sdk_instructions::snapshot_vault_operator_delegation(
+            &handler.ncn_program_id,
+            &handler.restaking_program_id,
+            &handler.vault_program_id,
+            &ncn,
+            &vault,
+            &operator,
+            epoch,
+        );
 
     send_and_log_transaction(
         handler,
@@ -834,6 +1704,97 @@ pub async fn snapshot_vault_operator_delegation(
     Ok(())
 }
 
+/// Snapshots the `(operator, vault)` delegations, packed into as few transactions as possible
+///
+/// Up to `max_in_flight_transactions` transactions are sent at once. The delegations of a failed
+/// transaction are retried one by one, so one failing delegation does not hold back the others.
+/// The delegations snapshotted are recorded in `progress`, the vault of a delegation that fails
+/// is forgotten there to be updated again.
+pub async fn snapshot_vault_operator_delegations(
+    handler: &CliHandler,
+    delegations: &[(Pubkey, Pubkey)],
+    epoch: u64,
+    progress: &SnapshotProgress,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+    load_epoch_lookup_table(handler, epoch).await;
+
+    let instructions: Vec<BudgetedInstruction> = delegations
+        .iter()
+        .map(|(operator, vault)| {
+            BudgetedInstruction::new(
+                sdk_instructions::snapshot_vault_operator_delegation(
+                    &handler.ncn_program_id,
+                    &handler.restaking_program_id,
+                    &handler.vault_program_id,
+                    &ncn,
+                    vault,
+                    operator,
+                    epoch,
+                ),
+                SNAPSHOT_VAULT_OPERATOR_DELEGATION_COMPUTE_UNITS,
+            )
+        })
+        .collect();
+
+    // The packer keeps the order, each transaction holds the next delegations after its compute
+    // unit limit
+    let transactions = pack_transactions(handler, &instructions)?;
+    let mut packed_delegations = Vec::with_capacity(transactions.len());
+    let mut offset = 0;
+    for transaction in transactions.iter() {
+        let len = transaction.len() - 1;
+        packed_delegations.push(&delegations[offset..offset + len]);
+        offset += len;
+    }
+
+    stream::iter(transactions.iter().zip(packed_delegations))
+        .for_each_concurrent(
+            handler.max_in_flight_transactions,
+            |(transaction, delegations)| async move {
+                let result = send_and_log_transaction(
+                    handler,
+                    transaction,
+                    &[],
+                    "Snapshotted Vault Operator Delegations",
+                    &[
+                        format!("NCN: {:?}", ncn),
+                        format!("Epoch: {:?}", epoch),
+                        format!("Delegations: {:?}", delegations),
+                    ],
+                )
+                .await;
+
+                if result.is_ok() {
+                    progress.record_delegations_snapshotted(delegations);
+                    return;
+                }
+
+                for (operator, vault) in delegations {
+                    let result =
+                        snapshot_vault_operator_delegation(handler, vault, operator, epoch).await;
+
+                    if let Err(err) = result {
+                        log::error!(
+                        "Failed to snapshot vault operator delegation for vault: {:?} and operator: {:?} in epoch: {:?} with error: {:?}",
+                        vault,
+                        operator,
+                        epoch,
+                        err
+                    );
+                        progress.forget_vault_updated(vault);
+                        continue;
+                    }
+
+                    progress.record_delegations_snapshotted(&[(*operator, *vault)]);
+                }
+            },
+        )
+        .await;
+
+    Ok(())
+}
+
 pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
@@ -861,6 +1822,7 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
             .ballot_box(ballot_box)
             .ncn(ncn)
             .epoch(epoch)
+            .init_if_needed(true)
             .account_payer(account_payer)
             .consensus_result(consensus_result)
             .system_program(system_program::id())
@@ -890,13 +1852,12 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
         .system_program(system_program::id())
         .instruction();
 
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_ballot_box_ix.clone());
-    }
+    let realloc_ixs = vec![
+        BudgetedInstruction::new(realloc_ballot_box_ix, REALLOC_COMPUTE_UNITS);
+        num_reallocs as usize
+    ];
 
-    send_and_log_transaction(
+    send_packed_transactions(
         handler,
         &realloc_ixs,
         &[],
@@ -965,61 +1926,49 @@ pub async fn close_epoch_account(
     Ok(())
 }
 
-// --------------------- operator ------------------------------
-
-pub async fn operator_cast_vote(
+/// Closes a NCN or operator vault reward router along with its reward receiver, rewards left in
+/// the receiver go to the NCN fee wallet
+pub async fn close_reward_router(
     handler: &CliHandler,
-    operator: &Pubkey,
+    ncn: Pubkey,
     epoch: u64,
-    weather_status: u8,
+    reward_router: Pubkey,
+    reward_receiver: Pubkey,
 ) -> Result<()> {
-    let keypair = handler.keypair()?;
-
-    let ncn = *handler.ncn()?;
-
-    let operator = *operator;
-
-    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let (ballot_box, _, _) = BallotBox::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
 
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
-    let (operator_snapshot, _, _) =
-        OperatorSnapshot::find_program_address(&handler.ncn_program_id, &operator, &ncn, epoch);
-    let (consensus_result, _, _) =
-        ConsensusResult::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    let ncn_config = get_ncn_program_config(handler).await?;
+
+    let mut ix = CloseEpochAccountBuilder::new();
 
-    let cast_vote_ix = CastVoteBuilder::new()
+    ix.account_payer(account_payer)
+        .epoch_marker(epoch_marker)
         .config(config)
+        .account_to_close(reward_router)
         .epoch_state(epoch_state)
-        .ballot_box(ballot_box)
         .ncn(ncn)
-        .epoch_snapshot(epoch_snapshot)
-        .operator_snapshot(operator_snapshot)
-        .operator(operator)
-        .operator_voter(keypair.pubkey())
-        .consensus_result(consensus_result)
-        .weather_status(weather_status)
-        .epoch(epoch)
-        .instruction();
+        .system_program(system_program::id())
+        .ncn_fee_wallet(Some(*ncn_config.fee_config.ncn_fee_wallet()))
+        .receiver_to_close(Some(reward_receiver))
+        .epoch(epoch);
 
     send_and_log_transaction(
         handler,
-        &[cast_vote_ix],
+        &[ix.instruction()],
         &[],
-        "Cast Vote",
+        "Close Reward Router",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Operator: {:?}", operator),
-            format!(
-                "Weather Status: {:?}",
-                WeatherStatus::from_u8(weather_status)
-            ),
+            format!("Reward Router: {:?}", reward_router),
+            format!("Reward Receiver: {:?}", reward_receiver),
             format!("Epoch: {:?}", epoch),
         ],
     )
@@ -1028,11 +1977,276 @@ pub async fn operator_cast_vote(
     Ok(())
 }
 
-// --------------------- MIDDLEWARE ------------------------------
-
-pub async fn get_consensus_result_instruction(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let consensus_result = get_consensus_result(handler, epoch).await?;
-
+/// Sends the lamports left in a reward receiver whose reward router was closed to the NCN fee
+/// wallet, the NCN reward receiver when `operator` is `None`
+pub async fn close_reward_receiver(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    operator: Option<Pubkey>,
+) -> Result<()> {
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (reward_router, reward_receiver) = match operator {
+        Some(operator) => (
+            OperatorVaultRewardRouter::find_program_address(
+                &handler.ncn_program_id,
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0,
+            OperatorVaultRewardReceiver::find_program_address(
+                &handler.ncn_program_id,
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0,
+        ),
+        None => (
+            NCNRewardRouter::find_program_address(&handler.ncn_program_id, &ncn, epoch).0,
+            NCNRewardReceiver::find_program_address(&handler.ncn_program_id, &ncn, epoch).0,
+        ),
+    };
+
+    let ncn_config = get_ncn_program_config(handler).await?;
+
+    let mut ix = CloseRewardReceiverBuilder::new();
+
+    ix.epoch_marker(epoch_marker)
+        .epoch_state(epoch_state)
+        .config(config)
+        .ncn(ncn)
+        .reward_router(reward_router)
+        .reward_receiver(reward_receiver)
+        .ncn_fee_wallet(*ncn_config.fee_config.ncn_fee_wallet())
+        .system_program(system_program::id())
+        .epoch(epoch);
+
+    if let Some(operator) = operator {
+        ix.operator(operator);
+    }
+
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Close Reward Receiver",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Reward Receiver: {:?}", reward_receiver),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub const EPOCH_ACCOUNTS_CLOSED_PER_TRANSACTION: usize = 16;
+
+/// Closes the given epoch accounts in as few transactions as possible. Accounts are closed in the
+/// order they are passed, already closed accounts are skipped. Reward routers are not supported,
+/// use `close_epoch_account` for those.
+pub async fn close_all_epoch_accounts(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    accounts_to_close: &[Pubkey],
+) -> Result<()> {
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let ncn_config = get_ncn_program_config(handler).await?;
+
+    let mut open_accounts = vec![];
+    for account_to_close in accounts_to_close.iter() {
+        let account_already_closed = get_account(handler, account_to_close)
+            .await?
+            .map_or(true, |account| {
+                account.data.is_empty() || account.lamports == 0
+            });
+        if account_already_closed {
+            info!("Account already closed: {:?}", account_to_close);
+            continue;
+        }
+
+        open_accounts.push(*account_to_close);
+    }
+
+    for accounts in open_accounts.chunks(EPOCH_ACCOUNTS_CLOSED_PER_TRANSACTION) {
+        let close_accounts: Vec<AccountMeta> = accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false))
+            .collect();
+
+        let close_all_epoch_accounts_ix = CloseAllEpochAccountsBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .ncn_fee_wallet(*ncn_config.fee_config.ncn_fee_wallet())
+            .epoch(epoch)
+            .add_remaining_accounts(&close_accounts)
+            .instruction();
+
+        let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+        send_and_log_transaction(
+            handler,
+            &[cul_ix, close_all_epoch_accounts_ix],
+            &[],
+            "Closed Epoch Accounts",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Accounts Closed: {:?}", accounts.len()),
+                format!("Epoch: {:?}", epoch),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+// --------------------- operator ------------------------------
+
+pub async fn operator_set_metadata(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    name: &str,
+    website: Option<&str>,
+    contact: Option<&str>,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (ncn_operator_state, _, _) =
+        NcnOperatorState::find_program_address(&handler.restaking_program_id, &ncn, operator);
+
+    let (operator_metadata, _, _) =
+        OperatorMetadata::find_program_address(&handler.ncn_program_id, operator, &ncn);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let name_bytes = zero_padded::<{ OperatorMetadata::NAME_LEN }>("Operator name", name)?;
+    let website_bytes = zero_padded::<{ OperatorMetadata::WEBSITE_LEN }>(
+        "Operator website",
+        website.unwrap_or_default(),
+    )?;
+    let contact_hash = contact.map_or([0; OperatorMetadata::CONTACT_HASH_LEN], |contact| {
+        hash(contact.as_bytes()).to_bytes()
+    });
+
+    let set_operator_metadata_ix = SetOperatorMetadataBuilder::new()
+        .ncn(ncn)
+        .operator(*operator)
+        .ncn_operator_state(ncn_operator_state)
+        .operator_metadata(operator_metadata)
+        .operator_admin(keypair.pubkey())
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .name(name_bytes)
+        .website(website_bytes)
+        .contact_hash(contact_hash)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[set_operator_metadata_ix],
+        &[],
+        "Set Operator Metadata",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Name: {}", name),
+            format!("Website: {}", website.unwrap_or("None")),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn operator_cast_vote(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+    weather_status: u8,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let operator = *operator;
+
+    let cast_vote_ix = sdk_instructions::cast_vote(
+        &handler.ncn_program_id,
+        &ncn,
+        &operator,
+        &keypair.pubkey(),
+        weather_status,
+        epoch,
+    );
+
+    // Simulated and signed only votes are not sent, they are not recorded
+    let vote_journal = handler
+        .vote_journal
+        .as_ref()
+        .filter(|_| !handler.dry_run && !handler.sign_only);
+    if let Some(vote_journal) = vote_journal {
+        vote_journal.record_vote(&ncn, &operator, epoch, weather_status)?;
+    }
+
+    let signature = send_and_log_landed_transaction(
+        handler,
+        &[cast_vote_ix],
+        &[],
+        "Cast Vote",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!(
+                "Weather Status: {:?}",
+                WeatherStatus::from_u8(weather_status)
+            ),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    if let (Some(vote_journal), Some(signature)) = (vote_journal, signature) {
+        if let Err(e) = vote_journal.record_signature(&ncn, &operator, epoch, &signature) {
+            warn!("Vote landed but {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+// --------------------- MIDDLEWARE ------------------------------
+
+pub async fn get_consensus_result_instruction(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let consensus_result = get_consensus_result(handler, epoch).await?;
+
     info!(
         "Consensus Result for epoch {}: {:?}",
         epoch, consensus_result
@@ -1354,7 +2568,13 @@ pub async fn get_or_create_ballot_box(handler: &CliHandler, epoch: u64) -> Resul
 
 // --------------------- CRANKERS ------------------------------
 
-pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
+/// Registers the vaults of the NCN missing from the vault registry. With `st_mint_weight` the
+/// supported mints of the new vaults are registered first with that weight, when the keypair is
+/// the NCN program admin.
+pub async fn crank_register_vaults(
+    handler: &CliHandler,
+    st_mint_weight: Option<u128>,
+) -> Result<()> {
     let all_ncn_vaults = get_all_vaults_in_ncn(handler).await?;
     let vault_registry = get_or_create_vault_registry(handler).await?;
     let all_registered_vaults: Vec<Pubkey> = vault_registry
@@ -1369,6 +2589,18 @@ pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
         .copied()
         .collect();
 
+    if let Some(st_mint_weight) = st_mint_weight {
+        if !vaults_to_register.is_empty() {
+            crank_register_st_mints(
+                handler,
+                &vault_registry,
+                &vaults_to_register,
+                st_mint_weight,
+            )
+            .await?;
+        }
+    }
+
     for vault in vaults_to_register.iter() {
         let result = register_vault(handler, vault).await;
 
@@ -1384,10 +2616,72 @@ pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
     Ok(())
 }
 
-pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
+/// Registers the supported mints of the vaults missing from the vault registry with the weight,
+/// vaults can only be registered once their mint is
+async fn crank_register_st_mints(
+    handler: &CliHandler,
+    vault_registry: &VaultRegistry,
+    vaults: &[Pubkey],
+    weight: u128,
+) -> Result<()> {
+    let ncn_account = get_ncn(handler).await?;
+    if ncn_account
+        .ncn_program_admin
+        .ne(&handler.keypair()?.pubkey())
+    {
+        warn!(
+            "Not registering the st mints of the new vaults, the keypair is not the NCN program admin {}",
+            ncn_account.ncn_program_admin
+        );
+        return Ok(());
+    }
+
+    let mut registered_st_mints: HashSet<Pubkey> = vault_registry
+        .get_valid_mint_entries()
+        .iter()
+        .map(|entry| *entry.st_mint())
+        .collect();
+
+    for vault in vaults.iter() {
+        let vault_account = get_vault(handler, vault).await?;
+        if !registered_st_mints.insert(vault_account.supported_mint) {
+            continue;
+        }
+
+        let result = admin_register_st_mint(handler, vault, Some(weight)).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to register st mint: {:?} of vault: {:?} with error: {:?}",
+                vault_account.supported_mint,
+                vault,
+                err
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Snapshots the operators and their delegations, skipping `skip_operators` whose snapshots are
+/// known to be finalized already
+///
+/// # Returns
+/// The operators whose snapshots were found finalized
+pub async fn crank_snapshot(
+    handler: &CliHandler,
+    epoch: u64,
+    skip_operators: &HashSet<Pubkey>,
+) -> Result<Vec<Pubkey>> {
+    load_epoch_lookup_table(handler, epoch).await;
     let vault_registry = get_vault_registry(handler).await?;
 
-    let operators = get_all_operators_in_ncn(handler).await?;
+    let operators: Vec<Pubkey> = get_all_operators_in_ncn(handler)
+        .await?
+        .into_iter()
+        .filter(|operator| !skip_operators.contains(operator))
+        .collect();
+    let mut finalized_operators = vec![];
     let all_vaults: Vec<Pubkey> = vault_registry
         .get_valid_vault_entries()
         .iter()
@@ -1396,7 +2690,37 @@ pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
 
     let epoch_snapshot = get_or_create_epoch_snapshot(handler, epoch).await?;
     if !epoch_snapshot.finalized() {
+        // Nothing lands in a dry run, there is no progress to persist
+        let progress_file = handler
+            .snapshot_progress_file
+            .as_deref()
+            .filter(|_| !handler.dry_run);
+        let progress = SnapshotProgress::load(progress_file, epoch)?;
+
+        // Create the missing operator snapshots in batches, leftovers are retried one by one below
+        let result = create_operator_snapshots(handler, &operators, epoch).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to batch create operator snapshots in epoch: {:?} with error: {:?}",
+                epoch,
+                err
+            );
+        }
+
+        let mut delegations_to_snapshot: Vec<(Pubkey, Pubkey)> = vec![];
         for operator in operators.iter() {
+            // Every delegation of the operator was snapshotted by an earlier run, which finalized
+            // its snapshot
+            if !all_vaults.is_empty()
+                && all_vaults
+                    .iter()
+                    .all(|vault| progress.is_delegation_snapshotted(operator, vault))
+            {
+                finalized_operators.push(*operator);
+                continue;
+            }
+
             // Create Vault Operator Delegation
             let result = get_or_create_operator_snapshot(handler, operator, epoch).await;
 
@@ -1412,36 +2736,58 @@ pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
 
             let operator_snapshot = result?;
 
-            let vaults_to_run: Vec<Pubkey> = all_vaults
-                .iter()
-                .filter(|vault| !operator_snapshot.contains_vault(vault))
-                .cloned()
-                .collect();
+            // Finalized snapshots have their vaults without delegation pruned
+            if operator_snapshot.finalized() {
+                finalized_operators.push(*operator);
+                continue;
+            }
 
-            for vault in vaults_to_run.iter() {
+            delegations_to_snapshot.extend(
+                all_vaults
+                    .iter()
+                    .filter(|vault| !operator_snapshot.contains_vault(vault))
+                    .filter(|vault| !progress.is_delegation_snapshotted(operator, vault))
+                    .map(|vault| (*operator, *vault)),
+            );
+        }
+
+        // Every vault is updated once, however many operators it is snapshotted for
+        let mut vaults_to_update: Vec<Pubkey> = delegations_to_snapshot
+            .iter()
+            .map(|(_, vault)| *vault)
+            .filter(|vault| !progress.is_vault_updated(vault))
+            .collect();
+        vaults_to_update.sort();
+        vaults_to_update.dedup();
+
+        // Up to `max_in_flight_transactions` vaults and delegations are cranked at once, each
+        // transaction with its own blockhash and confirmation
+        let progress = &progress;
+        stream::iter(vaults_to_update.iter())
+            .for_each_concurrent(handler.max_in_flight_transactions, |vault| async move {
                 let result = full_vault_update(handler, vault).await;
 
-                if let Err(err) = result {
-                    log::error!(
+                match result {
+                    std::result::Result::Ok(()) => progress.record_vault_updated(vault),
+                    Err(err) => log::error!(
                         "Failed to update the vault: {:?} with error: {:?}",
                         vault,
                         err
-                    );
+                    ),
                 }
+            })
+            .await;
 
-                let result =
-                    snapshot_vault_operator_delegation(handler, vault, operator, epoch).await;
+        let result =
+            snapshot_vault_operator_delegations(handler, &delegations_to_snapshot, epoch, progress)
+                .await;
 
-                if let Err(err) = result {
-                    log::error!(
-                    "Failed to snapshot vault operator delegation for vault: {:?} and operator: {:?} in epoch: {:?} with error: {:?}",
-                    vault,
-                    operator,
-                    epoch,
-                    err
-                );
-                }
-            }
+        if let Err(err) = result {
+            log::error!(
+                "Failed to snapshot vault operator delegations in epoch: {:?} with error: {:?}",
+                epoch,
+                err
+            );
         }
     }
 
@@ -1454,65 +2800,27 @@ pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
         );
     }
 
-    Ok(())
-}
-
-#[derive(Deserialize, Debug)]
-struct WeatherInfo {
-    main: String,
-}
-
-#[derive(Deserialize, Debug)]
-struct WeatherResponse {
-    weather: Vec<WeatherInfo>,
+    Ok(finalized_operators)
 }
 
-async fn get_weather_status(api_key: &str, city_name: &str) -> Result<u8> {
-    let url = format!(
-        "http://api.openweathermap.org/data/2.5/weather?q={}&appid={}&units=metric",
-        city_name, api_key
-    );
-
-    let response = reqwest::get(&url).await?.json::<WeatherResponse>().await?;
-
-    if let Some(weather_condition) = response.weather.get(0) {
-        match weather_condition.main.as_str() {
-            "Clear" => Ok(0),                                      // Sunny
-            "Rain" | "Snow" | "Drizzle" | "Thunderstorm" => Ok(2), // Raining/Snowing
-            _ => Ok(1),                                            // Anything else
-        }
-    } else {
-        Ok(1) // Default to "Anything else" if no weather info is available
-    }
-}
-
-/// Casts a vote for an operator based on the current weather in Solana Beach
+/// Casts a vote for an operator with the weather status decided for the epoch
 ///
 /// # Arguments
 /// * `handler` - CLI handler for RPC communication
 /// * `epoch` - Current epoch number
 /// * `operator` - Public key of the operator voting
+/// * `weather_status` - Weather status to vote
 ///
 /// # Returns
-/// * `Result<u8>` - Weather value that was voted (0:Sunny, 1:Other, 2:Rain/Snow)
+/// * `Result<u8>` - Weather value that was voted (0:Sunny, 1:Cloudy, 2:Rainy)
 pub async fn operator_crank_vote(
     handler: &CliHandler,
     epoch: u64,
     operator: &Pubkey,
+    weather_status: u8,
 ) -> Result<u8> {
-    // Get API key for weather service
-    let api_key = handler.open_weather_api_key()?;
-
-    // Fetch current weather status from OpenWeather API
-    let weather_value = get_weather_status(&api_key, "Solana Beach").await?;
-    info!(
-        "Current weather in Solana Beach (0:Sunny, 1:Other, 2:Rain/Snow): {}",
-        weather_value
-    );
-
-    // Cast the vote with the weather value
-    operator_cast_vote(handler, operator, epoch, weather_value).await?;
-    Ok(weather_value)
+    operator_cast_vote(handler, operator, epoch, weather_status).await?;
+    Ok(weather_status)
 }
 
 /// Logs detailed information about an operator's vote and ballot box state
@@ -1541,10 +2849,7 @@ pub async fn operator_crank_post_vote(
     // Check if operator voted and get their vote details
     let did_operator_vote = ballot_box.did_operator_vote(operator);
     let operator_vote = if did_operator_vote {
-        ballot_box
-            .operator_votes()
-            .iter()
-            .find(|v| v.operator().eq(&operator))
+        ballot_box.iter_votes().find(|v| v.operator().eq(&operator))
     } else {
         None
     };
@@ -1620,94 +2925,210 @@ pub async fn crank_test_vote(handler: &CliHandler, epoch: u64) -> Result<()> {
     Ok(())
 }
 
-pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<()> {
+/// Closes the epoch accounts of the given types. The epoch state is only closed along with every
+/// other type, once it is closed the epoch is marked closed for good and the accounts left open
+/// can not be closed anymore.
+pub async fn crank_close_epoch_accounts(
+    handler: &CliHandler,
+    epoch: u64,
+    account_types: &[EpochAccountType],
+) -> Result<()> {
     let ncn = *handler.ncn()?;
+    let closes = |account_type: EpochAccountType| account_types.contains(&account_type);
+
+    let closes_epoch_state = closes(EpochAccountType::EpochState)
+        && [
+            EpochAccountType::BallotBox,
+            EpochAccountType::OperatorSnapshot,
+            EpochAccountType::EpochSnapshot,
+            EpochAccountType::WeightTable,
+        ]
+        .into_iter()
+        .all(closes);
+    if closes(EpochAccountType::EpochState) && !closes_epoch_state {
+        info!(
+            "Keeping the epoch state of epoch {}, the accounts kept could not be closed after it",
+            epoch
+        );
+    }
 
     // Close NCN Reward Routers
     let operators = get_all_operators_in_ncn(handler).await?;
 
-    // Close Ballot Box
-    let (ballot_box, _, _) = BallotBox::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    // Close everything in batches first, leftovers are retried one by one below
+    {
+        let mut accounts_to_close = vec![];
+        if closes(EpochAccountType::BallotBox) {
+            accounts_to_close
+                .push(BallotBox::find_program_address(&handler.ncn_program_id, &ncn, epoch).0);
+        }
+        if closes(EpochAccountType::OperatorSnapshot) {
+            for operator in operators.iter() {
+                accounts_to_close.push(
+                    OperatorSnapshot::find_program_address(
+                        &handler.ncn_program_id,
+                        operator,
+                        &ncn,
+                        epoch,
+                    )
+                    .0,
+                );
+            }
+        }
+        if closes(EpochAccountType::EpochSnapshot) {
+            accounts_to_close
+                .push(EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch).0);
+        }
+        if closes(EpochAccountType::WeightTable) {
+            accounts_to_close
+                .push(WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch).0);
+        }
+        if closes_epoch_state {
+            accounts_to_close
+                .push(EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch).0);
+        }
 
-    let result = close_epoch_account(handler, ncn, epoch, ballot_box).await;
+        let result = close_all_epoch_accounts(handler, ncn, epoch, &accounts_to_close).await;
 
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close ballot box: {:?} in epoch: {:?} with error: {:?}",
-            ballot_box,
-            epoch,
-            err
-        );
+        if let Err(err) = result {
+            log::error!(
+                "Failed to batch close epoch accounts in epoch: {:?} with error: {:?}",
+                epoch,
+                err
+            );
+        }
     }
 
-    // Close Operator Snapshots
-    for operator in operators.iter() {
-        let (operator_snapshot, _, _) =
-            OperatorSnapshot::find_program_address(&handler.ncn_program_id, operator, &ncn, epoch);
+    // Close Ballot Box
+    if closes(EpochAccountType::BallotBox) {
+        let (ballot_box, _, _) =
+            BallotBox::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-        let result = close_epoch_account(handler, ncn, epoch, operator_snapshot).await;
+        let result = close_epoch_account(handler, ncn, epoch, ballot_box).await;
 
         if let Err(err) = result {
             log::error!(
-                "Failed to close operator snapshot: {:?} in epoch: {:?} with error: {:?}",
-                operator_snapshot,
+                "Failed to close ballot box: {:?} in epoch: {:?} with error: {:?}",
+                ballot_box,
                 epoch,
                 err
             );
         }
     }
 
+    // Close Operator Snapshots
+    if closes(EpochAccountType::OperatorSnapshot) {
+        for operator in operators.iter() {
+            let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+                &handler.ncn_program_id,
+                operator,
+                &ncn,
+                epoch,
+            );
+
+            let result = close_epoch_account(handler, ncn, epoch, operator_snapshot).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close operator snapshot: {:?} in epoch: {:?} with error: {:?}",
+                    operator_snapshot,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
     // Close Epoch Snapshot
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    if closes(EpochAccountType::EpochSnapshot) {
+        let (epoch_snapshot, _, _) =
+            EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let result = close_epoch_account(handler, ncn, epoch, epoch_snapshot).await;
+        let result = close_epoch_account(handler, ncn, epoch, epoch_snapshot).await;
 
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close epoch snapshot: {:?} in epoch: {:?} with error: {:?}",
-            epoch_snapshot,
-            epoch,
-            err
-        );
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close epoch snapshot: {:?} in epoch: {:?} with error: {:?}",
+                epoch_snapshot,
+                epoch,
+                err
+            );
+        }
     }
 
     // Close Weight Table
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    if closes(EpochAccountType::WeightTable) {
+        let (weight_table, _, _) =
+            WeightTable::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let result = close_epoch_account(handler, ncn, epoch, weight_table).await;
+        let result = close_epoch_account(handler, ncn, epoch, weight_table).await;
 
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close weight table: {:?} in epoch: {:?} with error: {:?}",
-            weight_table,
-            epoch,
-            err
-        );
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close weight table: {:?} in epoch: {:?} with error: {:?}",
+                weight_table,
+                epoch,
+                err
+            );
+        }
     }
 
     // Close Epoch State
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+    if closes_epoch_state {
+        let (epoch_state, _, _) =
+            EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let result = close_epoch_account(handler, ncn, epoch, epoch_state).await;
+        let result = close_epoch_account(handler, ncn, epoch, epoch_state).await;
 
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close epoch state: {:?} in epoch: {:?} with error: {:?}",
-            epoch_state,
-            epoch,
-            err
-        );
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close epoch state: {:?} in epoch: {:?} with error: {:?}",
+                epoch_state,
+                epoch,
+                err
+            );
+        }
     }
 
     Ok(())
 }
 
+/// Bootstraps the accounts of an epoch without an epoch state, or marks the epoch as skipped once
+/// it stalled without ever being started
+pub async fn crank_create_or_skip_epoch(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let config = get_ncn_program_config(handler).await?;
+    let (current_epoch, _) = get_guaranteed_epoch_and_slot(handler).await;
+
+    let can_skip = EpochMarker::check_can_skip(
+        epoch,
+        current_epoch,
+        config.starting_valid_epoch(),
+        config.epochs_before_stall(),
+    )
+    .is_ok();
+
+    if can_skip {
+        info!("Epoch {} was missed, marking it as skipped", epoch);
+        return mark_epoch_skipped(handler, epoch).await;
+    }
+
+    create_epoch_accounts(handler, epoch).await
+}
+
 pub async fn crank_set_weight(handler: &CliHandler, epoch: u64) -> Result<()> {
     create_weight_table(handler, epoch).await?;
-    set_epoch_weights(handler, epoch).await?;
+
+    // Stable registries can reuse the previous epoch's weights
+    let result = copy_previous_epoch_weights(handler, epoch).await;
+    if let Err(err) = result {
+        info!(
+            "Could not copy previous epoch weights for epoch {}, setting them: {:?}",
+            epoch, err
+        );
+        set_epoch_weights(handler, epoch).await?;
+    }
+
+    set_weights_from_switchboard(handler, epoch).await?;
     Ok(())
 }
 
@@ -1722,7 +3143,64 @@ pub async fn crank_post_vote_cooldown(handler: &CliHandler, epoch: u64) -> Resul
 }
 
 pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
+    crank_route_and_distribute(handler, epoch, true, true, &HashSet::new())
+        .await
+        .map(|_| ())
+}
+
+/// Drives the whole reward waterfall of the epoch: the reward routers are created, the NCN rewards
+/// routed and distributed to the protocol, the NCN, the fee groups and the operators, whose rewards
+/// are routed and distributed to the operators and their vaults. Rewards arriving at a receiver
+/// while a pass runs are picked up by the next one, passes repeat until every reward receiver is
+/// empty.
+///
+/// Fails when rewards are still left in the receivers after `max_passes`.
+pub async fn crank_distribute_until_empty(
+    handler: &CliHandler,
+    epoch: u64,
+    max_passes: u64,
+) -> Result<()> {
+    let mut rewards_left = get_total_rewards_to_be_distributed(handler, epoch).await?;
+    info!("Rewards to distribute in epoch {}: {}", epoch, rewards_left);
+
+    for pass in 1..=max_passes {
+        crank_distribute(handler, epoch).await?;
+
+        rewards_left = get_total_rewards_to_be_distributed(handler, epoch).await?;
+        info!(
+            "Pass {}/{}: rewards left to distribute in epoch {}: {}",
+            pass, max_passes, epoch, rewards_left
+        );
+
+        if rewards_left == 0 {
+            return Ok(());
+        }
+    }
+
+    Err(anyhow!(
+        "Rewards of epoch {} are not distributed after {} passes, {} left",
+        epoch,
+        max_passes,
+        rewards_left
+    ))
+}
+
+/// Routes and distributes the rewards of the epoch, `route` and `distribute` select the steps so
+/// keepers can split the two between them. The reward routers are created by either.
+/// `skip_operators` are known to have nothing to route or distribute.
+///
+/// # Returns
+/// The operators that had nothing to route or distribute, when both steps are run
+pub async fn crank_route_and_distribute(
+    handler: &CliHandler,
+    epoch: u64,
+    route: bool,
+    distribute: bool,
+    skip_operators: &HashSet<Pubkey>,
+) -> Result<Vec<Pubkey>> {
+    load_epoch_lookup_table(handler, epoch).await;
     let operators = get_all_operators_in_ncn(handler).await?;
+    let mut idle_operators = vec![];
 
     let ncn_reward_router = get_or_create_ncn_reward_router(handler, epoch).await?;
 
@@ -1731,12 +3209,12 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
         "NCN Reward Receiver Rewards for epoch {}: {}",
         epoch, ncn_reward_receiver_rewards
     );
-    if ncn_reward_receiver_rewards > 0 {
+    if route && ncn_reward_receiver_rewards > 0 {
         route_ncn_rewards(handler, epoch).await?;
     }
 
     // NCN Rewards Distribution
-    {
+    if distribute {
         let result = distribute_ncn_rewards(handler, epoch).await;
 
         if let Err(err) = result {
@@ -1749,7 +3227,7 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
     }
 
     // Protocol Rewards Distribution
-    {
+    if distribute {
         let result = distribute_protocol_rewards(handler, epoch).await;
         if let Err(err) = result {
             log::error!(
@@ -1760,7 +3238,30 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
         }
     }
 
-    for operator in operators.iter() {
+    // Custom Fee Group Rewards Distribution
+    if distribute {
+        let ncn_config = get_ncn_program_config(handler).await?;
+        for (group, fee_group) in ncn_config.fee_config.fee_groups().iter().enumerate() {
+            if fee_group.is_empty() {
+                continue;
+            }
+
+            let result = distribute_fee_group_rewards(handler, group as u8, epoch).await;
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to distribute fee group {} rewards in epoch: {:?} with error: {:?}",
+                    group,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    for operator in operators
+        .iter()
+        .filter(|operator| !skip_operators.contains(operator))
+    {
         let result = get_or_create_operator_vault_reward_router(handler, operator, epoch).await;
         if let Err(err) = result {
             log::info!(
@@ -1784,8 +3285,9 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
 
         let operator_route = operator_route_result.unwrap();
         let has_rewards = operator_route.has_rewards().unwrap_or(false);
+        let mut idle = !has_rewards;
 
-        if has_rewards {
+        if distribute && has_rewards {
             let result = distribute_operator_vault_rewards(handler, operator, epoch).await;
 
             if let Err(err) = result {
@@ -1799,8 +3301,12 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
             }
         }
 
-        let operator_vault_reward_receiver_rewards =
-            get_operator_vault_reward_receiver_rewards(handler, operator, epoch).await?;
+        let operator_vault_reward_receiver_rewards = if route {
+            get_operator_vault_reward_receiver_rewards(handler, operator, epoch).await?
+        } else {
+            0
+        };
+        idle &= operator_vault_reward_receiver_rewards == 0;
 
         if operator_vault_reward_receiver_rewards > 0 {
             let result = route_operator_vault_rewards(handler, operator, epoch).await;
@@ -1828,6 +3334,10 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
         }
         let operator_vault_reward_router = result?;
 
+        if !distribute {
+            continue;
+        }
+
         if operator_vault_reward_router.operator_rewards() != 0 {
             let result = distribute_ncn_operator_rewards(handler, operator, epoch).await;
 
@@ -1849,6 +3359,14 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
             .map(|route| route.vault())
             .collect::<Vec<Pubkey>>();
 
+        if route
+            && idle
+            && operator_vault_reward_router.operator_rewards() == 0
+            && vaults_to_route.is_empty()
+        {
+            idle_operators.push(*operator);
+        }
+
         for vault in vaults_to_route {
             let result: std::result::Result<(), anyhow::Error> =
                 distribute_ncn_vault_rewards(handler, &vault, operator, epoch).await;
@@ -1865,7 +3383,7 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
         }
     }
 
-    Ok(())
+    Ok(idle_operators)
 }
 
 // --------------------- Reward Routing Functions ------------------------------
@@ -1897,6 +3415,7 @@ pub async fn create_ncn_reward_router(handler: &CliHandler, epoch: u64) -> Resul
             .ncn_reward_receiver(ncn_reward_receiver)
             .ncn(ncn)
             .epoch(epoch)
+            .init_if_needed(true)
             .account_payer(account_payer)
             .system_program(system_program::id())
             .instruction();
@@ -1927,13 +3446,13 @@ pub async fn create_ncn_reward_router(handler: &CliHandler, epoch: u64) -> Resul
         .system_program(system_program::id())
         .instruction();
 
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_ncn_reward_router_ix.clone());
-    }
+    let realloc_ixs =
+        vec![
+            BudgetedInstruction::new(realloc_ncn_reward_router_ix, REALLOC_COMPUTE_UNITS);
+            num_reallocs as usize
+        ];
 
-    send_and_log_transaction(
+    send_packed_transactions(
         handler,
         &realloc_ixs,
         &[],
@@ -1997,6 +3516,7 @@ pub async fn create_operator_vault_reward_router(
                 .ncn(ncn)
                 .operator(operator)
                 .epoch(epoch)
+                .init_if_needed(true)
                 .account_payer(account_payer)
                 .system_program(system_program::id())
                 .instruction();
@@ -2035,6 +3555,36 @@ pub async fn route_ncn_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
 pub async fn process_route_ncn_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
+    let keypair = handler.keypair()?;
+
+    let route_ncn_rewards_ix = sdk_instructions::route_ncn_rewards(
+        &handler.ncn_program_id,
+        &ncn,
+        &keypair.pubkey(),
+        epoch,
+    );
+
+    let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    send_and_log_transaction(
+        handler,
+        &[cul_ix, route_ncn_rewards_ix],
+        &[],
+        "Routed NCN Rewards",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn route_and_distribute_ncn_rewards(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
     let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
     let (epoch_state, _, _) =
@@ -2051,7 +3601,35 @@ pub async fn process_route_ncn_rewards(handler: &CliHandler, epoch: u64) -> Resu
     let (epoch_snapshot, _, _) =
         EpochSnapshot::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-    let route_ncn_rewards_ix = RouteNCNRewardsBuilder::new()
+    let ncn_config = get_ncn_program_config(handler).await?;
+
+    let operator_route_accounts: Vec<AccountMeta> = operators
+        .iter()
+        .flat_map(|operator| {
+            let (operator_vault_reward_router, _, _) =
+                OperatorVaultRewardRouter::find_program_address(
+                    &handler.ncn_program_id,
+                    operator,
+                    &ncn,
+                    epoch,
+                );
+            let (operator_vault_reward_receiver, _, _) =
+                OperatorVaultRewardReceiver::find_program_address(
+                    &handler.ncn_program_id,
+                    operator,
+                    &ncn,
+                    epoch,
+                );
+
+            [
+                AccountMeta::new_readonly(*operator, false),
+                AccountMeta::new_readonly(operator_vault_reward_router, false),
+                AccountMeta::new(operator_vault_reward_receiver, false),
+            ]
+        })
+        .collect();
+
+    let route_and_distribute_ix = RouteAndDistributeNCNRewardsBuilder::new()
         .epoch_state(epoch_state)
         .config(config)
         .ncn(ncn)
@@ -2059,17 +3637,51 @@ pub async fn process_route_ncn_rewards(handler: &CliHandler, epoch: u64) -> Resu
         .ballot_box(ballot_box)
         .ncn_reward_router(ncn_reward_router)
         .ncn_reward_receiver(ncn_reward_receiver)
+        .protocol_fee_wallet(*ncn_config.fee_config.protocol_fee_wallet())
+        .ncn_fee_wallet(*ncn_config.fee_config.ncn_fee_wallet())
         .max_iterations(NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS)
         .epoch(epoch)
+        .add_remaining_accounts(&operator_route_accounts)
         .instruction();
 
     let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
 
     send_and_log_transaction(
         handler,
-        &[cul_ix, route_ncn_rewards_ix],
+        &[cul_ix, route_and_distribute_ix],
         &[],
-        "Routed NCN Rewards",
+        "Routed and Distributed NCN Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epoch: {:?}", epoch),
+            format!("Operators: {:?}", operators),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn shrink_ncn_reward_router(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (ncn_reward_router, _, _) =
+        NCNRewardRouter::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) = AccountPayer::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let shrink_ncn_reward_router_ix = ShrinkNCNRewardRouterBuilder::new()
+        .ncn(ncn)
+        .ncn_reward_router(ncn_reward_router)
+        .account_payer(account_payer)
+        .epoch(epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[shrink_ncn_reward_router_ix],
+        &[],
+        "Shrunk NCN Reward Router",
         &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
     )
     .await?;
@@ -2116,7 +3728,11 @@ pub async fn distribute_ncn_rewards(handler: &CliHandler, epoch: u64) -> Result<
     Ok(())
 }
 
-pub async fn distribute_protocol_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn distribute_fee_group_rewards(
+    handler: &CliHandler,
+    group: u8,
+    epoch: u64,
+) -> Result<()> {
     let ncn = *handler.ncn()?;
 
     let (ncn_config_address, _, _) =
@@ -2132,44 +3748,89 @@ pub async fn distribute_protocol_rewards(handler: &CliHandler, epoch: u64) -> Re
         NCNRewardReceiver::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
     let ncn_config = get_ncn_program_config(handler).await?;
+    let fee_group_wallet = *ncn_config.fee_config.fee_group_wallet(group as usize)?;
 
-    let distribute_protocol_rewards_ix = DistributeProtocolRewardsBuilder::new()
+    let distribute_fee_group_rewards_ix = DistributeFeeGroupRewardsBuilder::new()
         .epoch_state(epoch_state)
         .config(ncn_config_address)
         .ncn(ncn)
         .ncn_reward_router(ncn_reward_router)
         .ncn_reward_receiver(ncn_reward_receiver)
-        .protocol_fee_wallet(*ncn_config.fee_config.protocol_fee_wallet())
+        .fee_group_wallet(fee_group_wallet)
+        .group(group)
         .epoch(epoch)
-        .system_program(system_program::id())
         .instruction();
 
     send_and_log_transaction(
         handler,
-        &[distribute_protocol_rewards_ix],
+        &[distribute_fee_group_rewards_ix],
         &[],
-        "Distributed Protocol Rewards",
-        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+        "Distributed Fee Group Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Group: {:?}", group),
+            format!("Epoch: {:?}", epoch),
+        ],
     )
     .await?;
 
     Ok(())
 }
 
-pub async fn route_operator_vault_rewards(
-    handler: &CliHandler,
-    operator: &Pubkey,
-    epoch: u64,
-) -> Result<()> {
-    let mut still_routing = true;
+pub async fn distribute_protocol_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
 
-    while still_routing {
-        process_route_operator_vault_rewards(handler, operator, epoch).await?;
+    let (ncn_config_address, _, _) =
+        NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
 
-        let ncn_reward_router_account =
-            get_operator_vault_reward_router(handler, operator, epoch).await?;
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
-        still_routing = ncn_reward_router_account.still_routing();
+    let (ncn_reward_router, _, _) =
+        NCNRewardRouter::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let (ncn_reward_receiver, _, _) =
+        NCNRewardReceiver::find_program_address(&handler.ncn_program_id, &ncn, epoch);
+
+    let ncn_config = get_ncn_program_config(handler).await?;
+
+    let distribute_protocol_rewards_ix = DistributeProtocolRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config_address)
+        .ncn(ncn)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .protocol_fee_wallet(*ncn_config.fee_config.protocol_fee_wallet())
+        .epoch(epoch)
+        .system_program(system_program::id())
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[distribute_protocol_rewards_ix],
+        &[],
+        "Distributed Protocol Rewards",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn route_operator_vault_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let mut still_routing = true;
+
+    while still_routing {
+        process_route_operator_vault_rewards(handler, operator, epoch).await?;
+
+        let ncn_reward_router_account =
+            get_operator_vault_reward_router(handler, operator, epoch).await?;
+
+        still_routing = ncn_reward_router_account.still_routing();
     }
 
     Ok(())
@@ -2204,6 +3865,10 @@ pub async fn process_route_operator_vault_rewards(
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.ncn_program_id, &ncn, epoch);
 
+    let (config, _, _) = NCNProgramConfig::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let keypair = handler.keypair()?;
+
     let route_operator_vault_rewards_ix = RouteOperatorVaultRewardsBuilder::new()
         .ncn(ncn)
         .epoch_state(epoch_state)
@@ -2211,15 +3876,16 @@ pub async fn process_route_operator_vault_rewards(
         .operator_snapshot(operator_snapshot)
         .operator_vault_reward_router(operator_vault_reward_router)
         .operator_vault_reward_receiver(operator_vault_reward_receiver)
+        .config(Some(config))
+        .cranker(Some(keypair.pubkey()))
+        .system_program(Some(system_program::id()))
         .epoch(epoch)
         .max_iterations(OperatorVaultRewardRouter::MAX_ROUTE_NCN_ITERATIONS)
         .instruction();
 
-    let cul_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
-
     send_and_log_transaction(
         handler,
-        &[cul_ix, route_operator_vault_rewards_ix],
+        &[route_operator_vault_rewards_ix],
         &[],
         "Routed Operator Vault Rewards",
         &[
@@ -2389,6 +4055,13 @@ pub async fn distribute_ncn_vault_rewards(
         epoch,
     );
 
+    let (vault_registry, _, _) = VaultRegistry::find_program_address(&handler.ncn_program_id, &ncn);
+
+    let vault_reward_recipient = *get_vault_registry(handler)
+        .await?
+        .get_vault_entry(&vault)?
+        .reward_destination();
+
     let distribute_vault_rewards_ix = DistributeVaultRewardsBuilder::new()
         .epoch_state(epoch_state)
         .config(config)
@@ -2400,6 +4073,9 @@ pub async fn distribute_ncn_vault_rewards(
         .operator_vault_reward_receiver(operator_vault_reward_receiver)
         .epoch(epoch)
         .system_program(system_program::id())
+        .vault_registry(vault_registry)
+        .vault_reward_recipient(vault_reward_recipient)
+        .token_program(spl_token::id())
         .instruction();
 
     send_and_log_transaction(
@@ -2410,6 +4086,7 @@ pub async fn distribute_ncn_vault_rewards(
         &[
             format!("NCN: {:?}", ncn),
             format!("Vault: {:?}", vault),
+            format!("Reward Recipient: {:?}", vault_reward_recipient),
             format!("Operator: {:?}", operator),
             format!("Epoch: {:?}", epoch),
         ],
@@ -2421,6 +4098,72 @@ pub async fn distribute_ncn_vault_rewards(
 
 // --------------------- HELPERS -------------------------
 
+/// Sends admin instructions or, when the NCN admin is a Squads vault, proposes them to its
+/// multisig as the next vault transaction and approves it with the keypair. The other members then
+/// approve and execute it from their Squads tooling.
+pub async fn send_and_log_admin_transaction(
+    handler: &CliHandler,
+    instructions: &[Instruction],
+    signing_keypairs: &[&Keypair],
+    title: &str,
+    log_items: &[String],
+) -> Result<()> {
+    if handler.as_proposal {
+        if !signing_keypairs.is_empty() {
+            return Err(anyhow!("Admin approvers can not sign a proposal"));
+        }
+
+        let proposal = AdminProposal::new(&handler.admin()?, instructions, title, log_items)?;
+        info!("Printing {} as a proposal", title);
+        println!("{}", serde_json::to_string_pretty(&proposal)?);
+        return Ok(());
+    }
+
+    let Some(multisig) = handler.squads_multisig else {
+        return send_and_log_transaction(handler, instructions, signing_keypairs, title, log_items)
+            .await;
+    };
+
+    if !signing_keypairs.is_empty() {
+        return Err(anyhow!(
+            "Admin approvers can not sign a Squads vault transaction"
+        ));
+    }
+
+    let keypair = handler.keypair()?;
+    let multisig_account = handler.rpc_client().get_account(&multisig).await?;
+    if multisig_account.owner != squads::SQUADS_PROGRAM_ID {
+        return Err(anyhow!("{} is not a Squads multisig", multisig));
+    }
+
+    let transaction_index = squads::multisig_transaction_index(&multisig_account.data)? + 1;
+    let proposal_instructions = squads::propose_vault_transaction(
+        &multisig,
+        transaction_index,
+        handler.squads_vault_index,
+        &keypair.pubkey(),
+        instructions,
+        Some(title.to_string()),
+    )?;
+
+    let mut log_items = log_items.to_vec();
+    log_items.push(format!("Squads Multisig: {:?}", multisig));
+    log_items.push(format!("Transaction Index: {:?}", transaction_index));
+    log_items.push(format!(
+        "Proposal: {:?}",
+        squads::proposal_address(&multisig, transaction_index)
+    ));
+
+    send_and_log_transaction(
+        handler,
+        &proposal_instructions,
+        &[],
+        &format!("Proposed {}", title),
+        &log_items,
+    )
+    .await
+}
+
 pub async fn send_and_log_transaction(
     handler: &CliHandler,
     instructions: &[Instruction],
@@ -2428,91 +4171,818 @@ pub async fn send_and_log_transaction(
     title: &str,
     log_items: &[String],
 ) -> Result<()> {
+    send_and_log_landed_transaction(handler, instructions, signing_keypairs, title, log_items)
+        .await
+        .map(|_| ())
+}
+
+/// Sends and logs the transaction like [`send_and_log_transaction`], returning its signature when
+/// it landed. Dry runs, signed only transactions and transactions already processed have none.
+pub async fn send_and_log_landed_transaction(
+    handler: &CliHandler,
+    instructions: &[Instruction],
+    signing_keypairs: &[&Keypair],
+    title: &str,
+    log_items: &[String],
+) -> Result<Option<Signature>> {
+    if handler.dry_run {
+        simulate_and_log_transaction(handler, instructions, signing_keypairs, title, log_items)
+            .await?;
+        return Ok(None);
+    }
+
     sleep(Duration::from_secs(1)).await;
 
-    let signature = send_transactions(handler, instructions, signing_keypairs).await?;
+    let signature = match send_transactions(handler, instructions, signing_keypairs).await {
+        std::result::Result::Ok(signature) => signature,
+        Err(e) => match e.downcast_ref::<AlreadyProcessed>() {
+            Some(already_processed) => {
+                warn!(
+                    "\n\n---------- {} ----------\nAlready processed: {}\n{}\n",
+                    title,
+                    already_processed,
+                    log_items.join("\n")
+                );
+                record_transaction(
+                    handler,
+                    title,
+                    instructions,
+                    None,
+                    JournalResult::AlreadyProcessed,
+                    Some(already_processed.to_string()),
+                )
+                .await;
+                return Ok(None);
+            }
+            None => {
+                if !handler.sign_only {
+                    record_transaction(
+                        handler,
+                        title,
+                        instructions,
+                        None,
+                        JournalResult::Failed,
+                        Some(e.to_string().trim().to_string()),
+                    )
+                    .await;
+                }
+                return Err(e);
+            }
+        },
+    };
+
+    if handler.sign_only {
+        log_transaction(&format!("Signed {}", title), signature, log_items);
+        return Ok(None);
+    }
 
+    handler.transactions_sent.fetch_add(1, Ordering::Relaxed);
     log_transaction(title, signature, log_items);
+    record_transaction(
+        handler,
+        title,
+        instructions,
+        Some(&signature),
+        JournalResult::Landed,
+        None,
+    )
+    .await;
+
+    Ok(Some(signature))
+}
+
+/// A transaction failed because its work was already done, e.g. by another keeper. It is not
+/// retried and the crank moves on as if it succeeded.
+#[derive(Debug, thiserror::Error)]
+#[error("{}", .0.describe())]
+pub struct AlreadyProcessed(pub NCNProgramError);
+
+/// The [`AlreadyProcessed`] error of a failed transaction, `None` for any other failure
+fn as_already_processed(
+    handler: &CliHandler,
+    message: &VersionedMessage,
+    error: &ClientError,
+) -> Option<AlreadyProcessed> {
+    let transaction_error = error.get_transaction_error()?;
+    already_processed_error(message, &handler.ncn_program_id, &transaction_error)
+        .map(AlreadyProcessed)
+}
+
+/// Sends independent instructions in as few transactions as their size and compute units allow,
+/// each transaction is logged with its position among them
+pub async fn send_packed_transactions(
+    handler: &CliHandler,
+    instructions: &[BudgetedInstruction],
+    signing_keypairs: &[&Keypair],
+    title: &str,
+    log_items: &[String],
+) -> Result<()> {
+    let transactions = pack_transactions(handler, instructions)?;
+    let num_transactions = transactions.len();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let mut items = log_items.to_vec();
+        items.push(format!("Transaction: {}/{}", index + 1, num_transactions));
+
+        send_and_log_transaction(handler, transaction, signing_keypairs, title, &items).await?;
+    }
+
+    Ok(())
+}
+
+/// Packs the instructions into transactions paid by the keypair, leaving room for the Jito tip
+/// when transactions are sent as bundles
+pub fn pack_transactions(
+    handler: &CliHandler,
+    instructions: &[BudgetedInstruction],
+) -> Result<Vec<Vec<Instruction>>> {
+    let payer = handler.keypair()?.pubkey();
+
+    let mut packer = TransactionPacker::new(&payer);
+    // Nonce and bundled transactions are sent as legacy transactions
+    if handler.nonce_account.is_none() && handler.jito_block_engine_url.is_none() {
+        packer = packer.versioned(handler.lookup_tables());
+    }
+    if handler.jito_block_engine_url.is_some() {
+        packer = packer.reserve(transfer(
+            &payer,
+            &JITO_TIP_ACCOUNTS[0],
+            handler.jito_tip_lamports,
+        ));
+    }
+
+    packer.pack(instructions)
+}
+
+/// Simulates the transaction instead of sending it, logging its compute units and the changes to
+/// the accounts it writes. Fails like a sent transaction would when the simulation fails.
+pub async fn simulate_and_log_transaction(
+    handler: &CliHandler,
+    instructions: &[Instruction],
+    signing_keypairs: &[&Keypair],
+    title: &str,
+    log_items: &[String],
+) -> Result<()> {
+    let client = handler.rpc_client();
+    let keypair = handler.keypair()?;
+
+    let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+        handler.priority_fee_micro_lamports,
+    )];
+    all_instructions.extend_from_slice(instructions);
+
+    let all_signers = transaction_signers(keypair, signing_keypairs);
+
+    let blockhash = client.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(
+        &all_instructions,
+        Some(&keypair.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+
+    let writable_accounts: Vec<Pubkey> = tx
+        .message
+        .account_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| tx.message.is_maybe_writable(*index, None))
+        .map(|(_, address)| *address)
+        .collect();
+    let accounts_before = client.get_multiple_accounts(&writable_accounts).await?;
+
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        commitment: Some(client.commitment()),
+        accounts: Some(RpcSimulateTransactionAccountsConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            addresses: writable_accounts
+                .iter()
+                .map(|address| address.to_string())
+                .collect(),
+        }),
+        ..RpcSimulateTransactionConfig::default()
+    };
+    let simulation = client
+        .simulate_transaction_with_config(&tx, config)
+        .await?
+        .value;
+
+    if let std::result::Result::Ok(mut simulated_transactions) =
+        handler.simulated_transactions.lock()
+    {
+        simulated_transactions.push(SimulatedTransaction {
+            title: title.to_string(),
+            compute_units: simulation.units_consumed,
+            error: simulation.err.as_ref().map(|err| format!("{:?}", err)),
+        });
+    }
+
+    let mut log_message = format!(
+        "\n\n---------- [DRY RUN] {} ----------\nCompute Units: {}",
+        title,
+        simulation
+            .units_consumed
+            .map_or("Unknown".to_string(), |units| units.to_string())
+    );
+
+    for item in log_items {
+        log_message.push_str(&format!("\n{}", item));
+    }
+
+    let accounts_after = simulation.accounts.unwrap_or_default();
+    log_message.push_str("\nAccount Changes:");
+    for (index, address) in writable_accounts.iter().enumerate() {
+        let before = accounts_before.get(index).cloned().flatten();
+        let after = accounts_after
+            .get(index)
+            .cloned()
+            .flatten()
+            .and_then(|account| account.decode::<Account>());
+
+        log_message.push_str(&format!(
+            "\n  {}: {}",
+            address,
+            describe_account_change(before.as_ref(), after.as_ref())
+        ));
+    }
+
+    if let Some(err) = simulation.err {
+        for log in simulation.logs.unwrap_or_default() {
+            log_message.push_str(&format!("\n  {}", log));
+        }
+        log::error!("{}\n", log_message);
+
+        return Err(anyhow!("Simulation of {} failed: {:?}", title, err));
+    }
+
+    log_message.push('\n');
+    info!("{}", log_message);
 
     Ok(())
 }
 
+/// One line summary of how a transaction changes an account, accounts left without lamports are
+/// closed
+fn describe_account_change(before: Option<&Account>, after: Option<&Account>) -> String {
+    let before = before.filter(|account| account.lamports > 0);
+    let after = after.filter(|account| account.lamports > 0);
+
+    match (before, after) {
+        (None, None) => "No account".to_string(),
+        (None, Some(after)) => format!(
+            "Created with {} lamports and {} bytes",
+            after.lamports,
+            after.data.len()
+        ),
+        (Some(before), None) => format!("Closed, refunding {} lamports", before.lamports),
+        (Some(before), Some(after)) if before == after => "Unchanged".to_string(),
+        (Some(before), Some(after)) => {
+            let mut change = format!("Lamports {} -> {}", before.lamports, after.lamports);
+            if before.data.len() != after.data.len() {
+                change.push_str(&format!(
+                    ", size {} -> {} bytes",
+                    before.data.len(),
+                    after.data.len()
+                ));
+            } else if before.data != after.data {
+                change.push_str(", data changed");
+            }
+            change
+        }
+    }
+}
+
+/// Compute units left on top of the estimate for the instructions added when sending, the nonce
+/// advance or the Jito tip
+const COMPUTE_UNIT_HEADROOM: u64 = 5_000;
+
+/// Compute units every instruction gets when the transaction sets no limit
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// The instructions under a new compute unit limit, replacing the one they set
+fn with_compute_unit_limit(
+    instructions: &[Instruction],
+    compute_unit_limit: u32,
+) -> Vec<Instruction> {
+    let mut limited_instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+        compute_unit_limit,
+    )];
+    limited_instructions.extend(
+        instructions
+            .iter()
+            // `SetComputeUnitLimit` is the compute budget instruction with tag 2
+            .filter(|instruction| {
+                !(compute_budget::check_id(&instruction.program_id)
+                    && instruction.data.first() == Some(&2))
+            })
+            .cloned(),
+    );
+    limited_instructions
+}
+
+/// Compute unit limit the instructions run under, the one they set or else the default limit of
+/// every instruction
+fn instructions_compute_unit_limit(instructions: &[Instruction]) -> u32 {
+    let set_limit = instructions.iter().find_map(|instruction| {
+        // `SetComputeUnitLimit` is the compute budget instruction with tag 2
+        if !compute_budget::check_id(&instruction.program_id)
+            || instruction.data.first() != Some(&2)
+        {
+            return None;
+        }
+        let limit = instruction.data.get(1..5)?.try_into().ok()?;
+        Some(u32::from_le_bytes(limit))
+    });
+
+    set_limit.unwrap_or_else(|| {
+        let budgeted_instructions = instructions
+            .iter()
+            .filter(|instruction| !compute_budget::check_id(&instruction.program_id))
+            .count() as u32;
+        budgeted_instructions
+            .saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+            .min(MAX_COMPUTE_UNITS_PER_TRANSACTION)
+    })
+}
+
+/// Replaces the compute unit limit of the instructions by the compute units they consume in a
+/// simulation, plus `--compute-unit-margin` percent. The instructions are left as they are with
+/// `--skip-compute-unit-estimate` or when the simulation fails, so the transaction is sent and
+/// fails with the error of the program.
+async fn with_estimated_compute_unit_limit(
+    handler: &CliHandler,
+    instructions: &[Instruction],
+) -> Vec<Instruction> {
+    let Some(compute_unit_margin) = handler.compute_unit_margin else {
+        return instructions.to_vec();
+    };
+
+    match estimate_compute_units(handler, instructions).await {
+        std::result::Result::Ok(compute_units) => {
+            let margin = compute_units as f64 * compute_unit_margin as f64 / 100.0;
+            let compute_unit_limit = (compute_units + margin.ceil() as u64 + COMPUTE_UNIT_HEADROOM)
+                .min(MAX_COMPUTE_UNITS_PER_TRANSACTION as u64);
+            info!(
+                "Consumed {} compute units in simulation, limit set to {}",
+                compute_units, compute_unit_limit
+            );
+
+            with_compute_unit_limit(instructions, compute_unit_limit as u32)
+        }
+        Err(e) => {
+            info!("Could not estimate the compute units: {}", e);
+            instructions.to_vec()
+        }
+    }
+}
+
+/// Compute units the instructions consume in a simulation under the maximum limit
+async fn estimate_compute_units(handler: &CliHandler, instructions: &[Instruction]) -> Result<u64> {
+    let client = handler.rpc_client();
+    let payer = handler.keypair()?.pubkey();
+
+    let simulated_instructions =
+        with_compute_unit_limit(instructions, MAX_COMPUTE_UNITS_PER_TRANSACTION);
+
+    // Neither signed nor given a blockhash, the RPC replaces it
+    let message = v0_message(handler, &payer, &simulated_instructions, Hash::default())?;
+    let tx = VersionedTransaction {
+        signatures: vec![Signature::default(); message.header().num_required_signatures as usize],
+        message,
+    };
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: true,
+        commitment: Some(client.commitment()),
+        ..RpcSimulateTransactionConfig::default()
+    };
+    let simulation = client
+        .simulate_transaction_with_config(&tx, config)
+        .await?
+        .value;
+
+    if let Some(err) = simulation.err {
+        return Err(anyhow!("Simulation failed: {:?}", err));
+    }
+
+    simulation
+        .units_consumed
+        .ok_or_else(|| anyhow!("The RPC did not return the compute units consumed"))
+}
+
+/// Compiles the instructions into a v0 message, loading the accounts found in the lookup tables
+/// of `--address-lookup-tables` and of the loaded epochs from them
+fn v0_message(
+    handler: &CliHandler,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    blockhash: Hash,
+) -> Result<VersionedMessage> {
+    let message =
+        v0::Message::try_compile(payer, instructions, &handler.lookup_tables(), blockhash)?;
+    Ok(VersionedMessage::V0(message))
+}
+
+/// Sends the instructions in a v0 transaction, after setting their compute unit limit to the
+/// simulated compute units
 pub async fn send_transactions(
     handler: &CliHandler,
     instructions: &[Instruction],
     signing_keypairs: &[&Keypair],
 ) -> Result<Signature> {
+    // Signed offline, the accounts may be different once sent
+    let instructions = if handler.sign_only {
+        instructions.to_vec()
+    } else {
+        with_estimated_compute_unit_limit(handler, instructions).await
+    };
+    let instructions = instructions.as_slice();
+
+    if let Some(nonce_account) = handler.nonce_account {
+        return send_nonce_transaction(handler, &nonce_account, instructions, signing_keypairs)
+            .await;
+    }
+
+    if let Some(block_engine_url) = handler.jito_block_engine_url.as_deref() {
+        return send_bundle(handler, block_engine_url, instructions, signing_keypairs).await;
+    }
+
     let client = handler.rpc_client();
     let keypair = handler.keypair()?;
-    let retries = handler.retries;
-    let priority_fee_micro_lamports = handler.priority_fee_micro_lamports;
+    let retry_policy = handler.retry_policy;
+    let mut priority_fee_micro_lamports = handler.priority_fee_micro_lamports;
+    let mut compute_unit_limit = instructions_compute_unit_limit(instructions);
+    let mut escalated_instructions = instructions.to_vec();
 
-    let mut all_instructions = vec![];
+    let mut attempt = 0;
+    loop {
+        let mut all_instructions = vec![];
 
-    all_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
-        priority_fee_micro_lamports,
-    ));
+        all_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee_micro_lamports,
+        ));
 
-    all_instructions.extend_from_slice(instructions);
+        all_instructions.extend_from_slice(&escalated_instructions);
 
-    for iteration in 0..retries {
         let blockhash = client.get_latest_blockhash().await?;
 
         // Create a vector that combines all signing keypairs
-        let mut all_signers = vec![keypair];
-        all_signers.extend(signing_keypairs.iter());
+        let all_signers = transaction_signers(keypair, signing_keypairs);
 
-        let tx = Transaction::new_signed_with_payer(
-            &all_instructions,
-            Some(&keypair.pubkey()),
-            &all_signers, // Pass the reference to the vector of keypair references
-            blockhash,
-        );
+        let message = v0_message(handler, &keypair.pubkey(), &all_instructions, blockhash)?;
+        let tx = VersionedTransaction::try_new(message, &all_signers)?;
 
+        // The last attempt is simulated first, for the program logs of a failure
         let config = RpcSendTransactionConfig {
-            skip_preflight: true,
+            skip_preflight: attempt < retry_policy.max_retries,
             ..RpcSendTransactionConfig::default()
         };
         let result = client
             .send_and_confirm_transaction_with_spinner_and_config(&tx, client.commitment(), config)
             .await;
 
-        if result.is_err() {
+        let e = match result {
+            std::result::Result::Ok(signature) => return Ok(signature),
+            Err(e) => e,
+        };
+
+        if let Some(already_processed) = as_already_processed(handler, &tx.message, &e) {
+            return Err(already_processed.into());
+        }
+
+        // Resent with more compute units when it exhausted them, with a higher fee when it did
+        // not land
+        let retry_budget =
+            retry_policy.retry_budget(attempt, &e, priority_fee_micro_lamports, compute_unit_limit);
+
+        if let Some((next_priority_fee, next_compute_unit_limit)) = retry_budget {
+            if next_compute_unit_limit != compute_unit_limit {
+                info!(
+                    "Compute units exhausted, raising the compute unit limit from {} to {}",
+                    compute_unit_limit, next_compute_unit_limit
+                );
+                compute_unit_limit = next_compute_unit_limit;
+                escalated_instructions = with_compute_unit_limit(instructions, compute_unit_limit);
+            }
+            if next_priority_fee != priority_fee_micro_lamports {
+                info!(
+                    "Transaction did not land, raising the priority fee from {} to {} micro lamports",
+                    priority_fee_micro_lamports, next_priority_fee
+                );
+                priority_fee_micro_lamports = next_priority_fee;
+            }
+
+            let delay_ms = retry_policy.delay_ms(attempt);
             info!(
-                "Retrying transaction after {}s {}/{}",
-                (1 + iteration),
-                iteration,
-                retries
+                "Retrying transaction after {}ms {}/{}",
+                delay_ms,
+                attempt + 1,
+                retry_policy.max_retries
             );
 
-            boring_progress_bar((1 + iteration) * 1000).await;
+            boring_progress_bar(delay_ms).await;
+            attempt += 1;
             continue;
         }
 
-        return Ok(result?);
+        if let Some(description) = describe_client_error(&e) {
+            return Err(anyhow!("\nError: {}\n\n{:?}\n\n", description, e));
+        }
+        return Err(anyhow!("\nError: \n\n{:?}\n\n", e));
     }
+}
 
-    // last retry
-    let blockhash = client.get_latest_blockhash().await?;
+/// Jito tip accounts, the one paid is picked from the blockhash to spread the write locks
+const JITO_TIP_ACCOUNTS: [Pubkey; 8] = [
+    pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5"),
+    pubkey!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe"),
+    pubkey!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY"),
+    pubkey!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49"),
+    pubkey!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh"),
+    pubkey!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt"),
+    pubkey!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL"),
+    pubkey!("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT"),
+];
+
+/// Submits the instructions to the Jito block engine as a bundle of one transaction, which tips
+/// `handler.jito_tip_lamports` from the keypair. The tip is only paid when the transaction lands.
+/// Bundles that are dropped are resubmitted with a fresh blockhash, following the retry policy.
+pub async fn send_bundle(
+    handler: &CliHandler,
+    block_engine_url: &str,
+    instructions: &[Instruction],
+    signing_keypairs: &[&Keypair],
+) -> Result<Signature> {
+    let client = handler.rpc_client();
+    let keypair = handler.keypair()?;
+    let http_client = reqwest::Client::new();
+
+    let all_signers = transaction_signers(keypair, signing_keypairs);
+    let mut priority_fee_micro_lamports = handler.priority_fee_micro_lamports;
+
+    for attempt in 0..=handler.retry_policy.max_retries {
+        let blockhash = client.get_latest_blockhash().await?;
+        let tip_account =
+            JITO_TIP_ACCOUNTS[blockhash.to_bytes()[0] as usize % JITO_TIP_ACCOUNTS.len()];
+
+        let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee_micro_lamports,
+        )];
+        all_instructions.extend_from_slice(instructions);
+        all_instructions.push(transfer(
+            &keypair.pubkey(),
+            &tip_account,
+            handler.jito_tip_lamports,
+        ));
+
+        let tx = Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&keypair.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        let signature = tx.signatures[0];
 
-    // Create a vector that combines all signing keypairs
-    let mut all_signers = vec![keypair];
-    all_signers.extend(signing_keypairs.iter());
+        match submit_bundle(&http_client, block_engine_url, &tx).await {
+            std::result::Result::Ok(bundle_id) => {
+                info!(
+                    "Submitted bundle {} with transaction {}",
+                    bundle_id, signature
+                );
+
+                if confirm_bundled_transaction(client, &signature, &blockhash).await? {
+                    return Ok(signature);
+                }
+                info!("Bundle {} did not land", bundle_id);
+            }
+            Err(err) => info!("Failed to submit bundle: {:?}", err),
+        }
+
+        if attempt < handler.retry_policy.max_retries {
+            priority_fee_micro_lamports = handler
+                .retry_policy
+                .escalated_priority_fee(priority_fee_micro_lamports);
+
+            let delay_ms = handler.retry_policy.delay_ms(attempt);
+            info!(
+                "Retrying bundle after {}ms {}/{}",
+                delay_ms,
+                attempt + 1,
+                handler.retry_policy.max_retries
+            );
+            boring_progress_bar(delay_ms).await;
+        }
+    }
+
+    Err(anyhow!(
+        "Bundle did not land after {} attempts",
+        handler.retry_policy.max_retries + 1
+    ))
+}
+
+/// Signs the instructions over the nonce of the nonce account, advanced by the keypair first, and
+/// sends them. The transaction does not expire until the nonce is advanced, so it is resent as is
+/// on failure. With `--sign-only` it is printed in base64 instead, to be sent later with
+/// `send-signed-transaction`.
+async fn send_nonce_transaction(
+    handler: &CliHandler,
+    nonce_account: &Pubkey,
+    instructions: &[Instruction],
+    signing_keypairs: &[&Keypair],
+) -> Result<Signature> {
+    let keypair = handler.keypair()?;
+    let nonce_hash = get_nonce_hash(handler, nonce_account).await?;
+
+    let mut all_instructions = vec![
+        advance_nonce_account(nonce_account, &keypair.pubkey()),
+        ComputeBudgetInstruction::set_compute_unit_price(handler.priority_fee_micro_lamports),
+    ];
+    all_instructions.extend_from_slice(instructions);
+
+    let all_signers = transaction_signers(keypair, signing_keypairs);
 
     let tx = Transaction::new_signed_with_payer(
-        instructions,
+        &all_instructions,
         Some(&keypair.pubkey()),
-        &all_signers, // Pass the reference to the vector of keypair references
-        blockhash,
+        &all_signers,
+        nonce_hash,
     );
 
-    let result = client.send_and_confirm_transaction(&tx).await;
+    if handler.sign_only {
+        // Every transaction signed over the same nonce but the first one would fail once sent
+        if handler.nonce_signed.swap(true, Ordering::SeqCst) {
+            return Err(anyhow!(
+                "Nonce account {} already signed a transaction, sign the next one with another nonce account",
+                nonce_account
+            ));
+        }
 
-    if let Err(e) = result {
-        return Err(anyhow!("\nError: \n\n{:?}\n\n", e));
+        println!(
+            "{}",
+            general_purpose::STANDARD.encode(bincode::serialize(&tx)?)
+        );
+        return Ok(tx.signatures[0]);
     }
 
-    Ok(result?)
+    send_signed(handler, &tx).await
+}
+
+/// The nonce given with `--nonce-hash`, or else the one stored in the nonce account
+async fn get_nonce_hash(handler: &CliHandler, nonce_account: &Pubkey) -> Result<Hash> {
+    if let Some(nonce_hash) = handler.nonce_hash {
+        return Ok(nonce_hash);
+    }
+
+    let account = handler.rpc_client().get_account(nonce_account).await?;
+    let versions: nonce::state::Versions = bincode::deserialize(&account.data)
+        .map_err(|e| anyhow!("{} is not a nonce account: {}", nonce_account, e))?;
+
+    match versions.state() {
+        nonce::State::Initialized(data) => Ok(data.blockhash()),
+        nonce::State::Uninitialized => Err(anyhow!(
+            "Nonce account {} is not initialized",
+            nonce_account
+        )),
+    }
+}
+
+/// Sends a transaction signed with `--sign-only`
+pub async fn send_signed_transaction(handler: &CliHandler, transaction: &str) -> Result<()> {
+    let tx: Transaction = bincode::deserialize(
+        &general_purpose::STANDARD
+            .decode(transaction.trim())
+            .map_err(|e| anyhow!("Transaction is not base64: {}", e))?,
+    )
+    .map_err(|e| anyhow!("Could not decode the transaction: {}", e))?;
+    tx.verify()?;
+
+    let signature = send_signed(handler, &tx).await?;
+
+    log_transaction(
+        "Sent Signed Transaction",
+        signature,
+        &[format!("Fee Payer: {:?}", tx.message.account_keys[0])],
+    );
+
+    Ok(())
+}
+
+/// Sends an already signed transaction, resending it as is on failure
+async fn send_signed(handler: &CliHandler, tx: &Transaction) -> Result<Signature> {
+    let client = handler.rpc_client();
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let mut attempt = 0;
+    loop {
+        let result = client
+            .send_and_confirm_transaction_with_spinner_and_config(tx, client.commitment(), config)
+            .await;
+
+        let err = match result {
+            std::result::Result::Ok(signature) => return Ok(signature),
+            Err(err) => err,
+        };
+
+        let message = VersionedMessage::Legacy(tx.message.clone());
+        if let Some(already_processed) = as_already_processed(handler, &message, &err) {
+            return Err(already_processed.into());
+        }
+        if !handler.retry_policy.should_retry(attempt, &err) {
+            return Err(err.into());
+        }
+
+        let delay_ms = handler.retry_policy.delay_ms(attempt);
+        info!(
+            "Retrying transaction after {}ms {}/{}",
+            delay_ms,
+            attempt + 1,
+            handler.retry_policy.max_retries
+        );
+        boring_progress_bar(delay_ms).await;
+        attempt += 1;
+    }
+}
+
+/// The keypair, paying for and signing every transaction, followed by the other signers
+fn transaction_signers<'a>(
+    keypair: &'a CliSigner,
+    signing_keypairs: &[&'a Keypair],
+) -> Vec<&'a dyn Signer> {
+    let mut all_signers: Vec<&dyn Signer> = vec![keypair];
+    all_signers.extend(
+        signing_keypairs
+            .iter()
+            .map(|signing_keypair| *signing_keypair as &dyn Signer),
+    );
+    all_signers
+}
+
+/// Sends `sendBundle` to the block engine, returning the bundle id
+async fn submit_bundle(
+    http_client: &reqwest::Client,
+    block_engine_url: &str,
+    tx: &Transaction,
+) -> Result<String> {
+    let encoded_tx = general_purpose::STANDARD.encode(bincode::serialize(tx)?);
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "sendBundle",
+        "params": [[encoded_tx], { "encoding": "base64" }],
+    });
+
+    let response: serde_json::Value = http_client
+        .post(format!(
+            "{}/api/v1/bundles",
+            block_engine_url.trim_end_matches('/')
+        ))
+        .json(&request)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    if let Some(error) = response.get("error") {
+        return Err(anyhow!("Block engine rejected the bundle: {}", error));
+    }
+
+    response
+        .get("result")
+        .and_then(|bundle_id| bundle_id.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Unexpected block engine response: {}", response))
+}
+
+/// Waits for a bundled transaction until it is confirmed, `false` once its blockhash expired
+/// without it landing
+async fn confirm_bundled_transaction(
+    client: &RpcClient,
+    signature: &Signature,
+    blockhash: &Hash,
+) -> Result<bool> {
+    loop {
+        let status = client
+            .get_signature_status_with_commitment(signature, client.commitment())
+            .await?;
+
+        if let Some(status) = status {
+            status.map_err(|err| anyhow!("Bundled transaction {} failed: {:?}", signature, err))?;
+            return Ok(true);
+        }
+
+        if !client
+            .is_blockhash_valid(blockhash, client.commitment())
+            .await?
+        {
+            return Ok(false);
+        }
+
+        sleep(Duration::from_millis(500)).await;
+    }
 }
 
 pub fn log_transaction(title: &str, signature: Signature, log_items: &[String]) {
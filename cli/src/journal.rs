@@ -0,0 +1,478 @@
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use borsh::BorshDeserialize;
+use log::{info, warn};
+use ncn_program_core::instruction::NCNProgramInstruction;
+use rusqlite::{params, Connection, Row};
+use serde::Serialize;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{
+    compute_budget, instruction::Instruction, pubkey::Pubkey, signature::Signature, system_program,
+};
+use solana_transaction_status::UiTransactionEncoding;
+
+use crate::{args::ReportFormat, handler::CliHandler};
+
+/// A transaction sent by the CLI, as recorded in the journal
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalEntry {
+    pub timestamp: String,
+    /// The CLI command that sent it, e.g. `run-keeper`
+    pub command: String,
+    pub title: String,
+    pub instructions: Vec<String>,
+    pub accounts: Vec<String>,
+    /// `None` when the transaction failed before it was signed
+    pub signature: Option<String>,
+    pub result: JournalResult,
+    pub error: Option<String>,
+    pub compute_units: Option<u64>,
+    pub fee: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalResult {
+    Landed,
+    AlreadyProcessed,
+    Failed,
+}
+
+impl JournalResult {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Landed => "landed",
+            Self::AlreadyProcessed => "already_processed",
+            Self::Failed => "failed",
+        }
+    }
+
+    fn parse(result: &str) -> Self {
+        match result {
+            "landed" => Self::Landed,
+            "already_processed" => Self::AlreadyProcessed,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// Which entries `history` lists, the most recent first
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub limit: u64,
+    pub command: Option<String>,
+    pub failed_only: bool,
+}
+
+/// Local SQLite database of every transaction the CLI sends with `--journal`, the audit trail of
+/// the automated actions of a keeper
+pub struct Journal {
+    connection: Mutex<Connection>,
+}
+
+impl Journal {
+    pub fn open(path: &str) -> Result<Self> {
+        let connection = Connection::open(path)
+            .map_err(|e| anyhow!("Could not open the journal {}: {}", path, e))?;
+
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS transactions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                command TEXT NOT NULL,
+                title TEXT NOT NULL,
+                instructions TEXT NOT NULL,
+                accounts TEXT NOT NULL,
+                signature TEXT,
+                result TEXT NOT NULL,
+                error TEXT,
+                compute_units INTEGER,
+                fee INTEGER
+            );
+            CREATE INDEX IF NOT EXISTS transactions_command ON transactions (command);",
+        )?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    pub fn record(&self, entry: &JournalEntry) -> Result<()> {
+        let connection = self
+            .connection
+            .lock()
+            .map_err(|_| anyhow!("The journal lock is poisoned"))?;
+
+        connection.execute(
+            "INSERT INTO transactions (timestamp, command, title, instructions, accounts, signature, result, error, compute_units, fee)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                entry.timestamp,
+                entry.command,
+                entry.title,
+                entry.instructions.join(","),
+                entry.accounts.join(","),
+                entry.signature,
+                entry.result.as_str(),
+                entry.error,
+                entry.compute_units,
+                entry.fee,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn history(&self, filter: &HistoryFilter) -> Result<Vec<JournalEntry>> {
+        let connection = self
+            .connection
+            .lock()
+            .map_err(|_| anyhow!("The journal lock is poisoned"))?;
+
+        let mut statement = connection.prepare(
+            "SELECT timestamp, command, title, instructions, accounts, signature, result, error, compute_units, fee
+            FROM transactions
+            WHERE (?1 IS NULL OR command = ?1) AND (?2 = 0 OR result = 'failed')
+            ORDER BY id DESC
+            LIMIT ?3",
+        )?;
+
+        let entries = statement
+            .query_map(
+                params![filter.command, filter.failed_only, filter.limit],
+                journal_entry,
+            )?
+            .collect::<rusqlite::Result<Vec<JournalEntry>>>()?;
+
+        Ok(entries)
+    }
+}
+
+fn journal_entry(row: &Row) -> rusqlite::Result<JournalEntry> {
+    let list = |list: String| -> Vec<String> {
+        list.split(',')
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+
+    Ok(JournalEntry {
+        timestamp: row.get(0)?,
+        command: row.get(1)?,
+        title: row.get(2)?,
+        instructions: list(row.get(3)?),
+        accounts: list(row.get(4)?),
+        signature: row.get(5)?,
+        result: JournalResult::parse(&row.get::<_, String>(6)?),
+        error: row.get(7)?,
+        compute_units: row.get(8)?,
+        fee: row.get(9)?,
+    })
+}
+
+/// The NCN program instructions by name, the others by their program
+pub fn instruction_names(ncn_program_id: &Pubkey, instructions: &[Instruction]) -> Vec<String> {
+    instructions
+        .iter()
+        .map(|instruction| {
+            if instruction.program_id.eq(ncn_program_id) {
+                return NCNProgramInstruction::try_from_slice(&instruction.data).map_or_else(
+                    |_| "Unknown".to_string(),
+                    |ncn_instruction| {
+                        format!("{:?}", ncn_instruction)
+                            .split([' ', '{', '('])
+                            .next()
+                            .unwrap_or_default()
+                            .to_string()
+                    },
+                );
+            }
+
+            if instruction.program_id.eq(&system_program::id()) {
+                "System".to_string()
+            } else if instruction.program_id.eq(&compute_budget::id()) {
+                "ComputeBudget".to_string()
+            } else {
+                instruction.program_id.to_string()
+            }
+        })
+        .collect()
+}
+
+/// The accounts of the instructions, each once
+pub fn instruction_accounts(instructions: &[Instruction]) -> Vec<String> {
+    let mut accounts: Vec<Pubkey> = vec![];
+    for account in instructions.iter().flat_map(|ix| ix.accounts.iter()) {
+        if !accounts.contains(&account.pubkey) {
+            accounts.push(account.pubkey);
+        }
+    }
+
+    accounts.iter().map(Pubkey::to_string).collect()
+}
+
+/// Records the transaction in the journal of the handler, if any. The compute units and fee of
+/// a landed transaction are fetched from the RPC, a journal that can not be written only warns.
+pub async fn record_transaction(
+    handler: &CliHandler,
+    title: &str,
+    instructions: &[Instruction],
+    signature: Option<&Signature>,
+    result: JournalResult,
+    error: Option<String>,
+) {
+    let Some(journal) = handler.journal.as_ref() else {
+        return;
+    };
+
+    let (compute_units, fee) = match signature {
+        Some(signature) if result == JournalResult::Landed => {
+            transaction_cost(handler, signature).await
+        }
+        _ => (None, None),
+    };
+
+    let entry = JournalEntry {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        command: handler.command_name.clone(),
+        title: title.to_string(),
+        instructions: instruction_names(&handler.ncn_program_id, instructions),
+        accounts: instruction_accounts(instructions),
+        signature: signature.map(Signature::to_string),
+        result,
+        error,
+        compute_units,
+        fee,
+    };
+
+    if let Err(e) = journal.record(&entry) {
+        warn!("Could not record {} in the journal: {}", title, e);
+    }
+}
+
+/// The compute units and fee of a landed transaction, `None` when it can not be fetched
+async fn transaction_cost(
+    handler: &CliHandler,
+    signature: &Signature,
+) -> (Option<u64>, Option<u64>) {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(handler.commitment),
+        max_supported_transaction_version: Some(0),
+    };
+
+    match handler
+        .rpc_client()
+        .get_transaction_with_config(signature, config)
+        .await
+    {
+        Ok(transaction) => transaction.transaction.meta.map_or((None, None), |meta| {
+            (meta.compute_units_consumed.into(), Some(meta.fee))
+        }),
+        Err(e) => {
+            warn!("Could not fetch the cost of {}: {}", signature, e);
+            (None, None)
+        }
+    }
+}
+
+/// Lists the transactions of the journal matching the filter
+pub fn report_history(
+    handler: &CliHandler,
+    filter: &HistoryFilter,
+    format: ReportFormat,
+) -> Result<()> {
+    let journal = handler
+        .journal
+        .as_ref()
+        .ok_or_else(|| anyhow!("No journal to list, set it with --journal"))?;
+    let entries = journal.history(filter)?;
+
+    match format {
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&entries)?),
+        ReportFormat::Table => info!("{}", history_table(&entries)),
+    }
+
+    Ok(())
+}
+
+fn history_table(entries: &[JournalEntry]) -> String {
+    let mut table = format!(
+        "\n\n--- Last {} transactions ---\n{:<25} {:<20} {:<40} {:<17} {:>10} {:>10}  {}",
+        entries.len(),
+        "Time",
+        "Command",
+        "Title",
+        "Result",
+        "CU",
+        "Fee",
+        "Signature"
+    );
+
+    for entry in entries {
+        let optional =
+            |value: Option<u64>| value.map_or("-".to_string(), |value| value.to_string());
+
+        table.push_str(&format!(
+            "\n{:<25} {:<20} {:<40} {:<17} {:>10} {:>10}  {}",
+            entry.timestamp,
+            entry.command,
+            entry.title,
+            entry.result.as_str(),
+            optional(entry.compute_units),
+            optional(entry.fee),
+            entry.signature.as_deref().unwrap_or("-")
+        ));
+        table.push_str(&format!(
+            "\n  Instructions: {}",
+            entry.instructions.join(", ")
+        ));
+        if let Some(error) = entry.error.as_deref() {
+            table.push_str(&format!("\n  Error: {}", error));
+        }
+    }
+
+    table.push('\n');
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sent_entry(command: &str, title: &str, result: JournalResult) -> JournalEntry {
+        JournalEntry {
+            timestamp: "2024-01-01T00:00:00+00:00".to_string(),
+            command: command.to_string(),
+            title: title.to_string(),
+            instructions: vec!["ComputeBudget".to_string(), "CastVote".to_string()],
+            accounts: vec![Pubkey::new_unique().to_string()],
+            signature: (result != JournalResult::Failed)
+                .then(|| Signature::new_unique().to_string()),
+            result,
+            error: (result == JournalResult::Failed).then(|| "custom program error".to_string()),
+            compute_units: (result == JournalResult::Landed).then_some(1_400),
+            fee: (result == JournalResult::Landed).then_some(5_000),
+        }
+    }
+
+    fn history(journal: &Journal, limit: u64) -> Vec<JournalEntry> {
+        journal
+            .history(&HistoryFilter {
+                limit,
+                ..HistoryFilter::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_entry_round_trip() {
+        let journal = Journal::open(":memory:").unwrap();
+        let entry = sent_entry("run-keeper", "Cast Vote", JournalResult::Landed);
+
+        journal.record(&entry).unwrap();
+
+        let entries = history(&journal, 10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].command, entry.command);
+        assert_eq!(entries[0].title, entry.title);
+        assert_eq!(entries[0].instructions, entry.instructions);
+        assert_eq!(entries[0].accounts, entry.accounts);
+        assert_eq!(entries[0].signature, entry.signature);
+        assert_eq!(entries[0].result, JournalResult::Landed);
+        assert_eq!(entries[0].error, None);
+        assert_eq!(entries[0].compute_units, Some(1_400));
+        assert_eq!(entries[0].fee, Some(5_000));
+    }
+
+    #[test]
+    fn test_every_attempt_is_recorded() {
+        let journal = Journal::open(":memory:").unwrap();
+        let entry = sent_entry("run-keeper", "Cast Vote", JournalResult::AlreadyProcessed);
+
+        // The same transaction sent twice is two entries of the audit trail
+        journal.record(&entry).unwrap();
+        journal.record(&entry).unwrap();
+
+        let entries = history(&journal, 10);
+        assert_eq!(entries.len(), 2);
+        assert!(entries
+            .iter()
+            .all(|recorded| recorded.signature == entry.signature
+                && recorded.result == JournalResult::AlreadyProcessed));
+    }
+
+    #[test]
+    fn test_history_filter() {
+        let journal = Journal::open(":memory:").unwrap();
+        journal
+            .record(&sent_entry("run-keeper", "Snapshot", JournalResult::Landed))
+            .unwrap();
+        journal
+            .record(&sent_entry(
+                "run-keeper",
+                "Cast Vote",
+                JournalResult::Failed,
+            ))
+            .unwrap();
+        journal
+            .record(&sent_entry(
+                "admin-set-parameters",
+                "Set Parameters",
+                JournalResult::Landed,
+            ))
+            .unwrap();
+
+        // The most recent first
+        let titles = |entries: Vec<JournalEntry>| -> Vec<String> {
+            entries.into_iter().map(|entry| entry.title).collect()
+        };
+        assert_eq!(
+            titles(history(&journal, 10)),
+            vec!["Set Parameters", "Cast Vote", "Snapshot"]
+        );
+        assert_eq!(titles(history(&journal, 1)), vec!["Set Parameters"]);
+
+        let filtered = |command: Option<&str>, failed_only: bool| {
+            titles(
+                journal
+                    .history(&HistoryFilter {
+                        limit: 10,
+                        command: command.map(str::to_string),
+                        failed_only,
+                    })
+                    .unwrap(),
+            )
+        };
+        assert_eq!(
+            filtered(Some("run-keeper"), false),
+            vec!["Cast Vote", "Snapshot"]
+        );
+        assert_eq!(filtered(None, true), vec!["Cast Vote"]);
+        assert_eq!(
+            filtered(Some("admin-set-parameters"), true),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_instruction_accounts_are_listed_once() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let instruction = |accounts: &[Pubkey]| {
+            Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                accounts
+                    .iter()
+                    .map(|account| solana_sdk::instruction::AccountMeta::new(*account, false))
+                    .collect(),
+            )
+        };
+
+        assert_eq!(
+            instruction_accounts(&[instruction(&[first, second]), instruction(&[second, first])]),
+            vec![first.to_string(), second.to_string()]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use log::info;
+use solana_sdk::native_token::lamports_to_sol;
+
+use crate::{
+    getters::get_account_payer, handler::CliHandler, instructions::admin_fund_account_payer,
+    keeper::keeper_metrics::emit_error,
+};
+
+/// Keeps the account payer funded so the epoch accounts never fail to be created for rent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountPayerTopUp {
+    /// Balance under which the account payer is topped up, in lamports
+    pub min_balance: u64,
+    /// Lamports transferred from the keeper wallet, `0` to only alert
+    pub amount: u64,
+}
+
+impl AccountPayerTopUp {
+    /// Checks the account payer balance, topping it up from the keeper wallet or alerting when it
+    /// dropped under the minimum
+    ///
+    /// # Returns
+    /// An error when the top up transfer fails
+    pub async fn check(&self, handler: &CliHandler, keeper_epoch: u64) -> Result<()> {
+        let balance = get_account_payer(handler).await?.lamports;
+        if balance >= self.min_balance {
+            return Ok(());
+        }
+
+        if self.amount == 0 {
+            let title = "Account Payer Balance".to_string();
+            let error = format!(
+                "Account payer balance {} SOL is under {} SOL",
+                lamports_to_sol(balance),
+                lamports_to_sol(self.min_balance)
+            );
+            let message = format!("Error: [{}] \n{}\n\n", title, error);
+
+            log::error!("{}", message);
+            emit_error(title, error, message, keeper_epoch).await;
+            return Ok(());
+        }
+
+        info!(
+            "Account payer balance {} SOL is under {} SOL, topping it up with {} SOL",
+            lamports_to_sol(balance),
+            lamports_to_sol(self.min_balance),
+            lamports_to_sol(self.amount)
+        );
+        admin_fund_account_payer(handler, lamports_to_sol(self.amount)).await
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+    sync::Mutex,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    getters::{get_ballot_box, get_guaranteed_epoch_and_slot, get_ncn_program_config},
+    handler::CliHandler,
+};
+
+/// PagerDuty Events API v2 endpoint, PagerDuty webhooks only carry the routing key
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// Time to wait for a webhook to answer
+const WEBHOOK_TIMEOUT_MS: u64 = 10_000;
+
+/// Where an alert is posted and in which format
+///
+/// Parsed from `slack=<url>`, `discord=<url>` or `pagerduty=<routing key>`, a bare URL is a Slack
+/// compatible webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlertWebhook {
+    Slack(String),
+    Discord(String),
+    PagerDuty(String),
+}
+
+impl FromStr for AlertWebhook {
+    type Err = anyhow::Error;
+
+    fn from_str(webhook: &str) -> Result<Self> {
+        let webhook = webhook.trim();
+
+        match webhook.split_once('=') {
+            Some(("slack", url)) => Ok(Self::Slack(url.to_string())),
+            Some(("discord", url)) => Ok(Self::Discord(url.to_string())),
+            Some(("pagerduty", routing_key)) => Ok(Self::PagerDuty(routing_key.to_string())),
+            _ if webhook.starts_with("http://") || webhook.starts_with("https://") => {
+                Ok(Self::Slack(webhook.to_string()))
+            }
+            _ => Err(anyhow!(
+                "Invalid alert webhook {}, expected slack=<url>, discord=<url> or pagerduty=<routing key>",
+                webhook
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AlertWebhook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Slack(_) => write!(f, "Slack"),
+            Self::Discord(_) => write!(f, "Discord"),
+            Self::PagerDuty(_) => write!(f, "PagerDuty"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    /// Voting went on for `epochs_before_stall` epochs without consensus
+    VotingStalled,
+    ConsensusReached,
+    /// A crank failed `failure_threshold` times in a row
+    CrankFailing,
+}
+
+impl AlertKind {
+    const fn severity(&self) -> &'static str {
+        match self {
+            Self::VotingStalled => "critical",
+            Self::ConsensusReached => "info",
+            Self::CrankFailing => "error",
+        }
+    }
+}
+
+impl fmt::Display for AlertKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VotingStalled => write!(f, "Voting Stalled"),
+            Self::ConsensusReached => write!(f, "Consensus Reached"),
+            Self::CrankFailing => write!(f, "Crank Failing"),
+        }
+    }
+}
+
+/// Posts the events worth waking someone up for to webhooks, instead of leaving them in the logs
+///
+/// Stalls and consensus are alerted once per epoch, failing cranks once every time they reach
+/// the failure threshold in a row. Without webhooks nothing is sent.
+pub struct KeeperAlerts {
+    webhooks: Vec<AlertWebhook>,
+    failure_threshold: u64,
+    ncn: Pubkey,
+    http_client: reqwest::Client,
+    /// Consecutive failures of each crank
+    failures: Mutex<HashMap<String, u64>>,
+    /// The once per epoch alerts already sent
+    sent: Mutex<HashSet<(u64, AlertKind)>>,
+}
+
+impl KeeperAlerts {
+    pub fn new(webhooks: Vec<AlertWebhook>, failure_threshold: u64, ncn: Pubkey) -> Self {
+        Self {
+            webhooks,
+            failure_threshold: failure_threshold.max(1),
+            ncn,
+            http_client: reqwest::Client::new(),
+            failures: Mutex::new(HashMap::new()),
+            sent: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.webhooks.is_empty()
+    }
+
+    /// Counts the consecutive failures of the crank, alerting when they reach the threshold
+    pub async fn record_result<T>(&self, title: &str, result: &Result<T>, keeper_epoch: u64) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let failures = {
+            let Ok(mut failures) = self.failures.lock() else {
+                return;
+            };
+
+            if result.is_ok() {
+                failures.remove(title);
+                return;
+            }
+
+            let count = failures.entry(title.to_string()).or_default();
+            *count += 1;
+            *count
+        };
+
+        if failures % self.failure_threshold == 0 {
+            if let Err(e) = result {
+                self.send(
+                    AlertKind::CrankFailing,
+                    keeper_epoch,
+                    format!("{} failed {} times in a row", title, failures),
+                    format!("{:?}", e),
+                )
+                .await;
+            }
+        }
+    }
+
+    /// Alerts once when the epoch reaches consensus, or once when its voting stalled past
+    /// `epochs_before_stall` without consensus
+    pub async fn check_vote(&self, handler: &CliHandler, epoch: u64) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let ballot_box = get_ballot_box(handler, epoch).await?;
+
+        if ballot_box.is_consensus_reached() {
+            let winning_ballot = ballot_box.get_winning_ballot_tally()?;
+
+            self.send_once(
+                AlertKind::ConsensusReached,
+                epoch,
+                format!("Epoch {} reached consensus", epoch),
+                format!(
+                    "Ballot {} won with {} votes and stake weight {}",
+                    winning_ballot.ballot(),
+                    winning_ballot.tally(),
+                    winning_ballot.stake_weights().stake_weight()
+                ),
+            )
+            .await;
+            return Ok(());
+        }
+
+        let epochs_before_stall = get_ncn_program_config(handler).await?.epochs_before_stall();
+        let (current_epoch, _) = get_guaranteed_epoch_and_slot(handler).await;
+
+        if current_epoch >= epoch.saturating_add(epochs_before_stall) {
+            self.send_once(
+                AlertKind::VotingStalled,
+                epoch,
+                format!("Voting of epoch {} is stalled", epoch),
+                format!(
+                    "No consensus after {} epochs, {} operators voted, a tie breaker can be set",
+                    current_epoch - epoch,
+                    ballot_box.operators_voted()
+                ),
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+
+    async fn send_once(&self, kind: AlertKind, epoch: u64, summary: String, details: String) {
+        let first = self
+            .sent
+            .lock()
+            .map(|mut sent| sent.insert((epoch, kind)))
+            .unwrap_or(false);
+
+        if first {
+            self.send(kind, epoch, summary, details).await;
+        }
+    }
+
+    /// Posts the alert to every webhook, a webhook failing is only logged
+    async fn send(&self, kind: AlertKind, epoch: u64, summary: String, details: String) {
+        info!("Alert [{}] {}: {}", kind, summary, details);
+
+        for webhook in self.webhooks.iter() {
+            let (url, body) = self.webhook_request(webhook, kind, epoch, &summary, &details);
+
+            let result = self
+                .http_client
+                .post(url)
+                .timeout(Duration::from_millis(WEBHOOK_TIMEOUT_MS))
+                .json(&body)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            if let Err(e) = result {
+                // The webhook URLs and routing keys are secrets, only the service is logged
+                error!("Could not send the alert to the {} webhook: {}", webhook, e);
+            }
+        }
+    }
+
+    fn webhook_request<'a>(
+        &self,
+        webhook: &'a AlertWebhook,
+        kind: AlertKind,
+        epoch: u64,
+        summary: &str,
+        details: &str,
+    ) -> (&'a str, Value) {
+        let text = format!(
+            "[{}] {}\nNCN: {}\nEpoch: {}\n{}",
+            kind, summary, self.ncn, epoch, details
+        );
+
+        match webhook {
+            AlertWebhook::Slack(url) => (url, json!({ "text": text })),
+            AlertWebhook::Discord(url) => (url, json!({ "content": text })),
+            AlertWebhook::PagerDuty(routing_key) => (
+                PAGERDUTY_EVENTS_URL,
+                json!({
+                    "routing_key": routing_key,
+                    "event_action": "trigger",
+                    "dedup_key": format!("ncn-keeper-{}-{}-{:?}", self.ncn, epoch, kind),
+                    "payload": {
+                        "summary": summary,
+                        "source": self.ncn.to_string(),
+                        "severity": kind.severity(),
+                        "custom_details": { "epoch": epoch, "details": details },
+                    },
+                }),
+            ),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::Result;
+use log::info;
+use ncn_program_core::epoch_state::State;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::state_file;
+
+/// Progress of the keeper, persisted to a JSON file so a restarted keeper resumes where it left
+/// off instead of scanning every epoch and operator again
+///
+/// The file is only a shortcut, the on-chain accounts stay the source of truth: anything missing
+/// from it is fetched again, and any progress recorded in it can be redone safely.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct KeeperCheckpoint {
+    /// The epoch the keeper was working on
+    pub keeper_epoch: Option<u64>,
+    pub epochs: BTreeMap<u64, EpochProgress>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// Progress of the keeper in one epoch
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EpochProgress {
+    /// The last stage cranked without error
+    pub last_stage: Option<String>,
+    /// Operators whose snapshot is finalized
+    pub snapshotted_operators: BTreeSet<String>,
+    /// Operators left with nothing to route or distribute while the NCN reward router held
+    /// `routed_ncn_rewards`, they only get new rewards once NCN rewards are routed again
+    pub routed_operators: BTreeSet<String>,
+    pub routed_ncn_rewards: u64,
+    /// The epoch accounts are closed
+    pub completed: bool,
+}
+
+impl EpochProgress {
+    pub fn snapshotted_operators(&self) -> HashSet<Pubkey> {
+        parse_pubkeys(&self.snapshotted_operators)
+    }
+
+    /// The operators with nothing to route, as long as the NCN reward router still holds
+    /// `ncn_rewards`
+    pub fn routed_operators(&self, ncn_rewards: u64) -> HashSet<Pubkey> {
+        if ncn_rewards != self.routed_ncn_rewards {
+            return HashSet::new();
+        }
+
+        parse_pubkeys(&self.routed_operators)
+    }
+}
+
+impl KeeperCheckpoint {
+    /// Reads the checkpoint from `path`, starting a new one when the file does not exist yet.
+    /// Without a path the progress is kept in memory only.
+    pub fn load(path: Option<&str>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let path = PathBuf::from(path);
+
+        let mut checkpoint = match state_file::load::<Self>(&path, "keeper state file")? {
+            Some(checkpoint) => {
+                info!(
+                    "Loaded keeper state from {}, keeper epoch {:?}",
+                    path.display(),
+                    checkpoint.keeper_epoch
+                );
+                checkpoint
+            }
+            None => Self::default(),
+        };
+
+        checkpoint.path = Some(path);
+        Ok(checkpoint)
+    }
+
+    /// Writes the checkpoint to disk, see `state_file::save`
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        state_file::save(path, self)
+    }
+
+    pub fn epoch(&self, epoch: u64) -> Option<&EpochProgress> {
+        self.epochs.get(&epoch)
+    }
+
+    pub fn epoch_mut(&mut self, epoch: u64) -> &mut EpochProgress {
+        self.epochs.entry(epoch).or_default()
+    }
+
+    pub fn is_completed(&self, epoch: u64) -> bool {
+        self.epoch(epoch).is_some_and(|progress| progress.completed)
+    }
+
+    pub fn set_keeper_epoch(&mut self, epoch: u64) {
+        self.keeper_epoch = Some(epoch);
+        state_file::retain_recent_epochs(&mut self.epochs, epoch);
+    }
+
+    pub fn record_stage(&mut self, epoch: u64, stage: State) {
+        self.epoch_mut(epoch).last_stage = Some(format!("{:?}", stage));
+    }
+
+    pub fn record_completed(&mut self, epoch: u64) {
+        self.epoch_mut(epoch).completed = true;
+    }
+
+    pub fn record_snapshotted_operators(&mut self, epoch: u64, operators: &[Pubkey]) {
+        self.epoch_mut(epoch)
+            .snapshotted_operators
+            .extend(operators.iter().map(|operator| operator.to_string()));
+    }
+
+    /// Records the operators left with nothing to route, replacing the ones recorded for other
+    /// NCN rewards
+    pub fn record_routed_operators(&mut self, epoch: u64, ncn_rewards: u64, operators: &[Pubkey]) {
+        let progress = self.epoch_mut(epoch);
+        if progress.routed_ncn_rewards != ncn_rewards {
+            progress.routed_operators.clear();
+            progress.routed_ncn_rewards = ncn_rewards;
+        }
+
+        progress
+            .routed_operators
+            .extend(operators.iter().map(|operator| operator.to_string()));
+    }
+}
+
+fn parse_pubkeys(pubkeys: &BTreeSet<String>) -> HashSet<Pubkey> {
+    pubkeys
+        .iter()
+        .filter_map(|pubkey| Pubkey::from_str(pubkey).ok())
+        .collect()
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use log::{info, warn};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+use crate::{
+    getters::{get_guaranteed_epoch_and_slot, get_keeper_lease},
+    handler::CliHandler,
+    instructions::acquire_keeper_lease,
+};
+
+/// Elects the one keeper instance cranking the NCN among the instances signing with the same
+/// wallet, through the keeper lease of the wallet. The others stand by and take over once the
+/// lease of the leader expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeeperLeader {
+    /// Identifies this instance in the lease
+    holder: Pubkey,
+    /// Slots the lease is taken for, it is renewed once half of them passed
+    lease_slots: u64,
+    is_leader: bool,
+}
+
+impl KeeperLeader {
+    pub const fn new(holder: Pubkey, lease_slots: u64) -> Self {
+        Self {
+            holder,
+            lease_slots,
+            is_leader: false,
+        }
+    }
+
+    pub const fn holder(&self) -> &Pubkey {
+        &self.holder
+    }
+
+    /// Takes the lease when it is free or expired and renews it when held by this instance
+    ///
+    /// # Returns
+    /// Whether this instance is the leader and should crank
+    pub async fn campaign(&mut self, handler: &CliHandler) -> Result<bool> {
+        let authority = handler.keypair()?.pubkey();
+        let (_, slot) = get_guaranteed_epoch_and_slot(handler).await;
+
+        // Not created yet when the wallet never took the lease
+        if let Ok(keeper_lease) = get_keeper_lease(handler, &authority).await {
+            if !keeper_lease.is_expired(slot) {
+                if keeper_lease.holder().ne(&self.holder) {
+                    return Ok(self.stand_by(keeper_lease.holder(), keeper_lease.slot_expires()));
+                }
+
+                let renew_slot = keeper_lease
+                    .slot_expires()
+                    .saturating_sub(self.lease_slots.div_ceil(2));
+                if slot < renew_slot {
+                    return Ok(self.lead(keeper_lease.slot_expires()));
+                }
+            }
+        }
+
+        let result = acquire_keeper_lease(handler, &self.holder, self.lease_slots).await;
+        let keeper_lease = get_keeper_lease(handler, &authority).await;
+
+        // Another instance may have taken the lease since it was fetched
+        match (result, keeper_lease) {
+            (Ok(()), keeper_lease) => Ok(self.lead(keeper_lease?.slot_expires())),
+            (Err(e), Ok(keeper_lease)) => {
+                let (_, slot) = get_guaranteed_epoch_and_slot(handler).await;
+                if keeper_lease.holder().ne(&self.holder) && !keeper_lease.is_expired(slot) {
+                    return Ok(self.stand_by(keeper_lease.holder(), keeper_lease.slot_expires()));
+                }
+                Err(e)
+            }
+            (Err(e), Err(_)) => Err(e),
+        }
+    }
+
+    fn lead(&mut self, slot_expires: u64) -> bool {
+        if !self.is_leader {
+            info!(
+                "Keeper {} is the leader until slot {}",
+                self.holder, slot_expires
+            );
+        }
+        self.is_leader = true;
+        true
+    }
+
+    fn stand_by(&mut self, leader: &Pubkey, slot_expires: u64) -> bool {
+        if self.is_leader {
+            warn!("Keeper {} lost the lease to {}", self.holder, leader);
+        }
+        info!(
+            "Keeper {} is standing by, {} holds the lease until slot {}",
+            self.holder, leader, slot_expires
+        );
+        self.is_leader = false;
+        false
+    }
+}
@@ -1,21 +1,37 @@
-use std::time::Duration;
+use std::{
+    collections::HashSet,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
 
 use crate::{
-    getters::get_guaranteed_epoch_and_slot,
+    args::{EpochAccountType, KeeperStage},
+    getters::{get_guaranteed_epoch_and_slot, get_ncn_reward_router},
     handler::CliHandler,
     instructions::{
-        crank_close_epoch_accounts, crank_distribute, crank_post_vote_cooldown,
-        crank_register_vaults, crank_set_weight, crank_snapshot, create_epoch_state,
+        crank_close_epoch_accounts, crank_create_or_skip_epoch, crank_post_vote_cooldown,
+        crank_register_vaults, crank_route_and_distribute, crank_set_weight, crank_snapshot,
     },
     keeper::{
-        keeper_metrics::{emit_epoch_metrics, emit_error, emit_heartbeat, emit_ncn_metrics},
+        keeper_account_payer::AccountPayerTopUp,
+        keeper_alerts::KeeperAlerts,
+        keeper_checkpoint::KeeperCheckpoint,
+        keeper_lease::KeeperLeader,
+        keeper_metrics::{
+            emit_consensus_metrics, emit_epoch_metrics, emit_error, emit_heartbeat,
+            emit_ncn_metrics,
+        },
+        keeper_notifications::KeeperNotifier,
+        keeper_schedule::StageSchedule,
         keeper_state::KeeperState,
     },
+    run_once::PassOutcome,
 };
 use anyhow::Result;
 use log::info;
 use ncn_program_core::epoch_state::State;
 use solana_metrics::set_host_id;
+use solana_sdk::clock::DEFAULT_MS_PER_SLOT;
 use std::process::Command;
 use tokio::time::sleep;
 
@@ -31,22 +47,77 @@ use tokio::time::sleep;
 /// The keeper runs in a continuous loop, handling multiple epochs and automatically
 /// progressing to new epochs when the current one is complete or stalled.
 ///
+/// Only the enabled stages are cranked, the others are left to other keepers. An epoch waiting on
+/// a disabled stage is treated as stalled so the keeper moves on to the epochs it can work on, as
+/// is an epoch waiting for the current epoch to reach the minimum slots of its stage.
+///
 /// # Arguments
 /// * `handler` - CLI handler containing RPC client and configuration
 /// * `loop_timeout_ms` - Timeout between main loop iterations when stalled
 /// * `error_timeout_ms` - Timeout after errors before retrying
+/// * `loop_interval_ms` - Minimum time between the start of two loop iterations
+/// * `stages` - The stages this keeper runs
+/// * `stage_schedule` - Minimum slots into the current epoch before each stage runs
+/// * `register_st_mint_weight` - Weight the st mints of new vaults are registered with
+/// * `websocket_url` - RPC websocket, the loop timeout ends early when the epoch accounts change
+/// * `state_file` - File the progress is persisted to, a restarted keeper resumes from it
+/// * `account_payer_top_up` - Minimum account payer balance and how much to top it up with
+/// * `alerts` - Webhooks alerted on stalls, consensus and failing cranks
+/// * `leader` - Keeper lease to take before cranking, for running standby instances
+/// * `once` - Make a single pass over the epochs up to the current one instead of looping
+///
+/// # Returns
+/// How the pass went with `once`, the keeper loops indefinitely otherwise
+#[allow(clippy::too_many_arguments)]
 pub async fn startup_ncn_keeper(
     handler: &CliHandler,
     loop_timeout_ms: u64,
     error_timeout_ms: u64,
-) -> Result<()> {
+    loop_interval_ms: u64,
+    stages: &[KeeperStage],
+    stage_schedule: &StageSchedule,
+    register_st_mint_weight: Option<u128>,
+    websocket_url: Option<String>,
+    state_file: Option<String>,
+    account_payer_top_up: Option<AccountPayerTopUp>,
+    alerts: KeeperAlerts,
+    mut leader: Option<KeeperLeader>,
+    once: bool,
+) -> Result<PassOutcome> {
     let mut state: KeeperState = KeeperState::default();
     let mut epoch_stall = false;
-    let mut current_keeper_epoch = handler.epoch;
+    let mut checkpoint = KeeperCheckpoint::load(state_file.as_deref())?;
+    let mut current_keeper_epoch = checkpoint.keeper_epoch.unwrap_or(handler.epoch);
+    if current_keeper_epoch != handler.epoch {
+        info!("Resuming the keeper at epoch {}", current_keeper_epoch);
+    }
     let mut tick = 0;
+    let mut errors = 0;
+    let mut last_loop_start: Option<Instant> = None;
+    // Epochs whose consensus metrics were emitted
+    let mut consensus_emitted: HashSet<u64> = HashSet::new();
+
+    let is_enabled = |stage: KeeperStage| stages.contains(&stage);
+    info!(
+        "Keeper stages: {}",
+        stages
+            .iter()
+            .map(|stage| stage.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    // Slots into the epoch are only tracked when a stage has to wait for them
+    let epoch_schedule = if stage_schedule.is_empty() {
+        None
+    } else {
+        info!("Keeper stage schedule: {}", stage_schedule);
+        Some(handler.rpc_client().get_epoch_schedule().await?)
+    };
 
     let mut start_of_loop;
     let mut end_of_loop;
+    let mut slots_into_epoch = u64::MAX;
 
     // Set up metrics host identification
     let hostname_cmd = Command::new("hostname")
@@ -59,7 +130,59 @@ pub async fn startup_ncn_keeper(
 
     set_host_id(format!("ncn-program-keeper_{}", hostname));
 
+    let notifier = KeeperNotifier::start(
+        websocket_url,
+        handler.ncn_program_id,
+        *handler.ncn()?,
+        handler.commitment,
+        current_keeper_epoch,
+    );
+
     loop {
+        // A single pass stops at its first error instead of retrying
+        if once && errors > 0 {
+            break;
+        }
+
+        // LOOP INTERVAL
+        // Space out the iterations, every path through the loop ends up here
+        if let Some(last_loop_start) = last_loop_start {
+            let interval = Duration::from_millis(loop_interval_ms);
+            let elapsed = last_loop_start.elapsed();
+            if elapsed < interval {
+                sleep(interval - elapsed).await;
+            }
+        }
+        last_loop_start = Some(Instant::now());
+
+        // PHASE 0.0: KEEPER LEASE
+        // Only the instance holding the keeper lease cranks, the others stand by until it expires
+        if let Some(leader) = leader.as_mut() {
+            info!("\n\n0.0. Campaign For Keeper Lease - {}\n", leader.holder());
+            let result = leader.campaign(handler).await;
+
+            if check_and_timeout_error(
+                "Campaign For Keeper Lease".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+                &alerts,
+                &mut errors,
+            )
+            .await
+            {
+                continue;
+            }
+
+            if let Ok(false) = result {
+                if once {
+                    break;
+                }
+                timeout_keeper(&notifier, loop_timeout_ms).await;
+                continue;
+            }
+        }
+
         // PHASE 0.1: EPOCH PROGRESSION LOGIC
         // This will progress the epoch automatically based on various conditions:
         // - If a new epoch has started on the blockchain, move to it
@@ -73,7 +196,17 @@ pub async fn startup_ncn_keeper(
             let starting_epoch = handler.epoch;
             let keeper_epoch = current_keeper_epoch;
 
-            let (current_epoch, _) = get_guaranteed_epoch_and_slot(handler).await;
+            let (current_epoch, slot) = get_guaranteed_epoch_and_slot(handler).await;
+            if let Some(epoch_schedule) = epoch_schedule.as_ref() {
+                slots_into_epoch =
+                    slot.saturating_sub(epoch_schedule.get_first_slot_in_epoch(current_epoch));
+            }
+
+            // Done with the current epoch, the pass is over instead of starting again
+            if once && keeper_epoch == current_epoch && (state.is_epoch_completed || epoch_stall) {
+                break;
+            }
+
             let result = progress_epoch(
                 state.is_epoch_completed,
                 current_epoch,
@@ -91,6 +224,11 @@ pub async fn startup_ncn_keeper(
             }
 
             current_keeper_epoch = result;
+            notifier.set_epoch(current_keeper_epoch);
+            if checkpoint.keeper_epoch != Some(current_keeper_epoch) {
+                checkpoint.set_keeper_epoch(current_keeper_epoch);
+                save_checkpoint(&checkpoint);
+            }
             epoch_stall = false;
             start_of_loop = current_keeper_epoch == handler.epoch;
             end_of_loop = current_keeper_epoch == current_epoch;
@@ -109,36 +247,77 @@ pub async fn startup_ncn_keeper(
             &result,
             error_timeout_ms,
             state.epoch,
+            &alerts,
+            &mut errors,
         )
         .await;
 
-        // PHASE 0.3: VAULT REGISTRATION
-        // Register any outstanding vaults with the Global Vault Registry
-        // This is a prerequisite for other operations and can be done at any time
-        info!("\n\n0.3. Register Vaults - {}\n", current_keeper_epoch);
-        let result = crank_register_vaults(handler).await;
+        // PHASE 0.3: ACCOUNT PAYER TOP UP
+        // Top up the account payer before it runs out of lamports for the rent of the epoch
+        // accounts, or alert when the keeper is not allowed to
+        if let Some(account_payer_top_up) = &account_payer_top_up {
+            info!("\n\n0.3. Check Account Payer - {}\n", current_keeper_epoch);
+            let result = account_payer_top_up
+                .check(handler, current_keeper_epoch)
+                .await;
 
-        if check_and_timeout_error(
-            "Register Vaults".to_string(),
-            &result,
-            error_timeout_ms,
-            state.epoch,
-        )
-        .await
+            check_and_timeout_error(
+                "Check Account Payer".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+                &alerts,
+                &mut errors,
+            )
+            .await;
+        }
+
+        // PHASE 0.4: VAULT REGISTRATION
+        // Register any outstanding vaults with the Global Vault Registry
+        // This is a prerequisite for other operations and can be done at any time, the st mints of
+        // the new vaults are registered first when the keeper is configured with a weight for them
+        if is_enabled(KeeperStage::Register)
+            && stage_schedule.slots_to_wait(KeeperStage::Register, slots_into_epoch) == 0
         {
-            continue;
+            info!("\n\n0.4. Register Vaults - {}\n", current_keeper_epoch);
+            let result = crank_register_vaults(handler, register_st_mint_weight).await;
+
+            if check_and_timeout_error(
+                "Register Vaults".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+                &alerts,
+                &mut errors,
+            )
+            .await
+            {
+                continue;
+            }
         }
 
-        // PHASE 0.4: KEEPER STATE AND EPOCH STATE UPDATE
+        // PHASE 0.5: KEEPER STATE AND EPOCH STATE UPDATE
         // Fetch and update the keeper's internal state for the current epoch
         // This includes the EpochState account and derived information
         // We also update our local understanding of the epoch's progress
         {
             info!(
-                "\n\n0.4. Fetch and Update State - {}\n",
+                "\n\n0.5. Fetch and Update State - {}\n",
                 current_keeper_epoch
             );
 
+            // Closed epochs recorded in the state file are not fetched again
+            if state.epoch != current_keeper_epoch && checkpoint.is_completed(current_keeper_epoch)
+            {
+                info!("Epoch {} is complete", current_keeper_epoch);
+                state = KeeperState {
+                    epoch: current_keeper_epoch,
+                    is_epoch_completed: true,
+                    ..KeeperState::default()
+                };
+                continue;
+            }
+
             // If the epoch has changed, fetch the new epoch state
             if state.epoch != current_keeper_epoch {
                 let result = state.fetch(handler, current_keeper_epoch).await;
@@ -148,6 +327,8 @@ pub async fn startup_ncn_keeper(
                     &result,
                     error_timeout_ms,
                     state.epoch,
+                    &alerts,
+                    &mut errors,
                 )
                 .await
                 {
@@ -162,6 +343,8 @@ pub async fn startup_ncn_keeper(
                     &result,
                     error_timeout_ms,
                     state.epoch,
+                    &alerts,
+                    &mut errors,
                 )
                 .await
                 {
@@ -181,18 +364,58 @@ pub async fn startup_ncn_keeper(
         // If the epoch is marked as complete, move to next iteration
         if state.is_epoch_completed {
             info!("Epoch {} is complete", state.epoch);
+            if !checkpoint.is_completed(state.epoch) {
+                checkpoint.record_completed(state.epoch);
+                save_checkpoint(&checkpoint);
+            }
             continue;
         }
 
-        // If no epoch state account exists, create it and retry
+        // If no epoch state account exists, bootstrap the epoch accounts and retry. Epochs that
+        // stalled before they were started are marked as skipped instead
         if state.epoch_state.is_none() {
-            let result = create_epoch_state(handler, state.epoch).await;
+            if !is_enabled(KeeperStage::Snapshot) {
+                info!(
+                    "Waiting for another keeper to create the epoch accounts of {}",
+                    state.epoch
+                );
+                epoch_stall = true;
+                if end_of_loop {
+                    if once {
+                        break;
+                    }
+                    timeout_keeper(&notifier, loop_timeout_ms).await;
+                }
+                continue;
+            }
+
+            let slots_to_wait =
+                stage_schedule.slots_to_wait(KeeperStage::Snapshot, slots_into_epoch);
+            if slots_to_wait > 0 {
+                info!(
+                    "Waiting {} more slots to create the epoch accounts of {}",
+                    slots_to_wait, state.epoch
+                );
+                epoch_stall = true;
+                if end_of_loop {
+                    if once {
+                        break;
+                    }
+                    timeout_keeper(&notifier, stage_timeout_ms(loop_timeout_ms, slots_to_wait))
+                        .await;
+                }
+                continue;
+            }
+
+            let result = crank_create_or_skip_epoch(handler, state.epoch).await;
 
             check_and_timeout_error(
-                "Create Epoch State".to_string(),
+                "Create Epoch Accounts".to_string(),
                 &result,
                 error_timeout_ms,
                 state.epoch,
+                &alerts,
+                &mut errors,
             )
             .await;
 
@@ -210,11 +433,95 @@ pub async fn startup_ncn_keeper(
             current_state, current_keeper_epoch
         );
 
+        // Alert on the outcome of the vote, whichever keeper runs the vote stage
+        if matches!(current_state, State::Vote | State::PostVoteCooldown) {
+            let result = alerts.check_vote(handler, state.epoch).await;
+
+            check_and_timeout_error(
+                "Check Vote Alerts".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+                &alerts,
+                &mut errors,
+            )
+            .await;
+        }
+
+        let route = is_enabled(KeeperStage::Route);
+        let distribute = is_enabled(KeeperStage::Distribute);
+        let stage_enabled = match current_state {
+            State::SetWeight | State::Snapshot => is_enabled(KeeperStage::Snapshot),
+            State::Vote | State::PostVoteCooldown => is_enabled(KeeperStage::Vote),
+            State::Distribute => route || distribute,
+            State::Close => is_enabled(KeeperStage::Close),
+        };
+
+        if !stage_enabled {
+            info!(
+                "Stage of [{:?}] is disabled, leaving epoch {} to another keeper",
+                current_state, state.epoch
+            );
+            epoch_stall = true;
+            if end_of_loop {
+                if once {
+                    break;
+                }
+                timeout_keeper(&notifier, loop_timeout_ms).await;
+            }
+            continue;
+        }
+
+        // Route and distribute wait on their own minimum slots, the stage waits for the first
+        let slots_to_wait_for = |stage: KeeperStage| {
+            if is_enabled(stage) {
+                stage_schedule.slots_to_wait(stage, slots_into_epoch)
+            } else {
+                u64::MAX
+            }
+        };
+        let route = route && slots_to_wait_for(KeeperStage::Route) == 0;
+        let distribute = distribute && slots_to_wait_for(KeeperStage::Distribute) == 0;
+        let slots_to_wait = match current_state {
+            State::SetWeight | State::Snapshot => slots_to_wait_for(KeeperStage::Snapshot),
+            State::Vote | State::PostVoteCooldown => slots_to_wait_for(KeeperStage::Vote),
+            State::Distribute => slots_to_wait_for(KeeperStage::Route)
+                .min(slots_to_wait_for(KeeperStage::Distribute)),
+            State::Close => slots_to_wait_for(KeeperStage::Close),
+        };
+
+        if slots_to_wait > 0 {
+            info!(
+                "Stage of [{:?}] waits {} more slots, {} slots into the current epoch",
+                current_state, slots_to_wait, slots_into_epoch
+            );
+            epoch_stall = true;
+            if end_of_loop {
+                if once {
+                    break;
+                }
+                timeout_keeper(&notifier, stage_timeout_ms(loop_timeout_ms, slots_to_wait)).await;
+            }
+            continue;
+        }
+
         let result = match current_state {
             // SetWeight: Establish stake weights for all supported tokens
             State::SetWeight => crank_set_weight(handler, state.epoch).await,
-            // Snapshot: Capture operator and vault state snapshots
-            State::Snapshot => crank_snapshot(handler, state.epoch).await,
+            // Snapshot: Capture operator and vault state snapshots, operators finalized before
+            // are skipped
+            State::Snapshot => {
+                let skip_operators = checkpoint
+                    .epoch(state.epoch)
+                    .map(|progress| progress.snapshotted_operators())
+                    .unwrap_or_default();
+
+                crank_snapshot(handler, state.epoch, &skip_operators)
+                    .await
+                    .map(|finalized_operators| {
+                        checkpoint.record_snapshotted_operators(state.epoch, &finalized_operators)
+                    })
+            }
             // Vote: No need to do anything here
             State::Vote => {
                 info!("No explicit handling for voting phase. System will wait and re-evaluate.");
@@ -224,10 +531,21 @@ pub async fn startup_ncn_keeper(
             // consensus result
             State::PostVoteCooldown => crank_post_vote_cooldown(handler, state.epoch).await,
 
-            State::Distribute => crank_distribute(handler, state.epoch).await,
+            State::Distribute => {
+                crank_route_and_distribute_from_checkpoint(
+                    handler,
+                    &mut checkpoint,
+                    state.epoch,
+                    route,
+                    distribute,
+                )
+                .await
+            }
 
             // Close: Finalize and close the epoch's accounts
-            State::Close => crank_close_epoch_accounts(handler, state.epoch).await,
+            State::Close => {
+                crank_close_epoch_accounts(handler, state.epoch, &EpochAccountType::ALL).await
+            }
         };
 
         if check_and_timeout_error(
@@ -235,12 +553,17 @@ pub async fn startup_ncn_keeper(
             &result,
             error_timeout_ms,
             state.epoch,
+            &alerts,
+            &mut errors,
         )
         .await
         {
             continue;
         }
 
+        checkpoint.record_stage(state.epoch, current_state);
+        save_checkpoint(&checkpoint);
+
         // PHASE 4: EPOCH METRICS EMISSION
         // Emit detailed metrics about the current epoch's state and progress
         info!("\n\n4. Emit Epoch Metrics - {}\n", current_keeper_epoch);
@@ -251,9 +574,32 @@ pub async fn startup_ncn_keeper(
             &result,
             error_timeout_ms,
             state.epoch,
+            &alerts,
+            &mut errors,
         )
         .await;
 
+        // Consensus latency is emitted once, when the keeper first sees consensus
+        if matches!(current_state, State::Vote | State::PostVoteCooldown)
+            && !consensus_emitted.contains(&state.epoch)
+        {
+            let result = emit_consensus_metrics(handler, state.epoch).await;
+
+            if let Ok(true) = result {
+                consensus_emitted.insert(state.epoch);
+            }
+
+            check_and_timeout_error(
+                "Emit Consensus Metrics".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+                &alerts,
+                &mut errors,
+            )
+            .await;
+        }
+
         // PHASE 5: STALL DETECTION
         // Detect if the epoch has stalled and should be progressed
         {
@@ -266,6 +612,8 @@ pub async fn startup_ncn_keeper(
                 &result,
                 error_timeout_ms,
                 state.epoch,
+                &alerts,
+                &mut errors,
             )
             .await
             {
@@ -283,13 +631,68 @@ pub async fn startup_ncn_keeper(
         // If we've reached the end of processing and detected a stall,
         // wait before the next iteration and emit a heartbeat
         if end_of_loop && epoch_stall {
+            if once {
+                break;
+            }
+
             info!("\n\n -- Timeout -- {}\n", current_keeper_epoch);
 
-            timeout_keeper(loop_timeout_ms).await;
+            timeout_keeper(&notifier, loop_timeout_ms).await;
             emit_heartbeat(tick).await;
             tick += 1;
         }
     }
+
+    Ok(PassOutcome::new(
+        errors,
+        handler.transactions_sent.load(Ordering::Relaxed),
+    ))
+}
+
+/// Routes and distributes the rewards of the epoch, skipping the operators the checkpoint found
+/// with nothing to route while the NCN reward router held the same rewards
+///
+/// Operators are only recorded after a pass that did not route NCN rewards: rewards routed
+/// during the pass can reach operators that were seen with nothing to route before.
+async fn crank_route_and_distribute_from_checkpoint(
+    handler: &CliHandler,
+    checkpoint: &mut KeeperCheckpoint,
+    epoch: u64,
+    route: bool,
+    distribute: bool,
+) -> Result<()> {
+    let ncn_rewards_before = get_ncn_reward_router(handler, epoch)
+        .await
+        .ok()
+        .map(|ncn_reward_router| ncn_reward_router.total_rewards());
+
+    let skip_operators = match (ncn_rewards_before, checkpoint.epoch(epoch)) {
+        (Some(ncn_rewards), Some(progress)) => progress.routed_operators(ncn_rewards),
+        _ => HashSet::new(),
+    };
+
+    let idle_operators =
+        crank_route_and_distribute(handler, epoch, route, distribute, &skip_operators).await?;
+
+    let ncn_reward_router = get_ncn_reward_router(handler, epoch).await?;
+    if ncn_rewards_before == Some(ncn_reward_router.total_rewards())
+        && !ncn_reward_router.still_routing()
+    {
+        checkpoint.record_routed_operators(
+            epoch,
+            ncn_reward_router.total_rewards(),
+            &idle_operators,
+        );
+    }
+
+    Ok(())
+}
+
+/// Persists the checkpoint, a failure only costs the progress made since the last save
+fn save_checkpoint(checkpoint: &KeeperCheckpoint) {
+    if let Err(e) = checkpoint.save() {
+        log::error!("Failed to save the keeper state: {:?}", e);
+    }
 }
 
 /// Determines the next epoch to process based on current conditions
@@ -341,6 +744,8 @@ async fn progress_epoch(
 /// * `result` - The result to check for errors
 /// * `error_timeout_ms` - How long to wait after an error
 /// * `keeper_epoch` - Current epoch for error context
+/// * `alerts` - Alerted when the operation keeps failing
+/// * `errors` - Errors counted so far
 ///
 /// # Returns
 /// `true` if an error occurred and was handled, `false` if no error
@@ -350,8 +755,13 @@ async fn check_and_timeout_error<T>(
     result: &Result<T>,
     error_timeout_ms: u64,
     keeper_epoch: u64,
+    alerts: &KeeperAlerts,
+    errors: &mut u64,
 ) -> bool {
+    alerts.record_result(&title, result, keeper_epoch).await;
+
     if let Err(e) = result {
+        *errors += 1;
         let error = format!("{:?}", e);
         let message = format!("Error: [{}] \n{}\n\n", title, error);
 
@@ -376,15 +786,27 @@ async fn timeout_error(duration_ms: u64) {
     sleep(Duration::from_millis(duration_ms)).await;
 }
 
+/// The loop timeout of a stage waiting for the epoch to reach its minimum slots, cut short once
+/// the slots should have passed
+fn stage_timeout_ms(loop_timeout_ms: u64, slots_to_wait: u64) -> u64 {
+    slots_to_wait
+        .saturating_mul(DEFAULT_MS_PER_SLOT)
+        .min(loop_timeout_ms)
+}
+
 /// Applies the main keeper loop timeout
 ///
 /// This timeout occurs when the keeper has completed all work for the current
 /// epoch and is waiting for external conditions to change (e.g., new epoch,
-/// operator votes, etc.).
+/// operator votes, etc.). It ends early when the notifier sees one of the
+/// watched accounts change.
 ///
 /// # Arguments
+/// * `notifier` - Websocket notifications of the keeper
 /// * `duration_ms` - Timeout duration in milliseconds
-async fn timeout_keeper(duration_ms: u64) {
+async fn timeout_keeper(notifier: &KeeperNotifier, duration_ms: u64) {
     info!("Keeper Timeout for {}s", duration_ms as f64 / 1000.0);
-    sleep(Duration::from_millis(duration_ms)).await;
+    if notifier.wait(duration_ms).await {
+        info!("Keeper woken up by an account change");
+    }
 }
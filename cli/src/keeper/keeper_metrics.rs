@@ -320,9 +320,9 @@ pub async fn emit_ncn_metrics_operators(handler: &CliHandler) -> Result<()> {
         // Check if the operator has voted in the current epoch
         // This is emitted for all operators to avoid NoData issues in alerting
         let operator_has_voted = ballot_box_result.as_ref().map_or(false, |ballot_box| {
-            ballot_box.operator_votes().iter().any(|operator_vote| {
-                operator_vote.operator() == &operator && !operator_vote.is_empty()
-            })
+            ballot_box
+                .iter_votes()
+                .any(|operator_vote| operator_vote.operator() == &operator)
         });
 
         datapoint_info!(
@@ -502,11 +502,7 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
             let total_stake_weight = epoch_snapshot.stake_weights().stake_weight();
 
             // Emit metrics for individual operator votes
-            for operator_vote in ballot_box.operator_votes() {
-                if operator_vote.is_empty() {
-                    continue;
-                }
-
+            for operator_vote in ballot_box.iter_votes() {
                 let ballot_index = operator_vote.ballot_index();
                 let ballot_tally = ballot_box.ballot_tallies()[ballot_index as usize];
                 let vote = format!("{:?}", ballot_tally.ballot().status());
@@ -617,6 +613,50 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
     Ok(())
 }
 
+/// Emits how long the epoch took to reach consensus and how many operators it took
+///
+/// `slots-to-consensus` counts the slots from the start of the epoch to the slot consensus was
+/// reached, `operators-voted-at-consensus` the operators that had voted by then. Returns whether
+/// consensus was reached, so the keeper only emits it once per epoch.
+pub async fn emit_consensus_metrics(handler: &CliHandler, epoch: u64) -> Result<bool> {
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+    if !ballot_box.is_consensus_reached() {
+        return Ok(false);
+    }
+
+    let (current_epoch, current_slot) = get_current_epoch_and_slot(handler).await?;
+    let is_current_epoch = current_epoch == epoch;
+
+    let epoch_schedule = handler.rpc_client().get_epoch_schedule().await?;
+    let epoch_start_slot = epoch_schedule.get_first_slot_in_epoch(epoch);
+    let slot_consensus_reached = ballot_box.slot_consensus_reached();
+    let slots_to_consensus = slot_consensus_reached.saturating_sub(epoch_start_slot);
+
+    let operators_voted_at_consensus = ballot_box
+        .iter_votes()
+        .filter(|vote| vote.slot_voted() <= slot_consensus_reached)
+        .count();
+
+    emit_epoch_datapoint!(
+        "ncn-program-keeper-ee-consensus",
+        is_current_epoch,
+        ("current-epoch", current_epoch, i64),
+        ("current-slot", current_slot, i64),
+        ("keeper-epoch", epoch, i64),
+        ("epoch-start-slot", epoch_start_slot, i64),
+        ("slot-consensus-reached", slot_consensus_reached, i64),
+        ("slots-to-consensus", slots_to_consensus, i64),
+        (
+            "operators-voted-at-consensus",
+            operators_voted_at_consensus,
+            i64
+        ),
+        ("operators-voted", ballot_box.operators_voted(), i64)
+    );
+
+    Ok(true)
+}
+
 /// Emits operator snapshot metrics for each operator in the epoch
 ///
 /// Operator snapshots capture the state of each operator at the time of
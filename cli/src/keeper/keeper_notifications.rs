@@ -0,0 +1,191 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use log::{error, info};
+use ncn_program_core::ncn_reward_router::NCNRewardReceiver;
+use ncn_program_sdk::filters::{ballot_box_filters, epoch_state_filters};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::pubsub_client::PubsubClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::RpcFilterType,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use tokio::{
+    sync::{watch, Notify},
+    time::{sleep, timeout},
+};
+
+/// Time to wait before reconnecting a dropped websocket
+const RECONNECT_TIMEOUT_MS: u64 = 5_000;
+
+/// Wakes the keeper up as soon as the accounts it cranks change
+///
+/// When a websocket URL is given, a background task subscribes to the epoch states and ballot
+/// boxes of the NCN and to the NCN reward receiver of the epoch the keeper works on. Any
+/// notification ends the current keeper timeout early, the keeper then re-evaluates the epoch
+/// right away instead of waiting for the next poll. Without a websocket URL the keeper only polls.
+pub struct KeeperNotifier {
+    notify: Arc<Notify>,
+    keeper_epoch: watch::Sender<u64>,
+}
+
+impl KeeperNotifier {
+    /// Starts the subscriptions in the background, reconnecting whenever the websocket drops
+    ///
+    /// # Arguments
+    /// * `websocket_url` - Websocket endpoint of the RPC, `None` to only poll
+    /// * `ncn_program_id` - The NCN program
+    /// * `ncn` - The NCN the keeper cranks
+    /// * `commitment` - Commitment of the notifications
+    /// * `epoch` - The first epoch the keeper works on
+    pub fn start(
+        websocket_url: Option<String>,
+        ncn_program_id: Pubkey,
+        ncn: Pubkey,
+        commitment: CommitmentConfig,
+        epoch: u64,
+    ) -> Self {
+        let notify = Arc::new(Notify::new());
+        let (keeper_epoch, epoch_receiver) = watch::channel(epoch);
+
+        if let Some(websocket_url) = websocket_url {
+            let notify = notify.clone();
+
+            tokio::spawn(async move {
+                let mut epoch_receiver = epoch_receiver;
+
+                loop {
+                    let result = subscribe(
+                        &websocket_url,
+                        &ncn_program_id,
+                        &ncn,
+                        commitment,
+                        &notify,
+                        &mut epoch_receiver,
+                    )
+                    .await;
+
+                    match result {
+                        // The keeper is gone
+                        Ok(()) => return,
+                        Err(e) => {
+                            error!("Keeper websocket dropped, reconnecting: {:?}", e);
+                            sleep(Duration::from_millis(RECONNECT_TIMEOUT_MS)).await;
+                        }
+                    }
+                }
+            });
+        }
+
+        Self {
+            notify,
+            keeper_epoch,
+        }
+    }
+
+    /// Follows the epoch the keeper works on, its NCN reward receiver is watched from then on
+    pub fn set_epoch(&self, epoch: u64) {
+        self.keeper_epoch.send_if_modified(|keeper_epoch| {
+            let modified = *keeper_epoch != epoch;
+            *keeper_epoch = epoch;
+            modified
+        });
+    }
+
+    /// Sleeps for `duration_ms` or until one of the watched accounts changes
+    ///
+    /// # Returns
+    /// `true` if the keeper was woken up by a notification
+    pub async fn wait(&self, duration_ms: u64) -> bool {
+        timeout(Duration::from_millis(duration_ms), self.notify.notified())
+            .await
+            .is_ok()
+    }
+}
+
+/// Forwards the notifications of one websocket connection to the keeper until it drops
+///
+/// # Returns
+/// `Ok` once the keeper stopped following epochs, an error when the connection drops
+async fn subscribe(
+    websocket_url: &str,
+    ncn_program_id: &Pubkey,
+    ncn: &Pubkey,
+    commitment: CommitmentConfig,
+    notify: &Notify,
+    epoch_receiver: &mut watch::Receiver<u64>,
+) -> Result<()> {
+    let pubsub_client = PubsubClient::new(websocket_url).await?;
+
+    let program_config = |filters: Vec<RpcFilterType>| RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(commitment),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    let (mut epoch_states, _) = pubsub_client
+        .program_subscribe(
+            ncn_program_id,
+            Some(program_config(epoch_state_filters(ncn, None))),
+        )
+        .await?;
+    let (mut ballot_boxes, _) = pubsub_client
+        .program_subscribe(
+            ncn_program_id,
+            Some(program_config(ballot_box_filters(ncn, None))),
+        )
+        .await?;
+
+    info!("Keeper websocket connected to {}", websocket_url);
+
+    loop {
+        let epoch = *epoch_receiver.borrow_and_update();
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(ncn_program_id, ncn, epoch).0;
+
+        let (mut ncn_reward_receiver_updates, unsubscribe) = pubsub_client
+            .account_subscribe(
+                &ncn_reward_receiver,
+                Some(RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(commitment),
+                    ..RpcAccountInfoConfig::default()
+                }),
+            )
+            .await?;
+
+        loop {
+            tokio::select! {
+                update = epoch_states.next() => {
+                    update.ok_or_else(|| anyhow!("Epoch state subscription closed"))?;
+                    notify.notify_one();
+                }
+                update = ballot_boxes.next() => {
+                    update.ok_or_else(|| anyhow!("Ballot box subscription closed"))?;
+                    notify.notify_one();
+                }
+                update = ncn_reward_receiver_updates.next() => {
+                    update.ok_or_else(|| anyhow!("NCN reward receiver subscription closed"))?;
+                    notify.notify_one();
+                }
+                changed = epoch_receiver.changed() => {
+                    if changed.is_err() {
+                        return Ok(());
+                    }
+
+                    // Resubscribe to the reward receiver of the new epoch
+                    break;
+                }
+            }
+        }
+
+        drop(ncn_reward_receiver_updates);
+        unsubscribe().await;
+    }
+}
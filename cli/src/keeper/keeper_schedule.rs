@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+
+use crate::args::KeeperStage;
+
+/// Minimum slots into the current epoch before each stage runs, so the keeper follows the
+/// timeline of the NCN, e.g. snapshots once the stake settled or closes away from the start of
+/// the epoch. Stages without a minimum run as soon as the epoch reaches them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StageSchedule {
+    min_slots: HashMap<KeeperStage, u64>,
+}
+
+impl StageSchedule {
+    /// Parses `<stage>=<slots>` entries, e.g. `snapshot=1000` and `close=200000`, themselves comma
+    /// separated or not. A stage is given at most once.
+    pub fn parse(entries: &[String]) -> Result<Self> {
+        let mut min_slots = HashMap::new();
+
+        for entry in entries
+            .iter()
+            .flat_map(|entry| entry.split(','))
+            .map(|entry| entry.trim())
+        {
+            if entry.is_empty() {
+                continue;
+            }
+
+            let (stage, slots) = entry.split_once('=').ok_or_else(|| {
+                anyhow!(
+                    "Invalid stage minimum slots {}, expected <stage>=<slots>",
+                    entry
+                )
+            })?;
+            let stage = KeeperStage::from_str(stage.trim(), true)
+                .map_err(|e| anyhow!("Invalid stage in {}: {}", entry, e))?;
+            let slots: u64 = slots
+                .trim()
+                .parse()
+                .map_err(|e| anyhow!("Invalid slots in {}: {}", entry, e))?;
+
+            if min_slots.insert(stage, slots).is_some() {
+                return Err(anyhow!(
+                    "Stage {} is given minimum slots more than once",
+                    stage
+                ));
+            }
+        }
+
+        Ok(Self { min_slots })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_slots.is_empty()
+    }
+
+    pub fn min_slots(&self, stage: KeeperStage) -> u64 {
+        self.min_slots.get(&stage).copied().unwrap_or_default()
+    }
+
+    /// Slots left before the stage runs, 0 once the epoch is far enough
+    pub fn slots_to_wait(&self, stage: KeeperStage, slots_into_epoch: u64) -> u64 {
+        self.min_slots(stage).saturating_sub(slots_into_epoch)
+    }
+}
+
+/// Written the way it is parsed, e.g. `snapshot=1000,close=200000`
+impl std::fmt::Display for StageSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = KeeperStage::ALL
+            .iter()
+            .filter(|stage| self.min_slots.contains_key(stage))
+            .map(|stage| format!("{}={}", stage, self.min_slots(*stage)))
+            .collect();
+
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(entries: &[&str]) -> Result<StageSchedule> {
+        StageSchedule::parse(
+            &entries
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let schedule = parse(&["snapshot=1000", " Close = 200000 ", ""]).unwrap();
+
+        assert_eq!(schedule.min_slots(KeeperStage::Snapshot), 1_000);
+        assert_eq!(schedule.min_slots(KeeperStage::Close), 200_000);
+        assert_eq!(schedule.min_slots(KeeperStage::Vote), 0);
+
+        // A single comma separated entry, as read from a file or an environment variable
+        assert_eq!(parse(&["snapshot=1000,close=200000"]).unwrap(), schedule);
+
+        assert!(parse(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_malformed_entries() {
+        assert!(parse(&["snapshot"]).is_err());
+        assert!(parse(&["snapshot:1000"]).is_err());
+        assert!(parse(&["snapshot="]).is_err());
+        assert!(parse(&["snapshot=-1"]).is_err());
+        assert!(parse(&["snapshot=1e3"]).is_err());
+        assert!(parse(&["=1000"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_stage() {
+        assert!(parse(&["snapshots=1000"]).is_err());
+        assert!(parse(&["snapshot=1000", "settle=1000"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_duplicate_stage() {
+        assert!(parse(&["snapshot=1000", "snapshot=2000"]).is_err());
+        assert!(parse(&["snapshot=1000,SNAPSHOT=1000"]).is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let schedule = parse(&["close=200000", "snapshot=1000", "register=0"]).unwrap();
+
+        // Written in the order of the stages
+        assert_eq!(
+            schedule.to_string(),
+            "register=0,snapshot=1000,close=200000"
+        );
+        assert_eq!(parse(&[schedule.to_string().as_str()]).unwrap(), schedule);
+
+        let empty = StageSchedule::default();
+        assert_eq!(parse(&[empty.to_string().as_str()]).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_slots_to_wait() {
+        let schedule = parse(&["snapshot=1000"]).unwrap();
+
+        // Right after the epoch boundary the whole minimum is left
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Snapshot, 0), 1_000);
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Snapshot, 999), 1);
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Snapshot, 1_000), 0);
+
+        // Once the minimum passed, until the next epoch starts over at slot 0
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Snapshot, 5_000), 0);
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Snapshot, u64::MAX), 0);
+
+        // Stages without a minimum never wait
+        assert_eq!(schedule.slots_to_wait(KeeperStage::Close, 0), 0);
+    }
+}
@@ -1,3 +1,9 @@
+pub mod keeper_account_payer;
+pub mod keeper_alerts;
+pub mod keeper_checkpoint;
+pub mod keeper_lease;
 pub mod keeper_loop;
 pub mod keeper_metrics;
+pub mod keeper_notifications;
+pub mod keeper_schedule;
 pub mod keeper_state;
@@ -1,8 +1,29 @@
 pub mod args;
+pub mod audit;
+pub mod epoch_accounts;
+pub mod export;
 pub mod getters;
 pub mod handler;
 pub mod instructions;
+pub mod journal;
 pub mod log;
+pub mod lookup_tables;
+pub mod proposal;
+pub mod rate_limit;
+pub mod report;
+pub mod retry;
+pub mod rpc_failover;
+pub mod run_once;
+pub mod settings;
+pub mod signer;
+pub mod simulate_epoch;
+pub mod snapshot_progress;
+pub mod state_file;
+pub mod stats;
+pub mod test_env;
+pub mod verify_config;
+pub mod vote_journal;
+pub mod watch;
 
 #[path = "keeper/mod.rs"]
 pub mod keeper;
@@ -0,0 +1,182 @@
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use ncn_program_sdk::lookup_tables::epoch_lookup_table_addresses;
+use solana_sdk::{
+    address_lookup_table::{
+        instruction::{create_lookup_table, extend_lookup_table},
+        state::LOOKUP_TABLE_MAX_ADDRESSES,
+    },
+    commitment_config::CommitmentConfig,
+    pubkey::Pubkey,
+    signer::Signer,
+};
+
+use crate::{
+    getters::{get_all_operators_in_ncn, get_epoch_lookup_table},
+    handler::CliHandler,
+    instructions::send_and_log_transaction,
+};
+
+/// Addresses added by every extend transaction, 32 bytes each within the packet size
+const ADDRESSES_PER_EXTEND: usize = 20;
+
+/// Creates the lookup table of the epoch accounts of every operator in the NCN, owned by the
+/// keypair. The epoch state goes in with the creation, the table is found again by it.
+pub async fn create_epoch_lookup_table(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let authority = handler.keypair()?.pubkey();
+
+    if let Some(lookup_table) = get_epoch_lookup_table(handler, &authority, epoch).await? {
+        return Err(anyhow!(
+            "Epoch {} already has the lookup table {}, extend it instead",
+            epoch,
+            lookup_table.key
+        ));
+    }
+
+    let addresses = epoch_addresses(handler, epoch).await?;
+    let (first_addresses, other_addresses) =
+        addresses.split_at(addresses.len().min(ADDRESSES_PER_EXTEND));
+
+    // The table address is derived from a slot the cluster still holds the hash of
+    let recent_slot = handler
+        .rpc_client()
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .await?;
+    let (create_instruction, lookup_table) = create_lookup_table(authority, authority, recent_slot);
+    let extend_instruction = extend_lookup_table(
+        lookup_table,
+        authority,
+        Some(authority),
+        first_addresses.to_vec(),
+    );
+
+    send_and_log_transaction(
+        handler,
+        &[create_instruction, extend_instruction],
+        &[],
+        "Created Epoch Lookup Table",
+        &[
+            format!("NCN: {:?}", handler.ncn()?),
+            format!("Epoch: {:?}", epoch),
+            format!("Lookup Table: {:?}", lookup_table),
+            format!("Addresses: {:?}", first_addresses.len()),
+        ],
+    )
+    .await?;
+
+    extend_with(handler, &lookup_table, epoch, other_addresses).await
+}
+
+/// Adds the epoch accounts missing from the lookup table of the epoch, e.g. of operators that
+/// joined the NCN since it was created
+pub async fn extend_epoch_lookup_table(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let authority = handler.keypair()?.pubkey();
+
+    let lookup_table = get_epoch_lookup_table(handler, &authority, epoch)
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "Epoch {} has no lookup table of {}, create it first",
+                epoch,
+                authority
+            )
+        })?;
+
+    let missing_addresses: Vec<Pubkey> = epoch_addresses(handler, epoch)
+        .await?
+        .into_iter()
+        .filter(|address| !lookup_table.addresses.contains(address))
+        .collect();
+
+    if missing_addresses.is_empty() {
+        info!(
+            "Lookup table {} already holds every account of epoch {}",
+            lookup_table.key, epoch
+        );
+        return Ok(());
+    }
+
+    extend_with(handler, &lookup_table.key, epoch, &missing_addresses).await
+}
+
+/// Loads the lookup table of the epoch once, for the transactions of the batched cranks. The
+/// cranks carry on without it when it can not be fetched.
+pub async fn load_epoch_lookup_table(handler: &CliHandler, epoch: u64) {
+    if let Ok(epoch_lookup_tables) = handler.epoch_lookup_tables.lock() {
+        if epoch_lookup_tables.contains_key(&epoch) {
+            return;
+        }
+    }
+
+    let Ok(keypair) = handler.keypair() else {
+        return;
+    };
+
+    match get_epoch_lookup_table(handler, &keypair.pubkey(), epoch).await {
+        Ok(lookup_table) => {
+            if let Some(lookup_table) = lookup_table.as_ref() {
+                info!(
+                    "Loaded lookup table {} of epoch {} with {} addresses",
+                    lookup_table.key,
+                    epoch,
+                    lookup_table.addresses.len()
+                );
+            }
+            if let Ok(mut epoch_lookup_tables) = handler.epoch_lookup_tables.lock() {
+                epoch_lookup_tables.insert(epoch, lookup_table);
+            }
+        }
+        Err(e) => warn!("Could not fetch the lookup table of epoch {}: {}", epoch, e),
+    }
+}
+
+async fn epoch_addresses(handler: &CliHandler, epoch: u64) -> Result<Vec<Pubkey>> {
+    let operators = get_all_operators_in_ncn(handler).await?;
+    let addresses =
+        epoch_lookup_table_addresses(&handler.ncn_program_id, handler.ncn()?, epoch, &operators);
+
+    if addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+        return Err(anyhow!(
+            "The {} accounts of epoch {} do not fit in a lookup table of {} addresses",
+            addresses.len(),
+            epoch,
+            LOOKUP_TABLE_MAX_ADDRESSES
+        ));
+    }
+
+    Ok(addresses)
+}
+
+async fn extend_with(
+    handler: &CliHandler,
+    lookup_table: &Pubkey,
+    epoch: u64,
+    addresses: &[Pubkey],
+) -> Result<()> {
+    let authority = handler.keypair()?.pubkey();
+
+    for chunk in addresses.chunks(ADDRESSES_PER_EXTEND) {
+        let instruction =
+            extend_lookup_table(*lookup_table, authority, Some(authority), chunk.to_vec());
+
+        send_and_log_transaction(
+            handler,
+            &[instruction],
+            &[],
+            "Extended Epoch Lookup Table",
+            &[
+                format!("Epoch: {:?}", epoch),
+                format!("Lookup Table: {:?}", lookup_table),
+                format!("Addresses: {:?}", chunk.len()),
+            ],
+        )
+        .await?;
+    }
+
+    // Loaded again with its new addresses
+    if let Ok(mut epoch_lookup_tables) = handler.epoch_lookup_tables.lock() {
+        epoch_lookup_tables.remove(&epoch);
+    }
+
+    Ok(())
+}
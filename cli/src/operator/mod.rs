@@ -1,3 +1,7 @@
+pub mod operator_checkpoint;
 pub mod operator_loop;
 pub mod operator_metrics;
+pub mod operator_prometheus;
 pub mod operator_state;
+pub mod vote_server;
+pub mod vote_source;
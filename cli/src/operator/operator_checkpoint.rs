@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Result;
+use log::info;
+use ncn_program_core::epoch_state::State;
+use serde::{Deserialize, Serialize};
+
+use crate::state_file;
+
+/// Progress of the operator loop, persisted to a JSON file so a restarted operator resumes at the
+/// epoch it was working on, with the vote it decided there, instead of starting over from the
+/// current epoch and asking the vote source again
+///
+/// Like the keeper state file it is only a shortcut, the on-chain epoch state and ballot box stay
+/// the source of truth.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OperatorCheckpoint {
+    /// The epoch the operator was working on
+    pub operator_epoch: Option<u64>,
+    pub epochs: BTreeMap<u64, OperatorEpochProgress>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// Progress of the operator in one epoch
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct OperatorEpochProgress {
+    /// The last state cranked without error
+    pub last_state: Option<String>,
+    /// The weather status the vote source decided, voted again after a restart
+    pub weather_status: Option<u8>,
+}
+
+impl OperatorCheckpoint {
+    /// Reads the checkpoint from `path`, starting a new one when the file does not exist yet.
+    /// Without a path the progress is kept in memory only.
+    pub fn load(path: Option<&str>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let path = PathBuf::from(path);
+
+        let mut checkpoint = match state_file::load::<Self>(&path, "operator state file")? {
+            Some(checkpoint) => {
+                info!(
+                    "Loaded operator state from {}, operator epoch {:?}",
+                    path.display(),
+                    checkpoint.operator_epoch
+                );
+                checkpoint
+            }
+            None => Self::default(),
+        };
+
+        checkpoint.path = Some(path);
+        Ok(checkpoint)
+    }
+
+    /// Writes the checkpoint to disk, see `state_file::save`
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        state_file::save(path, self)
+    }
+
+    pub fn weather_status(&self, epoch: u64) -> Option<u8> {
+        self.epochs
+            .get(&epoch)
+            .and_then(|progress| progress.weather_status)
+    }
+
+    pub fn set_operator_epoch(&mut self, epoch: u64) {
+        self.operator_epoch = Some(epoch);
+        state_file::retain_recent_epochs(&mut self.epochs, epoch);
+    }
+
+    pub fn record_state(&mut self, epoch: u64, state: State) {
+        self.epochs.entry(epoch).or_default().last_state = Some(format!("{:?}", state));
+    }
+
+    pub fn record_weather_status(&mut self, epoch: u64, weather_status: u8) {
+        self.epochs.entry(epoch).or_default().weather_status = Some(weather_status);
+    }
+}
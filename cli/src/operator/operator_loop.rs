@@ -1,16 +1,23 @@
-use std::time::Duration;
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
 
 use crate::{
     getters::{get_ballot_box, get_guaranteed_epoch_and_slot, get_operator_snapshot},
     handler::CliHandler,
     instructions::{operator_crank_post_vote, operator_crank_vote},
     operator::{
+        operator_checkpoint::OperatorCheckpoint,
         operator_metrics::{
             emit_error, emit_heartbeat, emit_ncn_metrics_operator_post_vote,
             emit_ncn_metrics_operator_vote,
         },
+        operator_prometheus::OperatorPrometheusMetrics,
         operator_state::KeeperState,
+        vote_source::VoteSource,
     },
+    run_once::PassOutcome,
 };
 use anyhow::Result;
 use log::info;
@@ -30,19 +37,32 @@ use tokio::time::sleep;
 /// * `loop_timeout_ms` - Timeout between main loop iterations in milliseconds
 /// * `error_timeout_ms` - Timeout after errors in milliseconds
 /// * `operator` - Public key of the operator
+/// * `vote_source` - Source of the weather status voted in each epoch
+/// * `prometheus_metrics` - Metrics served to Prometheus
+/// * `state_file` - File the progress is persisted to, a restarted operator resumes from it
+/// * `once` - Make a single pass over the epochs up to the current one instead of looping
 ///
 /// # Returns
-/// * Result indicating success or failure (though this function loops indefinitely)
-#[allow(clippy::large_stack_frames)]
+/// * How the pass went with `once`, the loop runs indefinitely otherwise
+#[allow(clippy::large_stack_frames, clippy::too_many_arguments)]
 pub async fn startup_operator_loop(
     handler: &CliHandler,
     loop_timeout_ms: u64,
     error_timeout_ms: u64,
     operator: Pubkey,
-) -> Result<()> {
+    vote_source: &dyn VoteSource,
+    prometheus_metrics: &OperatorPrometheusMetrics,
+    state_file: Option<String>,
+    once: bool,
+) -> Result<PassOutcome> {
     let mut state: KeeperState = KeeperState::default();
-    let mut current_keeper_epoch = handler.epoch;
+    let mut checkpoint = OperatorCheckpoint::load(state_file.as_deref())?;
+    let mut current_keeper_epoch = checkpoint.operator_epoch.unwrap_or(handler.epoch);
+    if current_keeper_epoch != handler.epoch {
+        info!("Resuming the operator at epoch {}", current_keeper_epoch);
+    }
     let mut tick = 0;
+    let mut errors = 0;
 
     let mut end_of_loop;
 
@@ -59,6 +79,13 @@ pub async fn startup_operator_loop(
     set_host_id(format!("ncn-operator-keeper_{}", hostname));
 
     loop {
+        prometheus_metrics.record_errors(errors);
+
+        // A single pass stops at its first error instead of retrying
+        if once && errors > 0 {
+            break;
+        }
+
         // Progress to next epoch if needed
         // If a new epoch has started, advance to it
         // If there's still work in the current epoch, stay on it
@@ -70,6 +97,12 @@ pub async fn startup_operator_loop(
             let starting_epoch = handler.epoch;
 
             let (current_epoch, _) = get_guaranteed_epoch_and_slot(handler).await;
+
+            // Done with the current epoch, the pass is over instead of starting again
+            if once && state.is_epoch_completed && current_keeper_epoch == current_epoch {
+                break;
+            }
+
             let result = progress_epoch(
                 state.is_epoch_completed,
                 current_epoch,
@@ -86,7 +119,12 @@ pub async fn startup_operator_loop(
             }
 
             current_keeper_epoch = result;
+            if checkpoint.operator_epoch != Some(current_keeper_epoch) {
+                checkpoint.set_operator_epoch(current_keeper_epoch);
+                save_checkpoint(&checkpoint);
+            }
             end_of_loop = current_keeper_epoch == current_epoch;
+            prometheus_metrics.record_epochs(current_keeper_epoch, current_epoch);
         }
 
         // Keeper state and epoch state update
@@ -95,6 +133,7 @@ pub async fn startup_operator_loop(
         // We also update our local understanding of the epoch's progress
         {
             info!("\n\n0. Fetch and Update State - {}\n", current_keeper_epoch);
+            let fetch_started_at = Instant::now();
 
             // If the epoch has changed, fetch the new epoch state
             if state.epoch != current_keeper_epoch {
@@ -105,6 +144,7 @@ pub async fn startup_operator_loop(
                     &result,
                     error_timeout_ms,
                     state.epoch,
+                    &mut errors,
                 )
                 .await
                 {
@@ -119,12 +159,15 @@ pub async fn startup_operator_loop(
                     &result,
                     error_timeout_ms,
                     state.epoch,
+                    &mut errors,
                 )
                 .await
                 {
                     continue;
                 }
             }
+
+            prometheus_metrics.record_stage("FetchState", fetch_started_at.elapsed());
         }
 
         // Check the current state and perform appropriate actions
@@ -146,6 +189,7 @@ pub async fn startup_operator_loop(
             );
 
             // Handle different epoch states with appropriate actions
+            let crank_started_at = Instant::now();
             let crank_result = match current_crank_state {
                 // Weight and Snapshot states are passive - no operator action needed
                 State::SetWeight => {
@@ -175,8 +219,17 @@ pub async fn startup_operator_loop(
 
                     if can_operator_vote {
                         // If operator can vote:
-                        // 1. Cast the vote
-                        let result = operator_crank_vote(handler, state.epoch, &operator).await;
+                        // 1. Decide the vote, a restarted operator votes the decision it
+                        // persisted, and cast it
+                        let result = decide_vote(vote_source, &mut checkpoint, state.epoch).await;
+                        let result = match result {
+                            Ok(weather_status) => {
+                                operator_crank_vote(handler, state.epoch, &operator, weather_status)
+                                    .await
+                            }
+                            Err(e) => Err(e),
+                        };
+                        prometheus_metrics.record_vote(result.is_ok());
 
                         // 2. Handle any errors that occurred during voting
                         check_and_timeout_error(
@@ -184,6 +237,7 @@ pub async fn startup_operator_loop(
                             &result,
                             error_timeout_ms,
                             state.epoch,
+                            &mut errors,
                         )
                         .await;
 
@@ -204,6 +258,7 @@ pub async fn startup_operator_loop(
                             &result,
                             error_timeout_ms,
                             state.epoch,
+                            &mut errors,
                         )
                         .await;
                     } else {
@@ -226,6 +281,7 @@ pub async fn startup_operator_loop(
                             &result,
                             error_timeout_ms,
                             state.epoch,
+                            &mut errors,
                         )
                         .await;
 
@@ -250,27 +306,40 @@ pub async fn startup_operator_loop(
                         &result,
                         error_timeout_ms,
                         state.epoch,
+                        &mut errors,
                     )
                     .await;
                     state.is_epoch_completed = true;
                     Ok(())
                 }
             };
+            prometheus_metrics.record_stage(
+                &format!("{:?}", current_crank_state),
+                crank_started_at.elapsed(),
+            );
 
             if check_and_timeout_error(
                 format!("Crank State: {:?}", current_crank_state),
                 &crank_result,
                 error_timeout_ms,
                 state.epoch,
+                &mut errors,
             )
             .await
             {
                 continue;
             }
+
+            checkpoint.record_state(state.epoch, current_crank_state);
+            save_checkpoint(&checkpoint);
         }
 
         // Main loop timing control - add delay between iterations
         if end_of_loop {
+            if once {
+                break;
+            }
+
             info!("\n\nF. Timeout - {}\n", current_keeper_epoch);
 
             timeout_keeper(loop_timeout_ms).await;
@@ -280,6 +349,46 @@ pub async fn startup_operator_loop(
             tick += 1;
         }
     }
+
+    Ok(PassOutcome::new(
+        errors,
+        handler.transactions_sent.load(Ordering::Relaxed),
+    ))
+}
+
+/// The weather status to vote in the epoch, the one persisted in the checkpoint or else the one
+/// the vote source decides, persisted before it is voted
+async fn decide_vote(
+    vote_source: &dyn VoteSource,
+    checkpoint: &mut OperatorCheckpoint,
+    epoch: u64,
+) -> Result<u8> {
+    if let Some(weather_status) = checkpoint.weather_status(epoch) {
+        info!(
+            "Voting the weather status {} decided before the restart",
+            weather_status
+        );
+        return Ok(weather_status);
+    }
+
+    let weather_status = vote_source.weather_status(epoch).await?;
+    info!(
+        "Weather status from the {} vote source (0:Sunny, 1:Cloudy, 2:Rainy): {}",
+        vote_source.name(),
+        weather_status
+    );
+
+    checkpoint.record_weather_status(epoch, weather_status);
+    save_checkpoint(checkpoint);
+
+    Ok(weather_status)
+}
+
+/// Persists the checkpoint, a failure only costs the progress made since the last save
+fn save_checkpoint(checkpoint: &OperatorCheckpoint) {
+    if let Err(e) = checkpoint.save() {
+        log::error!("Failed to save the operator state: {:?}", e);
+    }
 }
 
 /// Determines whether to progress to the next epoch
@@ -323,6 +432,7 @@ async fn progress_epoch(
 /// * `result` - The result to check for errors
 /// * `error_timeout_ms` - Milliseconds to wait if an error is found
 /// * `keeper_epoch` - Current epoch for error reporting
+/// * `errors` - Errors counted so far
 ///
 /// # Returns
 /// * Boolean indicating whether an error was found (true = error)
@@ -332,8 +442,10 @@ async fn check_and_timeout_error<T>(
     result: &Result<T>,
     error_timeout_ms: u64,
     keeper_epoch: u64,
+    errors: &mut u64,
 ) -> bool {
     if let Err(e) = result {
+        *errors += 1;
         let error = format!("{:?}", e);
         let message = format!("Error: [{}] \n{}\n\n", title, error);
 
@@ -123,10 +123,7 @@ pub async fn emit_ncn_metrics_operator_post_vote(
     // Check if this operator has voted
     let did_operator_vote = ballot_box.did_operator_vote(operator);
     let operator_vote = if did_operator_vote {
-        ballot_box
-            .operator_votes()
-            .iter()
-            .find(|v| v.operator().eq(&operator))
+        ballot_box.iter_votes().find(|v| v.operator().eq(&operator))
     } else {
         None
     };
@@ -0,0 +1,231 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    future::IntoFuture,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use axum::{
+    extract::State,
+    http::{header::CONTENT_TYPE, HeaderName},
+    routing::get,
+    Router,
+};
+use log::info;
+use solana_sdk::pubkey::Pubkey;
+use tokio::net::TcpListener;
+
+/// Metrics of the operator loop, served in the Prometheus text format on `GET /metrics` of
+/// `--prometheus-address` in addition to the datapoints of `operator_metrics`
+#[derive(Debug)]
+pub struct OperatorPrometheusMetrics {
+    ncn: String,
+    operator: String,
+    votes_submitted: AtomicU64,
+    vote_failures: AtomicU64,
+    errors: AtomicU64,
+    keeper_epoch: AtomicU64,
+    current_epoch: AtomicU64,
+    stage_durations: Mutex<BTreeMap<String, StageDuration>>,
+}
+
+#[derive(Debug, Default)]
+struct StageDuration {
+    last_seconds: f64,
+    total_seconds: f64,
+    count: u64,
+}
+
+impl OperatorPrometheusMetrics {
+    pub fn new(ncn: &Pubkey, operator: &Pubkey) -> Self {
+        Self {
+            ncn: ncn.to_string(),
+            operator: operator.to_string(),
+            votes_submitted: AtomicU64::new(0),
+            vote_failures: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            keeper_epoch: AtomicU64::new(0),
+            current_epoch: AtomicU64::new(0),
+            stage_durations: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn record_vote(&self, submitted: bool) {
+        if submitted {
+            self.votes_submitted.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.vote_failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_errors(&self, errors: u64) {
+        self.errors.store(errors, Ordering::Relaxed);
+    }
+
+    pub fn record_epochs(&self, keeper_epoch: u64, current_epoch: u64) {
+        self.keeper_epoch.store(keeper_epoch, Ordering::Relaxed);
+        self.current_epoch.store(current_epoch, Ordering::Relaxed);
+    }
+
+    pub fn record_stage(&self, stage: &str, duration: Duration) {
+        if let Ok(mut stage_durations) = self.stage_durations.lock() {
+            let stage_duration = stage_durations.entry(stage.to_string()).or_default();
+            stage_duration.last_seconds = duration.as_secs_f64();
+            stage_duration.total_seconds += duration.as_secs_f64();
+            stage_duration.count += 1;
+        }
+    }
+
+    fn labels(&self) -> String {
+        format!("ncn=\"{}\",operator=\"{}\"", self.ncn, self.operator)
+    }
+
+    fn stage_durations(&self) -> Vec<(String, f64, f64, u64)> {
+        let labels = self.labels();
+
+        self.stage_durations
+            .lock()
+            .map(|stage_durations| {
+                stage_durations
+                    .iter()
+                    .map(|(stage, duration)| {
+                        (
+                            format!("{},stage=\"{}\"", labels, stage),
+                            duration.last_seconds,
+                            duration.total_seconds,
+                            duration.count,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// The metrics of the operator loops in the Prometheus text exposition format, one series per NCN
+pub fn render_metrics(operator_metrics: &[Arc<OperatorPrometheusMetrics>]) -> String {
+    let mut metrics = String::new();
+    let mut metric =
+        |name: &str, kind: &str, help: &str, value: &dyn Fn(&OperatorPrometheusMetrics) -> u64| {
+            let _ = writeln!(metrics, "# HELP {} {}", name, help);
+            let _ = writeln!(metrics, "# TYPE {} {}", name, kind);
+            for loop_metrics in operator_metrics {
+                let _ = writeln!(
+                    metrics,
+                    "{}{{{}}} {}",
+                    name,
+                    loop_metrics.labels(),
+                    value(loop_metrics)
+                );
+            }
+        };
+
+    metric(
+        "ncn_operator_votes_submitted_total",
+        "counter",
+        "Votes cast by the operator",
+        &|loop_metrics| loop_metrics.votes_submitted.load(Ordering::Relaxed),
+    );
+    metric(
+        "ncn_operator_vote_failures_total",
+        "counter",
+        "Votes the operator failed to cast",
+        &|loop_metrics| loop_metrics.vote_failures.load(Ordering::Relaxed),
+    );
+    metric(
+        "ncn_operator_errors_total",
+        "counter",
+        "Errors of the operator loop",
+        &|loop_metrics| loop_metrics.errors.load(Ordering::Relaxed),
+    );
+    metric(
+        "ncn_operator_keeper_epoch",
+        "gauge",
+        "Epoch the operator loop works on",
+        &|loop_metrics| loop_metrics.keeper_epoch.load(Ordering::Relaxed),
+    );
+    metric(
+        "ncn_operator_current_epoch",
+        "gauge",
+        "Current epoch of the chain",
+        &|loop_metrics| loop_metrics.current_epoch.load(Ordering::Relaxed),
+    );
+    metric(
+        "ncn_operator_epoch_lag",
+        "gauge",
+        "Epochs the operator loop is behind the current epoch",
+        &|loop_metrics| {
+            loop_metrics
+                .current_epoch
+                .load(Ordering::Relaxed)
+                .saturating_sub(loop_metrics.keeper_epoch.load(Ordering::Relaxed))
+        },
+    );
+
+    let stage_durations = operator_metrics
+        .iter()
+        .flat_map(|loop_metrics| loop_metrics.stage_durations())
+        .collect::<Vec<_>>();
+
+    let name = "ncn_operator_stage_last_duration_seconds";
+    let _ = writeln!(
+        metrics,
+        "# HELP {} Duration of the last run of each stage of the operator loop",
+        name
+    );
+    let _ = writeln!(metrics, "# TYPE {} gauge", name);
+    for (labels, last, _, _) in stage_durations.iter() {
+        let _ = writeln!(metrics, "{}{{{}}} {}", name, labels, last);
+    }
+
+    // A summary without quantiles, its sum and count give the average duration of the stages
+    let name = "ncn_operator_stage_duration_seconds";
+    let _ = writeln!(
+        metrics,
+        "# HELP {} Duration of the runs of each stage of the operator loop",
+        name
+    );
+    let _ = writeln!(metrics, "# TYPE {} summary", name);
+    for (labels, _, total, count) in stage_durations.iter() {
+        let _ = writeln!(metrics, "{}_sum{{{}}} {}", name, labels, total);
+        let _ = writeln!(metrics, "{}_count{{{}}} {}", name, labels, count);
+    }
+
+    metrics
+}
+
+/// Serves the metrics of the operator loops on `GET /metrics` of `address` until the server fails
+pub async fn serve_prometheus_metrics(
+    address: &str,
+    metrics: Vec<Arc<OperatorPrometheusMetrics>>,
+) -> Result<()> {
+    let app = Router::new()
+        .route("/metrics", get(get_metrics))
+        .with_state(Arc::new(metrics));
+
+    let listener = TcpListener::bind(address)
+        .await
+        .map_err(|e| anyhow!("Could not bind the metrics server to {}: {}", address, e))?;
+    info!("Prometheus metrics served on {}/metrics", address);
+
+    axum::serve(listener, app)
+        .into_future()
+        .await
+        .map_err(|e| anyhow!("Metrics server failed: {}", e))?;
+
+    Err(anyhow!("The metrics server stopped"))
+}
+
+async fn get_metrics(
+    State(metrics): State<Arc<Vec<Arc<OperatorPrometheusMetrics>>>>,
+) -> ([(HeaderName, &'static str); 1], String) {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_metrics(&metrics),
+    )
+}
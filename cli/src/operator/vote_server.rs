@@ -0,0 +1,419 @@
+use std::{
+    collections::HashMap,
+    future::IntoFuture,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use axum::{
+    extract::State,
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use subtle::ConstantTimeEq;
+use tokio::{
+    net::TcpListener,
+    sync::{mpsc, oneshot},
+};
+
+use crate::{
+    handler::CliHandler,
+    instructions::operator_cast_vote,
+    operator::vote_source::{checked_weather_status, VoteSource},
+    vote_journal::ConflictingBallot,
+};
+
+/// Votes the vote server cast, by epoch
+///
+/// As the `server` vote source it answers the weather status cast for the epoch, so the operator
+/// loop never decides a vote on its own. A vote the server could not cast, typically pushed before
+/// the ballot box was created, is not kept and has to be pushed again.
+#[derive(Debug, Clone, Default)]
+pub struct PushedVotes {
+    votes: Arc<Mutex<HashMap<u64, u8>>>,
+}
+
+impl PushedVotes {
+    fn push(&self, epoch: u64, weather_status: u8) {
+        if let Ok(mut votes) = self.votes.lock() {
+            votes.insert(epoch, weather_status);
+        }
+    }
+
+    fn get(&self, epoch: u64) -> Option<u8> {
+        self.votes
+            .lock()
+            .ok()
+            .and_then(|votes| votes.get(&epoch).copied())
+    }
+}
+
+#[async_trait]
+impl VoteSource for PushedVotes {
+    async fn weather_status(&self, epoch: u64) -> Result<u8> {
+        self.get(epoch).ok_or_else(|| {
+            anyhow!(
+                "No vote was pushed to the vote server for epoch {} yet",
+                epoch
+            )
+        })
+    }
+
+    fn name(&self) -> String {
+        "vote server".to_string()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct VoteRequest {
+    /// Needed when the operator votes for several NCNs
+    ncn: Option<String>,
+    epoch: u64,
+    value: u8,
+}
+
+#[derive(Serialize, Debug)]
+struct VoteResponse {
+    epoch: u64,
+    value: u8,
+    cast: bool,
+    error: Option<String>,
+}
+
+/// A pushed vote handed over to be cast with the borrowed handler of its NCN
+struct CastVote {
+    /// Index of the NCN among the voters
+    voter: usize,
+    epoch: u64,
+    weather_status: u8,
+    result: oneshot::Sender<Result<()>>,
+}
+
+#[derive(Clone)]
+struct VoteServerState {
+    token: Arc<String>,
+    /// The NCN of each voter and the votes pushed for it
+    voters: Arc<Vec<(Pubkey, PushedVotes)>>,
+    casts: mpsc::Sender<CastVote>,
+}
+
+/// Serves `POST /vote` on `address`, casting for `operator` the votes pushed by the operator
+/// infrastructure with the handler of their NCN, until the server fails
+///
+/// Requests carry `Authorization: Bearer <token>` and a `{"epoch": <epoch>, "value": <weather
+/// status>}` body, with the `"ncn"` of the vote when there are several voters. The server answers
+/// 200 once the vote is cast, 409 when the vote journal refuses it for another ballot voted in the
+/// epoch, and 503 when it could not be cast yet.
+pub async fn run_vote_server(
+    voters: Vec<(&CliHandler, PushedVotes)>,
+    operator: Pubkey,
+    address: &str,
+    token: String,
+) -> Result<()> {
+    if token.is_empty() {
+        return Err(anyhow!("The vote server needs a --vote-server-token"));
+    }
+
+    let (casts, mut cast_requests) = mpsc::channel::<CastVote>(16);
+    let app = Router::new()
+        .route("/vote", post(push_vote))
+        .with_state(VoteServerState {
+            token: Arc::new(token),
+            voters: Arc::new(
+                voters
+                    .iter()
+                    .map(|(handler, pushed_votes)| Ok((*handler.ncn()?, pushed_votes.clone())))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            casts,
+        });
+
+    let listener = TcpListener::bind(address)
+        .await
+        .map_err(|e| anyhow!("Could not bind the vote server to {}: {}", address, e))?;
+    info!("Vote server listening on {}", address);
+
+    // The requests are served on their own tasks, the votes are cast here with the handler
+    let cast_votes = async {
+        while let Some(cast_vote) = cast_requests.recv().await {
+            let (handler, _) = voters[cast_vote.voter];
+            let result = operator_cast_vote(
+                handler,
+                &operator,
+                cast_vote.epoch,
+                cast_vote.weather_status,
+            )
+            .await;
+            let _ = cast_vote.result.send(result);
+        }
+    };
+
+    tokio::select! {
+        result = axum::serve(listener, app).into_future() => {
+            result.map_err(|e| anyhow!("Vote server failed: {}", e))?;
+        }
+        () = cast_votes => {}
+    }
+
+    Err(anyhow!("The vote server stopped"))
+}
+
+async fn push_vote(
+    State(state): State<VoteServerState>,
+    headers: HeaderMap,
+    Json(request): Json<VoteRequest>,
+) -> (StatusCode, Json<VoteResponse>) {
+    if !is_authorized(&headers, &state.token) {
+        return vote_response(
+            StatusCode::UNAUTHORIZED,
+            &request,
+            false,
+            Some("Invalid or missing bearer token".to_string()),
+        );
+    }
+
+    if let Err(e) = checked_weather_status(request.value) {
+        return vote_response(
+            StatusCode::BAD_REQUEST,
+            &request,
+            false,
+            Some(e.to_string()),
+        );
+    }
+
+    let voter = match voter(&state.voters, request.ncn.as_deref()) {
+        Ok(voter) => voter,
+        Err(e) => {
+            return vote_response(
+                StatusCode::BAD_REQUEST,
+                &request,
+                false,
+                Some(e.to_string()),
+            )
+        }
+    };
+    let (ncn, pushed_votes) = &state.voters[voter];
+
+    info!(
+        "Vote of {} pushed for epoch {} of NCN {}",
+        request.value, request.epoch, ncn
+    );
+
+    let (result, cast_result) = oneshot::channel();
+    let cast_vote = CastVote {
+        voter,
+        epoch: request.epoch,
+        weather_status: request.value,
+        result,
+    };
+    let result = match state.casts.send(cast_vote).await {
+        Ok(()) => cast_result
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("The vote was not cast"))),
+        Err(_) => Err(anyhow!("The vote server is stopping")),
+    };
+
+    match result {
+        Ok(()) => {
+            // Only cast votes are served to the operator loop
+            pushed_votes.push(request.epoch, request.value);
+            vote_response(StatusCode::OK, &request, true, None)
+        }
+        Err(e) => {
+            error!(
+                "Failed to cast the vote pushed for epoch {}: {:?}",
+                request.epoch, e
+            );
+            let status = if e.downcast_ref::<ConflictingBallot>().is_some() {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::SERVICE_UNAVAILABLE
+            };
+            vote_response(status, &request, false, Some(e.to_string()))
+        }
+    }
+}
+
+/// Whether the request carries the bearer token. The token is compared in constant time, the
+/// time taken to refuse a guess does not tell how much of it is right.
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|bearer| bool::from(bearer.as_bytes().ct_eq(token.as_bytes())))
+}
+
+/// Index of the voter of the NCN, which can be left out when there is a single one
+fn voter(voters: &[(Pubkey, PushedVotes)], ncn: Option<&str>) -> Result<usize> {
+    match ncn {
+        Some(ncn) => {
+            let ncn = Pubkey::from_str(ncn).map_err(|e| anyhow!("Invalid NCN {}: {}", ncn, e))?;
+            voters
+                .iter()
+                .position(|(voter_ncn, _)| voter_ncn.eq(&ncn))
+                .ok_or_else(|| anyhow!("The operator does not vote for NCN {}", ncn))
+        }
+        None if voters.len() == 1 => Ok(0),
+        None => Err(anyhow!(
+            "The NCN of the vote is needed, the operator votes for several"
+        )),
+    }
+}
+
+fn vote_response(
+    status: StatusCode,
+    request: &VoteRequest,
+    cast: bool,
+    error: Option<String>,
+) -> (StatusCode, Json<VoteResponse>) {
+    (
+        status,
+        Json(VoteResponse {
+            epoch: request.epoch,
+            value: request.value,
+            cast,
+            error,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    const TOKEN: &str = "vote-server-token";
+
+    /// State of a vote server with a single voter, casting its votes with `cast`
+    fn vote_server_state(cast: fn(u64, u8) -> Result<()>) -> (VoteServerState, PushedVotes) {
+        let pushed_votes = PushedVotes::default();
+
+        let (casts, mut cast_requests) = mpsc::channel::<CastVote>(1);
+        tokio::spawn(async move {
+            while let Some(cast_vote) = cast_requests.recv().await {
+                let _ = cast_vote
+                    .result
+                    .send(cast(cast_vote.epoch, cast_vote.weather_status));
+            }
+        });
+
+        let state = VoteServerState {
+            token: Arc::new(TOKEN.to_string()),
+            voters: Arc::new(vec![(Pubkey::new_unique(), pushed_votes.clone())]),
+            casts,
+        };
+
+        (state, pushed_votes)
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", token)).unwrap(),
+        );
+        headers
+    }
+
+    async fn push(
+        state: &VoteServerState,
+        headers: HeaderMap,
+        epoch: u64,
+        value: u8,
+    ) -> (StatusCode, VoteResponse) {
+        let request = VoteRequest {
+            ncn: None,
+            epoch,
+            value,
+        };
+        let (status, Json(response)) =
+            push_vote(State(state.clone()), headers, Json(request)).await;
+
+        (status, response)
+    }
+
+    #[tokio::test]
+    async fn test_missing_token_is_unauthorized() {
+        let (state, pushed_votes) = vote_server_state(|_, _| Ok(()));
+
+        let (status, response) = push(&state, HeaderMap::new(), 10, 1).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(!response.cast);
+        assert_eq!(pushed_votes.get(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_token_is_unauthorized() {
+        let (state, pushed_votes) = vote_server_state(|_, _| Ok(()));
+
+        let (status, _) = push(&state, bearer("wrong-token"), 10, 1).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // A prefix of the token is not the token
+        let (status, _) = push(&state, bearer(&TOKEN[..TOKEN.len() - 1]), 10, 1).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        assert_eq!(pushed_votes.get(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_cast_vote_is_accepted() {
+        let (state, pushed_votes) = vote_server_state(|_, _| Ok(()));
+
+        let (status, response) = push(&state, bearer(TOKEN), 10, 1).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(response.cast);
+        assert_eq!(response.error, None);
+        assert_eq!(pushed_votes.get(10), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_ballot_is_a_conflict() {
+        let (state, pushed_votes) = vote_server_state(|epoch, weather_status| {
+            Err(ConflictingBallot {
+                operator: Pubkey::new_unique().to_string(),
+                epoch,
+                weather_status,
+                voted_weather_status: 0,
+                path: PathBuf::from("vote-journal.json"),
+            }
+            .into())
+        });
+
+        let (status, response) = push(&state, bearer(TOKEN), 10, 1).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(!response.cast);
+        assert!(response.error.is_some());
+        assert_eq!(pushed_votes.get(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_failed_cast_is_not_kept() {
+        let (state, pushed_votes) =
+            vote_server_state(|_, _| Err(anyhow!("Ballot box does not exist yet")));
+
+        let (status, response) = push(&state, bearer(TOKEN), 10, 1).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(!response.cast);
+        assert_eq!(pushed_votes.get(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_weather_status_is_a_bad_request() {
+        let (state, pushed_votes) = vote_server_state(|_, _| Ok(()));
+
+        let (status, _) = push(&state, bearer(TOKEN), 10, u8::MAX).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(pushed_votes.get(10), None);
+    }
+}
@@ -0,0 +1,361 @@
+use std::{fs, path::PathBuf, process::Stdio, time::Duration};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{info, warn};
+use ncn_program_core::ballot_box::WeatherStatus;
+use serde::Deserialize;
+use tokio::{process::Command, time::timeout};
+
+use crate::{args::VoteSourceKind, handler::CliHandler, operator::vote_server::PushedVotes};
+
+/// Time given to the HTTP vote source to answer
+const HTTP_VOTE_SOURCE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the operator loop gets the weather status it votes in each epoch
+///
+/// The operator loop asks the source once per epoch, when the operator can vote. A source failing
+/// skips the vote, it is retried on the next loop iteration.
+#[async_trait]
+pub trait VoteSource: Send + Sync {
+    /// The weather status to vote in the epoch (0:Sunny, 1:Cloudy, 2:Rainy)
+    async fn weather_status(&self, epoch: u64) -> Result<u8>;
+
+    /// Name of the source, for the logs
+    fn name(&self) -> String;
+}
+
+/// Builds the vote source of `run-operator`
+#[allow(clippy::too_many_arguments)]
+pub fn vote_source(
+    handler: &CliHandler,
+    kind: VoteSourceKind,
+    weather_status: u8,
+    file: Option<String>,
+    url: Option<String>,
+    command: Option<String>,
+    command_timeout_ms: u64,
+    pushed_votes: Option<&PushedVotes>,
+) -> Result<Box<dyn VoteSource>> {
+    let vote_source: Box<dyn VoteSource> = match kind {
+        VoteSourceKind::OpenWeather => Box::new(OpenWeatherVoteSource {
+            api_key: handler.open_weather_api_key.clone(),
+            city_name: "Solana Beach".to_string(),
+        }),
+        VoteSourceKind::Constant => Box::new(ConstantVoteSource {
+            weather_status: checked_weather_status(weather_status)?,
+        }),
+        VoteSourceKind::File => Box::new(FileVoteSource {
+            path: file
+                .map(PathBuf::from)
+                .ok_or_else(|| anyhow!("The file vote source needs --vote-file"))?,
+        }),
+        VoteSourceKind::Http => Box::new(HttpVoteSource {
+            url: url.ok_or_else(|| anyhow!("The HTTP vote source needs --vote-url"))?,
+        }),
+        VoteSourceKind::Command => Box::new(CommandVoteSource {
+            path: command
+                .map(PathBuf::from)
+                .ok_or_else(|| anyhow!("The command vote source needs --vote-command"))?,
+            timeout: Duration::from_millis(command_timeout_ms),
+        }),
+        VoteSourceKind::Server => Box::new(
+            pushed_votes
+                .cloned()
+                .ok_or_else(|| anyhow!("The server vote source needs --vote-server-address"))?,
+        ),
+    };
+
+    info!("Voting with the {} vote source", vote_source.name());
+    Ok(vote_source)
+}
+
+/// Votes the same weather status in every epoch
+#[derive(Debug)]
+pub struct ConstantVoteSource {
+    pub weather_status: u8,
+}
+
+#[async_trait]
+impl VoteSource for ConstantVoteSource {
+    async fn weather_status(&self, _epoch: u64) -> Result<u8> {
+        Ok(self.weather_status)
+    }
+
+    fn name(&self) -> String {
+        format!("constant ({})", self.weather_status)
+    }
+}
+
+/// Votes the weather status written in a file, read again in every epoch so the process
+/// producing the signal can rewrite it while the operator runs
+#[derive(Debug)]
+pub struct FileVoteSource {
+    pub path: PathBuf,
+}
+
+#[async_trait]
+impl VoteSource for FileVoteSource {
+    async fn weather_status(&self, _epoch: u64) -> Result<u8> {
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|e| anyhow!("Could not read {}: {}", self.path.display(), e))?;
+        let weather_status = contents
+            .trim()
+            .parse::<u8>()
+            .map_err(|e| anyhow!("Invalid weather status in {}: {}", self.path.display(), e))?;
+
+        checked_weather_status(weather_status)
+    }
+
+    fn name(&self) -> String {
+        format!("file ({})", self.path.display())
+    }
+}
+
+/// Votes the weather status answered by an HTTP endpoint to a GET of `url`, where `{epoch}` is
+/// replaced by the epoch. The endpoint answers `{"weather_status": <status>}`.
+#[derive(Debug)]
+pub struct HttpVoteSource {
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct HttpVoteResponse {
+    weather_status: u8,
+}
+
+#[async_trait]
+impl VoteSource for HttpVoteSource {
+    async fn weather_status(&self, epoch: u64) -> Result<u8> {
+        let url = self.url.replace("{epoch}", &epoch.to_string());
+
+        let response = reqwest::Client::builder()
+            .timeout(HTTP_VOTE_SOURCE_TIMEOUT)
+            .build()?
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<HttpVoteResponse>()
+            .await?;
+
+        checked_weather_status(response.weather_status)
+    }
+
+    fn name(&self) -> String {
+        format!("HTTP ({})", self.url)
+    }
+}
+
+/// Votes the weather status decided by a command, run in every epoch with the epoch as its
+/// argument
+///
+/// The command prints the weather status and exits with 0, or prints nothing and exits with the
+/// weather status. A command printing something else, exiting with another code, killed or running
+/// past the timeout fails the vote source, what it wrote to stderr is logged.
+#[derive(Debug)]
+pub struct CommandVoteSource {
+    pub path: PathBuf,
+    pub timeout: Duration,
+}
+
+#[async_trait]
+impl VoteSource for CommandVoteSource {
+    async fn weather_status(&self, epoch: u64) -> Result<u8> {
+        let output = Command::new(&self.path)
+            .arg(epoch.to_string())
+            .env("NCN_EPOCH", epoch.to_string())
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output();
+
+        let output = timeout(self.timeout, output)
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "{} did not decide the vote within {}ms",
+                    self.path.display(),
+                    self.timeout.as_millis()
+                )
+            })?
+            .map_err(|e| anyhow!("Could not run {}: {}", self.path.display(), e))?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.trim().is_empty() {
+            warn!("{} stderr: {}", self.path.display(), stderr.trim());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let weather_status = match (stdout.trim(), output.status.code()) {
+            ("", Some(code)) => u8::try_from(code)
+                .map_err(|_| anyhow!("{} exited with {}", self.path.display(), code))?,
+            (stdout, Some(0)) => stdout.parse::<u8>().map_err(|e| {
+                anyhow!(
+                    "Invalid weather status printed by {}: {}",
+                    self.path.display(),
+                    e
+                )
+            })?,
+            (_, code) => {
+                return Err(anyhow!(
+                    "{} failed with {}",
+                    self.path.display(),
+                    code.map_or_else(
+                        || "a signal".to_string(),
+                        |code| format!("exit code {}", code)
+                    )
+                ))
+            }
+        };
+
+        checked_weather_status(weather_status)
+    }
+
+    fn name(&self) -> String {
+        format!("command ({})", self.path.display())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WeatherInfo {
+    main: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct WeatherResponse {
+    weather: Vec<WeatherInfo>,
+}
+
+/// Votes the current weather of a city, from the OpenWeather API
+#[derive(Debug)]
+pub struct OpenWeatherVoteSource {
+    pub api_key: Option<String>,
+    pub city_name: String,
+}
+
+#[async_trait]
+impl VoteSource for OpenWeatherVoteSource {
+    async fn weather_status(&self, _epoch: u64) -> Result<u8> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            anyhow!("No Open Weather API key provided. Set the OPENWEATHER_API_KEY environment variable or pass it as an argument.")
+        })?;
+
+        let url = format!(
+            "http://api.openweathermap.org/data/2.5/weather?q={}&appid={}&units=metric",
+            self.city_name, api_key
+        );
+
+        let response = reqwest::get(&url).await?.json::<WeatherResponse>().await?;
+
+        if let Some(weather_condition) = response.weather.get(0) {
+            match weather_condition.main.as_str() {
+                "Clear" => Ok(0),                                      // Sunny
+                "Rain" | "Snow" | "Drizzle" | "Thunderstorm" => Ok(2), // Raining/Snowing
+                _ => Ok(1),                                            // Anything else
+            }
+        } else {
+            Ok(1) // Default to "Anything else" if no weather info is available
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("OpenWeather ({})", self.city_name)
+    }
+}
+
+/// The weather status when it is one the program accepts
+pub fn checked_weather_status(weather_status: u8) -> Result<u8> {
+    WeatherStatus::from_u8(weather_status)
+        .map(|_| weather_status)
+        .ok_or_else(|| anyhow!("Invalid weather status: {}", weather_status))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+
+    /// A `sh` script of its own for each test, run as the vote command
+    fn vote_command(name: &str, script: &str) -> CommandVoteSource {
+        let path =
+            std::env::temp_dir().join(format!("vote-command-{}-{}.sh", name, std::process::id()));
+        fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        CommandVoteSource {
+            path,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_command_prints_weather_status() {
+        let source = vote_command("prints", "echo 2");
+
+        assert_eq!(source.weather_status(10).await.unwrap(), 2);
+
+        fs::remove_file(&source.path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_command_gets_the_epoch() {
+        let source = vote_command(
+            "epoch",
+            r#"[ "$1" = 10 ] && [ "$NCN_EPOCH" = 10 ] && echo 2"#,
+        );
+
+        assert_eq!(source.weather_status(10).await.unwrap(), 2);
+
+        fs::remove_file(&source.path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_command_exits_with_weather_status() {
+        let source = vote_command("exits", "exit 1");
+
+        assert_eq!(source.weather_status(10).await.unwrap(), 1);
+
+        // Not a weather status
+        let invalid_source = vote_command("exits-invalid", "exit 3");
+        assert!(invalid_source.weather_status(10).await.is_err());
+
+        fs::remove_file(&source.path).unwrap();
+        fs::remove_file(&invalid_source.path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_command_unparseable_output() {
+        let source = vote_command("unparseable", "echo cloudy");
+
+        assert!(source.weather_status(10).await.is_err());
+
+        // Printing with a nonzero exit code is a failure, whatever is printed
+        let failing_source = vote_command("prints-and-fails", "echo 1; exit 2");
+        assert!(failing_source.weather_status(10).await.is_err());
+
+        fs::remove_file(&source.path).unwrap();
+        fs::remove_file(&failing_source.path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_command_timeout_kills_it() {
+        let marker =
+            std::env::temp_dir().join(format!("vote-command-marker-{}", std::process::id()));
+        let _ = fs::remove_file(&marker);
+
+        let mut source = vote_command(
+            "timeout",
+            &format!("sleep 1; touch {}; echo 1", marker.display()),
+        );
+        source.timeout = Duration::from_millis(100);
+
+        assert!(source.weather_status(10).await.is_err());
+
+        // The command would have left the marker by now had it not been killed
+        tokio::time::sleep(Duration::from_millis(1_500)).await;
+        assert!(!marker.exists());
+
+        fs::remove_file(&source.path).unwrap();
+    }
+}
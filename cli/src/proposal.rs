@@ -0,0 +1,83 @@
+use anyhow::Result;
+use base64::{engine::general_purpose, Engine};
+use ncn_program_sdk::governance::governance_instruction_data;
+use serde::Serialize;
+use solana_sdk::{bs58, instruction::Instruction, message::Message, pubkey::Pubkey};
+
+/// Admin instructions printed with `--as-proposal`, to be executed by the governance or multisig
+/// that is the NCN admin instead of signed by the keypair
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminProposal {
+    pub title: String,
+    pub details: Vec<String>,
+    /// The governance or multisig vault signing the instructions
+    pub authority: String,
+    pub instructions: Vec<ProposalInstruction>,
+    /// Base58 legacy message of the instructions paid by the authority, as imported by Squads
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalInstruction {
+    pub program_id: String,
+    pub accounts: Vec<ProposalAccountMeta>,
+    /// Base64 instruction data
+    pub data: String,
+    /// Base58 instruction data
+    pub data_base58: String,
+    /// Base64 `InstructionData` of an spl-governance proposal transaction
+    pub governance_instruction: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AdminProposal {
+    pub fn new(
+        authority: &Pubkey,
+        instructions: &[Instruction],
+        title: &str,
+        details: &[String],
+    ) -> Result<Self> {
+        let message = Message::new(instructions, Some(authority));
+
+        Ok(Self {
+            title: title.to_string(),
+            details: details.to_vec(),
+            authority: authority.to_string(),
+            instructions: instructions
+                .iter()
+                .map(ProposalInstruction::from_instruction)
+                .collect(),
+            message: bs58::encode(bincode::serialize(&message)?).into_string(),
+        })
+    }
+}
+
+impl ProposalInstruction {
+    fn from_instruction(instruction: &Instruction) -> Self {
+        Self {
+            program_id: instruction.program_id.to_string(),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| ProposalAccountMeta {
+                    pubkey: account.pubkey.to_string(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: general_purpose::STANDARD.encode(&instruction.data),
+            data_base58: bs58::encode(&instruction.data).into_string(),
+            governance_instruction: general_purpose::STANDARD
+                .encode(governance_instruction_data(instruction)),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use solana_client::{client_error::Result as ClientResult, rpc_request::RpcRequest};
+use solana_rpc_client::{
+    http_sender::HttpSender,
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use tokio::{sync::Mutex, time::sleep};
+
+/// Token bucket spacing out the requests sent to the RPC
+///
+/// The bucket holds up to `burst` requests and refills at `requests_per_second`. Requests finding
+/// it empty wait their turn, in the order they came in.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// `None` when `requests_per_second` is 0, no limit. A `burst` of 0 still lets one request
+    /// through at a time.
+    pub fn new(requests_per_second: u64, burst: u64) -> Option<Self> {
+        if requests_per_second == 0 {
+            return None;
+        }
+
+        let burst = burst.max(1) as f64;
+
+        Some(Self {
+            requests_per_second: requests_per_second as f64,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: burst,
+                refilled_at: Instant::now(),
+            }),
+        })
+    }
+
+    /// Waits until a request can be sent. The lock is held while waiting, so the next request
+    /// only starts waiting once this one is through.
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            let wait = (1.0 - bucket.tokens) / self.requests_per_second;
+            sleep(Duration::from_secs_f64(wait)).await;
+            self.refill(&mut bucket);
+        }
+
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second).min(self.burst);
+        bucket.refilled_at = now;
+    }
+}
+
+/// HTTP sender of the RPC client going through the rate limiter, so every getter and sender of
+/// the CLI is limited without knowing about it. With 0 requests per second it is a plain HTTP
+/// sender.
+pub struct RateLimitedSender {
+    sender: HttpSender,
+    limiter: Option<RateLimiter>,
+}
+
+impl RateLimitedSender {
+    pub fn new(url: String, requests_per_second: u64, burst: u64) -> Self {
+        Self {
+            sender: HttpSender::new(url),
+            limiter: RateLimiter::new(requests_per_second, burst),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcSender for RateLimitedSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+        self.sender.send(request, params).await
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.sender.get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.sender.url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_is_sent_at_once() {
+        let limiter = RateLimiter::new(2, 3).unwrap();
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(250));
+
+        // The bucket is empty, the next request waits for a token at 2 requests per second
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(490));
+    }
+
+    #[tokio::test]
+    async fn test_refill_is_capped_at_burst() {
+        let limiter = RateLimiter::new(4, 2).unwrap();
+        for _ in 0..2 {
+            limiter.acquire().await;
+        }
+
+        // A second refills 4 tokens, only 2 fit in the bucket
+        sleep(Duration::from_secs(1)).await;
+
+        let start = Instant::now();
+        for _ in 0..2 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(240));
+    }
+
+    #[tokio::test]
+    async fn test_zero_configuration() {
+        // 0 requests per second is no limit
+        assert!(RateLimiter::new(0, 10).is_none());
+        assert!(
+            RateLimitedSender::new("http://localhost:8899".to_string(), 0, 10)
+                .limiter
+                .is_none()
+        );
+
+        // A burst of 0 still lets one request through
+        let limiter = RateLimiter::new(1, 0).unwrap();
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    diff::{EpochSet, EpochSetDiff, SetChange},
+    epoch_snapshot::OperatorSnapshot,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    args::ReportFormat,
+    getters::{get_all_operators_in_ncn, get_ballot_box, get_operator_snapshot, get_weight_table},
+    handler::CliHandler,
+};
+
+/// How one operator took part in the vote of an epoch
+#[derive(Debug, Clone, Serialize)]
+pub struct OperatorParticipation {
+    pub operator: String,
+    pub voted: bool,
+    pub slot_voted: Option<u64>,
+    /// Slots between the creation of the ballot box and the vote
+    pub slots_to_vote: Option<u64>,
+    /// The stake weight of the vote, or of the operator snapshot when the operator did not vote
+    pub stake_weight: u128,
+    pub ballot: Option<String>,
+    /// Whether the operator voted for the winning ballot, unknown until consensus is reached
+    pub voted_with_consensus: Option<bool>,
+}
+
+/// The vote of an epoch, operator by operator
+#[derive(Debug, Clone, Serialize)]
+pub struct EpochParticipation {
+    pub epoch: u64,
+    pub consensus_reached: bool,
+    pub operators_voted: usize,
+    pub operators: Vec<OperatorParticipation>,
+}
+
+/// How one operator took part in the votes of several epochs
+#[derive(Debug, Clone, Serialize)]
+pub struct OperatorParticipationSummary {
+    pub operator: String,
+    pub epochs_voted: u64,
+    pub epochs_missed: u64,
+    pub missed_epochs: Vec<u64>,
+    pub average_slots_to_vote: Option<u64>,
+}
+
+/// The operators with an active snapshot are expected to vote, the ones that voted without one
+/// are reported all the same
+pub fn epoch_participation(
+    ballot_box: &BallotBox,
+    operator_snapshots: &[OperatorSnapshot],
+) -> EpochParticipation {
+    let winning_ballot_index = ballot_box
+        .get_winning_ballot_tally()
+        .ok()
+        .map(|tally| tally.index());
+    let consensus_reached = ballot_box.is_consensus_reached();
+
+    let mut operators = vec![];
+    for vote in ballot_box.iter_votes() {
+        let ballot = ballot_box
+            .ballot_tallies()
+            .get(vote.ballot_index() as usize)
+            .map(|tally| tally.ballot().to_string());
+
+        operators.push(OperatorParticipation {
+            operator: vote.operator().to_string(),
+            voted: true,
+            slot_voted: Some(vote.slot_voted()),
+            slots_to_vote: Some(vote.slot_voted().saturating_sub(ballot_box.slot_created())),
+            stake_weight: vote.stake_weights().stake_weight(),
+            ballot,
+            voted_with_consensus: consensus_reached
+                .then(|| winning_ballot_index == Some(vote.ballot_index())),
+        });
+    }
+
+    for operator_snapshot in operator_snapshots {
+        if !operator_snapshot.is_active()
+            || ballot_box.did_operator_vote(operator_snapshot.operator())
+        {
+            continue;
+        }
+
+        operators.push(OperatorParticipation {
+            operator: operator_snapshot.operator().to_string(),
+            voted: false,
+            slot_voted: None,
+            slots_to_vote: None,
+            stake_weight: operator_snapshot.stake_weights().stake_weight(),
+            ballot: None,
+            voted_with_consensus: consensus_reached.then_some(false),
+        });
+    }
+
+    EpochParticipation {
+        epoch: ballot_box.epoch(),
+        consensus_reached,
+        operators_voted: operators.iter().filter(|operator| operator.voted).count(),
+        operators,
+    }
+}
+
+/// Sums up the participation of every operator over the epochs
+pub fn summarize_participation(epochs: &[EpochParticipation]) -> Vec<OperatorParticipationSummary> {
+    let mut summaries: BTreeMap<String, (OperatorParticipationSummary, u64)> = BTreeMap::new();
+
+    for epoch in epochs {
+        for operator in epoch.operators.iter() {
+            let (summary, total_slots_to_vote) = summaries
+                .entry(operator.operator.clone())
+                .or_insert_with(|| {
+                    (
+                        OperatorParticipationSummary {
+                            operator: operator.operator.clone(),
+                            epochs_voted: 0,
+                            epochs_missed: 0,
+                            missed_epochs: vec![],
+                            average_slots_to_vote: None,
+                        },
+                        0,
+                    )
+                });
+
+            match operator.slots_to_vote {
+                Some(slots_to_vote) if operator.voted => {
+                    summary.epochs_voted += 1;
+                    *total_slots_to_vote = total_slots_to_vote.saturating_add(slots_to_vote);
+                }
+                _ => {
+                    summary.epochs_missed += 1;
+                    summary.missed_epochs.push(epoch.epoch);
+                }
+            }
+        }
+    }
+
+    summaries
+        .into_values()
+        .map(|(mut summary, total_slots_to_vote)| {
+            summary.average_slots_to_vote = total_slots_to_vote.checked_div(summary.epochs_voted);
+            summary
+        })
+        .collect()
+}
+
+async fn get_epoch_participation(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<EpochParticipation> {
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+
+    let mut operator_snapshots = vec![];
+    for operator in operators {
+        if let Ok(operator_snapshot) = get_operator_snapshot(handler, operator, epoch).await {
+            operator_snapshots.push(operator_snapshot);
+        }
+    }
+
+    Ok(epoch_participation(&ballot_box, &operator_snapshots))
+}
+
+/// Reports which operators voted in the epoch, when and with how much stake, and which did not.
+/// With `last`, the participation of every operator over the last epochs up to `epoch` is summed
+/// up instead, epochs without a ballot box are left out.
+pub async fn report_participation(
+    handler: &CliHandler,
+    epoch: u64,
+    last: Option<u64>,
+    format: ReportFormat,
+) -> Result<()> {
+    let operators = get_all_operators_in_ncn(handler).await?;
+
+    let Some(last) = last else {
+        let participation = get_epoch_participation(handler, &operators, epoch).await?;
+        match format {
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&participation)?),
+            ReportFormat::Table => info!("{}", participation_table(&participation)),
+        }
+        return Ok(());
+    };
+
+    if last == 0 {
+        return Err(anyhow!("--last needs at least one epoch"));
+    }
+
+    let mut epochs = vec![];
+    for report_epoch in epoch.saturating_sub(last - 1)..=epoch {
+        match get_epoch_participation(handler, &operators, report_epoch).await {
+            Ok(participation) => epochs.push(participation),
+            Err(e) => info!("Leaving out epoch {}: {}", report_epoch, e),
+        }
+    }
+
+    let summaries = summarize_participation(&epochs);
+    match format {
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&summaries)?),
+        ReportFormat::Table => info!("{}", summary_table(epoch, last, epochs.len(), &summaries)),
+    }
+
+    Ok(())
+}
+
+fn participation_table(participation: &EpochParticipation) -> String {
+    let mut table = format!(
+        "\n\n--- Participation in epoch {} ---\nOperators Voted: {} of {}\nConsensus Reached: {}\n{:<44} {:<6} {:>12} {:>10} {:<10} {:>40}",
+        participation.epoch,
+        participation.operators_voted,
+        participation.operators.len(),
+        participation.consensus_reached,
+        "Operator",
+        "Voted",
+        "Slot",
+        "Slots In",
+        "Ballot",
+        "Stake Weight"
+    );
+
+    for operator in participation.operators.iter() {
+        let optional =
+            |value: Option<u64>| value.map_or("-".to_string(), |value| value.to_string());
+
+        table.push_str(&format!(
+            "\n{:<44} {:<6} {:>12} {:>10} {:<10} {:>40}",
+            operator.operator,
+            if operator.voted { "Yes" } else { "No" },
+            optional(operator.slot_voted),
+            optional(operator.slots_to_vote),
+            operator.ballot.as_deref().unwrap_or("-"),
+            operator.stake_weight
+        ));
+    }
+
+    table.push('\n');
+    table
+}
+
+fn summary_table(
+    epoch: u64,
+    last: u64,
+    epochs_found: usize,
+    summaries: &[OperatorParticipationSummary],
+) -> String {
+    let mut table = format!(
+        "\n\n--- Participation in the last {} epochs up to {} ({} with a ballot box) ---\n{:<44} {:>6} {:>6} {:>14}  {}",
+        last, epoch, epochs_found, "Operator", "Voted", "Missed", "Avg Slots In", "Missed Epochs"
+    );
+
+    for summary in summaries {
+        table.push_str(&format!(
+            "\n{:<44} {:>6} {:>6} {:>14}  {}",
+            summary.operator,
+            summary.epochs_voted,
+            summary.epochs_missed,
+            summary
+                .average_slots_to_vote
+                .map_or("-".to_string(), |slots| slots.to_string()),
+            summary
+                .missed_epochs
+                .iter()
+                .map(|epoch| epoch.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        ));
+    }
+
+    table.push('\n');
+    table
+}
+
+/// The vaults and weights of the epoch weight table, and the stake weights of the operators
+/// snapshotted in the epoch among `operators`
+pub async fn get_epoch_set(
+    handler: &CliHandler,
+    operators: &[Pubkey],
+    epoch: u64,
+) -> Result<EpochSet> {
+    let weight_table = get_weight_table(handler, epoch).await?;
+
+    let mut operator_snapshots = vec![];
+    for operator in operators {
+        if let Ok(operator_snapshot) = get_operator_snapshot(handler, operator, epoch).await {
+            operator_snapshots.push(operator_snapshot);
+        }
+    }
+
+    Ok(EpochSet::new(&weight_table, &operator_snapshots))
+}
+
+pub fn epoch_set_diff_report(diff: &EpochSetDiff) -> String {
+    let mut report = format!(
+        "\n\n--- Changes from epoch {} to epoch {} ---\nTotal Stake Weight: {} -> {}",
+        diff.before_epoch,
+        diff.after_epoch,
+        diff.total_stake_weight_before,
+        diff.total_stake_weight_after
+    );
+
+    if diff.is_empty() {
+        report.push_str("\nNo vault, weight or operator stake weight changes\n");
+        return report;
+    }
+
+    let mut section = |title: &str, lines: Vec<String>| {
+        if !lines.is_empty() {
+            report.push_str(&format!("\n{}:", title));
+            for line in lines {
+                report.push_str(&format!("\n  {}", line));
+            }
+        }
+    };
+    let keys = |keys: &[Pubkey]| -> Vec<String> { keys.iter().map(Pubkey::to_string).collect() };
+    let changes = |changes: &[SetChange<u128>]| -> Vec<String> {
+        changes.iter().map(|change| change.to_string()).collect()
+    };
+
+    section("Vaults Added", keys(&diff.vaults_added));
+    section("Vaults Removed", keys(&diff.vaults_removed));
+    section("St Mint Weight Changes", changes(&diff.weight_changes));
+    section("Operators Added", keys(&diff.operators_added));
+    section("Operators Removed", keys(&diff.operators_removed));
+    section(
+        "Operator Stake Weight Changes",
+        changes(&diff.stake_weight_changes),
+    );
+
+    report.push('\n');
+    report
+}
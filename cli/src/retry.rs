@@ -0,0 +1,454 @@
+use ncn_program_sdk::packing::MAX_COMPUTE_UNITS_PER_TRANSACTION;
+use solana_client::client_error::ClientError;
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+/// How failed transactions are retried
+///
+/// Attempt `n` waits `base_delay_ms * backoff_factor^n`, capped at `max_delay_ms`, before being
+/// resent. Transactions failing in an instruction are not retried unless `retry_program_errors`
+/// is set: the program returns the same error for the same accounts, resending only pays fees
+/// again.
+///
+/// Retries are not resent identical: a transaction that did not land is resent with its priority
+/// fee grown by `priority_fee_escalation_factor`, up to `max_priority_fee_micro_lamports`, and one
+/// that exhausted its compute units with its compute unit limit grown by
+/// `compute_unit_escalation_factor`, up to `max_compute_unit_limit`. A factor of 1 keeps them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u64,
+    pub base_delay_ms: u64,
+    pub backoff_factor: f64,
+    pub max_delay_ms: u64,
+    pub retry_program_errors: bool,
+    pub priority_fee_escalation_factor: f64,
+    pub max_priority_fee_micro_lamports: u64,
+    pub compute_unit_escalation_factor: f64,
+    pub max_compute_unit_limit: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay_ms: 1_000,
+            backoff_factor: 2.0,
+            max_delay_ms: 30_000,
+            retry_program_errors: false,
+            priority_fee_escalation_factor: 1.0,
+            max_priority_fee_micro_lamports: 1_000_000,
+            compute_unit_escalation_factor: 1.0,
+            max_compute_unit_limit: MAX_COMPUTE_UNITS_PER_TRANSACTION,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Time to wait before the retry following the failed attempt, counted from 0
+    pub fn delay_ms(&self, attempt: u64) -> u64 {
+        let delay =
+            self.base_delay_ms as f64 * self.backoff_factor.max(1.0).powi(attempt.min(64) as i32);
+
+        if delay.is_finite() {
+            (delay as u64).min(self.max_delay_ms)
+        } else {
+            self.max_delay_ms
+        }
+    }
+
+    /// Whether a transaction whose attempt failed with the error is worth resending
+    pub fn should_retry(&self, attempt: u64, error: &ClientError) -> bool {
+        if attempt >= self.max_retries {
+            return false;
+        }
+
+        self.retry_program_errors
+            || (self.compute_unit_escalation_factor > 1.0 && is_compute_exhausted(error))
+            || !error
+                .get_transaction_error()
+                .as_ref()
+                .is_some_and(is_deterministic)
+    }
+
+    /// Priority fee and compute unit limit of the retry following an attempt sent with them that
+    /// failed with the error, `None` when it is not retried
+    ///
+    /// Only an attempt that exhausted its compute units is resent with a higher compute unit
+    /// limit, and only one that did not land with a higher priority fee. An attempt that exhausted
+    /// its compute units is not retried once the limit cannot grow, unless program errors are.
+    pub fn retry_budget(
+        &self,
+        attempt: u64,
+        error: &ClientError,
+        priority_fee_micro_lamports: u64,
+        compute_unit_limit: u32,
+    ) -> Option<(u64, u32)> {
+        let compute_exhausted = is_compute_exhausted(error);
+        let next_compute_unit_limit = if compute_exhausted {
+            self.escalated_compute_unit_limit(compute_unit_limit)
+        } else {
+            compute_unit_limit
+        };
+        let can_escalate = !compute_exhausted
+            || self.retry_program_errors
+            || next_compute_unit_limit > compute_unit_limit;
+
+        if !self.should_retry(attempt, error) || !can_escalate {
+            return None;
+        }
+
+        if next_compute_unit_limit != compute_unit_limit {
+            return Some((priority_fee_micro_lamports, next_compute_unit_limit));
+        }
+
+        if is_not_landed(error) {
+            return Some((
+                self.escalated_priority_fee(priority_fee_micro_lamports),
+                compute_unit_limit,
+            ));
+        }
+
+        Some((priority_fee_micro_lamports, compute_unit_limit))
+    }
+
+    /// Priority fee of the retry following an attempt that did not land. Never lowers the fee,
+    /// even when it is already above `max_priority_fee_micro_lamports`.
+    pub fn escalated_priority_fee(&self, priority_fee_micro_lamports: u64) -> u64 {
+        let ceiling = self
+            .max_priority_fee_micro_lamports
+            .max(priority_fee_micro_lamports);
+
+        escalate(
+            priority_fee_micro_lamports,
+            self.priority_fee_escalation_factor,
+            ceiling,
+        )
+    }
+
+    /// Compute unit limit of the retry following an attempt that exhausted its compute units
+    pub fn escalated_compute_unit_limit(&self, compute_unit_limit: u32) -> u32 {
+        let ceiling = self
+            .max_compute_unit_limit
+            .min(MAX_COMPUTE_UNITS_PER_TRANSACTION)
+            .max(compute_unit_limit);
+
+        escalate(
+            compute_unit_limit.into(),
+            self.compute_unit_escalation_factor,
+            ceiling.into(),
+        ) as u32
+    }
+}
+
+/// The value grown by the factor, rounded up and capped at the ceiling
+fn escalate(value: u64, factor: f64, ceiling: u64) -> u64 {
+    let escalated = (value as f64 * factor.max(1.0)).ceil();
+
+    if escalated.is_finite() && escalated < ceiling as f64 {
+        (escalated as u64).max(value)
+    } else {
+        ceiling
+    }
+}
+
+/// Whether the transaction landed and failed by running out of compute units
+pub fn is_compute_exhausted(error: &ClientError) -> bool {
+    matches!(
+        error.get_transaction_error(),
+        Some(TransactionError::InstructionError(
+            _,
+            InstructionError::ComputationalBudgetExceeded
+        ))
+    )
+}
+
+/// Whether the transaction failed without being executed, an instruction failing means it landed
+pub fn is_not_landed(error: &ClientError) -> bool {
+    !matches!(
+        error.get_transaction_error(),
+        Some(TransactionError::InstructionError(_, _))
+    )
+}
+
+/// Errors the transaction fails with again when resent as is
+fn is_deterministic(error: &TransactionError) -> bool {
+    matches!(
+        error,
+        TransactionError::InstructionError(_, _)
+            | TransactionError::AccountNotFound
+            | TransactionError::InsufficientFundsForFee
+            | TransactionError::InvalidAccountForFee
+            | TransactionError::SignatureFailure
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            ..RetryPolicy::default()
+        }
+    }
+
+    fn instruction_error(error: InstructionError) -> ClientError {
+        TransactionError::InstructionError(0, error).into()
+    }
+
+    fn connection_error() -> ClientError {
+        io::Error::from(io::ErrorKind::ConnectionReset).into()
+    }
+
+    #[test]
+    fn test_delay_grows_by_the_backoff_factor() {
+        let policy = policy();
+
+        assert_eq!(policy.delay_ms(0), 1_000);
+        assert_eq!(policy.delay_ms(1), 2_000);
+        assert_eq!(policy.delay_ms(2), 4_000);
+        assert_eq!(policy.delay_ms(4), 16_000);
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let policy = policy();
+
+        assert_eq!(policy.delay_ms(5), 30_000);
+        assert_eq!(policy.delay_ms(64), 30_000);
+        assert_eq!(policy.delay_ms(u64::MAX), 30_000);
+
+        // Growing past what an f64 holds
+        let policy = RetryPolicy {
+            backoff_factor: f64::MAX,
+            ..policy
+        };
+        assert_eq!(policy.delay_ms(2), 30_000);
+        assert_eq!(policy.delay_ms(u64::MAX), 30_000);
+    }
+
+    #[test]
+    fn test_delay_never_shrinks() {
+        let policy = RetryPolicy {
+            backoff_factor: 0.5,
+            ..policy()
+        };
+
+        assert_eq!(policy.delay_ms(0), 1_000);
+        assert_eq!(policy.delay_ms(3), 1_000);
+    }
+
+    #[test]
+    fn test_should_retry_transient_errors() {
+        let policy = policy();
+
+        assert!(policy.should_retry(0, &connection_error()));
+        assert!(policy.should_retry(2, &TransactionError::BlockhashNotFound.into()));
+
+        // Out of retries
+        assert!(!policy.should_retry(3, &connection_error()));
+        assert!(!RetryPolicy::default().should_retry(0, &connection_error()));
+    }
+
+    #[test]
+    fn test_should_not_retry_deterministic_errors() {
+        let policy = policy();
+
+        assert!(!policy.should_retry(0, &instruction_error(InstructionError::Custom(1))));
+        assert!(!policy.should_retry(0, &TransactionError::InsufficientFundsForFee.into()));
+        assert!(!policy.should_retry(
+            0,
+            &instruction_error(InstructionError::ComputationalBudgetExceeded)
+        ));
+
+        // Unless program errors are retried
+        let policy = RetryPolicy {
+            retry_program_errors: true,
+            ..policy
+        };
+        assert!(policy.should_retry(0, &instruction_error(InstructionError::Custom(1))));
+        assert!(!policy.should_retry(3, &instruction_error(InstructionError::Custom(1))));
+    }
+
+    #[test]
+    fn test_should_retry_compute_exhausted_when_escalating() {
+        let policy = RetryPolicy {
+            compute_unit_escalation_factor: 1.5,
+            ..policy()
+        };
+
+        assert!(policy.should_retry(
+            0,
+            &instruction_error(InstructionError::ComputationalBudgetExceeded)
+        ));
+        // Other program errors are still not retried
+        assert!(!policy.should_retry(0, &instruction_error(InstructionError::Custom(1))));
+    }
+
+    #[test]
+    fn test_is_deterministic() {
+        assert!(is_deterministic(&TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(1)
+        )));
+        assert!(is_deterministic(&TransactionError::AccountNotFound));
+        assert!(is_deterministic(&TransactionError::InsufficientFundsForFee));
+        assert!(is_deterministic(&TransactionError::InvalidAccountForFee));
+        assert!(is_deterministic(&TransactionError::SignatureFailure));
+
+        assert!(!is_deterministic(&TransactionError::BlockhashNotFound));
+        assert!(!is_deterministic(
+            &TransactionError::WouldExceedMaxBlockCostLimit
+        ));
+    }
+
+    #[test]
+    fn test_is_not_landed() {
+        assert!(is_not_landed(&connection_error()));
+        assert!(is_not_landed(&TransactionError::BlockhashNotFound.into()));
+
+        assert!(!is_not_landed(&instruction_error(
+            InstructionError::Custom(1)
+        )));
+        assert!(!is_not_landed(&instruction_error(
+            InstructionError::ComputationalBudgetExceeded
+        )));
+    }
+
+    #[test]
+    fn test_is_compute_exhausted() {
+        assert!(is_compute_exhausted(&instruction_error(
+            InstructionError::ComputationalBudgetExceeded
+        )));
+
+        assert!(!is_compute_exhausted(&instruction_error(
+            InstructionError::Custom(1)
+        )));
+        assert!(!is_compute_exhausted(&connection_error()));
+    }
+
+    fn escalating_policy() -> RetryPolicy {
+        RetryPolicy {
+            priority_fee_escalation_factor: 2.0,
+            max_priority_fee_micro_lamports: 1_000,
+            compute_unit_escalation_factor: 2.0,
+            max_compute_unit_limit: 300_000,
+            ..policy()
+        }
+    }
+
+    #[test]
+    fn test_priority_fee_stops_at_ceiling() {
+        let policy = escalating_policy();
+
+        assert_eq!(policy.escalated_priority_fee(400), 800);
+        assert_eq!(policy.escalated_priority_fee(800), 1_000);
+        assert_eq!(policy.escalated_priority_fee(1_000), 1_000);
+
+        // A fee above the ceiling is kept, not lowered
+        assert_eq!(policy.escalated_priority_fee(5_000), 5_000);
+
+        // Rounded up, and kept by a factor of 1
+        let policy = RetryPolicy {
+            priority_fee_escalation_factor: 1.5,
+            ..policy
+        };
+        assert_eq!(policy.escalated_priority_fee(3), 5);
+        assert_eq!(policy().escalated_priority_fee(400), 400);
+    }
+
+    #[test]
+    fn test_compute_unit_limit_stops_at_ceiling() {
+        let policy = escalating_policy();
+
+        assert_eq!(policy.escalated_compute_unit_limit(100_000), 200_000);
+        assert_eq!(policy.escalated_compute_unit_limit(200_000), 300_000);
+        assert_eq!(policy.escalated_compute_unit_limit(300_000), 300_000);
+
+        // Never past what a transaction can use
+        let policy = RetryPolicy {
+            max_compute_unit_limit: u32::MAX,
+            ..policy
+        };
+        assert_eq!(
+            policy.escalated_compute_unit_limit(1_000_000),
+            MAX_COMPUTE_UNITS_PER_TRANSACTION
+        );
+    }
+
+    #[test]
+    fn test_escalate_does_not_overflow() {
+        assert_eq!(escalate(u64::MAX, 2.0, u64::MAX), u64::MAX);
+        assert_eq!(escalate(u64::MAX / 2, f64::MAX, 10), 10);
+        // A factor that is not a number keeps the value
+        assert_eq!(escalate(10, f64::NAN, 100), 10);
+    }
+
+    #[test]
+    fn test_retry_budget_escalates_fee_when_not_landed() {
+        let policy = escalating_policy();
+
+        assert_eq!(
+            policy.retry_budget(0, &TransactionError::BlockhashNotFound.into(), 400, 100_000),
+            Some((800, 100_000))
+        );
+        assert_eq!(
+            policy.retry_budget(0, &connection_error(), 800, 100_000),
+            Some((1_000, 100_000))
+        );
+    }
+
+    #[test]
+    fn test_retry_budget_escalates_compute_units_when_exhausted() {
+        let policy = escalating_policy();
+        let compute_exhausted = instruction_error(InstructionError::ComputationalBudgetExceeded);
+
+        assert_eq!(
+            policy.retry_budget(0, &compute_exhausted, 400, 100_000),
+            Some((400, 200_000))
+        );
+
+        // Resending with the same limit would exhaust it again
+        assert_eq!(
+            policy.retry_budget(0, &compute_exhausted, 400, 300_000),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_budget_does_not_escalate_other_errors() {
+        let policy = RetryPolicy {
+            retry_program_errors: true,
+            ..escalating_policy()
+        };
+
+        // A program error retried as is, the fee and compute units did not make it fail
+        assert_eq!(
+            policy.retry_budget(
+                0,
+                &instruction_error(InstructionError::Custom(1)),
+                400,
+                100_000
+            ),
+            Some((400, 100_000))
+        );
+        // A compute unit limit that cannot grow is kept as well
+        assert_eq!(
+            policy.retry_budget(
+                0,
+                &instruction_error(InstructionError::ComputationalBudgetExceeded),
+                400,
+                300_000
+            ),
+            Some((400, 300_000))
+        );
+
+        // Nothing is escalated without retries left
+        assert_eq!(
+            policy.retry_budget(3, &connection_error(), 400, 100_000),
+            None
+        );
+    }
+}
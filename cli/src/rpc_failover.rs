@@ -0,0 +1,261 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use log::{info, warn};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind, Result as ClientResult},
+    rpc_request::{RpcError, RpcRequest, RpcResponseErrorData},
+};
+use solana_rpc_client::rpc_sender::{RpcSender, RpcTransportStats};
+
+/// Sender of the RPC client failing over between several RPCs, so every getter and sender of the
+/// CLI, epoch info, account fetches and transactions alike, moves on to the next RPC when one is
+/// down without knowing about it
+///
+/// Requests go to the current RPC, `--rpc-url` first. A request it fails to answer, unreachable,
+/// answering an HTTP error or unhealthy, is sent to the next RPC in order, which becomes the
+/// current one. Errors answered by a healthy RPC, a failed simulation or a rejected transaction,
+/// are returned as they would be by any other RPC.
+pub struct FailoverSender {
+    senders: Vec<Box<dyn RpcSender + Send + Sync>>,
+    /// Index of the sender the requests go to, until it fails
+    current: AtomicUsize,
+}
+
+impl FailoverSender {
+    pub fn new(senders: Vec<Box<dyn RpcSender + Send + Sync>>) -> Self {
+        Self {
+            senders,
+            current: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcSender for FailoverSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        let first = self.current.load(Ordering::Relaxed);
+
+        let mut attempt = 0;
+        loop {
+            let index = (first + attempt) % self.senders.len();
+            let sender = &self.senders[index];
+
+            match sender.send(request, params.clone()).await {
+                Err(e) if is_rpc_outage(&e) && attempt + 1 < self.senders.len() => {
+                    let next = &self.senders[(index + 1) % self.senders.len()];
+                    warn!(
+                        "RPC {} failed to answer {}: {}, failing over to {}",
+                        sender.url(),
+                        request,
+                        e,
+                        next.url()
+                    );
+                    attempt += 1;
+                }
+                result => {
+                    if index != first
+                        && self
+                            .current
+                            .compare_exchange(first, index, Ordering::Relaxed, Ordering::Relaxed)
+                            .is_ok()
+                    {
+                        info!("Sending the RPC requests to {}", sender.url());
+                    }
+                    return result;
+                }
+            }
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.senders[self.current.load(Ordering::Relaxed)].get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.senders[self.current.load(Ordering::Relaxed)].url()
+    }
+}
+
+/// Whether the RPC failed to answer, rather than answered an error another RPC would answer too
+fn is_rpc_outage(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) => true,
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { data, .. }) => {
+            matches!(data, RpcResponseErrorData::NodeUnhealthy { .. })
+        }
+        ClientErrorKind::RpcError(RpcError::RpcRequestError(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize},
+            Arc,
+        },
+    };
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// RPC answering its URL, or failing to answer while it is down
+    struct MockSender {
+        url: String,
+        down: Arc<AtomicBool>,
+        application_error: bool,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl MockSender {
+        fn new(url: &str) -> (Self, Arc<AtomicBool>, Arc<AtomicUsize>) {
+            let down = Arc::new(AtomicBool::new(false));
+            let requests = Arc::new(AtomicUsize::new(0));
+
+            (
+                Self {
+                    url: url.to_string(),
+                    down: down.clone(),
+                    application_error: false,
+                    requests: requests.clone(),
+                },
+                down,
+                requests,
+            )
+        }
+    }
+
+    #[async_trait]
+    impl RpcSender for MockSender {
+        async fn send(
+            &self,
+            _request: RpcRequest,
+            _params: serde_json::Value,
+        ) -> ClientResult<serde_json::Value> {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+
+            if self.down.load(Ordering::Relaxed) {
+                return Err(io::Error::from(io::ErrorKind::ConnectionRefused).into());
+            }
+            if self.application_error {
+                return Err(application_error());
+            }
+
+            Ok(json!(self.url))
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            self.url.clone()
+        }
+    }
+
+    fn application_error() -> ClientError {
+        RpcError::RpcResponseError {
+            code: -32002,
+            message: "Transaction simulation failed".to_string(),
+            data: RpcResponseErrorData::Empty,
+        }
+        .into()
+    }
+
+    async fn send(sender: &FailoverSender) -> ClientResult<serde_json::Value> {
+        sender
+            .send(RpcRequest::GetSlot, serde_json::Value::Null)
+            .await
+    }
+
+    #[test]
+    fn test_is_rpc_outage() {
+        assert!(is_rpc_outage(
+            &io::Error::from(io::ErrorKind::TimedOut).into()
+        ));
+        assert!(is_rpc_outage(
+            &RpcError::RpcRequestError("connection reset".to_string()).into()
+        ));
+        assert!(is_rpc_outage(
+            &RpcError::RpcResponseError {
+                code: -32005,
+                message: "Node is unhealthy".to_string(),
+                data: RpcResponseErrorData::NodeUnhealthy {
+                    num_slots_behind: Some(100),
+                },
+            }
+            .into()
+        ));
+
+        // Errors any healthy RPC would answer as well
+        assert!(!is_rpc_outage(&application_error()));
+        assert!(!is_rpc_outage(
+            &ClientErrorKind::Custom("invalid account data".to_string()).into()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_application_error_does_not_fail_over() {
+        let (mut primary, _, primary_requests) = MockSender::new("primary");
+        primary.application_error = true;
+        let (fallback, _, fallback_requests) = MockSender::new("fallback");
+
+        let sender = FailoverSender::new(vec![Box::new(primary), Box::new(fallback)]);
+
+        assert!(send(&sender).await.is_err());
+        assert_eq!(primary_requests.load(Ordering::Relaxed), 1);
+        assert_eq!(fallback_requests.load(Ordering::Relaxed), 0);
+        assert_eq!(sender.url(), "primary");
+    }
+
+    #[tokio::test]
+    async fn test_fail_over_and_wrap_around() {
+        let (primary, primary_down, _) = MockSender::new("primary");
+        let (first_fallback, first_fallback_down, _) = MockSender::new("first-fallback");
+        let (second_fallback, second_fallback_down, _) = MockSender::new("second-fallback");
+
+        let sender = FailoverSender::new(vec![
+            Box::new(primary),
+            Box::new(first_fallback),
+            Box::new(second_fallback),
+        ]);
+
+        assert_eq!(send(&sender).await.unwrap(), json!("primary"));
+
+        // The next RPC takes over and stays the current one once the primary is back
+        primary_down.store(true, Ordering::Relaxed);
+        assert_eq!(send(&sender).await.unwrap(), json!("first-fallback"));
+        primary_down.store(false, Ordering::Relaxed);
+        assert_eq!(send(&sender).await.unwrap(), json!("first-fallback"));
+        assert_eq!(sender.url(), "first-fallback");
+
+        // Past the last RPC the requests go back to the primary
+        first_fallback_down.store(true, Ordering::Relaxed);
+        second_fallback_down.store(true, Ordering::Relaxed);
+        assert_eq!(send(&sender).await.unwrap(), json!("primary"));
+        assert_eq!(sender.url(), "primary");
+    }
+
+    #[tokio::test]
+    async fn test_every_rpc_down() {
+        let (primary, primary_down, primary_requests) = MockSender::new("primary");
+        let (fallback, fallback_down, fallback_requests) = MockSender::new("fallback");
+        primary_down.store(true, Ordering::Relaxed);
+        fallback_down.store(true, Ordering::Relaxed);
+
+        let sender = FailoverSender::new(vec![Box::new(primary), Box::new(fallback)]);
+
+        // Each RPC is tried once, the error of the last one is returned
+        assert!(send(&sender).await.is_err());
+        assert_eq!(primary_requests.load(Ordering::Relaxed), 1);
+        assert_eq!(fallback_requests.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+use log::info;
+
+/// How the single pass of a keeper loop run with `--once` went, told to cron jobs and systemd
+/// timers through the exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassOutcome {
+    /// Nothing to do, exits with 0
+    Idle,
+    /// Transactions were sent, exits with 2
+    Worked,
+    /// A crank failed, exits with 1
+    Failed,
+}
+
+impl PassOutcome {
+    pub const fn new(errors: u64, transactions_sent: u64) -> Self {
+        if errors > 0 {
+            Self::Failed
+        } else if transactions_sent > 0 {
+            Self::Worked
+        } else {
+            Self::Idle
+        }
+    }
+
+    /// The outcome of the passes of several loops run together, any failed pass fails it
+    pub fn combine(outcomes: impl IntoIterator<Item = Self>) -> Self {
+        outcomes
+            .into_iter()
+            .fold(Self::Idle, |combined, outcome| match (combined, outcome) {
+                (Self::Failed, _) | (_, Self::Failed) => Self::Failed,
+                (Self::Worked, _) | (_, Self::Worked) => Self::Worked,
+                (Self::Idle, Self::Idle) => Self::Idle,
+            })
+    }
+
+    pub const fn exit_code(self) -> i32 {
+        match self {
+            Self::Idle => 0,
+            Self::Worked => 2,
+            Self::Failed => 1,
+        }
+    }
+
+    /// Ends the command with the exit code of the pass, failures are returned as an error so
+    /// `main` exits with 1 after logging it
+    pub fn exit(self, transactions_sent: u64) -> Result<()> {
+        match self {
+            Self::Idle => {
+                info!("Pass complete, nothing to do");
+                Ok(())
+            }
+            Self::Worked => {
+                info!("Pass complete, {} transactions sent", transactions_sent);
+                std::process::exit(self.exit_code())
+            }
+            Self::Failed => Err(anyhow!("Pass ended with errors")),
+        }
+    }
+}
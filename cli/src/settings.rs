@@ -0,0 +1,261 @@
+use std::{env, fs};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+/// Environment variable naming the settings file when `--config` is not passed
+pub const CONFIG_ENV: &str = "CLI_CONFIG";
+
+/// Settings read from the TOML file given with `--config`
+///
+/// Every setting is the one of the global argument of the same name. Flags and environment
+/// variables take precedence over the file, which takes precedence over the defaults.
+///
+/// ```toml
+/// rpc_url = "https://api.devnet.solana.com"
+/// ncn = "5zqy3uyXMi5Uurup7S5kKUUuwHGnGcykVwwUik37fm6i"
+/// keypair_path = "~/.config/solana/id.json"
+/// priority_fee_micro_lamports = 10000
+/// transaction_retries = 3
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CliSettings {
+    pub rpc_url: Option<String>,
+    pub fallback_rpc_urls: Option<Vec<String>>,
+    pub commitment: Option<String>,
+    pub rpc_requests_per_second: Option<u64>,
+    pub rpc_burst: Option<u64>,
+    pub priority_fee_micro_lamports: Option<u64>,
+    pub compute_unit_margin: Option<u64>,
+    pub skip_compute_unit_estimate: Option<bool>,
+    pub transaction_retries: Option<u64>,
+    pub retry_base_delay_ms: Option<u64>,
+    pub retry_backoff_factor: Option<f64>,
+    pub retry_max_delay_ms: Option<u64>,
+    pub retry_program_errors: Option<bool>,
+    pub priority_fee_escalation_factor: Option<f64>,
+    pub max_priority_fee_micro_lamports: Option<u64>,
+    pub compute_unit_escalation_factor: Option<f64>,
+    pub max_compute_unit_limit: Option<u32>,
+    pub max_in_flight_transactions: Option<usize>,
+    pub address_lookup_tables: Option<Vec<String>>,
+    pub ncn_program_id: Option<String>,
+    pub restaking_program_id: Option<String>,
+    pub vault_program_id: Option<String>,
+    pub token_program_id: Option<String>,
+    pub ncn: Option<String>,
+    pub keypair_path: Option<String>,
+    pub jito_block_engine_url: Option<String>,
+    pub jito_tip_lamports: Option<u64>,
+}
+
+impl CliSettings {
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|e| anyhow!("Invalid CLI settings: {}", e))
+    }
+
+    /// The settings as the environment variables of their arguments
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let settings = [
+            ("RPC_URL", self.rpc_url.clone()),
+            (
+                "FALLBACK_RPC_URLS",
+                self.fallback_rpc_urls.as_ref().map(|v| v.join(",")),
+            ),
+            ("COMMITMENT", self.commitment.clone()),
+            (
+                "RPC_REQUESTS_PER_SECOND",
+                self.rpc_requests_per_second.map(|v| v.to_string()),
+            ),
+            ("RPC_BURST", self.rpc_burst.map(|v| v.to_string())),
+            (
+                "PRIORITY_FEE_MICRO_LAMPORTS",
+                self.priority_fee_micro_lamports.map(|v| v.to_string()),
+            ),
+            (
+                "COMPUTE_UNIT_MARGIN",
+                self.compute_unit_margin.map(|v| v.to_string()),
+            ),
+            (
+                "SKIP_COMPUTE_UNIT_ESTIMATE",
+                self.skip_compute_unit_estimate.map(|v| v.to_string()),
+            ),
+            (
+                "TRANSACTION_RETRIES",
+                self.transaction_retries.map(|v| v.to_string()),
+            ),
+            (
+                "RETRY_BASE_DELAY_MS",
+                self.retry_base_delay_ms.map(|v| v.to_string()),
+            ),
+            (
+                "RETRY_BACKOFF_FACTOR",
+                self.retry_backoff_factor.map(|v| v.to_string()),
+            ),
+            (
+                "RETRY_MAX_DELAY_MS",
+                self.retry_max_delay_ms.map(|v| v.to_string()),
+            ),
+            (
+                "RETRY_PROGRAM_ERRORS",
+                self.retry_program_errors.map(|v| v.to_string()),
+            ),
+            (
+                "PRIORITY_FEE_ESCALATION_FACTOR",
+                self.priority_fee_escalation_factor.map(|v| v.to_string()),
+            ),
+            (
+                "MAX_PRIORITY_FEE_MICRO_LAMPORTS",
+                self.max_priority_fee_micro_lamports.map(|v| v.to_string()),
+            ),
+            (
+                "COMPUTE_UNIT_ESCALATION_FACTOR",
+                self.compute_unit_escalation_factor.map(|v| v.to_string()),
+            ),
+            (
+                "MAX_COMPUTE_UNIT_LIMIT",
+                self.max_compute_unit_limit.map(|v| v.to_string()),
+            ),
+            (
+                "MAX_IN_FLIGHT_TRANSACTIONS",
+                self.max_in_flight_transactions.map(|v| v.to_string()),
+            ),
+            (
+                "ADDRESS_LOOKUP_TABLES",
+                self.address_lookup_tables.as_ref().map(|v| v.join(",")),
+            ),
+            ("NCN_PROGRAM_ID", self.ncn_program_id.clone()),
+            ("RESTAKING_PROGRAM_ID", self.restaking_program_id.clone()),
+            ("VAULT_PROGRAM_ID", self.vault_program_id.clone()),
+            ("TOKEN_PROGRAM_ID", self.token_program_id.clone()),
+            ("NCN", self.ncn.clone()),
+            ("KEYPAIR_PATH", self.keypair_path.clone()),
+            ("JITO_BLOCK_ENGINE_URL", self.jito_block_engine_url.clone()),
+            (
+                "JITO_TIP_LAMPORTS",
+                self.jito_tip_lamports.map(|v| v.to_string()),
+            ),
+        ];
+
+        settings
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect()
+    }
+}
+
+/// Loads the settings file named by `--config` or `CLI_CONFIG`, before the arguments are parsed
+///
+/// The settings are exported as the environment variables of their arguments, unless already set,
+/// so clap merges them below the flags and the environment like it does for a `.env` file.
+pub fn load_settings_file(args: &[String]) -> Result<()> {
+    let Some(path) = config_path(args).or_else(|| env::var(CONFIG_ENV).ok()) else {
+        return Ok(());
+    };
+
+    let toml = fs::read_to_string(&path).map_err(|e| anyhow!("Could not read {}: {}", path, e))?;
+    let settings = CliSettings::from_toml(&toml).map_err(|e| anyhow!("{}: {}", path, e))?;
+
+    for (name, value) in settings.env_vars() {
+        if env::var_os(name).is_none() {
+            env::set_var(name, value);
+        }
+    }
+
+    Ok(())
+}
+
+/// The value of `--config`, as `--config <PATH>` or `--config=<PATH>`
+fn config_path(args: &[String]) -> Option<String> {
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().cloned();
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(path.to_string());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::args::Args;
+
+    #[test]
+    fn test_config_path() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            config_path(&args(&["cli", "--config", "ncn.toml", "run-keeper"])),
+            Some("ncn.toml".to_string())
+        );
+        assert_eq!(
+            config_path(&args(&["cli", "run-keeper", "--config=ncn.toml"])),
+            Some("ncn.toml".to_string())
+        );
+        assert_eq!(config_path(&args(&["cli", "run-keeper"])), None);
+    }
+
+    #[test]
+    fn test_unknown_setting_is_an_error() {
+        assert!(CliSettings::from_toml("rpc = \"http://localhost:8899\"").is_err());
+    }
+
+    // The only test touching the environment variables of these settings
+    #[test]
+    fn test_flags_and_env_take_precedence_over_the_file() {
+        let path = std::env::temp_dir().join(format!("cli-settings-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            r#"
+            rpc_url = "http://file:8899"
+            commitment = "processed"
+            ncn = "5zqy3uyXMi5Uurup7S5kKUUuwHGnGcykVwwUik37fm6i"
+            keypair_path = "file.json"
+            "#,
+        )
+        .unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        env::set_var("RPC_URL", "http://env:8899");
+        env::set_var("KEYPAIR_PATH", "env.json");
+        env::remove_var("COMMITMENT");
+        env::remove_var("NCN");
+
+        let args = [
+            "ncn-program-cli",
+            "--config",
+            &path,
+            "--keypair-path",
+            "flag.json",
+            "crank-snapshot",
+        ]
+        .map(str::to_string);
+        load_settings_file(&args).unwrap();
+        let args = Args::try_parse_from(args).unwrap();
+
+        for name in ["RPC_URL", "KEYPAIR_PATH", "COMMITMENT", "NCN"] {
+            env::remove_var(name);
+        }
+        fs::remove_file(&path).unwrap();
+
+        // The flag over the environment over the file
+        assert_eq!(args.keypair_path.as_deref(), Some("flag.json"));
+        assert_eq!(args.rpc_url, "http://env:8899");
+
+        // Only in the file
+        assert_eq!(args.commitment, "processed");
+        assert_eq!(
+            args.ncn.as_deref(),
+            Some("5zqy3uyXMi5Uurup7S5kKUUuwHGnGcykVwwUik37fm6i")
+        );
+    }
+}
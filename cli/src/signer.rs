@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine};
+use serde::Deserialize;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::{Signer, SignerError},
+};
+use tokio::{runtime::Handle, task::block_in_place};
+
+/// The signer of the CLI transactions: a local keypair, or a remote signing service keeping the key
+/// out of the machine running the CLI, e.g. behind an HSM
+pub enum CliSigner {
+    Keypair(Keypair),
+    Remote(RemoteSigner),
+}
+
+impl Signer for CliSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        match self {
+            Self::Keypair(keypair) => keypair.try_pubkey(),
+            Self::Remote(remote) => Ok(remote.pubkey),
+        }
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        match self {
+            Self::Keypair(keypair) => keypair.try_sign_message(message),
+            Self::Remote(remote) => remote.sign_message(message),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// A signing service reached over HTTP
+///
+/// - `GET <url>/pubkey` answers `{ "pubkey": "<base58>" }`, the key the service signs with
+/// - `POST <url>/sign` with `{ "pubkey": "<base58>", "message": "<base64>" }` answers
+///   `{ "signature": "<base58>" }`, the signature of the serialized transaction message
+///
+/// The token, when given, is sent as a bearer `Authorization` header. Services speaking gRPC are
+/// reached through an HTTP gateway.
+pub struct RemoteSigner {
+    url: String,
+    token: Option<String>,
+    pubkey: Pubkey,
+    http_client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct PubkeyResponse {
+    pubkey: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+impl RemoteSigner {
+    /// Connects to the signing service, asking it for its public key unless one is given
+    pub async fn connect(url: &str, token: Option<String>, pubkey: Option<Pubkey>) -> Result<Self> {
+        let url = url.trim_end_matches('/').to_string();
+        let http_client = reqwest::Client::new();
+
+        let pubkey = match pubkey {
+            Some(pubkey) => pubkey,
+            None => {
+                let mut request = http_client.get(format!("{}/pubkey", url));
+                if let Some(token) = &token {
+                    request = request.bearer_auth(token);
+                }
+
+                let response: PubkeyResponse =
+                    request.send().await?.error_for_status()?.json().await?;
+                Pubkey::from_str(&response.pubkey)
+                    .map_err(|e| anyhow!("Remote signer returned an invalid public key: {}", e))?
+            }
+        };
+
+        Ok(Self {
+            url,
+            token,
+            pubkey,
+            http_client,
+        })
+    }
+
+    async fn request_signature(&self, message: &[u8]) -> Result<Signature> {
+        let mut request =
+            self.http_client
+                .post(format!("{}/sign", self.url))
+                .json(&serde_json::json!({
+                    "pubkey": self.pubkey.to_string(),
+                    "message": general_purpose::STANDARD.encode(message),
+                }));
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response: SignResponse = request.send().await?.error_for_status()?.json().await?;
+        let signature = Signature::from_str(&response.signature)
+            .map_err(|e| anyhow!("Remote signer returned an invalid signature: {}", e))?;
+
+        // Do not hand out signatures of another key or message
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(anyhow!(
+                "Remote signer returned a signature that does not verify for {}",
+                self.pubkey
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    /// Transactions are signed synchronously, the request runs on the current runtime without
+    /// blocking its other tasks
+    fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        block_in_place(|| Handle::current().block_on(self.request_signature(message)))
+            .map_err(|e| SignerError::Connection(e.to_string()))
+    }
+}
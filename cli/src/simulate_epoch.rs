@@ -0,0 +1,249 @@
+use std::{collections::HashSet, future::Future};
+
+use anyhow::{anyhow, Result};
+use log::info;
+use ncn_program_core::simulation::{simulate_reward_waterfall, RewardSimulation};
+
+use crate::{
+    args::EpochAccountType,
+    getters::{
+        get_all_operators_in_ncn, get_ballot_box, get_epoch_snapshot, get_ncn_program_config,
+        get_ncn_reward_receiver_rewards, get_operator_snapshot,
+    },
+    handler::CliHandler,
+    instructions::{
+        crank_close_epoch_accounts, crank_create_or_skip_epoch, crank_post_vote_cooldown,
+        crank_register_vaults, crank_route_and_distribute, crank_set_weight, crank_snapshot,
+    },
+};
+
+/// Outcome of a transaction simulated instead of sent with `--dry-run`
+#[derive(Debug, Clone)]
+pub struct SimulatedTransaction {
+    pub title: String,
+    pub compute_units: Option<u64>,
+    /// Why the simulation failed, `None` when it succeeded
+    pub error: Option<String>,
+}
+
+/// The transactions one keeper stage simulated
+#[derive(Debug, Clone)]
+pub struct StageSimulation {
+    pub name: &'static str,
+    pub transactions: Vec<SimulatedTransaction>,
+    /// The error the stage stopped on, if any
+    pub error: Option<String>,
+}
+
+impl StageSimulation {
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+            && self
+                .transactions
+                .iter()
+                .all(|transaction| transaction.error.is_none())
+    }
+}
+
+/// Runs every keeper stage of the epoch against the current state of the cluster without sending
+/// anything, then reports the transactions that would succeed or fail and how the rewards would
+/// be split
+///
+/// Each stage is simulated against the accounts as they are on chain, not as the previous stages
+/// would leave them: a stage needing accounts a previous stage creates fails until they exist.
+/// Operators vote themselves, the vote stage is not simulated.
+///
+/// # Arguments
+/// * `handler` - CLI handler, transactions are only simulated when it runs with `--dry-run`
+/// * `epoch` - The epoch to simulate
+/// * `rewards` - Rewards to split, the rewards in the NCN reward receiver when `None`
+pub async fn simulate_epoch(handler: &CliHandler, epoch: u64, rewards: Option<u64>) -> Result<()> {
+    if !handler.dry_run {
+        return Err(anyhow!("Epoch simulation requires --dry-run"));
+    }
+
+    let no_operators = HashSet::new();
+    let stages = vec![
+        simulate_stage(
+            handler,
+            "Register Vaults",
+            crank_register_vaults(handler, None),
+        )
+        .await,
+        simulate_stage(
+            handler,
+            "Create Epoch",
+            crank_create_or_skip_epoch(handler, epoch),
+        )
+        .await,
+        simulate_stage(handler, "Set Weight", crank_set_weight(handler, epoch)).await,
+        simulate_stage(handler, "Snapshot", async {
+            crank_snapshot(handler, epoch, &no_operators)
+                .await
+                .map(|_| ())
+        })
+        .await,
+        simulate_stage(
+            handler,
+            "Post Vote Cooldown",
+            crank_post_vote_cooldown(handler, epoch),
+        )
+        .await,
+        simulate_stage(handler, "Distribute", async {
+            crank_route_and_distribute(handler, epoch, true, true, &no_operators)
+                .await
+                .map(|_| ())
+        })
+        .await,
+        simulate_stage(
+            handler,
+            "Close",
+            crank_close_epoch_accounts(handler, epoch, &EpochAccountType::ALL),
+        )
+        .await,
+    ];
+
+    let reward_simulation = simulate_rewards(handler, epoch, rewards).await;
+
+    info!(
+        "{}",
+        simulation_report(epoch, &stages, reward_simulation.as_ref())
+    );
+
+    Ok(())
+}
+
+async fn simulate_stage(
+    handler: &CliHandler,
+    name: &'static str,
+    stage: impl Future<Output = Result<()>>,
+) -> StageSimulation {
+    info!("Simulating stage {}", name);
+
+    let simulated_before = simulated_transactions(handler).len();
+    let error = stage.await.err().map(|e| e.to_string());
+    let transactions = simulated_transactions(handler)
+        .get(simulated_before..)
+        .unwrap_or_default()
+        .to_vec();
+
+    StageSimulation {
+        name,
+        transactions,
+        error,
+    }
+}
+
+fn simulated_transactions(handler: &CliHandler) -> Vec<SimulatedTransaction> {
+    handler
+        .simulated_transactions
+        .lock()
+        .map(|transactions| transactions.clone())
+        .unwrap_or_default()
+}
+
+/// Splits the rewards the way the reward routers of the epoch would
+async fn simulate_rewards(
+    handler: &CliHandler,
+    epoch: u64,
+    rewards: Option<u64>,
+) -> Result<RewardSimulation> {
+    let config = get_ncn_program_config(handler).await?;
+    let epoch_snapshot = get_epoch_snapshot(handler, epoch).await?;
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+
+    let rewards = match rewards {
+        Some(rewards) => rewards,
+        None => get_ncn_reward_receiver_rewards(handler, epoch).await?,
+    };
+
+    let mut operator_snapshots = vec![];
+    for operator in get_all_operators_in_ncn(handler).await? {
+        if let Ok(operator_snapshot) = get_operator_snapshot(handler, &operator, epoch).await {
+            operator_snapshots.push(operator_snapshot);
+        }
+    }
+
+    simulate_reward_waterfall(
+        &config,
+        &epoch_snapshot,
+        &ballot_box,
+        &operator_snapshots,
+        rewards,
+    )
+    .map_err(|e| anyhow!("Could not simulate the rewards of epoch {}: {:?}", epoch, e))
+}
+
+fn simulation_report(
+    epoch: u64,
+    stages: &[StageSimulation],
+    reward_simulation: Result<&RewardSimulation, &anyhow::Error>,
+) -> String {
+    let mut report = format!("\n\n---------- Simulation of epoch {} ----------", epoch);
+
+    for stage in stages {
+        report.push_str(&format!(
+            "\n{}: {}",
+            stage.name,
+            if stage.succeeded() {
+                "Would succeed"
+            } else {
+                "Would fail"
+            }
+        ));
+
+        for transaction in stage.transactions.iter() {
+            let compute_units = transaction
+                .compute_units
+                .map_or("Unknown".to_string(), |units| units.to_string());
+
+            match &transaction.error {
+                None => report.push_str(&format!(
+                    "\n  OK     {} ({} CU)",
+                    transaction.title, compute_units
+                )),
+                Some(error) => report.push_str(&format!(
+                    "\n  FAILED {} ({} CU): {}",
+                    transaction.title, compute_units, error
+                )),
+            }
+        }
+
+        if let Some(error) = &stage.error {
+            report.push_str(&format!("\n  Stopped: {}", error));
+        }
+    }
+
+    match reward_simulation {
+        Ok(simulation) => {
+            report.push_str(&format!(
+                "\nReward Split of {} lamports:\n  Protocol: {}\n  NCN: {}",
+                simulation.rewards, simulation.protocol_rewards, simulation.ncn_rewards
+            ));
+
+            for (group, rewards) in simulation.fee_group_rewards.iter().enumerate() {
+                if *rewards > 0 {
+                    report.push_str(&format!("\n  Fee Group {}: {}", group, rewards));
+                }
+            }
+
+            for operator in simulation.operators.iter() {
+                report.push_str(&format!(
+                    "\n  Operator {}: {} routed, {} to the operator",
+                    operator.operator, operator.route_rewards, operator.operator_rewards
+                ));
+
+                for vault in operator.vaults.iter() {
+                    report.push_str(&format!("\n    Vault {}: {}", vault.vault, vault.rewards));
+                }
+            }
+        }
+        Err(e) => report.push_str(&format!("\nReward Split: Unknown, {}", e)),
+    }
+
+    report.push_str(
+        "\nStages are simulated against the current accounts, a stage depending on accounts an earlier stage creates fails until they exist\n",
+    );
+
+    report
+}
@@ -0,0 +1,136 @@
+use std::{
+    collections::BTreeSet,
+    path::PathBuf,
+    sync::{Mutex, MutexGuard},
+};
+
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::state_file;
+
+/// Progress of the snapshot crank in one epoch, persisted to the JSON file of
+/// `--snapshot-progress-file` as the vaults are updated and the delegations snapshotted, so a
+/// restarted crank resumes from the first `(operator, vault)` delegation left instead of updating
+/// every vault again
+///
+/// Like the keeper state file it is only a shortcut: a vault whose delegations then fail to
+/// snapshot is forgotten and updated again on the next run, and a file of another epoch is
+/// started over.
+#[derive(Debug)]
+pub struct SnapshotProgress {
+    path: Option<PathBuf>,
+    progress: Mutex<EpochSnapshotProgress>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EpochSnapshotProgress {
+    epoch: u64,
+    updated_vaults: BTreeSet<String>,
+    /// `<operator>:<vault>`
+    snapshotted_delegations: BTreeSet<String>,
+}
+
+impl SnapshotProgress {
+    /// Reads the progress of the epoch from `path`. Without a path the progress is kept in
+    /// memory only.
+    pub fn load(path: Option<&str>, epoch: u64) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self {
+                path: None,
+                progress: Mutex::new(EpochSnapshotProgress {
+                    epoch,
+                    ..EpochSnapshotProgress::default()
+                }),
+            });
+        };
+        let path = PathBuf::from(path);
+
+        let progress = state_file::load::<EpochSnapshotProgress>(&path, "snapshot progress file")?
+            .filter(|progress| progress.epoch == epoch);
+
+        if let Some(progress) = &progress {
+            info!(
+                "Resuming the snapshot of epoch {} from {}, {} vaults updated and {} delegations snapshotted",
+                epoch,
+                path.display(),
+                progress.updated_vaults.len(),
+                progress.snapshotted_delegations.len()
+            );
+        }
+
+        Ok(Self {
+            path: Some(path),
+            progress: Mutex::new(progress.unwrap_or(EpochSnapshotProgress {
+                epoch,
+                ..EpochSnapshotProgress::default()
+            })),
+        })
+    }
+
+    pub fn is_vault_updated(&self, vault: &Pubkey) -> bool {
+        self.lock()
+            .is_some_and(|progress| progress.updated_vaults.contains(&vault.to_string()))
+    }
+
+    pub fn is_delegation_snapshotted(&self, operator: &Pubkey, vault: &Pubkey) -> bool {
+        self.lock().is_some_and(|progress| {
+            progress
+                .snapshotted_delegations
+                .contains(&delegation_key(operator, vault))
+        })
+    }
+
+    pub fn record_vault_updated(&self, vault: &Pubkey) {
+        if let Some(mut progress) = self.lock() {
+            progress.updated_vaults.insert(vault.to_string());
+            self.save(&progress);
+        }
+    }
+
+    /// Forgets the vault was updated, it is updated again on the next run
+    pub fn forget_vault_updated(&self, vault: &Pubkey) {
+        if let Some(mut progress) = self.lock() {
+            if progress.updated_vaults.remove(&vault.to_string()) {
+                self.save(&progress);
+            }
+        }
+    }
+
+    pub fn record_delegations_snapshotted(&self, delegations: &[(Pubkey, Pubkey)]) {
+        if let Some(mut progress) = self.lock() {
+            progress.snapshotted_delegations.extend(
+                delegations
+                    .iter()
+                    .map(|(operator, vault)| delegation_key(operator, vault)),
+            );
+            self.save(&progress);
+        }
+    }
+
+    fn lock(&self) -> Option<MutexGuard<'_, EpochSnapshotProgress>> {
+        self.progress.lock().ok()
+    }
+
+    /// Writes the progress to disk, see `state_file::save`. Failing to write it only costs a
+    /// slower restart, it is logged.
+    fn save(&self, progress: &EpochSnapshotProgress) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        if let Err(e) = state_file::save(path, progress) {
+            warn!(
+                "Could not save the snapshot progress to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+fn delegation_key(operator: &Pubkey, vault: &Pubkey) -> String {
+    format!("{}:{}", operator, vault)
+}
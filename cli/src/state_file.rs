@@ -0,0 +1,108 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+};
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Epochs kept in a state file before the epoch being worked on, older ones were closed long ago
+pub const EPOCHS_KEPT: u64 = 16;
+
+/// Reads the JSON state file `path`, `None` when it does not exist yet. `name` describes the file
+/// in errors, e.g. "keeper state file".
+pub fn load<T: DeserializeOwned>(path: &Path, name: &str) -> Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Could not read {}: {}", path.display(), e))?;
+    let state = serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Invalid {} {}: {}", name, path.display(), e))?;
+
+    Ok(Some(state))
+}
+
+/// Writes the state to a temporary file first, a crash mid-write leaves the previous one. The file
+/// is synced to disk before it replaces `path` and the directory after, so a state that was saved
+/// survives a crash of the host and not only of the process.
+pub fn save<T: Serialize + ?Sized>(path: &Path, state: &T) -> Result<()> {
+    let contents = serde_json::to_string_pretty(state)?;
+
+    write_synced(path, contents.as_bytes())
+        .map_err(|e| anyhow!("Could not save {}: {}", path.display(), e))
+}
+
+/// Forgets the epochs more than `EPOCHS_KEPT` before `epoch`
+pub fn retain_recent_epochs<V>(epochs: &mut BTreeMap<u64, V>, epoch: u64) {
+    epochs.retain(|progress_epoch, _| progress_epoch + EPOCHS_KEPT >= epoch);
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+
+    fs::rename(&tmp_path, path)?;
+
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn state_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("state-file-{}-{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        path
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = state_path("round-trip");
+
+        assert!(load::<BTreeMap<u64, String>>(&path, "test state file")
+            .unwrap()
+            .is_none());
+
+        let state = BTreeMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+        save(&path, &state).unwrap();
+        assert_eq!(load(&path, "test state file").unwrap(), Some(state));
+        assert!(!path.with_extension("tmp").exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_load_corrupt_file() {
+        let path = state_path("corrupt");
+        fs::write(&path, "not a state file").unwrap();
+
+        assert!(load::<BTreeMap<u64, String>>(&path, "test state file").is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_retain_recent_epochs() {
+        let mut epochs: BTreeMap<u64, ()> = (0..=20).map(|epoch| (epoch, ())).collect();
+
+        retain_recent_epochs(&mut epochs, 20);
+        assert_eq!(epochs.keys().next(), Some(&(20 - EPOCHS_KEPT)));
+        assert_eq!(epochs.len() as u64, EPOCHS_KEPT + 1);
+    }
+}
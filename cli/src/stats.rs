@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use ncn_program_core::{
+    ballot_box::BallotBox, epoch_snapshot::EpochSnapshot, ncn_reward_router::NCNRewardRouter,
+};
+use serde::Serialize;
+
+use crate::{
+    args::ReportFormat,
+    getters::{get_ballot_box, get_epoch_snapshot, get_ncn_reward_router},
+    handler::CliHandler,
+};
+
+/// The consensus, participation and rewards of one epoch
+#[derive(Debug, Clone, Serialize)]
+pub struct EpochStats {
+    pub epoch: u64,
+    pub consensus_reached: bool,
+    /// Slots from the first vote to consensus
+    pub consensus_latency: Option<u64>,
+    pub operators_voted: u64,
+    /// The operators of the epoch snapshot, unknown when it was closed
+    pub operators_expected: Option<u64>,
+    pub participation_rate: Option<f64>,
+    /// Lamports routed by the NCN reward router, unknown when it was closed or never created
+    pub rewards_routed: Option<u64>,
+    pub operator_rewards: Vec<(String, u64)>,
+}
+
+/// The rewards routed to one operator over several epochs
+#[derive(Debug, Clone, Serialize)]
+pub struct OperatorRewards {
+    pub operator: String,
+    pub rewards: u64,
+    pub epochs: u64,
+}
+
+/// The epochs summed up
+#[derive(Debug, Clone, Serialize)]
+pub struct NcnStats {
+    pub epochs_found: usize,
+    pub epochs_with_consensus: usize,
+    pub average_consensus_latency: Option<u64>,
+    pub max_consensus_latency: Option<u64>,
+    pub average_participation_rate: Option<f64>,
+    pub total_rewards_routed: u64,
+    pub operator_rewards: Vec<OperatorRewards>,
+    pub epochs: Vec<EpochStats>,
+}
+
+pub fn epoch_stats(
+    ballot_box: &BallotBox,
+    epoch_snapshot: Option<&EpochSnapshot>,
+    ncn_reward_router: Option<&NCNRewardRouter>,
+) -> Result<EpochStats> {
+    let consensus_reached = ballot_box.is_consensus_reached();
+    let first_vote = ballot_box.iter_votes().map(|vote| vote.slot_voted()).min();
+    let consensus_latency = first_vote
+        .filter(|_| consensus_reached)
+        .map(|slot| ballot_box.slot_consensus_reached().saturating_sub(slot));
+
+    let operators_voted = ballot_box.operators_voted();
+    let operators_expected = epoch_snapshot.map(|epoch_snapshot| epoch_snapshot.operator_count());
+    let participation_rate = operators_expected
+        .filter(|operators| *operators > 0)
+        .map(|operators| operators_voted as f64 / operators as f64);
+
+    let mut operator_rewards = vec![];
+    if let Some(ncn_reward_router) = ncn_reward_router {
+        for route in ncn_reward_router.iter_routes() {
+            operator_rewards.push((route.operator().to_string(), route.rewards()?));
+        }
+    }
+
+    Ok(EpochStats {
+        epoch: ballot_box.epoch(),
+        consensus_reached,
+        consensus_latency,
+        operators_voted,
+        operators_expected,
+        participation_rate,
+        rewards_routed: ncn_reward_router
+            .map(|ncn_reward_router| ncn_reward_router.total_rewards()),
+        operator_rewards,
+    })
+}
+
+pub fn summarize_stats(epochs: Vec<EpochStats>) -> NcnStats {
+    let latencies: Vec<u64> = epochs
+        .iter()
+        .filter_map(|epoch| epoch.consensus_latency)
+        .collect();
+    let participation_rates: Vec<f64> = epochs
+        .iter()
+        .filter_map(|epoch| epoch.participation_rate)
+        .collect();
+
+    let mut operator_rewards: BTreeMap<String, OperatorRewards> = BTreeMap::new();
+    for epoch in epochs.iter() {
+        for (operator, rewards) in epoch.operator_rewards.iter() {
+            let totals =
+                operator_rewards
+                    .entry(operator.clone())
+                    .or_insert_with(|| OperatorRewards {
+                        operator: operator.clone(),
+                        rewards: 0,
+                        epochs: 0,
+                    });
+            totals.rewards = totals.rewards.saturating_add(*rewards);
+            totals.epochs += 1;
+        }
+    }
+
+    NcnStats {
+        epochs_found: epochs.len(),
+        epochs_with_consensus: epochs
+            .iter()
+            .filter(|epoch| epoch.consensus_reached)
+            .count(),
+        average_consensus_latency: latencies
+            .iter()
+            .sum::<u64>()
+            .checked_div(latencies.len() as u64),
+        max_consensus_latency: latencies.iter().max().copied(),
+        average_participation_rate: (!participation_rates.is_empty())
+            .then(|| participation_rates.iter().sum::<f64>() / participation_rates.len() as f64),
+        total_rewards_routed: epochs
+            .iter()
+            .filter_map(|epoch| epoch.rewards_routed)
+            .fold(0, u64::saturating_add),
+        operator_rewards: operator_rewards.into_values().collect(),
+        epochs,
+    }
+}
+
+async fn get_epoch_stats(handler: &CliHandler, epoch: u64) -> Result<EpochStats> {
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+    let epoch_snapshot = get_epoch_snapshot(handler, epoch).await.ok();
+    let ncn_reward_router = get_ncn_reward_router(handler, epoch).await.ok();
+
+    epoch_stats(
+        &ballot_box,
+        epoch_snapshot.as_ref(),
+        ncn_reward_router.as_ref(),
+    )
+}
+
+/// Sums up the consensus latency, participation and rewards routed of the last epochs up to
+/// `epoch`, epochs without a ballot box are left out
+pub async fn report_stats(
+    handler: &CliHandler,
+    epoch: u64,
+    last: u64,
+    format: ReportFormat,
+) -> Result<()> {
+    if last == 0 {
+        return Err(anyhow!("--last needs at least one epoch"));
+    }
+
+    let mut epochs = vec![];
+    for stats_epoch in epoch.saturating_sub(last - 1)..=epoch {
+        match get_epoch_stats(handler, stats_epoch).await {
+            Ok(stats) => epochs.push(stats),
+            Err(e) => info!("Leaving out epoch {}: {}", stats_epoch, e),
+        }
+    }
+
+    let stats = summarize_stats(epochs);
+    match format {
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+        ReportFormat::Table => info!("{}", stats_table(epoch, last, &stats)),
+    }
+
+    Ok(())
+}
+
+fn stats_table(epoch: u64, last: u64, stats: &NcnStats) -> String {
+    let optional = |value: Option<u64>| value.map_or("-".to_string(), |value| value.to_string());
+    let rate =
+        |rate: Option<f64>| rate.map_or("-".to_string(), |rate| format!("{:.2}%", rate * 100.0));
+
+    let mut table = format!(
+        "\n\n--- Stats of the last {} epochs up to {} ({} with a ballot box) ---\nEpochs With Consensus: {}\nAverage Consensus Latency: {} slots\nMax Consensus Latency: {} slots\nAverage Participation: {}\nTotal Rewards Routed: {}\n\n{:>8} {:<9} {:>10} {:>8} {:>14} {:>20}",
+        last,
+        epoch,
+        stats.epochs_found,
+        stats.epochs_with_consensus,
+        optional(stats.average_consensus_latency),
+        optional(stats.max_consensus_latency),
+        rate(stats.average_participation_rate),
+        stats.total_rewards_routed,
+        "Epoch",
+        "Consensus",
+        "Latency",
+        "Voted",
+        "Participation",
+        "Rewards Routed"
+    );
+
+    for epoch in stats.epochs.iter() {
+        table.push_str(&format!(
+            "\n{:>8} {:<9} {:>10} {:>8} {:>14} {:>20}",
+            epoch.epoch,
+            if epoch.consensus_reached { "Yes" } else { "No" },
+            optional(epoch.consensus_latency),
+            epoch.operators_voted,
+            rate(epoch.participation_rate),
+            optional(epoch.rewards_routed)
+        ));
+    }
+
+    table.push_str(&format!(
+        "\n\n{:<44} {:>20} {:>8}",
+        "Operator", "Rewards", "Epochs"
+    ));
+    for operator in stats.operator_rewards.iter() {
+        table.push_str(&format!(
+            "\n{:<44} {:>20} {:>8}",
+            operator.operator, operator.rewards, operator.epochs
+        ));
+    }
+
+    table.push('\n');
+    table
+}
@@ -0,0 +1,538 @@
+use anyhow::{anyhow, Result};
+use jito_restaking_core::{
+    config::Config as RestakingConfig, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::sdk::{
+    initialize_ncn, initialize_ncn_operator_state, initialize_ncn_vault_ticket,
+    initialize_operator, initialize_operator_vault_ticket, ncn_warmup_operator,
+    operator_warmup_ncn, warmup_ncn_vault_ticket, warmup_operator_vault_ticket,
+};
+use jito_vault_core::{
+    burn_vault::BurnVault, config::Config as VaultConfig, vault::Vault,
+    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+};
+use jito_vault_sdk::sdk::{
+    add_delegation, initialize_vault, initialize_vault_ncn_ticket,
+    initialize_vault_operator_delegation, mint_to, warmup_vault_ncn_ticket,
+};
+use log::info;
+use ncn_program_core::constants::{
+    MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MIN_EPOCHS_BEFORE_STALL,
+    MIN_VALID_SLOTS_AFTER_CONSENSUS,
+};
+use solana_sdk::{
+    program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    system_instruction::create_account,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+use spl_token::{state::Mint, ui_amount_to_amount};
+
+use crate::{
+    getters::{get_vault, get_vault_config},
+    handler::CliHandler,
+    instructions::{
+        admin_create_config, admin_fund_account_payer, crank_register_vaults,
+        create_vault_registry, send_and_log_transaction,
+    },
+};
+
+/// Decimals of the st mints of the test vaults
+const ST_MINT_DECIMALS: u8 = 9;
+
+/// Creates a throwaway NCN administered by the keypair, with operators and vaults delegating
+/// `delegation` tokens to each of them, registered with the NCN program. The keypair pays for
+/// everything and owns the st mints.
+///
+/// The tickets and delegations take effect from the next NCN epoch of the restaking program, the
+/// keeper can only snapshot them from then on.
+pub async fn setup_test_env(
+    handler: &CliHandler,
+    operators: u64,
+    vaults: u64,
+    delegation: f64,
+    st_mint_weight: u128,
+    ncn_fee_bps: u16,
+    account_payer_sol: f64,
+) -> Result<()> {
+    if handler.ncn.get().is_some() {
+        return Err(anyhow!(
+            "test-env setup creates its own NCN, run it without --ncn"
+        ));
+    }
+
+    let keypair = handler.keypair()?.pubkey();
+    if handler.admin()?.ne(&keypair) {
+        return Err(anyhow!(
+            "test-env setup administers the NCN with the keypair, run it without a multisig or proposal authority"
+        ));
+    }
+
+    let ncn = create_ncn(handler).await?;
+    handler
+        .ncn
+        .set(ncn)
+        .map_err(|_| anyhow!("NCN already set"))?;
+
+    let mut operator_addresses = vec![];
+    for _ in 0..operators {
+        operator_addresses.push(create_operator(handler, &ncn).await?);
+    }
+
+    let delegation = ui_amount_to_amount(delegation, ST_MINT_DECIMALS);
+    let mut vault_addresses = vec![];
+    for _ in 0..vaults {
+        let vault = create_vault(handler, delegation.saturating_mul(operators)).await?;
+        connect_vault(handler, &ncn, &vault).await?;
+
+        for operator in operator_addresses.iter() {
+            delegate_to_operator(handler, &vault, operator, delegation).await?;
+        }
+
+        vault_addresses.push(vault);
+    }
+
+    admin_create_config(
+        handler,
+        keypair,
+        ncn_fee_bps,
+        None,
+        MIN_EPOCHS_BEFORE_STALL,
+        MIN_VALID_SLOTS_AFTER_CONSENSUS,
+        MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
+    )
+    .await?;
+    create_vault_registry(handler).await?;
+    crank_register_vaults(handler, Some(st_mint_weight)).await?;
+    admin_fund_account_payer(handler, account_payer_sol).await?;
+
+    info!(
+        "\n\nTest environment ready\n  NCN: {}\n  Operators: {:?}\n  Vaults: {:?}\n\nThe tickets and delegations take effect from the next NCN epoch, then run the keeper with --ncn {}\n",
+        ncn, operator_addresses, vault_addresses, ncn
+    );
+
+    Ok(())
+}
+
+async fn create_ncn(handler: &CliHandler) -> Result<Pubkey> {
+    let admin = handler.keypair()?.pubkey();
+    let base = Keypair::new();
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (ncn, _, _) = Ncn::find_program_address(&handler.restaking_program_id, &base.pubkey());
+
+    let instruction = initialize_ncn(
+        &handler.restaking_program_id,
+        &restaking_config,
+        &ncn,
+        &admin,
+        &base.pubkey(),
+    );
+
+    send_and_log_transaction(
+        handler,
+        &[instruction],
+        &[&base],
+        "Created Test NCN",
+        &[format!("NCN: {:?}", ncn)],
+    )
+    .await?;
+
+    Ok(ncn)
+}
+
+/// Creates an operator and opts it and the NCN in to each other
+async fn create_operator(handler: &CliHandler, ncn: &Pubkey) -> Result<Pubkey> {
+    let admin = handler.keypair()?.pubkey();
+    let base = Keypair::new();
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (operator, _, _) =
+        Operator::find_program_address(&handler.restaking_program_id, &base.pubkey());
+    let (ncn_operator_state, _, _) =
+        NcnOperatorState::find_program_address(&handler.restaking_program_id, ncn, &operator);
+
+    let initialize_instructions = [
+        initialize_operator(
+            &handler.restaking_program_id,
+            &restaking_config,
+            &operator,
+            &admin,
+            &base.pubkey(),
+            0,
+        ),
+        initialize_ncn_operator_state(
+            &handler.restaking_program_id,
+            &restaking_config,
+            ncn,
+            &operator,
+            &ncn_operator_state,
+            &admin,
+            &admin,
+        ),
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &initialize_instructions,
+        &[&base],
+        "Created Test Operator",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+        ],
+    )
+    .await?;
+
+    // Warmed up once the state exists, in a later slot than its creation
+    let warmup_instructions = [
+        ncn_warmup_operator(
+            &handler.restaking_program_id,
+            &restaking_config,
+            ncn,
+            &operator,
+            &ncn_operator_state,
+            &admin,
+        ),
+        operator_warmup_ncn(
+            &handler.restaking_program_id,
+            &restaking_config,
+            ncn,
+            &operator,
+            &ncn_operator_state,
+            &admin,
+        ),
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &warmup_instructions,
+        &[],
+        "Warmed Up Test Operator",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+        ],
+    )
+    .await?;
+
+    Ok(operator)
+}
+
+/// Creates a vault with a new st mint and deposits `deposit` tokens in it
+async fn create_vault(handler: &CliHandler, deposit: u64) -> Result<Pubkey> {
+    let admin = handler.keypair()?.pubkey();
+    let st_mint = Keypair::new();
+    let vrt_mint = Keypair::new();
+    let base = Keypair::new();
+
+    let admin_st_token_account = get_associated_token_address(&admin, &st_mint.pubkey());
+    let initialize_token_amount = Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT;
+
+    let mint_rent = handler
+        .rpc_client()
+        .get_minimum_balance_for_rent_exemption(Mint::LEN)
+        .await?;
+
+    let mint_instructions = [
+        create_account(
+            &admin,
+            &st_mint.pubkey(),
+            mint_rent,
+            Mint::LEN as u64,
+            &handler.token_program_id,
+        ),
+        spl_token::instruction::initialize_mint2(
+            &handler.token_program_id,
+            &st_mint.pubkey(),
+            &admin,
+            None,
+            ST_MINT_DECIMALS,
+        )?,
+        create_associated_token_account_idempotent(
+            &admin,
+            &admin,
+            &st_mint.pubkey(),
+            &handler.token_program_id,
+        ),
+        spl_token::instruction::mint_to(
+            &handler.token_program_id,
+            &st_mint.pubkey(),
+            &admin_st_token_account,
+            &admin,
+            &[],
+            initialize_token_amount.saturating_add(deposit),
+        )?,
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &mint_instructions,
+        &[&st_mint],
+        "Created Test St Mint",
+        &[format!("St Mint: {:?}", st_mint.pubkey())],
+    )
+    .await?;
+
+    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+    let (vault, _, _) = Vault::find_program_address(&handler.vault_program_id, &base.pubkey());
+    let (burn_vault, _, _) =
+        BurnVault::find_program_address(&handler.vault_program_id, &base.pubkey());
+    let vault_st_token_account = get_associated_token_address(&vault, &st_mint.pubkey());
+
+    let vault_instructions = [
+        create_associated_token_account_idempotent(
+            &admin,
+            &vault,
+            &st_mint.pubkey(),
+            &handler.token_program_id,
+        ),
+        initialize_vault(
+            &handler.vault_program_id,
+            &vault_config,
+            &vault,
+            &vrt_mint.pubkey(),
+            &st_mint.pubkey(),
+            &admin_st_token_account,
+            &vault_st_token_account,
+            &burn_vault,
+            &get_associated_token_address(&burn_vault, &vrt_mint.pubkey()),
+            &admin,
+            &base.pubkey(),
+            0,
+            0,
+            0,
+            ST_MINT_DECIMALS,
+            initialize_token_amount,
+        ),
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &vault_instructions,
+        &[&vrt_mint, &base],
+        "Created Test Vault",
+        &[
+            format!("Vault: {:?}", vault),
+            format!("St Mint: {:?}", st_mint.pubkey()),
+        ],
+    )
+    .await?;
+
+    let vault_account = get_vault(handler, &vault).await?;
+    let program_fee_wallet = get_vault_config(handler).await?.program_fee_wallet;
+    let admin_vrt_token_account = get_associated_token_address(&admin, &vrt_mint.pubkey());
+
+    let deposit_instructions = [
+        create_associated_token_account_idempotent(
+            &admin,
+            &admin,
+            &vrt_mint.pubkey(),
+            &handler.token_program_id,
+        ),
+        create_associated_token_account_idempotent(
+            &admin,
+            &vault_account.fee_wallet,
+            &vrt_mint.pubkey(),
+            &handler.token_program_id,
+        ),
+        create_associated_token_account_idempotent(
+            &admin,
+            &program_fee_wallet,
+            &vrt_mint.pubkey(),
+            &handler.token_program_id,
+        ),
+        mint_to(
+            &handler.vault_program_id,
+            &vault_config,
+            &vault,
+            &vrt_mint.pubkey(),
+            &admin,
+            &admin_st_token_account,
+            &vault_st_token_account,
+            &admin_vrt_token_account,
+            &get_associated_token_address(&vault_account.fee_wallet, &vrt_mint.pubkey()),
+            None,
+            deposit,
+            deposit,
+        ),
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &deposit_instructions,
+        &[],
+        "Deposited In Test Vault",
+        &[
+            format!("Vault: {:?}", vault),
+            format!("Amount: {:?}", deposit),
+        ],
+    )
+    .await?;
+
+    Ok(vault)
+}
+
+/// Opts the vault and the NCN in to each other
+async fn connect_vault(handler: &CliHandler, ncn: &Pubkey, vault: &Pubkey) -> Result<()> {
+    let admin = handler.keypair()?.pubkey();
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+    let (ncn_vault_ticket, _, _) =
+        NcnVaultTicket::find_program_address(&handler.restaking_program_id, ncn, vault);
+    let (vault_ncn_ticket, _, _) =
+        VaultNcnTicket::find_program_address(&handler.vault_program_id, vault, ncn);
+
+    let log_items = [format!("NCN: {:?}", ncn), format!("Vault: {:?}", vault)];
+
+    // Every ticket is warmed up in a later slot than its creation, as the vault ticket needs the
+    // NCN ticket warming up
+    send_and_log_transaction(
+        handler,
+        &[initialize_ncn_vault_ticket(
+            &handler.restaking_program_id,
+            &restaking_config,
+            ncn,
+            vault,
+            &ncn_vault_ticket,
+            &admin,
+            &admin,
+        )],
+        &[],
+        "Created Test NCN Vault Ticket",
+        &log_items,
+    )
+    .await?;
+
+    send_and_log_transaction(
+        handler,
+        &[
+            warmup_ncn_vault_ticket(
+                &handler.restaking_program_id,
+                &restaking_config,
+                ncn,
+                vault,
+                &ncn_vault_ticket,
+                &admin,
+            ),
+            initialize_vault_ncn_ticket(
+                &handler.vault_program_id,
+                &vault_config,
+                vault,
+                ncn,
+                &ncn_vault_ticket,
+                &vault_ncn_ticket,
+                &admin,
+                &admin,
+            ),
+        ],
+        &[],
+        "Created Test Vault NCN Ticket",
+        &log_items,
+    )
+    .await?;
+
+    send_and_log_transaction(
+        handler,
+        &[warmup_vault_ncn_ticket(
+            &handler.vault_program_id,
+            &vault_config,
+            vault,
+            ncn,
+            &vault_ncn_ticket,
+            &admin,
+        )],
+        &[],
+        "Warmed Up Test Vault NCN Ticket",
+        &log_items,
+    )
+    .await
+}
+
+/// Opts the operator in to the vault and delegates `amount` of the vault to it
+async fn delegate_to_operator(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let admin = handler.keypair()?.pubkey();
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+    let (operator_vault_ticket, _, _) =
+        OperatorVaultTicket::find_program_address(&handler.restaking_program_id, operator, vault);
+    let (vault_operator_delegation, _, _) =
+        VaultOperatorDelegation::find_program_address(&handler.vault_program_id, vault, operator);
+
+    let log_items = [
+        format!("Vault: {:?}", vault),
+        format!("Operator: {:?}", operator),
+    ];
+
+    send_and_log_transaction(
+        handler,
+        &[initialize_operator_vault_ticket(
+            &handler.restaking_program_id,
+            &restaking_config,
+            operator,
+            vault,
+            &operator_vault_ticket,
+            &admin,
+            &admin,
+        )],
+        &[],
+        "Created Test Operator Vault Ticket",
+        &log_items,
+    )
+    .await?;
+
+    send_and_log_transaction(
+        handler,
+        &[
+            warmup_operator_vault_ticket(
+                &handler.restaking_program_id,
+                &restaking_config,
+                operator,
+                vault,
+                &operator_vault_ticket,
+                &admin,
+            ),
+            initialize_vault_operator_delegation(
+                &handler.vault_program_id,
+                &vault_config,
+                vault,
+                operator,
+                &operator_vault_ticket,
+                &vault_operator_delegation,
+                &admin,
+                &admin,
+            ),
+            add_delegation(
+                &handler.vault_program_id,
+                &vault_config,
+                vault,
+                operator,
+                &vault_operator_delegation,
+                &admin,
+                amount,
+            ),
+        ],
+        &[],
+        "Delegated Test Vault To Operator",
+        &[
+            format!("Vault: {:?}", vault),
+            format!("Operator: {:?}", operator),
+            format!("Amount: {:?}", amount),
+        ],
+    )
+    .await
+}
@@ -0,0 +1,535 @@
+use std::{collections::BTreeSet, fmt::Display, fs, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use ncn_program_core::config::Config as NCNProgramConfig;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{getters::get_ncn_program_config, handler::CliHandler};
+
+/// The config an NCN is expected to have, read from the TOML file given to `verify-config`. Only
+/// the declared settings are checked, the others can hold anything.
+///
+/// ```toml
+/// tie_breaker_admin = "5zqy3uyXMi5Uurup7S5kKUUuwHGnGcykVwwUik37fm6i"
+/// epochs_before_stall = 3
+///
+/// [fees]
+/// ncn_fee_bps = 400
+///
+/// [[fees.fee_groups]]
+/// group = 0
+/// name = "Insurance"
+/// fee_bps = 100
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedConfig {
+    pub tie_breaker_admin: Option<String>,
+    pub valid_slots_after_consensus: Option<u64>,
+    pub epochs_before_stall: Option<u64>,
+    pub epochs_after_consensus_before_close: Option<u64>,
+    pub starting_valid_epoch: Option<u64>,
+    pub cranker_fee_bps: Option<u16>,
+    pub timeliness_bonus_slots: Option<u64>,
+    pub timeliness_bonus_bps: Option<u16>,
+    pub weight_decay_epochs: Option<u64>,
+    pub weight_decay_bps: Option<u16>,
+    pub max_vault_stake_weight_bps: Option<u16>,
+    pub permissionless_vault_registration: Option<bool>,
+    pub parameters_timelock_slots: Option<u64>,
+    /// Compared as a set, unused slots left out
+    pub reward_mints: Option<Vec<String>>,
+    pub admin_threshold: Option<u8>,
+    /// Compared as a set
+    pub admin_approvers: Option<Vec<String>>,
+    pub admin_governance_program: Option<String>,
+    pub admin_governance_realm: Option<String>,
+    pub fees: Option<ExpectedFees>,
+}
+
+/// The fees active in the verified epoch and the wallets receiving them
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedFees {
+    pub protocol_fee_wallet: Option<String>,
+    pub ncn_fee_wallet: Option<String>,
+    pub protocol_fee_bps: Option<u16>,
+    pub ncn_fee_bps: Option<u16>,
+    #[serde(default)]
+    pub fee_groups: Vec<ExpectedFeeGroup>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedFeeGroup {
+    pub group: usize,
+    pub name: Option<String>,
+    pub wallet: Option<String>,
+    pub fee_bps: Option<u16>,
+}
+
+/// A setting whose value on chain is not the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDrift {
+    pub setting: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Collects the settings drifting from their expected values
+#[derive(Debug, Default)]
+struct DriftCheck {
+    drifts: Vec<ConfigDrift>,
+}
+
+impl DriftCheck {
+    fn check<T: PartialEq + Display>(&mut self, setting: &str, expected: Option<T>, actual: T) {
+        if let Some(expected) = expected {
+            if expected != actual {
+                self.drifts.push(ConfigDrift {
+                    setting: setting.to_string(),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Checks the value a parameter change queued behind the timelock would set, the setting
+    /// drifts once the change is applied
+    fn check_pending<T: PartialEq + Display>(
+        &mut self,
+        setting: &str,
+        effective_slot: u64,
+        expected: Option<T>,
+        pending: Option<T>,
+    ) {
+        if let Some(pending) = pending {
+            self.check(
+                &format!("{} (pending from slot {})", setting, effective_slot),
+                expected,
+                pending,
+            );
+        }
+    }
+
+    fn check_set(
+        &mut self,
+        setting: &str,
+        expected: Option<BTreeSet<Pubkey>>,
+        actual: BTreeSet<Pubkey>,
+    ) {
+        self.check(setting, expected.map(PubkeySet), PubkeySet(actual));
+    }
+}
+
+/// Addresses compared regardless of their order
+#[derive(Debug, PartialEq, Eq)]
+struct PubkeySet(BTreeSet<Pubkey>);
+
+impl Display for PubkeySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let addresses: Vec<String> = self.0.iter().map(Pubkey::to_string).collect();
+        write!(f, "[{}]", addresses.join(", "))
+    }
+}
+
+fn parse_pubkey(setting: &str, address: &Option<String>) -> Result<Option<Pubkey>> {
+    address
+        .as_deref()
+        .map(|address| {
+            Pubkey::from_str(address).map_err(|e| anyhow!("Invalid {} {}: {}", setting, address, e))
+        })
+        .transpose()
+}
+
+fn parse_pubkey_set(
+    setting: &str,
+    addresses: &Option<Vec<String>>,
+) -> Result<Option<BTreeSet<Pubkey>>> {
+    addresses
+        .as_ref()
+        .map(|addresses| {
+            addresses
+                .iter()
+                .map(|address| {
+                    Pubkey::from_str(address)
+                        .map_err(|e| anyhow!("Invalid {} {}: {}", setting, address, e))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+impl ExpectedConfig {
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|e| anyhow!("Invalid expected config: {}", e))
+    }
+
+    /// The settings of `config` drifting from the expected ones, with the fees active in `epoch`.
+    /// Parameter changes queued behind the timelock are checked as well, a setting they would move
+    /// away from its expected value drifts already.
+    pub fn drifts(&self, config: &NCNProgramConfig, epoch: u64) -> Result<Vec<ConfigDrift>> {
+        let mut check = DriftCheck::default();
+
+        check.check(
+            "tie_breaker_admin",
+            parse_pubkey("tie_breaker_admin", &self.tie_breaker_admin)?,
+            config.tie_breaker_admin,
+        );
+        check.check(
+            "valid_slots_after_consensus",
+            self.valid_slots_after_consensus,
+            config.valid_slots_after_consensus(),
+        );
+        check.check(
+            "epochs_before_stall",
+            self.epochs_before_stall,
+            config.epochs_before_stall(),
+        );
+        check.check(
+            "epochs_after_consensus_before_close",
+            self.epochs_after_consensus_before_close,
+            config.epochs_after_consensus_before_close(),
+        );
+        check.check(
+            "starting_valid_epoch",
+            self.starting_valid_epoch,
+            config.starting_valid_epoch(),
+        );
+        check.check(
+            "cranker_fee_bps",
+            self.cranker_fee_bps,
+            config.cranker_fee_bps(),
+        );
+        check.check(
+            "timeliness_bonus_slots",
+            self.timeliness_bonus_slots,
+            config.timeliness_bonus_slots(),
+        );
+        check.check(
+            "timeliness_bonus_bps",
+            self.timeliness_bonus_bps,
+            config.timeliness_bonus_bps(),
+        );
+        check.check(
+            "weight_decay_epochs",
+            self.weight_decay_epochs,
+            config.weight_decay_epochs(),
+        );
+        check.check(
+            "weight_decay_bps",
+            self.weight_decay_bps,
+            config.weight_decay_bps(),
+        );
+        check.check(
+            "max_vault_stake_weight_bps",
+            self.max_vault_stake_weight_bps,
+            config.max_vault_stake_weight_bps(),
+        );
+        check.check(
+            "permissionless_vault_registration",
+            self.permissionless_vault_registration,
+            config.permissionless_vault_registration(),
+        );
+        check.check(
+            "parameters_timelock_slots",
+            self.parameters_timelock_slots,
+            config.parameters_timelock_slots(),
+        );
+        let pending_parameters = config.pending_parameters();
+        if pending_parameters.is_pending() {
+            let changes = pending_parameters.changes();
+            let effective_slot = pending_parameters.effective_slot();
+
+            check.check_pending(
+                "starting_valid_epoch",
+                effective_slot,
+                self.starting_valid_epoch,
+                changes.starting_valid_epoch,
+            );
+            check.check_pending(
+                "epochs_before_stall",
+                effective_slot,
+                self.epochs_before_stall,
+                changes.epochs_before_stall,
+            );
+            check.check_pending(
+                "epochs_after_consensus_before_close",
+                effective_slot,
+                self.epochs_after_consensus_before_close,
+                changes.epochs_after_consensus_before_close,
+            );
+            check.check_pending(
+                "valid_slots_after_consensus",
+                effective_slot,
+                self.valid_slots_after_consensus,
+                changes.valid_slots_after_consensus,
+            );
+            check.check_pending(
+                "cranker_fee_bps",
+                effective_slot,
+                self.cranker_fee_bps,
+                changes.cranker_fee_bps,
+            );
+            check.check_pending(
+                "timeliness_bonus_slots",
+                effective_slot,
+                self.timeliness_bonus_slots,
+                changes.timeliness_bonus_slots,
+            );
+            check.check_pending(
+                "timeliness_bonus_bps",
+                effective_slot,
+                self.timeliness_bonus_bps,
+                changes.timeliness_bonus_bps,
+            );
+            check.check_pending(
+                "weight_decay_epochs",
+                effective_slot,
+                self.weight_decay_epochs,
+                changes.weight_decay_epochs,
+            );
+            check.check_pending(
+                "weight_decay_bps",
+                effective_slot,
+                self.weight_decay_bps,
+                changes.weight_decay_bps,
+            );
+            check.check_pending(
+                "max_vault_stake_weight_bps",
+                effective_slot,
+                self.max_vault_stake_weight_bps,
+                changes.max_vault_stake_weight_bps,
+            );
+            check.check_pending(
+                "permissionless_vault_registration",
+                effective_slot,
+                self.permissionless_vault_registration,
+                changes.permissionless_vault_registration,
+            );
+            check.check_pending(
+                "parameters_timelock_slots",
+                effective_slot,
+                self.parameters_timelock_slots,
+                changes.parameters_timelock_slots,
+            );
+        }
+        check.check_set(
+            "reward_mints",
+            parse_pubkey_set("reward_mints", &self.reward_mints)?,
+            config
+                .reward_mints()
+                .iter()
+                .filter(|mint| mint.ne(&&Pubkey::default()))
+                .copied()
+                .collect(),
+        );
+        check.check(
+            "admin_threshold",
+            self.admin_threshold,
+            config.admin_threshold(),
+        );
+        check.check_set(
+            "admin_approvers",
+            parse_pubkey_set("admin_approvers", &self.admin_approvers)?,
+            config.admin_approvers().copied().collect(),
+        );
+        check.check(
+            "admin_governance_program",
+            parse_pubkey("admin_governance_program", &self.admin_governance_program)?,
+            *config.admin_governance_program(),
+        );
+        check.check(
+            "admin_governance_realm",
+            parse_pubkey("admin_governance_realm", &self.admin_governance_realm)?,
+            *config.admin_governance_realm(),
+        );
+
+        if let Some(fees) = &self.fees {
+            let fee_config = &config.fee_config;
+            let current_fees = fee_config.current_fees(epoch);
+
+            check.check(
+                "fees.protocol_fee_wallet",
+                parse_pubkey("fees.protocol_fee_wallet", &fees.protocol_fee_wallet)?,
+                *fee_config.protocol_fee_wallet(),
+            );
+            check.check(
+                "fees.ncn_fee_wallet",
+                parse_pubkey("fees.ncn_fee_wallet", &fees.ncn_fee_wallet)?,
+                *fee_config.ncn_fee_wallet(),
+            );
+            check.check(
+                "fees.protocol_fee_bps",
+                fees.protocol_fee_bps,
+                current_fees.protocol_fee_bps()?,
+            );
+            check.check(
+                "fees.ncn_fee_bps",
+                fees.ncn_fee_bps,
+                current_fees.ncn_fee_bps()?,
+            );
+
+            for expected_group in fees.fee_groups.iter() {
+                let group = expected_group.group;
+                let fee_group = fee_config.fee_group(group)?;
+                let setting = |name: &str| format!("fees.fee_groups.{}.{}", group, name);
+
+                check.check(
+                    &setting("name"),
+                    expected_group.name.clone(),
+                    fee_group.name_str().to_string(),
+                );
+                check.check(
+                    &setting("wallet"),
+                    parse_pubkey(&setting("wallet"), &expected_group.wallet)?,
+                    *fee_group.wallet(),
+                );
+                check.check(
+                    &setting("fee_bps"),
+                    expected_group.fee_bps,
+                    current_fees.fee_group_bps(group)?,
+                );
+            }
+        }
+
+        Ok(check.drifts)
+    }
+}
+
+/// Compares the on-chain config with the expected one of the TOML file, failing when any setting
+/// drifted so monitoring and CI can alert on it
+pub async fn verify_config(handler: &CliHandler, expected_path: &str) -> Result<()> {
+    let toml = fs::read_to_string(expected_path)
+        .map_err(|e| anyhow!("Could not read {}: {}", expected_path, e))?;
+    let expected =
+        ExpectedConfig::from_toml(&toml).map_err(|e| anyhow!("{}: {}", expected_path, e))?;
+
+    let config = get_ncn_program_config(handler).await?;
+    let drifts = expected.drifts(&config, handler.epoch)?;
+
+    if drifts.is_empty() {
+        info!("Config of NCN {} matches {}", handler.ncn()?, expected_path);
+        return Ok(());
+    }
+
+    let mut report = format!(
+        "\n\n---------- Config Drift ----------\nNCN: {}\nExpected: {}\nEpoch: {}",
+        handler.ncn()?,
+        expected_path,
+        handler.epoch
+    );
+    for drift in drifts.iter() {
+        report.push_str(&format!(
+            "\n  {}: expected {}, found {}",
+            drift.setting, drift.expected, drift.actual
+        ));
+    }
+    error!("{}\n", report);
+
+    Err(anyhow!(
+        "{} settings drifted from {}",
+        drifts.len(),
+        expected_path
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_bytemuck::types::PodU64;
+    use ncn_program_core::{config::ParameterChanges, constants::MIN_EPOCHS_BEFORE_STALL};
+
+    use super::*;
+
+    fn expected_config(ncn: &Pubkey) -> ExpectedConfig {
+        ExpectedConfig::from_toml(&format!(
+            r#"
+            tie_breaker_admin = "{ncn}"
+            epochs_before_stall = {MIN_EPOCHS_BEFORE_STALL}
+            cranker_fee_bps = 0
+
+            [fees]
+            ncn_fee_wallet = "{ncn}"
+            "#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_no_drift() {
+        let ncn = Pubkey::new_unique();
+        let config = NCNProgramConfig::new_for_test(&ncn);
+
+        assert_eq!(expected_config(&ncn).drifts(&config, 0).unwrap(), vec![]);
+
+        // Nothing declared, nothing checked
+        assert_eq!(
+            ExpectedConfig::default().drifts(&config, 0).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_single_drift() {
+        let ncn = Pubkey::new_unique();
+        let config = NCNProgramConfig::builder()
+            .ncn(&ncn)
+            .tie_breaker_admin(&ncn)
+            .ncn_fee_wallet(&ncn)
+            .epochs_before_stall(MIN_EPOCHS_BEFORE_STALL + 2)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            expected_config(&ncn).drifts(&config, 0).unwrap(),
+            vec![ConfigDrift {
+                setting: "epochs_before_stall".to_string(),
+                expected: MIN_EPOCHS_BEFORE_STALL.to_string(),
+                actual: (MIN_EPOCHS_BEFORE_STALL + 2).to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_pending_parameter_drift() {
+        let ncn = Pubkey::new_unique();
+        let mut config = NCNProgramConfig::new_for_test(&ncn);
+        config.parameters_timelock_slots = PodU64::from(100);
+
+        // In sync until the queued change is applied
+        config
+            .queue_parameters(
+                &ParameterChanges {
+                    cranker_fee_bps: Some(50),
+                    ..ParameterChanges::default()
+                },
+                1_000,
+            )
+            .unwrap();
+
+        assert_eq!(
+            expected_config(&ncn).drifts(&config, 0).unwrap(),
+            vec![ConfigDrift {
+                setting: "cranker_fee_bps (pending from slot 1100)".to_string(),
+                expected: "0".to_string(),
+                actual: "50".to_string(),
+            }]
+        );
+
+        // A queued change to the expected value does not hide the current drift
+        let mut expected = expected_config(&ncn);
+        expected.cranker_fee_bps = Some(50);
+
+        assert_eq!(
+            expected.drifts(&config, 0).unwrap(),
+            vec![ConfigDrift {
+                setting: "cranker_fee_bps".to_string(),
+                expected: "50".to_string(),
+                actual: "0".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::{fmt, path::PathBuf, sync::Mutex};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::info;
+use ncn_program_core::ballot_box::WeatherStatus;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+use crate::state_file;
+
+/// Every vote cast from this host with `--vote-journal`, persisted to a JSON file before the vote
+/// is sent so a restarted or misconfigured operator never votes a different ballot in an epoch it
+/// already voted in
+///
+/// A vote is recorded before it is sent, a vote that then failed may still have landed and stays
+/// recorded: the same ballot can be voted again, another one is refused. Deleting its entry from
+/// the file lifts the protection for that epoch.
+#[derive(Debug)]
+pub struct VoteJournal {
+    path: PathBuf,
+    votes: Mutex<Vec<JournaledVote>>,
+}
+
+/// A vote recorded in the vote journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournaledVote {
+    pub ncn: String,
+    pub operator: String,
+    pub epoch: u64,
+    pub weather_status: u8,
+    /// `None` until the vote lands
+    pub signature: Option<String>,
+    pub recorded_at: String,
+}
+
+/// A vote refused by the vote journal, another ballot was already voted by the operator in the
+/// epoch
+#[derive(Debug)]
+pub struct ConflictingBallot {
+    pub operator: String,
+    pub epoch: u64,
+    pub weather_status: u8,
+    /// The ballot recorded in the journal
+    pub voted_weather_status: u8,
+    pub path: PathBuf,
+}
+
+impl fmt::Display for ConflictingBallot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Refusing to vote {:?} for operator {} in epoch {}, {:?} was already voted according to the vote journal {}",
+            WeatherStatus::from_u8(self.weather_status),
+            self.operator,
+            self.epoch,
+            WeatherStatus::from_u8(self.voted_weather_status),
+            self.path.display()
+        )
+    }
+}
+
+impl std::error::Error for ConflictingBallot {}
+
+impl VoteJournal {
+    /// Reads the votes recorded in `path`, starting a new journal when the file does not exist yet
+    pub fn open(path: &str) -> Result<Self> {
+        let path = PathBuf::from(path);
+
+        let votes: Vec<JournaledVote> =
+            state_file::load(&path, "vote journal")?.unwrap_or_default();
+        if !votes.is_empty() {
+            info!(
+                "Loaded {} votes from the vote journal {}",
+                votes.len(),
+                path.display()
+            );
+        }
+
+        Ok(Self {
+            path,
+            votes: Mutex::new(votes),
+        })
+    }
+
+    /// Records the vote before it is sent, refusing it with a [`ConflictingBallot`] error when
+    /// another ballot was already voted by the operator in the epoch
+    pub fn record_vote(
+        &self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+        weather_status: u8,
+    ) -> Result<()> {
+        let mut votes = self
+            .votes
+            .lock()
+            .map_err(|_| anyhow!("The vote journal lock is poisoned"))?;
+
+        let (ncn, operator) = (ncn.to_string(), operator.to_string());
+        if let Some(vote) = votes
+            .iter()
+            .find(|vote| vote.ncn == ncn && vote.operator == operator && vote.epoch == epoch)
+        {
+            if vote.weather_status != weather_status {
+                return Err(ConflictingBallot {
+                    operator,
+                    epoch,
+                    weather_status,
+                    voted_weather_status: vote.weather_status,
+                    path: self.path.clone(),
+                }
+                .into());
+            }
+
+            return Ok(());
+        }
+
+        votes.push(JournaledVote {
+            ncn,
+            operator,
+            epoch,
+            weather_status,
+            signature: None,
+            recorded_at: Utc::now().to_rfc3339(),
+        });
+
+        // The vote is not sent unless it is recorded
+        self.save(&votes)
+    }
+
+    /// Records the signature of the vote once it landed
+    pub fn record_signature(
+        &self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+        signature: &Signature,
+    ) -> Result<()> {
+        let mut votes = self
+            .votes
+            .lock()
+            .map_err(|_| anyhow!("The vote journal lock is poisoned"))?;
+
+        let (ncn, operator) = (ncn.to_string(), operator.to_string());
+        if let Some(vote) = votes
+            .iter_mut()
+            .find(|vote| vote.ncn == ncn && vote.operator == operator && vote.epoch == epoch)
+        {
+            vote.signature = Some(signature.to_string());
+        }
+
+        self.save(&votes)
+    }
+
+    /// Writes the journal to disk, see `state_file::save`. The vote is synced to disk before it
+    /// is sent, not only to the page cache.
+    fn save(&self, votes: &[JournaledVote]) -> Result<()> {
+        state_file::save(&self.path, votes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+
+    /// A journal path of its own for each test, without a journal yet
+    fn journal_path(name: &str) -> String {
+        let path =
+            std::env::temp_dir().join(format!("vote-journal-{}-{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_same_ballot_is_accepted_again() {
+        let path = journal_path("same-ballot");
+        let journal = VoteJournal::open(&path).unwrap();
+        let (ncn, operator) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        journal.record_vote(&ncn, &operator, 10, 1).unwrap();
+        journal.record_vote(&ncn, &operator, 10, 1).unwrap();
+
+        assert_eq!(journal.votes.lock().unwrap().len(), 1);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_conflicting_ballot_is_refused() {
+        let path = journal_path("conflicting-ballot");
+        let journal = VoteJournal::open(&path).unwrap();
+        let (ncn, operator) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        journal.record_vote(&ncn, &operator, 10, 1).unwrap();
+        let error = journal.record_vote(&ncn, &operator, 10, 2).unwrap_err();
+        let conflict = error.downcast_ref::<ConflictingBallot>().unwrap();
+        assert_eq!(conflict.voted_weather_status, 1);
+
+        // Other epochs, operators and NCNs are not affected
+        journal.record_vote(&ncn, &operator, 11, 2).unwrap();
+        journal
+            .record_vote(&ncn, &Pubkey::new_unique(), 10, 2)
+            .unwrap();
+        journal
+            .record_vote(&Pubkey::new_unique(), &operator, 10, 2)
+            .unwrap();
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_journal_survives_reload() {
+        let path = journal_path("reload");
+        let (ncn, operator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let signature = Signature::new_unique();
+
+        {
+            let journal = VoteJournal::open(&path).unwrap();
+            journal.record_vote(&ncn, &operator, 10, 1).unwrap();
+            journal
+                .record_signature(&ncn, &operator, 10, &signature)
+                .unwrap();
+        }
+
+        let journal = VoteJournal::open(&path).unwrap();
+        {
+            let votes = journal.votes.lock().unwrap();
+            assert_eq!(votes.len(), 1);
+            assert_eq!(votes[0].signature, Some(signature.to_string()));
+        }
+
+        assert!(journal.record_vote(&ncn, &operator, 10, 2).is_err());
+        journal.record_vote(&ncn, &operator, 10, 1).unwrap();
+
+        // Nothing is left behind next to the journal
+        assert!(!Path::new(&path).with_extension("tmp").exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_journal_is_an_error() {
+        let path = journal_path("corrupt");
+        fs::write(&path, "not a vote journal").unwrap();
+
+        assert!(VoteJournal::open(&path).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
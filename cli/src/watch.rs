@@ -0,0 +1,248 @@
+use std::{fmt::Write as _, io::Write as _, time::Duration};
+
+use anyhow::Result;
+use chrono::Local;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    epoch_state::{EpochState, Progress, State},
+};
+use solana_sdk::native_token::lamports_to_sol;
+use tokio::time::sleep;
+
+use crate::{
+    getters::{
+        get_ballot_box, get_current_epoch_and_slot, get_epoch_snapshot, get_epoch_state,
+        get_is_epoch_completed, get_ncn_program_config, get_ncn_reward_router, get_weight_table,
+    },
+    handler::CliHandler,
+};
+
+/// Width of the progress bars, in characters
+const BAR_WIDTH: usize = 30;
+
+/// Renders a dashboard of the epoch in the terminal, refreshed from the RPC every `refresh_ms`
+/// until the epoch accounts are closed
+///
+/// A refresh failing shows its error in place of the dashboard and is retried on the next one.
+pub async fn watch_epoch(handler: &CliHandler, epoch: u64, refresh_ms: u64) -> Result<()> {
+    loop {
+        let (dashboard, completed) = match render_dashboard(handler, epoch).await {
+            Ok(dashboard) => dashboard,
+            Err(e) => (format!("Could not refresh epoch {}: {}\n", epoch, e), false),
+        };
+
+        // Clears the screen and moves the cursor home before drawing
+        print!("\x1B[2J\x1B[H{}", dashboard);
+        if completed {
+            println!();
+            return Ok(());
+        }
+        println!(
+            "\nRefreshed at {}, every {}ms - Ctrl+C to exit",
+            Local::now().format("%H:%M:%S"),
+            refresh_ms
+        );
+        std::io::stdout().flush()?;
+
+        sleep(Duration::from_millis(refresh_ms.max(1))).await;
+    }
+}
+
+/// The dashboard of the epoch, and whether the epoch accounts are closed
+async fn render_dashboard(handler: &CliHandler, epoch: u64) -> Result<(String, bool)> {
+    let (current_epoch, current_slot) = get_current_epoch_and_slot(handler).await?;
+
+    let mut dashboard = String::new();
+    writeln!(
+        dashboard,
+        " NCN Epoch {} Dashboard - current epoch {}, slot {}",
+        epoch, current_epoch, current_slot
+    )?;
+    writeln!(dashboard, "═══════════════════════════════════════")?;
+
+    if get_is_epoch_completed(handler, epoch).await? {
+        writeln!(
+            dashboard,
+            "\nEpoch {} is complete, its accounts are closed",
+            epoch
+        )?;
+        return Ok((dashboard, true));
+    }
+
+    let epoch_state = match get_epoch_state(handler, epoch).await {
+        Ok(epoch_state) => epoch_state,
+        Err(_) => {
+            writeln!(dashboard, "\nEpoch {} was not started yet", epoch)?;
+            return Ok((dashboard, false));
+        }
+    };
+
+    let state = current_state(handler, &epoch_state, current_slot).await?;
+    writeln!(dashboard, "\n State: {:?}", state)?;
+
+    writeln!(dashboard, "\n Snapshot")?;
+    writeln!(
+        dashboard,
+        "  {}",
+        progress_line("Weights Set", epoch_state.set_weight_progress())
+    )?;
+    writeln!(
+        dashboard,
+        "  {}",
+        progress_line(
+            "Operators Snapshotted",
+            epoch_state.epoch_snapshot_progress()
+        )
+    )?;
+
+    writeln!(dashboard, "\n Vote")?;
+    writeln!(
+        dashboard,
+        "  {}",
+        progress_line("Operators Voted", epoch_state.voting_progress())
+    )?;
+    match get_ballot_box(handler, epoch).await {
+        Ok(ballot_box) => write_consensus(handler, epoch, &ballot_box, &mut dashboard).await?,
+        Err(_) => writeln!(dashboard, "  No ballot box yet")?,
+    }
+
+    writeln!(dashboard, "\n Rewards")?;
+    match get_ncn_reward_router(handler, epoch).await {
+        Ok(ncn_reward_router) => {
+            writeln!(
+                dashboard,
+                "  Routing:      {} - {} SOL routed, {} SOL in the pool",
+                if ncn_reward_router.still_routing() {
+                    "In Progress"
+                } else {
+                    "Done"
+                },
+                lamports_to_sol(ncn_reward_router.total_rewards()),
+                lamports_to_sol(ncn_reward_router.reward_pool())
+            )?;
+        }
+        Err(_) => writeln!(dashboard, "  No NCN reward router yet")?,
+    }
+    writeln!(
+        dashboard,
+        "  {}",
+        progress_line(
+            "Distributed (lamports)",
+            epoch_state.total_distribution_progress()
+        )
+    )?;
+
+    Ok((dashboard, false))
+}
+
+/// The stake of the leading ballot against the two thirds needed, and the tally of every ballot
+async fn write_consensus(
+    handler: &CliHandler,
+    epoch: u64,
+    ballot_box: &BallotBox,
+    dashboard: &mut String,
+) -> Result<()> {
+    let total_stake_weight = get_epoch_snapshot(handler, epoch)
+        .await
+        .map(|epoch_snapshot| epoch_snapshot.stake_weights().stake_weight())
+        .unwrap_or_default();
+    let share = |stake_weight: u128| {
+        if total_stake_weight == 0 {
+            0.0
+        } else {
+            stake_weight as f64 / total_stake_weight as f64
+        }
+    };
+
+    let leading_stake_weight = ballot_box
+        .ballot_tallies()
+        .iter()
+        .filter(|tally| tally.is_valid())
+        .map(|tally| tally.stake_weights().stake_weight())
+        .max()
+        .unwrap_or_default();
+
+    writeln!(
+        dashboard,
+        "  {:<24}{} {:.2}% of the stake, 66.67% needed",
+        "Stake To Consensus",
+        bar(share(leading_stake_weight).min(1.0)),
+        share(leading_stake_weight) * 100.0
+    )?;
+
+    if ballot_box.is_consensus_reached() {
+        writeln!(
+            dashboard,
+            "  Consensus reached at slot {} on ballot {}",
+            ballot_box.slot_consensus_reached(),
+            ballot_box.get_winning_ballot()?
+        )?;
+    }
+
+    for tally in ballot_box
+        .ballot_tallies()
+        .iter()
+        .filter(|tally| tally.is_valid())
+    {
+        writeln!(
+            dashboard,
+            "    Ballot {:<24} {:>4} votes {:>7.2}% of the stake",
+            tally.ballot().to_string(),
+            tally.tally(),
+            share(tally.stake_weights().stake_weight()) * 100.0
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The state of the epoch the keeper would crank
+async fn current_state(
+    handler: &CliHandler,
+    epoch_state: &EpochState,
+    current_slot: u64,
+) -> Result<State> {
+    let config = get_ncn_program_config(handler).await?;
+    let epoch_schedule = handler.rpc_client().get_epoch_schedule().await?;
+
+    let state = if epoch_state.set_weight_progress().tally() > 0 {
+        let weight_table = get_weight_table(handler, epoch_state.epoch()).await?;
+        epoch_state.current_state_patched(
+            &epoch_schedule,
+            config.valid_slots_after_consensus(),
+            config.epochs_after_consensus_before_close(),
+            weight_table.st_mint_count() as u64,
+            current_slot,
+        )
+    } else {
+        epoch_state.current_state(
+            &epoch_schedule,
+            config.valid_slots_after_consensus(),
+            config.epochs_after_consensus_before_close(),
+            current_slot,
+        )
+    }?;
+
+    Ok(state)
+}
+
+fn progress_line(label: &str, progress: Progress) -> String {
+    let ratio = if progress.total() == 0 {
+        0.0
+    } else {
+        (progress.tally() as f64 / progress.total() as f64).min(1.0)
+    };
+
+    format!(
+        "{:<24}{} {}/{}",
+        label,
+        bar(ratio),
+        progress.tally(),
+        progress.total()
+    )
+}
+
+fn bar(ratio: f64) -> String {
+    let filled = ((ratio * BAR_WIDTH as f64) as usize).min(BAR_WIDTH);
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
@@ -15,8 +15,14 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -35,8 +41,12 @@ import {
 import {
   getFeeConfigDecoder,
   getFeeConfigEncoder,
+  getPendingParametersDecoder,
+  getPendingParametersEncoder,
   type FeeConfig,
   type FeeConfigArgs,
+  type PendingParameters,
+  type PendingParametersArgs,
 } from '../types';
 
 export type Config = {
@@ -49,6 +59,20 @@ export type Config = {
   startingValidEpoch: bigint;
   feeConfig: FeeConfig;
   bump: number;
+  rewardMints: Array<Address>;
+  crankerFeeBps: number;
+  timelinessBonusSlots: bigint;
+  timelinessBonusBps: number;
+  weightDecayEpochs: bigint;
+  weightDecayBps: number;
+  maxVaultStakeWeightBps: number;
+  permissionlessVaultRegistration: number;
+  adminApprovers: Array<Address>;
+  adminThreshold: number;
+  parametersTimelockSlots: bigint;
+  pendingParameters: PendingParameters;
+  adminGovernanceProgram: Address;
+  adminGovernanceRealm: Address;
 };
 
 export type ConfigArgs = {
@@ -61,6 +85,20 @@ export type ConfigArgs = {
   startingValidEpoch: number | bigint;
   feeConfig: FeeConfigArgs;
   bump: number;
+  rewardMints: Array<Address>;
+  crankerFeeBps: number;
+  timelinessBonusSlots: number | bigint;
+  timelinessBonusBps: number;
+  weightDecayEpochs: number | bigint;
+  weightDecayBps: number;
+  maxVaultStakeWeightBps: number;
+  permissionlessVaultRegistration: number;
+  adminApprovers: Array<Address>;
+  adminThreshold: number;
+  parametersTimelockSlots: number | bigint;
+  pendingParameters: PendingParametersArgs;
+  adminGovernanceProgram: Address;
+  adminGovernanceRealm: Address;
 };
 
 export function getConfigEncoder(): Encoder<ConfigArgs> {
@@ -74,6 +112,20 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
     ['startingValidEpoch', getU64Encoder()],
     ['feeConfig', getFeeConfigEncoder()],
     ['bump', getU8Encoder()],
+    ['rewardMints', getArrayEncoder(getAddressEncoder(), { size: 4 })],
+    ['crankerFeeBps', getU16Encoder()],
+    ['timelinessBonusSlots', getU64Encoder()],
+    ['timelinessBonusBps', getU16Encoder()],
+    ['weightDecayEpochs', getU64Encoder()],
+    ['weightDecayBps', getU16Encoder()],
+    ['maxVaultStakeWeightBps', getU16Encoder()],
+    ['permissionlessVaultRegistration', getBoolEncoder()],
+    ['adminApprovers', getArrayEncoder(getAddressEncoder(), { size: 8 })],
+    ['adminThreshold', getU8Encoder()],
+    ['parametersTimelockSlots', getU64Encoder()],
+    ['pendingParameters', getPendingParametersEncoder()],
+    ['adminGovernanceProgram', getAddressEncoder()],
+    ['adminGovernanceRealm', getAddressEncoder()],
   ]);
 }
 
@@ -88,6 +140,20 @@ export function getConfigDecoder(): Decoder<Config> {
     ['startingValidEpoch', getU64Decoder()],
     ['feeConfig', getFeeConfigDecoder()],
     ['bump', getU8Decoder()],
+    ['rewardMints', getArrayDecoder(getAddressDecoder(), { size: 4 })],
+    ['crankerFeeBps', getU16Decoder()],
+    ['timelinessBonusSlots', getU64Decoder()],
+    ['timelinessBonusBps', getU16Decoder()],
+    ['weightDecayEpochs', getU64Decoder()],
+    ['weightDecayBps', getU16Decoder()],
+    ['maxVaultStakeWeightBps', getU16Decoder()],
+    ['permissionlessVaultRegistration', getBoolDecoder()],
+    ['adminApprovers', getArrayDecoder(getAddressDecoder(), { size: 8 })],
+    ['adminThreshold', getU8Decoder()],
+    ['parametersTimelockSlots', getU64Decoder()],
+    ['pendingParameters', getPendingParametersDecoder()],
+    ['adminGovernanceProgram', getAddressDecoder()],
+    ['adminGovernanceRealm', getAddressDecoder()],
   ]);
 }
 
@@ -15,6 +15,8 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -36,6 +38,7 @@ export type EpochMarker = {
   ncn: Address;
   epoch: bigint;
   slotClosed: bigint;
+  skipped: number;
 };
 
 export type EpochMarkerArgs = {
@@ -43,6 +46,7 @@ export type EpochMarkerArgs = {
   ncn: Address;
   epoch: number | bigint;
   slotClosed: number | bigint;
+  skipped: number;
 };
 
 export function getEpochMarkerEncoder(): Encoder<EpochMarkerArgs> {
@@ -51,6 +55,7 @@ export function getEpochMarkerEncoder(): Encoder<EpochMarkerArgs> {
     ['ncn', getAddressEncoder()],
     ['epoch', getU64Encoder()],
     ['slotClosed', getU64Encoder()],
+    ['skipped', getBoolEncoder()],
   ]);
 }
 
@@ -60,6 +65,7 @@ export function getEpochMarkerDecoder(): Decoder<EpochMarker> {
     ['ncn', getAddressDecoder()],
     ['epoch', getU64Decoder()],
     ['slotClosed', getU64Decoder()],
+    ['skipped', getBoolDecoder()],
   ]);
 }
 
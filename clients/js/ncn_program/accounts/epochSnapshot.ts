@@ -56,6 +56,7 @@ export type EpochSnapshot = {
   validOperatorVaultDelegations: bigint;
   stakeWeights: StakeWeights;
   fees: Fees;
+  maxVaultStakeWeight: StakeWeights;
 };
 
 export type EpochSnapshotArgs = {
@@ -71,6 +72,7 @@ export type EpochSnapshotArgs = {
   validOperatorVaultDelegations: number | bigint;
   stakeWeights: StakeWeightsArgs;
   fees: FeesArgs;
+  maxVaultStakeWeight: StakeWeightsArgs;
 };
 
 export function getEpochSnapshotEncoder(): Encoder<EpochSnapshotArgs> {
@@ -87,6 +89,7 @@ export function getEpochSnapshotEncoder(): Encoder<EpochSnapshotArgs> {
     ['validOperatorVaultDelegations', getU64Encoder()],
     ['stakeWeights', getStakeWeightsEncoder()],
     ['fees', getFeesEncoder()],
+    ['maxVaultStakeWeight', getStakeWeightsEncoder()],
   ]);
 }
 
@@ -104,6 +107,7 @@ export function getEpochSnapshotDecoder(): Decoder<EpochSnapshot> {
     ['validOperatorVaultDelegations', getU64Decoder()],
     ['stakeWeights', getStakeWeightsDecoder()],
     ['fees', getFeesDecoder()],
+    ['maxVaultStakeWeight', getStakeWeightsDecoder()],
   ]);
 }
 
@@ -12,7 +12,9 @@ export * from './consensusResult';
 export * from './epochMarker';
 export * from './epochSnapshot';
 export * from './epochState';
+export * from './keeperLease';
 export * from './nCNRewardRouter';
+export * from './operatorMetadata';
 export * from './operatorSnapshot';
 export * from './operatorVaultRewardRouter';
 export * from './vaultRegistry';
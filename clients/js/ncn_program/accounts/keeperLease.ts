@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type KeeperLease = {
+  discriminator: bigint;
+  ncn: Address;
+  authority: Address;
+  bump: number;
+  holder: Address;
+  slotAcquired: bigint;
+  slotExpires: bigint;
+  reserved: Array<number>;
+};
+
+export type KeeperLeaseArgs = {
+  discriminator: number | bigint;
+  ncn: Address;
+  authority: Address;
+  bump: number;
+  holder: Address;
+  slotAcquired: number | bigint;
+  slotExpires: number | bigint;
+  reserved: Array<number>;
+};
+
+export function getKeeperLeaseEncoder(): Encoder<KeeperLeaseArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['ncn', getAddressEncoder()],
+    ['authority', getAddressEncoder()],
+    ['bump', getU8Encoder()],
+    ['holder', getAddressEncoder()],
+    ['slotAcquired', getU64Encoder()],
+    ['slotExpires', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
+  ]);
+}
+
+export function getKeeperLeaseDecoder(): Decoder<KeeperLease> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['ncn', getAddressDecoder()],
+    ['authority', getAddressDecoder()],
+    ['bump', getU8Decoder()],
+    ['holder', getAddressDecoder()],
+    ['slotAcquired', getU64Decoder()],
+    ['slotExpires', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
+  ]);
+}
+
+export function getKeeperLeaseCodec(): Codec<KeeperLeaseArgs, KeeperLease> {
+  return combineCodec(getKeeperLeaseEncoder(), getKeeperLeaseDecoder());
+}
+
+export function decodeKeeperLease<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<KeeperLease, TAddress>;
+export function decodeKeeperLease<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<KeeperLease, TAddress>;
+export function decodeKeeperLease<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<KeeperLease, TAddress> | MaybeAccount<KeeperLease, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getKeeperLeaseDecoder()
+  );
+}
+
+export async function fetchKeeperLease<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<KeeperLease, TAddress>> {
+  const maybeAccount = await fetchMaybeKeeperLease(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeKeeperLease<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<KeeperLease, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeKeeperLease(maybeAccount);
+}
+
+export async function fetchAllKeeperLease(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<KeeperLease>[]> {
+  const maybeAccounts = await fetchAllMaybeKeeperLease(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeKeeperLease(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<KeeperLease>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeKeeperLease(maybeAccount));
+}
@@ -19,8 +19,6 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU16Decoder,
-  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -37,8 +35,12 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
+  getNCNMintRewardsDecoder,
+  getNCNMintRewardsEncoder,
   getOperatorVaultRewardRouteDecoder,
   getOperatorVaultRewardRouteEncoder,
+  type NCNMintRewards,
+  type NCNMintRewardsArgs,
   type OperatorVaultRewardRoute,
   type OperatorVaultRewardRouteArgs,
 } from '../types';
@@ -49,15 +51,8 @@ export type NCNRewardRouter = {
   epoch: bigint;
   bump: number;
   slotCreated: bigint;
-  totalRewards: bigint;
-  rewardPool: bigint;
-  rewardsProcessed: bigint;
   reserved: Array<number>;
-  lastVoteIndex: number;
-  lastRewardsToProcess: bigint;
-  protocolRewards: bigint;
-  ncnRewards: bigint;
-  operatorVaultRewards: bigint;
+  rewards: Array<NCNMintRewards>;
   operatorVaultRewardRoutes: Array<OperatorVaultRewardRoute>;
 };
 
@@ -67,15 +62,8 @@ export type NCNRewardRouterArgs = {
   epoch: number | bigint;
   bump: number;
   slotCreated: number | bigint;
-  totalRewards: number | bigint;
-  rewardPool: number | bigint;
-  rewardsProcessed: number | bigint;
   reserved: Array<number>;
-  lastVoteIndex: number;
-  lastRewardsToProcess: number | bigint;
-  protocolRewards: number | bigint;
-  ncnRewards: number | bigint;
-  operatorVaultRewards: number | bigint;
+  rewards: Array<NCNMintRewardsArgs>;
   operatorVaultRewardRoutes: Array<OperatorVaultRewardRouteArgs>;
 };
 
@@ -86,15 +74,8 @@ export function getNCNRewardRouterEncoder(): Encoder<NCNRewardRouterArgs> {
     ['epoch', getU64Encoder()],
     ['bump', getU8Encoder()],
     ['slotCreated', getU64Encoder()],
-    ['totalRewards', getU64Encoder()],
-    ['rewardPool', getU64Encoder()],
-    ['rewardsProcessed', getU64Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
-    ['lastVoteIndex', getU16Encoder()],
-    ['lastRewardsToProcess', getU64Encoder()],
-    ['protocolRewards', getU64Encoder()],
-    ['ncnRewards', getU64Encoder()],
-    ['operatorVaultRewards', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 96 })],
+    ['rewards', getArrayEncoder(getNCNMintRewardsEncoder(), { size: 5 })],
     [
       'operatorVaultRewardRoutes',
       getArrayEncoder(getOperatorVaultRewardRouteEncoder(), { size: 256 }),
@@ -109,15 +90,8 @@ export function getNCNRewardRouterDecoder(): Decoder<NCNRewardRouter> {
     ['epoch', getU64Decoder()],
     ['bump', getU8Decoder()],
     ['slotCreated', getU64Decoder()],
-    ['totalRewards', getU64Decoder()],
-    ['rewardPool', getU64Decoder()],
-    ['rewardsProcessed', getU64Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
-    ['lastVoteIndex', getU16Decoder()],
-    ['lastRewardsToProcess', getU64Decoder()],
-    ['protocolRewards', getU64Decoder()],
-    ['ncnRewards', getU64Decoder()],
-    ['operatorVaultRewards', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 96 })],
+    ['rewards', getArrayDecoder(getNCNMintRewardsDecoder(), { size: 5 })],
     [
       'operatorVaultRewardRoutes',
       getArrayDecoder(getOperatorVaultRewardRouteDecoder(), { size: 256 }),
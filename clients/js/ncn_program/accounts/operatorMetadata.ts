@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type OperatorMetadata = {
+  discriminator: bigint;
+  operator: Address;
+  ncn: Address;
+  bump: number;
+  name: ReadonlyUint8Array;
+  website: Array<number>;
+  contactHash: ReadonlyUint8Array;
+  slotUpdated: bigint;
+  reserved: Array<number>;
+};
+
+export type OperatorMetadataArgs = {
+  discriminator: number | bigint;
+  operator: Address;
+  ncn: Address;
+  bump: number;
+  name: ReadonlyUint8Array;
+  website: Array<number>;
+  contactHash: ReadonlyUint8Array;
+  slotUpdated: number | bigint;
+  reserved: Array<number>;
+};
+
+export function getOperatorMetadataEncoder(): Encoder<OperatorMetadataArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['operator', getAddressEncoder()],
+    ['ncn', getAddressEncoder()],
+    ['bump', getU8Encoder()],
+    ['name', fixEncoderSize(getBytesEncoder(), 32)],
+    ['website', getArrayEncoder(getU8Encoder(), { size: 64 })],
+    ['contactHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ['slotUpdated', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
+  ]);
+}
+
+export function getOperatorMetadataDecoder(): Decoder<OperatorMetadata> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['operator', getAddressDecoder()],
+    ['ncn', getAddressDecoder()],
+    ['bump', getU8Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['website', getArrayDecoder(getU8Decoder(), { size: 64 })],
+    ['contactHash', fixDecoderSize(getBytesDecoder(), 32)],
+    ['slotUpdated', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
+  ]);
+}
+
+export function getOperatorMetadataCodec(): Codec<
+  OperatorMetadataArgs,
+  OperatorMetadata
+> {
+  return combineCodec(
+    getOperatorMetadataEncoder(),
+    getOperatorMetadataDecoder()
+  );
+}
+
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<OperatorMetadata, TAddress>;
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<OperatorMetadata, TAddress>;
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<OperatorMetadata, TAddress>
+  | MaybeAccount<OperatorMetadata, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getOperatorMetadataDecoder()
+  );
+}
+
+export async function fetchOperatorMetadata<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<OperatorMetadata, TAddress>> {
+  const maybeAccount = await fetchMaybeOperatorMetadata(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorMetadata<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<OperatorMetadata, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeOperatorMetadata(maybeAccount);
+}
+
+export async function fetchAllOperatorMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<OperatorMetadata>[]> {
+  const maybeAccounts = await fetchAllMaybeOperatorMetadata(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeOperatorMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<OperatorMetadata>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeOperatorMetadata(maybeAccount)
+  );
+}
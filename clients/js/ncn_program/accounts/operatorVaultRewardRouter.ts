@@ -37,8 +37,12 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
+  getOperatorVaultMintRewardsDecoder,
+  getOperatorVaultMintRewardsEncoder,
   getVaultRewardRouteDecoder,
   getVaultRewardRouteEncoder,
+  type OperatorVaultMintRewards,
+  type OperatorVaultMintRewardsArgs,
   type VaultRewardRoute,
   type VaultRewardRouteArgs,
 } from '../types';
@@ -58,6 +62,7 @@ export type OperatorVaultRewardRouter = {
   lastRewardsToProcess: bigint;
   lastVaultOperatorDelegationIndex: number;
   vaultRewardRoutes: Array<VaultRewardRoute>;
+  mintRewards: Array<OperatorVaultMintRewards>;
 };
 
 export type OperatorVaultRewardRouterArgs = {
@@ -75,6 +80,7 @@ export type OperatorVaultRewardRouterArgs = {
   lastRewardsToProcess: number | bigint;
   lastVaultOperatorDelegationIndex: number;
   vaultRewardRoutes: Array<VaultRewardRouteArgs>;
+  mintRewards: Array<OperatorVaultMintRewardsArgs>;
 };
 
 export function getOperatorVaultRewardRouterEncoder(): Encoder<OperatorVaultRewardRouterArgs> {
@@ -96,6 +102,10 @@ export function getOperatorVaultRewardRouterEncoder(): Encoder<OperatorVaultRewa
       'vaultRewardRoutes',
       getArrayEncoder(getVaultRewardRouteEncoder(), { size: 64 }),
     ],
+    [
+      'mintRewards',
+      getArrayEncoder(getOperatorVaultMintRewardsEncoder(), { size: 4 }),
+    ],
   ]);
 }
 
@@ -118,6 +128,10 @@ export function getOperatorVaultRewardRouterDecoder(): Decoder<OperatorVaultRewa
       'vaultRewardRoutes',
       getArrayDecoder(getVaultRewardRouteDecoder(), { size: 64 }),
     ],
+    [
+      'mintRewards',
+      getArrayDecoder(getOperatorVaultMintRewardsDecoder(), { size: 4 }),
+    ],
   ]);
 }
 
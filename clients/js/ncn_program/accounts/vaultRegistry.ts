@@ -19,6 +19,8 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -51,6 +53,7 @@ export type VaultRegistry = {
   bump: number;
   stMintList: Array<StMintEntry>;
   vaultList: Array<VaultEntry>;
+  defaultWeight: bigint;
 };
 
 export type VaultRegistryArgs = {
@@ -59,6 +62,7 @@ export type VaultRegistryArgs = {
   bump: number;
   stMintList: Array<StMintEntryArgs>;
   vaultList: Array<VaultEntryArgs>;
+  defaultWeight: number | bigint;
 };
 
 export function getVaultRegistryEncoder(): Encoder<VaultRegistryArgs> {
@@ -66,8 +70,9 @@ export function getVaultRegistryEncoder(): Encoder<VaultRegistryArgs> {
     ['discriminator', getU64Encoder()],
     ['ncn', getAddressEncoder()],
     ['bump', getU8Encoder()],
-    ['stMintList', getArrayEncoder(getStMintEntryEncoder(), { size: 64 })],
+    ['stMintList', getArrayEncoder(getStMintEntryEncoder(), { size: 128 })],
     ['vaultList', getArrayEncoder(getVaultEntryEncoder(), { size: 64 })],
+    ['defaultWeight', getU128Encoder()],
   ]);
 }
 
@@ -76,8 +81,9 @@ export function getVaultRegistryDecoder(): Decoder<VaultRegistry> {
     ['discriminator', getU64Decoder()],
     ['ncn', getAddressDecoder()],
     ['bump', getU8Decoder()],
-    ['stMintList', getArrayDecoder(getStMintEntryDecoder(), { size: 64 })],
+    ['stMintList', getArrayDecoder(getStMintEntryDecoder(), { size: 128 })],
     ['vaultList', getArrayDecoder(getVaultEntryDecoder(), { size: 64 })],
+    ['defaultWeight', getU128Decoder()],
   ]);
 }
 
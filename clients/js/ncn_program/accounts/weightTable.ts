@@ -76,7 +76,7 @@ export function getWeightTableEncoder(): Encoder<WeightTableArgs> {
     ['vaultCount', getU64Encoder()],
     ['bump', getU8Encoder()],
     ['vaultRegistry', getArrayEncoder(getVaultEntryEncoder(), { size: 64 })],
-    ['table', getArrayEncoder(getWeightEntryEncoder(), { size: 64 })],
+    ['table', getArrayEncoder(getWeightEntryEncoder(), { size: 128 })],
   ]);
 }
 
@@ -89,7 +89,7 @@ export function getWeightTableDecoder(): Decoder<WeightTable> {
     ['vaultCount', getU64Decoder()],
     ['bump', getU8Decoder()],
     ['vaultRegistry', getArrayDecoder(getVaultEntryDecoder(), { size: 64 })],
-    ['table', getArrayDecoder(getWeightEntryDecoder(), { size: 64 })],
+    ['table', getArrayDecoder(getWeightEntryDecoder(), { size: 128 })],
   ]);
 }
 
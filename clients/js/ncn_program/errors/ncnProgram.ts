@@ -196,14 +196,96 @@ export const NCN_PROGRAM_ERROR__INVALID_N_C_N_FEE_WALLET = 0x224e; // 8782
 export const NCN_PROGRAM_ERROR__EPOCH_IS_CLOSING_DOWN = 0x224f; // 8783
 /** MarkerExists: Marker exists */
 export const NCN_PROGRAM_ERROR__MARKER_EXISTS = 0x2250; // 8784
+/** InvalidVaultRewardRecipient: Vault reward recipient must be a system account or a wrapped SOL token account */
+export const NCN_PROGRAM_ERROR__INVALID_VAULT_REWARD_RECIPIENT = 0x2251; // 8785
+/** RewardMintNotRegistered: Reward mint is not registered in the config */
+export const NCN_PROGRAM_ERROR__REWARD_MINT_NOT_REGISTERED = 0x2252; // 8786
+/** InvalidRewardMint: Invalid reward mint */
+export const NCN_PROGRAM_ERROR__INVALID_REWARD_MINT = 0x2253; // 8787
+/** InvalidRewardMintIndex: Invalid reward mint index */
+export const NCN_PROGRAM_ERROR__INVALID_REWARD_MINT_INDEX = 0x2254; // 8788
+/** RewardMintListFull: Reward mint list is full */
+export const NCN_PROGRAM_ERROR__REWARD_MINT_LIST_FULL = 0x2255; // 8789
+/** RewardMintNotFound: Reward mint not found */
+export const NCN_PROGRAM_ERROR__REWARD_MINT_NOT_FOUND = 0x2256; // 8790
+/** InvalidRewardTokenAccount: Invalid reward token account */
+export const NCN_PROGRAM_ERROR__INVALID_REWARD_TOKEN_ACCOUNT = 0x2257; // 8791
+/** InvalidCrankerFeeBps: Cranker fee exceeds the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_CRANKER_FEE_BPS = 0x2258; // 8792
+/** InvalidTimelinessBonusBps: Timeliness bonus exceeds the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_TIMELINESS_BONUS_BPS = 0x2259; // 8793
+/** RouterRewardsInTransit: Router still has rewards to route or distribute */
+export const NCN_PROGRAM_ERROR__ROUTER_REWARDS_IN_TRANSIT = 0x225a; // 8794
+/** NoSwitchboardFeed: No switchboard feed is registered for the st mint */
+export const NCN_PROGRAM_ERROR__NO_SWITCHBOARD_FEED = 0x225b; // 8795
+/** IncorrectSwitchboardFeed: Switchboard feed does not match the registered feed */
+export const NCN_PROGRAM_ERROR__INCORRECT_SWITCHBOARD_FEED = 0x225c; // 8796
+/** BadSwitchboardFeed: Could not parse the switchboard feed */
+export const NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_FEED = 0x225d; // 8797
+/** BadSwitchboardValue: Switchboard feed value is invalid */
+export const NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_VALUE = 0x225e; // 8798
+/** StaleSwitchboardFeed: Switchboard feed is stale */
+export const NCN_PROGRAM_ERROR__STALE_SWITCHBOARD_FEED = 0x225f; // 8799
+/** SwitchboardConfidenceTooLow: Switchboard feed confidence interval is too wide */
+export const NCN_PROGRAM_ERROR__SWITCHBOARD_CONFIDENCE_TOO_LOW = 0x2260; // 8800
+/** VaultRegistryChanged: Vault registry changed since the previous epoch */
+export const NCN_PROGRAM_ERROR__VAULT_REGISTRY_CHANGED = 0x2261; // 8801
+/** InvalidWeightDecayBps: Weight decay exceeds the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_WEIGHT_DECAY_BPS = 0x2262; // 8802
+/** InvalidMaxVaultStakeWeightBps: Vault stake weight cap exceeds the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_MAX_VAULT_STAKE_WEIGHT_BPS = 0x2263; // 8803
+/** NcnVaultTicketNotActive: NCN vault ticket is not active */
+export const NCN_PROGRAM_ERROR__NCN_VAULT_TICKET_NOT_ACTIVE = 0x2264; // 8804
+/** InvalidVaultName: Vault name is not valid UTF-8 */
+export const NCN_PROGRAM_ERROR__INVALID_VAULT_NAME = 0x2265; // 8805
+/** InvalidOperatorMetadata: Operator metadata is not valid UTF-8 */
+export const NCN_PROGRAM_ERROR__INVALID_OPERATOR_METADATA = 0x2266; // 8806
+/** InvalidAdminMultisig: Invalid admin multisig */
+export const NCN_PROGRAM_ERROR__INVALID_ADMIN_MULTISIG = 0x2267; // 8807
+/** AdminThresholdNotMet: Not enough admin approvers signed */
+export const NCN_PROGRAM_ERROR__ADMIN_THRESHOLD_NOT_MET = 0x2268; // 8808
+/** InvalidParametersTimelock: Parameters timelock exceeds the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_PARAMETERS_TIMELOCK = 0x2269; // 8809
+/** NoPendingParameters: No parameter changes are pending */
+export const NCN_PROGRAM_ERROR__NO_PENDING_PARAMETERS = 0x226a; // 8810
+/** ParametersTimelockNotElapsed: Parameters timelock has not elapsed */
+export const NCN_PROGRAM_ERROR__PARAMETERS_TIMELOCK_NOT_ELAPSED = 0x226b; // 8811
+/** EpochNotSkippable: Epoch can only be skipped once it stalled without an epoch state */
+export const NCN_PROGRAM_ERROR__EPOCH_NOT_SKIPPABLE = 0x226c; // 8812
+/** InvalidFeeGroup: Invalid fee group */
+export const NCN_PROGRAM_ERROR__INVALID_FEE_GROUP = 0x226d; // 8813
+/** InvalidFeeGroupName: Fee group name is not valid UTF-8 */
+export const NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_NAME = 0x226e; // 8814
+/** DefaultFeeGroupWallet: Fee group wallet cannot be the default pubkey */
+export const NCN_PROGRAM_ERROR__DEFAULT_FEE_GROUP_WALLET = 0x226f; // 8815
+/** InvalidFeeGroupWallet: Fee group wallet does not match the config */
+export const NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_WALLET = 0x2270; // 8816
+/** InvalidAdminGovernance: Admin governance program and realm have to be set together */
+export const NCN_PROGRAM_ERROR__INVALID_ADMIN_GOVERNANCE = 0x2271; // 8817
+/** NotAdminGovernance: Admin instructions have to be signed by a governance of the admin realm */
+export const NCN_PROGRAM_ERROR__NOT_ADMIN_GOVERNANCE = 0x2272; // 8818
+/** BallotTallyMismatch: Ballot tallies do not match the operator votes */
+export const NCN_PROGRAM_ERROR__BALLOT_TALLY_MISMATCH = 0x2273; // 8819
+/** ConsensusResultMismatch: Consensus result does not match the ballot box */
+export const NCN_PROGRAM_ERROR__CONSENSUS_RESULT_MISMATCH = 0x2274; // 8820
+/** KeeperLeaseHeld: Keeper lease is held by another keeper */
+export const NCN_PROGRAM_ERROR__KEEPER_LEASE_HELD = 0x2275; // 8821
+/** InvalidKeeperLeaseSlots: Keeper lease slots exceed the maximum */
+export const NCN_PROGRAM_ERROR__INVALID_KEEPER_LEASE_SLOTS = 0x2276; // 8822
+/** RewardRouterNotClosed: Reward router is not closed for good */
+export const NCN_PROGRAM_ERROR__REWARD_ROUTER_NOT_CLOSED = 0x2277; // 8823
 
 export type NcnProgramError =
   | typeof NCN_PROGRAM_ERROR__ACCOUNT_ALREADY_INITIALIZED
+  | typeof NCN_PROGRAM_ERROR__ADMIN_THRESHOLD_NOT_MET
   | typeof NCN_PROGRAM_ERROR__ARITHMETIC_FLOOR_ERROR
   | typeof NCN_PROGRAM_ERROR__ARITHMETIC_OVERFLOW
   | typeof NCN_PROGRAM_ERROR__ARITHMETIC_UNDERFLOW_ERROR
   | typeof NCN_PROGRAM_ERROR__BAD_BALLOT
+  | typeof NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_FEED
+  | typeof NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_VALUE
   | typeof NCN_PROGRAM_ERROR__BALLOT_TALLY_FULL
+  | typeof NCN_PROGRAM_ERROR__BALLOT_TALLY_MISMATCH
   | typeof NCN_PROGRAM_ERROR__BALLOT_TALLY_NOT_EMPTY
   | typeof NCN_PROGRAM_ERROR__BALLOT_TALLY_NOT_FOUND_FULL
   | typeof NCN_PROGRAM_ERROR__CANNOT_CLOSE_ACCOUNT
@@ -221,6 +303,8 @@ export type NcnProgramError =
   | typeof NCN_PROGRAM_ERROR__CONFIG_MINTS_NOT_UPDATED
   | typeof NCN_PROGRAM_ERROR__CONSENSUS_ALREADY_REACHED
   | typeof NCN_PROGRAM_ERROR__CONSENSUS_NOT_REACHED
+  | typeof NCN_PROGRAM_ERROR__CONSENSUS_RESULT_MISMATCH
+  | typeof NCN_PROGRAM_ERROR__DEFAULT_FEE_GROUP_WALLET
   | typeof NCN_PROGRAM_ERROR__DEFAULT_NCN_WALLET
   | typeof NCN_PROGRAM_ERROR__DEFAULT_PROTOCOL_WALLET
   | typeof NCN_PROGRAM_ERROR__DENOMINATOR_IS_ZERO
@@ -229,33 +313,57 @@ export type NcnProgramError =
   | typeof NCN_PROGRAM_ERROR__DUPLICATE_VAULT_OPERATOR_DELEGATION
   | typeof NCN_PROGRAM_ERROR__DUPLICATE_VOTE_CAST
   | typeof NCN_PROGRAM_ERROR__EPOCH_IS_CLOSING_DOWN
+  | typeof NCN_PROGRAM_ERROR__EPOCH_NOT_SKIPPABLE
   | typeof NCN_PROGRAM_ERROR__EPOCH_SNAPSHOT_NOT_FINALIZED
   | typeof NCN_PROGRAM_ERROR__FEE_CAP_EXCEEDED
   | typeof NCN_PROGRAM_ERROR__FEE_NOT_ACTIVE
   | typeof NCN_PROGRAM_ERROR__INCORRECT_FEE_ADMIN
   | typeof NCN_PROGRAM_ERROR__INCORRECT_NCN
   | typeof NCN_PROGRAM_ERROR__INCORRECT_NCN_ADMIN
+  | typeof NCN_PROGRAM_ERROR__INCORRECT_SWITCHBOARD_FEED
   | typeof NCN_PROGRAM_ERROR__INCORRECT_WEIGHT_TABLE_ADMIN
   | typeof NCN_PROGRAM_ERROR__INVALID_ACCOUNT_STATUS
   | typeof NCN_PROGRAM_ERROR__INVALID_ACCOUNT_TO_CLOSE_DISCRIMINATOR
+  | typeof NCN_PROGRAM_ERROR__INVALID_ADMIN_GOVERNANCE
+  | typeof NCN_PROGRAM_ERROR__INVALID_ADMIN_MULTISIG
   | typeof NCN_PROGRAM_ERROR__INVALID_BASE_FEE_GROUP
+  | typeof NCN_PROGRAM_ERROR__INVALID_CRANKER_FEE_BPS
   | typeof NCN_PROGRAM_ERROR__INVALID_EPOCHS_BEFORE_CLOSE
   | typeof NCN_PROGRAM_ERROR__INVALID_EPOCHS_BEFORE_STALL
+  | typeof NCN_PROGRAM_ERROR__INVALID_FEE_GROUP
+  | typeof NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_NAME
+  | typeof NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_WALLET
+  | typeof NCN_PROGRAM_ERROR__INVALID_KEEPER_LEASE_SLOTS
+  | typeof NCN_PROGRAM_ERROR__INVALID_MAX_VAULT_STAKE_WEIGHT_BPS
   | typeof NCN_PROGRAM_ERROR__INVALID_MERKLE_PROOF
   | typeof NCN_PROGRAM_ERROR__INVALID_MINT_FOR_WEIGHT_TABLE
   | typeof NCN_PROGRAM_ERROR__INVALID_NCN_FEE_GROUP
   | typeof NCN_PROGRAM_ERROR__INVALID_N_C_N_FEE_WALLET
+  | typeof NCN_PROGRAM_ERROR__INVALID_OPERATOR_METADATA
   | typeof NCN_PROGRAM_ERROR__INVALID_OPERATOR_VOTER
+  | typeof NCN_PROGRAM_ERROR__INVALID_PARAMETERS_TIMELOCK
+  | typeof NCN_PROGRAM_ERROR__INVALID_REWARD_MINT
+  | typeof NCN_PROGRAM_ERROR__INVALID_REWARD_MINT_INDEX
+  | typeof NCN_PROGRAM_ERROR__INVALID_REWARD_TOKEN_ACCOUNT
   | typeof NCN_PROGRAM_ERROR__INVALID_SLOTS_AFTER_CONSENSUS
+  | typeof NCN_PROGRAM_ERROR__INVALID_TIMELINESS_BONUS_BPS
+  | typeof NCN_PROGRAM_ERROR__INVALID_VAULT_NAME
+  | typeof NCN_PROGRAM_ERROR__INVALID_VAULT_REWARD_RECIPIENT
+  | typeof NCN_PROGRAM_ERROR__INVALID_WEIGHT_DECAY_BPS
+  | typeof NCN_PROGRAM_ERROR__KEEPER_LEASE_HELD
   | typeof NCN_PROGRAM_ERROR__MARKER_EXISTS
   | typeof NCN_PROGRAM_ERROR__MINT_ENTRY_NOT_FOUND
   | typeof NCN_PROGRAM_ERROR__MINT_IN_TABLE
   | typeof NCN_PROGRAM_ERROR__MODULO_OVERFLOW
   | typeof NCN_PROGRAM_ERROR__NCN_REWARD_ROUTE_NOT_FOUND
+  | typeof NCN_PROGRAM_ERROR__NCN_VAULT_TICKET_NOT_ACTIVE
   | typeof NCN_PROGRAM_ERROR__NEW_PRECISE_NUMBER_ERROR
   | typeof NCN_PROGRAM_ERROR__NO_MINTS_IN_TABLE
   | typeof NCN_PROGRAM_ERROR__NO_OPERATORS
+  | typeof NCN_PROGRAM_ERROR__NO_PENDING_PARAMETERS
   | typeof NCN_PROGRAM_ERROR__NO_REWARDS
+  | typeof NCN_PROGRAM_ERROR__NO_SWITCHBOARD_FEED
+  | typeof NCN_PROGRAM_ERROR__NOT_ADMIN_GOVERNANCE
   | typeof NCN_PROGRAM_ERROR__NO_VALID_BALLOTS
   | typeof NCN_PROGRAM_ERROR__NO_VAULTS_IN_REGISTRY
   | typeof NCN_PROGRAM_ERROR__OPERATOR_ALREADY_VOTED
@@ -264,8 +372,16 @@ export type NcnProgramError =
   | typeof NCN_PROGRAM_ERROR__OPERATOR_REWARD_LIST_FULL
   | typeof NCN_PROGRAM_ERROR__OPERATOR_REWARD_NOT_FOUND
   | typeof NCN_PROGRAM_ERROR__OPERATOR_VOTES_FULL
+  | typeof NCN_PROGRAM_ERROR__PARAMETERS_TIMELOCK_NOT_ELAPSED
   | typeof NCN_PROGRAM_ERROR__REGISTRY_NOT_INITIALIZED
+  | typeof NCN_PROGRAM_ERROR__REWARD_MINT_LIST_FULL
+  | typeof NCN_PROGRAM_ERROR__REWARD_MINT_NOT_FOUND
+  | typeof NCN_PROGRAM_ERROR__REWARD_MINT_NOT_REGISTERED
+  | typeof NCN_PROGRAM_ERROR__REWARD_ROUTER_NOT_CLOSED
+  | typeof NCN_PROGRAM_ERROR__ROUTER_REWARDS_IN_TRANSIT
   | typeof NCN_PROGRAM_ERROR__ROUTER_STILL_ROUTING
+  | typeof NCN_PROGRAM_ERROR__STALE_SWITCHBOARD_FEED
+  | typeof NCN_PROGRAM_ERROR__SWITCHBOARD_CONFIDENCE_TOO_LOW
   | typeof NCN_PROGRAM_ERROR__TABLE_NOT_INITIALIZED
   | typeof NCN_PROGRAM_ERROR__TIE_BREAKER_ADMIN_INVALID
   | typeof NCN_PROGRAM_ERROR__TIE_BREAKER_NOT_IN_PRIOR_VOTES
@@ -277,6 +393,7 @@ export type NcnProgramError =
   | typeof NCN_PROGRAM_ERROR__VAULT_NEEDS_UPDATE
   | typeof NCN_PROGRAM_ERROR__VAULT_NOT_IN_REGISTRY
   | typeof NCN_PROGRAM_ERROR__VAULT_OPERATOR_DELEGATION_FINALIZED
+  | typeof NCN_PROGRAM_ERROR__VAULT_REGISTRY_CHANGED
   | typeof NCN_PROGRAM_ERROR__VAULT_REGISTRY_LIST_FULL
   | typeof NCN_PROGRAM_ERROR__VAULT_REGISTRY_VAULT_LOCKED
   | typeof NCN_PROGRAM_ERROR__VAULT_REWARD_NOT_FOUND
@@ -294,11 +411,15 @@ let ncnProgramErrorMessages: Record<NcnProgramError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   ncnProgramErrorMessages = {
     [NCN_PROGRAM_ERROR__ACCOUNT_ALREADY_INITIALIZED]: `Account already initialized`,
+    [NCN_PROGRAM_ERROR__ADMIN_THRESHOLD_NOT_MET]: `Not enough admin approvers signed`,
     [NCN_PROGRAM_ERROR__ARITHMETIC_FLOOR_ERROR]: `Floor Overflow`,
     [NCN_PROGRAM_ERROR__ARITHMETIC_OVERFLOW]: `Overflow`,
     [NCN_PROGRAM_ERROR__ARITHMETIC_UNDERFLOW_ERROR]: `Underflow`,
     [NCN_PROGRAM_ERROR__BAD_BALLOT]: `Cannot vote with uninitialized account`,
+    [NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_FEED]: `Could not parse the switchboard feed`,
+    [NCN_PROGRAM_ERROR__BAD_SWITCHBOARD_VALUE]: `Switchboard feed value is invalid`,
     [NCN_PROGRAM_ERROR__BALLOT_TALLY_FULL]: `Merkle root tally full`,
+    [NCN_PROGRAM_ERROR__BALLOT_TALLY_MISMATCH]: `Ballot tallies do not match the operator votes`,
     [NCN_PROGRAM_ERROR__BALLOT_TALLY_NOT_EMPTY]: `Ballot tally not empty`,
     [NCN_PROGRAM_ERROR__BALLOT_TALLY_NOT_FOUND_FULL]: `Ballot tally not found`,
     [NCN_PROGRAM_ERROR__CANNOT_CLOSE_ACCOUNT]: `Cannot close account`,
@@ -316,6 +437,8 @@ if (process.env.NODE_ENV !== 'production') {
     [NCN_PROGRAM_ERROR__CONFIG_MINTS_NOT_UPDATED]: `Config supported mints do not match NCN Vault Count`,
     [NCN_PROGRAM_ERROR__CONSENSUS_ALREADY_REACHED]: `Consensus already reached, cannot change vote`,
     [NCN_PROGRAM_ERROR__CONSENSUS_NOT_REACHED]: `Consensus not reached`,
+    [NCN_PROGRAM_ERROR__CONSENSUS_RESULT_MISMATCH]: `Consensus result does not match the ballot box`,
+    [NCN_PROGRAM_ERROR__DEFAULT_FEE_GROUP_WALLET]: `Fee group wallet cannot be the default pubkey`,
     [NCN_PROGRAM_ERROR__DEFAULT_NCN_WALLET]: `NCN Fee wallet cannot be default`,
     [NCN_PROGRAM_ERROR__DEFAULT_PROTOCOL_WALLET]: `Protocol wallet cannot be default`,
     [NCN_PROGRAM_ERROR__DENOMINATOR_IS_ZERO]: `Zero in the denominator`,
@@ -324,33 +447,57 @@ if (process.env.NODE_ENV !== 'production') {
     [NCN_PROGRAM_ERROR__DUPLICATE_VAULT_OPERATOR_DELEGATION]: `Duplicate vault operator delegation`,
     [NCN_PROGRAM_ERROR__DUPLICATE_VOTE_CAST]: `Duplicate Vote Cast`,
     [NCN_PROGRAM_ERROR__EPOCH_IS_CLOSING_DOWN]: `Epoch is closing down`,
+    [NCN_PROGRAM_ERROR__EPOCH_NOT_SKIPPABLE]: `Epoch can only be skipped once it stalled without an epoch state`,
     [NCN_PROGRAM_ERROR__EPOCH_SNAPSHOT_NOT_FINALIZED]: `Epoch snapshot not finalized`,
     [NCN_PROGRAM_ERROR__FEE_CAP_EXCEEDED]: `Fee cap exceeded`,
     [NCN_PROGRAM_ERROR__FEE_NOT_ACTIVE]: `Fee not active`,
     [NCN_PROGRAM_ERROR__INCORRECT_FEE_ADMIN]: `Incorrect fee admin`,
     [NCN_PROGRAM_ERROR__INCORRECT_NCN]: `Incorrect NCN`,
     [NCN_PROGRAM_ERROR__INCORRECT_NCN_ADMIN]: `Incorrect NCN Admin`,
+    [NCN_PROGRAM_ERROR__INCORRECT_SWITCHBOARD_FEED]: `Switchboard feed does not match the registered feed`,
     [NCN_PROGRAM_ERROR__INCORRECT_WEIGHT_TABLE_ADMIN]: `Incorrect weight table admin`,
     [NCN_PROGRAM_ERROR__INVALID_ACCOUNT_STATUS]: `Invalid Account Status`,
     [NCN_PROGRAM_ERROR__INVALID_ACCOUNT_TO_CLOSE_DISCRIMINATOR]: `Invalid account_to_close Discriminator`,
+    [NCN_PROGRAM_ERROR__INVALID_ADMIN_GOVERNANCE]: `Admin governance program and realm have to be set together`,
+    [NCN_PROGRAM_ERROR__INVALID_ADMIN_MULTISIG]: `Invalid admin multisig`,
     [NCN_PROGRAM_ERROR__INVALID_BASE_FEE_GROUP]: `Not a valid base fee group`,
+    [NCN_PROGRAM_ERROR__INVALID_CRANKER_FEE_BPS]: `Cranker fee exceeds the maximum`,
     [NCN_PROGRAM_ERROR__INVALID_EPOCHS_BEFORE_CLOSE]: `Invalid epochs before accounts can close`,
     [NCN_PROGRAM_ERROR__INVALID_EPOCHS_BEFORE_STALL]: `Invalid epochs before stall`,
+    [NCN_PROGRAM_ERROR__INVALID_FEE_GROUP]: `Invalid fee group`,
+    [NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_NAME]: `Fee group name is not valid UTF-8`,
+    [NCN_PROGRAM_ERROR__INVALID_FEE_GROUP_WALLET]: `Fee group wallet does not match the config`,
+    [NCN_PROGRAM_ERROR__INVALID_KEEPER_LEASE_SLOTS]: `Keeper lease slots exceed the maximum`,
+    [NCN_PROGRAM_ERROR__INVALID_MAX_VAULT_STAKE_WEIGHT_BPS]: `Vault stake weight cap exceeds the maximum`,
     [NCN_PROGRAM_ERROR__INVALID_MERKLE_PROOF]: `Invalid merkle proof`,
     [NCN_PROGRAM_ERROR__INVALID_MINT_FOR_WEIGHT_TABLE]: `Invalid mint for weight table`,
     [NCN_PROGRAM_ERROR__INVALID_NCN_FEE_GROUP]: `Not a valid NCN fee group`,
     [NCN_PROGRAM_ERROR__INVALID_N_C_N_FEE_WALLET]: `Invalid NCN Fee wallet`,
+    [NCN_PROGRAM_ERROR__INVALID_OPERATOR_METADATA]: `Operator metadata is not valid UTF-8`,
     [NCN_PROGRAM_ERROR__INVALID_OPERATOR_VOTER]: `Operator voter needs to sign its vote`,
+    [NCN_PROGRAM_ERROR__INVALID_PARAMETERS_TIMELOCK]: `Parameters timelock exceeds the maximum`,
+    [NCN_PROGRAM_ERROR__INVALID_REWARD_MINT]: `Invalid reward mint`,
+    [NCN_PROGRAM_ERROR__INVALID_REWARD_MINT_INDEX]: `Invalid reward mint index`,
+    [NCN_PROGRAM_ERROR__INVALID_REWARD_TOKEN_ACCOUNT]: `Invalid reward token account`,
     [NCN_PROGRAM_ERROR__INVALID_SLOTS_AFTER_CONSENSUS]: `Invalid slots after consensus`,
+    [NCN_PROGRAM_ERROR__INVALID_TIMELINESS_BONUS_BPS]: `Timeliness bonus exceeds the maximum`,
+    [NCN_PROGRAM_ERROR__INVALID_VAULT_NAME]: `Vault name is not valid UTF-8`,
+    [NCN_PROGRAM_ERROR__INVALID_VAULT_REWARD_RECIPIENT]: `Vault reward recipient must be a system account or a wrapped SOL token account`,
+    [NCN_PROGRAM_ERROR__INVALID_WEIGHT_DECAY_BPS]: `Weight decay exceeds the maximum`,
+    [NCN_PROGRAM_ERROR__KEEPER_LEASE_HELD]: `Keeper lease is held by another keeper`,
     [NCN_PROGRAM_ERROR__MARKER_EXISTS]: `Marker exists`,
     [NCN_PROGRAM_ERROR__MINT_ENTRY_NOT_FOUND]: `Mint Entry not found`,
     [NCN_PROGRAM_ERROR__MINT_IN_TABLE]: `Mint is already in the table`,
     [NCN_PROGRAM_ERROR__MODULO_OVERFLOW]: `Modulo Overflow`,
     [NCN_PROGRAM_ERROR__NCN_REWARD_ROUTE_NOT_FOUND]: `Ncn reward route not found`,
+    [NCN_PROGRAM_ERROR__NCN_VAULT_TICKET_NOT_ACTIVE]: `NCN vault ticket is not active`,
     [NCN_PROGRAM_ERROR__NEW_PRECISE_NUMBER_ERROR]: `New precise number error`,
     [NCN_PROGRAM_ERROR__NO_MINTS_IN_TABLE]: `There are no mints in the table`,
     [NCN_PROGRAM_ERROR__NO_OPERATORS]: `No operators in ncn`,
+    [NCN_PROGRAM_ERROR__NO_PENDING_PARAMETERS]: `No parameter changes are pending`,
     [NCN_PROGRAM_ERROR__NO_REWARDS]: `No rewards to distribute`,
+    [NCN_PROGRAM_ERROR__NO_SWITCHBOARD_FEED]: `No switchboard feed is registered for the st mint`,
+    [NCN_PROGRAM_ERROR__NOT_ADMIN_GOVERNANCE]: `Admin instructions have to be signed by a governance of the admin realm`,
     [NCN_PROGRAM_ERROR__NO_VALID_BALLOTS]: `No valid Ballot`,
     [NCN_PROGRAM_ERROR__NO_VAULTS_IN_REGISTRY]: `There are no vaults in the registry`,
     [NCN_PROGRAM_ERROR__OPERATOR_ALREADY_VOTED]: `Operator Already Voted`,
@@ -359,8 +506,16 @@ if (process.env.NODE_ENV !== 'production') {
     [NCN_PROGRAM_ERROR__OPERATOR_REWARD_LIST_FULL]: `Operator reward list full`,
     [NCN_PROGRAM_ERROR__OPERATOR_REWARD_NOT_FOUND]: `Operator Reward not found`,
     [NCN_PROGRAM_ERROR__OPERATOR_VOTES_FULL]: `Operator votes full`,
+    [NCN_PROGRAM_ERROR__PARAMETERS_TIMELOCK_NOT_ELAPSED]: `Parameters timelock has not elapsed`,
     [NCN_PROGRAM_ERROR__REGISTRY_NOT_INITIALIZED]: `Registry not initialized`,
+    [NCN_PROGRAM_ERROR__REWARD_MINT_LIST_FULL]: `Reward mint list is full`,
+    [NCN_PROGRAM_ERROR__REWARD_MINT_NOT_FOUND]: `Reward mint not found`,
+    [NCN_PROGRAM_ERROR__REWARD_MINT_NOT_REGISTERED]: `Reward mint is not registered in the config`,
+    [NCN_PROGRAM_ERROR__REWARD_ROUTER_NOT_CLOSED]: `Reward router is not closed for good`,
+    [NCN_PROGRAM_ERROR__ROUTER_REWARDS_IN_TRANSIT]: `Router still has rewards to route or distribute`,
     [NCN_PROGRAM_ERROR__ROUTER_STILL_ROUTING]: `Router still routing`,
+    [NCN_PROGRAM_ERROR__STALE_SWITCHBOARD_FEED]: `Switchboard feed is stale`,
+    [NCN_PROGRAM_ERROR__SWITCHBOARD_CONFIDENCE_TOO_LOW]: `Switchboard feed confidence interval is too wide`,
     [NCN_PROGRAM_ERROR__TABLE_NOT_INITIALIZED]: `Table not initialized`,
     [NCN_PROGRAM_ERROR__TIE_BREAKER_ADMIN_INVALID]: `Tie breaker admin invalid`,
     [NCN_PROGRAM_ERROR__TIE_BREAKER_NOT_IN_PRIOR_VOTES]: `Tie breaking ballot must be one of the prior votes`,
@@ -372,6 +527,7 @@ if (process.env.NODE_ENV !== 'production') {
     [NCN_PROGRAM_ERROR__VAULT_NEEDS_UPDATE]: `Vault needs to be updated`,
     [NCN_PROGRAM_ERROR__VAULT_NOT_IN_REGISTRY]: `Vault not in weight table registry`,
     [NCN_PROGRAM_ERROR__VAULT_OPERATOR_DELEGATION_FINALIZED]: `Vault operator delegation is already finalized - should not happen`,
+    [NCN_PROGRAM_ERROR__VAULT_REGISTRY_CHANGED]: `Vault registry changed since the previous epoch`,
     [NCN_PROGRAM_ERROR__VAULT_REGISTRY_LIST_FULL]: `Vault Registry mints are at capacity`,
     [NCN_PROGRAM_ERROR__VAULT_REGISTRY_VAULT_LOCKED]: `Vault registry are locked for the epoch`,
     [NCN_PROGRAM_ERROR__VAULT_REWARD_NOT_FOUND]: `Vault Reward not found`,
@@ -0,0 +1,237 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ACQUIRE_KEEPER_LEASE_DISCRIMINATOR = 53;
+
+export function getAcquireKeeperLeaseDiscriminatorBytes() {
+  return getU8Encoder().encode(ACQUIRE_KEEPER_LEASE_DISCRIMINATOR);
+}
+
+export type AcquireKeeperLeaseInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountKeeperLease extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountKeeperLease extends string
+        ? WritableAccount<TAccountKeeperLease>
+        : TAccountKeeperLease,
+      TAccountAuthority extends string
+        ? WritableSignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AcquireKeeperLeaseInstructionData = {
+  discriminator: number;
+  holder: Address;
+  leaseSlots: bigint;
+};
+
+export type AcquireKeeperLeaseInstructionDataArgs = {
+  holder: Address;
+  leaseSlots: number | bigint;
+};
+
+export function getAcquireKeeperLeaseInstructionDataEncoder(): Encoder<AcquireKeeperLeaseInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['holder', getAddressEncoder()],
+      ['leaseSlots', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: ACQUIRE_KEEPER_LEASE_DISCRIMINATOR })
+  );
+}
+
+export function getAcquireKeeperLeaseInstructionDataDecoder(): Decoder<AcquireKeeperLeaseInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['holder', getAddressDecoder()],
+    ['leaseSlots', getU64Decoder()],
+  ]);
+}
+
+export function getAcquireKeeperLeaseInstructionDataCodec(): Codec<
+  AcquireKeeperLeaseInstructionDataArgs,
+  AcquireKeeperLeaseInstructionData
+> {
+  return combineCodec(
+    getAcquireKeeperLeaseInstructionDataEncoder(),
+    getAcquireKeeperLeaseInstructionDataDecoder()
+  );
+}
+
+export type AcquireKeeperLeaseInput<
+  TAccountNcn extends string = string,
+  TAccountKeeperLease extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  keeperLease: Address<TAccountKeeperLease>;
+  authority: TransactionSigner<TAccountAuthority>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  holder: AcquireKeeperLeaseInstructionDataArgs['holder'];
+  leaseSlots: AcquireKeeperLeaseInstructionDataArgs['leaseSlots'];
+};
+
+export function getAcquireKeeperLeaseInstruction<
+  TAccountNcn extends string,
+  TAccountKeeperLease extends string,
+  TAccountAuthority extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AcquireKeeperLeaseInput<
+    TAccountNcn,
+    TAccountKeeperLease,
+    TAccountAuthority,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AcquireKeeperLeaseInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountKeeperLease,
+  TAccountAuthority,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    keeperLease: { value: input.keeperLease ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.keeperLease),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getAcquireKeeperLeaseInstructionDataEncoder().encode(
+      args as AcquireKeeperLeaseInstructionDataArgs
+    ),
+  } as AcquireKeeperLeaseInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountKeeperLease,
+    TAccountAuthority,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedAcquireKeeperLeaseInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    keeperLease: TAccountMetas[1];
+    authority: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+  };
+  data: AcquireKeeperLeaseInstructionData;
+};
+
+export function parseAcquireKeeperLeaseInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAcquireKeeperLeaseInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      keeperLease: getNextAccount(),
+      authority: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getAcquireKeeperLeaseInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
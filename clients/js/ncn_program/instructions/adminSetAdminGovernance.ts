@@ -0,0 +1,216 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_ADMIN_GOVERNANCE_DISCRIMINATOR = 49;
+
+export function getAdminSetAdminGovernanceDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_ADMIN_GOVERNANCE_DISCRIMINATOR);
+}
+
+export type AdminSetAdminGovernanceInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnAdmin extends string
+        ? ReadonlySignerAccount<TAccountNcnAdmin> &
+            IAccountSignerMeta<TAccountNcnAdmin>
+        : TAccountNcnAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetAdminGovernanceInstructionData = {
+  discriminator: number;
+  governanceProgram: Address;
+  realm: Address;
+};
+
+export type AdminSetAdminGovernanceInstructionDataArgs = {
+  governanceProgram: Address;
+  realm: Address;
+};
+
+export function getAdminSetAdminGovernanceInstructionDataEncoder(): Encoder<AdminSetAdminGovernanceInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['governanceProgram', getAddressEncoder()],
+      ['realm', getAddressEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_ADMIN_GOVERNANCE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetAdminGovernanceInstructionDataDecoder(): Decoder<AdminSetAdminGovernanceInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['governanceProgram', getAddressDecoder()],
+    ['realm', getAddressDecoder()],
+  ]);
+}
+
+export function getAdminSetAdminGovernanceInstructionDataCodec(): Codec<
+  AdminSetAdminGovernanceInstructionDataArgs,
+  AdminSetAdminGovernanceInstructionData
+> {
+  return combineCodec(
+    getAdminSetAdminGovernanceInstructionDataEncoder(),
+    getAdminSetAdminGovernanceInstructionDataDecoder()
+  );
+}
+
+export type AdminSetAdminGovernanceInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
+  governanceProgram: AdminSetAdminGovernanceInstructionDataArgs['governanceProgram'];
+  realm: AdminSetAdminGovernanceInstructionDataArgs['realm'];
+};
+
+export function getAdminSetAdminGovernanceInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetAdminGovernanceInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetAdminGovernanceInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnAdmin),
+    ],
+    programAddress,
+    data: getAdminSetAdminGovernanceInstructionDataEncoder().encode(
+      args as AdminSetAdminGovernanceInstructionDataArgs
+    ),
+  } as AdminSetAdminGovernanceInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetAdminGovernanceInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnAdmin: TAccountMetas[2];
+  };
+  data: AdminSetAdminGovernanceInstructionData;
+};
+
+export function parseAdminSetAdminGovernanceInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetAdminGovernanceInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnAdmin: getNextAccount(),
+    },
+    data: getAdminSetAdminGovernanceInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
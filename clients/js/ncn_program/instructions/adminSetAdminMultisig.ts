@@ -0,0 +1,218 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_ADMIN_MULTISIG_DISCRIMINATOR = 44;
+
+export function getAdminSetAdminMultisigDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_ADMIN_MULTISIG_DISCRIMINATOR);
+}
+
+export type AdminSetAdminMultisigInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnAdmin extends string
+        ? ReadonlySignerAccount<TAccountNcnAdmin> &
+            IAccountSignerMeta<TAccountNcnAdmin>
+        : TAccountNcnAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetAdminMultisigInstructionData = {
+  discriminator: number;
+  approvers: Array<Address>;
+  threshold: number;
+};
+
+export type AdminSetAdminMultisigInstructionDataArgs = {
+  approvers: Array<Address>;
+  threshold: number;
+};
+
+export function getAdminSetAdminMultisigInstructionDataEncoder(): Encoder<AdminSetAdminMultisigInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['approvers', getArrayEncoder(getAddressEncoder())],
+      ['threshold', getU8Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_ADMIN_MULTISIG_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetAdminMultisigInstructionDataDecoder(): Decoder<AdminSetAdminMultisigInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['approvers', getArrayDecoder(getAddressDecoder())],
+    ['threshold', getU8Decoder()],
+  ]);
+}
+
+export function getAdminSetAdminMultisigInstructionDataCodec(): Codec<
+  AdminSetAdminMultisigInstructionDataArgs,
+  AdminSetAdminMultisigInstructionData
+> {
+  return combineCodec(
+    getAdminSetAdminMultisigInstructionDataEncoder(),
+    getAdminSetAdminMultisigInstructionDataDecoder()
+  );
+}
+
+export type AdminSetAdminMultisigInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
+  approvers: AdminSetAdminMultisigInstructionDataArgs['approvers'];
+  threshold: AdminSetAdminMultisigInstructionDataArgs['threshold'];
+};
+
+export function getAdminSetAdminMultisigInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetAdminMultisigInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetAdminMultisigInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnAdmin),
+    ],
+    programAddress,
+    data: getAdminSetAdminMultisigInstructionDataEncoder().encode(
+      args as AdminSetAdminMultisigInstructionDataArgs
+    ),
+  } as AdminSetAdminMultisigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetAdminMultisigInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnAdmin: TAccountMetas[2];
+  };
+  data: AdminSetAdminMultisigInstructionData;
+};
+
+export function parseAdminSetAdminMultisigInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetAdminMultisigInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnAdmin: getNextAccount(),
+    },
+    data: getAdminSetAdminMultisigInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,225 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_DEFAULT_WEIGHT_DISCRIMINATOR = 41;
+
+export function getAdminSetDefaultWeightDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_DEFAULT_WEIGHT_DISCRIMINATOR);
+}
+
+export type AdminSetDefaultWeightInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVaultRegistry extends string
+        ? WritableAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountAdmin extends string
+        ? WritableSignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetDefaultWeightInstructionData = {
+  discriminator: number;
+  defaultWeight: bigint;
+};
+
+export type AdminSetDefaultWeightInstructionDataArgs = {
+  defaultWeight: number | bigint;
+};
+
+export function getAdminSetDefaultWeightInstructionDataEncoder(): Encoder<AdminSetDefaultWeightInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['defaultWeight', getU128Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_DEFAULT_WEIGHT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetDefaultWeightInstructionDataDecoder(): Decoder<AdminSetDefaultWeightInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['defaultWeight', getU128Decoder()],
+  ]);
+}
+
+export function getAdminSetDefaultWeightInstructionDataCodec(): Codec<
+  AdminSetDefaultWeightInstructionDataArgs,
+  AdminSetDefaultWeightInstructionData
+> {
+  return combineCodec(
+    getAdminSetDefaultWeightInstructionDataEncoder(),
+    getAdminSetDefaultWeightInstructionDataDecoder()
+  );
+}
+
+export type AdminSetDefaultWeightInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  admin: TransactionSigner<TAccountAdmin>;
+  defaultWeight: AdminSetDefaultWeightInstructionDataArgs['defaultWeight'];
+};
+
+export function getAdminSetDefaultWeightInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVaultRegistry extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetDefaultWeightInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetDefaultWeightInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountVaultRegistry,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getAdminSetDefaultWeightInstructionDataEncoder().encode(
+      args as AdminSetDefaultWeightInstructionDataArgs
+    ),
+  } as AdminSetDefaultWeightInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetDefaultWeightInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vaultRegistry: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: AdminSetDefaultWeightInstructionData;
+};
+
+export function parseAdminSetDefaultWeightInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetDefaultWeightInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetDefaultWeightInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,239 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_FEE_GROUP_DISCRIMINATOR = 47;
+
+export function getAdminSetFeeGroupDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_FEE_GROUP_DISCRIMINATOR);
+}
+
+export type AdminSetFeeGroupInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountNcnAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountFeeWallet extends string
+        ? ReadonlyAccount<TAccountFeeWallet>
+        : TAccountFeeWallet,
+      TAccountNcnAdmin extends string
+        ? ReadonlySignerAccount<TAccountNcnAdmin> &
+            IAccountSignerMeta<TAccountNcnAdmin>
+        : TAccountNcnAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetFeeGroupInstructionData = {
+  discriminator: number;
+  group: number;
+  name: ReadonlyUint8Array;
+  feeBps: Option<number>;
+};
+
+export type AdminSetFeeGroupInstructionDataArgs = {
+  group: number;
+  name: ReadonlyUint8Array;
+  feeBps: OptionOrNullable<number>;
+};
+
+export function getAdminSetFeeGroupInstructionDataEncoder(): Encoder<AdminSetFeeGroupInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['group', getU8Encoder()],
+      ['name', fixEncoderSize(getBytesEncoder(), 32)],
+      ['feeBps', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({ ...value, discriminator: ADMIN_SET_FEE_GROUP_DISCRIMINATOR })
+  );
+}
+
+export function getAdminSetFeeGroupInstructionDataDecoder(): Decoder<AdminSetFeeGroupInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['group', getU8Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['feeBps', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAdminSetFeeGroupInstructionDataCodec(): Codec<
+  AdminSetFeeGroupInstructionDataArgs,
+  AdminSetFeeGroupInstructionData
+> {
+  return combineCodec(
+    getAdminSetFeeGroupInstructionDataEncoder(),
+    getAdminSetFeeGroupInstructionDataDecoder()
+  );
+}
+
+export type AdminSetFeeGroupInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountFeeWallet extends string = string,
+  TAccountNcnAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  feeWallet: Address<TAccountFeeWallet>;
+  ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
+  group: AdminSetFeeGroupInstructionDataArgs['group'];
+  name: AdminSetFeeGroupInstructionDataArgs['name'];
+  feeBps: AdminSetFeeGroupInstructionDataArgs['feeBps'];
+};
+
+export function getAdminSetFeeGroupInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountFeeWallet extends string,
+  TAccountNcnAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetFeeGroupInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountFeeWallet,
+    TAccountNcnAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetFeeGroupInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountFeeWallet,
+  TAccountNcnAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    feeWallet: { value: input.feeWallet ?? null, isWritable: false },
+    ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.feeWallet),
+      getAccountMeta(accounts.ncnAdmin),
+    ],
+    programAddress,
+    data: getAdminSetFeeGroupInstructionDataEncoder().encode(
+      args as AdminSetFeeGroupInstructionDataArgs
+    ),
+  } as AdminSetFeeGroupInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountFeeWallet,
+    TAccountNcnAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetFeeGroupInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    feeWallet: TAccountMetas[2];
+    ncnAdmin: TAccountMetas[3];
+  };
+  data: AdminSetFeeGroupInstructionData;
+};
+
+export function parseAdminSetFeeGroupInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetFeeGroupInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      feeWallet: getNextAccount(),
+      ncnAdmin: getNextAccount(),
+    },
+    data: getAdminSetFeeGroupInstructionDataDecoder().decode(instruction.data),
+  };
+}
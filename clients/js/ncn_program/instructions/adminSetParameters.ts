@@ -8,10 +8,14 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -70,6 +74,14 @@ export type AdminSetParametersInstructionData = {
   epochsBeforeStall: Option<bigint>;
   epochsAfterConsensusBeforeClose: Option<bigint>;
   validSlotsAfterConsensus: Option<bigint>;
+  crankerFeeBps: Option<number>;
+  timelinessBonusSlots: Option<bigint>;
+  timelinessBonusBps: Option<number>;
+  weightDecayEpochs: Option<bigint>;
+  weightDecayBps: Option<number>;
+  maxVaultStakeWeightBps: Option<number>;
+  permissionlessVaultRegistration: Option<boolean>;
+  parametersTimelockSlots: Option<bigint>;
 };
 
 export type AdminSetParametersInstructionDataArgs = {
@@ -77,6 +89,14 @@ export type AdminSetParametersInstructionDataArgs = {
   epochsBeforeStall: OptionOrNullable<number | bigint>;
   epochsAfterConsensusBeforeClose: OptionOrNullable<number | bigint>;
   validSlotsAfterConsensus: OptionOrNullable<number | bigint>;
+  crankerFeeBps: OptionOrNullable<number>;
+  timelinessBonusSlots: OptionOrNullable<number | bigint>;
+  timelinessBonusBps: OptionOrNullable<number>;
+  weightDecayEpochs: OptionOrNullable<number | bigint>;
+  weightDecayBps: OptionOrNullable<number>;
+  maxVaultStakeWeightBps: OptionOrNullable<number>;
+  permissionlessVaultRegistration: OptionOrNullable<boolean>;
+  parametersTimelockSlots: OptionOrNullable<number | bigint>;
 };
 
 export function getAdminSetParametersInstructionDataEncoder(): Encoder<AdminSetParametersInstructionDataArgs> {
@@ -87,6 +107,14 @@ export function getAdminSetParametersInstructionDataEncoder(): Encoder<AdminSetP
       ['epochsBeforeStall', getOptionEncoder(getU64Encoder())],
       ['epochsAfterConsensusBeforeClose', getOptionEncoder(getU64Encoder())],
       ['validSlotsAfterConsensus', getOptionEncoder(getU64Encoder())],
+      ['crankerFeeBps', getOptionEncoder(getU16Encoder())],
+      ['timelinessBonusSlots', getOptionEncoder(getU64Encoder())],
+      ['timelinessBonusBps', getOptionEncoder(getU16Encoder())],
+      ['weightDecayEpochs', getOptionEncoder(getU64Encoder())],
+      ['weightDecayBps', getOptionEncoder(getU16Encoder())],
+      ['maxVaultStakeWeightBps', getOptionEncoder(getU16Encoder())],
+      ['permissionlessVaultRegistration', getOptionEncoder(getBoolEncoder())],
+      ['parametersTimelockSlots', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: ADMIN_SET_PARAMETERS_DISCRIMINATOR })
   );
@@ -99,6 +127,14 @@ export function getAdminSetParametersInstructionDataDecoder(): Decoder<AdminSetP
     ['epochsBeforeStall', getOptionDecoder(getU64Decoder())],
     ['epochsAfterConsensusBeforeClose', getOptionDecoder(getU64Decoder())],
     ['validSlotsAfterConsensus', getOptionDecoder(getU64Decoder())],
+    ['crankerFeeBps', getOptionDecoder(getU16Decoder())],
+    ['timelinessBonusSlots', getOptionDecoder(getU64Decoder())],
+    ['timelinessBonusBps', getOptionDecoder(getU16Decoder())],
+    ['weightDecayEpochs', getOptionDecoder(getU64Decoder())],
+    ['weightDecayBps', getOptionDecoder(getU16Decoder())],
+    ['maxVaultStakeWeightBps', getOptionDecoder(getU16Decoder())],
+    ['permissionlessVaultRegistration', getOptionDecoder(getBoolDecoder())],
+    ['parametersTimelockSlots', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -124,6 +160,14 @@ export type AdminSetParametersInput<
   epochsBeforeStall: AdminSetParametersInstructionDataArgs['epochsBeforeStall'];
   epochsAfterConsensusBeforeClose: AdminSetParametersInstructionDataArgs['epochsAfterConsensusBeforeClose'];
   validSlotsAfterConsensus: AdminSetParametersInstructionDataArgs['validSlotsAfterConsensus'];
+  crankerFeeBps: AdminSetParametersInstructionDataArgs['crankerFeeBps'];
+  timelinessBonusSlots: AdminSetParametersInstructionDataArgs['timelinessBonusSlots'];
+  timelinessBonusBps: AdminSetParametersInstructionDataArgs['timelinessBonusBps'];
+  weightDecayEpochs: AdminSetParametersInstructionDataArgs['weightDecayEpochs'];
+  weightDecayBps: AdminSetParametersInstructionDataArgs['weightDecayBps'];
+  maxVaultStakeWeightBps: AdminSetParametersInstructionDataArgs['maxVaultStakeWeightBps'];
+  permissionlessVaultRegistration: AdminSetParametersInstructionDataArgs['permissionlessVaultRegistration'];
+  parametersTimelockSlots: AdminSetParametersInstructionDataArgs['parametersTimelockSlots'];
 };
 
 export function getAdminSetParametersInstruction<
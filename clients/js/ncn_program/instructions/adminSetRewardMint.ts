@@ -0,0 +1,212 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_REWARD_MINT_DISCRIMINATOR = 32;
+
+export function getAdminSetRewardMintDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_REWARD_MINT_DISCRIMINATOR);
+}
+
+export type AdminSetRewardMintInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnAdmin extends string
+        ? ReadonlySignerAccount<TAccountNcnAdmin> &
+            IAccountSignerMeta<TAccountNcnAdmin>
+        : TAccountNcnAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetRewardMintInstructionData = {
+  discriminator: number;
+  index: number;
+  mint: Address;
+};
+
+export type AdminSetRewardMintInstructionDataArgs = {
+  index: number;
+  mint: Address;
+};
+
+export function getAdminSetRewardMintInstructionDataEncoder(): Encoder<AdminSetRewardMintInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['index', getU8Encoder()],
+      ['mint', getAddressEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_REWARD_MINT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetRewardMintInstructionDataDecoder(): Decoder<AdminSetRewardMintInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['index', getU8Decoder()],
+    ['mint', getAddressDecoder()],
+  ]);
+}
+
+export function getAdminSetRewardMintInstructionDataCodec(): Codec<
+  AdminSetRewardMintInstructionDataArgs,
+  AdminSetRewardMintInstructionData
+> {
+  return combineCodec(
+    getAdminSetRewardMintInstructionDataEncoder(),
+    getAdminSetRewardMintInstructionDataDecoder()
+  );
+}
+
+export type AdminSetRewardMintInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
+  index: AdminSetRewardMintInstructionDataArgs['index'];
+  mint: AdminSetRewardMintInstructionDataArgs['mint'];
+};
+
+export function getAdminSetRewardMintInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetRewardMintInput<TAccountConfig, TAccountNcn, TAccountNcnAdmin>,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetRewardMintInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnAdmin),
+    ],
+    programAddress,
+    data: getAdminSetRewardMintInstructionDataEncoder().encode(
+      args as AdminSetRewardMintInstructionDataArgs
+    ),
+  } as AdminSetRewardMintInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetRewardMintInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnAdmin: TAccountMetas[2];
+  };
+  data: AdminSetRewardMintInstructionData;
+};
+
+export function parseAdminSetRewardMintInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetRewardMintInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnAdmin: getNextAccount(),
+    },
+    data: getAdminSetRewardMintInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -74,11 +74,13 @@ export type AdminSetStMintInstructionData = {
   discriminator: number;
   stMint: Address;
   weight: Option<bigint>;
+  switchboardFeed: Option<Address>;
 };
 
 export type AdminSetStMintInstructionDataArgs = {
   stMint: Address;
   weight: OptionOrNullable<number | bigint>;
+  switchboardFeed: OptionOrNullable<Address>;
 };
 
 export function getAdminSetStMintInstructionDataEncoder(): Encoder<AdminSetStMintInstructionDataArgs> {
@@ -87,6 +89,7 @@ export function getAdminSetStMintInstructionDataEncoder(): Encoder<AdminSetStMin
       ['discriminator', getU8Encoder()],
       ['stMint', getAddressEncoder()],
       ['weight', getOptionEncoder(getU128Encoder())],
+      ['switchboardFeed', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: ADMIN_SET_ST_MINT_DISCRIMINATOR })
   );
@@ -97,6 +100,7 @@ export function getAdminSetStMintInstructionDataDecoder(): Decoder<AdminSetStMin
     ['discriminator', getU8Decoder()],
     ['stMint', getAddressDecoder()],
     ['weight', getOptionDecoder(getU128Decoder())],
+    ['switchboardFeed', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -122,6 +126,7 @@ export type AdminSetStMintInput<
   admin: TransactionSigner<TAccountAdmin>;
   stMint: AdminSetStMintInstructionDataArgs['stMint'];
   weight: AdminSetStMintInstructionDataArgs['weight'];
+  switchboardFeed: AdminSetStMintInstructionDataArgs['switchboardFeed'];
 };
 
 export function getAdminSetStMintInstruction<
@@ -0,0 +1,247 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_VAULT_METADATA_DISCRIMINATOR = 42;
+
+export function getAdminSetVaultMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_VAULT_METADATA_DISCRIMINATOR);
+}
+
+export type AdminSetVaultMetadataInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVaultRegistry extends string
+        ? WritableAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetVaultMetadataInstructionData = {
+  discriminator: number;
+  name: ReadonlyUint8Array;
+  uriHash: ReadonlyUint8Array;
+};
+
+export type AdminSetVaultMetadataInstructionDataArgs = {
+  name: ReadonlyUint8Array;
+  uriHash: ReadonlyUint8Array;
+};
+
+export function getAdminSetVaultMetadataInstructionDataEncoder(): Encoder<AdminSetVaultMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', fixEncoderSize(getBytesEncoder(), 32)],
+      ['uriHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_VAULT_METADATA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetVaultMetadataInstructionDataDecoder(): Decoder<AdminSetVaultMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['uriHash', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getAdminSetVaultMetadataInstructionDataCodec(): Codec<
+  AdminSetVaultMetadataInstructionDataArgs,
+  AdminSetVaultMetadataInstructionData
+> {
+  return combineCodec(
+    getAdminSetVaultMetadataInstructionDataEncoder(),
+    getAdminSetVaultMetadataInstructionDataDecoder()
+  );
+}
+
+export type AdminSetVaultMetadataInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  name: AdminSetVaultMetadataInstructionDataArgs['name'];
+  uriHash: AdminSetVaultMetadataInstructionDataArgs['uriHash'];
+};
+
+export function getAdminSetVaultMetadataInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVaultRegistry extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetVaultMetadataInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountVault,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetVaultMetadataInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountVaultRegistry,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getAdminSetVaultMetadataInstructionDataEncoder().encode(
+      args as AdminSetVaultMetadataInstructionDataArgs
+    ),
+  } as AdminSetVaultMetadataInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetVaultMetadataInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vaultRegistry: TAccountMetas[2];
+    vault: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: AdminSetVaultMetadataInstructionData;
+};
+
+export function parseAdminSetVaultMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetVaultMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetVaultMetadataInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
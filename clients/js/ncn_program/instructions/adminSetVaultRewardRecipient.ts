@@ -0,0 +1,239 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_VAULT_REWARD_RECIPIENT_DISCRIMINATOR = 31;
+
+export function getAdminSetVaultRewardRecipientDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_VAULT_REWARD_RECIPIENT_DISCRIMINATOR);
+}
+
+export type AdminSetVaultRewardRecipientInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountRewardRecipient extends string | IAccountMeta<string> = string,
+  TAccountVaultAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVaultRegistry extends string
+        ? WritableAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountRewardRecipient extends string
+        ? ReadonlyAccount<TAccountRewardRecipient>
+        : TAccountRewardRecipient,
+      TAccountVaultAdmin extends string
+        ? ReadonlySignerAccount<TAccountVaultAdmin> &
+            IAccountSignerMeta<TAccountVaultAdmin>
+        : TAccountVaultAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetVaultRewardRecipientInstructionData = {
+  discriminator: number;
+};
+
+export type AdminSetVaultRewardRecipientInstructionDataArgs = {};
+
+export function getAdminSetVaultRewardRecipientInstructionDataEncoder(): Encoder<AdminSetVaultRewardRecipientInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_VAULT_REWARD_RECIPIENT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetVaultRewardRecipientInstructionDataDecoder(): Decoder<AdminSetVaultRewardRecipientInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getAdminSetVaultRewardRecipientInstructionDataCodec(): Codec<
+  AdminSetVaultRewardRecipientInstructionDataArgs,
+  AdminSetVaultRewardRecipientInstructionData
+> {
+  return combineCodec(
+    getAdminSetVaultRewardRecipientInstructionDataEncoder(),
+    getAdminSetVaultRewardRecipientInstructionDataDecoder()
+  );
+}
+
+export type AdminSetVaultRewardRecipientInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountVault extends string = string,
+  TAccountRewardRecipient extends string = string,
+  TAccountVaultAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  vault: Address<TAccountVault>;
+  rewardRecipient: Address<TAccountRewardRecipient>;
+  vaultAdmin: TransactionSigner<TAccountVaultAdmin>;
+};
+
+export function getAdminSetVaultRewardRecipientInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVaultRegistry extends string,
+  TAccountVault extends string,
+  TAccountRewardRecipient extends string,
+  TAccountVaultAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetVaultRewardRecipientInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountVault,
+    TAccountRewardRecipient,
+    TAccountVaultAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetVaultRewardRecipientInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountVaultRegistry,
+  TAccountVault,
+  TAccountRewardRecipient,
+  TAccountVaultAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    rewardRecipient: {
+      value: input.rewardRecipient ?? null,
+      isWritable: false,
+    },
+    vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.rewardRecipient),
+      getAccountMeta(accounts.vaultAdmin),
+    ],
+    programAddress,
+    data: getAdminSetVaultRewardRecipientInstructionDataEncoder().encode({}),
+  } as AdminSetVaultRewardRecipientInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountVault,
+    TAccountRewardRecipient,
+    TAccountVaultAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetVaultRewardRecipientInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vaultRegistry: TAccountMetas[2];
+    vault: TAccountMetas[3];
+    rewardRecipient: TAccountMetas[4];
+    vaultAdmin: TAccountMetas[5];
+  };
+  data: AdminSetVaultRewardRecipientInstructionData;
+};
+
+export function parseAdminSetVaultRewardRecipientInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetVaultRewardRecipientInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      vault: getNextAccount(),
+      rewardRecipient: getNextAccount(),
+      vaultAdmin: getNextAccount(),
+    },
+    data: getAdminSetVaultRewardRecipientInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,236 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getStMintWeightDecoder,
+  getStMintWeightEncoder,
+  type StMintWeight,
+  type StMintWeightArgs,
+} from '../types';
+
+export const ADMIN_SET_WEIGHTS_DISCRIMINATOR = 40;
+
+export function getAdminSetWeightsDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_WEIGHTS_DISCRIMINATOR);
+}
+
+export type AdminSetWeightsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountWeightTableAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountWeightTable extends string
+        ? WritableAccount<TAccountWeightTable>
+        : TAccountWeightTable,
+      TAccountWeightTableAdmin extends string
+        ? ReadonlySignerAccount<TAccountWeightTableAdmin> &
+            IAccountSignerMeta<TAccountWeightTableAdmin>
+        : TAccountWeightTableAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetWeightsInstructionData = {
+  discriminator: number;
+  weights: Array<StMintWeight>;
+  epoch: bigint;
+};
+
+export type AdminSetWeightsInstructionDataArgs = {
+  weights: Array<StMintWeightArgs>;
+  epoch: number | bigint;
+};
+
+export function getAdminSetWeightsInstructionDataEncoder(): Encoder<AdminSetWeightsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['weights', getArrayEncoder(getStMintWeightEncoder())],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: ADMIN_SET_WEIGHTS_DISCRIMINATOR })
+  );
+}
+
+export function getAdminSetWeightsInstructionDataDecoder(): Decoder<AdminSetWeightsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['weights', getArrayDecoder(getStMintWeightDecoder())],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getAdminSetWeightsInstructionDataCodec(): Codec<
+  AdminSetWeightsInstructionDataArgs,
+  AdminSetWeightsInstructionData
+> {
+  return combineCodec(
+    getAdminSetWeightsInstructionDataEncoder(),
+    getAdminSetWeightsInstructionDataDecoder()
+  );
+}
+
+export type AdminSetWeightsInput<
+  TAccountEpochState extends string = string,
+  TAccountNcn extends string = string,
+  TAccountWeightTable extends string = string,
+  TAccountWeightTableAdmin extends string = string,
+> = {
+  epochState: Address<TAccountEpochState>;
+  ncn: Address<TAccountNcn>;
+  weightTable: Address<TAccountWeightTable>;
+  weightTableAdmin: TransactionSigner<TAccountWeightTableAdmin>;
+  weights: AdminSetWeightsInstructionDataArgs['weights'];
+  epoch: AdminSetWeightsInstructionDataArgs['epoch'];
+};
+
+export function getAdminSetWeightsInstruction<
+  TAccountEpochState extends string,
+  TAccountNcn extends string,
+  TAccountWeightTable extends string,
+  TAccountWeightTableAdmin extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: AdminSetWeightsInput<
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountWeightTableAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): AdminSetWeightsInstruction<
+  TProgramAddress,
+  TAccountEpochState,
+  TAccountNcn,
+  TAccountWeightTable,
+  TAccountWeightTableAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    weightTable: { value: input.weightTable ?? null, isWritable: true },
+    weightTableAdmin: {
+      value: input.weightTableAdmin ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.weightTableAdmin),
+    ],
+    programAddress,
+    data: getAdminSetWeightsInstructionDataEncoder().encode(
+      args as AdminSetWeightsInstructionDataArgs
+    ),
+  } as AdminSetWeightsInstruction<
+    TProgramAddress,
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountWeightTableAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetWeightsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochState: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    weightTable: TAccountMetas[2];
+    weightTableAdmin: TAccountMetas[3];
+  };
+  data: AdminSetWeightsInstructionData;
+};
+
+export function parseAdminSetWeightsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetWeightsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochState: getNextAccount(),
+      ncn: getNextAccount(),
+      weightTable: getNextAccount(),
+      weightTableAdmin: getNextAccount(),
+    },
+    data: getAdminSetWeightsInstructionDataDecoder().decode(instruction.data),
+  };
+}
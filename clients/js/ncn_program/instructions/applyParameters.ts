@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const APPLY_PARAMETERS_DISCRIMINATOR = 45;
+
+export function getApplyParametersDiscriminatorBytes() {
+  return getU8Encoder().encode(APPLY_PARAMETERS_DISCRIMINATOR);
+}
+
+export type ApplyParametersInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ApplyParametersInstructionData = { discriminator: number };
+
+export type ApplyParametersInstructionDataArgs = {};
+
+export function getApplyParametersInstructionDataEncoder(): Encoder<ApplyParametersInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: APPLY_PARAMETERS_DISCRIMINATOR })
+  );
+}
+
+export function getApplyParametersInstructionDataDecoder(): Decoder<ApplyParametersInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getApplyParametersInstructionDataCodec(): Codec<
+  ApplyParametersInstructionDataArgs,
+  ApplyParametersInstructionData
+> {
+  return combineCodec(
+    getApplyParametersInstructionDataEncoder(),
+    getApplyParametersInstructionDataDecoder()
+  );
+}
+
+export type ApplyParametersInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+};
+
+export function getApplyParametersInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: ApplyParametersInput<TAccountConfig, TAccountNcn>,
+  config?: { programAddress?: TProgramAddress }
+): ApplyParametersInstruction<TProgramAddress, TAccountConfig, TAccountNcn> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+    ],
+    programAddress,
+    data: getApplyParametersInstructionDataEncoder().encode({}),
+  } as ApplyParametersInstruction<TProgramAddress, TAccountConfig, TAccountNcn>;
+
+  return instruction;
+}
+
+export type ParsedApplyParametersInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+  };
+  data: ApplyParametersInstructionData;
+};
+
+export function parseApplyParametersInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedApplyParametersInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+    },
+    data: getApplyParametersInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,290 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const BATCH_INITIALIZE_OPERATOR_SNAPSHOT_DISCRIMINATOR = 35;
+
+export function getBatchInitializeOperatorSnapshotDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    BATCH_INITIALIZE_OPERATOR_SNAPSHOT_DISCRIMINATOR
+  );
+}
+
+export type BatchInitializeOperatorSnapshotInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochMarker extends string | IAccountMeta<string> = string,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochMarker extends string
+        ? ReadonlyAccount<TAccountEpochMarker>
+        : TAccountEpochMarker,
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountEpochSnapshot extends string
+        ? WritableAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type BatchInitializeOperatorSnapshotInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type BatchInitializeOperatorSnapshotInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getBatchInitializeOperatorSnapshotInstructionDataEncoder(): Encoder<BatchInitializeOperatorSnapshotInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: BATCH_INITIALIZE_OPERATOR_SNAPSHOT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getBatchInitializeOperatorSnapshotInstructionDataDecoder(): Decoder<BatchInitializeOperatorSnapshotInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getBatchInitializeOperatorSnapshotInstructionDataCodec(): Codec<
+  BatchInitializeOperatorSnapshotInstructionDataArgs,
+  BatchInitializeOperatorSnapshotInstructionData
+> {
+  return combineCodec(
+    getBatchInitializeOperatorSnapshotInstructionDataEncoder(),
+    getBatchInitializeOperatorSnapshotInstructionDataDecoder()
+  );
+}
+
+export type BatchInitializeOperatorSnapshotInput<
+  TAccountEpochMarker extends string = string,
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  epochMarker: Address<TAccountEpochMarker>;
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  ncn: Address<TAccountNcn>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  accountPayer: Address<TAccountAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: BatchInitializeOperatorSnapshotInstructionDataArgs['epoch'];
+};
+
+export function getBatchInitializeOperatorSnapshotInstruction<
+  TAccountEpochMarker extends string,
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountRestakingConfig extends string,
+  TAccountNcn extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: BatchInitializeOperatorSnapshotInput<
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): BatchInitializeOperatorSnapshotInstruction<
+  TProgramAddress,
+  TAccountEpochMarker,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountRestakingConfig,
+  TAccountNcn,
+  TAccountEpochSnapshot,
+  TAccountAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochMarker: { value: input.epochMarker ?? null, isWritable: false },
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: true },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochMarker),
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.accountPayer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getBatchInitializeOperatorSnapshotInstructionDataEncoder().encode(
+      args as BatchInitializeOperatorSnapshotInstructionDataArgs
+    ),
+  } as BatchInitializeOperatorSnapshotInstruction<
+    TProgramAddress,
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedBatchInitializeOperatorSnapshotInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochMarker: TAccountMetas[0];
+    epochState: TAccountMetas[1];
+    config: TAccountMetas[2];
+    restakingConfig: TAccountMetas[3];
+    ncn: TAccountMetas[4];
+    epochSnapshot: TAccountMetas[5];
+    accountPayer: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+  };
+  data: BatchInitializeOperatorSnapshotInstructionData;
+};
+
+export function parseBatchInitializeOperatorSnapshotInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedBatchInitializeOperatorSnapshotInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochMarker: getNextAccount(),
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      accountPayer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getBatchInitializeOperatorSnapshotInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
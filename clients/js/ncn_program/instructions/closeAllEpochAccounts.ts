@@ -0,0 +1,271 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_ALL_EPOCH_ACCOUNTS_DISCRIMINATOR = 37;
+
+export function getCloseAllEpochAccountsDiscriminatorBytes() {
+  return getU8Encoder().encode(CLOSE_ALL_EPOCH_ACCOUNTS_DISCRIMINATOR);
+}
+
+export type CloseAllEpochAccountsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochMarker extends string | IAccountMeta<string> = string,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountNcnFeeWallet extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochMarker extends string
+        ? WritableAccount<TAccountEpochMarker>
+        : TAccountEpochMarker,
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountNcnFeeWallet extends string
+        ? WritableAccount<TAccountNcnFeeWallet>
+        : TAccountNcnFeeWallet,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseAllEpochAccountsInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type CloseAllEpochAccountsInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getCloseAllEpochAccountsInstructionDataEncoder(): Encoder<CloseAllEpochAccountsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: CLOSE_ALL_EPOCH_ACCOUNTS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCloseAllEpochAccountsInstructionDataDecoder(): Decoder<CloseAllEpochAccountsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getCloseAllEpochAccountsInstructionDataCodec(): Codec<
+  CloseAllEpochAccountsInstructionDataArgs,
+  CloseAllEpochAccountsInstructionData
+> {
+  return combineCodec(
+    getCloseAllEpochAccountsInstructionDataEncoder(),
+    getCloseAllEpochAccountsInstructionDataDecoder()
+  );
+}
+
+export type CloseAllEpochAccountsInput<
+  TAccountEpochMarker extends string = string,
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountNcnFeeWallet extends string = string,
+> = {
+  epochMarker: Address<TAccountEpochMarker>;
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  accountPayer: Address<TAccountAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  ncnFeeWallet: Address<TAccountNcnFeeWallet>;
+  epoch: CloseAllEpochAccountsInstructionDataArgs['epoch'];
+};
+
+export function getCloseAllEpochAccountsInstruction<
+  TAccountEpochMarker extends string,
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TAccountNcnFeeWallet extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: CloseAllEpochAccountsInput<
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAccountPayer,
+    TAccountSystemProgram,
+    TAccountNcnFeeWallet
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CloseAllEpochAccountsInstruction<
+  TProgramAddress,
+  TAccountEpochMarker,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountAccountPayer,
+  TAccountSystemProgram,
+  TAccountNcnFeeWallet
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochMarker: { value: input.epochMarker ?? null, isWritable: true },
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochMarker),
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.accountPayer),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.ncnFeeWallet),
+    ],
+    programAddress,
+    data: getCloseAllEpochAccountsInstructionDataEncoder().encode(
+      args as CloseAllEpochAccountsInstructionDataArgs
+    ),
+  } as CloseAllEpochAccountsInstruction<
+    TProgramAddress,
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAccountPayer,
+    TAccountSystemProgram,
+    TAccountNcnFeeWallet
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseAllEpochAccountsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochMarker: TAccountMetas[0];
+    epochState: TAccountMetas[1];
+    config: TAccountMetas[2];
+    ncn: TAccountMetas[3];
+    accountPayer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+    ncnFeeWallet: TAccountMetas[6];
+  };
+  data: CloseAllEpochAccountsInstructionData;
+};
+
+export function parseCloseAllEpochAccountsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseAllEpochAccountsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochMarker: getNextAccount(),
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      accountPayer: getNextAccount(),
+      systemProgram: getNextAccount(),
+      ncnFeeWallet: getNextAccount(),
+    },
+    data: getCloseAllEpochAccountsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
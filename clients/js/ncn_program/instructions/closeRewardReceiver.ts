@@ -0,0 +1,293 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_REWARD_RECEIVER_DISCRIMINATOR = 54;
+
+export function getCloseRewardReceiverDiscriminatorBytes() {
+  return getU8Encoder().encode(CLOSE_REWARD_RECEIVER_DISCRIMINATOR);
+}
+
+export type CloseRewardReceiverInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochMarker extends string | IAccountMeta<string> = string,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountRewardReceiver extends string | IAccountMeta<string> = string,
+  TAccountNcnFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochMarker extends string
+        ? ReadonlyAccount<TAccountEpochMarker>
+        : TAccountEpochMarker,
+      TAccountEpochState extends string
+        ? ReadonlyAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountRewardRouter extends string
+        ? ReadonlyAccount<TAccountRewardRouter>
+        : TAccountRewardRouter,
+      TAccountRewardReceiver extends string
+        ? WritableAccount<TAccountRewardReceiver>
+        : TAccountRewardReceiver,
+      TAccountNcnFeeWallet extends string
+        ? WritableAccount<TAccountNcnFeeWallet>
+        : TAccountNcnFeeWallet,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseRewardReceiverInstructionData = {
+  discriminator: number;
+  operator: Option<Address>;
+  epoch: bigint;
+};
+
+export type CloseRewardReceiverInstructionDataArgs = {
+  operator: OptionOrNullable<Address>;
+  epoch: number | bigint;
+};
+
+export function getCloseRewardReceiverInstructionDataEncoder(): Encoder<CloseRewardReceiverInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['operator', getOptionEncoder(getAddressEncoder())],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CLOSE_REWARD_RECEIVER_DISCRIMINATOR })
+  );
+}
+
+export function getCloseRewardReceiverInstructionDataDecoder(): Decoder<CloseRewardReceiverInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['operator', getOptionDecoder(getAddressDecoder())],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getCloseRewardReceiverInstructionDataCodec(): Codec<
+  CloseRewardReceiverInstructionDataArgs,
+  CloseRewardReceiverInstructionData
+> {
+  return combineCodec(
+    getCloseRewardReceiverInstructionDataEncoder(),
+    getCloseRewardReceiverInstructionDataDecoder()
+  );
+}
+
+export type CloseRewardReceiverInput<
+  TAccountEpochMarker extends string = string,
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountRewardRouter extends string = string,
+  TAccountRewardReceiver extends string = string,
+  TAccountNcnFeeWallet extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  epochMarker: Address<TAccountEpochMarker>;
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  rewardRouter: Address<TAccountRewardRouter>;
+  rewardReceiver: Address<TAccountRewardReceiver>;
+  ncnFeeWallet: Address<TAccountNcnFeeWallet>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  operator: CloseRewardReceiverInstructionDataArgs['operator'];
+  epoch: CloseRewardReceiverInstructionDataArgs['epoch'];
+};
+
+export function getCloseRewardReceiverInstruction<
+  TAccountEpochMarker extends string,
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountRewardRouter extends string,
+  TAccountRewardReceiver extends string,
+  TAccountNcnFeeWallet extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: CloseRewardReceiverInput<
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountRewardRouter,
+    TAccountRewardReceiver,
+    TAccountNcnFeeWallet,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CloseRewardReceiverInstruction<
+  TProgramAddress,
+  TAccountEpochMarker,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountRewardRouter,
+  TAccountRewardReceiver,
+  TAccountNcnFeeWallet,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochMarker: { value: input.epochMarker ?? null, isWritable: false },
+    epochState: { value: input.epochState ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    rewardRouter: { value: input.rewardRouter ?? null, isWritable: false },
+    rewardReceiver: { value: input.rewardReceiver ?? null, isWritable: true },
+    ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochMarker),
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.rewardRouter),
+      getAccountMeta(accounts.rewardReceiver),
+      getAccountMeta(accounts.ncnFeeWallet),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getCloseRewardReceiverInstructionDataEncoder().encode(
+      args as CloseRewardReceiverInstructionDataArgs
+    ),
+  } as CloseRewardReceiverInstruction<
+    TProgramAddress,
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountRewardRouter,
+    TAccountRewardReceiver,
+    TAccountNcnFeeWallet,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseRewardReceiverInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochMarker: TAccountMetas[0];
+    epochState: TAccountMetas[1];
+    config: TAccountMetas[2];
+    ncn: TAccountMetas[3];
+    rewardRouter: TAccountMetas[4];
+    rewardReceiver: TAccountMetas[5];
+    ncnFeeWallet: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+  };
+  data: CloseRewardReceiverInstructionData;
+};
+
+export function parseCloseRewardReceiverInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseRewardReceiverInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochMarker: getNextAccount(),
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      rewardRouter: getNextAccount(),
+      rewardReceiver: getNextAccount(),
+      ncnFeeWallet: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getCloseRewardReceiverInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
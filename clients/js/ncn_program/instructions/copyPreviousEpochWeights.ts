@@ -0,0 +1,224 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const COPY_PREVIOUS_EPOCH_WEIGHTS_DISCRIMINATOR = 39;
+
+export function getCopyPreviousEpochWeightsDiscriminatorBytes() {
+  return getU8Encoder().encode(COPY_PREVIOUS_EPOCH_WEIGHTS_DISCRIMINATOR);
+}
+
+export type CopyPreviousEpochWeightsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountPreviousWeightTable extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountWeightTable extends string
+        ? WritableAccount<TAccountWeightTable>
+        : TAccountWeightTable,
+      TAccountPreviousWeightTable extends string
+        ? ReadonlyAccount<TAccountPreviousWeightTable>
+        : TAccountPreviousWeightTable,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CopyPreviousEpochWeightsInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type CopyPreviousEpochWeightsInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getCopyPreviousEpochWeightsInstructionDataEncoder(): Encoder<CopyPreviousEpochWeightsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: COPY_PREVIOUS_EPOCH_WEIGHTS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCopyPreviousEpochWeightsInstructionDataDecoder(): Decoder<CopyPreviousEpochWeightsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getCopyPreviousEpochWeightsInstructionDataCodec(): Codec<
+  CopyPreviousEpochWeightsInstructionDataArgs,
+  CopyPreviousEpochWeightsInstructionData
+> {
+  return combineCodec(
+    getCopyPreviousEpochWeightsInstructionDataEncoder(),
+    getCopyPreviousEpochWeightsInstructionDataDecoder()
+  );
+}
+
+export type CopyPreviousEpochWeightsInput<
+  TAccountEpochState extends string = string,
+  TAccountNcn extends string = string,
+  TAccountWeightTable extends string = string,
+  TAccountPreviousWeightTable extends string = string,
+> = {
+  epochState: Address<TAccountEpochState>;
+  ncn: Address<TAccountNcn>;
+  weightTable: Address<TAccountWeightTable>;
+  previousWeightTable: Address<TAccountPreviousWeightTable>;
+  epoch: CopyPreviousEpochWeightsInstructionDataArgs['epoch'];
+};
+
+export function getCopyPreviousEpochWeightsInstruction<
+  TAccountEpochState extends string,
+  TAccountNcn extends string,
+  TAccountWeightTable extends string,
+  TAccountPreviousWeightTable extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: CopyPreviousEpochWeightsInput<
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountPreviousWeightTable
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CopyPreviousEpochWeightsInstruction<
+  TProgramAddress,
+  TAccountEpochState,
+  TAccountNcn,
+  TAccountWeightTable,
+  TAccountPreviousWeightTable
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    weightTable: { value: input.weightTable ?? null, isWritable: true },
+    previousWeightTable: {
+      value: input.previousWeightTable ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.previousWeightTable),
+    ],
+    programAddress,
+    data: getCopyPreviousEpochWeightsInstructionDataEncoder().encode(
+      args as CopyPreviousEpochWeightsInstructionDataArgs
+    ),
+  } as CopyPreviousEpochWeightsInstruction<
+    TProgramAddress,
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountPreviousWeightTable
+  >;
+
+  return instruction;
+}
+
+export type ParsedCopyPreviousEpochWeightsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochState: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    weightTable: TAccountMetas[2];
+    previousWeightTable: TAccountMetas[3];
+  };
+  data: CopyPreviousEpochWeightsInstructionData;
+};
+
+export function parseCopyPreviousEpochWeightsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCopyPreviousEpochWeightsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochState: getNextAccount(),
+      ncn: getNextAccount(),
+      weightTable: getNextAccount(),
+      previousWeightTable: getNextAccount(),
+    },
+    data: getCopyPreviousEpochWeightsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
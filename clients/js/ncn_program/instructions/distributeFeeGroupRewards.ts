@@ -0,0 +1,354 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const DISTRIBUTE_FEE_GROUP_REWARDS_DISCRIMINATOR = 48;
+
+export function getDistributeFeeGroupRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(DISTRIBUTE_FEE_GROUP_REWARDS_DISCRIMINATOR);
+}
+
+export type DistributeFeeGroupRewardsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardReceiver extends string | IAccountMeta<string> = string,
+  TAccountFeeGroupWallet extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountNcnRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountFeeGroupWalletTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountNcnRewardReceiver extends string
+        ? WritableAccount<TAccountNcnRewardReceiver>
+        : TAccountNcnRewardReceiver,
+      TAccountFeeGroupWallet extends string
+        ? WritableAccount<TAccountFeeGroupWallet>
+        : TAccountFeeGroupWallet,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountNcnRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountNcnRewardReceiverTokenAccount>
+        : TAccountNcnRewardReceiverTokenAccount,
+      TAccountFeeGroupWalletTokenAccount extends string
+        ? WritableAccount<TAccountFeeGroupWalletTokenAccount>
+        : TAccountFeeGroupWalletTokenAccount,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeFeeGroupRewardsInstructionData = {
+  discriminator: number;
+  group: number;
+  mint: Option<Address>;
+  epoch: bigint;
+};
+
+export type DistributeFeeGroupRewardsInstructionDataArgs = {
+  group: number;
+  mint: OptionOrNullable<Address>;
+  epoch: number | bigint;
+};
+
+export function getDistributeFeeGroupRewardsInstructionDataEncoder(): Encoder<DistributeFeeGroupRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['group', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: DISTRIBUTE_FEE_GROUP_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getDistributeFeeGroupRewardsInstructionDataDecoder(): Decoder<DistributeFeeGroupRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['group', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getDistributeFeeGroupRewardsInstructionDataCodec(): Codec<
+  DistributeFeeGroupRewardsInstructionDataArgs,
+  DistributeFeeGroupRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeFeeGroupRewardsInstructionDataEncoder(),
+    getDistributeFeeGroupRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeFeeGroupRewardsInput<
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountNcnRewardReceiver extends string = string,
+  TAccountFeeGroupWallet extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountNcnRewardReceiverTokenAccount extends string = string,
+  TAccountFeeGroupWalletTokenAccount extends string = string,
+> = {
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
+  feeGroupWallet: Address<TAccountFeeGroupWallet>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ncnRewardReceiverTokenAccount?: Address<TAccountNcnRewardReceiverTokenAccount>;
+  feeGroupWalletTokenAccount?: Address<TAccountFeeGroupWalletTokenAccount>;
+  group: DistributeFeeGroupRewardsInstructionDataArgs['group'];
+  mint: DistributeFeeGroupRewardsInstructionDataArgs['mint'];
+  epoch: DistributeFeeGroupRewardsInstructionDataArgs['epoch'];
+};
+
+export function getDistributeFeeGroupRewardsInstruction<
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountNcnRewardReceiver extends string,
+  TAccountFeeGroupWallet extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountNcnRewardReceiverTokenAccount extends string,
+  TAccountFeeGroupWalletTokenAccount extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: DistributeFeeGroupRewardsInput<
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountFeeGroupWallet,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountFeeGroupWalletTokenAccount
+  >,
+  config?: { programAddress?: TProgramAddress }
+): DistributeFeeGroupRewardsInstruction<
+  TProgramAddress,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnRewardRouter,
+  TAccountNcnRewardReceiver,
+  TAccountFeeGroupWallet,
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountNcnRewardReceiverTokenAccount,
+  TAccountFeeGroupWalletTokenAccount
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    ncnRewardReceiver: {
+      value: input.ncnRewardReceiver ?? null,
+      isWritable: true,
+    },
+    feeGroupWallet: { value: input.feeGroupWallet ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    ncnRewardReceiverTokenAccount: {
+      value: input.ncnRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    feeGroupWalletTokenAccount: {
+      value: input.feeGroupWalletTokenAccount ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.ncnRewardReceiver),
+      getAccountMeta(accounts.feeGroupWallet),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.ncnRewardReceiverTokenAccount),
+      getAccountMeta(accounts.feeGroupWalletTokenAccount),
+    ],
+    programAddress,
+    data: getDistributeFeeGroupRewardsInstructionDataEncoder().encode(
+      args as DistributeFeeGroupRewardsInstructionDataArgs
+    ),
+  } as DistributeFeeGroupRewardsInstruction<
+    TProgramAddress,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountFeeGroupWallet,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountFeeGroupWalletTokenAccount
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeFeeGroupRewardsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochState: TAccountMetas[0];
+    config: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ncnRewardRouter: TAccountMetas[3];
+    ncnRewardReceiver: TAccountMetas[4];
+    feeGroupWallet: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
+    tokenProgram?: TAccountMetas[7] | undefined;
+    ncnRewardReceiverTokenAccount?: TAccountMetas[8] | undefined;
+    feeGroupWalletTokenAccount?: TAccountMetas[9] | undefined;
+  };
+  data: DistributeFeeGroupRewardsInstructionData;
+};
+
+export function parseDistributeFeeGroupRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeFeeGroupRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 10) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      ncnRewardReceiver: getNextAccount(),
+      feeGroupWallet: getNextAccount(),
+      systemProgram: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      ncnRewardReceiverTokenAccount: getNextOptionalAccount(),
+      feeGroupWalletTokenAccount: getNextOptionalAccount(),
+    },
+    data: getDistributeFeeGroupRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
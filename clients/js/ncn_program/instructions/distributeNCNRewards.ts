@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
@@ -46,6 +52,15 @@ export type DistributeNCNRewardsInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountNcnRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountNcnFeeWalletTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -70,16 +85,27 @@ export type DistributeNCNRewardsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountNcnRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountNcnRewardReceiverTokenAccount>
+        : TAccountNcnRewardReceiverTokenAccount,
+      TAccountNcnFeeWalletTokenAccount extends string
+        ? WritableAccount<TAccountNcnFeeWalletTokenAccount>
+        : TAccountNcnFeeWalletTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
 
 export type DistributeNCNRewardsInstructionData = {
   discriminator: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type DistributeNCNRewardsInstructionDataArgs = {
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -87,6 +113,7 @@ export function getDistributeNCNRewardsInstructionDataEncoder(): Encoder<Distrib
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -99,6 +126,7 @@ export function getDistributeNCNRewardsInstructionDataEncoder(): Encoder<Distrib
 export function getDistributeNCNRewardsInstructionDataDecoder(): Decoder<DistributeNCNRewardsInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -121,6 +149,9 @@ export type DistributeNCNRewardsInput<
   TAccountNcnRewardReceiver extends string = string,
   TAccountNcnFeeWallet extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountNcnRewardReceiverTokenAccount extends string = string,
+  TAccountNcnFeeWalletTokenAccount extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -129,6 +160,10 @@ export type DistributeNCNRewardsInput<
   ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
   ncnFeeWallet: Address<TAccountNcnFeeWallet>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ncnRewardReceiverTokenAccount?: Address<TAccountNcnRewardReceiverTokenAccount>;
+  ncnFeeWalletTokenAccount?: Address<TAccountNcnFeeWalletTokenAccount>;
+  mint: DistributeNCNRewardsInstructionDataArgs['mint'];
   epoch: DistributeNCNRewardsInstructionDataArgs['epoch'];
 };
 
@@ -140,6 +175,9 @@ export function getDistributeNCNRewardsInstruction<
   TAccountNcnRewardReceiver extends string,
   TAccountNcnFeeWallet extends string,
   TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountNcnRewardReceiverTokenAccount extends string,
+  TAccountNcnFeeWalletTokenAccount extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DistributeNCNRewardsInput<
@@ -149,7 +187,10 @@ export function getDistributeNCNRewardsInstruction<
     TAccountNcnRewardRouter,
     TAccountNcnRewardReceiver,
     TAccountNcnFeeWallet,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountNcnFeeWalletTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): DistributeNCNRewardsInstruction<
@@ -160,7 +201,10 @@ export function getDistributeNCNRewardsInstruction<
   TAccountNcnRewardRouter,
   TAccountNcnRewardReceiver,
   TAccountNcnFeeWallet,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountNcnRewardReceiverTokenAccount,
+  TAccountNcnFeeWalletTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -177,6 +221,15 @@ export function getDistributeNCNRewardsInstruction<
     },
     ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    ncnRewardReceiverTokenAccount: {
+      value: input.ncnRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    ncnFeeWalletTokenAccount: {
+      value: input.ncnFeeWalletTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -191,6 +244,10 @@ export function getDistributeNCNRewardsInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -202,6 +259,9 @@ export function getDistributeNCNRewardsInstruction<
       getAccountMeta(accounts.ncnRewardReceiver),
       getAccountMeta(accounts.ncnFeeWallet),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.ncnRewardReceiverTokenAccount),
+      getAccountMeta(accounts.ncnFeeWalletTokenAccount),
     ],
     programAddress,
     data: getDistributeNCNRewardsInstructionDataEncoder().encode(
@@ -215,7 +275,10 @@ export function getDistributeNCNRewardsInstruction<
     TAccountNcnRewardRouter,
     TAccountNcnRewardReceiver,
     TAccountNcnFeeWallet,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountNcnFeeWalletTokenAccount
   >;
 
   return instruction;
@@ -234,6 +297,9 @@ export type ParsedDistributeNCNRewardsInstruction<
     ncnRewardReceiver: TAccountMetas[4];
     ncnFeeWallet: TAccountMetas[5];
     systemProgram: TAccountMetas[6];
+    tokenProgram?: TAccountMetas[7] | undefined;
+    ncnRewardReceiverTokenAccount?: TAccountMetas[8] | undefined;
+    ncnFeeWalletTokenAccount?: TAccountMetas[9] | undefined;
   };
   data: DistributeNCNRewardsInstructionData;
 };
@@ -246,7 +312,7 @@ export function parseDistributeNCNRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDistributeNCNRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -256,6 +322,12 @@ export function parseDistributeNCNRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -266,6 +338,9 @@ export function parseDistributeNCNRewardsInstruction<
       ncnRewardReceiver: getNextAccount(),
       ncnFeeWallet: getNextAccount(),
       systemProgram: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      ncnRewardReceiverTokenAccount: getNextOptionalAccount(),
+      ncnFeeWalletTokenAccount: getNextOptionalAccount(),
     },
     data: getDistributeNCNRewardsInstructionDataDecoder().decode(
       instruction.data
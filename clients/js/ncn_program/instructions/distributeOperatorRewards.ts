@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
@@ -51,6 +57,13 @@ export type DistributeOperatorRewardsInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountOperatorVaultRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountOperatorTokenAccount extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -78,16 +91,27 @@ export type DistributeOperatorRewardsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountOperatorVaultRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountOperatorVaultRewardReceiverTokenAccount>
+        : TAccountOperatorVaultRewardReceiverTokenAccount,
+      TAccountOperatorTokenAccount extends string
+        ? WritableAccount<TAccountOperatorTokenAccount>
+        : TAccountOperatorTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
 
 export type DistributeOperatorRewardsInstructionData = {
   discriminator: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type DistributeOperatorRewardsInstructionDataArgs = {
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -95,6 +119,7 @@ export function getDistributeOperatorRewardsInstructionDataEncoder(): Encoder<Di
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -107,6 +132,7 @@ export function getDistributeOperatorRewardsInstructionDataEncoder(): Encoder<Di
 export function getDistributeOperatorRewardsInstructionDataDecoder(): Decoder<DistributeOperatorRewardsInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -130,6 +156,9 @@ export type DistributeOperatorRewardsInput<
   TAccountOperatorVaultRewardRouter extends string = string,
   TAccountOperatorVaultRewardReceiver extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string = string,
+  TAccountOperatorTokenAccount extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -139,6 +168,10 @@ export type DistributeOperatorRewardsInput<
   operatorVaultRewardRouter: Address<TAccountOperatorVaultRewardRouter>;
   operatorVaultRewardReceiver: Address<TAccountOperatorVaultRewardReceiver>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  operatorVaultRewardReceiverTokenAccount?: Address<TAccountOperatorVaultRewardReceiverTokenAccount>;
+  operatorTokenAccount?: Address<TAccountOperatorTokenAccount>;
+  mint: DistributeOperatorRewardsInstructionDataArgs['mint'];
   epoch: DistributeOperatorRewardsInstructionDataArgs['epoch'];
 };
 
@@ -151,6 +184,9 @@ export function getDistributeOperatorRewardsInstruction<
   TAccountOperatorVaultRewardRouter extends string,
   TAccountOperatorVaultRewardReceiver extends string,
   TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string,
+  TAccountOperatorTokenAccount extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DistributeOperatorRewardsInput<
@@ -161,7 +197,10 @@ export function getDistributeOperatorRewardsInstruction<
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountOperatorTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): DistributeOperatorRewardsInstruction<
@@ -173,7 +212,10 @@ export function getDistributeOperatorRewardsInstruction<
   TAccountOperatorSnapshot,
   TAccountOperatorVaultRewardRouter,
   TAccountOperatorVaultRewardReceiver,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountOperatorVaultRewardReceiverTokenAccount,
+  TAccountOperatorTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -197,6 +239,15 @@ export function getDistributeOperatorRewardsInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    operatorVaultRewardReceiverTokenAccount: {
+      value: input.operatorVaultRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    operatorTokenAccount: {
+      value: input.operatorTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -211,6 +262,10 @@ export function getDistributeOperatorRewardsInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -223,6 +278,9 @@ export function getDistributeOperatorRewardsInstruction<
       getAccountMeta(accounts.operatorVaultRewardRouter),
       getAccountMeta(accounts.operatorVaultRewardReceiver),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.operatorVaultRewardReceiverTokenAccount),
+      getAccountMeta(accounts.operatorTokenAccount),
     ],
     programAddress,
     data: getDistributeOperatorRewardsInstructionDataEncoder().encode(
@@ -237,7 +295,10 @@ export function getDistributeOperatorRewardsInstruction<
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountOperatorTokenAccount
   >;
 
   return instruction;
@@ -257,6 +318,9 @@ export type ParsedDistributeOperatorRewardsInstruction<
     operatorVaultRewardRouter: TAccountMetas[5];
     operatorVaultRewardReceiver: TAccountMetas[6];
     systemProgram: TAccountMetas[7];
+    tokenProgram?: TAccountMetas[8] | undefined;
+    operatorVaultRewardReceiverTokenAccount?: TAccountMetas[9] | undefined;
+    operatorTokenAccount?: TAccountMetas[10] | undefined;
   };
   data: DistributeOperatorRewardsInstructionData;
 };
@@ -269,7 +333,7 @@ export function parseDistributeOperatorRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDistributeOperatorRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -279,6 +343,12 @@ export function parseDistributeOperatorRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -290,6 +360,9 @@ export function parseDistributeOperatorRewardsInstruction<
       operatorVaultRewardRouter: getNextAccount(),
       operatorVaultRewardReceiver: getNextAccount(),
       systemProgram: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      operatorVaultRewardReceiverTokenAccount: getNextOptionalAccount(),
+      operatorTokenAccount: getNextOptionalAccount(),
     },
     data: getDistributeOperatorRewardsInstructionDataDecoder().decode(
       instruction.data
@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
@@ -54,6 +60,15 @@ export type DistributeOperatorVaultRewardRouteInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountNcnRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -84,16 +99,27 @@ export type DistributeOperatorVaultRewardRouteInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountNcnRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountNcnRewardReceiverTokenAccount>
+        : TAccountNcnRewardReceiverTokenAccount,
+      TAccountOperatorVaultRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountOperatorVaultRewardReceiverTokenAccount>
+        : TAccountOperatorVaultRewardReceiverTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
 
 export type DistributeOperatorVaultRewardRouteInstructionData = {
   discriminator: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type DistributeOperatorVaultRewardRouteInstructionDataArgs = {
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -101,6 +127,7 @@ export function getDistributeOperatorVaultRewardRouteInstructionDataEncoder(): E
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -113,6 +140,7 @@ export function getDistributeOperatorVaultRewardRouteInstructionDataEncoder(): E
 export function getDistributeOperatorVaultRewardRouteInstructionDataDecoder(): Decoder<DistributeOperatorVaultRewardRouteInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -137,6 +165,9 @@ export type DistributeOperatorVaultRewardRouteInput<
   TAccountOperatorVaultRewardRouter extends string = string,
   TAccountOperatorVaultRewardReceiver extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountNcnRewardReceiverTokenAccount extends string = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -147,6 +178,10 @@ export type DistributeOperatorVaultRewardRouteInput<
   operatorVaultRewardRouter: Address<TAccountOperatorVaultRewardRouter>;
   operatorVaultRewardReceiver: Address<TAccountOperatorVaultRewardReceiver>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ncnRewardReceiverTokenAccount?: Address<TAccountNcnRewardReceiverTokenAccount>;
+  operatorVaultRewardReceiverTokenAccount?: Address<TAccountOperatorVaultRewardReceiverTokenAccount>;
+  mint: DistributeOperatorVaultRewardRouteInstructionDataArgs['mint'];
   epoch: DistributeOperatorVaultRewardRouteInstructionDataArgs['epoch'];
 };
 
@@ -160,6 +195,9 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
   TAccountOperatorVaultRewardRouter extends string,
   TAccountOperatorVaultRewardReceiver extends string,
   TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountNcnRewardReceiverTokenAccount extends string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DistributeOperatorVaultRewardRouteInput<
@@ -171,7 +209,10 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
     TAccountNcnRewardReceiver,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountOperatorVaultRewardReceiverTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): DistributeOperatorVaultRewardRouteInstruction<
@@ -184,7 +225,10 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
   TAccountNcnRewardReceiver,
   TAccountOperatorVaultRewardRouter,
   TAccountOperatorVaultRewardReceiver,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountNcnRewardReceiverTokenAccount,
+  TAccountOperatorVaultRewardReceiverTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -209,6 +253,15 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    ncnRewardReceiverTokenAccount: {
+      value: input.ncnRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    operatorVaultRewardReceiverTokenAccount: {
+      value: input.operatorVaultRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -223,6 +276,10 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -236,6 +293,9 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
       getAccountMeta(accounts.operatorVaultRewardRouter),
       getAccountMeta(accounts.operatorVaultRewardReceiver),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.ncnRewardReceiverTokenAccount),
+      getAccountMeta(accounts.operatorVaultRewardReceiverTokenAccount),
     ],
     programAddress,
     data: getDistributeOperatorVaultRewardRouteInstructionDataEncoder().encode(
@@ -251,7 +311,10 @@ export function getDistributeOperatorVaultRewardRouteInstruction<
     TAccountNcnRewardReceiver,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountOperatorVaultRewardReceiverTokenAccount
   >;
 
   return instruction;
@@ -272,6 +335,9 @@ export type ParsedDistributeOperatorVaultRewardRouteInstruction<
     operatorVaultRewardRouter: TAccountMetas[6];
     operatorVaultRewardReceiver: TAccountMetas[7];
     systemProgram: TAccountMetas[8];
+    tokenProgram?: TAccountMetas[9] | undefined;
+    ncnRewardReceiverTokenAccount?: TAccountMetas[10] | undefined;
+    operatorVaultRewardReceiverTokenAccount?: TAccountMetas[11] | undefined;
   };
   data: DistributeOperatorVaultRewardRouteInstructionData;
 };
@@ -287,7 +353,7 @@ export function parseDistributeOperatorVaultRewardRouteInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -297,6 +363,12 @@ export function parseDistributeOperatorVaultRewardRouteInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -309,6 +381,9 @@ export function parseDistributeOperatorVaultRewardRouteInstruction<
       operatorVaultRewardRouter: getNextAccount(),
       operatorVaultRewardReceiver: getNextAccount(),
       systemProgram: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      ncnRewardReceiverTokenAccount: getNextOptionalAccount(),
+      operatorVaultRewardReceiverTokenAccount: getNextOptionalAccount(),
     },
     data: getDistributeOperatorVaultRewardRouteInstructionDataDecoder().decode(
       instruction.data
@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
@@ -46,6 +52,15 @@ export type DistributeProtocolRewardsInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountNcnRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountProtocolFeeWalletTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -70,16 +85,27 @@ export type DistributeProtocolRewardsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountNcnRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountNcnRewardReceiverTokenAccount>
+        : TAccountNcnRewardReceiverTokenAccount,
+      TAccountProtocolFeeWalletTokenAccount extends string
+        ? WritableAccount<TAccountProtocolFeeWalletTokenAccount>
+        : TAccountProtocolFeeWalletTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
 
 export type DistributeProtocolRewardsInstructionData = {
   discriminator: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type DistributeProtocolRewardsInstructionDataArgs = {
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -87,6 +113,7 @@ export function getDistributeProtocolRewardsInstructionDataEncoder(): Encoder<Di
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -99,6 +126,7 @@ export function getDistributeProtocolRewardsInstructionDataEncoder(): Encoder<Di
 export function getDistributeProtocolRewardsInstructionDataDecoder(): Decoder<DistributeProtocolRewardsInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -121,6 +149,9 @@ export type DistributeProtocolRewardsInput<
   TAccountNcnRewardReceiver extends string = string,
   TAccountProtocolFeeWallet extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountNcnRewardReceiverTokenAccount extends string = string,
+  TAccountProtocolFeeWalletTokenAccount extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -129,6 +160,10 @@ export type DistributeProtocolRewardsInput<
   ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
   protocolFeeWallet: Address<TAccountProtocolFeeWallet>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ncnRewardReceiverTokenAccount?: Address<TAccountNcnRewardReceiverTokenAccount>;
+  protocolFeeWalletTokenAccount?: Address<TAccountProtocolFeeWalletTokenAccount>;
+  mint: DistributeProtocolRewardsInstructionDataArgs['mint'];
   epoch: DistributeProtocolRewardsInstructionDataArgs['epoch'];
 };
 
@@ -140,6 +175,9 @@ export function getDistributeProtocolRewardsInstruction<
   TAccountNcnRewardReceiver extends string,
   TAccountProtocolFeeWallet extends string,
   TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountNcnRewardReceiverTokenAccount extends string,
+  TAccountProtocolFeeWalletTokenAccount extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DistributeProtocolRewardsInput<
@@ -149,7 +187,10 @@ export function getDistributeProtocolRewardsInstruction<
     TAccountNcnRewardRouter,
     TAccountNcnRewardReceiver,
     TAccountProtocolFeeWallet,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountProtocolFeeWalletTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): DistributeProtocolRewardsInstruction<
@@ -160,7 +201,10 @@ export function getDistributeProtocolRewardsInstruction<
   TAccountNcnRewardRouter,
   TAccountNcnRewardReceiver,
   TAccountProtocolFeeWallet,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountNcnRewardReceiverTokenAccount,
+  TAccountProtocolFeeWalletTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -180,6 +224,15 @@ export function getDistributeProtocolRewardsInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    ncnRewardReceiverTokenAccount: {
+      value: input.ncnRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    protocolFeeWalletTokenAccount: {
+      value: input.protocolFeeWalletTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -194,6 +247,10 @@ export function getDistributeProtocolRewardsInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -205,6 +262,9 @@ export function getDistributeProtocolRewardsInstruction<
       getAccountMeta(accounts.ncnRewardReceiver),
       getAccountMeta(accounts.protocolFeeWallet),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.ncnRewardReceiverTokenAccount),
+      getAccountMeta(accounts.protocolFeeWalletTokenAccount),
     ],
     programAddress,
     data: getDistributeProtocolRewardsInstructionDataEncoder().encode(
@@ -218,7 +278,10 @@ export function getDistributeProtocolRewardsInstruction<
     TAccountNcnRewardRouter,
     TAccountNcnRewardReceiver,
     TAccountProtocolFeeWallet,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountProtocolFeeWalletTokenAccount
   >;
 
   return instruction;
@@ -237,6 +300,9 @@ export type ParsedDistributeProtocolRewardsInstruction<
     ncnRewardReceiver: TAccountMetas[4];
     protocolFeeWallet: TAccountMetas[5];
     systemProgram: TAccountMetas[6];
+    tokenProgram?: TAccountMetas[7] | undefined;
+    ncnRewardReceiverTokenAccount?: TAccountMetas[8] | undefined;
+    protocolFeeWalletTokenAccount?: TAccountMetas[9] | undefined;
   };
   data: DistributeProtocolRewardsInstructionData;
 };
@@ -249,7 +315,7 @@ export function parseDistributeProtocolRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDistributeProtocolRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -259,6 +325,12 @@ export function parseDistributeProtocolRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -269,6 +341,9 @@ export function parseDistributeProtocolRewardsInstruction<
       ncnRewardReceiver: getNextAccount(),
       protocolFeeWallet: getNextAccount(),
       systemProgram: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      ncnRewardReceiverTokenAccount: getNextOptionalAccount(),
+      protocolFeeWalletTokenAccount: getNextOptionalAccount(),
     },
     data: getDistributeProtocolRewardsInstructionDataDecoder().decode(
       instruction.data
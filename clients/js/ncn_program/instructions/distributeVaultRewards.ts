@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
@@ -52,6 +58,17 @@ export type DistributeVaultRewardsInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountVaultRewardRecipient extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountOperatorVaultRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultRewardRecipientTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -82,16 +99,33 @@ export type DistributeVaultRewardsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountVaultRegistry extends string
+        ? ReadonlyAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountVaultRewardRecipient extends string
+        ? WritableAccount<TAccountVaultRewardRecipient>
+        : TAccountVaultRewardRecipient,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountOperatorVaultRewardReceiverTokenAccount extends string
+        ? WritableAccount<TAccountOperatorVaultRewardReceiverTokenAccount>
+        : TAccountOperatorVaultRewardReceiverTokenAccount,
+      TAccountVaultRewardRecipientTokenAccount extends string
+        ? WritableAccount<TAccountVaultRewardRecipientTokenAccount>
+        : TAccountVaultRewardRecipientTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
 
 export type DistributeVaultRewardsInstructionData = {
   discriminator: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type DistributeVaultRewardsInstructionDataArgs = {
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -99,6 +133,7 @@ export function getDistributeVaultRewardsInstructionDataEncoder(): Encoder<Distr
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -111,6 +146,7 @@ export function getDistributeVaultRewardsInstructionDataEncoder(): Encoder<Distr
 export function getDistributeVaultRewardsInstructionDataDecoder(): Decoder<DistributeVaultRewardsInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -135,6 +171,11 @@ export type DistributeVaultRewardsInput<
   TAccountOperatorVaultRewardRouter extends string = string,
   TAccountOperatorVaultRewardReceiver extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountVaultRewardRecipient extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string = string,
+  TAccountVaultRewardRecipientTokenAccount extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -145,6 +186,12 @@ export type DistributeVaultRewardsInput<
   operatorVaultRewardRouter: Address<TAccountOperatorVaultRewardRouter>;
   operatorVaultRewardReceiver: Address<TAccountOperatorVaultRewardReceiver>;
   systemProgram?: Address<TAccountSystemProgram>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  vaultRewardRecipient: Address<TAccountVaultRewardRecipient>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  operatorVaultRewardReceiverTokenAccount?: Address<TAccountOperatorVaultRewardReceiverTokenAccount>;
+  vaultRewardRecipientTokenAccount?: Address<TAccountVaultRewardRecipientTokenAccount>;
+  mint: DistributeVaultRewardsInstructionDataArgs['mint'];
   epoch: DistributeVaultRewardsInstructionDataArgs['epoch'];
 };
 
@@ -158,6 +205,11 @@ export function getDistributeVaultRewardsInstruction<
   TAccountOperatorVaultRewardRouter extends string,
   TAccountOperatorVaultRewardReceiver extends string,
   TAccountSystemProgram extends string,
+  TAccountVaultRegistry extends string,
+  TAccountVaultRewardRecipient extends string,
+  TAccountTokenProgram extends string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string,
+  TAccountVaultRewardRecipientTokenAccount extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DistributeVaultRewardsInput<
@@ -169,7 +221,12 @@ export function getDistributeVaultRewardsInstruction<
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountVaultRegistry,
+    TAccountVaultRewardRecipient,
+    TAccountTokenProgram,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountVaultRewardRecipientTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): DistributeVaultRewardsInstruction<
@@ -182,7 +239,12 @@ export function getDistributeVaultRewardsInstruction<
   TAccountOperatorSnapshot,
   TAccountOperatorVaultRewardRouter,
   TAccountOperatorVaultRewardReceiver,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountVaultRegistry,
+  TAccountVaultRewardRecipient,
+  TAccountTokenProgram,
+  TAccountOperatorVaultRewardReceiverTokenAccount,
+  TAccountVaultRewardRecipientTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -207,6 +269,20 @@ export function getDistributeVaultRewardsInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: false },
+    vaultRewardRecipient: {
+      value: input.vaultRewardRecipient ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    operatorVaultRewardReceiverTokenAccount: {
+      value: input.operatorVaultRewardReceiverTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultRewardRecipientTokenAccount: {
+      value: input.vaultRewardRecipientTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -221,6 +297,10 @@ export function getDistributeVaultRewardsInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -234,6 +314,11 @@ export function getDistributeVaultRewardsInstruction<
       getAccountMeta(accounts.operatorVaultRewardRouter),
       getAccountMeta(accounts.operatorVaultRewardReceiver),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.vaultRewardRecipient),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.operatorVaultRewardReceiverTokenAccount),
+      getAccountMeta(accounts.vaultRewardRecipientTokenAccount),
     ],
     programAddress,
     data: getDistributeVaultRewardsInstructionDataEncoder().encode(
@@ -249,7 +334,12 @@ export function getDistributeVaultRewardsInstruction<
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
     TAccountOperatorVaultRewardReceiver,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountVaultRegistry,
+    TAccountVaultRewardRecipient,
+    TAccountTokenProgram,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountVaultRewardRecipientTokenAccount
   >;
 
   return instruction;
@@ -270,6 +360,11 @@ export type ParsedDistributeVaultRewardsInstruction<
     operatorVaultRewardRouter: TAccountMetas[6];
     operatorVaultRewardReceiver: TAccountMetas[7];
     systemProgram: TAccountMetas[8];
+    vaultRegistry: TAccountMetas[9];
+    vaultRewardRecipient: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
+    operatorVaultRewardReceiverTokenAccount?: TAccountMetas[12] | undefined;
+    vaultRewardRecipientTokenAccount?: TAccountMetas[13] | undefined;
   };
   data: DistributeVaultRewardsInstructionData;
 };
@@ -282,7 +377,7 @@ export function parseDistributeVaultRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDistributeVaultRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -292,6 +387,12 @@ export function parseDistributeVaultRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -304,6 +405,11 @@ export function parseDistributeVaultRewardsInstruction<
       operatorVaultRewardRouter: getNextAccount(),
       operatorVaultRewardReceiver: getNextAccount(),
       systemProgram: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      vaultRewardRecipient: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      operatorVaultRewardReceiverTokenAccount: getNextOptionalAccount(),
+      vaultRewardRecipientTokenAccount: getNextOptionalAccount(),
     },
     data: getDistributeVaultRewardsInstructionDataDecoder().decode(
       instruction.data
@@ -0,0 +1,187 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_CONSENSUS_RESULT_DISCRIMINATOR = 50;
+
+export function getGetConsensusResultDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_CONSENSUS_RESULT_DISCRIMINATOR);
+}
+
+export type GetConsensusResultInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountConsensusResult extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountConsensusResult extends string
+        ? ReadonlyAccount<TAccountConsensusResult>
+        : TAccountConsensusResult,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetConsensusResultInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type GetConsensusResultInstructionDataArgs = { epoch: number | bigint };
+
+export function getGetConsensusResultInstructionDataEncoder(): Encoder<GetConsensusResultInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: GET_CONSENSUS_RESULT_DISCRIMINATOR })
+  );
+}
+
+export function getGetConsensusResultInstructionDataDecoder(): Decoder<GetConsensusResultInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getGetConsensusResultInstructionDataCodec(): Codec<
+  GetConsensusResultInstructionDataArgs,
+  GetConsensusResultInstructionData
+> {
+  return combineCodec(
+    getGetConsensusResultInstructionDataEncoder(),
+    getGetConsensusResultInstructionDataDecoder()
+  );
+}
+
+export type GetConsensusResultInput<
+  TAccountNcn extends string = string,
+  TAccountConsensusResult extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  consensusResult: Address<TAccountConsensusResult>;
+  epoch: GetConsensusResultInstructionDataArgs['epoch'];
+};
+
+export function getGetConsensusResultInstruction<
+  TAccountNcn extends string,
+  TAccountConsensusResult extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: GetConsensusResultInput<TAccountNcn, TAccountConsensusResult>,
+  config?: { programAddress?: TProgramAddress }
+): GetConsensusResultInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountConsensusResult
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    consensusResult: {
+      value: input.consensusResult ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.consensusResult),
+    ],
+    programAddress,
+    data: getGetConsensusResultInstructionDataEncoder().encode(
+      args as GetConsensusResultInstructionDataArgs
+    ),
+  } as GetConsensusResultInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountConsensusResult
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetConsensusResultInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    consensusResult: TAccountMetas[1];
+  };
+  data: GetConsensusResultInstructionData;
+};
+
+export function parseGetConsensusResultInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetConsensusResultInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      consensusResult: getNextAccount(),
+    },
+    data: getGetConsensusResultInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_OPERATOR_REWARD_ROUTE_DISCRIMINATOR = 52;
+
+export function getGetOperatorRewardRouteDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_OPERATOR_REWARD_ROUTE_DISCRIMINATOR);
+}
+
+export type GetOperatorRewardRouteInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnRewardRouter extends string
+        ? ReadonlyAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetOperatorRewardRouteInstructionData = {
+  discriminator: number;
+  operator: Address;
+  epoch: bigint;
+};
+
+export type GetOperatorRewardRouteInstructionDataArgs = {
+  operator: Address;
+  epoch: number | bigint;
+};
+
+export function getGetOperatorRewardRouteInstructionDataEncoder(): Encoder<GetOperatorRewardRouteInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['operator', getAddressEncoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: GET_OPERATOR_REWARD_ROUTE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getGetOperatorRewardRouteInstructionDataDecoder(): Decoder<GetOperatorRewardRouteInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['operator', getAddressDecoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getGetOperatorRewardRouteInstructionDataCodec(): Codec<
+  GetOperatorRewardRouteInstructionDataArgs,
+  GetOperatorRewardRouteInstructionData
+> {
+  return combineCodec(
+    getGetOperatorRewardRouteInstructionDataEncoder(),
+    getGetOperatorRewardRouteInstructionDataDecoder()
+  );
+}
+
+export type GetOperatorRewardRouteInput<
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  operator: GetOperatorRewardRouteInstructionDataArgs['operator'];
+  epoch: GetOperatorRewardRouteInstructionDataArgs['epoch'];
+};
+
+export function getGetOperatorRewardRouteInstruction<
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: GetOperatorRewardRouteInput<TAccountNcn, TAccountNcnRewardRouter>,
+  config?: { programAddress?: TProgramAddress }
+): GetOperatorRewardRouteInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountNcnRewardRouter
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: {
+      value: input.ncnRewardRouter ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+    ],
+    programAddress,
+    data: getGetOperatorRewardRouteInstructionDataEncoder().encode(
+      args as GetOperatorRewardRouteInstructionDataArgs
+    ),
+  } as GetOperatorRewardRouteInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountNcnRewardRouter
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetOperatorRewardRouteInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    ncnRewardRouter: TAccountMetas[1];
+  };
+  data: GetOperatorRewardRouteInstructionData;
+};
+
+export function parseGetOperatorRewardRouteInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetOperatorRewardRouteInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+    },
+    data: getGetOperatorRewardRouteInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_WINNING_BALLOT_DISCRIMINATOR = 51;
+
+export function getGetWinningBallotDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_WINNING_BALLOT_DISCRIMINATOR);
+}
+
+export type GetWinningBallotInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountBallotBox extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountBallotBox extends string
+        ? ReadonlyAccount<TAccountBallotBox>
+        : TAccountBallotBox,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetWinningBallotInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type GetWinningBallotInstructionDataArgs = { epoch: number | bigint };
+
+export function getGetWinningBallotInstructionDataEncoder(): Encoder<GetWinningBallotInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: GET_WINNING_BALLOT_DISCRIMINATOR })
+  );
+}
+
+export function getGetWinningBallotInstructionDataDecoder(): Decoder<GetWinningBallotInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getGetWinningBallotInstructionDataCodec(): Codec<
+  GetWinningBallotInstructionDataArgs,
+  GetWinningBallotInstructionData
+> {
+  return combineCodec(
+    getGetWinningBallotInstructionDataEncoder(),
+    getGetWinningBallotInstructionDataDecoder()
+  );
+}
+
+export type GetWinningBallotInput<
+  TAccountNcn extends string = string,
+  TAccountBallotBox extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  ballotBox: Address<TAccountBallotBox>;
+  epoch: GetWinningBallotInstructionDataArgs['epoch'];
+};
+
+export function getGetWinningBallotInstruction<
+  TAccountNcn extends string,
+  TAccountBallotBox extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: GetWinningBallotInput<TAccountNcn, TAccountBallotBox>,
+  config?: { programAddress?: TProgramAddress }
+): GetWinningBallotInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountBallotBox
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ballotBox: { value: input.ballotBox ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ballotBox),
+    ],
+    programAddress,
+    data: getGetWinningBallotInstructionDataEncoder().encode(
+      args as GetWinningBallotInstructionDataArgs
+    ),
+  } as GetWinningBallotInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountBallotBox
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetWinningBallotInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    ballotBox: TAccountMetas[1];
+  };
+  data: GetWinningBallotInstructionData;
+};
+
+export function parseGetWinningBallotInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetWinningBallotInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      ballotBox: getNextAccount(),
+    },
+    data: getGetWinningBallotInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -6,21 +6,40 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './acquireKeeperLease';
 export * from './adminRegisterStMint';
+export * from './adminSetAdminGovernance';
+export * from './adminSetAdminMultisig';
+export * from './adminSetDefaultWeight';
+export * from './adminSetFeeGroup';
 export * from './adminSetNewAdmin';
 export * from './adminSetParameters';
+export * from './adminSetRewardMint';
 export * from './adminSetStMint';
 export * from './adminSetTieBreaker';
+export * from './adminSetVaultMetadata';
+export * from './adminSetVaultRewardRecipient';
 export * from './adminSetWeight';
+export * from './adminSetWeights';
+export * from './applyParameters';
+export * from './batchInitializeOperatorSnapshot';
 export * from './castVote';
+export * from './closeAllEpochAccounts';
 export * from './closeEpochAccount';
+export * from './closeRewardReceiver';
+export * from './copyPreviousEpochWeights';
+export * from './distributeFeeGroupRewards';
 export * from './distributeNCNRewards';
 export * from './distributeOperatorRewards';
 export * from './distributeOperatorVaultRewardRoute';
 export * from './distributeProtocolRewards';
 export * from './distributeVaultRewards';
+export * from './getConsensusResult';
+export * from './getOperatorRewardRoute';
+export * from './getWinningBallot';
 export * from './initializeBallotBox';
 export * from './initializeConfig';
+export * from './initializeEpochAccounts';
 export * from './initializeEpochSnapshot';
 export * from './initializeEpochState';
 export * from './initializeNCNRewardRouter';
@@ -28,12 +47,17 @@ export * from './initializeOperatorSnapshot';
 export * from './initializeOperatorVaultRewardRouter';
 export * from './initializeVaultRegistry';
 export * from './initializeWeightTable';
+export * from './markEpochSkipped';
 export * from './reallocBallotBox';
 export * from './reallocNCNRewardRouter';
 export * from './reallocVaultRegistry';
 export * from './reallocWeightTable';
 export * from './registerVault';
+export * from './routeAndDistributeNCNRewards';
 export * from './routeNCNRewards';
 export * from './routeOperatorVaultRewards';
 export * from './setEpochWeights';
+export * from './setOperatorMetadata';
+export * from './setWeightFromSwitchboard';
+export * from './shrinkNCNRewardRouter';
 export * from './snapshotVaultOperatorDelegation';
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -81,15 +83,20 @@ export type InitializeBallotBoxInstruction<
 export type InitializeBallotBoxInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
-export type InitializeBallotBoxInstructionDataArgs = { epoch: number | bigint };
+export type InitializeBallotBoxInstructionDataArgs = {
+  epoch: number | bigint;
+  initIfNeeded: boolean;
+};
 
 export function getInitializeBallotBoxInstructionDataEncoder(): Encoder<InitializeBallotBoxInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -102,6 +109,7 @@ export function getInitializeBallotBoxInstructionDataDecoder(): Decoder<Initiali
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -134,6 +142,7 @@ export type InitializeBallotBoxInput<
   systemProgram?: Address<TAccountSystemProgram>;
   consensusResult: Address<TAccountConsensusResult>;
   epoch: InitializeBallotBoxInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeBallotBoxInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeBallotBoxInstruction<
@@ -0,0 +1,344 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_EPOCH_ACCOUNTS_DISCRIMINATOR = 36;
+
+export function getInitializeEpochAccountsDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_EPOCH_ACCOUNTS_DISCRIMINATOR);
+}
+
+export type InitializeEpochAccountsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochMarker extends string | IAccountMeta<string> = string,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountBallotBox extends string | IAccountMeta<string> = string,
+  TAccountConsensusResult extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardReceiver extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochMarker extends string
+        ? ReadonlyAccount<TAccountEpochMarker>
+        : TAccountEpochMarker,
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVaultRegistry extends string
+        ? ReadonlyAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountWeightTable extends string
+        ? WritableAccount<TAccountWeightTable>
+        : TAccountWeightTable,
+      TAccountBallotBox extends string
+        ? WritableAccount<TAccountBallotBox>
+        : TAccountBallotBox,
+      TAccountConsensusResult extends string
+        ? WritableAccount<TAccountConsensusResult>
+        : TAccountConsensusResult,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountNcnRewardReceiver extends string
+        ? WritableAccount<TAccountNcnRewardReceiver>
+        : TAccountNcnRewardReceiver,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeEpochAccountsInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type InitializeEpochAccountsInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getInitializeEpochAccountsInstructionDataEncoder(): Encoder<InitializeEpochAccountsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_EPOCH_ACCOUNTS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeEpochAccountsInstructionDataDecoder(): Decoder<InitializeEpochAccountsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getInitializeEpochAccountsInstructionDataCodec(): Codec<
+  InitializeEpochAccountsInstructionDataArgs,
+  InitializeEpochAccountsInstructionData
+> {
+  return combineCodec(
+    getInitializeEpochAccountsInstructionDataEncoder(),
+    getInitializeEpochAccountsInstructionDataDecoder()
+  );
+}
+
+export type InitializeEpochAccountsInput<
+  TAccountEpochMarker extends string = string,
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountNcn extends string = string,
+  TAccountWeightTable extends string = string,
+  TAccountBallotBox extends string = string,
+  TAccountConsensusResult extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountNcnRewardReceiver extends string = string,
+  TAccountAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  epochMarker: Address<TAccountEpochMarker>;
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  ncn: Address<TAccountNcn>;
+  weightTable: Address<TAccountWeightTable>;
+  ballotBox: Address<TAccountBallotBox>;
+  consensusResult: Address<TAccountConsensusResult>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
+  accountPayer: Address<TAccountAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: InitializeEpochAccountsInstructionDataArgs['epoch'];
+};
+
+export function getInitializeEpochAccountsInstruction<
+  TAccountEpochMarker extends string,
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountVaultRegistry extends string,
+  TAccountNcn extends string,
+  TAccountWeightTable extends string,
+  TAccountBallotBox extends string,
+  TAccountConsensusResult extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountNcnRewardReceiver extends string,
+  TAccountAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: InitializeEpochAccountsInput<
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountVaultRegistry,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountBallotBox,
+    TAccountConsensusResult,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeEpochAccountsInstruction<
+  TProgramAddress,
+  TAccountEpochMarker,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountVaultRegistry,
+  TAccountNcn,
+  TAccountWeightTable,
+  TAccountBallotBox,
+  TAccountConsensusResult,
+  TAccountNcnRewardRouter,
+  TAccountNcnRewardReceiver,
+  TAccountAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochMarker: { value: input.epochMarker ?? null, isWritable: false },
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    weightTable: { value: input.weightTable ?? null, isWritable: true },
+    ballotBox: { value: input.ballotBox ?? null, isWritable: true },
+    consensusResult: { value: input.consensusResult ?? null, isWritable: true },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    ncnRewardReceiver: {
+      value: input.ncnRewardReceiver ?? null,
+      isWritable: true,
+    },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochMarker),
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.ballotBox),
+      getAccountMeta(accounts.consensusResult),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.ncnRewardReceiver),
+      getAccountMeta(accounts.accountPayer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeEpochAccountsInstructionDataEncoder().encode(
+      args as InitializeEpochAccountsInstructionDataArgs
+    ),
+  } as InitializeEpochAccountsInstruction<
+    TProgramAddress,
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountVaultRegistry,
+    TAccountNcn,
+    TAccountWeightTable,
+    TAccountBallotBox,
+    TAccountConsensusResult,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeEpochAccountsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochMarker: TAccountMetas[0];
+    epochState: TAccountMetas[1];
+    config: TAccountMetas[2];
+    vaultRegistry: TAccountMetas[3];
+    ncn: TAccountMetas[4];
+    weightTable: TAccountMetas[5];
+    ballotBox: TAccountMetas[6];
+    consensusResult: TAccountMetas[7];
+    ncnRewardRouter: TAccountMetas[8];
+    ncnRewardReceiver: TAccountMetas[9];
+    accountPayer: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+  };
+  data: InitializeEpochAccountsInstructionData;
+};
+
+export function parseInitializeEpochAccountsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeEpochAccountsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 12) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochMarker: getNextAccount(),
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      ncn: getNextAccount(),
+      weightTable: getNextAccount(),
+      ballotBox: getNextAccount(),
+      consensusResult: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      ncnRewardReceiver: getNextAccount(),
+      accountPayer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeEpochAccountsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -81,10 +83,12 @@ export type InitializeEpochSnapshotInstruction<
 export type InitializeEpochSnapshotInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeEpochSnapshotInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeEpochSnapshotInstructionDataEncoder(): Encoder<InitializeEpochSnapshotInstructionDataArgs> {
@@ -92,6 +96,7 @@ export function getInitializeEpochSnapshotInstructionDataEncoder(): Encoder<Init
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -104,6 +109,7 @@ export function getInitializeEpochSnapshotInstructionDataDecoder(): Decoder<Init
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -136,6 +142,7 @@ export type InitializeEpochSnapshotInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeEpochSnapshotInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeEpochSnapshotInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeEpochSnapshotInstruction<
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -73,10 +75,12 @@ export type InitializeEpochStateInstruction<
 export type InitializeEpochStateInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeEpochStateInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeEpochStateInstructionDataEncoder(): Encoder<InitializeEpochStateInstructionDataArgs> {
@@ -84,6 +88,7 @@ export function getInitializeEpochStateInstructionDataEncoder(): Encoder<Initial
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -96,6 +101,7 @@ export function getInitializeEpochStateInstructionDataDecoder(): Decoder<Initial
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -124,6 +130,7 @@ export type InitializeEpochStateInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeEpochStateInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeEpochStateInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeEpochStateInstruction<
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -77,10 +79,12 @@ export type InitializeNCNRewardRouterInstruction<
 export type InitializeNCNRewardRouterInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeNCNRewardRouterInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeNCNRewardRouterInstructionDataEncoder(): Encoder<InitializeNCNRewardRouterInstructionDataArgs> {
@@ -88,6 +92,7 @@ export function getInitializeNCNRewardRouterInstructionDataEncoder(): Encoder<In
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -100,6 +105,7 @@ export function getInitializeNCNRewardRouterInstructionDataDecoder(): Decoder<In
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -130,6 +136,7 @@ export type InitializeNCNRewardRouterInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeNCNRewardRouterInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeNCNRewardRouterInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeNCNRewardRouterInstruction<
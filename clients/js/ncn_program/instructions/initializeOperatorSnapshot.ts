@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -93,10 +95,12 @@ export type InitializeOperatorSnapshotInstruction<
 export type InitializeOperatorSnapshotInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeOperatorSnapshotInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeOperatorSnapshotInstructionDataEncoder(): Encoder<InitializeOperatorSnapshotInstructionDataArgs> {
@@ -104,6 +108,7 @@ export function getInitializeOperatorSnapshotInstructionDataEncoder(): Encoder<I
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -116,6 +121,7 @@ export function getInitializeOperatorSnapshotInstructionDataDecoder(): Decoder<I
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -154,6 +160,7 @@ export type InitializeOperatorSnapshotInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeOperatorSnapshotInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeOperatorSnapshotInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeOperatorSnapshotInstruction<
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -91,10 +93,12 @@ export type InitializeOperatorVaultRewardRouterInstruction<
 export type InitializeOperatorVaultRewardRouterInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeOperatorVaultRewardRouterInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeOperatorVaultRewardRouterInstructionDataEncoder(): Encoder<InitializeOperatorVaultRewardRouterInstructionDataArgs> {
@@ -102,6 +106,7 @@ export function getInitializeOperatorVaultRewardRouterInstructionDataEncoder():
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -114,6 +119,7 @@ export function getInitializeOperatorVaultRewardRouterInstructionDataDecoder():
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -148,6 +154,7 @@ export type InitializeOperatorVaultRewardRouterInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeOperatorVaultRewardRouterInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeOperatorVaultRewardRouterInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeOperatorVaultRewardRouterInstruction<
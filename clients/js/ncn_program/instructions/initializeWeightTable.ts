@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -77,10 +79,12 @@ export type InitializeWeightTableInstruction<
 export type InitializeWeightTableInstructionData = {
   discriminator: number;
   epoch: bigint;
+  initIfNeeded: boolean;
 };
 
 export type InitializeWeightTableInstructionDataArgs = {
   epoch: number | bigint;
+  initIfNeeded: boolean;
 };
 
 export function getInitializeWeightTableInstructionDataEncoder(): Encoder<InitializeWeightTableInstructionDataArgs> {
@@ -88,6 +92,7 @@ export function getInitializeWeightTableInstructionDataEncoder(): Encoder<Initia
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['epoch', getU64Encoder()],
+      ['initIfNeeded', getBoolEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -100,6 +105,7 @@ export function getInitializeWeightTableInstructionDataDecoder(): Decoder<Initia
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['epoch', getU64Decoder()],
+    ['initIfNeeded', getBoolDecoder()],
   ]);
 }
 
@@ -130,6 +136,7 @@ export type InitializeWeightTableInput<
   accountPayer: Address<TAccountAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   epoch: InitializeWeightTableInstructionDataArgs['epoch'];
+  initIfNeeded: InitializeWeightTableInstructionDataArgs['initIfNeeded'];
 };
 
 export function getInitializeWeightTableInstruction<
@@ -0,0 +1,250 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const MARK_EPOCH_SKIPPED_DISCRIMINATOR = 46;
+
+export function getMarkEpochSkippedDiscriminatorBytes() {
+  return getU8Encoder().encode(MARK_EPOCH_SKIPPED_DISCRIMINATOR);
+}
+
+export type MarkEpochSkippedInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochMarker extends string | IAccountMeta<string> = string,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochMarker extends string
+        ? WritableAccount<TAccountEpochMarker>
+        : TAccountEpochMarker,
+      TAccountEpochState extends string
+        ? ReadonlyAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MarkEpochSkippedInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type MarkEpochSkippedInstructionDataArgs = { epoch: number | bigint };
+
+export function getMarkEpochSkippedInstructionDataEncoder(): Encoder<MarkEpochSkippedInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: MARK_EPOCH_SKIPPED_DISCRIMINATOR })
+  );
+}
+
+export function getMarkEpochSkippedInstructionDataDecoder(): Decoder<MarkEpochSkippedInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getMarkEpochSkippedInstructionDataCodec(): Codec<
+  MarkEpochSkippedInstructionDataArgs,
+  MarkEpochSkippedInstructionData
+> {
+  return combineCodec(
+    getMarkEpochSkippedInstructionDataEncoder(),
+    getMarkEpochSkippedInstructionDataDecoder()
+  );
+}
+
+export type MarkEpochSkippedInput<
+  TAccountEpochMarker extends string = string,
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  epochMarker: Address<TAccountEpochMarker>;
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  accountPayer: Address<TAccountAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: MarkEpochSkippedInstructionDataArgs['epoch'];
+};
+
+export function getMarkEpochSkippedInstruction<
+  TAccountEpochMarker extends string,
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: MarkEpochSkippedInput<
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): MarkEpochSkippedInstruction<
+  TProgramAddress,
+  TAccountEpochMarker,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochMarker: { value: input.epochMarker ?? null, isWritable: true },
+    epochState: { value: input.epochState ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochMarker),
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.accountPayer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getMarkEpochSkippedInstructionDataEncoder().encode(
+      args as MarkEpochSkippedInstructionDataArgs
+    ),
+  } as MarkEpochSkippedInstruction<
+    TProgramAddress,
+    TAccountEpochMarker,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedMarkEpochSkippedInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochMarker: TAccountMetas[0];
+    epochState: TAccountMetas[1];
+    config: TAccountMetas[2];
+    ncn: TAccountMetas[3];
+    accountPayer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+  };
+  data: MarkEpochSkippedInstructionData;
+};
+
+export function parseMarkEpochSkippedInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMarkEpochSkippedInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochMarker: getNextAccount(),
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      accountPayer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getMarkEpochSkippedInstructionDataDecoder().decode(instruction.data),
+  };
+}
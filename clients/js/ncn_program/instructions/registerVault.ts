@@ -40,6 +40,8 @@ export type RegisterVaultInstruction<
   TAccountNcn extends string | IAccountMeta<string> = string,
   TAccountVault extends string | IAccountMeta<string> = string,
   TAccountNcnVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountStMint extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -58,6 +60,12 @@ export type RegisterVaultInstruction<
       TAccountNcnVaultTicket extends string
         ? ReadonlyAccount<TAccountNcnVaultTicket>
         : TAccountNcnVaultTicket,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountStMint extends string
+        ? ReadonlyAccount<TAccountStMint>
+        : TAccountStMint,
       ...TRemainingAccounts,
     ]
   >;
@@ -93,12 +101,16 @@ export type RegisterVaultInput<
   TAccountNcn extends string = string,
   TAccountVault extends string = string,
   TAccountNcnVaultTicket extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountStMint extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vaultRegistry: Address<TAccountVaultRegistry>;
   ncn: Address<TAccountNcn>;
   vault: Address<TAccountVault>;
   ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  stMint: Address<TAccountStMint>;
 };
 
 export function getRegisterVaultInstruction<
@@ -107,6 +119,8 @@ export function getRegisterVaultInstruction<
   TAccountNcn extends string,
   TAccountVault extends string,
   TAccountNcnVaultTicket extends string,
+  TAccountRestakingConfig extends string,
+  TAccountStMint extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: RegisterVaultInput<
@@ -114,7 +128,9 @@ export function getRegisterVaultInstruction<
     TAccountVaultRegistry,
     TAccountNcn,
     TAccountVault,
-    TAccountNcnVaultTicket
+    TAccountNcnVaultTicket,
+    TAccountRestakingConfig,
+    TAccountStMint
   >,
   config?: { programAddress?: TProgramAddress }
 ): RegisterVaultInstruction<
@@ -123,7 +139,9 @@ export function getRegisterVaultInstruction<
   TAccountVaultRegistry,
   TAccountNcn,
   TAccountVault,
-  TAccountNcnVaultTicket
+  TAccountNcnVaultTicket,
+  TAccountRestakingConfig,
+  TAccountStMint
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -135,6 +153,11 @@ export function getRegisterVaultInstruction<
     ncn: { value: input.ncn ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: false },
     ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    stMint: { value: input.stMint ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -149,6 +172,8 @@ export function getRegisterVaultInstruction<
       getAccountMeta(accounts.ncn),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.stMint),
     ],
     programAddress,
     data: getRegisterVaultInstructionDataEncoder().encode({}),
@@ -158,7 +183,9 @@ export function getRegisterVaultInstruction<
     TAccountVaultRegistry,
     TAccountNcn,
     TAccountVault,
-    TAccountNcnVaultTicket
+    TAccountNcnVaultTicket,
+    TAccountRestakingConfig,
+    TAccountStMint
   >;
 
   return instruction;
@@ -175,6 +202,8 @@ export type ParsedRegisterVaultInstruction<
     ncn: TAccountMetas[2];
     vault: TAccountMetas[3];
     ncnVaultTicket: TAccountMetas[4];
+    restakingConfig: TAccountMetas[5];
+    stMint: TAccountMetas[6];
   };
   data: RegisterVaultInstructionData;
 };
@@ -187,7 +216,7 @@ export function parseRegisterVaultInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRegisterVaultInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -205,6 +234,8 @@ export function parseRegisterVaultInstruction<
       ncn: getNextAccount(),
       vault: getNextAccount(),
       ncnVaultTicket: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      stMint: getNextAccount(),
     },
     data: getRegisterVaultInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,328 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ROUTE_AND_DISTRIBUTE_N_C_N_REWARDS_DISCRIMINATOR = 33;
+
+export function getRouteAndDistributeNCNRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    ROUTE_AND_DISTRIBUTE_N_C_N_REWARDS_DISCRIMINATOR
+  );
+}
+
+export type RouteAndDistributeNCNRewardsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountBallotBox extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardReceiver extends string | IAccountMeta<string> = string,
+  TAccountProtocolFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountNcnFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountEpochSnapshot extends string
+        ? ReadonlyAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountBallotBox extends string
+        ? ReadonlyAccount<TAccountBallotBox>
+        : TAccountBallotBox,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountNcnRewardReceiver extends string
+        ? WritableAccount<TAccountNcnRewardReceiver>
+        : TAccountNcnRewardReceiver,
+      TAccountProtocolFeeWallet extends string
+        ? WritableAccount<TAccountProtocolFeeWallet>
+        : TAccountProtocolFeeWallet,
+      TAccountNcnFeeWallet extends string
+        ? WritableAccount<TAccountNcnFeeWallet>
+        : TAccountNcnFeeWallet,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RouteAndDistributeNCNRewardsInstructionData = {
+  discriminator: number;
+  maxIterations: number;
+  epoch: bigint;
+};
+
+export type RouteAndDistributeNCNRewardsInstructionDataArgs = {
+  maxIterations: number;
+  epoch: number | bigint;
+};
+
+export function getRouteAndDistributeNCNRewardsInstructionDataEncoder(): Encoder<RouteAndDistributeNCNRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxIterations', getU16Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ROUTE_AND_DISTRIBUTE_N_C_N_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getRouteAndDistributeNCNRewardsInstructionDataDecoder(): Decoder<RouteAndDistributeNCNRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxIterations', getU16Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getRouteAndDistributeNCNRewardsInstructionDataCodec(): Codec<
+  RouteAndDistributeNCNRewardsInstructionDataArgs,
+  RouteAndDistributeNCNRewardsInstructionData
+> {
+  return combineCodec(
+    getRouteAndDistributeNCNRewardsInstructionDataEncoder(),
+    getRouteAndDistributeNCNRewardsInstructionDataDecoder()
+  );
+}
+
+export type RouteAndDistributeNCNRewardsInput<
+  TAccountEpochState extends string = string,
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountBallotBox extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountNcnRewardReceiver extends string = string,
+  TAccountProtocolFeeWallet extends string = string,
+  TAccountNcnFeeWallet extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  epochState: Address<TAccountEpochState>;
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  ballotBox: Address<TAccountBallotBox>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
+  protocolFeeWallet: Address<TAccountProtocolFeeWallet>;
+  ncnFeeWallet: Address<TAccountNcnFeeWallet>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  maxIterations: RouteAndDistributeNCNRewardsInstructionDataArgs['maxIterations'];
+  epoch: RouteAndDistributeNCNRewardsInstructionDataArgs['epoch'];
+};
+
+export function getRouteAndDistributeNCNRewardsInstruction<
+  TAccountEpochState extends string,
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountBallotBox extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountNcnRewardReceiver extends string,
+  TAccountProtocolFeeWallet extends string,
+  TAccountNcnFeeWallet extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: RouteAndDistributeNCNRewardsInput<
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountBallotBox,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountProtocolFeeWallet,
+    TAccountNcnFeeWallet,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): RouteAndDistributeNCNRewardsInstruction<
+  TProgramAddress,
+  TAccountEpochState,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountEpochSnapshot,
+  TAccountBallotBox,
+  TAccountNcnRewardRouter,
+  TAccountNcnRewardReceiver,
+  TAccountProtocolFeeWallet,
+  TAccountNcnFeeWallet,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: false },
+    ballotBox: { value: input.ballotBox ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    ncnRewardReceiver: {
+      value: input.ncnRewardReceiver ?? null,
+      isWritable: true,
+    },
+    protocolFeeWallet: {
+      value: input.protocolFeeWallet ?? null,
+      isWritable: true,
+    },
+    ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.ballotBox),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.ncnRewardReceiver),
+      getAccountMeta(accounts.protocolFeeWallet),
+      getAccountMeta(accounts.ncnFeeWallet),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getRouteAndDistributeNCNRewardsInstructionDataEncoder().encode(
+      args as RouteAndDistributeNCNRewardsInstructionDataArgs
+    ),
+  } as RouteAndDistributeNCNRewardsInstruction<
+    TProgramAddress,
+    TAccountEpochState,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountBallotBox,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardReceiver,
+    TAccountProtocolFeeWallet,
+    TAccountNcnFeeWallet,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedRouteAndDistributeNCNRewardsInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochState: TAccountMetas[0];
+    config: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    epochSnapshot: TAccountMetas[3];
+    ballotBox: TAccountMetas[4];
+    ncnRewardRouter: TAccountMetas[5];
+    ncnRewardReceiver: TAccountMetas[6];
+    protocolFeeWallet: TAccountMetas[7];
+    ncnFeeWallet: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+  };
+  data: RouteAndDistributeNCNRewardsInstructionData;
+};
+
+export function parseRouteAndDistributeNCNRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRouteAndDistributeNCNRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 10) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochState: getNextAccount(),
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      ballotBox: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      ncnRewardReceiver: getNextAccount(),
+      protocolFeeWallet: getNextAccount(),
+      ncnFeeWallet: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getRouteAndDistributeNCNRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -22,11 +26,16 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -46,6 +55,13 @@ export type RouteNCNRewardsInstruction<
   TAccountBallotBox extends string | IAccountMeta<string> = string,
   TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
   TAccountNcnRewardReceiver extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountCranker extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -70,6 +86,16 @@ export type RouteNCNRewardsInstruction<
       TAccountNcnRewardReceiver extends string
         ? WritableAccount<TAccountNcnRewardReceiver>
         : TAccountNcnRewardReceiver,
+      TAccountNcnRewardReceiverTokenAccount extends string
+        ? ReadonlyAccount<TAccountNcnRewardReceiverTokenAccount>
+        : TAccountNcnRewardReceiverTokenAccount,
+      TAccountCranker extends string
+        ? WritableSignerAccount<TAccountCranker> &
+            IAccountSignerMeta<TAccountCranker>
+        : TAccountCranker,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -77,11 +103,13 @@ export type RouteNCNRewardsInstruction<
 export type RouteNCNRewardsInstructionData = {
   discriminator: number;
   maxIterations: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type RouteNCNRewardsInstructionDataArgs = {
   maxIterations: number;
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -90,6 +118,7 @@ export function getRouteNCNRewardsInstructionDataEncoder(): Encoder<RouteNCNRewa
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['maxIterations', getU16Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: ROUTE_N_C_N_REWARDS_DISCRIMINATOR })
@@ -100,6 +129,7 @@ export function getRouteNCNRewardsInstructionDataDecoder(): Decoder<RouteNCNRewa
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['maxIterations', getU16Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -122,6 +152,9 @@ export type RouteNCNRewardsInput<
   TAccountBallotBox extends string = string,
   TAccountNcnRewardRouter extends string = string,
   TAccountNcnRewardReceiver extends string = string,
+  TAccountNcnRewardReceiverTokenAccount extends string = string,
+  TAccountCranker extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   config: Address<TAccountConfig>;
@@ -130,7 +163,12 @@ export type RouteNCNRewardsInput<
   ballotBox: Address<TAccountBallotBox>;
   ncnRewardRouter: Address<TAccountNcnRewardRouter>;
   ncnRewardReceiver: Address<TAccountNcnRewardReceiver>;
+  ncnRewardReceiverTokenAccount?: Address<TAccountNcnRewardReceiverTokenAccount>;
+  /** Paid the cranker fee in SOL, routing a reward mint pays no fee */
+  cranker?: TransactionSigner<TAccountCranker>;
+  systemProgram?: Address<TAccountSystemProgram>;
   maxIterations: RouteNCNRewardsInstructionDataArgs['maxIterations'];
+  mint: RouteNCNRewardsInstructionDataArgs['mint'];
   epoch: RouteNCNRewardsInstructionDataArgs['epoch'];
 };
 
@@ -142,6 +180,9 @@ export function getRouteNCNRewardsInstruction<
   TAccountBallotBox extends string,
   TAccountNcnRewardRouter extends string,
   TAccountNcnRewardReceiver extends string,
+  TAccountNcnRewardReceiverTokenAccount extends string,
+  TAccountCranker extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: RouteNCNRewardsInput<
@@ -151,7 +192,10 @@ export function getRouteNCNRewardsInstruction<
     TAccountEpochSnapshot,
     TAccountBallotBox,
     TAccountNcnRewardRouter,
-    TAccountNcnRewardReceiver
+    TAccountNcnRewardReceiver,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountCranker,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RouteNCNRewardsInstruction<
@@ -162,7 +206,10 @@ export function getRouteNCNRewardsInstruction<
   TAccountEpochSnapshot,
   TAccountBallotBox,
   TAccountNcnRewardRouter,
-  TAccountNcnRewardReceiver
+  TAccountNcnRewardReceiver,
+  TAccountNcnRewardReceiverTokenAccount,
+  TAccountCranker,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -179,6 +226,12 @@ export function getRouteNCNRewardsInstruction<
       value: input.ncnRewardReceiver ?? null,
       isWritable: true,
     },
+    ncnRewardReceiverTokenAccount: {
+      value: input.ncnRewardReceiverTokenAccount ?? null,
+      isWritable: false,
+    },
+    cranker: { value: input.cranker ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -188,6 +241,12 @@ export function getRouteNCNRewardsInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
@@ -198,6 +257,9 @@ export function getRouteNCNRewardsInstruction<
       getAccountMeta(accounts.ballotBox),
       getAccountMeta(accounts.ncnRewardRouter),
       getAccountMeta(accounts.ncnRewardReceiver),
+      getAccountMeta(accounts.ncnRewardReceiverTokenAccount),
+      getAccountMeta(accounts.cranker),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getRouteNCNRewardsInstructionDataEncoder().encode(
@@ -211,7 +273,10 @@ export function getRouteNCNRewardsInstruction<
     TAccountEpochSnapshot,
     TAccountBallotBox,
     TAccountNcnRewardRouter,
-    TAccountNcnRewardReceiver
+    TAccountNcnRewardReceiver,
+    TAccountNcnRewardReceiverTokenAccount,
+    TAccountCranker,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -230,6 +295,10 @@ export type ParsedRouteNCNRewardsInstruction<
     ballotBox: TAccountMetas[4];
     ncnRewardRouter: TAccountMetas[5];
     ncnRewardReceiver: TAccountMetas[6];
+    ncnRewardReceiverTokenAccount?: TAccountMetas[7] | undefined;
+    /** Paid the cranker fee in SOL, routing a reward mint pays no fee */
+    cranker?: TAccountMetas[8] | undefined;
+    systemProgram?: TAccountMetas[9] | undefined;
   };
   data: RouteNCNRewardsInstructionData;
 };
@@ -242,7 +311,7 @@ export function parseRouteNCNRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRouteNCNRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -252,6 +321,12 @@ export function parseRouteNCNRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -262,6 +337,9 @@ export function parseRouteNCNRewardsInstruction<
       ballotBox: getNextAccount(),
       ncnRewardRouter: getNextAccount(),
       ncnRewardReceiver: getNextAccount(),
+      ncnRewardReceiverTokenAccount: getNextOptionalAccount(),
+      cranker: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getRouteNCNRewardsInstructionDataDecoder().decode(instruction.data),
   };
@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -22,11 +26,16 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -49,6 +58,14 @@ export type RouteOperatorVaultRewardsInstruction<
   TAccountOperatorVaultRewardReceiver extends
     | string
     | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountCranker extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -70,6 +87,19 @@ export type RouteOperatorVaultRewardsInstruction<
       TAccountOperatorVaultRewardReceiver extends string
         ? WritableAccount<TAccountOperatorVaultRewardReceiver>
         : TAccountOperatorVaultRewardReceiver,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountOperatorVaultRewardReceiverTokenAccount extends string
+        ? ReadonlyAccount<TAccountOperatorVaultRewardReceiverTokenAccount>
+        : TAccountOperatorVaultRewardReceiverTokenAccount,
+      TAccountCranker extends string
+        ? WritableSignerAccount<TAccountCranker> &
+            IAccountSignerMeta<TAccountCranker>
+        : TAccountCranker,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -77,11 +107,13 @@ export type RouteOperatorVaultRewardsInstruction<
 export type RouteOperatorVaultRewardsInstructionData = {
   discriminator: number;
   maxIterations: number;
+  mint: Option<Address>;
   epoch: bigint;
 };
 
 export type RouteOperatorVaultRewardsInstructionDataArgs = {
   maxIterations: number;
+  mint: OptionOrNullable<Address>;
   epoch: number | bigint;
 };
 
@@ -90,6 +122,7 @@ export function getRouteOperatorVaultRewardsInstructionDataEncoder(): Encoder<Ro
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['maxIterations', getU16Encoder()],
+      ['mint', getOptionEncoder(getAddressEncoder())],
       ['epoch', getU64Encoder()],
     ]),
     (value) => ({
@@ -103,6 +136,7 @@ export function getRouteOperatorVaultRewardsInstructionDataDecoder(): Decoder<Ro
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['maxIterations', getU16Decoder()],
+    ['mint', getOptionDecoder(getAddressDecoder())],
     ['epoch', getU64Decoder()],
   ]);
 }
@@ -124,6 +158,10 @@ export type RouteOperatorVaultRewardsInput<
   TAccountOperatorSnapshot extends string = string,
   TAccountOperatorVaultRewardRouter extends string = string,
   TAccountOperatorVaultRewardReceiver extends string = string,
+  TAccountConfig extends string = string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string = string,
+  TAccountCranker extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   ncn: Address<TAccountNcn>;
@@ -131,7 +169,12 @@ export type RouteOperatorVaultRewardsInput<
   operatorSnapshot: Address<TAccountOperatorSnapshot>;
   operatorVaultRewardRouter: Address<TAccountOperatorVaultRewardRouter>;
   operatorVaultRewardReceiver: Address<TAccountOperatorVaultRewardReceiver>;
+  config?: Address<TAccountConfig>;
+  operatorVaultRewardReceiverTokenAccount?: Address<TAccountOperatorVaultRewardReceiverTokenAccount>;
+  cranker?: TransactionSigner<TAccountCranker>;
+  systemProgram?: Address<TAccountSystemProgram>;
   maxIterations: RouteOperatorVaultRewardsInstructionDataArgs['maxIterations'];
+  mint: RouteOperatorVaultRewardsInstructionDataArgs['mint'];
   epoch: RouteOperatorVaultRewardsInstructionDataArgs['epoch'];
 };
 
@@ -142,6 +185,10 @@ export function getRouteOperatorVaultRewardsInstruction<
   TAccountOperatorSnapshot extends string,
   TAccountOperatorVaultRewardRouter extends string,
   TAccountOperatorVaultRewardReceiver extends string,
+  TAccountConfig extends string,
+  TAccountOperatorVaultRewardReceiverTokenAccount extends string,
+  TAccountCranker extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: RouteOperatorVaultRewardsInput<
@@ -150,7 +197,11 @@ export function getRouteOperatorVaultRewardsInstruction<
     TAccountOperator,
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
-    TAccountOperatorVaultRewardReceiver
+    TAccountOperatorVaultRewardReceiver,
+    TAccountConfig,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountCranker,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RouteOperatorVaultRewardsInstruction<
@@ -160,7 +211,11 @@ export function getRouteOperatorVaultRewardsInstruction<
   TAccountOperator,
   TAccountOperatorSnapshot,
   TAccountOperatorVaultRewardRouter,
-  TAccountOperatorVaultRewardReceiver
+  TAccountOperatorVaultRewardReceiver,
+  TAccountConfig,
+  TAccountOperatorVaultRewardReceiverTokenAccount,
+  TAccountCranker,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -182,6 +237,13 @@ export function getRouteOperatorVaultRewardsInstruction<
       value: input.operatorVaultRewardReceiver ?? null,
       isWritable: true,
     },
+    config: { value: input.config ?? null, isWritable: false },
+    operatorVaultRewardReceiverTokenAccount: {
+      value: input.operatorVaultRewardReceiverTokenAccount ?? null,
+      isWritable: false,
+    },
+    cranker: { value: input.cranker ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -191,6 +253,12 @@ export function getRouteOperatorVaultRewardsInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
@@ -200,6 +268,10 @@ export function getRouteOperatorVaultRewardsInstruction<
       getAccountMeta(accounts.operatorSnapshot),
       getAccountMeta(accounts.operatorVaultRewardRouter),
       getAccountMeta(accounts.operatorVaultRewardReceiver),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operatorVaultRewardReceiverTokenAccount),
+      getAccountMeta(accounts.cranker),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getRouteOperatorVaultRewardsInstructionDataEncoder().encode(
@@ -212,7 +284,11 @@ export function getRouteOperatorVaultRewardsInstruction<
     TAccountOperator,
     TAccountOperatorSnapshot,
     TAccountOperatorVaultRewardRouter,
-    TAccountOperatorVaultRewardReceiver
+    TAccountOperatorVaultRewardReceiver,
+    TAccountConfig,
+    TAccountOperatorVaultRewardReceiverTokenAccount,
+    TAccountCranker,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -230,6 +306,10 @@ export type ParsedRouteOperatorVaultRewardsInstruction<
     operatorSnapshot: TAccountMetas[3];
     operatorVaultRewardRouter: TAccountMetas[4];
     operatorVaultRewardReceiver: TAccountMetas[5];
+    config?: TAccountMetas[6] | undefined;
+    operatorVaultRewardReceiverTokenAccount?: TAccountMetas[7] | undefined;
+    cranker?: TAccountMetas[8] | undefined;
+    systemProgram?: TAccountMetas[9] | undefined;
   };
   data: RouteOperatorVaultRewardsInstructionData;
 };
@@ -242,7 +322,7 @@ export function parseRouteOperatorVaultRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRouteOperatorVaultRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -252,6 +332,12 @@ export function parseRouteOperatorVaultRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === NCN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -261,6 +347,10 @@ export function parseRouteOperatorVaultRewardsInstruction<
       operatorSnapshot: getNextAccount(),
       operatorVaultRewardRouter: getNextAccount(),
       operatorVaultRewardReceiver: getNextAccount(),
+      config: getNextOptionalAccount(),
+      operatorVaultRewardReceiverTokenAccount: getNextOptionalAccount(),
+      cranker: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getRouteOperatorVaultRewardsInstructionDataDecoder().decode(
       instruction.data
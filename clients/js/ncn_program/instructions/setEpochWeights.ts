@@ -41,6 +41,8 @@ export type SetEpochWeightsInstruction<
   TAccountNcn extends string | IAccountMeta<string> = string,
   TAccountVaultRegistry extends string | IAccountMeta<string> = string,
   TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -56,6 +58,12 @@ export type SetEpochWeightsInstruction<
       TAccountWeightTable extends string
         ? WritableAccount<TAccountWeightTable>
         : TAccountWeightTable,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -99,11 +107,15 @@ export type SetEpochWeightsInput<
   TAccountNcn extends string = string,
   TAccountVaultRegistry extends string = string,
   TAccountWeightTable extends string = string,
+  TAccountConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
 > = {
   epochState: Address<TAccountEpochState>;
   ncn: Address<TAccountNcn>;
   vaultRegistry: Address<TAccountVaultRegistry>;
   weightTable: Address<TAccountWeightTable>;
+  config: Address<TAccountConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
   epoch: SetEpochWeightsInstructionDataArgs['epoch'];
 };
 
@@ -112,13 +124,17 @@ export function getSetEpochWeightsInstruction<
   TAccountNcn extends string,
   TAccountVaultRegistry extends string,
   TAccountWeightTable extends string,
+  TAccountConfig extends string,
+  TAccountRestakingConfig extends string,
   TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: SetEpochWeightsInput<
     TAccountEpochState,
     TAccountNcn,
     TAccountVaultRegistry,
-    TAccountWeightTable
+    TAccountWeightTable,
+    TAccountConfig,
+    TAccountRestakingConfig
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetEpochWeightsInstruction<
@@ -126,7 +142,9 @@ export function getSetEpochWeightsInstruction<
   TAccountEpochState,
   TAccountNcn,
   TAccountVaultRegistry,
-  TAccountWeightTable
+  TAccountWeightTable,
+  TAccountConfig,
+  TAccountRestakingConfig
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
@@ -137,6 +155,11 @@ export function getSetEpochWeightsInstruction<
     ncn: { value: input.ncn ?? null, isWritable: false },
     vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: false },
     weightTable: { value: input.weightTable ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -153,6 +176,8 @@ export function getSetEpochWeightsInstruction<
       getAccountMeta(accounts.ncn),
       getAccountMeta(accounts.vaultRegistry),
       getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.restakingConfig),
     ],
     programAddress,
     data: getSetEpochWeightsInstructionDataEncoder().encode(
@@ -163,7 +188,9 @@ export function getSetEpochWeightsInstruction<
     TAccountEpochState,
     TAccountNcn,
     TAccountVaultRegistry,
-    TAccountWeightTable
+    TAccountWeightTable,
+    TAccountConfig,
+    TAccountRestakingConfig
   >;
 
   return instruction;
@@ -179,6 +206,8 @@ export type ParsedSetEpochWeightsInstruction<
     ncn: TAccountMetas[1];
     vaultRegistry: TAccountMetas[2];
     weightTable: TAccountMetas[3];
+    config: TAccountMetas[4];
+    restakingConfig: TAccountMetas[5];
   };
   data: SetEpochWeightsInstructionData;
 };
@@ -191,7 +220,7 @@ export function parseSetEpochWeightsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSetEpochWeightsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -208,6 +237,8 @@ export function parseSetEpochWeightsInstruction<
       ncn: getNextAccount(),
       vaultRegistry: getNextAccount(),
       weightTable: getNextAccount(),
+      config: getNextAccount(),
+      restakingConfig: getNextAccount(),
     },
     data: getSetEpochWeightsInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,296 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_OPERATOR_METADATA_DISCRIMINATOR = 43;
+
+export function getSetOperatorMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_OPERATOR_METADATA_DISCRIMINATOR);
+}
+
+export type SetOperatorMetadataInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountOperatorMetadata extends string | IAccountMeta<string> = string,
+  TAccountOperatorAdmin extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountNcnOperatorState extends string
+        ? ReadonlyAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountOperatorMetadata extends string
+        ? WritableAccount<TAccountOperatorMetadata>
+        : TAccountOperatorMetadata,
+      TAccountOperatorAdmin extends string
+        ? ReadonlySignerAccount<TAccountOperatorAdmin> &
+            IAccountSignerMeta<TAccountOperatorAdmin>
+        : TAccountOperatorAdmin,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetOperatorMetadataInstructionData = {
+  discriminator: number;
+  name: ReadonlyUint8Array;
+  website: Array<number>;
+  contactHash: ReadonlyUint8Array;
+};
+
+export type SetOperatorMetadataInstructionDataArgs = {
+  name: ReadonlyUint8Array;
+  website: Array<number>;
+  contactHash: ReadonlyUint8Array;
+};
+
+export function getSetOperatorMetadataInstructionDataEncoder(): Encoder<SetOperatorMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', fixEncoderSize(getBytesEncoder(), 32)],
+      ['website', getArrayEncoder(getU8Encoder(), { size: 64 })],
+      ['contactHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_OPERATOR_METADATA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetOperatorMetadataInstructionDataDecoder(): Decoder<SetOperatorMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['website', getArrayDecoder(getU8Decoder(), { size: 64 })],
+    ['contactHash', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getSetOperatorMetadataInstructionDataCodec(): Codec<
+  SetOperatorMetadataInstructionDataArgs,
+  SetOperatorMetadataInstructionData
+> {
+  return combineCodec(
+    getSetOperatorMetadataInstructionDataEncoder(),
+    getSetOperatorMetadataInstructionDataDecoder()
+  );
+}
+
+export type SetOperatorMetadataInput<
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountOperatorMetadata extends string = string,
+  TAccountOperatorAdmin extends string = string,
+  TAccountAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  operatorMetadata: Address<TAccountOperatorMetadata>;
+  operatorAdmin: TransactionSigner<TAccountOperatorAdmin>;
+  accountPayer: Address<TAccountAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  name: SetOperatorMetadataInstructionDataArgs['name'];
+  website: SetOperatorMetadataInstructionDataArgs['website'];
+  contactHash: SetOperatorMetadataInstructionDataArgs['contactHash'];
+};
+
+export function getSetOperatorMetadataInstruction<
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountOperatorMetadata extends string,
+  TAccountOperatorAdmin extends string,
+  TAccountAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: SetOperatorMetadataInput<
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountOperatorMetadata,
+    TAccountOperatorAdmin,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetOperatorMetadataInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountNcnOperatorState,
+  TAccountOperatorMetadata,
+  TAccountOperatorAdmin,
+  TAccountAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    operatorMetadata: {
+      value: input.operatorMetadata ?? null,
+      isWritable: true,
+    },
+    operatorAdmin: { value: input.operatorAdmin ?? null, isWritable: false },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.operatorMetadata),
+      getAccountMeta(accounts.operatorAdmin),
+      getAccountMeta(accounts.accountPayer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSetOperatorMetadataInstructionDataEncoder().encode(
+      args as SetOperatorMetadataInstructionDataArgs
+    ),
+  } as SetOperatorMetadataInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountOperatorMetadata,
+    TAccountOperatorAdmin,
+    TAccountAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetOperatorMetadataInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    ncnOperatorState: TAccountMetas[2];
+    operatorMetadata: TAccountMetas[3];
+    operatorAdmin: TAccountMetas[4];
+    accountPayer: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
+  };
+  data: SetOperatorMetadataInstructionData;
+};
+
+export function parseSetOperatorMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetOperatorMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      operatorMetadata: getNextAccount(),
+      operatorAdmin: getNextAccount(),
+      accountPayer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getSetOperatorMetadataInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,245 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_WEIGHT_FROM_SWITCHBOARD_DISCRIMINATOR = 38;
+
+export function getSetWeightFromSwitchboardDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_WEIGHT_FROM_SWITCHBOARD_DISCRIMINATOR);
+}
+
+export type SetWeightFromSwitchboardInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountEpochState extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVaultRegistry extends string | IAccountMeta<string> = string,
+  TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountSwitchboardFeed extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEpochState extends string
+        ? WritableAccount<TAccountEpochState>
+        : TAccountEpochState,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVaultRegistry extends string
+        ? ReadonlyAccount<TAccountVaultRegistry>
+        : TAccountVaultRegistry,
+      TAccountWeightTable extends string
+        ? WritableAccount<TAccountWeightTable>
+        : TAccountWeightTable,
+      TAccountSwitchboardFeed extends string
+        ? ReadonlyAccount<TAccountSwitchboardFeed>
+        : TAccountSwitchboardFeed,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetWeightFromSwitchboardInstructionData = {
+  discriminator: number;
+  stMint: Address;
+  epoch: bigint;
+};
+
+export type SetWeightFromSwitchboardInstructionDataArgs = {
+  stMint: Address;
+  epoch: number | bigint;
+};
+
+export function getSetWeightFromSwitchboardInstructionDataEncoder(): Encoder<SetWeightFromSwitchboardInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['stMint', getAddressEncoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_WEIGHT_FROM_SWITCHBOARD_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetWeightFromSwitchboardInstructionDataDecoder(): Decoder<SetWeightFromSwitchboardInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['stMint', getAddressDecoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getSetWeightFromSwitchboardInstructionDataCodec(): Codec<
+  SetWeightFromSwitchboardInstructionDataArgs,
+  SetWeightFromSwitchboardInstructionData
+> {
+  return combineCodec(
+    getSetWeightFromSwitchboardInstructionDataEncoder(),
+    getSetWeightFromSwitchboardInstructionDataDecoder()
+  );
+}
+
+export type SetWeightFromSwitchboardInput<
+  TAccountEpochState extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVaultRegistry extends string = string,
+  TAccountWeightTable extends string = string,
+  TAccountSwitchboardFeed extends string = string,
+> = {
+  epochState: Address<TAccountEpochState>;
+  ncn: Address<TAccountNcn>;
+  vaultRegistry: Address<TAccountVaultRegistry>;
+  weightTable: Address<TAccountWeightTable>;
+  switchboardFeed: Address<TAccountSwitchboardFeed>;
+  stMint: SetWeightFromSwitchboardInstructionDataArgs['stMint'];
+  epoch: SetWeightFromSwitchboardInstructionDataArgs['epoch'];
+};
+
+export function getSetWeightFromSwitchboardInstruction<
+  TAccountEpochState extends string,
+  TAccountNcn extends string,
+  TAccountVaultRegistry extends string,
+  TAccountWeightTable extends string,
+  TAccountSwitchboardFeed extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: SetWeightFromSwitchboardInput<
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountWeightTable,
+    TAccountSwitchboardFeed
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetWeightFromSwitchboardInstruction<
+  TProgramAddress,
+  TAccountEpochState,
+  TAccountNcn,
+  TAccountVaultRegistry,
+  TAccountWeightTable,
+  TAccountSwitchboardFeed
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    epochState: { value: input.epochState ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vaultRegistry: { value: input.vaultRegistry ?? null, isWritable: false },
+    weightTable: { value: input.weightTable ?? null, isWritable: true },
+    switchboardFeed: {
+      value: input.switchboardFeed ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.epochState),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vaultRegistry),
+      getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.switchboardFeed),
+    ],
+    programAddress,
+    data: getSetWeightFromSwitchboardInstructionDataEncoder().encode(
+      args as SetWeightFromSwitchboardInstructionDataArgs
+    ),
+  } as SetWeightFromSwitchboardInstruction<
+    TProgramAddress,
+    TAccountEpochState,
+    TAccountNcn,
+    TAccountVaultRegistry,
+    TAccountWeightTable,
+    TAccountSwitchboardFeed
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetWeightFromSwitchboardInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    epochState: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vaultRegistry: TAccountMetas[2];
+    weightTable: TAccountMetas[3];
+    switchboardFeed: TAccountMetas[4];
+  };
+  data: SetWeightFromSwitchboardInstructionData;
+};
+
+export function parseSetWeightFromSwitchboardInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetWeightFromSwitchboardInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      epochState: getNextAccount(),
+      ncn: getNextAccount(),
+      vaultRegistry: getNextAccount(),
+      weightTable: getNextAccount(),
+      switchboardFeed: getNextAccount(),
+    },
+    data: getSetWeightFromSwitchboardInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { NCN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SHRINK_N_C_N_REWARD_ROUTER_DISCRIMINATOR = 34;
+
+export function getShrinkNCNRewardRouterDiscriminatorBytes() {
+  return getU8Encoder().encode(SHRINK_N_C_N_REWARD_ROUTER_DISCRIMINATOR);
+}
+
+export type ShrinkNCNRewardRouterInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountAccountPayer extends string
+        ? WritableAccount<TAccountAccountPayer>
+        : TAccountAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ShrinkNCNRewardRouterInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type ShrinkNCNRewardRouterInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getShrinkNCNRewardRouterInstructionDataEncoder(): Encoder<ShrinkNCNRewardRouterInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SHRINK_N_C_N_REWARD_ROUTER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getShrinkNCNRewardRouterInstructionDataDecoder(): Decoder<ShrinkNCNRewardRouterInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getShrinkNCNRewardRouterInstructionDataCodec(): Codec<
+  ShrinkNCNRewardRouterInstructionDataArgs,
+  ShrinkNCNRewardRouterInstructionData
+> {
+  return combineCodec(
+    getShrinkNCNRewardRouterInstructionDataEncoder(),
+    getShrinkNCNRewardRouterInstructionDataDecoder()
+  );
+}
+
+export type ShrinkNCNRewardRouterInput<
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountAccountPayer extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  accountPayer: Address<TAccountAccountPayer>;
+  epoch: ShrinkNCNRewardRouterInstructionDataArgs['epoch'];
+};
+
+export function getShrinkNCNRewardRouterInstruction<
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountAccountPayer extends string,
+  TProgramAddress extends Address = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+>(
+  input: ShrinkNCNRewardRouterInput<
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountAccountPayer
+  >,
+  config?: { programAddress?: TProgramAddress }
+): ShrinkNCNRewardRouterInstruction<
+  TProgramAddress,
+  TAccountNcn,
+  TAccountNcnRewardRouter,
+  TAccountAccountPayer
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? NCN_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    accountPayer: { value: input.accountPayer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.accountPayer),
+    ],
+    programAddress,
+    data: getShrinkNCNRewardRouterInstructionDataEncoder().encode(
+      args as ShrinkNCNRewardRouterInstructionDataArgs
+    ),
+  } as ShrinkNCNRewardRouterInstruction<
+    TProgramAddress,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedShrinkNCNRewardRouterInstruction<
+  TProgram extends string = typeof NCN_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    ncnRewardRouter: TAccountMetas[1];
+    accountPayer: TAccountMetas[2];
+  };
+  data: ShrinkNCNRewardRouterInstructionData;
+};
+
+export function parseShrinkNCNRewardRouterInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedShrinkNCNRewardRouterInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      accountPayer: getNextAccount(),
+    },
+    data: getShrinkNCNRewardRouterInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
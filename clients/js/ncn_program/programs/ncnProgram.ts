@@ -13,21 +13,40 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedAcquireKeeperLeaseInstruction,
   type ParsedAdminRegisterStMintInstruction,
+  type ParsedAdminSetAdminGovernanceInstruction,
+  type ParsedAdminSetAdminMultisigInstruction,
+  type ParsedAdminSetDefaultWeightInstruction,
+  type ParsedAdminSetFeeGroupInstruction,
   type ParsedAdminSetNewAdminInstruction,
   type ParsedAdminSetParametersInstruction,
+  type ParsedAdminSetRewardMintInstruction,
   type ParsedAdminSetStMintInstruction,
   type ParsedAdminSetTieBreakerInstruction,
+  type ParsedAdminSetVaultMetadataInstruction,
+  type ParsedAdminSetVaultRewardRecipientInstruction,
   type ParsedAdminSetWeightInstruction,
+  type ParsedAdminSetWeightsInstruction,
+  type ParsedApplyParametersInstruction,
+  type ParsedBatchInitializeOperatorSnapshotInstruction,
   type ParsedCastVoteInstruction,
+  type ParsedCloseAllEpochAccountsInstruction,
   type ParsedCloseEpochAccountInstruction,
+  type ParsedCloseRewardReceiverInstruction,
+  type ParsedCopyPreviousEpochWeightsInstruction,
+  type ParsedDistributeFeeGroupRewardsInstruction,
   type ParsedDistributeNCNRewardsInstruction,
   type ParsedDistributeOperatorRewardsInstruction,
   type ParsedDistributeOperatorVaultRewardRouteInstruction,
   type ParsedDistributeProtocolRewardsInstruction,
   type ParsedDistributeVaultRewardsInstruction,
+  type ParsedGetConsensusResultInstruction,
+  type ParsedGetOperatorRewardRouteInstruction,
+  type ParsedGetWinningBallotInstruction,
   type ParsedInitializeBallotBoxInstruction,
   type ParsedInitializeConfigInstruction,
+  type ParsedInitializeEpochAccountsInstruction,
   type ParsedInitializeEpochSnapshotInstruction,
   type ParsedInitializeEpochStateInstruction,
   type ParsedInitializeNCNRewardRouterInstruction,
@@ -35,14 +54,19 @@ import {
   type ParsedInitializeOperatorVaultRewardRouterInstruction,
   type ParsedInitializeVaultRegistryInstruction,
   type ParsedInitializeWeightTableInstruction,
+  type ParsedMarkEpochSkippedInstruction,
   type ParsedReallocBallotBoxInstruction,
   type ParsedReallocNCNRewardRouterInstruction,
   type ParsedReallocVaultRegistryInstruction,
   type ParsedReallocWeightTableInstruction,
   type ParsedRegisterVaultInstruction,
+  type ParsedRouteAndDistributeNCNRewardsInstruction,
   type ParsedRouteNCNRewardsInstruction,
   type ParsedRouteOperatorVaultRewardsInstruction,
   type ParsedSetEpochWeightsInstruction,
+  type ParsedSetOperatorMetadataInstruction,
+  type ParsedSetWeightFromSwitchboardInstruction,
+  type ParsedShrinkNCNRewardRouterInstruction,
   type ParsedSnapshotVaultOperatorDelegationInstruction,
 } from '../instructions';
 
@@ -58,6 +82,8 @@ export enum NcnProgramAccount {
   OperatorSnapshot,
   EpochState,
   NCNRewardRouter,
+  OperatorMetadata,
+  KeeperLease,
   OperatorVaultRewardRouter,
   VaultRegistry,
   WeightTable,
@@ -95,6 +121,30 @@ export enum NcnProgramInstruction {
   AdminSetWeight,
   AdminRegisterStMint,
   AdminSetStMint,
+  AdminSetVaultRewardRecipient,
+  AdminSetRewardMint,
+  RouteAndDistributeNCNRewards,
+  ShrinkNCNRewardRouter,
+  BatchInitializeOperatorSnapshot,
+  InitializeEpochAccounts,
+  CloseAllEpochAccounts,
+  SetWeightFromSwitchboard,
+  CopyPreviousEpochWeights,
+  AdminSetWeights,
+  AdminSetDefaultWeight,
+  AdminSetVaultMetadata,
+  SetOperatorMetadata,
+  AdminSetAdminMultisig,
+  ApplyParameters,
+  MarkEpochSkipped,
+  AdminSetFeeGroup,
+  DistributeFeeGroupRewards,
+  AdminSetAdminGovernance,
+  GetConsensusResult,
+  GetWinningBallot,
+  GetOperatorRewardRoute,
+  AcquireKeeperLease,
+  CloseRewardReceiver,
 }
 
 export function identifyNcnProgramInstruction(
@@ -194,6 +244,78 @@ export function identifyNcnProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(30), 0)) {
     return NcnProgramInstruction.AdminSetStMint;
   }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return NcnProgramInstruction.AdminSetVaultRewardRecipient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return NcnProgramInstruction.AdminSetRewardMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return NcnProgramInstruction.RouteAndDistributeNCNRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return NcnProgramInstruction.ShrinkNCNRewardRouter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return NcnProgramInstruction.BatchInitializeOperatorSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return NcnProgramInstruction.InitializeEpochAccounts;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return NcnProgramInstruction.CloseAllEpochAccounts;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return NcnProgramInstruction.SetWeightFromSwitchboard;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return NcnProgramInstruction.CopyPreviousEpochWeights;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return NcnProgramInstruction.AdminSetWeights;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return NcnProgramInstruction.AdminSetDefaultWeight;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return NcnProgramInstruction.AdminSetVaultMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return NcnProgramInstruction.SetOperatorMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return NcnProgramInstruction.AdminSetAdminMultisig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return NcnProgramInstruction.ApplyParameters;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return NcnProgramInstruction.MarkEpochSkipped;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return NcnProgramInstruction.AdminSetFeeGroup;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return NcnProgramInstruction.DistributeFeeGroupRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return NcnProgramInstruction.AdminSetAdminGovernance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return NcnProgramInstruction.GetConsensusResult;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return NcnProgramInstruction.GetWinningBallot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return NcnProgramInstruction.GetOperatorRewardRoute;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return NcnProgramInstruction.AcquireKeeperLease;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return NcnProgramInstruction.CloseRewardReceiver;
+  }
   throw new Error(
     'The provided instruction could not be identified as a ncnProgram instruction.'
   );
@@ -294,4 +416,76 @@ export type ParsedNcnProgramInstruction<
     } & ParsedAdminRegisterStMintInstruction<TProgram>)
   | ({
       instructionType: NcnProgramInstruction.AdminSetStMint;
-    } & ParsedAdminSetStMintInstruction<TProgram>);
+    } & ParsedAdminSetStMintInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetVaultRewardRecipient;
+    } & ParsedAdminSetVaultRewardRecipientInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetRewardMint;
+    } & ParsedAdminSetRewardMintInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.RouteAndDistributeNCNRewards;
+    } & ParsedRouteAndDistributeNCNRewardsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.ShrinkNCNRewardRouter;
+    } & ParsedShrinkNCNRewardRouterInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.BatchInitializeOperatorSnapshot;
+    } & ParsedBatchInitializeOperatorSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.InitializeEpochAccounts;
+    } & ParsedInitializeEpochAccountsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.CloseAllEpochAccounts;
+    } & ParsedCloseAllEpochAccountsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.SetWeightFromSwitchboard;
+    } & ParsedSetWeightFromSwitchboardInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.CopyPreviousEpochWeights;
+    } & ParsedCopyPreviousEpochWeightsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetWeights;
+    } & ParsedAdminSetWeightsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetDefaultWeight;
+    } & ParsedAdminSetDefaultWeightInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetVaultMetadata;
+    } & ParsedAdminSetVaultMetadataInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.SetOperatorMetadata;
+    } & ParsedSetOperatorMetadataInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetAdminMultisig;
+    } & ParsedAdminSetAdminMultisigInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.ApplyParameters;
+    } & ParsedApplyParametersInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.MarkEpochSkipped;
+    } & ParsedMarkEpochSkippedInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetFeeGroup;
+    } & ParsedAdminSetFeeGroupInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.DistributeFeeGroupRewards;
+    } & ParsedDistributeFeeGroupRewardsInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AdminSetAdminGovernance;
+    } & ParsedAdminSetAdminGovernanceInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.GetConsensusResult;
+    } & ParsedGetConsensusResultInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.GetWinningBallot;
+    } & ParsedGetWinningBallotInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.GetOperatorRewardRoute;
+    } & ParsedGetOperatorRewardRouteInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.AcquireKeeperLease;
+    } & ParsedAcquireKeeperLeaseInstruction<TProgram>)
+  | ({
+      instructionType: NcnProgramInstruction.CloseRewardReceiver;
+    } & ParsedCloseRewardReceiverInstruction<TProgram>);
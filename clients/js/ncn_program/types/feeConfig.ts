@@ -10,6 +10,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   type Address,
@@ -17,13 +19,23 @@ import {
   type Decoder,
   type Encoder,
 } from '@solana/web3.js';
-import { getFeesDecoder, getFeesEncoder, type Fees, type FeesArgs } from '.';
+import {
+  getFeeGroupDecoder,
+  getFeeGroupEncoder,
+  getFeesDecoder,
+  getFeesEncoder,
+  type FeeGroup,
+  type FeeGroupArgs,
+  type Fees,
+  type FeesArgs,
+} from '.';
 
 export type FeeConfig = {
   protocolFeeWallet: Address;
   ncnFeeWallets: Address;
   fee1: Fees;
   fee2: Fees;
+  feeGroups: Array<FeeGroup>;
 };
 
 export type FeeConfigArgs = {
@@ -31,6 +43,7 @@ export type FeeConfigArgs = {
   ncnFeeWallets: Address;
   fee1: FeesArgs;
   fee2: FeesArgs;
+  feeGroups: Array<FeeGroupArgs>;
 };
 
 export function getFeeConfigEncoder(): Encoder<FeeConfigArgs> {
@@ -39,6 +52,7 @@ export function getFeeConfigEncoder(): Encoder<FeeConfigArgs> {
     ['ncnFeeWallets', getAddressEncoder()],
     ['fee1', getFeesEncoder()],
     ['fee2', getFeesEncoder()],
+    ['feeGroups', getArrayEncoder(getFeeGroupEncoder(), { size: 4 })],
   ]);
 }
 
@@ -48,6 +62,7 @@ export function getFeeConfigDecoder(): Decoder<FeeConfig> {
     ['ncnFeeWallets', getAddressDecoder()],
     ['fee1', getFeesDecoder()],
     ['fee2', getFeesDecoder()],
+    ['feeGroups', getArrayDecoder(getFeeGroupDecoder(), { size: 4 })],
   ]);
 }
 
@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type FeeGroup = { name: ReadonlyUint8Array; wallet: Address };
+
+export type FeeGroupArgs = FeeGroup;
+
+export function getFeeGroupEncoder(): Encoder<FeeGroupArgs> {
+  return getStructEncoder([
+    ['name', fixEncoderSize(getBytesEncoder(), 32)],
+    ['wallet', getAddressEncoder()],
+  ]);
+}
+
+export function getFeeGroupDecoder(): Decoder<FeeGroup> {
+  return getStructDecoder([
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['wallet', getAddressDecoder()],
+  ]);
+}
+
+export function getFeeGroupCodec(): Codec<FeeGroupArgs, FeeGroup> {
+  return combineCodec(getFeeGroupEncoder(), getFeeGroupDecoder());
+}
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -22,12 +24,14 @@ export type Fees = {
   activationEpoch: bigint;
   protocolFeeBps: Fee;
   ncnFeeBps: Fee;
+  feeGroupBps: Array<Fee>;
 };
 
 export type FeesArgs = {
   activationEpoch: number | bigint;
   protocolFeeBps: FeeArgs;
   ncnFeeBps: FeeArgs;
+  feeGroupBps: Array<FeeArgs>;
 };
 
 export function getFeesEncoder(): Encoder<FeesArgs> {
@@ -35,6 +39,7 @@ export function getFeesEncoder(): Encoder<FeesArgs> {
     ['activationEpoch', getU64Encoder()],
     ['protocolFeeBps', getFeeEncoder()],
     ['ncnFeeBps', getFeeEncoder()],
+    ['feeGroupBps', getArrayEncoder(getFeeEncoder(), { size: 4 })],
   ]);
 }
 
@@ -43,6 +48,7 @@ export function getFeesDecoder(): Decoder<Fees> {
     ['activationEpoch', getU64Decoder()],
     ['protocolFeeBps', getFeeDecoder()],
     ['ncnFeeBps', getFeeDecoder()],
+    ['feeGroupBps', getArrayDecoder(getFeeDecoder(), { size: 4 })],
   ]);
 }
 
@@ -12,13 +12,18 @@ export * from './configAdminRole';
 export * from './epochAccountStatus';
 export * from './fee';
 export * from './feeConfig';
+export * from './feeGroup';
 export * from './fees';
+export * from './nCNMintRewards';
 export * from './nCNRewardRouterRewards';
+export * from './operatorVaultMintRewards';
 export * from './operatorVaultRewardRoute';
 export * from './operatorVote';
+export * from './pendingParameters';
 export * from './progress';
 export * from './stakeWeights';
 export * from './stMintEntry';
+export * from './stMintWeight';
 export * from './vaultEntry';
 export * from './vaultOperatorStakeWeight';
 export * from './vaultRewardRoute';
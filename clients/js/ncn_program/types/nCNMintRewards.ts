@@ -0,0 +1,88 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type NCNMintRewards = {
+  mint: Address;
+  totalRewards: bigint;
+  rewardPool: bigint;
+  rewardsProcessed: bigint;
+  protocolRewards: bigint;
+  ncnRewards: bigint;
+  feeGroupRewards: Array<bigint>;
+  operatorVaultRewards: bigint;
+  lastVoteIndex: number;
+  lastRewardsToProcess: bigint;
+};
+
+export type NCNMintRewardsArgs = {
+  mint: Address;
+  totalRewards: number | bigint;
+  rewardPool: number | bigint;
+  rewardsProcessed: number | bigint;
+  protocolRewards: number | bigint;
+  ncnRewards: number | bigint;
+  feeGroupRewards: Array<number | bigint>;
+  operatorVaultRewards: number | bigint;
+  lastVoteIndex: number;
+  lastRewardsToProcess: number | bigint;
+};
+
+export function getNCNMintRewardsEncoder(): Encoder<NCNMintRewardsArgs> {
+  return getStructEncoder([
+    ['mint', getAddressEncoder()],
+    ['totalRewards', getU64Encoder()],
+    ['rewardPool', getU64Encoder()],
+    ['rewardsProcessed', getU64Encoder()],
+    ['protocolRewards', getU64Encoder()],
+    ['ncnRewards', getU64Encoder()],
+    ['feeGroupRewards', getArrayEncoder(getU64Encoder(), { size: 4 })],
+    ['operatorVaultRewards', getU64Encoder()],
+    ['lastVoteIndex', getU16Encoder()],
+    ['lastRewardsToProcess', getU64Encoder()],
+  ]);
+}
+
+export function getNCNMintRewardsDecoder(): Decoder<NCNMintRewards> {
+  return getStructDecoder([
+    ['mint', getAddressDecoder()],
+    ['totalRewards', getU64Decoder()],
+    ['rewardPool', getU64Decoder()],
+    ['rewardsProcessed', getU64Decoder()],
+    ['protocolRewards', getU64Decoder()],
+    ['ncnRewards', getU64Decoder()],
+    ['feeGroupRewards', getArrayDecoder(getU64Decoder(), { size: 4 })],
+    ['operatorVaultRewards', getU64Decoder()],
+    ['lastVoteIndex', getU16Decoder()],
+    ['lastRewardsToProcess', getU64Decoder()],
+  ]);
+}
+
+export function getNCNMintRewardsCodec(): Codec<
+  NCNMintRewardsArgs,
+  NCNMintRewards
+> {
+  return combineCodec(getNCNMintRewardsEncoder(), getNCNMintRewardsDecoder());
+}
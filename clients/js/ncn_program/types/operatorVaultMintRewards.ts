@@ -0,0 +1,77 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type OperatorVaultMintRewards = {
+  mint: Address;
+  totalRewards: bigint;
+  rewardPool: bigint;
+  rewardsProcessed: bigint;
+  operatorRewards: bigint;
+  lastRewardsToProcess: bigint;
+  lastVaultOperatorDelegationIndex: number;
+};
+
+export type OperatorVaultMintRewardsArgs = {
+  mint: Address;
+  totalRewards: number | bigint;
+  rewardPool: number | bigint;
+  rewardsProcessed: number | bigint;
+  operatorRewards: number | bigint;
+  lastRewardsToProcess: number | bigint;
+  lastVaultOperatorDelegationIndex: number;
+};
+
+export function getOperatorVaultMintRewardsEncoder(): Encoder<OperatorVaultMintRewardsArgs> {
+  return getStructEncoder([
+    ['mint', getAddressEncoder()],
+    ['totalRewards', getU64Encoder()],
+    ['rewardPool', getU64Encoder()],
+    ['rewardsProcessed', getU64Encoder()],
+    ['operatorRewards', getU64Encoder()],
+    ['lastRewardsToProcess', getU64Encoder()],
+    ['lastVaultOperatorDelegationIndex', getU16Encoder()],
+  ]);
+}
+
+export function getOperatorVaultMintRewardsDecoder(): Decoder<OperatorVaultMintRewards> {
+  return getStructDecoder([
+    ['mint', getAddressDecoder()],
+    ['totalRewards', getU64Decoder()],
+    ['rewardPool', getU64Decoder()],
+    ['rewardsProcessed', getU64Decoder()],
+    ['operatorRewards', getU64Decoder()],
+    ['lastRewardsToProcess', getU64Decoder()],
+    ['lastVaultOperatorDelegationIndex', getU16Decoder()],
+  ]);
+}
+
+export function getOperatorVaultMintRewardsCodec(): Codec<
+  OperatorVaultMintRewardsArgs,
+  OperatorVaultMintRewards
+> {
+  return combineCodec(
+    getOperatorVaultMintRewardsEncoder(),
+    getOperatorVaultMintRewardsDecoder()
+  );
+}
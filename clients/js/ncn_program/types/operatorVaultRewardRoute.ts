@@ -10,6 +10,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   type Address,
@@ -26,25 +28,31 @@ import {
 
 export type OperatorVaultRewardRoute = {
   operator: Address;
-  rewards: NCNRewardRouterRewards;
+  rewards: Array<NCNRewardRouterRewards>;
 };
 
 export type OperatorVaultRewardRouteArgs = {
   operator: Address;
-  rewards: NCNRewardRouterRewardsArgs;
+  rewards: Array<NCNRewardRouterRewardsArgs>;
 };
 
 export function getOperatorVaultRewardRouteEncoder(): Encoder<OperatorVaultRewardRouteArgs> {
   return getStructEncoder([
     ['operator', getAddressEncoder()],
-    ['rewards', getNCNRewardRouterRewardsEncoder()],
+    [
+      'rewards',
+      getArrayEncoder(getNCNRewardRouterRewardsEncoder(), { size: 5 }),
+    ],
   ]);
 }
 
 export function getOperatorVaultRewardRouteDecoder(): Decoder<OperatorVaultRewardRoute> {
   return getStructDecoder([
     ['operator', getAddressDecoder()],
-    ['rewards', getNCNRewardRouterRewardsDecoder()],
+    [
+      'rewards',
+      getArrayDecoder(getNCNRewardRouterRewardsDecoder(), { size: 5 }),
+    ],
   ]);
 }
 
@@ -0,0 +1,104 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBoolDecoder,
+  getBoolEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type PendingParameters = {
+  effectiveSlot: bigint;
+  queued: number;
+  startingValidEpoch: bigint;
+  epochsBeforeStall: bigint;
+  epochsAfterConsensusBeforeClose: bigint;
+  validSlotsAfterConsensus: bigint;
+  crankerFeeBps: number;
+  timelinessBonusSlots: bigint;
+  timelinessBonusBps: number;
+  weightDecayEpochs: bigint;
+  weightDecayBps: number;
+  maxVaultStakeWeightBps: number;
+  permissionlessVaultRegistration: number;
+  parametersTimelockSlots: bigint;
+};
+
+export type PendingParametersArgs = {
+  effectiveSlot: number | bigint;
+  queued: number;
+  startingValidEpoch: number | bigint;
+  epochsBeforeStall: number | bigint;
+  epochsAfterConsensusBeforeClose: number | bigint;
+  validSlotsAfterConsensus: number | bigint;
+  crankerFeeBps: number;
+  timelinessBonusSlots: number | bigint;
+  timelinessBonusBps: number;
+  weightDecayEpochs: number | bigint;
+  weightDecayBps: number;
+  maxVaultStakeWeightBps: number;
+  permissionlessVaultRegistration: number;
+  parametersTimelockSlots: number | bigint;
+};
+
+export function getPendingParametersEncoder(): Encoder<PendingParametersArgs> {
+  return getStructEncoder([
+    ['effectiveSlot', getU64Encoder()],
+    ['queued', getU16Encoder()],
+    ['startingValidEpoch', getU64Encoder()],
+    ['epochsBeforeStall', getU64Encoder()],
+    ['epochsAfterConsensusBeforeClose', getU64Encoder()],
+    ['validSlotsAfterConsensus', getU64Encoder()],
+    ['crankerFeeBps', getU16Encoder()],
+    ['timelinessBonusSlots', getU64Encoder()],
+    ['timelinessBonusBps', getU16Encoder()],
+    ['weightDecayEpochs', getU64Encoder()],
+    ['weightDecayBps', getU16Encoder()],
+    ['maxVaultStakeWeightBps', getU16Encoder()],
+    ['permissionlessVaultRegistration', getBoolEncoder()],
+    ['parametersTimelockSlots', getU64Encoder()],
+  ]);
+}
+
+export function getPendingParametersDecoder(): Decoder<PendingParameters> {
+  return getStructDecoder([
+    ['effectiveSlot', getU64Decoder()],
+    ['queued', getU16Decoder()],
+    ['startingValidEpoch', getU64Decoder()],
+    ['epochsBeforeStall', getU64Decoder()],
+    ['epochsAfterConsensusBeforeClose', getU64Decoder()],
+    ['validSlotsAfterConsensus', getU64Decoder()],
+    ['crankerFeeBps', getU16Decoder()],
+    ['timelinessBonusSlots', getU64Decoder()],
+    ['timelinessBonusBps', getU16Decoder()],
+    ['weightDecayEpochs', getU64Decoder()],
+    ['weightDecayBps', getU16Decoder()],
+    ['maxVaultStakeWeightBps', getU16Decoder()],
+    ['permissionlessVaultRegistration', getBoolDecoder()],
+    ['parametersTimelockSlots', getU64Decoder()],
+  ]);
+}
+
+export function getPendingParametersCodec(): Codec<
+  PendingParametersArgs,
+  PendingParameters
+> {
+  return combineCodec(
+    getPendingParametersEncoder(),
+    getPendingParametersDecoder()
+  );
+}
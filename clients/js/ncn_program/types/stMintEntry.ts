@@ -8,48 +8,49 @@
 
 import {
   combineCodec,
-  fixDecoderSize,
-  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getBytesDecoder,
-  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type Codec,
   type Decoder,
   type Encoder,
-  type ReadonlyUint8Array,
 } from '@solana/web3.js';
 
 export type StMintEntry = {
   stMint: Address;
-  reserveSwitchboardFeed: ReadonlyUint8Array;
+  switchboardFeed: Address;
   weight: bigint;
+  decimals: number;
 };
 
 export type StMintEntryArgs = {
   stMint: Address;
-  reserveSwitchboardFeed: ReadonlyUint8Array;
+  switchboardFeed: Address;
   weight: number | bigint;
+  decimals: number;
 };
 
 export function getStMintEntryEncoder(): Encoder<StMintEntryArgs> {
   return getStructEncoder([
     ['stMint', getAddressEncoder()],
-    ['reserveSwitchboardFeed', fixEncoderSize(getBytesEncoder(), 32)],
+    ['switchboardFeed', getAddressEncoder()],
     ['weight', getU128Encoder()],
+    ['decimals', getU8Encoder()],
   ]);
 }
 
 export function getStMintEntryDecoder(): Decoder<StMintEntry> {
   return getStructDecoder([
     ['stMint', getAddressDecoder()],
-    ['reserveSwitchboardFeed', fixDecoderSize(getBytesDecoder(), 32)],
+    ['switchboardFeed', getAddressDecoder()],
     ['weight', getU128Decoder()],
+    ['decimals', getU8Decoder()],
   ]);
 }
 
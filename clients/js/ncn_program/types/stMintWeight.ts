@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type StMintWeight = { stMint: Address; weight: bigint };
+
+export type StMintWeightArgs = { stMint: Address; weight: number | bigint };
+
+export function getStMintWeightEncoder(): Encoder<StMintWeightArgs> {
+  return getStructEncoder([
+    ['stMint', getAddressEncoder()],
+    ['weight', getU128Encoder()],
+  ]);
+}
+
+export function getStMintWeightDecoder(): Decoder<StMintWeight> {
+  return getStructDecoder([
+    ['stMint', getAddressDecoder()],
+    ['weight', getU128Decoder()],
+  ]);
+}
+
+export function getStMintWeightCodec(): Codec<StMintWeightArgs, StMintWeight> {
+  return combineCodec(getStMintWeightEncoder(), getStMintWeightDecoder());
+}
@@ -17,16 +17,25 @@ import {
   type Encoder,
 } from '@solana/web3.js';
 
-export type StakeWeights = { stakeWeight: bigint };
+export type StakeWeights = { stakeWeight: bigint; stakeWeightHi: bigint };
 
-export type StakeWeightsArgs = { stakeWeight: number | bigint };
+export type StakeWeightsArgs = {
+  stakeWeight: number | bigint;
+  stakeWeightHi: number | bigint;
+};
 
 export function getStakeWeightsEncoder(): Encoder<StakeWeightsArgs> {
-  return getStructEncoder([['stakeWeight', getU128Encoder()]]);
+  return getStructEncoder([
+    ['stakeWeight', getU128Encoder()],
+    ['stakeWeightHi', getU128Encoder()],
+  ]);
 }
 
 export function getStakeWeightsDecoder(): Decoder<StakeWeights> {
-  return getStructDecoder([['stakeWeight', getU128Decoder()]]);
+  return getStructDecoder([
+    ['stakeWeight', getU128Decoder()],
+    ['stakeWeightHi', getU128Decoder()],
+  ]);
 }
 
 export function getStakeWeightsCodec(): Codec<StakeWeightsArgs, StakeWeights> {
@@ -8,8 +8,12 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -18,6 +22,7 @@ import {
   type Codec,
   type Decoder,
   type Encoder,
+  type ReadonlyUint8Array,
 } from '@solana/web3.js';
 
 export type VaultEntry = {
@@ -25,6 +30,9 @@ export type VaultEntry = {
   stMint: Address;
   vaultIndex: bigint;
   slotRegistered: bigint;
+  rewardRecipient: Address;
+  name: ReadonlyUint8Array;
+  uriHash: ReadonlyUint8Array;
 };
 
 export type VaultEntryArgs = {
@@ -32,6 +40,9 @@ export type VaultEntryArgs = {
   stMint: Address;
   vaultIndex: number | bigint;
   slotRegistered: number | bigint;
+  rewardRecipient: Address;
+  name: ReadonlyUint8Array;
+  uriHash: ReadonlyUint8Array;
 };
 
 export function getVaultEntryEncoder(): Encoder<VaultEntryArgs> {
@@ -40,6 +51,9 @@ export function getVaultEntryEncoder(): Encoder<VaultEntryArgs> {
     ['stMint', getAddressEncoder()],
     ['vaultIndex', getU64Encoder()],
     ['slotRegistered', getU64Encoder()],
+    ['rewardRecipient', getAddressEncoder()],
+    ['name', fixEncoderSize(getBytesEncoder(), 32)],
+    ['uriHash', fixEncoderSize(getBytesEncoder(), 32)],
   ]);
 }
 
@@ -49,6 +63,9 @@ export function getVaultEntryDecoder(): Decoder<VaultEntry> {
     ['stMint', getAddressDecoder()],
     ['vaultIndex', getU64Decoder()],
     ['slotRegistered', getU64Decoder()],
+    ['rewardRecipient', getAddressDecoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['uriHash', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
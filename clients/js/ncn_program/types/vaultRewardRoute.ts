@@ -10,6 +10,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -20,14 +22,23 @@ import {
   type Encoder,
 } from '@solana/web3.js';
 
-export type VaultRewardRoute = { vault: Address; rewards: bigint };
+export type VaultRewardRoute = {
+  vault: Address;
+  rewards: bigint;
+  mintRewards: Array<bigint>;
+};
 
-export type VaultRewardRouteArgs = { vault: Address; rewards: number | bigint };
+export type VaultRewardRouteArgs = {
+  vault: Address;
+  rewards: number | bigint;
+  mintRewards: Array<number | bigint>;
+};
 
 export function getVaultRewardRouteEncoder(): Encoder<VaultRewardRouteArgs> {
   return getStructEncoder([
     ['vault', getAddressEncoder()],
     ['rewards', getU64Encoder()],
+    ['mintRewards', getArrayEncoder(getU64Encoder(), { size: 4 })],
   ]);
 }
 
@@ -35,6 +46,7 @@ export function getVaultRewardRouteDecoder(): Decoder<VaultRewardRoute> {
   return getStructDecoder([
     ['vault', getAddressDecoder()],
     ['rewards', getU64Decoder()],
+    ['mintRewards', getArrayDecoder(getU64Decoder(), { size: 4 })],
   ]);
 }
 
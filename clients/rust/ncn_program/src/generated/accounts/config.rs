@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::FeeConfig;
+use crate::generated::types::PendingParameters;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -30,6 +31,28 @@ pub struct Config {
     pub starting_valid_epoch: u64,
     pub fee_config: FeeConfig,
     pub bump: u8,
+    pub reward_mints: [Pubkey; 4],
+    pub cranker_fee_bps: u16,
+    pub timeliness_bonus_slots: u64,
+    pub timeliness_bonus_bps: u16,
+    pub weight_decay_epochs: u64,
+    pub weight_decay_bps: u16,
+    pub max_vault_stake_weight_bps: u16,
+    pub permissionless_vault_registration: bool,
+    pub admin_approvers: [Pubkey; 8],
+    pub admin_threshold: u8,
+    pub parameters_timelock_slots: u64,
+    pub pending_parameters: PendingParameters,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin_governance_program: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin_governance_realm: Pubkey,
 }
 
 impl Config {
@@ -20,6 +20,7 @@ pub struct EpochMarker {
     pub ncn: Pubkey,
     pub epoch: u64,
     pub slot_closed: u64,
+    pub skipped: bool,
 }
 
 impl EpochMarker {
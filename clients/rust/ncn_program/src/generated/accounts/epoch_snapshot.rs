@@ -30,6 +30,7 @@ pub struct EpochSnapshot {
     pub valid_operator_vault_delegations: u64,
     pub stake_weights: StakeWeights,
     pub fees: Fees,
+    pub max_vault_stake_weight: StakeWeights,
 }
 
 impl EpochSnapshot {
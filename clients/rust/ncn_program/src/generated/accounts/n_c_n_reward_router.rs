@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::NCNMintRewards;
 use crate::generated::types::OperatorVaultRewardRoute;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -22,16 +23,9 @@ pub struct NCNRewardRouter {
     pub epoch: u64,
     pub bump: u8,
     pub slot_created: u64,
-    pub total_rewards: u64,
-    pub reward_pool: u64,
-    pub rewards_processed: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 128],
-    pub last_vote_index: u16,
-    pub last_rewards_to_process: u64,
-    pub protocol_rewards: u64,
-    pub ncn_rewards: u64,
-    pub operator_vault_rewards: u64,
+    pub reserved: [u8; 96],
+    pub rewards: [NCNMintRewards; 5],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
     pub operator_vault_reward_routes: [OperatorVaultRewardRoute; 256],
 }
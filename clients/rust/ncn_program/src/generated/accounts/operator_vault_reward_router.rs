@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::OperatorVaultMintRewards;
 use crate::generated::types::VaultRewardRoute;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -36,6 +37,7 @@ pub struct OperatorVaultRewardRouter {
     pub last_vault_operator_delegation_index: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
     pub vault_reward_routes: [VaultRewardRoute; 64],
+    pub mint_rewards: [OperatorVaultMintRewards; 4],
 }
 
 impl OperatorVaultRewardRouter {
@@ -22,9 +22,10 @@ pub struct VaultRegistry {
     pub ncn: Pubkey,
     pub bump: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub st_mint_list: [StMintEntry; 64],
+    pub st_mint_list: [StMintEntry; 128],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
     pub vault_list: [VaultEntry; 64],
+    pub default_weight: u128,
 }
 
 impl VaultRegistry {
@@ -27,7 +27,7 @@ pub struct WeightTable {
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
     pub vault_registry: [VaultEntry; 64],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub table: [WeightEntry; 64],
+    pub table: [WeightEntry; 128],
 }
 
 impl WeightTable {
@@ -283,6 +283,123 @@ pub enum NcnProgramError {
     /// 8784 - Marker exists
     #[error("Marker exists")]
     MarkerExists = 0x2250,
+    /// 8785 - Vault reward recipient must be a system account or a wrapped SOL token account
+    #[error("Vault reward recipient must be a system account or a wrapped SOL token account")]
+    InvalidVaultRewardRecipient = 0x2251,
+    /// 8786 - Reward mint is not registered in the config
+    #[error("Reward mint is not registered in the config")]
+    RewardMintNotRegistered = 0x2252,
+    /// 8787 - Invalid reward mint
+    #[error("Invalid reward mint")]
+    InvalidRewardMint = 0x2253,
+    /// 8788 - Invalid reward mint index
+    #[error("Invalid reward mint index")]
+    InvalidRewardMintIndex = 0x2254,
+    /// 8789 - Reward mint list is full
+    #[error("Reward mint list is full")]
+    RewardMintListFull = 0x2255,
+    /// 8790 - Reward mint not found
+    #[error("Reward mint not found")]
+    RewardMintNotFound = 0x2256,
+    /// 8791 - Invalid reward token account
+    #[error("Invalid reward token account")]
+    InvalidRewardTokenAccount = 0x2257,
+    /// 8792 - Cranker fee exceeds the maximum
+    #[error("Cranker fee exceeds the maximum")]
+    InvalidCrankerFeeBps = 0x2258,
+    /// 8793 - Timeliness bonus exceeds the maximum
+    #[error("Timeliness bonus exceeds the maximum")]
+    InvalidTimelinessBonusBps = 0x2259,
+    /// 8794 - Router still has rewards to route or distribute
+    #[error("Router still has rewards to route or distribute")]
+    RouterRewardsInTransit = 0x225A,
+    /// 8795 - No switchboard feed is registered for the st mint
+    #[error("No switchboard feed is registered for the st mint")]
+    NoSwitchboardFeed = 0x225B,
+    /// 8796 - Switchboard feed does not match the registered feed
+    #[error("Switchboard feed does not match the registered feed")]
+    IncorrectSwitchboardFeed = 0x225C,
+    /// 8797 - Could not parse the switchboard feed
+    #[error("Could not parse the switchboard feed")]
+    BadSwitchboardFeed = 0x225D,
+    /// 8798 - Switchboard feed value is invalid
+    #[error("Switchboard feed value is invalid")]
+    BadSwitchboardValue = 0x225E,
+    /// 8799 - Switchboard feed is stale
+    #[error("Switchboard feed is stale")]
+    StaleSwitchboardFeed = 0x225F,
+    /// 8800 - Switchboard feed confidence interval is too wide
+    #[error("Switchboard feed confidence interval is too wide")]
+    SwitchboardConfidenceTooLow = 0x2260,
+    /// 8801 - Vault registry changed since the previous epoch
+    #[error("Vault registry changed since the previous epoch")]
+    VaultRegistryChanged = 0x2261,
+    /// 8802 - Weight decay exceeds the maximum
+    #[error("Weight decay exceeds the maximum")]
+    InvalidWeightDecayBps = 0x2262,
+    /// 8803 - Vault stake weight cap exceeds the maximum
+    #[error("Vault stake weight cap exceeds the maximum")]
+    InvalidMaxVaultStakeWeightBps = 0x2263,
+    /// 8804 - NCN vault ticket is not active
+    #[error("NCN vault ticket is not active")]
+    NcnVaultTicketNotActive = 0x2264,
+    /// 8805 - Vault name is not valid UTF-8
+    #[error("Vault name is not valid UTF-8")]
+    InvalidVaultName = 0x2265,
+    /// 8806 - Operator metadata is not valid UTF-8
+    #[error("Operator metadata is not valid UTF-8")]
+    InvalidOperatorMetadata = 0x2266,
+    /// 8807 - Invalid admin multisig
+    #[error("Invalid admin multisig")]
+    InvalidAdminMultisig = 0x2267,
+    /// 8808 - Not enough admin approvers signed
+    #[error("Not enough admin approvers signed")]
+    AdminThresholdNotMet = 0x2268,
+    /// 8809 - Parameters timelock exceeds the maximum
+    #[error("Parameters timelock exceeds the maximum")]
+    InvalidParametersTimelock = 0x2269,
+    /// 8810 - No parameter changes are pending
+    #[error("No parameter changes are pending")]
+    NoPendingParameters = 0x226A,
+    /// 8811 - Parameters timelock has not elapsed
+    #[error("Parameters timelock has not elapsed")]
+    ParametersTimelockNotElapsed = 0x226B,
+    /// 8812 - Epoch can only be skipped once it stalled without an epoch state
+    #[error("Epoch can only be skipped once it stalled without an epoch state")]
+    EpochNotSkippable = 0x226C,
+    /// 8813 - Invalid fee group
+    #[error("Invalid fee group")]
+    InvalidFeeGroup = 0x226D,
+    /// 8814 - Fee group name is not valid UTF-8
+    #[error("Fee group name is not valid UTF-8")]
+    InvalidFeeGroupName = 0x226E,
+    /// 8815 - Fee group wallet cannot be the default pubkey
+    #[error("Fee group wallet cannot be the default pubkey")]
+    DefaultFeeGroupWallet = 0x226F,
+    /// 8816 - Fee group wallet does not match the config
+    #[error("Fee group wallet does not match the config")]
+    InvalidFeeGroupWallet = 0x2270,
+    /// 8817 - Admin governance program and realm have to be set together
+    #[error("Admin governance program and realm have to be set together")]
+    InvalidAdminGovernance = 0x2271,
+    /// 8818 - Admin instructions have to be signed by a governance of the admin realm
+    #[error("Admin instructions have to be signed by a governance of the admin realm")]
+    NotAdminGovernance = 0x2272,
+    /// 8819 - Ballot tallies do not match the operator votes
+    #[error("Ballot tallies do not match the operator votes")]
+    BallotTallyMismatch = 0x2273,
+    /// 8820 - Consensus result does not match the ballot box
+    #[error("Consensus result does not match the ballot box")]
+    ConsensusResultMismatch = 0x2274,
+    /// 8821 - Keeper lease is held by another keeper
+    #[error("Keeper lease is held by another keeper")]
+    KeeperLeaseHeld = 0x2275,
+    /// 8822 - Keeper lease slots exceed the maximum
+    #[error("Keeper lease slots exceed the maximum")]
+    InvalidKeeperLeaseSlots = 0x2276,
+    /// 8823 - Reward router is not closed for good
+    #[error("Reward router is not closed for good")]
+    RewardRouterNotClosed = 0x2277,
 }
 
 impl solana_program::program_error::PrintProgramError for NcnProgramError {
@@ -81,6 +81,14 @@ pub struct AdminSetParametersInstructionArgs {
     pub epochs_before_stall: Option<u64>,
     pub epochs_after_consensus_before_close: Option<u64>,
     pub valid_slots_after_consensus: Option<u64>,
+    pub cranker_fee_bps: Option<u16>,
+    pub timeliness_bonus_slots: Option<u64>,
+    pub timeliness_bonus_bps: Option<u16>,
+    pub weight_decay_epochs: Option<u64>,
+    pub weight_decay_bps: Option<u16>,
+    pub max_vault_stake_weight_bps: Option<u16>,
+    pub permissionless_vault_registration: Option<bool>,
+    pub parameters_timelock_slots: Option<u64>,
 }
 
 /// Instruction builder for `AdminSetParameters`.
@@ -99,6 +107,14 @@ pub struct AdminSetParametersBuilder {
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    cranker_fee_bps: Option<u16>,
+    timeliness_bonus_slots: Option<u64>,
+    timeliness_bonus_bps: Option<u16>,
+    weight_decay_epochs: Option<u64>,
+    weight_decay_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    permissionless_vault_registration: Option<bool>,
+    parameters_timelock_slots: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -148,6 +164,57 @@ impl AdminSetParametersBuilder {
         self.valid_slots_after_consensus = Some(valid_slots_after_consensus);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn cranker_fee_bps(&mut self, cranker_fee_bps: u16) -> &mut Self {
+        self.cranker_fee_bps = Some(cranker_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timeliness_bonus_slots(&mut self, timeliness_bonus_slots: u64) -> &mut Self {
+        self.timeliness_bonus_slots = Some(timeliness_bonus_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timeliness_bonus_bps(&mut self, timeliness_bonus_bps: u16) -> &mut Self {
+        self.timeliness_bonus_bps = Some(timeliness_bonus_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_decay_epochs(&mut self, weight_decay_epochs: u64) -> &mut Self {
+        self.weight_decay_epochs = Some(weight_decay_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_decay_bps(&mut self, weight_decay_bps: u16) -> &mut Self {
+        self.weight_decay_bps = Some(weight_decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_vault_stake_weight_bps(&mut self, max_vault_stake_weight_bps: u16) -> &mut Self {
+        self.max_vault_stake_weight_bps = Some(max_vault_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn permissionless_vault_registration(
+        &mut self,
+        permissionless_vault_registration: bool,
+    ) -> &mut Self {
+        self.permissionless_vault_registration = Some(permissionless_vault_registration);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn parameters_timelock_slots(&mut self, parameters_timelock_slots: u64) -> &mut Self {
+        self.parameters_timelock_slots = Some(parameters_timelock_slots);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -178,6 +245,14 @@ impl AdminSetParametersBuilder {
             epochs_before_stall: self.epochs_before_stall.clone(),
             epochs_after_consensus_before_close: self.epochs_after_consensus_before_close.clone(),
             valid_slots_after_consensus: self.valid_slots_after_consensus.clone(),
+            cranker_fee_bps: self.cranker_fee_bps.clone(),
+            timeliness_bonus_slots: self.timeliness_bonus_slots.clone(),
+            timeliness_bonus_bps: self.timeliness_bonus_bps.clone(),
+            weight_decay_epochs: self.weight_decay_epochs.clone(),
+            weight_decay_bps: self.weight_decay_bps.clone(),
+            max_vault_stake_weight_bps: self.max_vault_stake_weight_bps.clone(),
+            permissionless_vault_registration: self.permissionless_vault_registration.clone(),
+            parameters_timelock_slots: self.parameters_timelock_slots.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -325,6 +400,14 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
             epochs_before_stall: None,
             epochs_after_consensus_before_close: None,
             valid_slots_after_consensus: None,
+            cranker_fee_bps: None,
+            timeliness_bonus_slots: None,
+            timeliness_bonus_bps: None,
+            weight_decay_epochs: None,
+            weight_decay_bps: None,
+            max_vault_stake_weight_bps: None,
+            permissionless_vault_registration: None,
+            parameters_timelock_slots: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -378,6 +461,58 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
         self.instruction.valid_slots_after_consensus = Some(valid_slots_after_consensus);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn cranker_fee_bps(&mut self, cranker_fee_bps: u16) -> &mut Self {
+        self.instruction.cranker_fee_bps = Some(cranker_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timeliness_bonus_slots(&mut self, timeliness_bonus_slots: u64) -> &mut Self {
+        self.instruction.timeliness_bonus_slots = Some(timeliness_bonus_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timeliness_bonus_bps(&mut self, timeliness_bonus_bps: u16) -> &mut Self {
+        self.instruction.timeliness_bonus_bps = Some(timeliness_bonus_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_decay_epochs(&mut self, weight_decay_epochs: u64) -> &mut Self {
+        self.instruction.weight_decay_epochs = Some(weight_decay_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_decay_bps(&mut self, weight_decay_bps: u16) -> &mut Self {
+        self.instruction.weight_decay_bps = Some(weight_decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_vault_stake_weight_bps(&mut self, max_vault_stake_weight_bps: u16) -> &mut Self {
+        self.instruction.max_vault_stake_weight_bps = Some(max_vault_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn permissionless_vault_registration(
+        &mut self,
+        permissionless_vault_registration: bool,
+    ) -> &mut Self {
+        self.instruction.permissionless_vault_registration =
+            Some(permissionless_vault_registration);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn parameters_timelock_slots(&mut self, parameters_timelock_slots: u64) -> &mut Self {
+        self.instruction.parameters_timelock_slots = Some(parameters_timelock_slots);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -427,6 +562,17 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
                 .epochs_after_consensus_before_close
                 .clone(),
             valid_slots_after_consensus: self.instruction.valid_slots_after_consensus.clone(),
+            cranker_fee_bps: self.instruction.cranker_fee_bps.clone(),
+            timeliness_bonus_slots: self.instruction.timeliness_bonus_slots.clone(),
+            timeliness_bonus_bps: self.instruction.timeliness_bonus_bps.clone(),
+            weight_decay_epochs: self.instruction.weight_decay_epochs.clone(),
+            weight_decay_bps: self.instruction.weight_decay_bps.clone(),
+            max_vault_stake_weight_bps: self.instruction.max_vault_stake_weight_bps.clone(),
+            permissionless_vault_registration: self
+                .instruction
+                .permissionless_vault_registration
+                .clone(),
+            parameters_timelock_slots: self.instruction.parameters_timelock_slots.clone(),
         };
         let instruction = AdminSetParametersCpi {
             __program: self.instruction.__program,
@@ -455,6 +601,14 @@ struct AdminSetParametersCpiBuilderInstruction<'a, 'b> {
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    cranker_fee_bps: Option<u16>,
+    timeliness_bonus_slots: Option<u64>,
+    timeliness_bonus_bps: Option<u16>,
+    weight_decay_epochs: Option<u64>,
+    weight_decay_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    permissionless_vault_registration: Option<bool>,
+    parameters_timelock_slots: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
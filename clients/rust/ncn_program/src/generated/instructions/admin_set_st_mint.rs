@@ -83,6 +83,7 @@ impl Default for AdminSetStMintInstructionData {
 pub struct AdminSetStMintInstructionArgs {
     pub st_mint: Pubkey,
     pub weight: Option<u128>,
+    pub switchboard_feed: Option<Pubkey>,
 }
 
 /// Instruction builder for `AdminSetStMint`.
@@ -101,6 +102,7 @@ pub struct AdminSetStMintBuilder {
     admin: Option<solana_program::pubkey::Pubkey>,
     st_mint: Option<Pubkey>,
     weight: Option<u128>,
+    switchboard_feed: Option<Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -139,6 +141,12 @@ impl AdminSetStMintBuilder {
         self.weight = Some(weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn switchboard_feed(&mut self, switchboard_feed: Pubkey) -> &mut Self {
+        self.switchboard_feed = Some(switchboard_feed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -168,6 +176,7 @@ impl AdminSetStMintBuilder {
         let args = AdminSetStMintInstructionArgs {
             st_mint: self.st_mint.clone().expect("st_mint is not set"),
             weight: self.weight.clone(),
+            switchboard_feed: self.switchboard_feed.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -323,6 +332,7 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
             admin: None,
             st_mint: None,
             weight: None,
+            switchboard_feed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -364,6 +374,12 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
         self.instruction.weight = Some(weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn switchboard_feed(&mut self, switchboard_feed: Pubkey) -> &mut Self {
+        self.instruction.switchboard_feed = Some(switchboard_feed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -412,6 +428,7 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("st_mint is not set"),
             weight: self.instruction.weight.clone(),
+            switchboard_feed: self.instruction.switchboard_feed.clone(),
         };
         let instruction = AdminSetStMintCpi {
             __program: self.instruction.__program,
@@ -444,6 +461,7 @@ struct AdminSetStMintCpiBuilderInstruction<'a, 'b> {
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     st_mint: Option<Pubkey>,
     weight: Option<u128>,
+    switchboard_feed: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
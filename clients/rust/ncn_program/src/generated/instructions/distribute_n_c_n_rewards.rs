@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct DistributeNCNRewards {
@@ -23,6 +24,12 @@ pub struct DistributeNCNRewards {
     pub ncn_fee_wallet: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeNCNRewards {
@@ -38,7 +45,7 @@ impl DistributeNCNRewards {
         args: DistributeNCNRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -66,6 +73,39 @@ impl DistributeNCNRewards {
             self.system_program,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_fee_wallet_token_account) = self.ncn_fee_wallet_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_fee_wallet_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DistributeNCNRewardsInstructionData::new()
             .try_to_vec()
@@ -101,6 +141,7 @@ impl Default for DistributeNCNRewardsInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeNCNRewardsInstructionArgs {
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -115,6 +156,9 @@ pub struct DistributeNCNRewardsInstructionArgs {
 ///   4. `[writable]` ncn_reward_receiver
 ///   5. `[writable]` ncn_fee_wallet
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` token_program
+///   8. `[writable, optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` ncn_fee_wallet_token_account
 #[derive(Clone, Debug, Default)]
 pub struct DistributeNCNRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -124,6 +168,10 @@ pub struct DistributeNCNRewardsBuilder {
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     ncn_fee_wallet: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ncn_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -174,6 +222,39 @@ impl DistributeNCNRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_fee_wallet_token_account(
+        &mut self,
+        ncn_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_fee_wallet_token_account = ncn_fee_wallet_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -213,8 +294,12 @@ impl DistributeNCNRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            ncn_fee_wallet_token_account: self.ncn_fee_wallet_token_account,
         };
         let args = DistributeNCNRewardsInstructionArgs {
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -237,6 +322,13 @@ pub struct DistributeNCNRewardsCpiAccounts<'a, 'b> {
     pub ncn_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_fee_wallet_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_n_c_n_rewards` CPI instruction.
@@ -257,6 +349,13 @@ pub struct DistributeNCNRewardsCpi<'a, 'b> {
     pub ncn_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_fee_wallet_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeNCNRewardsInstructionArgs,
 }
@@ -276,6 +375,9 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
             ncn_reward_receiver: accounts.ncn_reward_receiver,
             ncn_fee_wallet: accounts.ncn_fee_wallet,
             system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            ncn_fee_wallet_token_account: accounts.ncn_fee_wallet_token_account,
             __args: args,
         }
     }
@@ -312,7 +414,7 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -341,6 +443,39 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_fee_wallet_token_account) = self.ncn_fee_wallet_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_fee_wallet_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -359,7 +494,7 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -368,6 +503,15 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
         account_infos.push(self.ncn_reward_receiver.clone());
         account_infos.push(self.ncn_fee_wallet.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(ncn_fee_wallet_token_account) = self.ncn_fee_wallet_token_account {
+            account_infos.push(ncn_fee_wallet_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -391,6 +535,9 @@ impl<'a, 'b> DistributeNCNRewardsCpi<'a, 'b> {
 ///   4. `[writable]` ncn_reward_receiver
 ///   5. `[writable]` ncn_fee_wallet
 ///   6. `[]` system_program
+///   7. `[optional]` token_program
+///   8. `[writable, optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` ncn_fee_wallet_token_account
 #[derive(Clone, Debug)]
 pub struct DistributeNCNRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeNCNRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -407,6 +554,10 @@ impl<'a, 'b> DistributeNCNRewardsCpiBuilder<'a, 'b> {
             ncn_reward_receiver: None,
             ncn_fee_wallet: None,
             system_program: None,
+            token_program: None,
+            ncn_reward_receiver_token_account: None,
+            ncn_fee_wallet_token_account: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -465,6 +616,41 @@ impl<'a, 'b> DistributeNCNRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_fee_wallet_token_account(
+        &mut self,
+        ncn_fee_wallet_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.ncn_fee_wallet_token_account = ncn_fee_wallet_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -512,6 +698,7 @@ impl<'a, 'b> DistributeNCNRewardsCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = DistributeNCNRewardsInstructionArgs {
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = DistributeNCNRewardsCpi {
@@ -545,6 +732,12 @@ impl<'a, 'b> DistributeNCNRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            token_program: self.instruction.token_program,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            ncn_fee_wallet_token_account: self.instruction.ncn_fee_wallet_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -564,6 +757,10 @@ struct DistributeNCNRewardsCpiBuilderInstruction<'a, 'b> {
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_fee_wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_fee_wallet_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
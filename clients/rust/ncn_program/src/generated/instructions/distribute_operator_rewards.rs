@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct DistributeOperatorRewards {
@@ -25,6 +26,12 @@ pub struct DistributeOperatorRewards {
     pub operator_vault_reward_receiver: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub operator_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeOperatorRewards {
@@ -40,7 +47,7 @@ impl DistributeOperatorRewards {
         args: DistributeOperatorRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -72,6 +79,41 @@ impl DistributeOperatorRewards {
             self.system_program,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_vault_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_token_account) = self.operator_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DistributeOperatorRewardsInstructionData::new()
             .try_to_vec()
@@ -107,6 +149,7 @@ impl Default for DistributeOperatorRewardsInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeOperatorRewardsInstructionArgs {
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -122,6 +165,9 @@ pub struct DistributeOperatorRewardsInstructionArgs {
 ///   5. `[writable]` operator_vault_reward_router
 ///   6. `[writable]` operator_vault_reward_receiver
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` token_program
+///   9. `[writable, optional]` operator_vault_reward_receiver_token_account
+///   10. `[writable, optional]` operator_token_account
 #[derive(Clone, Debug, Default)]
 pub struct DistributeOperatorRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -132,6 +178,10 @@ pub struct DistributeOperatorRewardsBuilder {
     operator_vault_reward_router: Option<solana_program::pubkey::Pubkey>,
     operator_vault_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    operator_token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -190,6 +240,40 @@ impl DistributeOperatorRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_token_account(
+        &mut self,
+        operator_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_token_account = operator_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -232,8 +316,13 @@ impl DistributeOperatorRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program,
+            operator_vault_reward_receiver_token_account: self
+                .operator_vault_reward_receiver_token_account,
+            operator_token_account: self.operator_token_account,
         };
         let args = DistributeOperatorRewardsInstructionArgs {
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -258,6 +347,13 @@ pub struct DistributeOperatorRewardsCpiAccounts<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_operator_rewards` CPI instruction.
@@ -280,6 +376,13 @@ pub struct DistributeOperatorRewardsCpi<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeOperatorRewardsInstructionArgs,
 }
@@ -300,6 +403,10 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
             operator_vault_reward_router: accounts.operator_vault_reward_router,
             operator_vault_reward_receiver: accounts.operator_vault_reward_receiver,
             system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            operator_vault_reward_receiver_token_account: accounts
+                .operator_vault_reward_receiver_token_account,
+            operator_token_account: accounts.operator_token_account,
             __args: args,
         }
     }
@@ -336,7 +443,7 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -369,6 +476,41 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_vault_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_token_account) = self.operator_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -387,7 +529,7 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -397,6 +539,17 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
         account_infos.push(self.operator_vault_reward_router.clone());
         account_infos.push(self.operator_vault_reward_receiver.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            account_infos.push(operator_vault_reward_receiver_token_account.clone());
+        }
+        if let Some(operator_token_account) = self.operator_token_account {
+            account_infos.push(operator_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -421,6 +574,9 @@ impl<'a, 'b> DistributeOperatorRewardsCpi<'a, 'b> {
 ///   5. `[writable]` operator_vault_reward_router
 ///   6. `[writable]` operator_vault_reward_receiver
 ///   7. `[]` system_program
+///   8. `[optional]` token_program
+///   9. `[writable, optional]` operator_vault_reward_receiver_token_account
+///   10. `[writable, optional]` operator_token_account
 #[derive(Clone, Debug)]
 pub struct DistributeOperatorRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeOperatorRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -438,6 +594,10 @@ impl<'a, 'b> DistributeOperatorRewardsCpiBuilder<'a, 'b> {
             operator_vault_reward_router: None,
             operator_vault_reward_receiver: None,
             system_program: None,
+            token_program: None,
+            operator_vault_reward_receiver_token_account: None,
+            operator_token_account: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -504,6 +664,43 @@ impl<'a, 'b> DistributeOperatorRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction
+            .operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_token_account(
+        &mut self,
+        operator_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_token_account = operator_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -551,6 +748,7 @@ impl<'a, 'b> DistributeOperatorRewardsCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = DistributeOperatorRewardsInstructionArgs {
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = DistributeOperatorRewardsCpi {
@@ -586,6 +784,14 @@ impl<'a, 'b> DistributeOperatorRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            token_program: self.instruction.token_program,
+
+            operator_vault_reward_receiver_token_account: self
+                .instruction
+                .operator_vault_reward_receiver_token_account,
+
+            operator_token_account: self.instruction.operator_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -606,6 +812,11 @@ struct DistributeOperatorRewardsCpiBuilderInstruction<'a, 'b> {
     operator_vault_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_vault_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct DistributeOperatorVaultRewardRoute {
@@ -27,6 +28,12 @@ pub struct DistributeOperatorVaultRewardRoute {
     pub operator_vault_reward_receiver: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeOperatorVaultRewardRoute {
@@ -42,7 +49,7 @@ impl DistributeOperatorVaultRewardRoute {
         args: DistributeOperatorVaultRewardRouteInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -78,6 +85,41 @@ impl DistributeOperatorVaultRewardRoute {
             self.system_program,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_vault_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DistributeOperatorVaultRewardRouteInstructionData::new()
             .try_to_vec()
@@ -113,6 +155,7 @@ impl Default for DistributeOperatorVaultRewardRouteInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeOperatorVaultRewardRouteInstructionArgs {
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -129,6 +172,9 @@ pub struct DistributeOperatorVaultRewardRouteInstructionArgs {
 ///   6. `[]` operator_vault_reward_router
 ///   7. `[writable]` operator_vault_reward_receiver
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` token_program
+///   10. `[writable, optional]` ncn_reward_receiver_token_account
+///   11. `[writable, optional]` operator_vault_reward_receiver_token_account
 #[derive(Clone, Debug, Default)]
 pub struct DistributeOperatorVaultRewardRouteBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -140,6 +186,10 @@ pub struct DistributeOperatorVaultRewardRouteBuilder {
     operator_vault_reward_router: Option<solana_program::pubkey::Pubkey>,
     operator_vault_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -206,6 +256,40 @@ impl DistributeOperatorVaultRewardRouteBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -251,8 +335,13 @@ impl DistributeOperatorVaultRewardRouteBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            operator_vault_reward_receiver_token_account: self
+                .operator_vault_reward_receiver_token_account,
         };
         let args = DistributeOperatorVaultRewardRouteInstructionArgs {
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -279,6 +368,14 @@ pub struct DistributeOperatorVaultRewardRouteCpiAccounts<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_operator_vault_reward_route` CPI instruction.
@@ -303,6 +400,14 @@ pub struct DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeOperatorVaultRewardRouteInstructionArgs,
 }
@@ -324,6 +429,10 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
             operator_vault_reward_router: accounts.operator_vault_reward_router,
             operator_vault_reward_receiver: accounts.operator_vault_reward_receiver,
             system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            operator_vault_reward_receiver_token_account: accounts
+                .operator_vault_reward_receiver_token_account,
             __args: args,
         }
     }
@@ -360,7 +469,7 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -397,6 +506,41 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_vault_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -415,7 +559,7 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -426,6 +570,17 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
         account_infos.push(self.operator_vault_reward_router.clone());
         account_infos.push(self.operator_vault_reward_receiver.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            account_infos.push(operator_vault_reward_receiver_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -451,6 +606,9 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpi<'a, 'b> {
 ///   6. `[]` operator_vault_reward_router
 ///   7. `[writable]` operator_vault_reward_receiver
 ///   8. `[]` system_program
+///   9. `[optional]` token_program
+///   10. `[writable, optional]` ncn_reward_receiver_token_account
+///   11. `[writable, optional]` operator_vault_reward_receiver_token_account
 #[derive(Clone, Debug)]
 pub struct DistributeOperatorVaultRewardRouteCpiBuilder<'a, 'b> {
     instruction: Box<DistributeOperatorVaultRewardRouteCpiBuilderInstruction<'a, 'b>>,
@@ -469,6 +627,10 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpiBuilder<'a, 'b> {
             operator_vault_reward_router: None,
             operator_vault_reward_receiver: None,
             system_program: None,
+            token_program: None,
+            ncn_reward_receiver_token_account: None,
+            operator_vault_reward_receiver_token_account: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -543,6 +705,45 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction
+            .operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -590,6 +791,7 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = DistributeOperatorVaultRewardRouteInstructionArgs {
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = DistributeOperatorVaultRewardRouteCpi {
@@ -630,6 +832,14 @@ impl<'a, 'b> DistributeOperatorVaultRewardRouteCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            token_program: self.instruction.token_program,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            operator_vault_reward_receiver_token_account: self
+                .instruction
+                .operator_vault_reward_receiver_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -651,6 +861,11 @@ struct DistributeOperatorVaultRewardRouteCpiBuilderInstruction<'a, 'b> {
     operator_vault_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_vault_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct DistributeProtocolRewards {
@@ -23,6 +24,12 @@ pub struct DistributeProtocolRewards {
     pub protocol_fee_wallet: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub protocol_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeProtocolRewards {
@@ -38,7 +45,7 @@ impl DistributeProtocolRewards {
         args: DistributeProtocolRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -66,6 +73,39 @@ impl DistributeProtocolRewards {
             self.system_program,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_fee_wallet_token_account) = self.protocol_fee_wallet_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                protocol_fee_wallet_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DistributeProtocolRewardsInstructionData::new()
             .try_to_vec()
@@ -101,6 +141,7 @@ impl Default for DistributeProtocolRewardsInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeProtocolRewardsInstructionArgs {
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -115,6 +156,9 @@ pub struct DistributeProtocolRewardsInstructionArgs {
 ///   4. `[writable]` ncn_reward_receiver
 ///   5. `[writable]` protocol_fee_wallet
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` token_program
+///   8. `[writable, optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` protocol_fee_wallet_token_account
 #[derive(Clone, Debug, Default)]
 pub struct DistributeProtocolRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -124,6 +168,10 @@ pub struct DistributeProtocolRewardsBuilder {
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     protocol_fee_wallet: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    protocol_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -177,6 +225,39 @@ impl DistributeProtocolRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_fee_wallet_token_account(
+        &mut self,
+        protocol_fee_wallet_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_fee_wallet_token_account = protocol_fee_wallet_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -218,8 +299,12 @@ impl DistributeProtocolRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            protocol_fee_wallet_token_account: self.protocol_fee_wallet_token_account,
         };
         let args = DistributeProtocolRewardsInstructionArgs {
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -242,6 +327,14 @@ pub struct DistributeProtocolRewardsCpiAccounts<'a, 'b> {
     pub protocol_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub protocol_fee_wallet_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_protocol_rewards` CPI instruction.
@@ -262,6 +355,14 @@ pub struct DistributeProtocolRewardsCpi<'a, 'b> {
     pub protocol_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub protocol_fee_wallet_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeProtocolRewardsInstructionArgs,
 }
@@ -281,6 +382,9 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
             ncn_reward_receiver: accounts.ncn_reward_receiver,
             protocol_fee_wallet: accounts.protocol_fee_wallet,
             system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            protocol_fee_wallet_token_account: accounts.protocol_fee_wallet_token_account,
             __args: args,
         }
     }
@@ -317,7 +421,7 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -346,6 +450,39 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_fee_wallet_token_account) = self.protocol_fee_wallet_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *protocol_fee_wallet_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -364,7 +501,7 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -373,6 +510,15 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
         account_infos.push(self.ncn_reward_receiver.clone());
         account_infos.push(self.protocol_fee_wallet.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(protocol_fee_wallet_token_account) = self.protocol_fee_wallet_token_account {
+            account_infos.push(protocol_fee_wallet_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -396,6 +542,9 @@ impl<'a, 'b> DistributeProtocolRewardsCpi<'a, 'b> {
 ///   4. `[writable]` ncn_reward_receiver
 ///   5. `[writable]` protocol_fee_wallet
 ///   6. `[]` system_program
+///   7. `[optional]` token_program
+///   8. `[writable, optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` protocol_fee_wallet_token_account
 #[derive(Clone, Debug)]
 pub struct DistributeProtocolRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeProtocolRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -412,6 +561,10 @@ impl<'a, 'b> DistributeProtocolRewardsCpiBuilder<'a, 'b> {
             ncn_reward_receiver: None,
             protocol_fee_wallet: None,
             system_program: None,
+            token_program: None,
+            ncn_reward_receiver_token_account: None,
+            protocol_fee_wallet_token_account: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -470,6 +623,43 @@ impl<'a, 'b> DistributeProtocolRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_fee_wallet_token_account(
+        &mut self,
+        protocol_fee_wallet_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_wallet_token_account = protocol_fee_wallet_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -517,6 +707,7 @@ impl<'a, 'b> DistributeProtocolRewardsCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = DistributeProtocolRewardsInstructionArgs {
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = DistributeProtocolRewardsCpi {
@@ -550,6 +741,12 @@ impl<'a, 'b> DistributeProtocolRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            token_program: self.instruction.token_program,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            protocol_fee_wallet_token_account: self.instruction.protocol_fee_wallet_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -569,6 +766,10 @@ struct DistributeProtocolRewardsCpiBuilderInstruction<'a, 'b> {
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     protocol_fee_wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    protocol_fee_wallet_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
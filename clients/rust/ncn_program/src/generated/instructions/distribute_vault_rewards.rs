@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct DistributeVaultRewards {
@@ -27,6 +28,16 @@ pub struct DistributeVaultRewards {
     pub operator_vault_reward_receiver: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub vault_registry: solana_program::pubkey::Pubkey,
+
+    pub vault_reward_recipient: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub vault_reward_recipient_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeVaultRewards {
@@ -42,7 +53,7 @@ impl DistributeVaultRewards {
         args: DistributeVaultRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -77,6 +88,44 @@ impl DistributeVaultRewards {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_registry,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_reward_recipient,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_vault_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(vault_reward_recipient_token_account) =
+            self.vault_reward_recipient_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_reward_recipient_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DistributeVaultRewardsInstructionData::new()
             .try_to_vec()
@@ -112,6 +161,7 @@ impl Default for DistributeVaultRewardsInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeVaultRewardsInstructionArgs {
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -128,6 +178,11 @@ pub struct DistributeVaultRewardsInstructionArgs {
 ///   6. `[writable]` operator_vault_reward_router
 ///   7. `[writable]` operator_vault_reward_receiver
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[]` vault_registry
+///   10. `[writable]` vault_reward_recipient
+///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   12. `[writable, optional]` operator_vault_reward_receiver_token_account
+///   13. `[writable, optional]` vault_reward_recipient_token_account
 #[derive(Clone, Debug, Default)]
 pub struct DistributeVaultRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -139,6 +194,12 @@ pub struct DistributeVaultRewardsBuilder {
     operator_vault_reward_router: Option<solana_program::pubkey::Pubkey>,
     operator_vault_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    vault_registry: Option<solana_program::pubkey::Pubkey>,
+    vault_reward_recipient: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    vault_reward_recipient_token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -203,6 +264,50 @@ impl DistributeVaultRewardsBuilder {
         self
     }
     #[inline(always)]
+    pub fn vault_registry(&mut self, vault_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault_registry = Some(vault_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_reward_recipient(
+        &mut self,
+        vault_reward_recipient: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_reward_recipient = Some(vault_reward_recipient);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault_reward_recipient_token_account(
+        &mut self,
+        vault_reward_recipient_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_reward_recipient_token_account = vault_reward_recipient_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
         self
@@ -245,8 +350,19 @@ impl DistributeVaultRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            vault_registry: self.vault_registry.expect("vault_registry is not set"),
+            vault_reward_recipient: self
+                .vault_reward_recipient
+                .expect("vault_reward_recipient is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            operator_vault_reward_receiver_token_account: self
+                .operator_vault_reward_receiver_token_account,
+            vault_reward_recipient_token_account: self.vault_reward_recipient_token_account,
         };
         let args = DistributeVaultRewardsInstructionArgs {
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -273,6 +389,18 @@ pub struct DistributeVaultRewardsCpiAccounts<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_reward_recipient: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub vault_reward_recipient_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_vault_rewards` CPI instruction.
@@ -297,6 +425,18 @@ pub struct DistributeVaultRewardsCpi<'a, 'b> {
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_reward_recipient: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub vault_reward_recipient_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeVaultRewardsInstructionArgs,
 }
@@ -318,6 +458,12 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
             operator_vault_reward_router: accounts.operator_vault_reward_router,
             operator_vault_reward_receiver: accounts.operator_vault_reward_receiver,
             system_program: accounts.system_program,
+            vault_registry: accounts.vault_registry,
+            vault_reward_recipient: accounts.vault_reward_recipient,
+            token_program: accounts.token_program,
+            operator_vault_reward_receiver_token_account: accounts
+                .operator_vault_reward_receiver_token_account,
+            vault_reward_recipient_token_account: accounts.vault_reward_recipient_token_account,
             __args: args,
         }
     }
@@ -354,7 +500,7 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -391,6 +537,44 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_registry.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_reward_recipient.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_vault_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(vault_reward_recipient_token_account) =
+            self.vault_reward_recipient_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_reward_recipient_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -409,7 +593,7 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -420,6 +604,19 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
         account_infos.push(self.operator_vault_reward_router.clone());
         account_infos.push(self.operator_vault_reward_receiver.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.vault_registry.clone());
+        account_infos.push(self.vault_reward_recipient.clone());
+        account_infos.push(self.token_program.clone());
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            account_infos.push(operator_vault_reward_receiver_token_account.clone());
+        }
+        if let Some(vault_reward_recipient_token_account) =
+            self.vault_reward_recipient_token_account
+        {
+            account_infos.push(vault_reward_recipient_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -445,6 +642,11 @@ impl<'a, 'b> DistributeVaultRewardsCpi<'a, 'b> {
 ///   6. `[writable]` operator_vault_reward_router
 ///   7. `[writable]` operator_vault_reward_receiver
 ///   8. `[]` system_program
+///   9. `[]` vault_registry
+///   10. `[writable]` vault_reward_recipient
+///   11. `[]` token_program
+///   12. `[writable, optional]` operator_vault_reward_receiver_token_account
+///   13. `[writable, optional]` vault_reward_recipient_token_account
 #[derive(Clone, Debug)]
 pub struct DistributeVaultRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeVaultRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -463,6 +665,12 @@ impl<'a, 'b> DistributeVaultRewardsCpiBuilder<'a, 'b> {
             operator_vault_reward_router: None,
             operator_vault_reward_receiver: None,
             system_program: None,
+            vault_registry: None,
+            vault_reward_recipient: None,
+            token_program: None,
+            operator_vault_reward_receiver_token_account: None,
+            vault_reward_recipient_token_account: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -535,6 +743,61 @@ impl<'a, 'b> DistributeVaultRewardsCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn vault_registry(
+        &mut self,
+        vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_registry = Some(vault_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_reward_recipient(
+        &mut self,
+        vault_reward_recipient: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_reward_recipient = Some(vault_reward_recipient);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction
+            .operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault_reward_recipient_token_account(
+        &mut self,
+        vault_reward_recipient_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.vault_reward_recipient_token_account =
+            vault_reward_recipient_token_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
         self
@@ -581,6 +844,7 @@ impl<'a, 'b> DistributeVaultRewardsCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = DistributeVaultRewardsInstructionArgs {
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = DistributeVaultRewardsCpi {
@@ -618,6 +882,29 @@ impl<'a, 'b> DistributeVaultRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            vault_registry: self
+                .instruction
+                .vault_registry
+                .expect("vault_registry is not set"),
+
+            vault_reward_recipient: self
+                .instruction
+                .vault_reward_recipient
+                .expect("vault_reward_recipient is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            operator_vault_reward_receiver_token_account: self
+                .instruction
+                .operator_vault_reward_receiver_token_account,
+
+            vault_reward_recipient_token_account: self
+                .instruction
+                .vault_reward_recipient_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -639,6 +926,13 @@ struct DistributeVaultRewardsCpiBuilderInstruction<'a, 'b> {
     operator_vault_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_vault_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_reward_recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_reward_recipient_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -108,6 +108,7 @@ impl Default for InitializeBallotBoxInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeBallotBoxInstructionArgs {
     pub epoch: u64,
+    pub init_if_needed: bool,
 }
 
 /// Instruction builder for `InitializeBallotBox`.
@@ -133,6 +134,7 @@ pub struct InitializeBallotBoxBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     consensus_result: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -189,6 +191,11 @@ impl InitializeBallotBoxBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -223,6 +230,10 @@ impl InitializeBallotBoxBuilder {
         };
         let args = InitializeBallotBoxInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -427,6 +438,7 @@ impl<'a, 'b> InitializeBallotBoxCpiBuilder<'a, 'b> {
             system_program: None,
             consensus_result: None,
             epoch: None,
+            init_if_needed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -497,6 +509,11 @@ impl<'a, 'b> InitializeBallotBoxCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.instruction.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -540,6 +557,11 @@ impl<'a, 'b> InitializeBallotBoxCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializeBallotBoxInstructionArgs {
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .instruction
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
         let instruction = InitializeBallotBoxCpi {
             __program: self.instruction.__program,
@@ -595,6 +617,7 @@ struct InitializeBallotBoxCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     consensus_result: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -108,6 +108,7 @@ impl Default for InitializeEpochSnapshotInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeEpochSnapshotInstructionArgs {
     pub epoch: u64,
+    pub init_if_needed: bool,
 }
 
 /// Instruction builder for `InitializeEpochSnapshot`.
@@ -133,6 +134,7 @@ pub struct InitializeEpochSnapshotBuilder {
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -186,6 +188,11 @@ impl InitializeEpochSnapshotBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -220,6 +227,10 @@ impl InitializeEpochSnapshotBuilder {
         };
         let args = InitializeEpochSnapshotInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -424,6 +435,7 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
             account_payer: None,
             system_program: None,
             epoch: None,
+            init_if_needed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -494,6 +506,11 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.instruction.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -537,6 +554,11 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializeEpochSnapshotInstructionArgs {
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .instruction
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
         let instruction = InitializeEpochSnapshotCpi {
             __program: self.instruction.__program,
@@ -595,6 +617,7 @@ struct InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b> {
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -96,6 +96,7 @@ impl Default for InitializeEpochStateInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeEpochStateInstructionArgs {
     pub epoch: u64,
+    pub init_if_needed: bool,
 }
 
 /// Instruction builder for `InitializeEpochState`.
@@ -117,6 +118,7 @@ pub struct InitializeEpochStateBuilder {
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -160,6 +162,11 @@ impl InitializeEpochStateBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -192,6 +199,10 @@ impl InitializeEpochStateBuilder {
         };
         let args = InitializeEpochStateInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -372,6 +383,7 @@ impl<'a, 'b> InitializeEpochStateCpiBuilder<'a, 'b> {
             account_payer: None,
             system_program: None,
             epoch: None,
+            init_if_needed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -426,6 +438,11 @@ impl<'a, 'b> InitializeEpochStateCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.instruction.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -469,6 +486,11 @@ impl<'a, 'b> InitializeEpochStateCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializeEpochStateInstructionArgs {
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .instruction
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
         let instruction = InitializeEpochStateCpi {
             __program: self.instruction.__program,
@@ -515,6 +537,7 @@ struct InitializeEpochStateCpiBuilderInstruction<'a, 'b> {
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
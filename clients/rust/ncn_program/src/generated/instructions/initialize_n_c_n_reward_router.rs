@@ -102,6 +102,7 @@ impl Default for InitializeNCNRewardRouterInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeNCNRewardRouterInstructionArgs {
     pub epoch: u64,
+    pub init_if_needed: bool,
 }
 
 /// Instruction builder for `InitializeNCNRewardRouter`.
@@ -125,6 +126,7 @@ pub struct InitializeNCNRewardRouterBuilder {
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -179,6 +181,11 @@ impl InitializeNCNRewardRouterBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -216,6 +223,10 @@ impl InitializeNCNRewardRouterBuilder {
         };
         let args = InitializeNCNRewardRouterInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -408,6 +419,7 @@ impl<'a, 'b> InitializeNCNRewardRouterCpiBuilder<'a, 'b> {
             account_payer: None,
             system_program: None,
             epoch: None,
+            init_if_needed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -470,6 +482,11 @@ impl<'a, 'b> InitializeNCNRewardRouterCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.instruction.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -513,6 +530,11 @@ impl<'a, 'b> InitializeNCNRewardRouterCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializeNCNRewardRouterInstructionArgs {
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .instruction
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
         let instruction = InitializeNCNRewardRouterCpi {
             __program: self.instruction.__program,
@@ -568,6 +590,7 @@ struct InitializeNCNRewardRouterCpiBuilderInstruction<'a, 'b> {
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
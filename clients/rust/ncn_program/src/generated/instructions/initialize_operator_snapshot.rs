@@ -126,6 +126,7 @@ impl Default for InitializeOperatorSnapshotInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeOperatorSnapshotInstructionArgs {
     pub epoch: u64,
+    pub init_if_needed: bool,
 }
 
 /// Instruction builder for `InitializeOperatorSnapshot`.
@@ -157,6 +158,7 @@ pub struct InitializeOperatorSnapshotBuilder {
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -234,6 +236,11 @@ impl InitializeOperatorSnapshotBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -275,6 +282,10 @@ impl InitializeOperatorSnapshotBuilder {
         };
         let args = InitializeOperatorSnapshotInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -515,6 +526,7 @@ impl<'a, 'b> InitializeOperatorSnapshotCpiBuilder<'a, 'b> {
             account_payer: None,
             system_program: None,
             epoch: None,
+            init_if_needed: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -609,6 +621,11 @@ impl<'a, 'b> InitializeOperatorSnapshotCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn init_if_needed(&mut self, init_if_needed: bool) -> &mut Self {
+        self.instruction.init_if_needed = Some(init_if_needed);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -652,6 +669,11 @@ impl<'a, 'b> InitializeOperatorSnapshotCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializeOperatorSnapshotInstructionArgs {
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            init_if_needed: self
+                .instruction
+                .init_if_needed
+                .clone()
+                .expect("init_if_needed is not set"),
         };
         let instruction = InitializeOperatorSnapshotCpi {
             __program: self.instruction.__program,
@@ -725,6 +747,7 @@ struct InitializeOperatorSnapshotCpiBuilderInstruction<'a, 'b> {
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
+    init_if_needed: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,21 +5,40 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#acquire_keeper_lease;
 pub(crate) mod r#admin_register_st_mint;
+pub(crate) mod r#admin_set_admin_governance;
+pub(crate) mod r#admin_set_admin_multisig;
+pub(crate) mod r#admin_set_default_weight;
+pub(crate) mod r#admin_set_fee_group;
 pub(crate) mod r#admin_set_new_admin;
 pub(crate) mod r#admin_set_parameters;
+pub(crate) mod r#admin_set_reward_mint;
 pub(crate) mod r#admin_set_st_mint;
 pub(crate) mod r#admin_set_tie_breaker;
+pub(crate) mod r#admin_set_vault_metadata;
+pub(crate) mod r#admin_set_vault_reward_recipient;
 pub(crate) mod r#admin_set_weight;
+pub(crate) mod r#admin_set_weights;
+pub(crate) mod r#apply_parameters;
+pub(crate) mod r#batch_initialize_operator_snapshot;
 pub(crate) mod r#cast_vote;
+pub(crate) mod r#close_all_epoch_accounts;
 pub(crate) mod r#close_epoch_account;
+pub(crate) mod r#close_reward_receiver;
+pub(crate) mod r#copy_previous_epoch_weights;
+pub(crate) mod r#distribute_fee_group_rewards;
 pub(crate) mod r#distribute_n_c_n_rewards;
 pub(crate) mod r#distribute_operator_rewards;
 pub(crate) mod r#distribute_operator_vault_reward_route;
 pub(crate) mod r#distribute_protocol_rewards;
 pub(crate) mod r#distribute_vault_rewards;
+pub(crate) mod r#get_consensus_result;
+pub(crate) mod r#get_operator_reward_route;
+pub(crate) mod r#get_winning_ballot;
 pub(crate) mod r#initialize_ballot_box;
 pub(crate) mod r#initialize_config;
+pub(crate) mod r#initialize_epoch_accounts;
 pub(crate) mod r#initialize_epoch_snapshot;
 pub(crate) mod r#initialize_epoch_state;
 pub(crate) mod r#initialize_n_c_n_reward_router;
@@ -27,31 +46,55 @@ pub(crate) mod r#initialize_operator_snapshot;
 pub(crate) mod r#initialize_operator_vault_reward_router;
 pub(crate) mod r#initialize_vault_registry;
 pub(crate) mod r#initialize_weight_table;
+pub(crate) mod r#mark_epoch_skipped;
 pub(crate) mod r#realloc_ballot_box;
 pub(crate) mod r#realloc_n_c_n_reward_router;
 pub(crate) mod r#realloc_vault_registry;
 pub(crate) mod r#realloc_weight_table;
 pub(crate) mod r#register_vault;
+pub(crate) mod r#route_and_distribute_n_c_n_rewards;
 pub(crate) mod r#route_n_c_n_rewards;
 pub(crate) mod r#route_operator_vault_rewards;
 pub(crate) mod r#set_epoch_weights;
+pub(crate) mod r#set_operator_metadata;
+pub(crate) mod r#set_weight_from_switchboard;
+pub(crate) mod r#shrink_n_c_n_reward_router;
 pub(crate) mod r#snapshot_vault_operator_delegation;
 
+pub use self::r#acquire_keeper_lease::*;
 pub use self::r#admin_register_st_mint::*;
+pub use self::r#admin_set_admin_governance::*;
+pub use self::r#admin_set_admin_multisig::*;
+pub use self::r#admin_set_default_weight::*;
+pub use self::r#admin_set_fee_group::*;
 pub use self::r#admin_set_new_admin::*;
 pub use self::r#admin_set_parameters::*;
+pub use self::r#admin_set_reward_mint::*;
 pub use self::r#admin_set_st_mint::*;
 pub use self::r#admin_set_tie_breaker::*;
+pub use self::r#admin_set_vault_metadata::*;
+pub use self::r#admin_set_vault_reward_recipient::*;
 pub use self::r#admin_set_weight::*;
+pub use self::r#admin_set_weights::*;
+pub use self::r#apply_parameters::*;
+pub use self::r#batch_initialize_operator_snapshot::*;
 pub use self::r#cast_vote::*;
+pub use self::r#close_all_epoch_accounts::*;
 pub use self::r#close_epoch_account::*;
+pub use self::r#close_reward_receiver::*;
+pub use self::r#copy_previous_epoch_weights::*;
+pub use self::r#distribute_fee_group_rewards::*;
 pub use self::r#distribute_n_c_n_rewards::*;
 pub use self::r#distribute_operator_rewards::*;
 pub use self::r#distribute_operator_vault_reward_route::*;
 pub use self::r#distribute_protocol_rewards::*;
 pub use self::r#distribute_vault_rewards::*;
+pub use self::r#get_consensus_result::*;
+pub use self::r#get_operator_reward_route::*;
+pub use self::r#get_winning_ballot::*;
 pub use self::r#initialize_ballot_box::*;
 pub use self::r#initialize_config::*;
+pub use self::r#initialize_epoch_accounts::*;
 pub use self::r#initialize_epoch_snapshot::*;
 pub use self::r#initialize_epoch_state::*;
 pub use self::r#initialize_n_c_n_reward_router::*;
@@ -59,12 +102,17 @@ pub use self::r#initialize_operator_snapshot::*;
 pub use self::r#initialize_operator_vault_reward_router::*;
 pub use self::r#initialize_vault_registry::*;
 pub use self::r#initialize_weight_table::*;
+pub use self::r#mark_epoch_skipped::*;
 pub use self::r#realloc_ballot_box::*;
 pub use self::r#realloc_n_c_n_reward_router::*;
 pub use self::r#realloc_vault_registry::*;
 pub use self::r#realloc_weight_table::*;
 pub use self::r#register_vault::*;
+pub use self::r#route_and_distribute_n_c_n_rewards::*;
 pub use self::r#route_n_c_n_rewards::*;
 pub use self::r#route_operator_vault_rewards::*;
 pub use self::r#set_epoch_weights::*;
+pub use self::r#set_operator_metadata::*;
+pub use self::r#set_weight_from_switchboard::*;
+pub use self::r#shrink_n_c_n_reward_router::*;
 pub use self::r#snapshot_vault_operator_delegation::*;
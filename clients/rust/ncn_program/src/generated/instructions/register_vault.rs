@@ -19,6 +19,10 @@ pub struct RegisterVault {
     pub vault: solana_program::pubkey::Pubkey,
 
     pub ncn_vault_ticket: solana_program::pubkey::Pubkey,
+
+    pub restaking_config: solana_program::pubkey::Pubkey,
+
+    pub st_mint: solana_program::pubkey::Pubkey,
 }
 
 impl RegisterVault {
@@ -30,7 +34,7 @@ impl RegisterVault {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -49,6 +53,14 @@ impl RegisterVault {
             self.ncn_vault_ticket,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.st_mint,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = RegisterVaultInstructionData::new().try_to_vec().unwrap();
 
@@ -86,6 +98,8 @@ impl Default for RegisterVaultInstructionData {
 ///   2. `[]` ncn
 ///   3. `[]` vault
 ///   4. `[]` ncn_vault_ticket
+///   5. `[]` restaking_config
+///   6. `[]` st_mint
 #[derive(Clone, Debug, Default)]
 pub struct RegisterVaultBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -93,6 +107,8 @@ pub struct RegisterVaultBuilder {
     ncn: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
     ncn_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    restaking_config: Option<solana_program::pubkey::Pubkey>,
+    st_mint: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -128,6 +144,19 @@ impl RegisterVaultBuilder {
         self.ncn_vault_ticket = Some(ncn_vault_ticket);
         self
     }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
+    pub fn st_mint(&mut self, st_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.st_mint = Some(st_mint);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -154,6 +183,8 @@ impl RegisterVaultBuilder {
             ncn: self.ncn.expect("ncn is not set"),
             vault: self.vault.expect("vault is not set"),
             ncn_vault_ticket: self.ncn_vault_ticket.expect("ncn_vault_ticket is not set"),
+            restaking_config: self.restaking_config.expect("restaking_config is not set"),
+            st_mint: self.st_mint.expect("st_mint is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -171,6 +202,10 @@ pub struct RegisterVaultCpiAccounts<'a, 'b> {
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub st_mint: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `register_vault` CPI instruction.
@@ -187,6 +222,10 @@ pub struct RegisterVaultCpi<'a, 'b> {
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub st_mint: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
@@ -201,6 +240,8 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             ncn: accounts.ncn,
             vault: accounts.vault,
             ncn_vault_ticket: accounts.ncn_vault_ticket,
+            restaking_config: accounts.restaking_config,
+            st_mint: accounts.st_mint,
         }
     }
     #[inline(always)]
@@ -236,7 +277,7 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -257,6 +298,14 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             *self.ncn_vault_ticket.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.st_mint.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -271,13 +320,15 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault_registry.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.ncn_vault_ticket.clone());
+        account_infos.push(self.restaking_config.clone());
+        account_infos.push(self.st_mint.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -299,6 +350,8 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
 ///   2. `[]` ncn
 ///   3. `[]` vault
 ///   4. `[]` ncn_vault_ticket
+///   5. `[]` restaking_config
+///   6. `[]` st_mint
 #[derive(Clone, Debug)]
 pub struct RegisterVaultCpiBuilder<'a, 'b> {
     instruction: Box<RegisterVaultCpiBuilderInstruction<'a, 'b>>,
@@ -313,6 +366,8 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
             ncn: None,
             vault: None,
             ncn_vault_ticket: None,
+            restaking_config: None,
+            st_mint: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -351,6 +406,22 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
         self.instruction.ncn_vault_ticket = Some(ncn_vault_ticket);
         self
     }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
+    pub fn st_mint(
+        &mut self,
+        st_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.st_mint = Some(st_mint);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -410,6 +481,13 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
                 .instruction
                 .ncn_vault_ticket
                 .expect("ncn_vault_ticket is not set"),
+
+            restaking_config: self
+                .instruction
+                .restaking_config
+                .expect("restaking_config is not set"),
+
+            st_mint: self.instruction.st_mint.expect("st_mint is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -426,6 +504,8 @@ struct RegisterVaultCpiBuilderInstruction<'a, 'b> {
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    restaking_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    st_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct RouteNCNRewards {
@@ -23,6 +24,13 @@ pub struct RouteNCNRewards {
     pub ncn_reward_router: solana_program::pubkey::Pubkey,
 
     pub ncn_reward_receiver: solana_program::pubkey::Pubkey,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    /// Paid the cranker fee in SOL, routing a reward mint pays no fee
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RouteNCNRewards {
@@ -38,7 +46,7 @@ impl RouteNCNRewards {
         args: RouteNCNRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -66,6 +74,36 @@ impl RouteNCNRewards {
             self.ncn_reward_receiver,
             false,
         ));
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = RouteNCNRewardsInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -100,6 +138,7 @@ impl Default for RouteNCNRewardsInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouteNCNRewardsInstructionArgs {
     pub max_iterations: u16,
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -114,6 +153,9 @@ pub struct RouteNCNRewardsInstructionArgs {
 ///   4. `[]` ballot_box
 ///   5. `[writable]` ncn_reward_router
 ///   6. `[writable]` ncn_reward_receiver
+///   7. `[optional]` ncn_reward_receiver_token_account
+///   8. `[writable, signer, optional]` cranker
+///   9. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct RouteNCNRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -123,7 +165,11 @@ pub struct RouteNCNRewardsBuilder {
     ballot_box: Option<solana_program::pubkey::Pubkey>,
     ncn_reward_router: Option<solana_program::pubkey::Pubkey>,
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     max_iterations: Option<u16>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -173,11 +219,42 @@ impl RouteNCNRewardsBuilder {
         self.ncn_reward_receiver = Some(ncn_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Paid the cranker fee in SOL, routing a reward mint pays no fee
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.max_iterations = Some(max_iterations);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -215,12 +292,16 @@ impl RouteNCNRewardsBuilder {
             ncn_reward_receiver: self
                 .ncn_reward_receiver
                 .expect("ncn_reward_receiver is not set"),
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            cranker: self.cranker,
+            system_program: self.system_program,
         };
         let args = RouteNCNRewardsInstructionArgs {
             max_iterations: self
                 .max_iterations
                 .clone()
                 .expect("max_iterations is not set"),
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -243,6 +324,14 @@ pub struct RouteNCNRewardsCpiAccounts<'a, 'b> {
     pub ncn_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Paid the cranker fee in SOL, routing a reward mint pays no fee
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `route_n_c_n_rewards` CPI instruction.
@@ -263,6 +352,14 @@ pub struct RouteNCNRewardsCpi<'a, 'b> {
     pub ncn_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Paid the cranker fee in SOL, routing a reward mint pays no fee
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: RouteNCNRewardsInstructionArgs,
 }
@@ -282,6 +379,9 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
             ballot_box: accounts.ballot_box,
             ncn_reward_router: accounts.ncn_reward_router,
             ncn_reward_receiver: accounts.ncn_reward_receiver,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            cranker: accounts.cranker,
+            system_program: accounts.system_program,
             __args: args,
         }
     }
@@ -318,7 +418,7 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -347,6 +447,39 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
             *self.ncn_reward_receiver.key,
             false,
         ));
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -363,7 +496,7 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -372,6 +505,15 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
         account_infos.push(self.ballot_box.clone());
         account_infos.push(self.ncn_reward_router.clone());
         account_infos.push(self.ncn_reward_receiver.clone());
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -395,6 +537,9 @@ impl<'a, 'b> RouteNCNRewardsCpi<'a, 'b> {
 ///   4. `[]` ballot_box
 ///   5. `[writable]` ncn_reward_router
 ///   6. `[writable]` ncn_reward_receiver
+///   7. `[optional]` ncn_reward_receiver_token_account
+///   8. `[writable, signer, optional]` cranker
+///   9. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct RouteNCNRewardsCpiBuilder<'a, 'b> {
     instruction: Box<RouteNCNRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -411,7 +556,11 @@ impl<'a, 'b> RouteNCNRewardsCpiBuilder<'a, 'b> {
             ballot_box: None,
             ncn_reward_router: None,
             ncn_reward_receiver: None,
+            ncn_reward_receiver_token_account: None,
+            cranker: None,
+            system_program: None,
             max_iterations: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -470,11 +619,47 @@ impl<'a, 'b> RouteNCNRewardsCpiBuilder<'a, 'b> {
         self.instruction.ncn_reward_receiver = Some(ncn_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Paid the cranker fee in SOL, routing a reward mint pays no fee
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.instruction.max_iterations = Some(max_iterations);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -527,6 +712,7 @@ impl<'a, 'b> RouteNCNRewardsCpiBuilder<'a, 'b> {
                 .max_iterations
                 .clone()
                 .expect("max_iterations is not set"),
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = RouteNCNRewardsCpi {
@@ -557,6 +743,12 @@ impl<'a, 'b> RouteNCNRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .ncn_reward_receiver
                 .expect("ncn_reward_receiver is not set"),
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            cranker: self.instruction.cranker,
+
+            system_program: self.instruction.system_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -576,7 +768,11 @@ struct RouteNCNRewardsCpiBuilderInstruction<'a, 'b> {
     ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     max_iterations: Option<u16>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct RouteOperatorVaultRewards {
@@ -21,6 +22,14 @@ pub struct RouteOperatorVaultRewards {
     pub operator_vault_reward_router: solana_program::pubkey::Pubkey,
 
     pub operator_vault_reward_receiver: solana_program::pubkey::Pubkey,
+
+    pub config: Option<solana_program::pubkey::Pubkey>,
+
+    pub operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RouteOperatorVaultRewards {
@@ -36,7 +45,7 @@ impl RouteOperatorVaultRewards {
         args: RouteOperatorVaultRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -60,6 +69,48 @@ impl RouteOperatorVaultRewards {
             self.operator_vault_reward_receiver,
             false,
         ));
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                config, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operator_vault_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = RouteOperatorVaultRewardsInstructionData::new()
             .try_to_vec()
@@ -96,6 +147,7 @@ impl Default for RouteOperatorVaultRewardsInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouteOperatorVaultRewardsInstructionArgs {
     pub max_iterations: u16,
+    pub mint: Option<Pubkey>,
     pub epoch: u64,
 }
 
@@ -109,6 +161,10 @@ pub struct RouteOperatorVaultRewardsInstructionArgs {
 ///   3. `[]` operator_snapshot
 ///   4. `[writable]` operator_vault_reward_router
 ///   5. `[writable]` operator_vault_reward_receiver
+///   6. `[optional]` config
+///   7. `[optional]` operator_vault_reward_receiver_token_account
+///   8. `[writable, signer, optional]` cranker
+///   9. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct RouteOperatorVaultRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -117,7 +173,12 @@ pub struct RouteOperatorVaultRewardsBuilder {
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
     operator_vault_reward_router: Option<solana_program::pubkey::Pubkey>,
     operator_vault_reward_receiver: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     max_iterations: Option<u16>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -165,11 +226,48 @@ impl RouteOperatorVaultRewardsBuilder {
         self.operator_vault_reward_receiver = Some(operator_vault_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn config(&mut self, config: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.config = config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.max_iterations = Some(max_iterations);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -208,12 +306,18 @@ impl RouteOperatorVaultRewardsBuilder {
             operator_vault_reward_receiver: self
                 .operator_vault_reward_receiver
                 .expect("operator_vault_reward_receiver is not set"),
+            config: self.config,
+            operator_vault_reward_receiver_token_account: self
+                .operator_vault_reward_receiver_token_account,
+            cranker: self.cranker,
+            system_program: self.system_program,
         };
         let args = RouteOperatorVaultRewardsInstructionArgs {
             max_iterations: self
                 .max_iterations
                 .clone()
                 .expect("max_iterations is not set"),
+            mint: self.mint.clone(),
             epoch: self.epoch.clone().expect("epoch is not set"),
         };
 
@@ -234,6 +338,15 @@ pub struct RouteOperatorVaultRewardsCpiAccounts<'a, 'b> {
     pub operator_vault_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `route_operator_vault_rewards` CPI instruction.
@@ -252,6 +365,15 @@ pub struct RouteOperatorVaultRewardsCpi<'a, 'b> {
     pub operator_vault_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_vault_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: RouteOperatorVaultRewardsInstructionArgs,
 }
@@ -270,6 +392,11 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
             operator_snapshot: accounts.operator_snapshot,
             operator_vault_reward_router: accounts.operator_vault_reward_router,
             operator_vault_reward_receiver: accounts.operator_vault_reward_receiver,
+            config: accounts.config,
+            operator_vault_reward_receiver_token_account: accounts
+                .operator_vault_reward_receiver_token_account,
+            cranker: accounts.cranker,
+            system_program: accounts.system_program,
             __args: args,
         }
     }
@@ -306,7 +433,7 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -331,6 +458,52 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
             *self.operator_vault_reward_receiver.key,
             false,
         ));
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operator_vault_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::NCN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -349,7 +522,7 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.ncn.clone());
@@ -357,6 +530,20 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
         account_infos.push(self.operator_snapshot.clone());
         account_infos.push(self.operator_vault_reward_router.clone());
         account_infos.push(self.operator_vault_reward_receiver.clone());
+        if let Some(config) = self.config {
+            account_infos.push(config.clone());
+        }
+        if let Some(operator_vault_reward_receiver_token_account) =
+            self.operator_vault_reward_receiver_token_account
+        {
+            account_infos.push(operator_vault_reward_receiver_token_account.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -379,6 +566,10 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpi<'a, 'b> {
 ///   3. `[]` operator_snapshot
 ///   4. `[writable]` operator_vault_reward_router
 ///   5. `[writable]` operator_vault_reward_receiver
+///   6. `[optional]` config
+///   7. `[optional]` operator_vault_reward_receiver_token_account
+///   8. `[writable, signer, optional]` cranker
+///   9. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct RouteOperatorVaultRewardsCpiBuilder<'a, 'b> {
     instruction: Box<RouteOperatorVaultRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -394,7 +585,12 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpiBuilder<'a, 'b> {
             operator_snapshot: None,
             operator_vault_reward_router: None,
             operator_vault_reward_receiver: None,
+            config: None,
+            operator_vault_reward_receiver_token_account: None,
+            cranker: None,
+            system_program: None,
             max_iterations: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -445,11 +641,57 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpiBuilder<'a, 'b> {
         self.instruction.operator_vault_reward_receiver = Some(operator_vault_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.config = config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vault_reward_receiver_token_account(
+        &mut self,
+        operator_vault_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction
+            .operator_vault_reward_receiver_token_account =
+            operator_vault_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.instruction.max_iterations = Some(max_iterations);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -502,6 +744,7 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpiBuilder<'a, 'b> {
                 .max_iterations
                 .clone()
                 .expect("max_iterations is not set"),
+            mint: self.instruction.mint.clone(),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
         };
         let instruction = RouteOperatorVaultRewardsCpi {
@@ -530,6 +773,16 @@ impl<'a, 'b> RouteOperatorVaultRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .operator_vault_reward_receiver
                 .expect("operator_vault_reward_receiver is not set"),
+
+            config: self.instruction.config,
+
+            operator_vault_reward_receiver_token_account: self
+                .instruction
+                .operator_vault_reward_receiver_token_account,
+
+            cranker: self.instruction.cranker,
+
+            system_program: self.instruction.system_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -548,7 +801,13 @@ struct RouteOperatorVaultRewardsCpiBuilderInstruction<'a, 'b> {
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_vault_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_vault_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     max_iterations: Option<u16>,
+    mint: Option<Pubkey>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
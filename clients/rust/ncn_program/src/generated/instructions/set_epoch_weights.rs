@@ -17,6 +17,10 @@ pub struct SetEpochWeights {
     pub vault_registry: solana_program::pubkey::Pubkey,
 
     pub weight_table: solana_program::pubkey::Pubkey,
+
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub restaking_config: solana_program::pubkey::Pubkey,
 }
 
 impl SetEpochWeights {
@@ -32,7 +36,7 @@ impl SetEpochWeights {
         args: SetEpochWeightsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -48,6 +52,14 @@ impl SetEpochWeights {
             self.weight_table,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SetEpochWeightsInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -92,12 +104,16 @@ pub struct SetEpochWeightsInstructionArgs {
 ///   1. `[]` ncn
 ///   2. `[]` vault_registry
 ///   3. `[writable]` weight_table
+///   4. `[]` config
+///   5. `[]` restaking_config
 #[derive(Clone, Debug, Default)]
 pub struct SetEpochWeightsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     vault_registry: Option<solana_program::pubkey::Pubkey>,
     weight_table: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
+    restaking_config: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -127,6 +143,19 @@ impl SetEpochWeightsBuilder {
         self
     }
     #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
         self
@@ -156,6 +185,8 @@ impl SetEpochWeightsBuilder {
             ncn: self.ncn.expect("ncn is not set"),
             vault_registry: self.vault_registry.expect("vault_registry is not set"),
             weight_table: self.weight_table.expect("weight_table is not set"),
+            config: self.config.expect("config is not set"),
+            restaking_config: self.restaking_config.expect("restaking_config is not set"),
         };
         let args = SetEpochWeightsInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -174,6 +205,10 @@ pub struct SetEpochWeightsCpiAccounts<'a, 'b> {
     pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `set_epoch_weights` CPI instruction.
@@ -188,6 +223,10 @@ pub struct SetEpochWeightsCpi<'a, 'b> {
     pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SetEpochWeightsInstructionArgs,
 }
@@ -204,6 +243,8 @@ impl<'a, 'b> SetEpochWeightsCpi<'a, 'b> {
             ncn: accounts.ncn,
             vault_registry: accounts.vault_registry,
             weight_table: accounts.weight_table,
+            config: accounts.config,
+            restaking_config: accounts.restaking_config,
             __args: args,
         }
     }
@@ -240,7 +281,7 @@ impl<'a, 'b> SetEpochWeightsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -257,6 +298,14 @@ impl<'a, 'b> SetEpochWeightsCpi<'a, 'b> {
             *self.weight_table.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -273,12 +322,14 @@ impl<'a, 'b> SetEpochWeightsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.vault_registry.clone());
         account_infos.push(self.weight_table.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.restaking_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -299,6 +350,8 @@ impl<'a, 'b> SetEpochWeightsCpi<'a, 'b> {
 ///   1. `[]` ncn
 ///   2. `[]` vault_registry
 ///   3. `[writable]` weight_table
+///   4. `[]` config
+///   5. `[]` restaking_config
 #[derive(Clone, Debug)]
 pub struct SetEpochWeightsCpiBuilder<'a, 'b> {
     instruction: Box<SetEpochWeightsCpiBuilderInstruction<'a, 'b>>,
@@ -312,6 +365,8 @@ impl<'a, 'b> SetEpochWeightsCpiBuilder<'a, 'b> {
             ncn: None,
             vault_registry: None,
             weight_table: None,
+            config: None,
+            restaking_config: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -347,6 +402,22 @@ impl<'a, 'b> SetEpochWeightsCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
         self
@@ -414,6 +485,13 @@ impl<'a, 'b> SetEpochWeightsCpiBuilder<'a, 'b> {
                 .instruction
                 .weight_table
                 .expect("weight_table is not set"),
+
+            config: self.instruction.config.expect("config is not set"),
+
+            restaking_config: self
+                .instruction
+                .restaking_config
+                .expect("restaking_config is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -430,6 +508,8 @@ struct SetEpochWeightsCpiBuilderInstruction<'a, 'b> {
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    restaking_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
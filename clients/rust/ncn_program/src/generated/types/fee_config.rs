@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::FeeGroup;
 use crate::generated::types::Fees;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -25,4 +26,5 @@ pub struct FeeConfig {
     pub ncn_fee_wallets: Pubkey,
     pub fee1: Fees,
     pub fee2: Fees,
+    pub fee_groups: [FeeGroup; 4],
 }
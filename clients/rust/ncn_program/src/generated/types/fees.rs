@@ -15,4 +15,5 @@ pub struct Fees {
     pub activation_epoch: u64,
     pub protocol_fee_bps: Fee,
     pub ncn_fee_bps: Fee,
+    pub fee_group_bps: [Fee; 4],
 }
@@ -11,12 +11,17 @@ pub(crate) mod r#config_admin_role;
 pub(crate) mod r#epoch_account_status;
 pub(crate) mod r#fee;
 pub(crate) mod r#fee_config;
+pub(crate) mod r#fee_group;
 pub(crate) mod r#fees;
+pub(crate) mod r#n_c_n_mint_rewards;
 pub(crate) mod r#n_c_n_reward_router_rewards;
+pub(crate) mod r#operator_vault_mint_rewards;
 pub(crate) mod r#operator_vault_reward_route;
 pub(crate) mod r#operator_vote;
+pub(crate) mod r#pending_parameters;
 pub(crate) mod r#progress;
 pub(crate) mod r#st_mint_entry;
+pub(crate) mod r#st_mint_weight;
 pub(crate) mod r#stake_weights;
 pub(crate) mod r#vault_entry;
 pub(crate) mod r#vault_operator_stake_weight;
@@ -29,12 +34,17 @@ pub use self::r#config_admin_role::*;
 pub use self::r#epoch_account_status::*;
 pub use self::r#fee::*;
 pub use self::r#fee_config::*;
+pub use self::r#fee_group::*;
 pub use self::r#fees::*;
+pub use self::r#n_c_n_mint_rewards::*;
 pub use self::r#n_c_n_reward_router_rewards::*;
+pub use self::r#operator_vault_mint_rewards::*;
 pub use self::r#operator_vault_reward_route::*;
 pub use self::r#operator_vote::*;
+pub use self::r#pending_parameters::*;
 pub use self::r#progress::*;
 pub use self::r#st_mint_entry::*;
+pub use self::r#st_mint_weight::*;
 pub use self::r#stake_weights::*;
 pub use self::r#vault_entry::*;
 pub use self::r#vault_operator_stake_weight::*;
@@ -0,0 +1,29 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NCNMintRewards {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub total_rewards: u64,
+    pub reward_pool: u64,
+    pub rewards_processed: u64,
+    pub protocol_rewards: u64,
+    pub ncn_rewards: u64,
+    pub fee_group_rewards: [u64; 4],
+    pub operator_vault_rewards: u64,
+    pub last_vote_index: u16,
+    pub last_rewards_to_process: u64,
+}
@@ -18,5 +18,5 @@ pub struct OperatorVaultRewardRoute {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub operator: Pubkey,
-    pub rewards: NCNRewardRouterRewards,
+    pub rewards: [NCNRewardRouterRewards; 5],
 }
@@ -0,0 +1,28 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingParameters {
+    pub effective_slot: u64,
+    pub queued: u16,
+    pub starting_valid_epoch: u64,
+    pub epochs_before_stall: u64,
+    pub epochs_after_consensus_before_close: u64,
+    pub valid_slots_after_consensus: u64,
+    pub cranker_fee_bps: u16,
+    pub timeliness_bonus_slots: u64,
+    pub timeliness_bonus_bps: u16,
+    pub weight_decay_epochs: u64,
+    pub weight_decay_bps: u16,
+    pub max_vault_stake_weight_bps: u16,
+    pub permissionless_vault_registration: bool,
+    pub parameters_timelock_slots: u64,
+}
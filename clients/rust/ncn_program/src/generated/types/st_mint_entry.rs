@@ -17,6 +17,11 @@ pub struct StMintEntry {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub st_mint: Pubkey,
-    pub reserve_switchboard_feed: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub switchboard_feed: Pubkey,
     pub weight: u128,
+    pub decimals: u8,
 }
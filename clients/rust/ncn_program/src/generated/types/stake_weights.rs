@@ -12,4 +12,5 @@ use borsh::BorshSerialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeWeights {
     pub stake_weight: u128,
+    pub stake_weight_hi: u128,
 }
@@ -24,4 +24,11 @@ pub struct VaultEntry {
     pub st_mint: Pubkey,
     pub vault_index: u64,
     pub slot_registered: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub reward_recipient: Pubkey,
+    pub name: [u8; 32],
+    pub uri_hash: [u8; 32],
 }
@@ -18,4 +18,5 @@ pub struct VaultRewardRoute {
     )]
     pub vault: Pubkey,
     pub rewards: u64,
+    pub mint_rewards: [u64; 4],
 }
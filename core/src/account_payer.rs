@@ -1,9 +1,12 @@
+use solana_program::pubkey::Pubkey;
+#[cfg(feature = "program")]
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
-    sysvar::Sysvar,
+    program_error::ProgramError, rent::Rent, system_instruction, system_program, sysvar::Sysvar,
 };
 
+use crate::pdas::NCNProgramPda;
+#[cfg(feature = "program")]
 use crate::{constants::MAX_REALLOC_BYTES, loaders::check_load};
 
 /// Uninitialized, no-data account used to hold SOL for ClaimStatus rent
@@ -11,25 +14,15 @@ use crate::{constants::MAX_REALLOC_BYTES, loaders::check_load};
 pub struct AccountPayer {}
 
 impl AccountPayer {
-    const ACCOUNT_PAYER_SEED: &'static [u8] = b"account_payer";
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
-        // Note: The second NCN is an error from the original code, most presumably a copy/paste or Claude error
-        vec![
-            Self::ACCOUNT_PAYER_SEED.to_vec(),
-            ncn.to_bytes().to_vec(),
-            ncn.to_bytes().to_vec(),
-        ]
+        NCNProgramPda::AccountPayer { ncn: *ncn }.seeds()
     }
 
     pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn);
-        let (address, bump) = Pubkey::find_program_address(
-            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
-            program_id,
-        );
-        (address, bump, seeds)
+        NCNProgramPda::AccountPayer { ncn: *ncn }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -49,6 +42,7 @@ impl AccountPayer {
 
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "program")]
     pub fn pay_and_create_account<'a, 'info>(
         program_id: &Pubkey,
         ncn: &Pubkey,
@@ -99,6 +93,7 @@ impl AccountPayer {
     }
 
     #[inline(always)]
+    #[cfg(feature = "program")]
     pub fn pay_and_realloc<'a, 'info>(
         program_id: &Pubkey,
         ncn: &Pubkey,
@@ -120,6 +115,7 @@ impl AccountPayer {
 
     /// Closes the program account
     #[inline(always)]
+    #[cfg(feature = "program")]
     pub fn close_account<'a, 'info>(
         program_id: &Pubkey,
         account_payer: &'a AccountInfo<'info>,
@@ -143,6 +139,7 @@ impl AccountPayer {
     }
 
     #[inline(always)]
+    #[cfg(feature = "program")]
     pub fn transfer<'a, 'info>(
         program_id: &Pubkey,
         ncn: &Pubkey,
@@ -186,7 +183,7 @@ mod tests {
 
         // Verify we get 3 seeds (original 2 plus the ncn bytes)
         assert_eq!(seeds.len(), 3);
-        assert_eq!(seeds[0], AccountPayer::ACCOUNT_PAYER_SEED.to_vec());
+        assert_eq!(seeds[0], crate::pdas::ACCOUNT_PAYER_SEED.to_vec());
         assert_eq!(seeds[1], ncn.to_bytes().to_vec());
         assert_eq!(seeds[2], ncn.to_bytes().to_vec());
 
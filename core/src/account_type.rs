@@ -0,0 +1,165 @@
+//! Detection of the account type behind raw account data, for dump tools and indexers that
+//! process arbitrary NCN program accounts.
+
+use core::fmt;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    ballot_box::BallotBox,
+    config::Config,
+    consensus_result::ConsensusResult,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    ncn_reward_router::NCNRewardRouter,
+    operator_metadata::OperatorMetadata,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+
+macro_rules! account_types {
+    ($($variant:ident),* $(,)?) => {
+        /// The NCN program accounts that carry a discriminator
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum AccountType {
+            $($variant,)*
+        }
+
+        impl AccountType {
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub const fn discriminator(&self) -> u8 {
+                match self {
+                    $(Self::$variant => $variant::DISCRIMINATOR,)*
+                }
+            }
+
+            /// Size of the account data, the discriminator included
+            pub const fn size(&self) -> usize {
+                match self {
+                    $(Self::$variant => $variant::SIZE,)*
+                }
+            }
+
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+
+            pub fn from_discriminator(discriminator: u8) -> Option<Self> {
+                match discriminator {
+                    $(d if d == $variant::DISCRIMINATOR => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        /// A decoded NCN program account, tagged with its type
+        #[derive(Debug, Clone, Copy)]
+        pub enum NCNProgramAccount<'a> {
+            $($variant(&'a $variant),)*
+        }
+
+        impl NCNProgramAccount<'_> {
+            pub const fn account_type(&self) -> AccountType {
+                match self {
+                    $(Self::$variant(_) => AccountType::$variant,)*
+                }
+            }
+        }
+
+        impl fmt::Display for NCNProgramAccount<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$variant(account) => fmt::Display::fmt(account, f),)*
+                }
+            }
+        }
+
+        /// Decodes account data of any NCN program account type.
+        ///
+        /// ### Errors:
+        /// - `InvalidAccountData`: The discriminator is unknown or the data is shorter than the
+        ///   account, e.g. a shrunk NCN reward router or an account still being reallocated
+        pub fn decode_any(data: &[u8]) -> Result<NCNProgramAccount<'_>, ProgramError> {
+            match detect_account(data).ok_or(ProgramError::InvalidAccountData)? {
+                $(AccountType::$variant => {
+                    Ok(NCNProgramAccount::$variant($variant::try_from_slice_unchecked(data)?))
+                })*
+            }
+        }
+    };
+}
+
+account_types!(
+    Config,
+    VaultRegistry,
+    WeightTable,
+    EpochSnapshot,
+    OperatorSnapshot,
+    BallotBox,
+    ConsensusResult,
+    NCNRewardRouter,
+    OperatorVaultRewardRouter,
+    EpochState,
+    EpochMarker,
+    OperatorMetadata,
+);
+
+/// The account type of the data from its discriminator, `None` for data of no NCN program account
+/// or of an account that is not initialized yet
+pub fn detect_account(data: &[u8]) -> Option<AccountType> {
+    data.first()
+        .and_then(|discriminator| AccountType::from_discriminator(*discriminator))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::builders::account_data;
+
+    #[test]
+    fn test_detect_account() {
+        for account_type in AccountType::ALL {
+            let mut data = vec![0; account_type.size()];
+            data[0] = account_type.discriminator();
+
+            assert_eq!(detect_account(&data), Some(*account_type));
+            assert_eq!(
+                AccountType::from_discriminator(account_type.discriminator()),
+                Some(*account_type)
+            );
+        }
+
+        assert_eq!(detect_account(&[]), None);
+        assert_eq!(detect_account(&[0; 64]), None);
+        assert_eq!(detect_account(&[0xff; 64]), None);
+    }
+
+    #[test]
+    fn test_decode_any() {
+        let ncn = Pubkey::new_unique();
+        let data = account_data(BallotBox::new_for_test(&ncn, 3).as_ref());
+
+        let account = decode_any(&data).unwrap();
+        assert_eq!(account.account_type(), AccountType::BallotBox);
+        match account {
+            NCNProgramAccount::BallotBox(ballot_box) => {
+                assert_eq!(ballot_box.ncn(), &ncn);
+                assert_eq!(ballot_box.epoch(), 3);
+            }
+            _ => panic!("Expected a ballot box"),
+        }
+
+        // Truncated data is detected but cannot be decoded
+        assert_eq!(detect_account(&data[..100]), Some(AccountType::BallotBox));
+        assert!(decode_any(&data[..100]).is_err());
+        assert!(decode_any(&[0; 64]).is_err());
+    }
+}
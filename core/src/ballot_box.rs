@@ -16,7 +16,7 @@
 // The system includes features for tie-breaking and detecting stalled votes.
 
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -24,15 +24,19 @@ use jito_bytemuck::{
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
     constants::{precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
     discriminators::Discriminators,
     error::NCNProgramError,
-    loaders::check_load,
-    stake_weight::StakeWeights,
+    pdas::NCNProgramPda,
+    stake_weight::{scale_to_u128, StakeWeights},
 };
 
 /// Enum representing weather status
@@ -301,9 +305,9 @@ pub struct BallotBox {
     /// The ballot that got at least 66% of votes
     winning_ballot: Ballot,
     /// Operator votes
-    operator_votes: [OperatorVote; 256],
+    operator_votes: [OperatorVote; MAX_OPERATORS],
     /// Mapping of ballots votes to stake weight
-    ballot_tallies: [BallotTally; 256],
+    ballot_tallies: [BallotTally; MAX_OPERATORS],
 }
 
 impl Discriminator for BallotBox {
@@ -311,9 +315,12 @@ impl Discriminator for BallotBox {
 }
 
 impl BallotBox {
-    const BALLOT_BOX_SEED: &'static [u8] = b"ballot_box";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     pub fn new(ncn: &Pubkey, epoch: u64, bump: u8, current_slot: u64) -> Self {
         Self {
             ncn: *ncn,
@@ -344,15 +351,7 @@ impl BallotBox {
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::BALLOT_BOX_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::BallotBox { ncn: *ncn, epoch }.seeds()
     }
 
     pub fn find_program_address(
@@ -360,12 +359,10 @@ impl BallotBox {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::BallotBox { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -383,6 +380,7 @@ impl BallotBox {
         )
     }
 
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -392,10 +390,18 @@ impl BallotBox {
         Self::load(program_id, account_to_close, ncn, epoch, true)
     }
 
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
 
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
     pub fn slot_consensus_reached(&self) -> u64 {
         self.slot_consensus_reached.into()
     }
@@ -456,6 +462,11 @@ impl BallotBox {
         &self.operator_votes
     }
 
+    /// The votes cast so far, skipping the empty vote slots
+    pub fn iter_votes(&self) -> impl Iterator<Item = &OperatorVote> {
+        self.operator_votes.iter().filter(|vote| !vote.is_empty())
+    }
+
     pub fn set_winning_ballot(&mut self, ballot: &Ballot) {
         self.winning_ballot = *ballot;
     }
@@ -545,7 +556,7 @@ impl BallotBox {
     /// Updates the winning ballot if consensus threshold is met
     pub fn tally_votes(
         &mut self,
-        total_stake_weight: u128,
+        total_stake_weights: &StakeWeights,
         current_slot: u64,
     ) -> Result<(), NCNProgramError> {
         if self.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT {
@@ -556,16 +567,19 @@ impl BallotBox {
         let max_tally = self
             .ballot_tallies
             .iter()
-            .max_by_key(|t| t.stake_weights().stake_weight())
+            .max_by_key(|t| t.stake_weights().stake_weight_u256())
             .ok_or(NCNProgramError::NoValidBallots)?;
 
-        let ballot_stake_weight = max_tally.stake_weights().stake_weight();
-
         // Prevent division by zero
-        if total_stake_weight == 0 {
+        if total_stake_weights.stake_weight_u256().is_zero() {
             return Err(NCNProgramError::DenominatorIsZero);
         }
 
+        let (ballot_stake_weight, total_stake_weight) = scale_to_u128(
+            max_tally.stake_weights().stake_weight_u256(),
+            total_stake_weights.stake_weight_u256(),
+        )?;
+
         let precise_ballot_stake_weight = PreciseNumber::new(ballot_stake_weight)
             .ok_or(NCNProgramError::NewPreciseNumberError)?;
         let precise_total_stake_weight =
@@ -662,6 +676,36 @@ impl BallotBox {
 
         false
     }
+
+    /// Casts the votes again, in the order they were cast, on an empty ballot box and tallies
+    /// them after each vote as the cast vote instruction does. The replayed ballot box holds the
+    /// outcome of the votes alone, a tie breaker ballot is never set on it.
+    pub fn replay_votes(
+        &self,
+        total_stake_weights: &StakeWeights,
+        valid_slots_after_consensus: u64,
+    ) -> Result<Self, NCNProgramError> {
+        let mut replay = Self::new(&self.ncn, self.epoch(), self.bump, self.slot_created());
+
+        for vote in self.iter_votes() {
+            let ballot = *self
+                .ballot_tallies
+                .get(vote.ballot_index() as usize)
+                .ok_or(NCNProgramError::BallotTallyNotFoundFull)?
+                .ballot();
+
+            replay.cast_vote(
+                vote.operator(),
+                &ballot,
+                vote.stake_weights(),
+                vote.slot_voted(),
+                valid_slots_after_consensus,
+            )?;
+            replay.tally_votes(total_stake_weights, vote.slot_voted())?;
+        }
+
+        Ok(replay)
+    }
 }
 
 #[rustfmt::skip]
@@ -683,14 +727,12 @@ impl fmt::Display for BallotBox {
        }
 
        writeln!(f, "\nOperator Votes:")?;
-       for vote in self.operator_votes().iter() {
-           if !vote.is_empty() {
-               writeln!(f, "  Operator:                     {}", vote.operator())?;
-               writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
-               writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
-               writeln!(f, "    Stake Weights:              {}", vote.stake_weights().stake_weight())?;
-               writeln!(f, "  ------------------------------------   ")?;
-           }
+       for vote in self.iter_votes() {
+           writeln!(f, "  Operator:                     {}", vote.operator())?;
+           writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
+           writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
+           writeln!(f, "    Stake Weights:              {}", vote.stake_weights().stake_weight_u256())?;
+           writeln!(f, "  ------------------------------------   ")?;
        }
 
        writeln!(f, "\nBallot Tallies:")?;
@@ -699,7 +741,7 @@ impl fmt::Display for BallotBox {
                writeln!(f, "  Index {}:", tally.index())?;
                writeln!(f, "    Ballot:                     {}", tally.ballot())?;
                writeln!(f, "    Tally:                      {}", tally.tally())?;
-               writeln!(f, "    Stake Weights:              {}", tally.stake_weights().stake_weight())?;
+               writeln!(f, "    Stake Weights:              {}", tally.stake_weights().stake_weight_u256())?;
            }
        }
 
@@ -711,6 +753,7 @@ impl fmt::Display for BallotBox {
 #[cfg(test)]
 mod tests {
     use solana_program::msg;
+    use spl_math::uint::U256;
 
     use crate::utils::assert_ncn_program_error;
 
@@ -898,6 +941,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_iter_votes() {
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&Pubkey::default(), 1, 0, current_slot);
+        assert_eq!(ballot_box.iter_votes().count(), 0);
+
+        let operators = [Pubkey::new_unique(), Pubkey::new_unique()];
+        for operator in operators.iter() {
+            ballot_box
+                .cast_vote(
+                    operator,
+                    &Ballot::new(WeatherStatus::Sunny as u8),
+                    &StakeWeights::new(1000),
+                    current_slot,
+                    10,
+                )
+                .unwrap();
+        }
+
+        let voters: Vec<Pubkey> = ballot_box
+            .iter_votes()
+            .map(|vote| *vote.operator())
+            .collect();
+        assert_eq!(voters, operators);
+    }
+
     #[test]
     fn test_increment_or_create_ballot_tally() {
         let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 1, 1);
@@ -950,7 +1019,7 @@ mod tests {
         let quarter_stake_weights = StakeWeights::new(500);
         let half_stake_weights = StakeWeights::new(500);
         let full_stake_weights = StakeWeights::new(1000);
-        let total_stake_weight: u128 = 1000;
+        let total_stake_weights = StakeWeights::new(1000);
         let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         let ballot = Ballot::new(WeatherStatus::Sunny as u8);
 
@@ -959,7 +1028,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&total_stake_weights, current_slot)
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
         assert_eq!(
@@ -976,7 +1045,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&total_stake_weights, current_slot)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
@@ -991,7 +1060,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot2, &full_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot + 1)
+            .tally_votes(&total_stake_weights, current_slot + 1)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
@@ -1017,7 +1086,7 @@ mod tests {
             .unwrap();
 
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&total_stake_weights, current_slot)
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
 
@@ -1026,7 +1095,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot3, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&total_stake_weights, current_slot)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(
@@ -1035,6 +1104,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tally_votes_above_u128() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        let ballot = Ballot::new(WeatherStatus::Sunny as u8);
+
+        // Four operators, each with more stake weight than fits in a u128
+        let operator_stake_weights =
+            StakeWeights::new_u256(U256::from(u128::MAX) * U256::from(4u64));
+        let mut total_stake_weights = StakeWeights::default();
+        for _ in 0..4 {
+            total_stake_weights
+                .increment(&operator_stake_weights)
+                .unwrap();
+        }
+
+        // 50% of the stake weight is not enough
+        for _ in 0..2 {
+            ballot_box
+                .cast_vote(
+                    &Pubkey::new_unique(),
+                    &ballot,
+                    &operator_stake_weights,
+                    current_slot,
+                    100,
+                )
+                .unwrap();
+        }
+        ballot_box
+            .tally_votes(&total_stake_weights, current_slot)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+
+        // 75% of the stake weight reaches consensus
+        ballot_box
+            .cast_vote(
+                &Pubkey::new_unique(),
+                &ballot,
+                &operator_stake_weights,
+                current_slot,
+                100,
+            )
+            .unwrap();
+        ballot_box
+            .tally_votes(&total_stake_weights, current_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box
+                .get_winning_ballot_tally()
+                .unwrap()
+                .stake_weights()
+                .stake_weight_u256(),
+            U256::from(u128::MAX) * U256::from(12u64)
+        );
+    }
+
     #[test]
     fn test_cast_bad_ballot() {
         let ncn = Pubkey::new_unique();
@@ -1128,7 +1255,9 @@ mod tests {
         assert_eq!(winning_tally.tally(), 2);
 
         // Verify ballot2 wins consensus with all votes
-        ballot_box.tally_votes(2000, current_slot + 4).unwrap();
+        ballot_box
+            .tally_votes(&StakeWeights::new(2000), current_slot + 4)
+            .unwrap();
         assert!(ballot_box.has_winning_ballot());
         assert_eq!(*ballot_box.get_winning_ballot().unwrap(), ballot2);
     }
@@ -1286,6 +1415,70 @@ mod tests {
         assert_eq!(ballot_box.operators_voted(), 1);
         assert_eq!(ballot_box.unique_ballots(), 1);
     }
+
+    #[test]
+    fn test_replay_votes() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let epoch = 1;
+        let valid_slots_after_consensus = 10;
+        let total_stake_weights = StakeWeights::new(400);
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+
+        let sunny = Ballot::new(WeatherStatus::Sunny as u8);
+        let cloudy = Ballot::new(WeatherStatus::Cloudy as u8);
+        let votes = [
+            (Pubkey::new_unique(), cloudy, 100),
+            (Pubkey::new_unique(), sunny, 100),
+            (Pubkey::new_unique(), sunny, 100),
+        ];
+
+        for (slot, (operator, ballot, stake_weight)) in (current_slot..).zip(votes.iter()) {
+            ballot_box
+                .cast_vote(
+                    operator,
+                    ballot,
+                    &StakeWeights::new(*stake_weight),
+                    slot,
+                    valid_slots_after_consensus,
+                )
+                .unwrap();
+            ballot_box.tally_votes(&total_stake_weights, slot).unwrap();
+        }
+        assert!(!ballot_box.is_consensus_reached());
+
+        // The votes alone do not reach consensus, unlike the tie breaker ballot
+        let replay = ballot_box
+            .replay_votes(&total_stake_weights, valid_slots_after_consensus)
+            .unwrap();
+        assert!(!replay.is_consensus_reached());
+        assert_eq!(replay.operators_voted(), 3);
+        assert_eq!(replay.unique_ballots(), 2);
+
+        ballot_box
+            .set_tie_breaker_ballot(WeatherStatus::Sunny as u8, epoch + 3, 3)
+            .unwrap();
+        let replay = ballot_box
+            .replay_votes(&total_stake_weights, valid_slots_after_consensus)
+            .unwrap();
+        assert!(ballot_box.tie_breaker_set());
+        assert!(!replay.has_winning_ballot());
+
+        // Over two thirds of the stake reach consensus at the slot of the last vote
+        let replay = ballot_box
+            .replay_votes(&StakeWeights::new(250), valid_slots_after_consensus)
+            .unwrap();
+        assert_eq!(replay.get_winning_ballot().unwrap(), &sunny);
+        assert_eq!(replay.slot_consensus_reached(), current_slot + 2);
+        assert_eq!(
+            replay
+                .get_winning_ballot_tally()
+                .unwrap()
+                .stake_weights()
+                .stake_weight(),
+            200
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1319,8 +1512,7 @@ mod zero_stake_tests {
 
         // Verify vote was recorded
         let operator_vote = ballot_box
-            .operator_votes()
-            .iter()
+            .iter_votes()
             .find(|v| v.operator().eq(&zero_stake_operator))
             .expect("Zero stake operator vote should be recorded");
 
@@ -1379,7 +1571,10 @@ mod zero_stake_tests {
         // Calculate consensus with only zero stake votes
         let total_stake = 1000u128;
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64)
+            .tally_votes(
+                &StakeWeights::new(total_stake),
+                current_slot + num_zero_stake as u64,
+            )
             .unwrap();
         assert!(
             !ballot_box.is_consensus_reached(),
@@ -1415,7 +1610,10 @@ mod zero_stake_tests {
 
         // Check consensus again
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64 + 1)
+            .tally_votes(
+                &StakeWeights::new(total_stake),
+                current_slot + num_zero_stake as u64 + 1,
+            )
             .unwrap();
         assert!(
             ballot_box.is_consensus_reached(),
@@ -1502,7 +1700,9 @@ mod zero_stake_tests {
 
         // Check consensus
         let total_stake = 1000u128;
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(&StakeWeights::new(total_stake), current_slot)
+            .unwrap();
 
         // Neither ballot should have consensus yet
         assert!(!ballot_box.is_consensus_reached());
@@ -1520,7 +1720,9 @@ mod zero_stake_tests {
             )
             .unwrap();
 
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(&StakeWeights::new(total_stake), current_slot)
+            .unwrap();
 
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot2);
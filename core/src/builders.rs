@@ -0,0 +1,724 @@
+//! Builders for the program accounts, so downstream programs and tests can construct valid
+//! accounts without repeating their initialization field by field.
+//!
+//! Accounts are built on the heap, the larger ones would overflow the stack when built by value.
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::Discriminator;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    ballot_box::BallotBox,
+    config::Config,
+    consensus_result::ConsensusResult,
+    constants::{
+        MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MIN_EPOCHS_BEFORE_STALL,
+        MIN_VALID_SLOTS_AFTER_CONSENSUS,
+    },
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    fees::{FeeConfig, Fees},
+    ncn_reward_router::NCNRewardRouter,
+    operator_metadata::OperatorMetadata,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+
+/// Allocates a zeroed account on the heap
+pub fn zeroed_account<T: Pod>() -> Box<T> {
+    bytemuck::zeroed_box()
+}
+
+/// The data of an account as stored on chain, the 8 byte discriminator included
+pub fn account_data<T: Pod + Discriminator>(account: &T) -> Vec<u8> {
+    let mut data = vec![0; 8];
+    data[0] = T::DISCRIMINATOR;
+    data.extend_from_slice(bytemuck::bytes_of(account));
+    data
+}
+
+macro_rules! setters {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        $(
+            pub const fn $field(mut self, $field: $ty) -> Self {
+                self.$field = $field;
+                self
+            }
+        )*
+    };
+}
+
+macro_rules! pubkey_setters {
+    ($($field:ident),* $(,)?) => {
+        $(
+            pub const fn $field(mut self, $field: &Pubkey) -> Self {
+                self.$field = *$field;
+                self
+            }
+        )*
+    };
+}
+
+// ----------------------- CONFIG -----------------------
+
+/// Builds a `Config`, the parameters default to the smallest valid values
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigBuilder {
+    ncn: Pubkey,
+    tie_breaker_admin: Pubkey,
+    ncn_fee_wallet: Pubkey,
+    ncn_fee_bps: u16,
+    starting_valid_epoch: u64,
+    valid_slots_after_consensus: u64,
+    epochs_before_stall: u64,
+    epochs_after_consensus_before_close: u64,
+    bump: u8,
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self {
+            ncn: Pubkey::default(),
+            tie_breaker_admin: Pubkey::default(),
+            ncn_fee_wallet: Pubkey::default(),
+            ncn_fee_bps: 0,
+            starting_valid_epoch: 0,
+            valid_slots_after_consensus: MIN_VALID_SLOTS_AFTER_CONSENSUS,
+            epochs_before_stall: MIN_EPOCHS_BEFORE_STALL,
+            epochs_after_consensus_before_close: MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
+            bump: 0,
+        }
+    }
+}
+
+impl ConfigBuilder {
+    pubkey_setters!(ncn, tie_breaker_admin, ncn_fee_wallet);
+    setters!(
+        ncn_fee_bps: u16,
+        starting_valid_epoch: u64,
+        valid_slots_after_consensus: u64,
+        epochs_before_stall: u64,
+        epochs_after_consensus_before_close: u64,
+        bump: u8,
+    );
+
+    /// Fails if the NCN fee wallet is unset or the NCN fee is too high
+    pub fn build(self) -> Result<Box<Config>, NCNProgramError> {
+        let fee_config = FeeConfig::new(
+            &self.ncn_fee_wallet,
+            self.ncn_fee_bps,
+            self.starting_valid_epoch,
+        )?;
+
+        let mut config = zeroed_account::<Config>();
+        *config = Config::new(
+            &self.ncn,
+            &self.tie_breaker_admin,
+            self.starting_valid_epoch,
+            self.valid_slots_after_consensus,
+            self.epochs_before_stall,
+            self.epochs_after_consensus_before_close,
+            &fee_config,
+            self.bump,
+        );
+
+        Ok(config)
+    }
+}
+
+impl Config {
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// A config of the NCN with the minimum parameters, the NCN doubles as the tie breaker admin
+    /// and the NCN fee wallet
+    pub fn new_for_test(ncn: &Pubkey) -> Box<Self> {
+        Self::builder()
+            .ncn(ncn)
+            .tie_breaker_admin(ncn)
+            .ncn_fee_wallet(ncn)
+            .build()
+            .expect("default config parameters are valid")
+    }
+}
+
+// ----------------------- VAULT REGISTRY -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VaultRegistryBuilder {
+    ncn: Pubkey,
+    bump: u8,
+}
+
+impl VaultRegistryBuilder {
+    pubkey_setters!(ncn);
+    setters!(bump: u8);
+
+    pub fn build(self) -> Box<VaultRegistry> {
+        let mut vault_registry = zeroed_account::<VaultRegistry>();
+        vault_registry.initialize(&self.ncn, self.bump);
+        vault_registry
+    }
+}
+
+impl VaultRegistry {
+    pub fn builder() -> VaultRegistryBuilder {
+        VaultRegistryBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey) -> Box<Self> {
+        Self::builder().ncn(ncn).build()
+    }
+}
+
+// ----------------------- WEIGHT TABLE -----------------------
+
+/// Builds a `WeightTable`, filled with the vaults and mints of a vault registry if one is given and
+/// left as created by `InitializeWeightTable` otherwise
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeightTableBuilder<'a> {
+    ncn: Pubkey,
+    epoch: u64,
+    slot_created: u64,
+    bump: u8,
+    vault_registry: Option<&'a VaultRegistry>,
+}
+
+impl<'a> WeightTableBuilder<'a> {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, slot_created: u64, bump: u8);
+
+    pub const fn vault_registry(mut self, vault_registry: &'a VaultRegistry) -> Self {
+        self.vault_registry = Some(vault_registry);
+        self
+    }
+
+    /// Fails if the vault registry has no vaults or no mints
+    pub fn build(self) -> Result<Box<WeightTable>, NCNProgramError> {
+        let mut weight_table = zeroed_account::<WeightTable>();
+
+        match self.vault_registry {
+            Some(vault_registry) => weight_table.initialize(
+                &self.ncn,
+                self.epoch,
+                self.slot_created,
+                vault_registry.vault_count(),
+                self.bump,
+                vault_registry.get_vault_entries(),
+                vault_registry.get_mint_entries(),
+            )?,
+            None => {
+                *weight_table =
+                    WeightTable::new(&self.ncn, self.epoch, self.slot_created, 0, self.bump)
+            }
+        }
+
+        Ok(weight_table)
+    }
+}
+
+impl WeightTable {
+    pub fn builder<'a>() -> WeightTableBuilder<'a> {
+        WeightTableBuilder::default()
+    }
+
+    /// A weight table of the vaults and mints of the vault registry
+    pub fn new_for_test(
+        ncn: &Pubkey,
+        epoch: u64,
+        vault_registry: &VaultRegistry,
+    ) -> Result<Box<Self>, NCNProgramError> {
+        Self::builder()
+            .ncn(ncn)
+            .epoch(epoch)
+            .vault_registry(vault_registry)
+            .build()
+    }
+}
+
+// ----------------------- EPOCH STATE -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EpochStateBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+}
+
+impl EpochStateBuilder {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, bump: u8, slot_created: u64);
+
+    pub fn build(self) -> Box<EpochState> {
+        let mut epoch_state = zeroed_account::<EpochState>();
+        epoch_state.initialize(&self.ncn, self.epoch, self.bump, self.slot_created);
+        epoch_state
+    }
+}
+
+impl EpochState {
+    pub fn builder() -> EpochStateBuilder {
+        EpochStateBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder().ncn(ncn).epoch(epoch).build()
+    }
+}
+
+// ----------------------- EPOCH SNAPSHOT -----------------------
+
+/// Builds an `EpochSnapshot`, the fees default to zero
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EpochSnapshotBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+    operator_count: u64,
+    vault_count: u64,
+    fees: Option<Fees>,
+}
+
+impl EpochSnapshotBuilder {
+    pubkey_setters!(ncn);
+    setters!(
+        epoch: u64,
+        bump: u8,
+        slot_created: u64,
+        operator_count: u64,
+        vault_count: u64,
+    );
+
+    pub const fn fees(mut self, fees: &Fees) -> Self {
+        self.fees = Some(*fees);
+        self
+    }
+
+    pub fn build(self) -> Box<EpochSnapshot> {
+        let mut epoch_snapshot = zeroed_account::<EpochSnapshot>();
+        *epoch_snapshot = EpochSnapshot::new(
+            &self.ncn,
+            self.epoch,
+            self.bump,
+            self.slot_created,
+            self.operator_count,
+            self.vault_count,
+            self.fees.unwrap_or_else(Fees::zeroed),
+        );
+        epoch_snapshot
+    }
+}
+
+impl EpochSnapshot {
+    pub fn builder() -> EpochSnapshotBuilder {
+        EpochSnapshotBuilder::default()
+    }
+
+    pub fn new_for_test(
+        ncn: &Pubkey,
+        epoch: u64,
+        operator_count: u64,
+        vault_count: u64,
+    ) -> Box<Self> {
+        Self::builder()
+            .ncn(ncn)
+            .epoch(epoch)
+            .operator_count(operator_count)
+            .vault_count(vault_count)
+            .build()
+    }
+}
+
+// ----------------------- OPERATOR SNAPSHOT -----------------------
+
+/// Builds an `OperatorSnapshot`, active by default
+#[derive(Debug, Clone, Copy)]
+pub struct OperatorSnapshotBuilder {
+    operator: Pubkey,
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+    is_active: bool,
+    ncn_operator_index: u64,
+    operator_index: u64,
+    operator_fee_bps: u16,
+    vault_operator_delegation_count: u64,
+}
+
+impl Default for OperatorSnapshotBuilder {
+    fn default() -> Self {
+        Self {
+            operator: Pubkey::default(),
+            ncn: Pubkey::default(),
+            epoch: 0,
+            bump: 0,
+            slot_created: 0,
+            is_active: true,
+            ncn_operator_index: 0,
+            operator_index: 0,
+            operator_fee_bps: 0,
+            vault_operator_delegation_count: 0,
+        }
+    }
+}
+
+impl OperatorSnapshotBuilder {
+    pubkey_setters!(operator, ncn);
+    setters!(
+        epoch: u64,
+        bump: u8,
+        slot_created: u64,
+        is_active: bool,
+        ncn_operator_index: u64,
+        operator_index: u64,
+        operator_fee_bps: u16,
+        vault_operator_delegation_count: u64,
+    );
+
+    /// Fails if the operator has more delegations than there can be vaults
+    pub fn build(self) -> Result<Box<OperatorSnapshot>, NCNProgramError> {
+        let mut operator_snapshot = zeroed_account::<OperatorSnapshot>();
+        operator_snapshot.initialize(
+            &self.operator,
+            &self.ncn,
+            self.epoch,
+            self.bump,
+            self.slot_created,
+            self.is_active,
+            self.ncn_operator_index,
+            self.operator_index,
+            self.operator_fee_bps,
+            self.vault_operator_delegation_count,
+        )?;
+        Ok(operator_snapshot)
+    }
+}
+
+impl OperatorSnapshot {
+    pub fn builder() -> OperatorSnapshotBuilder {
+        OperatorSnapshotBuilder::default()
+    }
+
+    /// An active operator snapshot without delegations
+    pub fn new_for_test(operator: &Pubkey, ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder()
+            .operator(operator)
+            .ncn(ncn)
+            .epoch(epoch)
+            .build()
+            .expect("a snapshot without delegations is valid")
+    }
+}
+
+// ----------------------- BALLOT BOX -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BallotBoxBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+}
+
+impl BallotBoxBuilder {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, bump: u8, slot_created: u64);
+
+    pub fn build(self) -> Box<BallotBox> {
+        let mut ballot_box = zeroed_account::<BallotBox>();
+        ballot_box.initialize(&self.ncn, self.epoch, self.bump, self.slot_created);
+        ballot_box
+    }
+}
+
+impl BallotBox {
+    pub fn builder() -> BallotBoxBuilder {
+        BallotBoxBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder().ncn(ncn).epoch(epoch).build()
+    }
+}
+
+// ----------------------- CONSENSUS RESULT -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsensusResultBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+}
+
+impl ConsensusResultBuilder {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, bump: u8);
+
+    pub fn build(self) -> Box<ConsensusResult> {
+        let mut consensus_result = zeroed_account::<ConsensusResult>();
+        *consensus_result = ConsensusResult::new(&self.ncn, self.epoch, self.bump);
+        consensus_result
+    }
+}
+
+impl ConsensusResult {
+    pub fn builder() -> ConsensusResultBuilder {
+        ConsensusResultBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder().ncn(ncn).epoch(epoch).build()
+    }
+}
+
+// ----------------------- NCN REWARD ROUTER -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NCNRewardRouterBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+}
+
+impl NCNRewardRouterBuilder {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, bump: u8, slot_created: u64);
+
+    pub fn build(self) -> Box<NCNRewardRouter> {
+        let mut ncn_reward_router = zeroed_account::<NCNRewardRouter>();
+        ncn_reward_router.initialize(&self.ncn, self.epoch, self.bump, self.slot_created);
+        ncn_reward_router
+    }
+}
+
+impl NCNRewardRouter {
+    pub fn builder() -> NCNRewardRouterBuilder {
+        NCNRewardRouterBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder().ncn(ncn).epoch(epoch).build()
+    }
+}
+
+// ----------------------- OPERATOR VAULT REWARD ROUTER -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OperatorVaultRewardRouterBuilder {
+    operator: Pubkey,
+    operator_ncn_index: u64,
+    ncn: Pubkey,
+    epoch: u64,
+    bump: u8,
+    slot_created: u64,
+}
+
+impl OperatorVaultRewardRouterBuilder {
+    pubkey_setters!(operator, ncn);
+    setters!(operator_ncn_index: u64, epoch: u64, bump: u8, slot_created: u64);
+
+    pub fn build(self) -> Box<OperatorVaultRewardRouter> {
+        let mut operator_vault_reward_router = zeroed_account::<OperatorVaultRewardRouter>();
+        *operator_vault_reward_router = OperatorVaultRewardRouter::new(
+            &self.operator,
+            self.operator_ncn_index,
+            &self.ncn,
+            self.epoch,
+            self.bump,
+            self.slot_created,
+        );
+        operator_vault_reward_router
+    }
+}
+
+impl OperatorVaultRewardRouter {
+    pub fn builder() -> OperatorVaultRewardRouterBuilder {
+        OperatorVaultRewardRouterBuilder::default()
+    }
+
+    pub fn new_for_test(operator: &Pubkey, ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder()
+            .operator(operator)
+            .ncn(ncn)
+            .epoch(epoch)
+            .build()
+    }
+}
+
+// ----------------------- EPOCH MARKER -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EpochMarkerBuilder {
+    ncn: Pubkey,
+    epoch: u64,
+    slot_closed: u64,
+    skipped: bool,
+}
+
+impl EpochMarkerBuilder {
+    pubkey_setters!(ncn);
+    setters!(epoch: u64, slot_closed: u64, skipped: bool);
+
+    pub fn build(self) -> Box<EpochMarker> {
+        let mut epoch_marker = zeroed_account::<EpochMarker>();
+        *epoch_marker = EpochMarker::new(&self.ncn, self.epoch, self.slot_closed, self.skipped);
+        epoch_marker
+    }
+}
+
+impl EpochMarker {
+    pub fn builder() -> EpochMarkerBuilder {
+        EpochMarkerBuilder::default()
+    }
+
+    pub fn new_for_test(ncn: &Pubkey, epoch: u64) -> Box<Self> {
+        Self::builder().ncn(ncn).epoch(epoch).build()
+    }
+}
+
+// ----------------------- OPERATOR METADATA -----------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OperatorMetadataBuilder {
+    operator: Pubkey,
+    ncn: Pubkey,
+    bump: u8,
+}
+
+impl OperatorMetadataBuilder {
+    pubkey_setters!(operator, ncn);
+    setters!(bump: u8);
+
+    pub fn build(self) -> Box<OperatorMetadata> {
+        let mut operator_metadata = zeroed_account::<OperatorMetadata>();
+        operator_metadata.initialize(&self.operator, &self.ncn, self.bump);
+        operator_metadata
+    }
+}
+
+impl OperatorMetadata {
+    pub fn builder() -> OperatorMetadataBuilder {
+        OperatorMetadataBuilder::default()
+    }
+
+    pub fn new_for_test(operator: &Pubkey, ncn: &Pubkey) -> Box<Self> {
+        Self::builder().operator(operator).ncn(ncn).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_bytemuck::AccountDeserialize;
+
+    use super::*;
+
+    #[test]
+    fn test_builders_match_constructors() {
+        let ncn = Pubkey::new_unique();
+
+        let ballot_box = BallotBox::builder()
+            .ncn(&ncn)
+            .epoch(5)
+            .bump(254)
+            .slot_created(100)
+            .build();
+        assert_eq!(
+            bytemuck::bytes_of(ballot_box.as_ref()),
+            bytemuck::bytes_of(&BallotBox::new(&ncn, 5, 254, 100))
+        );
+
+        let epoch_state = EpochState::new_for_test(&ncn, 5);
+        assert_eq!(
+            bytemuck::bytes_of(epoch_state.as_ref()),
+            bytemuck::bytes_of(&EpochState::new(&ncn, 5, 0, 0))
+        );
+
+        let ncn_reward_router = NCNRewardRouter::new_for_test(&ncn, 5);
+        assert_eq!(
+            bytemuck::bytes_of(ncn_reward_router.as_ref()),
+            bytemuck::bytes_of(&NCNRewardRouter::new(&ncn, 5, 0, 0))
+        );
+
+        let vault_registry = VaultRegistry::new_for_test(&ncn);
+        assert_eq!(
+            bytemuck::bytes_of(vault_registry.as_ref()),
+            bytemuck::bytes_of(&VaultRegistry::new(&ncn, 0))
+        );
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let ncn = Pubkey::new_unique();
+
+        let config = Config::new_for_test(&ncn);
+        assert_eq!(config.ncn, ncn);
+        assert_eq!(
+            config.valid_slots_after_consensus(),
+            MIN_VALID_SLOTS_AFTER_CONSENSUS
+        );
+
+        // The NCN fee wallet has to be set
+        assert_eq!(
+            Config::builder().ncn(&ncn).build().err(),
+            Some(NCNProgramError::DefaultNcnWallet)
+        );
+    }
+
+    #[test]
+    fn test_weight_table_builder() {
+        let ncn = Pubkey::new_unique();
+        let st_mint = Pubkey::new_unique();
+
+        let mut vault_registry = VaultRegistry::new_for_test(&ncn);
+        vault_registry.register_st_mint(&st_mint, 9, 100).unwrap();
+        vault_registry
+            .register_vault(&Pubkey::new_unique(), &st_mint, 0, 0)
+            .unwrap();
+
+        let weight_table = WeightTable::new_for_test(&ncn, 5, &vault_registry).unwrap();
+        assert_eq!(weight_table.epoch(), 5);
+        assert_eq!(weight_table.vault_count(), 1);
+        assert_eq!(weight_table.st_mint_count(), 1);
+
+        // Without a registry the table is left uninitialized
+        let weight_table = WeightTable::builder().ncn(&ncn).build().unwrap();
+        assert!(weight_table.check_table_initialized().is_err());
+    }
+
+    #[test]
+    fn test_operator_snapshot_builder() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+
+        let operator_snapshot = OperatorSnapshot::new_for_test(&operator, &ncn, 5);
+        assert!(operator_snapshot.is_active());
+
+        assert_eq!(
+            OperatorSnapshot::builder()
+                .vault_operator_delegation_count(u64::MAX)
+                .build()
+                .err(),
+            Some(NCNProgramError::TooManyVaultOperatorDelegations)
+        );
+    }
+
+    #[test]
+    fn test_account_data() {
+        let ncn = Pubkey::new_unique();
+        let ballot_box = BallotBox::new_for_test(&ncn, 5);
+
+        let data = account_data(ballot_box.as_ref());
+        assert_eq!(data.len(), BallotBox::SIZE);
+
+        let parsed = BallotBox::try_from_slice_unchecked(&data).unwrap();
+        assert_eq!(parsed.epoch(), 5);
+    }
+}
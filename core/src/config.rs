@@ -1,19 +1,182 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use jito_bytemuck::{
+    types::{PodBool, PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use shank::{ShankAccount, ShankType};
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, fees::FeeConfig, loaders::check_load};
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
+use crate::{
+    constants::{MAX_ADMIN_APPROVERS, MAX_FEE_BPS, MAX_REWARD_MINTS},
+    discriminators::Discriminators,
+    error::NCNProgramError,
+    fees::FeeConfig,
+    pdas::NCNProgramPda,
+};
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub enum ConfigAdminRole {
     TieBreakerAdmin,
 }
 
+/// Parameter changes of `AdminSetParameters`, `None` leaves the parameter unchanged
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParameterChanges {
+    pub starting_valid_epoch: Option<u64>,
+    pub epochs_before_stall: Option<u64>,
+    pub epochs_after_consensus_before_close: Option<u64>,
+    pub valid_slots_after_consensus: Option<u64>,
+    pub cranker_fee_bps: Option<u16>,
+    pub timeliness_bonus_slots: Option<u64>,
+    pub timeliness_bonus_bps: Option<u16>,
+    pub weight_decay_epochs: Option<u64>,
+    pub weight_decay_bps: Option<u16>,
+    pub max_vault_stake_weight_bps: Option<u16>,
+    pub permissionless_vault_registration: Option<bool>,
+    pub parameters_timelock_slots: Option<u64>,
+}
+
+impl ParameterChanges {
+    pub fn is_empty(&self) -> bool {
+        self.eq(&Self::default())
+    }
+}
+
+/// Parameter changes queued while the config has a timelock. Every parameter is stored next to a
+/// bit in `queued` telling if it is part of the change.
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct PendingParameters {
+    /// Slot from which the changes can be applied
+    effective_slot: PodU64,
+    /// Bit set of the queued parameters, in field order starting at the lowest bit
+    queued: PodU16,
+    starting_valid_epoch: PodU64,
+    epochs_before_stall: PodU64,
+    epochs_after_consensus_before_close: PodU64,
+    valid_slots_after_consensus: PodU64,
+    cranker_fee_bps: PodU16,
+    timeliness_bonus_slots: PodU64,
+    timeliness_bonus_bps: PodU16,
+    weight_decay_epochs: PodU64,
+    weight_decay_bps: PodU16,
+    max_vault_stake_weight_bps: PodU16,
+    permissionless_vault_registration: PodBool,
+    parameters_timelock_slots: PodU64,
+}
+
+impl PendingParameters {
+    pub fn new(changes: &ParameterChanges, effective_slot: u64) -> Self {
+        let mut queued: u16 = 0;
+        let mut flag = |bit: u16, is_set: bool| {
+            if is_set {
+                queued |= 1 << bit;
+            }
+        };
+
+        flag(0, changes.starting_valid_epoch.is_some());
+        flag(1, changes.epochs_before_stall.is_some());
+        flag(2, changes.epochs_after_consensus_before_close.is_some());
+        flag(3, changes.valid_slots_after_consensus.is_some());
+        flag(4, changes.cranker_fee_bps.is_some());
+        flag(5, changes.timeliness_bonus_slots.is_some());
+        flag(6, changes.timeliness_bonus_bps.is_some());
+        flag(7, changes.weight_decay_epochs.is_some());
+        flag(8, changes.weight_decay_bps.is_some());
+        flag(9, changes.max_vault_stake_weight_bps.is_some());
+        flag(10, changes.permissionless_vault_registration.is_some());
+        flag(11, changes.parameters_timelock_slots.is_some());
+
+        Self {
+            effective_slot: PodU64::from(effective_slot),
+            queued: PodU16::from(queued),
+            starting_valid_epoch: PodU64::from(changes.starting_valid_epoch.unwrap_or_default()),
+            epochs_before_stall: PodU64::from(changes.epochs_before_stall.unwrap_or_default()),
+            epochs_after_consensus_before_close: PodU64::from(
+                changes
+                    .epochs_after_consensus_before_close
+                    .unwrap_or_default(),
+            ),
+            valid_slots_after_consensus: PodU64::from(
+                changes.valid_slots_after_consensus.unwrap_or_default(),
+            ),
+            cranker_fee_bps: PodU16::from(changes.cranker_fee_bps.unwrap_or_default()),
+            timeliness_bonus_slots: PodU64::from(
+                changes.timeliness_bonus_slots.unwrap_or_default(),
+            ),
+            timeliness_bonus_bps: PodU16::from(changes.timeliness_bonus_bps.unwrap_or_default()),
+            weight_decay_epochs: PodU64::from(changes.weight_decay_epochs.unwrap_or_default()),
+            weight_decay_bps: PodU16::from(changes.weight_decay_bps.unwrap_or_default()),
+            max_vault_stake_weight_bps: PodU16::from(
+                changes.max_vault_stake_weight_bps.unwrap_or_default(),
+            ),
+            permissionless_vault_registration: PodBool::from(
+                changes
+                    .permissionless_vault_registration
+                    .unwrap_or_default(),
+            ),
+            parameters_timelock_slots: PodU64::from(
+                changes.parameters_timelock_slots.unwrap_or_default(),
+            ),
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        u16::from(self.queued) != 0
+    }
+
+    pub fn effective_slot(&self) -> u64 {
+        self.effective_slot.into()
+    }
+
+    fn is_queued(&self, bit: u16) -> bool {
+        u16::from(self.queued) & (1 << bit) != 0
+    }
+
+    pub fn changes(&self) -> ParameterChanges {
+        ParameterChanges {
+            starting_valid_epoch: self.is_queued(0).then(|| self.starting_valid_epoch.into()),
+            epochs_before_stall: self.is_queued(1).then(|| self.epochs_before_stall.into()),
+            epochs_after_consensus_before_close: self
+                .is_queued(2)
+                .then(|| self.epochs_after_consensus_before_close.into()),
+            valid_slots_after_consensus: self
+                .is_queued(3)
+                .then(|| self.valid_slots_after_consensus.into()),
+            cranker_fee_bps: self.is_queued(4).then(|| self.cranker_fee_bps.into()),
+            timeliness_bonus_slots: self
+                .is_queued(5)
+                .then(|| self.timeliness_bonus_slots.into()),
+            timeliness_bonus_bps: self.is_queued(6).then(|| self.timeliness_bonus_bps.into()),
+            weight_decay_epochs: self.is_queued(7).then(|| self.weight_decay_epochs.into()),
+            weight_decay_bps: self.is_queued(8).then(|| self.weight_decay_bps.into()),
+            max_vault_stake_weight_bps: self
+                .is_queued(9)
+                .then(|| self.max_vault_stake_weight_bps.into()),
+            permissionless_vault_registration: self
+                .is_queued(10)
+                .then(|| self.permissionless_vault_registration.into()),
+            parameters_timelock_slots: self
+                .is_queued(11)
+                .then(|| self.parameters_timelock_slots.into()),
+        }
+    }
+}
+
+impl Default for PendingParameters {
+    fn default() -> Self {
+        Self::new(&ParameterChanges::default(), 0)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct Config {
@@ -33,6 +196,34 @@ pub struct Config {
     pub fee_config: FeeConfig,
     /// Bump seed for the PDA
     pub bump: u8,
+    /// SPL token mints that rewards can be routed in next to SOL, default if unused
+    reward_mints: [Pubkey; MAX_REWARD_MINTS],
+    /// Share of the reward pool in basis points paid to whoever routes incoming rewards
+    pub cranker_fee_bps: PodU16,
+    /// Operators voting within this many slots of the ballot box creation get the timeliness bonus
+    pub timeliness_bonus_slots: PodU64,
+    /// Stake weight boost in basis points for timely voters, 0 disables the bonus
+    pub timeliness_bonus_bps: PodU16,
+    /// Vaults not updated for more than this many epochs have the weight of their st mint decayed
+    pub weight_decay_epochs: PodU64,
+    /// Weight reduction in basis points for st mints of stale vaults, 0 disables the decay
+    pub weight_decay_bps: PodU16,
+    /// Largest share of the total stake weight in basis points a single vault can hold, 0 disables the cap
+    pub max_vault_stake_weight_bps: PodU16,
+    /// Lets anyone register a vault with an active ticket, registering its st mint with the default weight
+    pub permissionless_vault_registration: PodBool,
+    /// Keys that approve admin instructions once the admin threshold is set, default if unused
+    admin_approvers: [Pubkey; MAX_ADMIN_APPROVERS],
+    /// Number of distinct approvers that have to sign admin instructions, 0 leaves them to the NCN admin
+    admin_threshold: u8,
+    /// Slots `AdminSetParameters` changes wait before they can be applied, 0 applies them right away
+    pub parameters_timelock_slots: PodU64,
+    /// Parameter changes waiting for the timelock to elapse
+    pending_parameters: PendingParameters,
+    /// spl-governance program of the realm holding the admin role, default if unused
+    admin_governance_program: Pubkey,
+    /// Realm whose governances approve admin instructions in place of the NCN admin, default if unused
+    admin_governance_realm: Pubkey,
 }
 
 impl Discriminator for Config {
@@ -40,9 +231,11 @@ impl Discriminator for Config {
 }
 
 impl Config {
-    const CONFIG_SEED: &'static [u8] = b"config";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+
     /// Creates a new Config instance with the specified parameters
     ///
     /// # Arguments
@@ -73,20 +266,29 @@ impl Config {
             epochs_after_consensus_before_close: PodU64::from(epochs_after_consensus_before_close),
             fee_config: *fee_config,
             bump,
+            reward_mints: [Pubkey::default(); MAX_REWARD_MINTS],
+            cranker_fee_bps: PodU16::from(0),
+            timeliness_bonus_slots: PodU64::from(0),
+            timeliness_bonus_bps: PodU16::from(0),
+            weight_decay_epochs: PodU64::from(0),
+            weight_decay_bps: PodU16::from(0),
+            max_vault_stake_weight_bps: PodU16::from(0),
+            permissionless_vault_registration: PodBool::from(false),
+            admin_approvers: [Pubkey::default(); MAX_ADMIN_APPROVERS],
+            admin_threshold: 0,
+            parameters_timelock_slots: PodU64::from(0),
+            pending_parameters: PendingParameters::default(),
+            admin_governance_program: Pubkey::default(),
+            admin_governance_realm: Pubkey::default(),
         }
     }
 
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
-        vec![Self::CONFIG_SEED.to_vec(), ncn.to_bytes().to_vec()]
+        NCNProgramPda::Config { ncn: *ncn }.seeds()
     }
 
     pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn);
-        let (address, bump) = Pubkey::find_program_address(
-            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
-            program_id,
-        );
-        (address, bump, seeds)
+        NCNProgramPda::Config { ncn: *ncn }.find_program_address(program_id)
     }
 
     /// Validates and loads the Config account - checks if it's the correct PDA and has the right discriminator
@@ -99,6 +301,7 @@ impl Config {
     ///
     /// # Returns
     /// * `Result<(), ProgramError>` - Ok if valid, Error otherwise
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -130,6 +333,249 @@ impl Config {
     pub fn epochs_after_consensus_before_close(&self) -> u64 {
         self.epochs_after_consensus_before_close.into()
     }
+
+    pub fn cranker_fee_bps(&self) -> u16 {
+        self.cranker_fee_bps.into()
+    }
+
+    pub fn timeliness_bonus_slots(&self) -> u64 {
+        self.timeliness_bonus_slots.into()
+    }
+
+    pub fn timeliness_bonus_bps(&self) -> u16 {
+        self.timeliness_bonus_bps.into()
+    }
+
+    pub fn weight_decay_epochs(&self) -> u64 {
+        self.weight_decay_epochs.into()
+    }
+
+    pub fn weight_decay_bps(&self) -> u16 {
+        self.weight_decay_bps.into()
+    }
+
+    pub fn weight_decay_enabled(&self) -> bool {
+        self.weight_decay_bps() > 0
+    }
+
+    /// Checks if a vault last updated in `vault_update_epoch` is too stale to carry its full weight
+    pub fn is_vault_stale(&self, vault_update_epoch: u64, epoch: u64) -> bool {
+        self.weight_decay_enabled()
+            && epoch.saturating_sub(vault_update_epoch) > self.weight_decay_epochs()
+    }
+
+    /// Applies the weight decay to the weight of a stale vault's st mint
+    pub fn decay_weight(&self, weight: u128) -> Result<u128, NCNProgramError> {
+        let remaining_bps = MAX_FEE_BPS
+            .checked_sub(self.weight_decay_bps() as u64)
+            .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
+
+        weight
+            .checked_mul(remaining_bps as u128)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?
+            .checked_div(MAX_FEE_BPS as u128)
+            .ok_or(NCNProgramError::DenominatorIsZero)
+    }
+
+    pub fn max_vault_stake_weight_bps(&self) -> u16 {
+        self.max_vault_stake_weight_bps.into()
+    }
+
+    pub fn vault_stake_weight_cap_enabled(&self) -> bool {
+        self.max_vault_stake_weight_bps() > 0
+    }
+
+    pub fn permissionless_vault_registration(&self) -> bool {
+        self.permissionless_vault_registration.into()
+    }
+
+    pub const fn reward_mints(&self) -> &[Pubkey; MAX_REWARD_MINTS] {
+        &self.reward_mints
+    }
+
+    /// Checks if rewards can be routed in the given mint
+    pub fn is_reward_mint(&self, mint: &Pubkey) -> bool {
+        mint.ne(&Pubkey::default()) && self.reward_mints.contains(mint)
+    }
+
+    /// Sets the reward mint at the given index, the default pubkey clears the slot
+    ///
+    /// SOL is always routed and cannot be registered as a reward mint
+    pub fn set_reward_mint(&mut self, index: usize, mint: &Pubkey) -> Result<(), NCNProgramError> {
+        if index >= MAX_REWARD_MINTS {
+            return Err(NCNProgramError::InvalidRewardMintIndex);
+        }
+
+        if mint.eq(&spl_token::native_mint::id()) {
+            return Err(NCNProgramError::InvalidRewardMint);
+        }
+
+        let is_duplicate = self
+            .reward_mints
+            .iter()
+            .enumerate()
+            .any(|(i, reward_mint)| i != index && reward_mint.eq(mint));
+
+        if mint.ne(&Pubkey::default()) && is_duplicate {
+            return Err(NCNProgramError::InvalidRewardMint);
+        }
+
+        self.reward_mints[index] = *mint;
+
+        Ok(())
+    }
+
+    pub fn admin_threshold(&self) -> u8 {
+        self.admin_threshold
+    }
+
+    pub fn has_admin_multisig(&self) -> bool {
+        self.admin_threshold > 0
+    }
+
+    /// The recorded approvers, without unused slots
+    pub fn admin_approvers(&self) -> impl Iterator<Item = &Pubkey> {
+        self.admin_approvers
+            .iter()
+            .filter(|approver| approver.ne(&&Pubkey::default()))
+    }
+
+    /// Replaces the admin multisig. A threshold of 0 removes the multisig and has to be passed
+    /// without approvers, otherwise the threshold can be at most the number of approvers
+    pub fn set_admin_multisig(
+        &mut self,
+        approvers: &[Pubkey],
+        threshold: u8,
+    ) -> Result<(), NCNProgramError> {
+        if approvers.len() > MAX_ADMIN_APPROVERS
+            || threshold as usize > approvers.len()
+            || (threshold == 0 && !approvers.is_empty())
+        {
+            return Err(NCNProgramError::InvalidAdminMultisig);
+        }
+
+        for (i, approver) in approvers.iter().enumerate() {
+            if approver.eq(&Pubkey::default()) || approvers[..i].contains(approver) {
+                return Err(NCNProgramError::InvalidAdminMultisig);
+            }
+        }
+
+        self.admin_approvers = [Pubkey::default(); MAX_ADMIN_APPROVERS];
+        self.admin_approvers[..approvers.len()].copy_from_slice(approvers);
+        self.admin_threshold = threshold;
+
+        Ok(())
+    }
+
+    /// Checks that the signers of an admin instruction approve it. Without a multisig the NCN
+    /// admin has to be one of the signers, with one at least `admin_threshold` distinct approvers
+    /// have to be.
+    pub fn check_admin_approval(
+        &self,
+        ncn_admin: &Pubkey,
+        signers: &[Pubkey],
+    ) -> Result<(), NCNProgramError> {
+        if !self.has_admin_multisig() {
+            if !signers.contains(ncn_admin) {
+                return Err(NCNProgramError::IncorrectNcnAdmin);
+            }
+            return Ok(());
+        }
+
+        let approvals = self
+            .admin_approvers()
+            .filter(|approver| signers.contains(approver))
+            .count();
+
+        if approvals < self.admin_threshold as usize {
+            return Err(NCNProgramError::AdminThresholdNotMet);
+        }
+
+        Ok(())
+    }
+
+    pub fn parameters_timelock_slots(&self) -> u64 {
+        self.parameters_timelock_slots.into()
+    }
+
+    pub fn has_parameters_timelock(&self) -> bool {
+        self.parameters_timelock_slots() > 0
+    }
+
+    pub const fn pending_parameters(&self) -> &PendingParameters {
+        &self.pending_parameters
+    }
+
+    /// Queues the changes until the timelock elapsed, replacing any pending changes. Empty changes
+    /// cancel the pending ones.
+    pub fn queue_parameters(
+        &mut self,
+        changes: &ParameterChanges,
+        current_slot: u64,
+    ) -> Result<(), NCNProgramError> {
+        if changes.is_empty() {
+            self.pending_parameters = PendingParameters::default();
+            return Ok(());
+        }
+
+        let effective_slot = current_slot
+            .checked_add(self.parameters_timelock_slots())
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+        self.pending_parameters = PendingParameters::new(changes, effective_slot);
+
+        Ok(())
+    }
+
+    /// Removes the pending changes once their timelock elapsed and returns them
+    pub fn take_pending_parameters(
+        &mut self,
+        current_slot: u64,
+    ) -> Result<ParameterChanges, NCNProgramError> {
+        if !self.pending_parameters.is_pending() {
+            return Err(NCNProgramError::NoPendingParameters);
+        }
+
+        if current_slot < self.pending_parameters.effective_slot() {
+            return Err(NCNProgramError::ParametersTimelockNotElapsed);
+        }
+
+        let changes = self.pending_parameters.changes();
+        self.pending_parameters = PendingParameters::default();
+
+        Ok(changes)
+    }
+
+    pub const fn admin_governance_program(&self) -> &Pubkey {
+        &self.admin_governance_program
+    }
+
+    pub const fn admin_governance_realm(&self) -> &Pubkey {
+        &self.admin_governance_realm
+    }
+
+    /// Whether admin instructions are approved by the governances of a realm, taking precedence
+    /// over the admin multisig and the NCN admin
+    pub fn has_admin_governance(&self) -> bool {
+        self.admin_governance_realm.ne(&Pubkey::default())
+    }
+
+    /// Hands the admin role to the governances of `realm`. Both keys default hand it back to the
+    /// admin multisig or the NCN admin.
+    pub fn set_admin_governance(
+        &mut self,
+        governance_program: &Pubkey,
+        realm: &Pubkey,
+    ) -> Result<(), NCNProgramError> {
+        if governance_program.eq(&Pubkey::default()) != realm.eq(&Pubkey::default()) {
+            return Err(NCNProgramError::InvalidAdminGovernance);
+        }
+
+        self.admin_governance_program = *governance_program;
+        self.admin_governance_realm = *realm;
+
+        Ok(())
+    }
 }
 
 #[rustfmt::skip]
@@ -142,6 +588,33 @@ impl fmt::Display for Config {
         writeln!(f, "  Epochs Before Stall:          {}", self.epochs_before_stall())?;
         writeln!(f, "  Starting Valid Epochs:        {}", self.starting_valid_epoch())?;
         writeln!(f, "  Close Epoch:                  {}", self.epochs_after_consensus_before_close())?;
+        writeln!(f, "  Cranker Fee Bps:              {}", self.cranker_fee_bps())?;
+        writeln!(f, "  Timeliness Bonus Slots:       {}", self.timeliness_bonus_slots())?;
+        writeln!(f, "  Timeliness Bonus Bps:         {}", self.timeliness_bonus_bps())?;
+        writeln!(f, "  Weight Decay Epochs:          {}", self.weight_decay_epochs())?;
+        writeln!(f, "  Weight Decay Bps:             {}", self.weight_decay_bps())?;
+        writeln!(f, "  Max Vault Stake Weight Bps:   {}", self.max_vault_stake_weight_bps())?;
+        writeln!(f, "  Permissionless Vault Reg.:    {}", self.permissionless_vault_registration())?;
+        writeln!(f, "  Admin Threshold:              {}", self.admin_threshold())?;
+
+        for approver in self.admin_approvers() {
+            writeln!(f, "  Admin Approver:               {}", approver)?;
+        }
+
+        if self.has_admin_governance() {
+            writeln!(f, "  Admin Governance Program:     {}", self.admin_governance_program)?;
+            writeln!(f, "  Admin Governance Realm:       {}", self.admin_governance_realm)?;
+        }
+
+        writeln!(f, "  Parameters Timelock Slots:    {}", self.parameters_timelock_slots())?;
+        if self.pending_parameters.is_pending() {
+            writeln!(f, "  Pending Parameters:           {:?}", self.pending_parameters.changes())?;
+            writeln!(f, "  Pending Effective Slot:       {}", self.pending_parameters.effective_slot())?;
+        }
+
+        for reward_mint in self.reward_mints.iter().filter(|mint| mint.ne(&&Pubkey::default())) {
+            writeln!(f, "  Reward Mint:                  {}", reward_mint)?;
+        }
 
         Ok(())
     }
@@ -162,9 +635,253 @@ mod tests {
             + size_of::<PodU64>() // epochs_after_consensus_before_close
             + size_of::<PodU64>() // starting_valid_epoch
             + size_of::<FeeConfig>() // fee_config
-            + 1; // bump
+            + 1 // bump
+            + size_of::<Pubkey>() * MAX_REWARD_MINTS // reward_mints
+            + size_of::<PodU16>() // cranker_fee_bps
+            + size_of::<PodU64>() // timeliness_bonus_slots
+            + size_of::<PodU16>() // timeliness_bonus_bps
+            + size_of::<PodU64>() // weight_decay_epochs
+            + size_of::<PodU16>() // weight_decay_bps
+            + size_of::<PodU16>() // max_vault_stake_weight_bps
+            + size_of::<PodBool>() // permissionless_vault_registration
+            + size_of::<Pubkey>() * MAX_ADMIN_APPROVERS // admin_approvers
+            + 1 // admin_threshold
+            + size_of::<PodU64>() // parameters_timelock_slots
+            + size_of::<PendingParameters>() // pending_parameters
+            + size_of::<Pubkey>() // admin_governance_program
+            + size_of::<Pubkey>(); // admin_governance_realm
 
         assert_eq!(size_of::<Config>(), expected_total);
         assert_eq!(size_of::<Config>() + 8, Config::SIZE);
     }
+
+    #[test]
+    fn test_weight_decay() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            &FeeConfig::new(&Pubkey::new_unique(), 0, 0).unwrap(),
+            0,
+        );
+
+        // Disabled by default
+        assert!(!config.weight_decay_enabled());
+        assert!(!config.is_vault_stale(0, 100));
+        assert_eq!(config.decay_weight(1_000).unwrap(), 1_000);
+
+        config.weight_decay_epochs = PodU64::from(2);
+        config.weight_decay_bps = PodU16::from(2_500);
+
+        assert!(!config.is_vault_stale(8, 10));
+        assert!(config.is_vault_stale(7, 10));
+        // Vaults updated after the target epoch are never stale
+        assert!(!config.is_vault_stale(11, 10));
+
+        assert_eq!(config.decay_weight(1_000).unwrap(), 750);
+    }
+
+    #[test]
+    fn test_set_reward_mint() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            &FeeConfig::new(&Pubkey::new_unique(), 0, 0).unwrap(),
+            0,
+        );
+
+        let mint = Pubkey::new_unique();
+        assert!(!config.is_reward_mint(&mint));
+        assert!(!config.is_reward_mint(&Pubkey::default()));
+
+        config.set_reward_mint(1, &mint).unwrap();
+        assert!(config.is_reward_mint(&mint));
+        assert_eq!(config.reward_mints()[1], mint);
+
+        // Same mint cannot be registered twice, but can be set again at its own index
+        assert_eq!(
+            config.set_reward_mint(2, &mint),
+            Err(NCNProgramError::InvalidRewardMint)
+        );
+        config.set_reward_mint(1, &mint).unwrap();
+
+        assert_eq!(
+            config.set_reward_mint(0, &spl_token::native_mint::id()),
+            Err(NCNProgramError::InvalidRewardMint)
+        );
+        assert_eq!(
+            config.set_reward_mint(MAX_REWARD_MINTS, &Pubkey::new_unique()),
+            Err(NCNProgramError::InvalidRewardMintIndex)
+        );
+
+        config.set_reward_mint(1, &Pubkey::default()).unwrap();
+        assert!(!config.is_reward_mint(&mint));
+    }
+
+    #[test]
+    fn test_admin_multisig() {
+        let ncn_admin = Pubkey::new_unique();
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            &FeeConfig::new(&Pubkey::new_unique(), 0, 0).unwrap(),
+            0,
+        );
+
+        // Without a multisig the NCN admin approves alone
+        assert!(!config.has_admin_multisig());
+        config
+            .check_admin_approval(&ncn_admin, &[ncn_admin])
+            .unwrap();
+        assert_eq!(
+            config.check_admin_approval(&ncn_admin, &[Pubkey::new_unique()]),
+            Err(NCNProgramError::IncorrectNcnAdmin)
+        );
+
+        let approvers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        config.set_admin_multisig(&approvers, 2).unwrap();
+        assert!(config.has_admin_multisig());
+        assert_eq!(config.admin_approvers().count(), 3);
+
+        // The NCN admin no longer approves alone
+        assert_eq!(
+            config.check_admin_approval(&ncn_admin, &[ncn_admin]),
+            Err(NCNProgramError::AdminThresholdNotMet)
+        );
+        // Duplicate signers count once
+        assert_eq!(
+            config.check_admin_approval(&ncn_admin, &[approvers[0], approvers[0]]),
+            Err(NCNProgramError::AdminThresholdNotMet)
+        );
+        config
+            .check_admin_approval(&ncn_admin, &[ncn_admin, approvers[0], approvers[2]])
+            .unwrap();
+
+        // Invalid multisigs
+        assert_eq!(
+            config.set_admin_multisig(&approvers, 4),
+            Err(NCNProgramError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            config.set_admin_multisig(&[approvers[0], approvers[0]], 1),
+            Err(NCNProgramError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            config.set_admin_multisig(&[Pubkey::default()], 1),
+            Err(NCNProgramError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            config.set_admin_multisig(&approvers, 0),
+            Err(NCNProgramError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            config.set_admin_multisig(&[Pubkey::new_unique(); MAX_ADMIN_APPROVERS + 1], 1),
+            Err(NCNProgramError::InvalidAdminMultisig)
+        );
+
+        // Removing the multisig hands approval back to the NCN admin
+        config.set_admin_multisig(&[], 0).unwrap();
+        assert!(!config.has_admin_multisig());
+        assert_eq!(config.admin_approvers().count(), 0);
+        config
+            .check_admin_approval(&ncn_admin, &[ncn_admin])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_admin_governance() {
+        let mut config = Config::new_for_test(&Pubkey::new_unique());
+        let governance_program = Pubkey::new_unique();
+        let realm = Pubkey::new_unique();
+
+        assert!(!config.has_admin_governance());
+
+        assert_eq!(
+            config.set_admin_governance(&governance_program, &Pubkey::default()),
+            Err(NCNProgramError::InvalidAdminGovernance)
+        );
+        assert_eq!(
+            config.set_admin_governance(&Pubkey::default(), &realm),
+            Err(NCNProgramError::InvalidAdminGovernance)
+        );
+        assert!(!config.has_admin_governance());
+
+        config
+            .set_admin_governance(&governance_program, &realm)
+            .unwrap();
+        assert!(config.has_admin_governance());
+        assert_eq!(config.admin_governance_program(), &governance_program);
+        assert_eq!(config.admin_governance_realm(), &realm);
+
+        config
+            .set_admin_governance(&Pubkey::default(), &Pubkey::default())
+            .unwrap();
+        assert!(!config.has_admin_governance());
+    }
+
+    #[test]
+    fn test_pending_parameters() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            &FeeConfig::new(&Pubkey::new_unique(), 0, 0).unwrap(),
+            0,
+        );
+        config.parameters_timelock_slots = PodU64::from(100);
+
+        assert_eq!(
+            config.take_pending_parameters(0),
+            Err(NCNProgramError::NoPendingParameters)
+        );
+
+        let changes = ParameterChanges {
+            epochs_before_stall: Some(5),
+            cranker_fee_bps: Some(0),
+            permissionless_vault_registration: Some(true),
+            parameters_timelock_slots: Some(200),
+            ..ParameterChanges::default()
+        };
+        config.queue_parameters(&changes, 1_000).unwrap();
+
+        assert!(config.pending_parameters().is_pending());
+        assert_eq!(config.pending_parameters().effective_slot(), 1_100);
+        // Zero values round trip as set, unset values stay unset
+        assert_eq!(config.pending_parameters().changes(), changes);
+
+        assert_eq!(
+            config.take_pending_parameters(1_099),
+            Err(NCNProgramError::ParametersTimelockNotElapsed)
+        );
+        assert_eq!(config.take_pending_parameters(1_100).unwrap(), changes);
+        assert!(!config.pending_parameters().is_pending());
+
+        // Empty changes cancel the pending ones
+        config.queue_parameters(&changes, 1_000).unwrap();
+        config
+            .queue_parameters(&ParameterChanges::default(), 1_050)
+            .unwrap();
+        assert_eq!(
+            config.take_pending_parameters(2_000),
+            Err(NCNProgramError::NoPendingParameters)
+        );
+    }
 }
@@ -14,14 +14,18 @@
 // to determine the most recent consensus state.
 
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, error::NCNProgramError, loaders::check_load};
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
+use crate::{discriminators::Discriminators, error::NCNProgramError, pdas::NCNProgramPda};
 
 // PDA'd ["consensus-result", NCN, NCN_EPOCH_SLOT]
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
@@ -48,9 +52,12 @@ impl Discriminator for ConsensusResult {
 }
 
 impl ConsensusResult {
-    const CONSENSUS_RESULT_SEED: &'static [u8] = b"consensus-result";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     /// Creates a new ConsensusResult instance with default values
     ///
     /// # Arguments
@@ -70,15 +77,7 @@ impl ConsensusResult {
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::CONSENSUS_RESULT_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::ConsensusResult { ncn: *ncn, epoch }.seeds()
     }
 
     pub fn find_program_address(
@@ -86,10 +85,7 @@ impl ConsensusResult {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::ConsensusResult { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
     /// Validates that the provided account matches the expected PDA and has the right discriminator
@@ -103,6 +99,7 @@ impl ConsensusResult {
     ///
     /// # Returns
     /// * `Result<(), ProgramError>` - Ok if valid, Error otherwise
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -120,6 +117,38 @@ impl ConsensusResult {
         )
     }
 
+    /// Validates the account like `load` and returns a copy of the consensus result once consensus
+    /// has been reached. This is the entry point for programs gating their own logic on the outcome
+    /// of an NCN epoch, `program_id` being the NCN program that owns the account.
+    ///
+    /// # Arguments
+    /// * `program_id` - The NCN program ID
+    /// * `account` - The consensus result account
+    /// * `ncn` - The NCN pubkey
+    /// * `epoch` - The epoch number
+    ///
+    /// # Returns
+    /// * `Result<ConsensusResult, ProgramError>` - The consensus result, `ConsensusNotReached` if
+    ///   the epoch has no outcome yet
+    #[cfg(feature = "program")]
+    pub fn load_and_read(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<Self, ProgramError> {
+        Self::load(program_id, account, ncn, epoch, false)?;
+
+        let account_data = account.try_borrow_data()?;
+        let consensus_result = *Self::try_from_slice_unchecked(&account_data)?;
+
+        if !consensus_result.is_consensus_reached() {
+            return Err(NCNProgramError::ConsensusNotReached.into());
+        }
+
+        Ok(consensus_result)
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
@@ -248,8 +277,71 @@ mod tests {
         let (_, _, seeds) = ConsensusResult::find_program_address(&program_id, &ncn, epoch);
 
         assert_eq!(seeds.len(), 3);
-        assert_eq!(seeds[0], ConsensusResult::CONSENSUS_RESULT_SEED.to_vec());
+        assert_eq!(seeds[0], crate::pdas::CONSENSUS_RESULT_SEED.to_vec());
         assert_eq!(seeds[1], ncn.to_bytes().to_vec());
         assert_eq!(seeds[2], epoch.to_le_bytes().to_vec());
     }
+
+    #[cfg(feature = "program")]
+    #[test]
+    fn test_load_and_read() {
+        use crate::builders::account_data;
+
+        let program_id = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let epoch = 123;
+        let (address, bump, _) = ConsensusResult::find_program_address(&program_id, &ncn, epoch);
+
+        let mut consensus_result = ConsensusResult::new(&ncn, epoch, bump);
+        let mut lamports = 0;
+        let mut data = account_data(&consensus_result);
+        let account = AccountInfo::new(
+            &address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // No outcome to read before consensus
+        assert_eq!(
+            ConsensusResult::load_and_read(&program_id, &account, &ncn, epoch).unwrap_err(),
+            ProgramError::from(NCNProgramError::ConsensusNotReached)
+        );
+
+        // Wrong epoch
+        assert_eq!(
+            ConsensusResult::load_and_read(&program_id, &account, &ncn, epoch + 1).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        consensus_result
+            .record_consensus(1, 1000, 2000, 5000)
+            .unwrap();
+        let mut data = account_data(&consensus_result);
+        let account = AccountInfo::new(
+            &address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let read = ConsensusResult::load_and_read(&program_id, &account, &ncn, epoch).unwrap();
+        assert_eq!(read.weather_status(), 1);
+        assert_eq!(read.consensus_slot(), 5000);
+
+        // Accounts not owned by the NCN program are rejected
+        assert_eq!(
+            ConsensusResult::load_and_read(&Pubkey::new_unique(), &account, &ncn, epoch)
+                .unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
 }
@@ -4,15 +4,84 @@ use spl_math::precise_number::PreciseNumber;
 use crate::error::NCNProgramError;
 
 pub const MAX_FEE_BPS: u64 = 10_000;
-pub const MAX_ST_MINTS: usize = 64;
-pub const MAX_VAULTS: usize = 64;
-pub const MAX_OPERATORS: usize = 256;
+
+// Account capacities, set at build time through the `[env]` table of `.cargo/config.toml` so
+// forks can resize the accounts in one place. They size the arrays of the accounts: changing one
+// changes the account layouts, the IDL and clients have to be regenerated and existing accounts
+// can not be read anymore.
+
+/// Capacity of the st mint lists of the `VaultRegistry` and `WeightTable`, set by
+/// `NCN_MAX_ST_MINTS`
+pub const MAX_ST_MINTS: usize = build_config(option_env!("NCN_MAX_ST_MINTS"), 128);
+/// Capacity of the vault lists and vault reward routes, set by `NCN_MAX_VAULTS`
+pub const MAX_VAULTS: usize = build_config(option_env!("NCN_MAX_VAULTS"), 64);
+/// Capacity of the votes, tallies and operator reward routes of an epoch, set by
+/// `NCN_MAX_OPERATORS`
+pub const MAX_OPERATORS: usize = build_config(option_env!("NCN_MAX_OPERATORS"), 256);
+/// Number of reward mints distributed next to SOL, set by `NCN_MAX_REWARD_MINTS`
+pub const MAX_REWARD_MINTS: usize = build_config(option_env!("NCN_MAX_REWARD_MINTS"), 4);
+/// Reward entries of the NCN reward router, SOL first then one per reward mint
+pub const MAX_REWARD_ENTRIES: usize = MAX_REWARD_MINTS + 1;
+/// Number of custom fee groups that can be set up next to the Protocol and NCN fees, set by
+/// `NCN_MAX_FEE_GROUPS`
+pub const MAX_FEE_GROUPS: usize = build_config(option_env!("NCN_MAX_FEE_GROUPS"), 4);
+/// Most approvers that can be recorded for the admin multisig of a config, set by
+/// `NCN_MAX_ADMIN_APPROVERS`
+pub const MAX_ADMIN_APPROVERS: usize = build_config(option_env!("NCN_MAX_ADMIN_APPROVERS"), 8);
+/// Votes routed per `RouteNCNRewards` call, set by `NCN_MAX_ROUTE_BASE_ITERATIONS`
+pub const MAX_ROUTE_BASE_ITERATIONS: u16 = ROUTE_BASE_ITERATIONS as u16;
+const ROUTE_BASE_ITERATIONS: usize = build_config(option_env!("NCN_MAX_ROUTE_BASE_ITERATIONS"), 30);
+
+// Indices are stored as u16 with u16::MAX as sentinel, fee groups are addressed by a u8
+const _: () = assert!(MAX_ST_MINTS > 0 && MAX_ST_MINTS < u16::MAX as usize);
+const _: () = assert!(MAX_VAULTS > 0 && MAX_VAULTS < u16::MAX as usize);
+const _: () = assert!(MAX_OPERATORS > 0 && MAX_OPERATORS < u16::MAX as usize);
+const _: () = assert!(MAX_REWARD_MINTS > 0 && MAX_FEE_GROUPS > 0 && MAX_ADMIN_APPROVERS > 0);
+const _: () = assert!(MAX_FEE_GROUPS <= u8::MAX as usize);
+const _: () = assert!(ROUTE_BASE_ITERATIONS > 0 && ROUTE_BASE_ITERATIONS <= u16::MAX as usize);
+
+/// Parses a decimal build time setting, `default` when it is not set. Fails the build on a value
+/// that is not a number.
+const fn build_config(value: Option<&str>, default: usize) -> usize {
+    let Some(value) = value else {
+        return default;
+    };
+
+    let bytes = value.as_bytes();
+    assert!(!bytes.is_empty(), "Empty NCN build setting");
+
+    let mut result: usize = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        let digit = bytes[index];
+        assert!(
+            digit.is_ascii_digit(),
+            "NCN build settings have to be decimal numbers"
+        );
+        result = result * 10 + (digit - b'0') as usize;
+        index += 1;
+    }
+
+    result
+}
+
 pub const MIN_EPOCHS_BEFORE_STALL: u64 = 1;
 pub const MAX_EPOCHS_BEFORE_STALL: u64 = 50;
 pub const MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 10;
 pub const MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 100;
 pub const MIN_VALID_SLOTS_AFTER_CONSENSUS: u64 = 1000;
 pub const MAX_VALID_SLOTS_AFTER_CONSENSUS: u64 = 50 * DEFAULT_SLOTS_PER_EPOCH;
+pub const MAX_CRANKER_FEE_BPS: u16 = 100;
+pub const MAX_TIMELINESS_BONUS_BPS: u16 = 1_000;
+pub const MAX_WEIGHT_DECAY_BPS: u16 = 5_000;
+/// Longest delay between queueing and applying parameter changes
+pub const MAX_PARAMETERS_TIMELOCK_SLOTS: u64 = 10 * DEFAULT_SLOTS_PER_EPOCH;
+/// Switchboard feed results older than this are rejected when setting weights
+pub const MAX_SWITCHBOARD_STALE_SLOTS: u64 = 100;
+/// Largest accepted switchboard feed standard deviation, relative to the feed value
+pub const MAX_SWITCHBOARD_CONFIDENCE_BPS: u128 = 100;
+/// Longest a keeper instance can hold the keeper lease without renewing it
+pub const MAX_KEEPER_LEASE_SLOTS: u64 = DEFAULT_SLOTS_PER_EPOCH;
 const PRECISE_CONSENSUS_NUMERATOR: u128 = 2;
 const PRECISE_CONSENSUS_DENOMINATOR: u128 = 3;
 pub fn precise_consensus() -> Result<PreciseNumber, NCNProgramError> {
@@ -29,3 +98,23 @@ pub const DEFAULT_CONSENSUS_REACHED_SLOT: u64 = u64::MAX;
 pub const MAX_REALLOC_BYTES: u64 = MAX_PERMITTED_DATA_INCREASE as u64;
 
 pub const WEIGHT: u128 = 100;
+/// Token amounts of every st-mint are brought to these decimals before their weight is applied
+pub const NORMALIZED_DECIMALS: u8 = 9;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_config() {
+        assert_eq!(build_config(None, 256), 256);
+        assert_eq!(build_config(Some("64"), 256), 64);
+        assert_eq!(build_config(Some("1024"), 256), 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "NCN build settings have to be decimal numbers")]
+    fn test_build_config_not_a_number() {
+        build_config(Some("0x40"), 256);
+    }
+}
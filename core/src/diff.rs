@@ -0,0 +1,719 @@
+//! Field level comparison of two instances of the same account, e.g. a ballot box before and after
+//! a vote. Entries of account arrays are compared one by one and reported with their index, so a
+//! vote shows up as the handful of fields it changed instead of two full `Display` dumps.
+//!
+//! [`EpochSet`] compares two epochs instead: the vaults, weights and operator stake weights they
+//! snapshotted.
+use core::fmt;
+use std::collections::{BTreeMap, BTreeSet};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    ballot_box::{BallotBox, WeatherStatus},
+    consensus_result::ConsensusResult,
+    constants::{MAX_FEE_GROUPS, MAX_OPERATORS},
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::{AccountStatus, EpochState, Progress},
+    error::NCNProgramError,
+    ncn_reward_router::NCNRewardRouter,
+    weight_table::WeightTable,
+};
+
+/// A field whose value differs between the two instances
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Path of the field, array entries include their index, e.g. `operator_votes[2].slot_voted`
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.before, self.after)
+    }
+}
+
+pub trait AccountDiff {
+    /// The fields that changed from `self` to `after`, in field order
+    fn diff(&self, after: &Self) -> Vec<FieldChange>;
+}
+
+/// Collects the changes of one comparison
+#[derive(Default)]
+struct FieldChanges(Vec<FieldChange>);
+
+impl FieldChanges {
+    fn field<T: PartialEq + fmt::Debug>(&mut self, field: impl fmt::Display, before: T, after: T) {
+        if before != after {
+            self.0.push(FieldChange {
+                field: field.to_string(),
+                before: format!("{:?}", before),
+                after: format!("{:?}", after),
+            });
+        }
+    }
+
+    fn progress(&mut self, field: &str, before: Progress, after: Progress) {
+        self.field(format!("{}.tally", field), before.tally(), after.tally());
+        self.field(format!("{}.total", field), before.total(), after.total());
+    }
+}
+
+fn status(status: Result<AccountStatus, NCNProgramError>) -> Option<AccountStatus> {
+    status.ok()
+}
+
+fn weather_status(weather_status: u8) -> &'static str {
+    WeatherStatus::from_u8(weather_status).unwrap_or("Invalid")
+}
+
+impl AccountDiff for BallotBox {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field("slot_created", self.slot_created(), after.slot_created());
+        changes.field(
+            "slot_consensus_reached",
+            self.slot_consensus_reached(),
+            after.slot_consensus_reached(),
+        );
+        changes.field(
+            "operators_voted",
+            self.operators_voted(),
+            after.operators_voted(),
+        );
+        changes.field(
+            "unique_ballots",
+            self.unique_ballots(),
+            after.unique_ballots(),
+        );
+        changes.field(
+            "winning_ballot",
+            self.get_winning_ballot()
+                .ok()
+                .map(|ballot| weather_status(ballot.weather_status())),
+            after
+                .get_winning_ballot()
+                .ok()
+                .map(|ballot| weather_status(ballot.weather_status())),
+        );
+
+        for (index, (before, after)) in self
+            .ballot_tallies()
+            .iter()
+            .zip(after.ballot_tallies().iter())
+            .enumerate()
+        {
+            changes.field(
+                format_args!("ballot_tallies[{}].ballot", index),
+                weather_status(before.ballot().weather_status()),
+                weather_status(after.ballot().weather_status()),
+            );
+            changes.field(
+                format_args!("ballot_tallies[{}].tally", index),
+                before.tally(),
+                after.tally(),
+            );
+            changes.field(
+                format_args!("ballot_tallies[{}].stake_weight", index),
+                before.stake_weights().stake_weight_u256(),
+                after.stake_weights().stake_weight_u256(),
+            );
+        }
+
+        for (index, (before, after)) in self
+            .operator_votes()
+            .iter()
+            .zip(after.operator_votes().iter())
+            .enumerate()
+        {
+            changes.field(
+                format_args!("operator_votes[{}].operator", index),
+                before.operator(),
+                after.operator(),
+            );
+            changes.field(
+                format_args!("operator_votes[{}].slot_voted", index),
+                before.slot_voted(),
+                after.slot_voted(),
+            );
+            changes.field(
+                format_args!("operator_votes[{}].ballot_index", index),
+                before.ballot_index(),
+                after.ballot_index(),
+            );
+            changes.field(
+                format_args!("operator_votes[{}].stake_weight", index),
+                before.stake_weights().stake_weight_u256(),
+                after.stake_weights().stake_weight_u256(),
+            );
+        }
+
+        changes.0
+    }
+}
+
+impl AccountDiff for ConsensusResult {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field(
+            "weather_status",
+            weather_status(self.weather_status()),
+            weather_status(after.weather_status()),
+        );
+        changes.field("vote_weight", self.vote_weight(), after.vote_weight());
+        changes.field(
+            "total_vote_weight",
+            self.total_vote_weight(),
+            after.total_vote_weight(),
+        );
+        changes.field(
+            "consensus_slot",
+            self.consensus_slot(),
+            after.consensus_slot(),
+        );
+
+        changes.0
+    }
+}
+
+impl AccountDiff for EpochState {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field(
+            "was_tie_breaker_set",
+            self.was_tie_breaker_set(),
+            after.was_tie_breaker_set(),
+        );
+        changes.field(
+            "slot_consensus_reached",
+            self.slot_consensus_reached(),
+            after.slot_consensus_reached(),
+        );
+        changes.field(
+            "operator_count",
+            self.operator_count(),
+            after.operator_count(),
+        );
+        changes.field("vault_count", self.vault_count(), after.vault_count());
+        changes.field("is_closing", self.is_closing(), after.is_closing());
+
+        let (before_status, after_status) = (self.account_status(), after.account_status());
+        changes.field(
+            "account_status.epoch_state",
+            status(before_status.epoch_state()),
+            status(after_status.epoch_state()),
+        );
+        changes.field(
+            "account_status.weight_table",
+            status(before_status.weight_table()),
+            status(after_status.weight_table()),
+        );
+        changes.field(
+            "account_status.epoch_snapshot",
+            status(before_status.epoch_snapshot()),
+            status(after_status.epoch_snapshot()),
+        );
+        for index in 0..MAX_OPERATORS {
+            changes.field(
+                format_args!("account_status.operator_snapshot[{}]", index),
+                status(before_status.operator_snapshot(index)),
+                status(after_status.operator_snapshot(index)),
+            );
+        }
+        changes.field(
+            "account_status.ballot_box",
+            status(before_status.ballot_box()),
+            status(after_status.ballot_box()),
+        );
+        changes.field(
+            "account_status.ncn_reward_router",
+            status(before_status.ncn_reward_router()),
+            status(after_status.ncn_reward_router()),
+        );
+
+        changes.progress(
+            "set_weight_progress",
+            self.set_weight_progress(),
+            after.set_weight_progress(),
+        );
+        changes.progress(
+            "epoch_snapshot_progress",
+            self.epoch_snapshot_progress(),
+            after.epoch_snapshot_progress(),
+        );
+        changes.progress(
+            "voting_progress",
+            self.voting_progress(),
+            after.voting_progress(),
+        );
+        changes.progress(
+            "total_distribution_progress",
+            self.total_distribution_progress(),
+            after.total_distribution_progress(),
+        );
+
+        changes.0
+    }
+}
+
+impl AccountDiff for WeightTable {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field("slot_created", self.slot_created(), after.slot_created());
+        changes.field("vault_count", self.vault_count(), after.vault_count());
+
+        for (index, (before, after)) in self.table().iter().zip(after.table().iter()).enumerate() {
+            changes.field(
+                format_args!("table[{}].st_mint", index),
+                before.st_mint(),
+                after.st_mint(),
+            );
+            changes.field(
+                format_args!("table[{}].weight", index),
+                before.weight(),
+                after.weight(),
+            );
+            changes.field(
+                format_args!("table[{}].slot_set", index),
+                before.slot_set(),
+                after.slot_set(),
+            );
+            changes.field(
+                format_args!("table[{}].slot_updated", index),
+                before.slot_updated(),
+                after.slot_updated(),
+            );
+        }
+
+        changes.0
+    }
+}
+
+impl AccountDiff for EpochSnapshot {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field(
+            "operator_count",
+            self.operator_count(),
+            after.operator_count(),
+        );
+        changes.field("vault_count", self.vault_count(), after.vault_count());
+        changes.field(
+            "operators_registered",
+            self.operators_registered(),
+            after.operators_registered(),
+        );
+        changes.field(
+            "valid_operator_vault_delegations",
+            self.valid_operator_vault_delegations(),
+            after.valid_operator_vault_delegations(),
+        );
+        changes.field(
+            "stake_weight",
+            self.stake_weights().stake_weight_u256(),
+            after.stake_weights().stake_weight_u256(),
+        );
+        changes.field(
+            "slot_finalized",
+            self.slot_finalized(),
+            after.slot_finalized(),
+        );
+
+        changes.0
+    }
+}
+
+impl AccountDiff for OperatorSnapshot {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("operator", self.operator(), after.operator());
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field("is_active", self.is_active(), after.is_active());
+        changes.field(
+            "operator_fee_bps",
+            self.operator_fee_bps(),
+            after.operator_fee_bps(),
+        );
+        changes.field(
+            "vault_operator_delegation_count",
+            self.vault_operator_delegation_count(),
+            after.vault_operator_delegation_count(),
+        );
+        changes.field(
+            "vault_operator_delegations_registered",
+            self.vault_operator_delegations_registered(),
+            after.vault_operator_delegations_registered(),
+        );
+        changes.field(
+            "valid_operator_vault_delegations",
+            self.valid_operator_vault_delegations(),
+            after.valid_operator_vault_delegations(),
+        );
+        changes.field(
+            "stake_weight",
+            self.stake_weights().stake_weight_u256(),
+            after.stake_weights().stake_weight_u256(),
+        );
+        changes.field(
+            "slot_finalized",
+            self.slot_finalized(),
+            after.slot_finalized(),
+        );
+
+        for (index, (before, after)) in self
+            .vault_operator_stake_weight()
+            .iter()
+            .zip(after.vault_operator_stake_weight().iter())
+            .enumerate()
+        {
+            changes.field(
+                format_args!("vault_operator_stake_weight[{}].vault", index),
+                before.vault(),
+                after.vault(),
+            );
+            changes.field(
+                format_args!("vault_operator_stake_weight[{}].stake_weight", index),
+                before.stake_weights().stake_weight_u256(),
+                after.stake_weights().stake_weight_u256(),
+            );
+        }
+
+        changes.0
+    }
+}
+
+impl AccountDiff for NCNRewardRouter {
+    fn diff(&self, after: &Self) -> Vec<FieldChange> {
+        let mut changes = FieldChanges::default();
+
+        changes.field("epoch", self.epoch(), after.epoch());
+        changes.field("total_rewards", self.total_rewards(), after.total_rewards());
+        changes.field("reward_pool", self.reward_pool(), after.reward_pool());
+        changes.field(
+            "rewards_processed",
+            self.rewards_processed(),
+            after.rewards_processed(),
+        );
+        changes.field(
+            "protocol_rewards",
+            self.protocol_rewards(),
+            after.protocol_rewards(),
+        );
+        changes.field("ncn_rewards", self.ncn_rewards(), after.ncn_rewards());
+        for group in 0..MAX_FEE_GROUPS {
+            changes.field(
+                format_args!("fee_group_rewards[{}]", group),
+                self.fee_group_rewards(group).ok(),
+                after.fee_group_rewards(group).ok(),
+            );
+        }
+        changes.field(
+            "operator_vault_rewards",
+            self.operator_vault_rewards(),
+            after.operator_vault_rewards(),
+        );
+        changes.field(
+            "last_vote_index",
+            self.last_vote_index(),
+            after.last_vote_index(),
+        );
+        changes.field(
+            "last_rewards_to_process",
+            self.last_rewards_to_process(),
+            after.last_rewards_to_process(),
+        );
+
+        for (index, (before, after)) in self
+            .operator_vault_reward_routes()
+            .iter()
+            .zip(after.operator_vault_reward_routes().iter())
+            .enumerate()
+        {
+            changes.field(
+                format_args!("operator_vault_reward_routes[{}].operator", index),
+                before.operator(),
+                after.operator(),
+            );
+            changes.field(
+                format_args!("operator_vault_reward_routes[{}].rewards", index),
+                before.rewards().ok(),
+                after.rewards().ok(),
+            );
+        }
+
+        changes.0
+    }
+}
+
+/// The vaults, st mint weights and operator stake weights of one epoch, as snapshotted in its
+/// weight table and operator snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpochSet {
+    pub epoch: u64,
+    /// The registered vaults and their st mint
+    pub vaults: BTreeMap<Pubkey, Pubkey>,
+    /// The weight of every st mint, `0` until set
+    pub weights: BTreeMap<Pubkey, u128>,
+    /// The stake weight of every active operator
+    pub operator_stake_weights: BTreeMap<Pubkey, u128>,
+}
+
+/// A value present in one epoch or both, `None` on the side it is missing from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetChange<T> {
+    pub key: Pubkey,
+    pub before: Option<T>,
+    pub after: Option<T>,
+}
+
+impl<T: fmt::Display> fmt::Display for SetChange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |value: &Option<T>| value.as_ref().map_or("-".to_string(), T::to_string);
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.key,
+            side(&self.before),
+            side(&self.after)
+        )
+    }
+}
+
+/// The changes of the vaults, weights and operator stake weights from one epoch to another, e.g. to
+/// find why consensus weight shifted between them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpochSetDiff {
+    pub before_epoch: u64,
+    pub after_epoch: u64,
+    pub vaults_added: Vec<Pubkey>,
+    pub vaults_removed: Vec<Pubkey>,
+    pub weight_changes: Vec<SetChange<u128>>,
+    pub operators_added: Vec<Pubkey>,
+    pub operators_removed: Vec<Pubkey>,
+    /// Stake weight changes of the operators active in both epochs
+    pub stake_weight_changes: Vec<SetChange<u128>>,
+    pub total_stake_weight_before: u128,
+    pub total_stake_weight_after: u128,
+}
+
+impl EpochSetDiff {
+    pub fn is_empty(&self) -> bool {
+        self.vaults_added.is_empty()
+            && self.vaults_removed.is_empty()
+            && self.weight_changes.is_empty()
+            && self.operators_added.is_empty()
+            && self.operators_removed.is_empty()
+            && self.stake_weight_changes.is_empty()
+    }
+}
+
+impl EpochSet {
+    pub fn new(weight_table: &WeightTable, operator_snapshots: &[OperatorSnapshot]) -> Self {
+        Self {
+            epoch: weight_table.epoch(),
+            vaults: weight_table
+                .vault_entries()
+                .iter()
+                .map(|entry| (*entry.vault(), *entry.st_mint()))
+                .collect(),
+            weights: weight_table
+                .table()
+                .iter()
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| (*entry.st_mint(), entry.weight()))
+                .collect(),
+            operator_stake_weights: operator_snapshots
+                .iter()
+                .filter(|snapshot| snapshot.is_active())
+                .map(|snapshot| {
+                    (
+                        *snapshot.operator(),
+                        snapshot.stake_weights().stake_weight(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn diff(&self, after: &Self) -> EpochSetDiff {
+        EpochSetDiff {
+            before_epoch: self.epoch,
+            after_epoch: after.epoch,
+            vaults_added: added(&self.vaults, &after.vaults),
+            vaults_removed: added(&after.vaults, &self.vaults),
+            weight_changes: changes(&self.weights, &after.weights, true),
+            operators_added: added(&self.operator_stake_weights, &after.operator_stake_weights),
+            operators_removed: added(&after.operator_stake_weights, &self.operator_stake_weights),
+            stake_weight_changes: changes(
+                &self.operator_stake_weights,
+                &after.operator_stake_weights,
+                false,
+            ),
+            total_stake_weight_before: self.total_stake_weight(),
+            total_stake_weight_after: after.total_stake_weight(),
+        }
+    }
+
+    pub fn total_stake_weight(&self) -> u128 {
+        self.operator_stake_weights
+            .values()
+            .fold(0_u128, |total, stake_weight| {
+                total.saturating_add(*stake_weight)
+            })
+    }
+}
+
+/// The keys of `after` missing from `before`
+fn added<T>(before: &BTreeMap<Pubkey, T>, after: &BTreeMap<Pubkey, T>) -> Vec<Pubkey> {
+    after
+        .keys()
+        .filter(|key| !before.contains_key(*key))
+        .copied()
+        .collect()
+}
+
+/// The values that differ between the two maps, the keys in only one of them included when
+/// `include_missing` is set
+fn changes<T: Copy + PartialEq>(
+    before: &BTreeMap<Pubkey, T>,
+    after: &BTreeMap<Pubkey, T>,
+    include_missing: bool,
+) -> Vec<SetChange<T>> {
+    let keys: BTreeSet<&Pubkey> = before.keys().chain(after.keys()).collect();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let before = before.get(key).copied();
+            let after = after.get(key).copied();
+
+            let changed = match (before, after) {
+                (Some(before), Some(after)) => before != after,
+                _ => include_missing,
+            };
+
+            changed.then_some(SetChange {
+                key: *key,
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::{ballot_box::Ballot, stake_weight::StakeWeights};
+
+    #[test]
+    fn test_ballot_box_diff() {
+        let ncn = Pubkey::new_unique();
+        let before = BallotBox::new(&ncn, 1, 255, 100);
+        let mut after = before;
+
+        assert!(before.diff(&after).is_empty());
+
+        let operator = Pubkey::new_unique();
+        let stake_weights = StakeWeights::snapshot(1_000).unwrap();
+        after
+            .cast_vote(&operator, &Ballot::new(1), &stake_weights, 101, 1_000)
+            .unwrap();
+
+        let fields: Vec<String> = before
+            .diff(&after)
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "operators_voted",
+                "unique_ballots",
+                "ballot_tallies[0].ballot",
+                "ballot_tallies[0].tally",
+                "ballot_tallies[0].stake_weight",
+                "operator_votes[0].operator",
+                "operator_votes[0].slot_voted",
+                "operator_votes[0].ballot_index",
+                "operator_votes[0].stake_weight",
+            ]
+        );
+
+        let change = &before.diff(&after)[0];
+        assert_eq!(change.to_string(), "operators_voted: 0 -> 1");
+    }
+
+    #[test]
+    fn test_epoch_set_diff() {
+        let vault = Pubkey::new_unique();
+        let new_vault = Pubkey::new_unique();
+        let st_mint = Pubkey::new_unique();
+        let new_st_mint = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let leaving_operator = Pubkey::new_unique();
+        let joining_operator = Pubkey::new_unique();
+
+        let before = EpochSet {
+            epoch: 1,
+            vaults: BTreeMap::from([(vault, st_mint)]),
+            weights: BTreeMap::from([(st_mint, 100)]),
+            operator_stake_weights: BTreeMap::from([(operator, 1_000), (leaving_operator, 500)]),
+        };
+        assert!(before.diff(&before).is_empty());
+
+        let after = EpochSet {
+            epoch: 2,
+            vaults: BTreeMap::from([(vault, st_mint), (new_vault, new_st_mint)]),
+            weights: BTreeMap::from([(st_mint, 120), (new_st_mint, 50)]),
+            operator_stake_weights: BTreeMap::from([(operator, 1_200), (joining_operator, 300)]),
+        };
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.before_epoch, 1);
+        assert_eq!(diff.after_epoch, 2);
+        assert_eq!(diff.vaults_added, vec![new_vault]);
+        assert!(diff.vaults_removed.is_empty());
+        assert_eq!(diff.weight_changes.len(), 2);
+        assert!(diff.weight_changes.contains(&SetChange {
+            key: st_mint,
+            before: Some(100),
+            after: Some(120),
+        }));
+        assert!(diff.weight_changes.contains(&SetChange {
+            key: new_st_mint,
+            before: None,
+            after: Some(50),
+        }));
+        assert_eq!(diff.operators_added, vec![joining_operator]);
+        assert_eq!(diff.operators_removed, vec![leaving_operator]);
+        assert_eq!(
+            diff.stake_weight_changes,
+            vec![SetChange {
+                key: operator,
+                before: Some(1_000),
+                after: Some(1_200),
+            }]
+        );
+        assert_eq!(diff.total_stake_weight_before, 1_500);
+        assert_eq!(diff.total_stake_weight_after, 1_500);
+        assert_eq!(
+            diff.stake_weight_changes[0].to_string(),
+            format!("{}: 1000 -> 1200", operator)
+        );
+    }
+}
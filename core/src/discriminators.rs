@@ -20,4 +20,10 @@ pub enum Discriminators {
     // State Tracking
     EpochState = 0x50,
     EpochMarker = 0x51,
+
+    // Metadata
+    OperatorMetadata = 0x60,
+
+    // Keepers
+    KeeperLease = 0x70,
 }
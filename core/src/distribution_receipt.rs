@@ -0,0 +1,220 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::PodU64;
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// Who a distribution paid out to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum DistributionKind {
+    Protocol = 0,
+    Ncn = 1,
+    OperatorVaultRoute = 2,
+    Operator = 3,
+    Vault = 4,
+    /// A custom fee group, identified by its wallet
+    FeeGroup = 5,
+}
+
+impl TryFrom<u8> for DistributionKind {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Protocol),
+            1 => Ok(Self::Ncn),
+            2 => Ok(Self::OperatorVaultRoute),
+            3 => Ok(Self::Operator),
+            4 => Ok(Self::Vault),
+            5 => Ok(Self::FeeGroup),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Compact record of a single payout, logged as program data by every `Distribute*`
+/// instruction so indexers can reconcile payouts after the routers have been zeroed or closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct DistributionReceipt {
+    /// The NCN the rewards belong to
+    ncn: Pubkey,
+    /// The epoch the rewards were routed in
+    epoch: PodU64,
+    /// The account the rewards were transferred to, the token account for mint rewards
+    recipient: Pubkey,
+    /// The reward mint, `Pubkey::default()` for SOL
+    mint: Pubkey,
+    /// The amount transferred, in lamports or base units of the mint
+    amount: PodU64,
+    /// The slot of the distribution
+    slot: PodU64,
+    /// The `DistributionKind` of the distribution
+    kind: u8,
+}
+
+impl DistributionReceipt {
+    /// Leading log field identifying a receipt among other program data logs
+    pub const LOG_PREFIX: &'static [u8] = b"distribution_receipt";
+
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+
+    pub fn new(
+        kind: DistributionKind,
+        ncn: &Pubkey,
+        epoch: u64,
+        recipient: &Pubkey,
+        mint: Option<&Pubkey>,
+        amount: u64,
+        slot: u64,
+    ) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            recipient: *recipient,
+            mint: mint.copied().unwrap_or_default(),
+            amount: PodU64::from(amount),
+            slot: PodU64::from(slot),
+            kind: kind as u8,
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn recipient(&self) -> &Pubkey {
+        &self.recipient
+    }
+
+    /// `None` for SOL distributions
+    pub fn mint(&self) -> Option<Pubkey> {
+        if self.mint.eq(&Pubkey::default()) {
+            None
+        } else {
+            Some(self.mint)
+        }
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.into()
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+
+    pub fn kind(&self) -> Option<DistributionKind> {
+        DistributionKind::try_from(self.kind).ok()
+    }
+
+    /// Logs the receipt as `Program data: <prefix> <receipt>`
+    pub fn log(&self) {
+        sol_log_data(&[Self::LOG_PREFIX, bytemuck::bytes_of(self)]);
+    }
+
+    /// Parses the base64 decoded fields of a `Program data:` log, returns `None` for any other data
+    pub fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        let [prefix, receipt] = fields else {
+            return None;
+        };
+
+        if prefix.ne(&Self::LOG_PREFIX) || receipt.len() != Self::SIZE {
+            return None;
+        }
+
+        Some(bytemuck::pod_read_unaligned(receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        use std::mem::size_of;
+
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<Pubkey>() // recipient
+            + size_of::<Pubkey>() // mint
+            + size_of::<PodU64>() // amount
+            + size_of::<PodU64>() // slot
+            + 1; // kind
+
+        assert_eq!(DistributionReceipt::SIZE, expected_total);
+    }
+
+    #[test]
+    fn test_log_data_round_trip() {
+        let ncn = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let receipt = DistributionReceipt::new(
+            DistributionKind::Vault,
+            &ncn,
+            5,
+            &recipient,
+            Some(&mint),
+            1_000,
+            42,
+        );
+
+        let parsed = DistributionReceipt::from_log_data(&[
+            DistributionReceipt::LOG_PREFIX,
+            bytemuck::bytes_of(&receipt),
+        ])
+        .unwrap();
+
+        assert_eq!(parsed, receipt);
+        assert_eq!(parsed.ncn(), &ncn);
+        assert_eq!(parsed.epoch(), 5);
+        assert_eq!(parsed.recipient(), &recipient);
+        assert_eq!(parsed.mint(), Some(mint));
+        assert_eq!(parsed.amount(), 1_000);
+        assert_eq!(parsed.slot(), 42);
+        assert_eq!(parsed.kind(), Some(DistributionKind::Vault));
+    }
+
+    #[test]
+    fn test_sol_receipt_has_no_mint() {
+        let receipt = DistributionReceipt::new(
+            DistributionKind::Protocol,
+            &Pubkey::new_unique(),
+            1,
+            &Pubkey::new_unique(),
+            None,
+            10,
+            1,
+        );
+
+        assert_eq!(receipt.mint(), None);
+    }
+
+    #[test]
+    fn test_from_log_data_rejects_other_data() {
+        let receipt = DistributionReceipt::new(
+            DistributionKind::Ncn,
+            &Pubkey::new_unique(),
+            1,
+            &Pubkey::new_unique(),
+            None,
+            10,
+            1,
+        );
+        let bytes = bytemuck::bytes_of(&receipt);
+
+        assert!(DistributionReceipt::from_log_data(&[bytes]).is_none());
+        assert!(DistributionReceipt::from_log_data(&[b"other".as_slice(), bytes]).is_none());
+        assert!(DistributionReceipt::from_log_data(&[
+            DistributionReceipt::LOG_PREFIX,
+            &bytes[..DistributionReceipt::SIZE - 1]
+        ])
+        .is_none());
+    }
+}
@@ -1,19 +1,28 @@
-use std::mem::size_of;
+use core::fmt;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use shank::{ShankAccount, ShankType};
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+#[cfg(feature = "program")]
+use solana_program::{account_info::AccountInfo, msg};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, error::NCNProgramError};
+use crate::{discriminators::Discriminators, error::NCNProgramError, pdas::NCNProgramPda};
 
-/// 56-byte account to mark that an epoch's accounts have all been closed
+/// 57-byte account to mark that an epoch's accounts have all been closed, or that the epoch was
+/// skipped without ever being started
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct EpochMarker {
     ncn: Pubkey,
     epoch: PodU64,
     slot_closed: PodU64,
+    /// True if the epoch was marked as skipped instead of closed
+    skipped: PodBool,
 }
 
 impl Discriminator for EpochMarker {
@@ -21,14 +30,18 @@ impl Discriminator for EpochMarker {
 }
 
 impl EpochMarker {
-    const EPOCH_MARKER_SEED: &'static [u8] = b"epoch_marker";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
-    pub fn new(ncn: &Pubkey, epoch: u64, slot_closed: u64) -> Self {
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
+    pub fn new(ncn: &Pubkey, epoch: u64, slot_closed: u64, skipped: bool) -> Self {
         Self {
             ncn: *ncn,
             epoch: PodU64::from(epoch),
             slot_closed: PodU64::from(slot_closed),
+            skipped: PodBool::from(skipped),
         }
     }
 
@@ -44,14 +57,35 @@ impl EpochMarker {
         self.slot_closed.into()
     }
 
+    pub fn is_skipped(&self) -> bool {
+        self.skipped.into()
+    }
+
+    /// Checks that an epoch without an epoch state can be marked as skipped. Like voting, an
+    /// epoch is only given up on once `epochs_before_stall` epochs have passed.
+    pub fn check_can_skip(
+        epoch: u64,
+        current_epoch: u64,
+        starting_valid_epoch: u64,
+        epochs_before_stall: u64,
+    ) -> Result<(), NCNProgramError> {
+        if epoch < starting_valid_epoch {
+            return Err(NCNProgramError::EpochNotSkippable);
+        }
+
+        let stall_epoch = epoch
+            .checked_add(epochs_before_stall)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+        if current_epoch < stall_epoch {
+            return Err(NCNProgramError::EpochNotSkippable);
+        }
+
+        Ok(())
+    }
+
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        // Note: The second NCN is an error from the original code, most presumably a copy/paste or Claude error
-        vec![
-            Self::EPOCH_MARKER_SEED.to_vec(),
-            ncn.to_bytes().to_vec(),
-            epoch.to_le_bytes().to_vec(),
-            ncn.to_bytes().to_vec(),
-        ]
+        NCNProgramPda::EpochMarker { ncn: *ncn, epoch }.seeds()
     }
 
     pub fn find_program_address(
@@ -59,14 +93,10 @@ impl EpochMarker {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let (address, bump) = Pubkey::find_program_address(
-            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
-            program_id,
-        );
-        (address, bump, seeds)
+        NCNProgramPda::EpochMarker { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn check_dne(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -93,3 +123,49 @@ impl EpochMarker {
         Ok(())
     }
 }
+
+impl fmt::Display for EpochMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "EpochMarker {{")?;
+        writeln!(f, "  ncn: {},", self.ncn)?;
+        writeln!(f, "  epoch: {},", self.epoch())?;
+        writeln!(f, "  slot_closed: {},", self.slot_closed())?;
+        writeln!(f, "  skipped: {}", self.is_skipped())?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // slot_closed
+            + size_of::<PodBool>(); // skipped
+
+        assert_eq!(size_of::<EpochMarker>(), expected_total);
+        assert_eq!(EpochMarker::SIZE, 57);
+    }
+
+    #[test]
+    fn test_check_can_skip() {
+        // Stalled epochs can be skipped
+        assert!(EpochMarker::check_can_skip(10, 13, 0, 3).is_ok());
+        assert!(EpochMarker::check_can_skip(10, 20, 0, 3).is_ok());
+
+        // Epochs that could still run cannot
+        assert_eq!(
+            EpochMarker::check_can_skip(10, 12, 0, 3),
+            Err(NCNProgramError::EpochNotSkippable)
+        );
+
+        // Epochs before the starting valid epoch are never run, so they are never skipped
+        assert_eq!(
+            EpochMarker::check_can_skip(10, 20, 11, 3),
+            Err(NCNProgramError::EpochNotSkippable)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -8,12 +8,21 @@ use jito_bytemuck::{
 };
 use jito_vault_core::vault_operator_delegation::VaultOperatorDelegation;
 use shank::{ShankAccount, ShankType};
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
-use spl_math::precise_number::PreciseNumber;
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use spl_math::uint::U256;
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
-    constants::MAX_VAULTS, discriminators::Discriminators, error::NCNProgramError, fees::Fees,
-    loaders::check_load, stake_weight::StakeWeights, weight_table::WeightTable,
+    constants::{MAX_FEE_BPS, MAX_FEE_GROUPS, MAX_VAULTS},
+    discriminators::Discriminators,
+    error::NCNProgramError,
+    fees::Fees,
+    pdas::NCNProgramPda,
+    stake_weight::{mul_div_round, normalized_stake_weight, StakeWeights},
+    weight_table::WeightTable,
 };
 
 // PDA'd ["epoch_snapshot", NCN, NCN_EPOCH_SLOT]
@@ -42,6 +51,8 @@ pub struct EpochSnapshot {
     stake_weights: StakeWeights,
     /// Snapshot of the fees configuration for this epoch
     fees: Fees,
+    /// Largest stake weight a single vault can contribute, 0 if uncapped
+    max_vault_stake_weight: StakeWeights,
 }
 
 impl Discriminator for EpochSnapshot {
@@ -49,9 +60,12 @@ impl Discriminator for EpochSnapshot {
 }
 
 impl EpochSnapshot {
-    const EPOCH_SNAPSHOT_SEED: &'static [u8] = b"epoch_snapshot";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     pub fn new(
         ncn: &Pubkey,
         ncn_epoch: u64,
@@ -73,19 +87,16 @@ impl EpochSnapshot {
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weights: StakeWeights::default(),
             fees,
+            max_vault_stake_weight: StakeWeights::default(),
         }
     }
 
     pub fn seeds(ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::EPOCH_SNAPSHOT_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                ncn_epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::EpochSnapshot {
+            ncn: *ncn,
+            epoch: ncn_epoch,
+        }
+        .seeds()
     }
 
     pub fn find_program_address(
@@ -93,12 +104,10 @@ impl EpochSnapshot {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::EpochSnapshot { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -116,6 +125,7 @@ impl EpochSnapshot {
         )
     }
 
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -188,9 +198,74 @@ impl EpochSnapshot {
         Ok(())
     }
 
+    /// Registers several inactive operators at once, finalizing the snapshot if they were the
+    /// last ones. Used when operator snapshots are initialized in a batch.
+    pub fn increment_inactive_operator_registrations(
+        &mut self,
+        current_slot: u64,
+        inactive_operators: u64,
+    ) -> Result<(), NCNProgramError> {
+        if inactive_operators == 0 {
+            return Ok(());
+        }
+
+        if self.finalized() {
+            return Err(NCNProgramError::OperatorFinalized);
+        }
+
+        self.operators_registered = PodU64::from(
+            self.operators_registered()
+                .checked_add(inactive_operators)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        if self.finalized() {
+            self.slot_finalized = PodU64::from(current_slot);
+        }
+
+        Ok(())
+    }
+
     pub const fn fees(&self) -> &Fees {
         &self.fees
     }
+
+    pub fn max_vault_stake_weight(&self) -> U256 {
+        self.max_vault_stake_weight.stake_weight_u256()
+    }
+
+    /// Caps the stake weight of every vault at `max_vault_stake_weight_bps` of the total stake
+    /// weight of all vaults
+    pub fn set_max_vault_stake_weight(
+        &mut self,
+        total_vault_stake_weight: U256,
+        max_vault_stake_weight_bps: u16,
+    ) -> Result<(), NCNProgramError> {
+        let max_vault_stake_weight = mul_div_round(
+            total_vault_stake_weight,
+            U256::from(max_vault_stake_weight_bps),
+            U256::from(MAX_FEE_BPS),
+        )?;
+
+        self.max_vault_stake_weight = StakeWeights::new_u256(max_vault_stake_weight);
+
+        Ok(())
+    }
+
+    /// Scales down the stake weight of a delegation if its vault would exceed the cap, where
+    /// `vault_stake_weight` is the stake weight of the vault across all its delegations
+    pub fn cap_vault_stake_weight(
+        &self,
+        stake_weight: U256,
+        vault_stake_weight: U256,
+    ) -> Result<U256, NCNProgramError> {
+        let max_vault_stake_weight = self.max_vault_stake_weight();
+        if max_vault_stake_weight.is_zero() || vault_stake_weight <= max_vault_stake_weight {
+            return Ok(stake_weight);
+        }
+
+        mul_div_round(stake_weight, max_vault_stake_weight, vault_stake_weight)
+    }
 }
 
 // PDA'd ["operator_snapshot", OPERATOR, NCN, NCN_EPOCH_SLOT]
@@ -217,7 +292,7 @@ pub struct OperatorSnapshot {
 
     stake_weights: StakeWeights,
 
-    vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
+    vault_operator_stake_weight: [VaultOperatorStakeWeight; MAX_VAULTS],
 }
 
 impl Discriminator for OperatorSnapshot {
@@ -225,9 +300,13 @@ impl Discriminator for OperatorSnapshot {
 }
 
 impl OperatorSnapshot {
-    const OPERATOR_SNAPSHOT_SEED: &'static [u8] = b"operator_snapshot";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const OPERATOR_OFFSET: usize = 8 + offset_of!(Self, operator);
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, ncn_epoch);
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         operator: &Pubkey,
@@ -310,16 +389,12 @@ impl OperatorSnapshot {
     }
 
     pub fn seeds(operator: &Pubkey, ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::OPERATOR_SNAPSHOT_SEED.to_vec(),
-                operator.to_bytes().to_vec(),
-                ncn.to_bytes().to_vec(),
-                ncn_epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::OperatorSnapshot {
+            operator: *operator,
+            ncn: *ncn,
+            epoch: ncn_epoch,
+        }
+        .seeds()
     }
 
     pub fn find_program_address(
@@ -328,12 +403,15 @@ impl OperatorSnapshot {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(operator, ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::OperatorSnapshot {
+            operator: *operator,
+            ncn: *ncn,
+            epoch,
+        }
+        .find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -352,6 +430,7 @@ impl OperatorSnapshot {
         )
     }
 
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -429,6 +508,38 @@ impl OperatorSnapshot {
         &self.vault_operator_stake_weight
     }
 
+    /// Number of filled entries in `vault_operator_stake_weight`. Entries without stake weight are
+    /// pruned once the snapshot is finalized, leaving only the valid delegations.
+    pub fn vault_operator_stake_weight_count(&self) -> u64 {
+        if self.finalized() {
+            self.valid_operator_vault_delegations()
+        } else {
+            self.vault_operator_delegations_registered()
+        }
+    }
+
+    /// Moves the entries with stake weight to the front and clears the rest, so reward routing
+    /// does not have to visit vaults without delegation
+    fn prune_inactive_vault_operator_stake_weights(&mut self) {
+        let registered = self.vault_operator_delegations_registered() as usize;
+
+        let mut active_entries: usize = 0;
+        for index in 0..registered {
+            if self.vault_operator_stake_weight[index]
+                .stake_weights()
+                .stake_weight()
+                > 0
+            {
+                self.vault_operator_stake_weight.swap(active_entries, index);
+                active_entries += 1;
+            }
+        }
+
+        for entry in self.vault_operator_stake_weight[active_entries..registered].iter_mut() {
+            *entry = VaultOperatorStakeWeight::default();
+        }
+    }
+
     pub fn insert_vault_operator_stake_weight(
         &mut self,
         vault: &Pubkey,
@@ -485,6 +596,7 @@ impl OperatorSnapshot {
 
         if self.finalized() {
             self.slot_finalized = PodU64::from(current_slot);
+            self.prune_inactive_vault_operator_stake_weights();
         }
 
         Ok(())
@@ -494,23 +606,28 @@ impl OperatorSnapshot {
         vault_operator_delegation: &VaultOperatorDelegation,
         weight_table: &WeightTable,
         st_mint: &Pubkey,
-    ) -> Result<u128, ProgramError> {
+    ) -> Result<U256, ProgramError> {
         let total_security = vault_operator_delegation
             .delegation_state
             .total_security()?;
 
-        let precise_total_security = PreciseNumber::new(total_security as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_weight = weight_table.get_precise_weight(st_mint)?;
+        Self::calculate_stake_weight(total_security, weight_table, st_mint)
+    }
 
-        let precise_total_stake_weight = precise_total_security
-            .checked_mul(&precise_weight)
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    /// Stake weight of `total_security` tokens of `st_mint`, normalized by the decimals of the mint
+    /// so that mints with different decimals are weighed alike
+    pub fn calculate_stake_weight(
+        total_security: u64,
+        weight_table: &WeightTable,
+        st_mint: &Pubkey,
+    ) -> Result<U256, ProgramError> {
+        let weight_entry = weight_table.get_weight_entry(st_mint)?;
 
-        let total_stake_weight = precise_total_stake_weight
-            .to_imprecise()
-            .ok_or(NCNProgramError::CastToImpreciseNumberError)?;
+        let total_stake_weight = normalized_stake_weight(
+            total_security,
+            weight_entry.st_mint_entry().decimals(),
+            weight_entry.weight(),
+        )?;
 
         Ok(total_stake_weight)
     }
@@ -573,10 +690,17 @@ impl fmt::Display for EpochSnapshot {
        writeln!(f, "  Valid Delegations:            {}", self.valid_operator_vault_delegations())?;
        writeln!(f, "  Slot Finalized:               {}", self.slot_finalized())?;
        writeln!(f, "  Finalized:                    {}", self.finalized())?;
-       writeln!(f, "  total Weight:                 {}", self.stake_weights().stake_weight())?;
+       writeln!(f, "  total Weight:                 {}", self.stake_weights().stake_weight_u256())?;
        writeln!(f, "  Protocol Fee BPS:             {}", self.fees().protocol_fee_bps().unwrap_or(0))?;
        writeln!(f, "  NCN Fee BPS:                  {}", self.fees().ncn_fee_bps().unwrap_or(0))?;
+       for group in 0..MAX_FEE_GROUPS {
+           let fee_group_bps = self.fees().fee_group_bps(group).unwrap_or(0);
+           if fee_group_bps > 0 {
+               writeln!(f, "  Fee Group {} BPS:              {}", group, fee_group_bps)?;
+           }
+       }
        writeln!(f, "  Total Fee BPS:                {}", self.fees().total_fees_bps().unwrap_or(0))?;
+       writeln!(f, "  Max Vault Stake Weight:       {}", self.max_vault_stake_weight())?;
 
        writeln!(f, "\n")?;
        Ok(())
@@ -601,14 +725,14 @@ impl fmt::Display for OperatorSnapshot {
        writeln!(f, "  Finalized:                    {}", self.finalized())?;
 
        let stake_weights = self.stake_weights();
-       writeln!(f, "\nTotal Stake Weight: {}", stake_weights.stake_weight())?;
+       writeln!(f, "\nTotal Stake Weight: {}", stake_weights.stake_weight_u256())?;
 
        writeln!(f, "\nVault Operator Stake Weights:")?;
        for weight in self.vault_operator_stake_weight().iter() {
            if !weight.is_empty() {
                writeln!(f, "  Vault:                        {}", weight.vault())?;
                writeln!(f, "    Vault Index:                {}", weight.vault_index())?;
-               writeln!(f, "    Stake Weight: {}", weight.stake_weights().stake_weight())?;
+               writeln!(f, "    Stake Weight: {}", weight.stake_weights().stake_weight_u256())?;
            }
        }
 
@@ -621,9 +745,100 @@ impl fmt::Display for OperatorSnapshot {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_operator_snapshot_filter_offsets() {
+        let operator = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let operator_snapshot =
+            OperatorSnapshot::new(&operator, &ncn, 42, 255, 1, true, 0, 0, 100, 1).unwrap();
+
+        let mut data = vec![OperatorSnapshot::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(bytemuck::bytes_of(&operator_snapshot));
+
+        let field = |offset: usize, len: usize| &data[offset..offset + len];
+        assert_eq!(
+            field(OperatorSnapshot::OPERATOR_OFFSET, 32),
+            operator.as_ref()
+        );
+        assert_eq!(field(OperatorSnapshot::NCN_OFFSET, 32), ncn.as_ref());
+        assert_eq!(
+            field(OperatorSnapshot::EPOCH_OFFSET, 8),
+            42u64.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn test_cap_vault_stake_weight() {
+        let mut epoch_snapshot = EpochSnapshot::new(
+            &Pubkey::new_unique(),
+            1,
+            0,
+            0,
+            1,
+            2,
+            Fees::new(0, 0).unwrap(),
+        );
+
+        // Uncapped by default
+        assert_eq!(
+            epoch_snapshot
+                .cap_vault_stake_weight(U256::from(500u64), U256::from(1_000u64))
+                .unwrap(),
+            U256::from(500u64)
+        );
+
+        // 30% of a total of 10_000
+        epoch_snapshot
+            .set_max_vault_stake_weight(U256::from(10_000u64), 3_000)
+            .unwrap();
+        assert_eq!(
+            epoch_snapshot.max_vault_stake_weight(),
+            U256::from(3_000u64)
+        );
+
+        // Vaults under the cap keep their stake weight
+        assert_eq!(
+            epoch_snapshot
+                .cap_vault_stake_weight(U256::from(2_000u64), U256::from(3_000u64))
+                .unwrap(),
+            U256::from(2_000u64)
+        );
+
+        // Delegations of larger vaults are scaled so the vault ends up at the cap
+        assert_eq!(
+            epoch_snapshot
+                .cap_vault_stake_weight(U256::from(3_000u64), U256::from(6_000u64))
+                .unwrap(),
+            U256::from(1_500u64)
+        );
+
+        // Vault stake weights above a u128 are capped the same way
+        let total_vault_stake_weight = U256::from(u128::MAX) * U256::from(10u64);
+        epoch_snapshot
+            .set_max_vault_stake_weight(total_vault_stake_weight, 3_000)
+            .unwrap();
+        assert_eq!(
+            epoch_snapshot.max_vault_stake_weight(),
+            U256::from(u128::MAX) * U256::from(3u64)
+        );
+        let capped_stake_weight = epoch_snapshot
+            .cap_vault_stake_weight(
+                U256::from(u128::MAX) * U256::from(3u64),
+                U256::from(u128::MAX) * U256::from(6u64),
+            )
+            .unwrap();
+        let expected_stake_weight = U256::from(u128::MAX) * U256::from(3u64) / U256::from(2u64);
+        let error = if capped_stake_weight > expected_stake_weight {
+            capped_stake_weight - expected_stake_weight
+        } else {
+            expected_stake_weight - capped_stake_weight
+        };
+        assert!(error <= U256::from(4u64));
+    }
+
     #[test]
     fn test_operator_snapshot_size() {
-        use std::mem::size_of;
+        use std::mem::{offset_of, size_of};
 
         let expected_total = size_of::<Pubkey>() // operator
             + size_of::<Pubkey>() // ncn
@@ -857,6 +1072,41 @@ mod tests {
         assert_eq!(result.unwrap_err(), NCNProgramError::OperatorFinalized);
     }
 
+    #[test]
+    fn test_increment_inactive_operator_registrations() {
+        let mut snapshot = EpochSnapshot::new(
+            &Pubkey::new_unique(),
+            1,                          // ncn_epoch
+            1,                          // bump
+            100,                        // current_slot
+            3,                          // operator_count
+            1,                          // vault_count
+            Fees::new(100, 1).unwrap(), // fees
+        );
+
+        // Nothing to register
+        snapshot
+            .increment_inactive_operator_registrations(150, 0)
+            .unwrap();
+        assert_eq!(snapshot.operators_registered(), 0);
+
+        snapshot
+            .increment_inactive_operator_registrations(200, 2)
+            .unwrap();
+        assert_eq!(snapshot.operators_registered(), 2);
+        assert!(!snapshot.finalized());
+
+        snapshot
+            .increment_inactive_operator_registrations(300, 1)
+            .unwrap();
+        assert!(snapshot.finalized());
+        assert_eq!(snapshot.slot_finalized(), 300);
+        assert_eq!(snapshot.stake_weights().stake_weight(), 0);
+
+        let result = snapshot.increment_inactive_operator_registrations(400, 1);
+        assert_eq!(result.unwrap_err(), NCNProgramError::OperatorFinalized);
+    }
+
     #[test]
     fn test_operator_snapshot_initialize_active_inactive() {
         let current_slot = 100;
@@ -938,4 +1188,61 @@ mod tests {
         assert_eq!(inactive_snapshot.vault_operator_delegation_count(), 0);
         // count should be zeroed
     }
+
+    #[test]
+    fn test_prune_inactive_vault_operator_stake_weights() {
+        let mut snapshot = OperatorSnapshot::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            1,
+            100,
+            true,
+            0,
+            0,
+            100,
+            4,
+        )
+        .unwrap();
+
+        let vaults = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let stake_weights = [0u128, 1_000, 0, 2_000];
+
+        for (index, (vault, stake_weight)) in vaults.iter().zip(stake_weights).enumerate() {
+            snapshot
+                .increment_vault_operator_delegation_registration(
+                    200,
+                    vault,
+                    index as u64,
+                    &StakeWeights::snapshot(stake_weight).unwrap(),
+                )
+                .unwrap();
+
+            // Entries are only pruned once the snapshot is finalized
+            if !snapshot.finalized() {
+                assert_eq!(
+                    snapshot.vault_operator_stake_weight_count(),
+                    index as u64 + 1
+                );
+            }
+        }
+
+        assert!(snapshot.finalized());
+        assert_eq!(snapshot.vault_operator_stake_weight_count(), 2);
+        assert_eq!(snapshot.vault_operator_delegations_registered(), 4);
+        assert_eq!(snapshot.stake_weights().stake_weight(), 3_000);
+
+        let entries = snapshot.vault_operator_stake_weight();
+        assert_eq!(entries[0].vault(), &vaults[1]);
+        assert_eq!(entries[0].stake_weights().stake_weight(), 1_000);
+        assert_eq!(entries[1].vault(), &vaults[3]);
+        assert_eq!(entries[1].stake_weights().stake_weight(), 2_000);
+        assert!(entries[2..].iter().all(|entry| entry.is_empty()));
+        assert!(!snapshot.contains_vault(&vaults[0]));
+    }
 }
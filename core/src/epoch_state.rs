@@ -1,5 +1,5 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -7,16 +7,17 @@ use jito_bytemuck::{
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
-use solana_program::{
-    account_info::AccountInfo, epoch_schedule::EpochSchedule, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+#[cfg(feature = "program")]
+use solana_program::{account_info::AccountInfo, msg};
+use solana_program::{epoch_schedule::EpochSchedule, program_error::ProgramError, pubkey::Pubkey};
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
     constants::{DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
     discriminators::Discriminators,
     error::NCNProgramError,
-    loaders::check_load,
+    pdas::NCNProgramPda,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,10 +35,10 @@ pub struct EpochAccountStatus {
     epoch_state: u8,
     weight_table: u8,
     epoch_snapshot: u8,
-    operator_snapshot: [u8; 256],
+    operator_snapshot: [u8; MAX_OPERATORS],
     ballot_box: u8,
     ncn_reward_router: u8,
-    operator_vault_reward_router: [u8; 256],
+    operator_vault_reward_router: [u8; MAX_OPERATORS],
 }
 
 impl Default for EpochAccountStatus {
@@ -269,7 +270,7 @@ pub struct EpochState {
     epoch_snapshot_progress: Progress,
 
     /// Progress on Snapshotting Operators
-    operator_snapshot_progress: [Progress; 256],
+    operator_snapshot_progress: [Progress; MAX_OPERATORS],
 
     /// Progress on voting
     voting_progress: Progress,
@@ -287,7 +288,7 @@ pub struct EpochState {
     operator_vault_distribution_progress: Progress,
 
     /// Operator Vault distribution progress per route
-    operator_vault_routes_distribution_progress: [Progress; 256],
+    operator_vault_routes_distribution_progress: [Progress; MAX_OPERATORS],
 
     /// Is closing
     is_closing: PodBool,
@@ -298,9 +299,12 @@ impl Discriminator for EpochState {
 }
 
 impl EpochState {
-    const EPOCH_STATE_SEED: &'static [u8] = b"epoch_state";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     pub fn new(ncn: &Pubkey, epoch: u64, bump: u8, slot_created: u64) -> Self {
         Self {
             ncn: *ncn,
@@ -335,15 +339,7 @@ impl EpochState {
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::EPOCH_STATE_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::EpochState { ncn: *ncn, epoch }.seeds()
     }
 
     pub fn find_program_address(
@@ -351,12 +347,10 @@ impl EpochState {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (address, bump, seeds)
+        NCNProgramPda::EpochState { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -398,6 +392,7 @@ impl EpochState {
         Ok(())
     }
 
+    #[cfg(feature = "program")]
     pub fn load_and_check_is_closing(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -646,6 +641,15 @@ impl EpochState {
         let _ = self.protocol_distribution_progress.increment(rewards);
     }
 
+    /// Custom fee groups are only tracked in the total distribution progress
+    pub fn update_distribute_fee_group_rewards(&mut self, rewards: u64) {
+        let _ = self.total_distribution_progress.increment(rewards);
+    }
+
+    pub fn update_distribute_cranker_rewards(&mut self, rewards: u64) {
+        let _ = self.total_distribution_progress.increment(rewards);
+    }
+
     pub fn update_route_operator_vault_rewards(
         &mut self,
         operator_index: usize,
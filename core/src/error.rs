@@ -1,7 +1,10 @@
+use std::ops::Range;
+
+use num_derive::FromPrimitive;
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, FromPrimitive)]
 pub enum NCNProgramError {
     #[error("No valid Ballot")]
     NoValidBallots,
@@ -187,6 +190,170 @@ pub enum NCNProgramError {
     EpochIsClosingDown,
     #[error("Marker exists")]
     MarkerExists,
+    #[error("Vault reward recipient must be a system account or a wrapped SOL token account")]
+    InvalidVaultRewardRecipient,
+    #[error("Reward mint is not registered in the config")]
+    RewardMintNotRegistered,
+    #[error("Invalid reward mint")]
+    InvalidRewardMint,
+    #[error("Invalid reward mint index")]
+    InvalidRewardMintIndex,
+    #[error("Reward mint list is full")]
+    RewardMintListFull,
+    #[error("Reward mint not found")]
+    RewardMintNotFound,
+    #[error("Invalid reward token account")]
+    InvalidRewardTokenAccount,
+    #[error("Cranker fee exceeds the maximum")]
+    InvalidCrankerFeeBps,
+    #[error("Timeliness bonus exceeds the maximum")]
+    InvalidTimelinessBonusBps,
+    #[error("Router still has rewards to route or distribute")]
+    RouterRewardsInTransit,
+    #[error("No switchboard feed is registered for the st mint")]
+    NoSwitchboardFeed,
+    #[error("Switchboard feed does not match the registered feed")]
+    IncorrectSwitchboardFeed,
+    #[error("Could not parse the switchboard feed")]
+    BadSwitchboardFeed,
+    #[error("Switchboard feed value is invalid")]
+    BadSwitchboardValue,
+    #[error("Switchboard feed is stale")]
+    StaleSwitchboardFeed,
+    #[error("Switchboard feed confidence interval is too wide")]
+    SwitchboardConfidenceTooLow,
+    #[error("Vault registry changed since the previous epoch")]
+    VaultRegistryChanged,
+    #[error("Weight decay exceeds the maximum")]
+    InvalidWeightDecayBps,
+    #[error("Vault stake weight cap exceeds the maximum")]
+    InvalidMaxVaultStakeWeightBps,
+    #[error("NCN vault ticket is not active")]
+    NcnVaultTicketNotActive,
+    #[error("Vault name is not valid UTF-8")]
+    InvalidVaultName,
+    #[error("Operator metadata is not valid UTF-8")]
+    InvalidOperatorMetadata,
+    #[error("Invalid admin multisig")]
+    InvalidAdminMultisig,
+    #[error("Not enough admin approvers signed")]
+    AdminThresholdNotMet,
+    #[error("Parameters timelock exceeds the maximum")]
+    InvalidParametersTimelock,
+    #[error("No parameter changes are pending")]
+    NoPendingParameters,
+    #[error("Parameters timelock has not elapsed")]
+    ParametersTimelockNotElapsed,
+    #[error("Epoch can only be skipped once it stalled without an epoch state")]
+    EpochNotSkippable,
+    #[error("Invalid fee group")]
+    InvalidFeeGroup,
+    #[error("Fee group name is not valid UTF-8")]
+    InvalidFeeGroupName,
+    #[error("Fee group wallet cannot be the default pubkey")]
+    DefaultFeeGroupWallet,
+    #[error("Fee group wallet does not match the config")]
+    InvalidFeeGroupWallet,
+    #[error("Admin governance program and realm have to be set together")]
+    InvalidAdminGovernance,
+    #[error("Admin instructions have to be signed by a governance of the admin realm")]
+    NotAdminGovernance,
+    #[error("Ballot tallies do not match the operator votes")]
+    BallotTallyMismatch,
+    #[error("Consensus result does not match the ballot box")]
+    ConsensusResultMismatch,
+    #[error("Keeper lease is held by another keeper")]
+    KeeperLeaseHeld,
+    #[error("Keeper lease slots exceed the maximum")]
+    InvalidKeeperLeaseSlots,
+    #[error("Reward router is not closed for good")]
+    RewardRouterNotClosed,
+}
+
+/// The ranges error codes are assigned from, every code in between is contiguous
+const ERROR_CODE_RANGES: [Range<u32>; 3] = [0..1, 0x2100..0x2200, 0x2200..0x2300];
+
+impl NCNProgramError {
+    /// The error behind a custom program error code, `None` if the program never returns the code
+    pub fn from_u32(code: u32) -> Option<Self> {
+        <Self as num_traits::FromPrimitive>::from_u32(code)
+    }
+
+    pub const fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// The variant name, e.g. `OperatorRewardListFull`
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Every error of the program, ordered by code
+    pub fn all() -> Vec<Self> {
+        ERROR_CODE_RANGES
+            .into_iter()
+            .flatten()
+            .filter_map(Self::from_u32)
+            .collect()
+    }
+
+    /// Whether the error means the work of the instruction was already done, e.g. the account was
+    /// created or the operator voted, so a crank failing with it can move on
+    pub const fn is_already_processed(self) -> bool {
+        matches!(
+            self,
+            Self::WeightTableAlreadyInitialized
+                | Self::MintInTable
+                | Self::OperatorFinalized
+                | Self::VaultOperatorDelegationFinalized
+                | Self::DuplicateVaultOperatorDelegation
+                | Self::DuplicateVoteCast
+                | Self::OperatorAlreadyVoted
+                | Self::AccountAlreadyInitialized
+                | Self::CannotCloseAccountAlreadyClosed
+        )
+    }
+
+    /// Name, code and message of the error, e.g.
+    /// `OperatorRewardListFull (0x2235): Operator reward list full`
+    pub fn describe(self) -> String {
+        format!("{} ({:#x}): {}", self.name(), self.code(), self)
+    }
+}
+
+/// An entry of the error registry, for clients that show or export the program errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NCNProgramErrorInfo {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+impl From<NCNProgramError> for NCNProgramErrorInfo {
+    fn from(error: NCNProgramError) -> Self {
+        Self {
+            code: error.code(),
+            name: error.name(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Every error of the program with its code and message, ordered by code
+pub fn error_registry() -> Vec<NCNProgramErrorInfo> {
+    NCNProgramError::all()
+        .into_iter()
+        .map(NCNProgramErrorInfo::from)
+        .collect()
+}
+
+/// Describes a custom program error code, falling back to the bare code for codes not returned by
+/// the program
+pub fn describe_error_code(code: u32) -> String {
+    NCNProgramError::from_u32(code).map_or_else(
+        || format!("Unknown error ({:#x})", code),
+        NCNProgramError::describe,
+    )
 }
 
 impl<T> DecodeError<T> for NCNProgramError {
@@ -252,6 +419,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_already_processed() {
+        assert!(NCNProgramError::AccountAlreadyInitialized.is_already_processed());
+        assert!(NCNProgramError::OperatorAlreadyVoted.is_already_processed());
+        assert!(NCNProgramError::CannotCloseAccountAlreadyClosed.is_already_processed());
+
+        assert!(!NCNProgramError::WeightTableNotFinalized.is_already_processed());
+        assert!(!NCNProgramError::ConsensusAlreadyReached.is_already_processed());
+    }
+
     #[test]
     fn test_program_error_conversion() {
         // Test conversion to ProgramError
@@ -289,6 +466,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_u32() {
+        assert_eq!(
+            NCNProgramError::from_u32(0),
+            Some(NCNProgramError::NoValidBallots)
+        );
+        assert_eq!(
+            NCNProgramError::from_u32(0x2100),
+            Some(NCNProgramError::DenominatorIsZero)
+        );
+        assert_eq!(
+            NCNProgramError::from_u32(NCNProgramError::OperatorRewardListFull.code()),
+            Some(NCNProgramError::OperatorRewardListFull)
+        );
+        assert_eq!(NCNProgramError::from_u32(1), None);
+        assert_eq!(NCNProgramError::from_u32(0x2300), None);
+    }
+
+    #[test]
+    fn test_error_registry() {
+        let registry = error_registry();
+
+        // Every error is listed once, ordered by code
+        assert!(registry.windows(2).all(|pair| pair[0].code < pair[1].code));
+        assert_eq!(registry.first().unwrap().name, "NoValidBallots");
+        assert!(registry
+            .iter()
+            .any(|info| info.code == NCNProgramError::InvalidFeeGroupWallet.code()));
+
+        for info in registry.iter() {
+            let error = NCNProgramError::from_u32(info.code).unwrap();
+            assert_eq!(info.name, error.name());
+            assert_eq!(info.message, error.to_string());
+        }
+
+        assert_eq!(
+            NCNProgramError::OperatorRewardListFull.describe(),
+            "OperatorRewardListFull (0x2235): Operator reward list full"
+        );
+        assert_eq!(describe_error_code(0x2300), "Unknown error (0x2300)");
+    }
+
     #[test]
     fn test_decode_error_type() {
         assert_eq!(
@@ -0,0 +1,576 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::PodU64;
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::{
+    constants::MAX_FEE_GROUPS,
+    distribution_receipt::DistributionReceipt,
+    error::NCNProgramError,
+    ncn_reward_router::{NCNMintRewards, NCNRewardRouter},
+    stake_weight::StakeWeights,
+};
+
+/// A structured event, logged as `Program data: <prefix> <event>` by the instruction that caused
+/// it so indexers can rebuild the history of an NCN without diffing accounts
+pub trait Event: Pod {
+    /// Leading log field identifying the event among other program data logs
+    const LOG_PREFIX: &'static [u8];
+
+    const SIZE: usize = std::mem::size_of::<Self>();
+
+    fn log(&self) {
+        sol_log_data(&[Self::LOG_PREFIX, bytemuck::bytes_of(self)]);
+    }
+
+    /// Parses the base64 decoded fields of a `Program data:` log, returns `None` for any other data
+    fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        let [prefix, event] = fields else {
+            return None;
+        };
+
+        if prefix.ne(&Self::LOG_PREFIX) || event.len() != Self::SIZE {
+            return None;
+        }
+
+        Some(bytemuck::pod_read_unaligned(event))
+    }
+}
+
+/// Every payout is logged as a `DistributionReceipt`
+pub type RewardsDistributed = DistributionReceipt;
+
+impl Event for DistributionReceipt {
+    const LOG_PREFIX: &'static [u8] = DistributionReceipt::LOG_PREFIX;
+}
+
+/// An operator cast or changed its vote
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct VoteCast {
+    /// The NCN voted in
+    ncn: Pubkey,
+    /// The epoch voted for
+    epoch: PodU64,
+    /// The operator that voted
+    operator: Pubkey,
+    /// The stake weights of the operator
+    stake_weights: StakeWeights,
+    /// The slot of the vote
+    slot: PodU64,
+    /// The weather status voted for
+    weather_status: u8,
+}
+
+impl Event for VoteCast {
+    const LOG_PREFIX: &'static [u8] = b"vote_cast";
+}
+
+impl VoteCast {
+    pub fn new(
+        ncn: &Pubkey,
+        epoch: u64,
+        operator: &Pubkey,
+        weather_status: u8,
+        stake_weights: &StakeWeights,
+        slot: u64,
+    ) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            operator: *operator,
+            stake_weights: *stake_weights,
+            slot: PodU64::from(slot),
+            weather_status,
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub const fn weather_status(&self) -> u8 {
+        self.weather_status
+    }
+
+    pub const fn stake_weights(&self) -> &StakeWeights {
+        &self.stake_weights
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+}
+
+/// Consensus was reached for an epoch, either by vote or by the tie breaker admin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct ConsensusReached {
+    /// The NCN consensus was reached in
+    ncn: Pubkey,
+    /// The epoch consensus was reached for
+    epoch: PodU64,
+    /// The stake weights of the winning ballot
+    winning_stake_weights: StakeWeights,
+    /// The total stake weights of the epoch, zero when set by the tie breaker admin
+    total_stake_weights: StakeWeights,
+    /// The slot consensus was reached in
+    slot: PodU64,
+    /// The winning weather status
+    weather_status: u8,
+    /// Whether the tie breaker admin set the winning ballot
+    tie_breaker: u8,
+}
+
+impl Event for ConsensusReached {
+    const LOG_PREFIX: &'static [u8] = b"consensus_reached";
+}
+
+impl ConsensusReached {
+    pub fn new(
+        ncn: &Pubkey,
+        epoch: u64,
+        weather_status: u8,
+        winning_stake_weights: &StakeWeights,
+        total_stake_weights: &StakeWeights,
+        tie_breaker: bool,
+        slot: u64,
+    ) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            winning_stake_weights: *winning_stake_weights,
+            total_stake_weights: *total_stake_weights,
+            slot: PodU64::from(slot),
+            weather_status,
+            tie_breaker: tie_breaker as u8,
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn weather_status(&self) -> u8 {
+        self.weather_status
+    }
+
+    pub const fn winning_stake_weights(&self) -> &StakeWeights {
+        &self.winning_stake_weights
+    }
+
+    pub const fn total_stake_weights(&self) -> &StakeWeights {
+        &self.total_stake_weights
+    }
+
+    pub fn tie_breaker(&self) -> bool {
+        self.tie_breaker != 0
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+}
+
+/// Where the rewards of an NCN reward router stand after a routing call, the amounts are the
+/// running totals of the router not yet distributed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct RewardsRouted {
+    /// The NCN the rewards belong to
+    ncn: Pubkey,
+    /// The epoch the rewards are routed in
+    epoch: PodU64,
+    /// The reward mint, `Pubkey::default()` for SOL
+    mint: Pubkey,
+    /// All rewards the router has processed
+    total_rewards: PodU64,
+    /// Rewards owed to the protocol
+    protocol_rewards: PodU64,
+    /// Rewards owed to the NCN
+    ncn_rewards: PodU64,
+    /// Rewards owed to each fee group
+    fee_group_rewards: [PodU64; MAX_FEE_GROUPS],
+    /// Rewards still to be split between operator vault reward routes
+    operator_vault_rewards: PodU64,
+    /// The slot of the routing call
+    slot: PodU64,
+    /// Whether routing has to be resumed by another call
+    still_routing: u8,
+}
+
+impl Event for RewardsRouted {
+    const LOG_PREFIX: &'static [u8] = b"rewards_routed";
+}
+
+impl RewardsRouted {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ncn: &Pubkey,
+        epoch: u64,
+        mint: Option<&Pubkey>,
+        total_rewards: u64,
+        protocol_rewards: u64,
+        ncn_rewards: u64,
+        fee_group_rewards: [u64; MAX_FEE_GROUPS],
+        operator_vault_rewards: u64,
+        still_routing: bool,
+        slot: u64,
+    ) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            mint: mint.copied().unwrap_or_default(),
+            total_rewards: PodU64::from(total_rewards),
+            protocol_rewards: PodU64::from(protocol_rewards),
+            ncn_rewards: PodU64::from(ncn_rewards),
+            fee_group_rewards: fee_group_rewards.map(PodU64::from),
+            operator_vault_rewards: PodU64::from(operator_vault_rewards),
+            slot: PodU64::from(slot),
+            still_routing: still_routing as u8,
+        }
+    }
+
+    /// The SOL rewards of the router
+    pub fn from_router(router: &NCNRewardRouter, slot: u64) -> Result<Self, NCNProgramError> {
+        Self::from_rewards(router, router.sol_rewards(), slot)
+    }
+
+    /// The rewards of the router for a reward mint
+    pub fn from_mint_router(
+        router: &NCNRewardRouter,
+        mint: &Pubkey,
+        slot: u64,
+    ) -> Result<Self, NCNProgramError> {
+        Self::from_rewards(router, router.get_mint_rewards(mint)?, slot)
+    }
+
+    /// The rewards of one entry of the router, SOL being the entry without a mint
+    fn from_rewards(
+        router: &NCNRewardRouter,
+        rewards: &NCNMintRewards,
+        slot: u64,
+    ) -> Result<Self, NCNProgramError> {
+        let mut fee_group_rewards = [0; MAX_FEE_GROUPS];
+        for (group, group_rewards) in fee_group_rewards.iter_mut().enumerate() {
+            *group_rewards = rewards.fee_group_rewards(group)?;
+        }
+
+        let mint = (!rewards.is_empty()).then_some(rewards.mint());
+
+        Ok(Self::new(
+            router.ncn(),
+            router.epoch(),
+            mint,
+            rewards.total_rewards(),
+            rewards.protocol_rewards(),
+            rewards.ncn_rewards(),
+            fee_group_rewards,
+            rewards.operator_vault_rewards(),
+            rewards.still_routing(),
+            slot,
+        ))
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    /// `None` for SOL rewards
+    pub fn mint(&self) -> Option<Pubkey> {
+        if self.mint.eq(&Pubkey::default()) {
+            None
+        } else {
+            Some(self.mint)
+        }
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn protocol_rewards(&self) -> u64 {
+        self.protocol_rewards.into()
+    }
+
+    pub fn ncn_rewards(&self) -> u64 {
+        self.ncn_rewards.into()
+    }
+
+    pub fn fee_group_rewards(&self) -> [u64; MAX_FEE_GROUPS] {
+        self.fee_group_rewards.map(u64::from)
+    }
+
+    pub fn operator_vault_rewards(&self) -> u64 {
+        self.operator_vault_rewards.into()
+    }
+
+    pub fn still_routing(&self) -> bool {
+        self.still_routing != 0
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+}
+
+/// The epoch state of an epoch was closed, once every other epoch account was closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct EpochClosed {
+    /// The NCN the epoch belongs to
+    ncn: Pubkey,
+    /// The closed epoch
+    epoch: PodU64,
+    /// The slot the epoch was closed in
+    slot: PodU64,
+}
+
+impl Event for EpochClosed {
+    const LOG_PREFIX: &'static [u8] = b"epoch_closed";
+}
+
+impl EpochClosed {
+    pub fn new(ncn: &Pubkey, epoch: u64, slot: u64) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            slot: PodU64::from(slot),
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+}
+
+/// Any event logged by the program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NCNProgramEvent {
+    VoteCast(VoteCast),
+    ConsensusReached(ConsensusReached),
+    RewardsRouted(RewardsRouted),
+    RewardsDistributed(RewardsDistributed),
+    EpochClosed(EpochClosed),
+}
+
+impl NCNProgramEvent {
+    /// Parses the base64 decoded fields of a `Program data:` log into whichever event it holds,
+    /// returns `None` for any other data
+    pub fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        if let Some(event) = <VoteCast as Event>::from_log_data(fields) {
+            return Some(Self::VoteCast(event));
+        }
+        if let Some(event) = <ConsensusReached as Event>::from_log_data(fields) {
+            return Some(Self::ConsensusReached(event));
+        }
+        if let Some(event) = <RewardsRouted as Event>::from_log_data(fields) {
+            return Some(Self::RewardsRouted(event));
+        }
+        if let Some(event) = <RewardsDistributed as Event>::from_log_data(fields) {
+            return Some(Self::RewardsDistributed(event));
+        }
+        if let Some(event) = <EpochClosed as Event>::from_log_data(fields) {
+            return Some(Self::EpochClosed(event));
+        }
+
+        None
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        match self {
+            Self::VoteCast(event) => event.ncn(),
+            Self::ConsensusReached(event) => event.ncn(),
+            Self::RewardsRouted(event) => event.ncn(),
+            Self::RewardsDistributed(event) => event.ncn(),
+            Self::EpochClosed(event) => event.ncn(),
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        match self {
+            Self::VoteCast(event) => event.epoch(),
+            Self::ConsensusReached(event) => event.epoch(),
+            Self::RewardsRouted(event) => event.epoch(),
+            Self::RewardsDistributed(event) => event.epoch(),
+            Self::EpochClosed(event) => event.epoch(),
+        }
+    }
+
+    pub fn slot(&self) -> u64 {
+        match self {
+            Self::VoteCast(event) => event.slot(),
+            Self::ConsensusReached(event) => event.slot(),
+            Self::RewardsRouted(event) => event.slot(),
+            Self::RewardsDistributed(event) => event.slot(),
+            Self::EpochClosed(event) => event.slot(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use super::*;
+    use crate::distribution_receipt::DistributionKind;
+
+    fn log_fields<T: Event>(event: &T) -> [&[u8]; 2] {
+        [T::LOG_PREFIX, bytemuck::bytes_of(event)]
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(
+            VoteCast::SIZE,
+            size_of::<Pubkey>() // ncn
+                + size_of::<PodU64>() // epoch
+                + size_of::<Pubkey>() // operator
+                + size_of::<StakeWeights>() // stake_weights
+                + size_of::<PodU64>() // slot
+                + 1 // weather_status
+        );
+        assert_eq!(
+            ConsensusReached::SIZE,
+            size_of::<Pubkey>() // ncn
+                + size_of::<PodU64>() // epoch
+                + size_of::<StakeWeights>() * 2 // winning and total stake weights
+                + size_of::<PodU64>() // slot
+                + 1 // weather_status
+                + 1 // tie_breaker
+        );
+        assert_eq!(
+            RewardsRouted::SIZE,
+            size_of::<Pubkey>() // ncn
+                + size_of::<PodU64>() // epoch
+                + size_of::<Pubkey>() // mint
+                + size_of::<PodU64>() * 3 // total, protocol and ncn rewards
+                + size_of::<PodU64>() * MAX_FEE_GROUPS // fee_group_rewards
+                + size_of::<PodU64>() * 2 // operator_vault_rewards and slot
+                + 1 // still_routing
+        );
+        assert_eq!(
+            EpochClosed::SIZE,
+            size_of::<Pubkey>() // ncn
+                + size_of::<PodU64>() // epoch
+                + size_of::<PodU64>() // slot
+        );
+        assert_eq!(
+            <RewardsDistributed as Event>::SIZE,
+            DistributionReceipt::SIZE
+        );
+    }
+
+    #[test]
+    fn test_log_data_round_trip() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let vote_cast = VoteCast::new(&ncn, 5, &operator, 1, &StakeWeights::new(100), 42);
+        let parsed = <VoteCast as Event>::from_log_data(&log_fields(&vote_cast)).unwrap();
+        assert_eq!(parsed, vote_cast);
+        assert_eq!(parsed.operator(), &operator);
+        assert_eq!(parsed.weather_status(), 1);
+        assert_eq!(parsed.stake_weights().stake_weight(), 100);
+
+        let consensus_reached = ConsensusReached::new(
+            &ncn,
+            5,
+            1,
+            &StakeWeights::new(70),
+            &StakeWeights::new(100),
+            true,
+            43,
+        );
+        let parsed =
+            <ConsensusReached as Event>::from_log_data(&log_fields(&consensus_reached)).unwrap();
+        assert_eq!(parsed, consensus_reached);
+        assert_eq!(parsed.winning_stake_weights().stake_weight(), 70);
+        assert_eq!(parsed.total_stake_weights().stake_weight(), 100);
+        assert!(parsed.tie_breaker());
+
+        let rewards_routed = RewardsRouted::new(
+            &ncn,
+            5,
+            Some(&mint),
+            1_000,
+            40,
+            30,
+            [10, 0, 0, 0],
+            920,
+            false,
+            44,
+        );
+        let parsed = <RewardsRouted as Event>::from_log_data(&log_fields(&rewards_routed)).unwrap();
+        assert_eq!(parsed, rewards_routed);
+        assert_eq!(parsed.mint(), Some(mint));
+        assert_eq!(parsed.total_rewards(), 1_000);
+        assert_eq!(parsed.fee_group_rewards(), [10, 0, 0, 0]);
+        assert_eq!(parsed.operator_vault_rewards(), 920);
+        assert!(!parsed.still_routing());
+
+        let epoch_closed = EpochClosed::new(&ncn, 5, 45);
+        let parsed = <EpochClosed as Event>::from_log_data(&log_fields(&epoch_closed)).unwrap();
+        assert_eq!(parsed, epoch_closed);
+        assert_eq!(parsed.slot(), 45);
+    }
+
+    #[test]
+    fn test_program_event_from_log_data() {
+        let ncn = Pubkey::new_unique();
+
+        let epoch_closed = EpochClosed::new(&ncn, 5, 45);
+        assert_eq!(
+            NCNProgramEvent::from_log_data(&log_fields(&epoch_closed)),
+            Some(NCNProgramEvent::EpochClosed(epoch_closed))
+        );
+
+        let receipt = DistributionReceipt::new(
+            DistributionKind::Ncn,
+            &ncn,
+            5,
+            &Pubkey::new_unique(),
+            None,
+            10,
+            46,
+        );
+        let event = NCNProgramEvent::from_log_data(&log_fields(&receipt)).unwrap();
+        assert_eq!(event, NCNProgramEvent::RewardsDistributed(receipt));
+        assert_eq!(event.ncn(), &ncn);
+        assert_eq!(event.epoch(), 5);
+        assert_eq!(event.slot(), 46);
+
+        // Events logged under another prefix are not mistaken for each other
+        let vote_cast = VoteCast::new(&ncn, 5, &ncn, 0, &StakeWeights::new(1), 42);
+        assert!(NCNProgramEvent::from_log_data(&[
+            EpochClosed::LOG_PREFIX,
+            bytemuck::bytes_of(&vote_cast)
+        ])
+        .is_none());
+        assert!(NCNProgramEvent::from_log_data(&[b"other".as_slice()]).is_none());
+    }
+}
@@ -4,7 +4,11 @@ use shank::ShankType;
 use solana_program::pubkey::Pubkey;
 use spl_math::precise_number::PreciseNumber;
 
-use crate::{constants::MAX_FEE_BPS, error::NCNProgramError};
+use crate::{
+    constants::{MAX_FEE_BPS, MAX_FEE_GROUPS},
+    error::NCNProgramError,
+    utils::{is_valid_padded_str, padded_str},
+};
 
 /// Fee Configuration with Epoch-Delayed Updates
 ///
@@ -15,6 +19,10 @@ use crate::{constants::MAX_FEE_BPS, error::NCNProgramError};
 /// The dual fee structure (fee_1 and fee_2) allows one fee to be active while the other
 /// is being prepared for a future epoch. On epoch boundaries, the system switches to
 /// the fee with the higher activation epoch.
+///
+/// Next to the Protocol and NCN fees, up to `MAX_FEE_GROUPS` custom fee groups can be set up by
+/// the NCN admin, e.g. for an insurance fund. Like the built-in fees, a group's wallet takes
+/// effect immediately while its fee follows the epoch-delayed updates.
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
 #[repr(C)]
 pub struct FeeConfig {
@@ -28,6 +36,9 @@ pub struct FeeConfig {
     fee_1: Fees,
     /// Secondary fee configuration (used for active or future epoch)
     fee_2: Fees,
+
+    /// Custom fee groups, the fee of each group is kept in `Fees`
+    fee_groups: [FeeGroup; MAX_FEE_GROUPS],
 }
 
 impl FeeConfig {
@@ -57,6 +68,8 @@ impl FeeConfig {
 
             fee_1: fee,
             fee_2: fee,
+
+            fee_groups: [FeeGroup::default(); MAX_FEE_GROUPS],
         };
 
         fee_config.check_fees_okay(current_epoch)?;
@@ -177,6 +190,41 @@ impl FeeConfig {
         updateable_fees.set_ncn_fee_bps(value)
     }
 
+    // ------------------- FEE GROUPS -------------------
+
+    /// Gets the fee of a custom fee group in basis points for the current epoch
+    pub fn fee_group_bps(&self, group: usize, current_epoch: u64) -> Result<u16, NCNProgramError> {
+        let current_fees = self.current_fees(current_epoch);
+        current_fees.fee_group_bps(group)
+    }
+
+    /// Sets the fee of a custom fee group for the next epoch
+    pub fn set_fee_group_bps(
+        &mut self,
+        group: usize,
+        value: u16,
+        current_epoch: u64,
+    ) -> Result<(), NCNProgramError> {
+        let updateable_fees = self.updatable_fees(current_epoch);
+        updateable_fees.set_fee_group_bps(group, value)
+    }
+
+    pub const fn fee_groups(&self) -> &[FeeGroup; MAX_FEE_GROUPS] {
+        &self.fee_groups
+    }
+
+    /// Gets a custom fee group
+    pub fn fee_group(&self, group: usize) -> Result<&FeeGroup, NCNProgramError> {
+        self.fee_groups
+            .get(group)
+            .ok_or(NCNProgramError::InvalidFeeGroup)
+    }
+
+    /// Gets the wallet of a custom fee group
+    pub fn fee_group_wallet(&self, group: usize) -> Result<&Pubkey, NCNProgramError> {
+        Ok(self.fee_group(group)?.wallet())
+    }
+
     // ------------------- WALLETS -------------------
 
     /// Gets the NCN fee wallet address
@@ -266,6 +314,55 @@ impl FeeConfig {
         Ok(())
     }
 
+    /// Updates a custom fee group
+    ///
+    /// The name and wallet are set immediately, the fee takes effect in the next epoch the
+    /// same way as in `update_fee_config`. A group is turned off by setting its fee to zero, its
+    /// wallet can not be cleared as rewards may still be routed to it in the current epoch.
+    pub fn update_fee_group(
+        &mut self,
+        group: usize,
+        name: &[u8; 32],
+        wallet: &Pubkey,
+        new_fee_bps: Option<u16>,
+        current_epoch: u64,
+    ) -> Result<(), NCNProgramError> {
+        if group >= MAX_FEE_GROUPS {
+            return Err(NCNProgramError::InvalidFeeGroup);
+        }
+
+        if wallet.eq(&Pubkey::default()) {
+            return Err(NCNProgramError::DefaultFeeGroupWallet);
+        }
+
+        // Copy current fees to updatable configuration if starting fresh
+        {
+            let updatable_fees = self.updatable_fees(current_epoch);
+            if updatable_fees.activation_epoch() <= current_epoch {
+                self.set_fees_to_current(current_epoch)?;
+            }
+        }
+
+        self.fee_groups[group].set(name, wallet)?;
+
+        if let Some(new_fee_bps) = new_fee_bps {
+            self.set_fee_group_bps(group, new_fee_bps, current_epoch)?;
+        }
+
+        // Set activation epoch to next epoch
+        self.update_updatable_epoch(current_epoch)?;
+
+        // Validate fee configurations for current and next epoch
+        self.check_fees_okay(current_epoch)?;
+        self.check_fees_okay(
+            current_epoch
+                .checked_add(1)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        )?;
+
+        Ok(())
+    }
+
     // ------ HELPERS -----------------
 
     /// Validates that fee configuration is acceptable
@@ -301,6 +398,8 @@ pub struct Fees {
     protocol_fee_bps: Fee,
     /// NCN fee in basis points  
     ncn_fee_bps: Fee,
+    /// Fee of each custom fee group in basis points
+    fee_group_bps: [Fee; MAX_FEE_GROUPS],
 }
 
 impl Fees {
@@ -313,6 +412,7 @@ impl Fees {
             activation_epoch: PodU64::from(epoch),
             protocol_fee_bps: Fee::default(),
             ncn_fee_bps: Fee::default(),
+            fee_group_bps: [Fee::default(); MAX_FEE_GROUPS],
         };
         fees.protocol_fee_bps = Fee::new(Self::PROTOCOL_FEE_BPS);
 
@@ -352,6 +452,14 @@ impl Fees {
         PreciseNumber::new(fee.into()).ok_or(NCNProgramError::NewPreciseNumberError)
     }
 
+    /// Gets the fee of a custom fee group in basis points
+    pub fn fee_group_bps(&self, group: usize) -> Result<u16, NCNProgramError> {
+        self.fee_group_bps
+            .get(group)
+            .map(Fee::fee)
+            .ok_or(NCNProgramError::InvalidFeeGroup)
+    }
+
     /// Calculates the total fees in basis points (sum of all individual fees)
     pub fn total_fees_bps(&self) -> Result<u64, NCNProgramError> {
         let mut total_fee_bps: u64 = 0;
@@ -367,6 +475,12 @@ impl Fees {
             .checked_add(ncn_fee_bps as u64)
             .ok_or(NCNProgramError::ArithmeticOverflow)?;
 
+        for fee_group_bps in self.fee_group_bps.iter() {
+            total_fee_bps = total_fee_bps
+                .checked_add(fee_group_bps.fee() as u64)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        }
+
         Ok(total_fee_bps)
     }
 
@@ -393,6 +507,77 @@ impl Fees {
 
         Ok(())
     }
+
+    /// Sets the fee of a custom fee group with validation
+    pub fn set_fee_group_bps(&mut self, group: usize, value: u16) -> Result<(), NCNProgramError> {
+        if value as u64 > MAX_FEE_BPS {
+            return Err(NCNProgramError::FeeCapExceeded);
+        }
+
+        let fee = self
+            .fee_group_bps
+            .get_mut(group)
+            .ok_or(NCNProgramError::InvalidFeeGroup)?;
+        *fee = Fee::new(value);
+
+        Ok(())
+    }
+}
+
+/// Custom Fee Group
+///
+/// A named recipient of a share of the rewards next to the Protocol and the NCN. Its fee is kept
+/// per epoch in `Fees`, a group with a default wallet has never been set up.
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct FeeGroup {
+    /// UTF-8 display name, padded with zeros
+    name: [u8; 32],
+    /// Wallet that receives the fees of the group
+    wallet: Pubkey,
+}
+
+impl Default for FeeGroup {
+    fn default() -> Self {
+        Self {
+            name: [0; Self::NAME_LEN],
+            wallet: Pubkey::default(),
+        }
+    }
+}
+
+impl FeeGroup {
+    pub const NAME_LEN: usize = 32;
+
+    pub const fn name(&self) -> &[u8; 32] {
+        &self.name
+    }
+
+    /// The name without its zero padding, empty if unset
+    pub fn name_str(&self) -> &str {
+        padded_str(&self.name)
+    }
+
+    pub const fn wallet(&self) -> &Pubkey {
+        &self.wallet
+    }
+
+    /// Checks if the group has never been set up
+    pub fn is_empty(&self) -> bool {
+        self.wallet.eq(&Pubkey::default())
+    }
+
+    /// Sets the name and wallet, the name has to be UTF-8 padded with zeros
+    fn set(&mut self, name: &[u8; 32], wallet: &Pubkey) -> Result<(), NCNProgramError> {
+        if !is_valid_padded_str(name) {
+            return Err(NCNProgramError::InvalidFeeGroupName);
+        }
+
+        self.name = *name;
+        self.wallet = *wallet;
+
+        Ok(())
+    }
 }
 
 /// Individual Fee Value Wrapper
@@ -788,4 +973,85 @@ mod tests {
 
         assert!(precise_total.eq(&expected));
     }
+
+    /// Tests that custom fee groups follow the epoch-delayed updates and count towards the total
+    #[test]
+    fn test_update_fee_group() {
+        const DEFAULT_NCN_FEE: u16 = 300;
+        const FEE_GROUP_FEE: u16 = 200;
+        const STARTING_EPOCH: u64 = 10;
+
+        let mut fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), DEFAULT_NCN_FEE, STARTING_EPOCH).unwrap();
+        assert!(fee_config.fee_groups().iter().all(FeeGroup::is_empty));
+
+        let mut name = [0; FeeGroup::NAME_LEN];
+        name[..9].copy_from_slice(b"Insurance");
+        let wallet = Pubkey::new_unique();
+
+        fee_config
+            .update_fee_group(1, &name, &wallet, Some(FEE_GROUP_FEE), STARTING_EPOCH)
+            .unwrap();
+
+        // The name and wallet update immediately
+        let fee_group = fee_config.fee_group(1).unwrap();
+        assert_eq!(fee_group.name_str(), "Insurance");
+        assert_eq!(*fee_config.fee_group_wallet(1).unwrap(), wallet);
+
+        // The fee updates on the next epoch
+        assert_eq!(fee_config.fee_group_bps(1, STARTING_EPOCH).unwrap(), 0);
+        assert_eq!(
+            fee_config.fee_group_bps(1, STARTING_EPOCH + 1).unwrap(),
+            FEE_GROUP_FEE
+        );
+        assert_eq!(
+            fee_config.total_fees_bps(STARTING_EPOCH + 1).unwrap(),
+            (Fees::PROTOCOL_FEE_BPS + DEFAULT_NCN_FEE + FEE_GROUP_FEE) as u64
+        );
+
+        // Other groups are untouched
+        assert!(fee_config.fee_group(0).unwrap().is_empty());
+        assert_eq!(fee_config.fee_group_bps(0, STARTING_EPOCH + 1).unwrap(), 0);
+    }
+
+    /// Tests the validation of custom fee group updates
+    #[test]
+    fn test_update_fee_group_errors() {
+        const STARTING_EPOCH: u64 = 10;
+
+        let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 300, STARTING_EPOCH).unwrap();
+        let name = [0; FeeGroup::NAME_LEN];
+        let wallet = Pubkey::new_unique();
+
+        assert_eq!(
+            fee_config.update_fee_group(MAX_FEE_GROUPS, &name, &wallet, None, STARTING_EPOCH),
+            Err(NCNProgramError::InvalidFeeGroup)
+        );
+        assert_eq!(
+            fee_config.fee_group_bps(MAX_FEE_GROUPS, STARTING_EPOCH),
+            Err(NCNProgramError::InvalidFeeGroup)
+        );
+
+        assert_eq!(
+            fee_config.update_fee_group(0, &name, &Pubkey::default(), None, STARTING_EPOCH),
+            Err(NCNProgramError::DefaultFeeGroupWallet)
+        );
+
+        let mut bad_name = name;
+        bad_name[0] = 0xff;
+        assert_eq!(
+            fee_config.update_fee_group(0, &bad_name, &wallet, None, STARTING_EPOCH),
+            Err(NCNProgramError::InvalidFeeGroupName)
+        );
+
+        // The total of all fees is capped
+        let error = fee_config.update_fee_group(
+            0,
+            &name,
+            &wallet,
+            Some(MAX_FEE_BPS as u16 - Fees::PROTOCOL_FEE_BPS - 300 + 1),
+            STARTING_EPOCH,
+        );
+        assert_eq!(error, Err(NCNProgramError::FeeCapExceeded));
+    }
 }
@@ -0,0 +1,153 @@
+//! Validation of spl-governance accounts, so the admin role of a config can be handed to a DAO.
+//!
+//! A governance signs the instructions of a proposal when the proposal is executed, as the PDA of
+//! the governance program it is. Admin instructions then accept it as the `ncn_admin` signer once
+//! it is owned by the governance program recorded in the config and belongs to its realm.
+//!
+//! Only the account header is read, spl-governance is not a dependency: every governance account
+//! type starts with its account type followed by its realm.
+
+use solana_program::pubkey::Pubkey;
+#[cfg(feature = "program")]
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError};
+
+use crate::error::NCNProgramError;
+
+/// `GovernanceAccountType` values of the governance accounts that can sign proposals, the
+/// governance, program, mint and token governance V2 variants
+pub const GOVERNANCE_ACCOUNT_TYPES: [u8; 4] = [18, 19, 20, 21];
+
+const REALM_OFFSET: usize = 1;
+
+/// Reads the realm of a governance account, `None` if the data is not a governance account
+pub fn governance_realm(data: &[u8]) -> Option<Pubkey> {
+    let account_type = *data.first()?;
+    if !GOVERNANCE_ACCOUNT_TYPES.contains(&account_type) {
+        return None;
+    }
+
+    let realm = data.get(REALM_OFFSET..REALM_OFFSET + 32)?;
+    Some(Pubkey::new_from_array(realm.try_into().ok()?))
+}
+
+/// Checks that the data is a governance account of `realm`
+pub fn check_governance(data: &[u8], realm: &Pubkey) -> Result<(), NCNProgramError> {
+    match governance_realm(data) {
+        Some(governance_realm) if governance_realm.eq(realm) => Ok(()),
+        _ => Err(NCNProgramError::NotAdminGovernance),
+    }
+}
+
+/// Checks that the account is a signing governance of `realm`, owned by the governance program
+#[cfg(feature = "program")]
+pub fn load_governance(
+    governance_program_id: &Pubkey,
+    governance: &AccountInfo,
+    realm: &Pubkey,
+) -> Result<(), ProgramError> {
+    if !governance.is_signer {
+        msg!("Governance did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if governance.owner.ne(governance_program_id) {
+        msg!("Governance is not owned by the governance program of the config");
+        return Err(NCNProgramError::NotAdminGovernance.into());
+    }
+
+    if let Err(error) = check_governance(&governance.data.borrow(), realm) {
+        msg!("Governance does not belong to the realm of the config");
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governance_data(account_type: u8, realm: &Pubkey) -> Vec<u8> {
+        let mut data = vec![account_type];
+        data.extend_from_slice(realm.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data
+    }
+
+    #[test]
+    fn test_governance_realm() {
+        let realm = Pubkey::new_unique();
+
+        for account_type in GOVERNANCE_ACCOUNT_TYPES {
+            assert_eq!(
+                governance_realm(&governance_data(account_type, &realm)),
+                Some(realm)
+            );
+        }
+
+        // Realms, proposals and other governance program accounts
+        assert_eq!(governance_realm(&governance_data(16, &realm)), None);
+        assert_eq!(governance_realm(&[]), None);
+        assert_eq!(governance_realm(&[18, 1, 2]), None);
+    }
+
+    #[test]
+    fn test_check_governance() {
+        let realm = Pubkey::new_unique();
+        let data = governance_data(18, &realm);
+
+        check_governance(&data, &realm).unwrap();
+        assert_eq!(
+            check_governance(&data, &Pubkey::new_unique()),
+            Err(NCNProgramError::NotAdminGovernance)
+        );
+    }
+
+    #[cfg(feature = "program")]
+    #[test]
+    fn test_load_governance() {
+        let governance_program_id = Pubkey::new_unique();
+        let realm = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = governance_data(18, &realm);
+
+        let governance = AccountInfo::new(
+            &key,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &governance_program_id,
+            false,
+            0,
+        );
+        load_governance(&governance_program_id, &governance, &realm).unwrap();
+
+        assert_eq!(
+            load_governance(&Pubkey::new_unique(), &governance, &realm),
+            Err(NCNProgramError::NotAdminGovernance.into())
+        );
+        assert_eq!(
+            load_governance(&governance_program_id, &governance, &Pubkey::new_unique()),
+            Err(NCNProgramError::NotAdminGovernance.into())
+        );
+
+        let mut lamports = 0;
+        let mut data = governance_data(18, &realm);
+        let unsigned_governance = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &governance_program_id,
+            false,
+            0,
+        );
+        assert_eq!(
+            load_governance(&governance_program_id, &unsigned_governance, &realm),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+}
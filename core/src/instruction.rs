@@ -2,7 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
 use solana_program::pubkey::Pubkey;
 
-use crate::config::ConfigAdminRole;
+use crate::{config::ConfigAdminRole, weight_table::StMintWeight};
 
 /// Represents all instructions supported by the NCN Program
 /// Each instruction specifies the accounts it requires and any parameters
@@ -61,6 +61,8 @@ pub enum NCNProgramInstruction {
     #[account(2, name = "ncn")]
     #[account(3, name = "vault")]
     #[account(4, name = "ncn_vault_ticket")]
+    #[account(5, name = "restaking_config")]
+    #[account(6, name = "st_mint")]
     RegisterVault,
 
     // ---------------------------------------------------- //
@@ -77,6 +79,8 @@ pub enum NCNProgramInstruction {
     InitializeEpochState {
         /// Target epoch for initialization
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
 
@@ -91,6 +95,8 @@ pub enum NCNProgramInstruction {
     InitializeWeightTable{
         /// Target epoch for the weight table
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
 
@@ -99,6 +105,8 @@ pub enum NCNProgramInstruction {
     #[account(1, name = "ncn")]
     #[account(2, name = "vault_registry")]
     #[account(3, writable, name = "weight_table")]
+    #[account(4, name = "config")]
+    #[account(5, name = "restaking_config")]
     SetEpochWeights{
         epoch: u64,
     },
@@ -128,6 +136,8 @@ pub enum NCNProgramInstruction {
     #[account(7, name = "system_program")]
     InitializeEpochSnapshot{
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
     /// Initializes the Operator Snapshot
@@ -144,6 +154,8 @@ pub enum NCNProgramInstruction {
     #[account(10, name = "system_program")]
     InitializeOperatorSnapshot{
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
     
     /// Snapshots the vault operator delegation
@@ -177,6 +189,8 @@ pub enum NCNProgramInstruction {
     #[account(7, writable, name = "consensus_result")]
     InitializeBallotBox {
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
     /// Resizes the ballot box account
@@ -219,6 +233,8 @@ pub enum NCNProgramInstruction {
     #[account(6, name = "system_program")]
     InitializeNCNRewardRouter{
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
     /// Resizes the NCN reward router account
@@ -233,6 +249,8 @@ pub enum NCNProgramInstruction {
     },
 
     /// Routes NCN reward router
+    /// Routes SOL, or the given reward mint when `mint` is set
+    /// The cranker fee is only paid when routing SOL, routing a reward mint pays no fee
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -240,12 +258,17 @@ pub enum NCNProgramInstruction {
     #[account(4, name = "ballot_box")]
     #[account(5, writable, name = "ncn_reward_router")]
     #[account(6, writable, name = "ncn_reward_receiver")]
+    #[account(7, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(8, writable, signer, optional, name = "cranker", desc = "Paid the cranker fee in SOL, routing a reward mint pays no fee")]
+    #[account(9, optional, name = "system_program")]
     RouteNCNRewards{
         max_iterations: u16,
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
     /// Distributes Protocol rewards
+    /// Distributes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -253,11 +276,16 @@ pub enum NCNProgramInstruction {
     #[account(4, writable, name = "ncn_reward_receiver")]
     #[account(5, writable, name = "protocol_fee_wallet")]
     #[account(6, name = "system_program")]
+    #[account(7, optional, name = "token_program")]
+    #[account(8, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(9, writable, optional, name = "protocol_fee_wallet_token_account")]
     DistributeProtocolRewards{
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
     /// Distributes NCN rewards
+    /// Distributes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -265,7 +293,11 @@ pub enum NCNProgramInstruction {
     #[account(4, writable, name = "ncn_reward_receiver")]
     #[account(5, writable, name = "ncn_fee_wallet")]
     #[account(6, name = "system_program")]
+    #[account(7, optional, name = "token_program")]
+    #[account(8, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(9, writable, optional, name = "ncn_fee_wallet_token_account")]
     DistributeNCNRewards{
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
@@ -280,9 +312,12 @@ pub enum NCNProgramInstruction {
     #[account(8, name = "system_program")]
     InitializeOperatorVaultRewardRouter{
         epoch: u64,
+        /// Succeed without changes if the account is already initialized
+        init_if_needed: bool,
     },
 
     /// Distributes base ncn reward routes
+    /// Distributes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -292,19 +327,29 @@ pub enum NCNProgramInstruction {
     #[account(6, name = "operator_vault_reward_router")]
     #[account(7, writable, name = "operator_vault_reward_receiver")]
     #[account(8, name = "system_program")]
+    #[account(9, optional, name = "token_program")]
+    #[account(10, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(11, writable, optional, name = "operator_vault_reward_receiver_token_account")]
     DistributeOperatorVaultRewardRoute{
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
     /// Routes ncn reward router
+    /// Routes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "ncn")]
     #[account(2, name = "operator")]
     #[account(3, name = "operator_snapshot")]
     #[account(4, writable, name = "operator_vault_reward_router")]
     #[account(5, writable, name = "operator_vault_reward_receiver")]
+    #[account(6, optional, name = "config")]
+    #[account(7, optional, name = "operator_vault_reward_receiver_token_account")]
+    #[account(8, writable, signer, optional, name = "cranker")]
+    #[account(9, optional, name = "system_program")]
     RouteOperatorVaultRewards{
         max_iterations: u16,
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
@@ -325,6 +370,7 @@ pub enum NCNProgramInstruction {
     },
 
     /// Distributes ncn operator rewards
+    /// Distributes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -333,11 +379,16 @@ pub enum NCNProgramInstruction {
     #[account(5, writable, name = "operator_vault_reward_router")]
     #[account(6, writable, name = "operator_vault_reward_receiver")]
     #[account(7, name = "system_program")]
+    #[account(8, optional, name = "token_program")]
+    #[account(9, writable, optional, name = "operator_vault_reward_receiver_token_account")]
+    #[account(10, writable, optional, name = "operator_token_account")]
     DistributeOperatorRewards{
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
     /// Distributes vault rewards
+    /// Distributes SOL, or the given reward mint when `mint` is set
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -347,7 +398,13 @@ pub enum NCNProgramInstruction {
     #[account(6, writable, name = "operator_vault_reward_router")]
     #[account(7, writable, name = "operator_vault_reward_receiver")]
     #[account(8, name = "system_program")]
+    #[account(9, name = "vault_registry")]
+    #[account(10, writable, name = "vault_reward_recipient")]
+    #[account(11, name = "token_program")]
+    #[account(12, writable, optional, name = "operator_vault_reward_receiver_token_account")]
+    #[account(13, writable, optional, name = "vault_reward_recipient_token_account")]
     DistributeVaultRewards{
+        mint: Option<Pubkey>,
         epoch: u64,
     },
 
@@ -356,6 +413,7 @@ pub enum NCNProgramInstruction {
     //                        ADMIN                         //
     // ---------------------------------------------------- //
     /// Updates NCN Config parameters
+    /// Followed by the signing admin approvers when the config has an admin multisig
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
@@ -364,10 +422,19 @@ pub enum NCNProgramInstruction {
         epochs_before_stall: Option<u64>,
         epochs_after_consensus_before_close: Option<u64>,
         valid_slots_after_consensus: Option<u64>,
+        cranker_fee_bps: Option<u16>,
+        timeliness_bonus_slots: Option<u64>,
+        timeliness_bonus_bps: Option<u16>,
+        weight_decay_epochs: Option<u64>,
+        weight_decay_bps: Option<u16>,
+        max_vault_stake_weight_bps: Option<u16>,
+        permissionless_vault_registration: Option<bool>,
+        parameters_timelock_slots: Option<u64>,
     },
 
 
     /// Sets a new secondary admin for the NCN
+    /// Followed by the signing admin approvers when the config has an admin multisig
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
@@ -399,6 +466,7 @@ pub enum NCNProgramInstruction {
     },
 
     /// Registers a new ST mint in the Vault Registry
+    /// Followed by the signing admin approvers when the config has an admin multisig
     #[account(0, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, name = "st_mint")]
@@ -409,6 +477,7 @@ pub enum NCNProgramInstruction {
     },
 
     /// Updates an ST mint in the Vault Registry
+    /// Followed by the signing admin approvers when the config has an admin multisig
     #[account(0, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, writable, name = "vault_registry")]
@@ -416,5 +485,291 @@ pub enum NCNProgramInstruction {
     AdminSetStMint{
         st_mint: Pubkey,
         weight: Option<u128>,
+        /// Pass `Pubkey::default()` to remove the feed
+        switchboard_feed: Option<Pubkey>,
+    },
+
+    /// Sets the wallet or wrapped SOL account a vault's rewards are sent to, pass the vault itself to reset
+    /// Reward mint rewards are sent to the token accounts of the recipient, or of the owner of the wrapped SOL account
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "vault")]
+    #[account(4, name = "reward_recipient")]
+    #[account(5, signer, name = "vault_admin")]
+    AdminSetVaultRewardRecipient,
+
+    /// Sets the SPL token mint at `index` that rewards can be routed in, the default pubkey clears it
+    /// Followed by the signing admin approvers when the config has an admin multisig
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    AdminSetRewardMint{
+        index: u8,
+        mint: Pubkey,
+    },
+
+    /// Routes NCN reward router and distributes the Protocol and NCN rewards in one call
+    /// Operator routes are distributed for every (operator, operator_vault_reward_router, operator_vault_reward_receiver) passed as remaining accounts
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "epoch_snapshot")]
+    #[account(4, name = "ballot_box")]
+    #[account(5, writable, name = "ncn_reward_router")]
+    #[account(6, writable, name = "ncn_reward_receiver")]
+    #[account(7, writable, name = "protocol_fee_wallet")]
+    #[account(8, writable, name = "ncn_fee_wallet")]
+    #[account(9, name = "system_program")]
+    RouteAndDistributeNCNRewards{
+        max_iterations: u16,
+        epoch: u64,
+    },
+
+    /// Shrinks the NCN reward router down to its recorded operator routes once all rewards are distributed
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "ncn_reward_router")]
+    #[account(2, writable, name = "account_payer")]
+    ShrinkNCNRewardRouter{
+        epoch: u64,
+    },
+
+    /// Initializes the Operator Snapshots of several operators
+    /// Every operator passes (operator, ncn_operator_state, operator_snapshot) as remaining accounts
+    #[account(0, name = "epoch_marker")]
+    #[account(1, writable, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, name = "restaking_config")]
+    #[account(4, name = "ncn")]
+    #[account(5, writable, name = "epoch_snapshot")]
+    #[account(6, writable, name = "account_payer")]
+    #[account(7, name = "system_program")]
+    BatchInitializeOperatorSnapshot{
+        epoch: u64,
+    },
+
+    /// Initializes the epoch state, weight table, ballot box and NCN reward router of an epoch in one call
+    #[account(0, name = "epoch_marker")]
+    #[account(1, writable, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, name = "vault_registry")]
+    #[account(4, name = "ncn")]
+    #[account(5, writable, name = "weight_table")]
+    #[account(6, writable, name = "ballot_box")]
+    #[account(7, writable, name = "consensus_result")]
+    #[account(8, writable, name = "ncn_reward_router")]
+    #[account(9, writable, name = "ncn_reward_receiver")]
+    #[account(10, writable, name = "account_payer")]
+    #[account(11, name = "system_program")]
+    InitializeEpochAccounts{
+        epoch: u64,
+    },
+
+    /// Closes several epoch accounts, passed as remaining accounts in closing order
+    /// Reward routers are followed by their reward receiver
+    #[account(0, writable, name = "epoch_marker")]
+    #[account(1, writable, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    #[account(6, writable, name = "ncn_fee_wallet")]
+    CloseAllEpochAccounts {
+        epoch: u64,
+    },
+
+    /// Sets the weight of an st mint from its registered switchboard feed
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "vault_registry")]
+    #[account(3, writable, name = "weight_table")]
+    #[account(4, name = "switchboard_feed")]
+    SetWeightFromSwitchboard {
+        st_mint: Pubkey,
+        epoch: u64,
+    },
+
+    /// Sets the weights of the weight table from the previous epoch's weight table
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "weight_table")]
+    #[account(3, name = "previous_weight_table")]
+    CopyPreviousEpochWeights {
+        epoch: u64,
+    },
+
+    /// Sets the weights of several st mints
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "weight_table")]
+    #[account(3, signer, name = "weight_table_admin")]
+    AdminSetWeights {
+        weights: Vec<StMintWeight>,
+        epoch: u64,
+    },
+
+    /// Sets the default weight of the Vault Registry
+    /// Followed by the signing admin approvers when the config has an admin multisig
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, signer, writable, name = "admin")]
+    AdminSetDefaultWeight {
+        default_weight: u128,
+    },
+
+    /// Sets the display name and URI hash of a vault in the Vault Registry
+    /// Followed by the signing admin approvers when the config has an admin multisig
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "vault")]
+    #[account(4, signer, name = "admin")]
+    AdminSetVaultMetadata {
+        name: [u8; 32],
+        uri_hash: [u8; 32],
+    },
+
+    /// Creates or updates the metadata an operator publishes for the NCN
+    #[account(0, name = "ncn")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "ncn_operator_state")]
+    #[account(3, writable, name = "operator_metadata")]
+    #[account(4, signer, name = "operator_admin")]
+    #[account(5, writable, name = "account_payer")]
+    #[account(6, name = "system_program")]
+    SetOperatorMetadata {
+        name: [u8; 32],
+        website: [u8; 64],
+        contact_hash: [u8; 32],
+    },
+
+    /// Sets the approvers and threshold required for admin instructions on the config
+    /// Followed by the signing current admin approvers when the config has an admin multisig
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    AdminSetAdminMultisig {
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    },
+
+    /// Applies the parameter changes queued by AdminSetParameters once the timelock elapsed
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    ApplyParameters,
+
+    /// Marks an epoch that stalled without ever being started as skipped
+    #[account(0, writable, name = "epoch_marker")]
+    #[account(1, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    MarkEpochSkipped {
+        epoch: u64,
+    },
+
+    /// Sets up or updates the custom fee group at `group`, the fee takes effect in the next epoch
+    /// Followed by the signing admin approvers when the config has an admin multisig
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "fee_wallet")]
+    #[account(3, signer, name = "ncn_admin")]
+    AdminSetFeeGroup {
+        group: u8,
+        name: [u8; 32],
+        fee_bps: Option<u16>,
+    },
+
+    /// Distributes the rewards of a custom fee group
+    /// Distributes SOL, or the given reward mint when `mint` is set
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "ncn_reward_router")]
+    #[account(4, writable, name = "ncn_reward_receiver")]
+    #[account(5, writable, name = "fee_group_wallet")]
+    #[account(6, name = "system_program")]
+    #[account(7, optional, name = "token_program")]
+    #[account(8, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(9, writable, optional, name = "fee_group_wallet_token_account")]
+    DistributeFeeGroupRewards {
+        group: u8,
+        mint: Option<Pubkey>,
+        epoch: u64,
+    },
+
+    /// Hands the admin role of the config to the governances of an spl-governance realm, the
+    /// default pubkeys hand it back. Once set, `ncn_admin` of admin instructions is a governance of
+    /// the realm signing through an executed proposal
+    /// Followed by the signing admin approvers when the config has an admin multisig
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    AdminSetAdminGovernance {
+        governance_program: Pubkey,
+        realm: Pubkey,
+    },
+
+    // ---------------------------------------------------- //
+    //                        GETTERS                       //
+    // ---------------------------------------------------- //
+    /// Returns the consensus result of the epoch as a `ConsensusResultView` in the return data
+    #[account(0, name = "ncn")]
+    #[account(1, name = "consensus_result")]
+    GetConsensusResult {
+        epoch: u64,
+    },
+
+    /// Returns the winning ballot of the ballot box as a `WinningBallotView` in the return data
+    #[account(0, name = "ncn")]
+    #[account(1, name = "ballot_box")]
+    GetWinningBallot {
+        epoch: u64,
+    },
+
+    /// Returns the route of the operator in the NCN reward router as an `OperatorRewardRouteView`
+    /// in the return data
+    #[account(0, name = "ncn")]
+    #[account(1, name = "ncn_reward_router")]
+    GetOperatorRewardRoute {
+        operator: Pubkey,
+        epoch: u64,
+    },
+
+    // ---------------------------------------------------- //
+    //                        KEEPERS                       //
+    // ---------------------------------------------------- //
+    /// Takes or renews the keeper lease of the authority for `lease_slots` slots, the holder
+    /// releases it with `0`. Keeper instances signing with the same authority only crank while
+    /// they hold the lease
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "keeper_lease")]
+    #[account(2, writable, signer, name = "authority")]
+    #[account(3, name = "system_program")]
+    AcquireKeeperLease {
+        holder: Pubkey,
+        lease_slots: u64,
+    },
+
+    // ---------------------------------------------------- //
+    //                       RECOVERY                       //
+    // ---------------------------------------------------- //
+    /// Sends the lamports left in a reward receiver whose reward router was closed to the NCN fee
+    /// wallet. The NCN reward receiver when `operator` is not set, the operator vault reward
+    /// receiver of `operator` otherwise. Only once the epoch is closing, when routers can not be
+    /// created anymore
+    #[account(0, name = "epoch_marker")]
+    #[account(1, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "reward_router")]
+    #[account(5, writable, name = "reward_receiver")]
+    #[account(6, writable, name = "ncn_fee_wallet")]
+    #[account(7, name = "system_program")]
+    CloseRewardReceiver {
+        operator: Option<Pubkey>,
+        epoch: u64,
     },
 }
@@ -0,0 +1,234 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
+use crate::{
+    constants::MAX_KEEPER_LEASE_SLOTS, discriminators::Discriminators, error::NCNProgramError,
+    pdas::NCNProgramPda,
+};
+
+/// Lease electing the one keeper instance that cranks the NCN among instances signing with the
+/// same authority, the others stand by until it expires
+///
+/// The lease is advisory: the program does not check it when cranking, keepers check it before
+/// sending transactions.
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct KeeperLease {
+    /// The NCN the keepers crank
+    ncn: Pubkey,
+    /// The wallet the keeper instances sign with, the only one allowed to take the lease
+    authority: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// The keeper instance holding the lease
+    holder: Pubkey,
+    /// The slot the holder took the lease in
+    slot_acquired: PodU64,
+    /// The first slot the lease can be taken by another instance
+    slot_expires: PodU64,
+    /// Reserved space
+    reserved: [u8; 128],
+}
+
+impl Discriminator for KeeperLease {
+    const DISCRIMINATOR: u8 = Discriminators::KeeperLease as u8;
+}
+
+impl KeeperLease {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, authority: &Pubkey, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            authority: *authority,
+            bump,
+            holder: Pubkey::default(),
+            slot_acquired: PodU64::from(0),
+            slot_expires: PodU64::from(0),
+            reserved: [0; 128],
+        }
+    }
+
+    pub fn initialize(&mut self, ncn: &Pubkey, authority: &Pubkey, bump: u8) {
+        *self = Self::new(ncn, authority, bump);
+    }
+
+    pub fn seeds(ncn: &Pubkey, authority: &Pubkey) -> Vec<Vec<u8>> {
+        NCNProgramPda::KeeperLease {
+            ncn: *ncn,
+            authority: *authority,
+        }
+        .seeds()
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        authority: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        NCNProgramPda::KeeperLease {
+            ncn: *ncn,
+            authority: *authority,
+        }
+        .find_program_address(program_id)
+    }
+
+    #[cfg(feature = "program")]
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        authority: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, authority).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    pub const fn holder(&self) -> &Pubkey {
+        &self.holder
+    }
+
+    pub fn slot_acquired(&self) -> u64 {
+        self.slot_acquired.into()
+    }
+
+    pub fn slot_expires(&self) -> u64 {
+        self.slot_expires.into()
+    }
+
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        current_slot >= self.slot_expires()
+    }
+
+    /// Whether `holder` holds the lease in `current_slot`
+    pub fn is_held_by(&self, holder: &Pubkey, current_slot: u64) -> bool {
+        self.holder.eq(holder) && !self.is_expired(current_slot)
+    }
+
+    /// Takes or renews the lease for `lease_slots` slots, the holder releases it with `0`
+    pub fn acquire(
+        &mut self,
+        holder: &Pubkey,
+        current_slot: u64,
+        lease_slots: u64,
+    ) -> Result<(), NCNProgramError> {
+        if lease_slots > MAX_KEEPER_LEASE_SLOTS {
+            return Err(NCNProgramError::InvalidKeeperLeaseSlots);
+        }
+
+        if self.holder.ne(holder) {
+            if !self.is_expired(current_slot) {
+                return Err(NCNProgramError::KeeperLeaseHeld);
+            }
+
+            self.holder = *holder;
+            self.slot_acquired = PodU64::from(current_slot);
+        }
+
+        let slot_expires = current_slot
+            .checked_add(lease_slots)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        self.slot_expires = PodU64::from(slot_expires);
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for KeeperLease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Keeper Lease -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Authority:                    {}", self.authority)?;
+        writeln!(f, "  Holder:                       {}", self.holder)?;
+        writeln!(
+            f,
+            "  Slot Acquired:                {}",
+            self.slot_acquired()
+        )?;
+        writeln!(f, "  Slot Expires:                 {}", self.slot_expires())?;
+        writeln!(f, "\n")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<Pubkey>() // authority
+            + 1 // bump
+            + size_of::<Pubkey>() // holder
+            + size_of::<PodU64>() // slot_acquired
+            + size_of::<PodU64>() // slot_expires
+            + 128; // reserved
+
+        assert_eq!(size_of::<KeeperLease>(), expected_total);
+        assert_eq!(KeeperLease::SIZE, expected_total + 8);
+    }
+
+    #[test]
+    fn test_acquire() {
+        let mut keeper_lease = KeeperLease::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 255);
+        let leader = Pubkey::new_unique();
+        let standby = Pubkey::new_unique();
+
+        // A new lease is expired
+        assert!(keeper_lease.is_expired(0));
+        keeper_lease.acquire(&leader, 100, 50).unwrap();
+        assert!(keeper_lease.is_held_by(&leader, 100));
+        assert!(!keeper_lease.is_held_by(&standby, 100));
+        assert_eq!(keeper_lease.slot_acquired(), 100);
+        assert_eq!(keeper_lease.slot_expires(), 150);
+
+        // Other instances wait for the lease to expire
+        assert_eq!(
+            keeper_lease.acquire(&standby, 149, 50),
+            Err(NCNProgramError::KeeperLeaseHeld)
+        );
+
+        // Renewing keeps the slot the lease was taken in
+        keeper_lease.acquire(&leader, 140, 50).unwrap();
+        assert_eq!(keeper_lease.slot_acquired(), 100);
+        assert_eq!(keeper_lease.slot_expires(), 190);
+
+        assert_eq!(
+            keeper_lease.acquire(&leader, 140, MAX_KEEPER_LEASE_SLOTS + 1),
+            Err(NCNProgramError::InvalidKeeperLeaseSlots)
+        );
+
+        // Released leases are taken over right away
+        keeper_lease.acquire(&leader, 160, 0).unwrap();
+        assert!(!keeper_lease.is_held_by(&leader, 160));
+        keeper_lease.acquire(&standby, 160, 50).unwrap();
+        assert!(keeper_lease.is_held_by(&standby, 160));
+        assert_eq!(keeper_lease.slot_acquired(), 160);
+    }
+}
@@ -1,20 +1,35 @@
 pub mod account_payer;
+pub mod account_type;
 pub mod ballot_box;
+pub mod builders;
 pub mod config;
 pub mod consensus_result;
 pub mod constants;
+pub mod diff;
 pub mod discriminators;
+pub mod distribution_receipt;
 pub mod epoch_marker;
 pub mod epoch_snapshot;
 pub mod epoch_state;
 pub mod error;
+pub mod events;
 pub mod fees;
+pub mod governance;
 pub mod instruction;
+pub mod keeper_lease;
+#[cfg(feature = "program")]
 pub mod loaders;
 pub mod ncn_reward_router;
+pub mod operator_metadata;
 pub mod operator_vault_reward_router;
+pub mod pdas;
+pub mod rent;
+pub mod simulation;
 pub mod stake_weight;
+pub mod switchboard;
 pub mod utils;
 pub mod vault_registry;
+pub mod verification;
+pub mod views;
 pub mod weight_entry;
 pub mod weight_table;
@@ -1,6 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::config::Config;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, program_pack::Pack,
+    pubkey::Pubkey, system_program,
+};
+use spl_associated_token_account::get_associated_token_address;
 
 use crate::error::NCNProgramError;
 
@@ -69,6 +73,80 @@ pub fn check_load(
     Ok(())
 }
 
+/// Used by `Initialize*` instructions called with `init_if_needed`. Returns `true` when the account
+/// at `expected_pda` is already owned by the program with `expected_discriminator`, and `false` when
+/// it still has to be created. Accounts that are created empty and only get their discriminator on
+/// the last realloc are also treated as initialized when `allow_pending_realloc` is set.
+pub fn check_already_initialized(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+    expected_pda: &Pubkey,
+    expected_discriminator: u8,
+    allow_pending_realloc: bool,
+) -> Result<bool, ProgramError> {
+    if account.key.ne(expected_pda) {
+        msg!("Account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if account.owner.eq(&system_program::id()) && account.data_is_empty() {
+        return Ok(false);
+    }
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if account.data_is_empty() {
+        msg!("Account data is empty");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let discriminator = account.data.borrow()[0];
+    if discriminator.eq(&expected_discriminator) || (allow_pending_realloc && discriminator == 0) {
+        return Ok(true);
+    }
+
+    msg!("Account discriminator is invalid");
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// Validates that the account is the associated token account of `owner` for `mint`
+/// Rewards routed in SPL token mints are only held in and sent to associated token accounts
+pub fn load_reward_token_account(
+    token_account: &AccountInfo,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    expect_writable: bool,
+) -> Result<(), ProgramError> {
+    let expected_token_account = get_associated_token_address(owner, mint);
+
+    if token_account.key.ne(&expected_token_account) {
+        msg!(
+            "Reward token account is not the associated token account of {}",
+            owner
+        );
+        return Err(NCNProgramError::InvalidRewardTokenAccount.into());
+    }
+
+    check_load(
+        &spl_token::id(),
+        token_account,
+        &expected_token_account,
+        None,
+        expect_writable,
+    )
+}
+
+/// Reads the token amount held in a reward token account
+pub fn reward_token_account_balance(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account.try_borrow_data()?;
+    let token_account = spl_token::state::Account::unpack(&token_account_data)?;
+
+    Ok(token_account.amount)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +299,106 @@ mod tests {
         );
         assert_eq!(result.err().unwrap(), ProgramError::InvalidAccountData);
     }
+
+    #[test]
+    fn test_check_already_initialized() {
+        let program_id = Pubkey::new_unique();
+        let mut lamports = 0;
+        const DISCRIMINATOR: u8 = 0x55;
+        let expected_pda = Pubkey::new_unique();
+
+        // Not created yet
+        let mut empty_data = [0u8; 0];
+        let system_program_id = system_program::id();
+        let account = AccountInfo::new(
+            &expected_pda,
+            false,
+            true,
+            &mut lamports,
+            &mut empty_data,
+            &system_program_id,
+            false,
+            0,
+        );
+
+        let result =
+            check_already_initialized(&program_id, &account, &expected_pda, DISCRIMINATOR, false);
+        assert_eq!(result, Ok(false));
+
+        // Wrong PDA
+        let result = check_already_initialized(
+            &program_id,
+            &account,
+            &Pubkey::new_unique(),
+            DISCRIMINATOR,
+            false,
+        );
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+
+        // Initialized
+        let mut data = [DISCRIMINATOR, 0];
+        let account = AccountInfo::new(
+            &expected_pda,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let result =
+            check_already_initialized(&program_id, &account, &expected_pda, DISCRIMINATOR, false);
+        assert_eq!(result, Ok(true));
+
+        // Different account type
+        let result = check_already_initialized(
+            &program_id,
+            &account,
+            &expected_pda,
+            DISCRIMINATOR + 1,
+            false,
+        );
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+
+        // Created, waiting for realloc
+        let mut pending_data = [0u8; 2];
+        let account = AccountInfo::new(
+            &expected_pda,
+            false,
+            true,
+            &mut lamports,
+            &mut pending_data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let result =
+            check_already_initialized(&program_id, &account, &expected_pda, DISCRIMINATOR, true);
+        assert_eq!(result, Ok(true));
+
+        let result =
+            check_already_initialized(&program_id, &account, &expected_pda, DISCRIMINATOR, false);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+
+        // Owned by another program
+        let bad_owner = Pubkey::new_unique();
+        let mut data = [DISCRIMINATOR, 0];
+        let account = AccountInfo::new(
+            &expected_pda,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &bad_owner,
+            false,
+            0,
+        );
+
+        let result =
+            check_already_initialized(&program_id, &account, &expected_pda, DISCRIMINATOR, false);
+        assert_eq!(result, Err(ProgramError::InvalidAccountOwner));
+    }
 }
@@ -6,16 +6,27 @@ use jito_bytemuck::{
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
+#[cfg(feature = "program")]
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    system_instruction, system_program, sysvar::Sysvar,
 };
-use spl_math::precise_number::PreciseNumber;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent};
+use spl_associated_token_account::get_associated_token_address;
+use spl_math::uint::U256;
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
-    ballot_box::BallotBox, discriminators::Discriminators, error::NCNProgramError, fees::Fees,
-    loaders::check_load,
+    ballot_box::{BallotBox, BallotTally, OperatorVote},
+    constants::{
+        MAX_FEE_BPS, MAX_FEE_GROUPS, MAX_OPERATORS, MAX_REWARD_ENTRIES, MAX_ROUTE_BASE_ITERATIONS,
+    },
+    discriminators::Discriminators,
+    error::NCNProgramError,
+    fees::Fees,
+    pdas::NCNProgramPda,
+    stake_weight::stake_weight_share,
 };
 
 /// NCN Reward Router - Main entry point for routing rewards from NCNs
@@ -23,11 +34,16 @@ use crate::{
 /// This router receives rewards and distributes them according to the fee structure:
 /// 1. Protocol receives a percentage (4%)
 /// 2. NCN receives a percentage (4%)
-/// 3. Remaining rewards (92%) go to operator-vault rewards
+/// 3. Each custom fee group receives its percentage, if any
+/// 4. Remaining rewards (92% without custom fee groups) go to operator-vault rewards
 ///
 /// The router supports partial routing through iterations to handle large numbers of operators
 /// without hitting transaction limits.
 ///
+/// Rewards are tracked per mint in `NCNMintRewards` entries: SOL is the first entry, followed by
+/// the SPL token mints registered in the config. Every entry is routed and resumed on its own by
+/// the same routing code.
+///
 /// PDA: ["ncn_reward_router", NCN, NCN_EPOCH_SLOT]
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -40,32 +56,16 @@ pub struct NCNRewardRouter {
     bump: u8,
     /// Slot the account was created
     slot_created: PodU64,
-    /// Total rewards routed (in lamports) - cumulative amount ever processed
-    total_rewards: PodU64,
-    /// Amount of rewards in the reward pool (in lamports) - awaiting distribution
-    reward_pool: PodU64,
-    /// Amount of rewards processed (in lamports) - moved out of reward pool for distribution
-    rewards_processed: PodU64,
     /// Reserved space for future fields
-    reserved: [u8; 128],
-
-    // Routing state tracking - enables recovery from incomplete routing operations
-    /// Last vote index processed during routing (for resuming partial operations)
-    last_vote_index: PodU16,
-    /// Last rewards amount being processed during routing (for resuming partial operations)
-    last_rewards_to_process: PodU64,
-
-    /// Rewards allocated to the Protocol (ready for distribution)
-    protocol_rewards: PodU64,
-    /// Rewards allocated to the NCN (ready for distribution)
-    ncn_rewards: PodU64,
+    reserved: [u8; 96],
 
-    /// Total rewards allocated to operator-vault reward receivers (before individual routing)
-    operator_vault_rewards: PodU64,
+    /// Rewards routed in each mint, SOL first then one entry per reward mint
+    rewards: [NCNMintRewards; MAX_REWARD_ENTRIES],
 
     /// Individual operator reward routes - tracks rewards per operator
     /// Array size 256 limits the number of operators that can participate in an epoch
-    operator_vault_reward_routes: [OperatorVaultRewardRoute; 256],
+    /// Kept last so the account can be shrunk down to the recorded routes
+    operator_vault_reward_routes: [OperatorVaultRewardRoute; MAX_OPERATORS],
 }
 
 impl Discriminator for NCNRewardRouter {
@@ -74,7 +74,10 @@ impl Discriminator for NCNRewardRouter {
 
 impl NCNRewardRouter {
     pub const SIZE: usize = 8 + size_of::<Self>();
-    pub const NCN_REWARD_ROUTER_SEED: &'static [u8] = b"ncn_reward_router";
+
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
 
     /// Sentinel values indicating no partial routing is in progress
     pub const NO_LAST_NCN_GROUP_INDEX: u8 = u8::MAX;
@@ -82,7 +85,10 @@ impl NCNRewardRouter {
     pub const NO_LAST_REWARDS_TO_PROCESS: u64 = u64::MAX;
 
     /// Maximum iterations per routing call to prevent transaction timeout
-    pub const MAX_ROUTE_BASE_ITERATIONS: u16 = 30;
+    pub const MAX_ROUTE_BASE_ITERATIONS: u16 = MAX_ROUTE_BASE_ITERATIONS;
+
+    /// Index of the SOL rewards in the rewards by mint, the reward mints follow it
+    pub const SOL_REWARDS_INDEX: usize = 0;
 
     /// Creates a new NCN reward router
     pub fn new(ncn: &Pubkey, ncn_epoch: u64, bump: u8, slot_created: u64) -> Self {
@@ -91,16 +97,9 @@ impl NCNRewardRouter {
             epoch: PodU64::from(ncn_epoch),
             bump,
             slot_created: PodU64::from(slot_created),
-            total_rewards: PodU64::from(0),
-            reward_pool: PodU64::from(0),
-            rewards_processed: PodU64::from(0),
-            reserved: [0; 128],
-            last_vote_index: PodU16::from(Self::NO_LAST_VOTE_INDEX),
-            last_rewards_to_process: PodU64::from(Self::NO_LAST_REWARDS_TO_PROCESS),
-            protocol_rewards: PodU64::from(0),
-            ncn_rewards: PodU64::from(0),
-            operator_vault_rewards: PodU64::from(0),
-            operator_vault_reward_routes: [OperatorVaultRewardRoute::default(); 256],
+            reserved: [0; 96],
+            rewards: [NCNMintRewards::default(); MAX_REWARD_ENTRIES],
+            operator_vault_reward_routes: [OperatorVaultRewardRoute::default(); MAX_OPERATORS],
         }
     }
 
@@ -112,29 +111,18 @@ impl NCNRewardRouter {
         self.epoch = PodU64::from(ncn_epoch);
         self.bump = bump;
         self.slot_created = PodU64::from(current_slot);
-        self.total_rewards = PodU64::from(0);
-        self.reward_pool = PodU64::from(0);
-        self.rewards_processed = PodU64::from(0);
-        self.reserved = [0; 128];
-        self.protocol_rewards = PodU64::from(0);
-        self.ncn_rewards = PodU64::from(0);
-        self.operator_vault_rewards = PodU64::from(0);
-        self.operator_vault_reward_routes = [OperatorVaultRewardRoute::default(); 256];
-
-        self.reset_routing_state();
+        self.reserved = [0; 96];
+        self.rewards = [NCNMintRewards::default(); MAX_REWARD_ENTRIES];
+        self.operator_vault_reward_routes = [OperatorVaultRewardRoute::default(); MAX_OPERATORS];
     }
 
     /// Generates PDA seeds for the NCN reward router
     pub fn seeds(ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::NCN_REWARD_ROUTER_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                ncn_epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::NCNRewardRouter {
+            ncn: *ncn,
+            epoch: ncn_epoch,
+        }
+        .seeds()
     }
 
     /// Finds the program address for the NCN reward router PDA
@@ -143,13 +131,11 @@ impl NCNRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds: Vec<Vec<u8>> = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::NCNRewardRouter { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
     /// Validates that the account matches expected PDA and discriminator
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -168,6 +154,7 @@ impl NCNRewardRouter {
     }
 
     /// Loads the account for closing (must be writable)
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -177,71 +164,104 @@ impl NCNRewardRouter {
         Self::load(program_id, account_to_close, ncn, epoch, true)
     }
 
-    // ----------------- ROUTE STATE TRACKING --------------
+    // ----------------- REWARDS BY MINT --------------
 
-    /// Gets the last vote index processed during partial routing
-    pub fn last_vote_index(&self) -> u16 {
-        self.last_vote_index.into()
+    /// Gets the rewards routed in each mint, SOL first then one entry per reward mint
+    pub const fn rewards_by_mint(&self) -> &[NCNMintRewards; MAX_REWARD_ENTRIES] {
+        &self.rewards
     }
 
-    /// Gets the NCN rewards ready for distribution
-    pub fn ncn_rewards(&self) -> u64 {
-        self.ncn_rewards.into()
+    /// Gets the rewards routed in SOL
+    pub const fn sol_rewards(&self) -> &NCNMintRewards {
+        &self.rewards[Self::SOL_REWARDS_INDEX]
     }
 
-    /// Gets the total operator vault rewards ready for distribution
-    pub fn operator_vault_rewards(&self) -> u64 {
-        self.operator_vault_rewards.into()
+    /// Gets the index of the entry tracking the given reward mint
+    pub fn mint_rewards_index(&self, mint: &Pubkey) -> Result<usize, NCNProgramError> {
+        self.rewards
+            .iter()
+            .enumerate()
+            .skip(Self::SOL_REWARDS_INDEX + 1)
+            .find(|(_, mint_rewards)| !mint_rewards.is_empty() && mint_rewards.mint().eq(mint))
+            .map(|(index, _)| index)
+            .ok_or(NCNProgramError::RewardMintNotFound)
     }
 
-    /// Gets the operator vault reward routes array
-    pub fn operator_vault_reward_routes(&self) -> &[OperatorVaultRewardRoute; 256] {
-        &self.operator_vault_reward_routes
+    /// Gets the entry tracking the given reward mint
+    pub fn get_mint_rewards(&self, mint: &Pubkey) -> Result<&NCNMintRewards, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        Ok(&self.rewards[index])
     }
 
-    /// Gets the operator vault reward route
-    pub fn operator_vault_reward_route(&self, operator: &Pubkey) -> OperatorVaultRewardRoute {
-        *self
-            .operator_vault_reward_routes
+    /// Gets the entry tracking the given reward mint, claiming an empty entry if there is none
+    /// Returns the index of the entry
+    pub fn register_mint_rewards(&mut self, mint: &Pubkey) -> Result<usize, NCNProgramError> {
+        if mint.eq(&Pubkey::default()) {
+            return Err(NCNProgramError::InvalidRewardMint);
+        }
+
+        if let Ok(index) = self.mint_rewards_index(mint) {
+            return Ok(index);
+        }
+
+        let index = self
+            .rewards
             .iter()
-            .find(|route| route.operator == *operator)
-            .unwrap_or(&OperatorVaultRewardRoute::default())
+            .enumerate()
+            .skip(Self::SOL_REWARDS_INDEX + 1)
+            .find(|(_, mint_rewards)| mint_rewards.is_empty())
+            .map(|(index, _)| index)
+            .ok_or(NCNProgramError::RewardMintListFull)?;
+
+        self.rewards[index] = NCNMintRewards::new(mint);
+
+        Ok(index)
     }
 
-    /// Gets the last rewards amount being processed during partial routing
-    pub fn last_rewards_to_process(&self) -> u64 {
-        self.last_rewards_to_process.into()
+    /// Checks if routing of the given mint is still in progress (was interrupted)
+    pub fn still_routing_mint(&self, mint: &Pubkey) -> bool {
+        self.get_mint_rewards(mint)
+            .map(|mint_rewards| mint_rewards.still_routing())
+            .unwrap_or(false)
     }
 
-    /// Resumes routing from the last saved state if routing was interrupted
-    /// Returns (starting_vote_index, rewards_to_process)
-    pub fn resume_routing_state(&mut self) -> (usize, u64) {
-        if !self.still_routing() {
-            return (0, 0);
-        }
+    // ----------------- ROUTE STATE TRACKING --------------
 
-        (
-            self.last_vote_index() as usize,
-            self.last_rewards_to_process(),
-        )
+    /// Gets the last vote index processed during partial routing of SOL
+    pub fn last_vote_index(&self) -> u16 {
+        self.sol_rewards().last_vote_index()
     }
 
-    /// Saves the current routing state for resumption if interrupted
-    pub fn save_routing_state(&mut self, vote_index: usize, rewards_to_process: u64) {
-        self.last_vote_index = PodU16::from(vote_index as u16);
-        self.last_rewards_to_process = PodU64::from(rewards_to_process);
+    /// Gets the last rewards amount being processed during partial routing of SOL
+    pub fn last_rewards_to_process(&self) -> u64 {
+        self.sol_rewards().last_rewards_to_process()
     }
 
-    /// Resets routing state to indicate no partial routing is in progress
-    pub fn reset_routing_state(&mut self) {
-        self.last_vote_index = PodU16::from(Self::NO_LAST_VOTE_INDEX);
-        self.last_rewards_to_process = PodU64::from(Self::NO_LAST_REWARDS_TO_PROCESS);
+    /// Checks if routing of SOL is still in progress (was interrupted)
+    pub fn still_routing(&self) -> bool {
+        self.sol_rewards().still_routing()
     }
 
-    /// Checks if routing is still in progress (was interrupted)
-    pub fn still_routing(&self) -> bool {
-        self.last_vote_index() != Self::NO_LAST_VOTE_INDEX
-            || self.last_rewards_to_process() != Self::NO_LAST_REWARDS_TO_PROCESS
+    /// Gets the operator vault reward routes array
+    pub fn operator_vault_reward_routes(&self) -> &[OperatorVaultRewardRoute; MAX_OPERATORS] {
+        &self.operator_vault_reward_routes
+    }
+
+    /// Iterates over the operator vault reward routes in use, skipping the empty route slots
+    pub fn iter_routes(&self) -> impl Iterator<Item = &OperatorVaultRewardRoute> {
+        self.operator_vault_reward_routes
+            .iter()
+            .filter(|route| !route.is_empty())
+    }
+
+    /// Gets the operator vault reward route
+    pub fn operator_vault_reward_route(&self, operator: &Pubkey) -> OperatorVaultRewardRoute {
+        *self
+            .operator_vault_reward_routes
+            .iter()
+            .find(|route| route.operator == *operator)
+            .unwrap_or(&OperatorVaultRewardRoute::default())
     }
 
     // ----------------- ROUTE REWARDS ---------------------
@@ -253,85 +273,117 @@ impl NCNRewardRouter {
         rent_cost: u64,
         account_balance: u64,
     ) -> Result<(), NCNProgramError> {
-        let total_rewards = self.total_rewards_in_transit()?;
-
-        // Calculate new rewards as difference between current balance and known rewards
-        let incoming_rewards = account_balance
-            .checked_sub(total_rewards)
-            .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
-
-        // Subtract rent cost to get distributable rewards
-        let rewards_to_route = incoming_rewards
+        // Subtract rent cost to get the balance holding rewards
+        let rewards_balance = account_balance
             .checked_sub(rent_cost)
             .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
 
-        self.route_to_reward_pool(rewards_to_route)?;
-
-        Ok(())
+        self.rewards[Self::SOL_REWARDS_INDEX].route_incoming_rewards(rewards_balance)
     }
 
-    /// Adds rewards to the reward pool and updates total rewards counter
-    pub fn route_to_reward_pool(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
+    /// Routes incoming rewards of a mint from the receiver's token account balance
+    /// Token accounts hold no rent, so the whole untracked balance is routed
+    pub fn route_incoming_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+        token_account_balance: u64,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.register_mint_rewards(mint)?;
 
-        self.total_rewards = PodU64::from(
-            self.total_rewards()
-                .checked_add(rewards)
-                .ok_or(NCNProgramError::ArithmeticOverflow)?,
-        );
+        self.rewards[index].route_incoming_rewards(token_account_balance)
+    }
 
-        self.reward_pool = PodU64::from(
-            self.reward_pool()
-                .checked_add(rewards)
-                .ok_or(NCNProgramError::ArithmeticOverflow)?,
-        );
+    /// Adds SOL rewards to the reward pool and updates total rewards counter
+    pub fn route_to_reward_pool(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
+        self.rewards[Self::SOL_REWARDS_INDEX].route_to_reward_pool(rewards)
+    }
 
-        Ok(())
+    /// Takes the cranker fee out of the SOL reward pool before the Protocol and NCN fees are split
+    /// Returns the fee, which leaves the reward receiver right away
+    pub fn route_cranker_fee(&mut self, cranker_fee_bps: u16) -> Result<u64, NCNProgramError> {
+        self.rewards[Self::SOL_REWARDS_INDEX].route_cranker_fee(cranker_fee_bps)
     }
 
-    /// Routes rewards from the reward pool to Protocol and NCN based on fee structure
-    /// This is the first phase of reward distribution
+    /// Routes the SOL reward pool to Protocol, NCN and the custom fee groups based on fee
+    /// structure. This is the first phase of reward distribution
     pub fn route_reward_pool(&mut self, fee: &Fees) -> Result<(), NCNProgramError> {
-        let rewards_to_process: u64 = self.reward_pool();
+        self.rewards[Self::SOL_REWARDS_INDEX].route_reward_pool(fee)
+    }
 
-        // Route Protocol fee (typically 4%)
-        {
-            let protocol_fee =
-                Self::calculate_reward_split(fee.protocol_fee_bps()?, rewards_to_process)?;
-            self.route_from_reward_pool(protocol_fee)?;
-            self.route_to_protocol(protocol_fee)?;
-        }
+    /// Routes the reward pool of a mint to Protocol, NCN and operator-vault rewards
+    pub fn route_mint_reward_pool(
+        &mut self,
+        mint: &Pubkey,
+        fee: &Fees,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
 
-        // Route NCN fee (typically 4%)
-        {
-            let ncn_fee = Self::calculate_reward_split(fee.ncn_fee_bps()?, rewards_to_process)?;
-            self.route_from_reward_pool(ncn_fee)?;
-            self.route_to_ncn(ncn_fee)?;
-        }
+        self.rewards[index].route_reward_pool(fee)
+    }
 
-        // The rest goes to operator-vault rewards (typically 92%)
-        {
-            let operator_vault_rewards = self.reward_pool();
-            self.route_from_reward_pool(operator_vault_rewards)?;
-            self.route_to_operator_vault(operator_vault_rewards)?;
-        }
+    /// Routes SOL operator vault rewards to individual operators based on their vote
+    /// participation. This is the second phase of reward distribution that can be done
+    /// iteratively
+    ///
+    /// With a non-zero `timeliness_bonus_bps`, operators that voted within `timeliness_bonus_slots`
+    /// of the ballot box creation have their stake weight boosted by that many basis points
+    pub fn route_operator_vault_rewards(
+        &mut self,
+        ballot_box: &BallotBox,
+        timeliness_bonus_slots: u64,
+        timeliness_bonus_bps: u16,
+        max_iterations: u16,
+    ) -> Result<(), NCNProgramError> {
+        self.route_operator_vault_rewards_of(
+            Self::SOL_REWARDS_INDEX,
+            ballot_box,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+            max_iterations,
+        )
+    }
 
-        Ok(())
+    /// Routes operator vault rewards of a mint to individual operators, the same way
+    /// `route_operator_vault_rewards` does for SOL
+    pub fn route_operator_vault_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+        ballot_box: &BallotBox,
+        timeliness_bonus_slots: u64,
+        timeliness_bonus_bps: u16,
+        max_iterations: u16,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.route_operator_vault_rewards_of(
+            index,
+            ballot_box,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+            max_iterations,
+        )
     }
 
-    /// Routes operator vault rewards to individual operators based on their vote participation
-    /// This is the second phase of reward distribution that can be done iteratively
-    pub fn route_operator_vault_rewards(
+    /// Routes the operator vault rewards of the entry at `index` to the operators that voted for
+    /// the winning ballot, proportionally to their reward stake weight
+    fn route_operator_vault_rewards_of(
         &mut self,
+        index: usize,
         ballot_box: &BallotBox,
+        timeliness_bonus_slots: u64,
+        timeliness_bonus_bps: u16,
         max_iterations: u16,
     ) -> Result<(), NCNProgramError> {
         let winning_ballot = ballot_box.get_winning_ballot_tally()?;
-        let winning_stake_weight = winning_ballot.stake_weights();
+        let winning_reward_stake_weight = Self::winning_reward_stake_weight(
+            ballot_box,
+            winning_ballot,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+        )?;
 
-        let (starting_vote_index, starting_rewards_to_process) = self.resume_routing_state();
+        let (starting_vote_index, starting_rewards_to_process) =
+            self.rewards[index].resume_routing_state();
 
         let mut iterations: u16 = 0;
         // Always have at least 1 iteration to make progress
@@ -340,7 +392,7 @@ impl NCNRewardRouter {
         let rewards_to_process = if starting_rewards_to_process > 0 {
             starting_rewards_to_process
         } else {
-            self.operator_vault_rewards()
+            self.rewards[index].operator_vault_rewards()
         };
 
         if rewards_to_process == 0 {
@@ -364,163 +416,769 @@ impl NCNRewardRouter {
                             "Reached max iterations, saving state and exiting {}",
                             vote_index
                         );
-                        self.save_routing_state(vote_index, rewards_to_process);
+                        self.rewards[index].save_routing_state(vote_index, rewards_to_process);
                         return Ok(());
                     }
                 }
 
-                let operator = vote.operator();
-
-                let winning_reward_stake_weight = winning_stake_weight.stake_weight();
-                let operator_vote_stake_weight = vote.stake_weights().stake_weight();
+                let operator_vote_stake_weight = Self::operator_reward_stake_weight(
+                    &vote,
+                    ballot_box.slot_created(),
+                    timeliness_bonus_slots,
+                    timeliness_bonus_bps,
+                )?;
 
                 // Calculate proportional reward based on operator's stake weight
-                let operator_route_reward = Self::calculate_operator_vault_route_reward(
+                let operator_route_reward = stake_weight_share(
+                    rewards_to_process,
                     operator_vote_stake_weight,
                     winning_reward_stake_weight,
-                    rewards_to_process,
                 )?;
 
-                self.route_from_operator_vault_rewards(operator_route_reward)?;
-                self.route_to_operator_vault_reward_route(operator, operator_route_reward)?;
+                self.rewards[index].route_from_operator_vault_rewards(operator_route_reward)?;
+                self.route_to_operator_vault_reward_route(
+                    index,
+                    vote.operator(),
+                    operator_route_reward,
+                )?;
             }
         }
 
         // NCN gets any remaining rewards due to rounding
         {
-            let leftover_rewards = self.operator_vault_rewards();
+            let rewards = &mut self.rewards[index];
+            let leftover_rewards = rewards.operator_vault_rewards();
 
-            self.route_from_operator_vault_rewards(leftover_rewards)?;
-            self.route_to_ncn(leftover_rewards)?;
+            rewards.route_from_operator_vault_rewards(leftover_rewards)?;
+            rewards.route_to_ncn(leftover_rewards)?;
         }
 
         msg!("Finished routing operator vault rewards");
-        self.reset_routing_state();
+        self.rewards[index].reset_routing_state();
 
         Ok(())
     }
 
     // ------------------ CALCULATIONS ---------------------
 
+    /// Stake weight an operator vote is rewarded for, including the timeliness bonus if it applies
+    fn operator_reward_stake_weight(
+        vote: &OperatorVote,
+        voting_start_slot: u64,
+        timeliness_bonus_slots: u64,
+        timeliness_bonus_bps: u16,
+    ) -> Result<U256, NCNProgramError> {
+        let stake_weight = vote.stake_weights().stake_weight_u256();
+
+        let voted_in_time =
+            vote.slot_voted().saturating_sub(voting_start_slot) < timeliness_bonus_slots;
+        if timeliness_bonus_bps == 0 || !voted_in_time {
+            return Ok(stake_weight);
+        }
+
+        stake_weight
+            .checked_mul(U256::from(MAX_FEE_BPS + timeliness_bonus_bps as u64))
+            .and_then(|x| x.checked_div(U256::from(MAX_FEE_BPS)))
+            .ok_or(NCNProgramError::ArithmeticOverflow)
+    }
+
+    /// Total stake weight the operator vault rewards are split by
+    ///
+    /// Without a timeliness bonus this is the stake weight of the winning ballot, otherwise the
+    /// boosted stake weights of all winning votes are summed up
+    fn winning_reward_stake_weight(
+        ballot_box: &BallotBox,
+        winning_ballot: &BallotTally,
+        timeliness_bonus_slots: u64,
+        timeliness_bonus_bps: u16,
+    ) -> Result<U256, NCNProgramError> {
+        if timeliness_bonus_bps == 0 {
+            return Ok(winning_ballot.stake_weights().stake_weight_u256());
+        }
+
+        let mut winning_reward_stake_weight = U256::zero();
+        for vote in ballot_box
+            .iter_votes()
+            .filter(|vote| vote.ballot_index() == winning_ballot.index())
+        {
+            let stake_weight = Self::operator_reward_stake_weight(
+                vote,
+                ballot_box.slot_created(),
+                timeliness_bonus_slots,
+                timeliness_bonus_bps,
+            )?;
+
+            winning_reward_stake_weight = winning_reward_stake_weight
+                .checked_add(stake_weight)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        }
+
+        Ok(winning_reward_stake_weight)
+    }
+
     /// Calculates reward amount based on basis points
     /// Used for fee calculations (Protocol and NCN fees)
     fn calculate_reward_split(
         fee_basis_points: u16,
         total_rewards: u64,
     ) -> Result<u64, NCNProgramError> {
-        const BASIS_POINTS_DENOMINATOR: u16 = 10000;
+        const BASIS_POINTS_DENOMINATOR: u128 = 10000;
 
         if fee_basis_points == 0 || total_rewards == 0 {
             return Ok(0);
         }
 
-        let precise_fee_basis_points = PreciseNumber::new(fee_basis_points as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+        // A u64 times a u16 always fits in a u128
+        let fee_amount = (total_rewards as u128)
+            .checked_mul(fee_basis_points as u128)
+            .and_then(|x| x.checked_div(BASIS_POINTS_DENOMINATOR))
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+        let fee_amount: u64 = fee_amount
+            .try_into()
+            .map_err(|_| NCNProgramError::CastToU64Error)?;
+
+        Ok(fee_amount)
+    }
 
-        let precise_basis_points_denominator = PreciseNumber::new(BASIS_POINTS_DENOMINATOR as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+    // ------------------ REWARD TALLIES ---------------------
 
-        let precise_total_rewards = PreciseNumber::new(total_rewards as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+    /// Calculates total SOL rewards currently being processed (reward pool + processed)
+    pub fn total_rewards_in_transit(&self) -> Result<u64, NCNProgramError> {
+        self.sol_rewards().total_rewards_in_transit()
+    }
 
-        let precise_fee_amount = precise_total_rewards
-            .checked_mul(&precise_fee_basis_points)
-            .and_then(|x| x.checked_div(&precise_basis_points_denominator))
+    /// Calculates minimum rent cost for this account
+    pub fn rent_cost(&self, rent: &Rent) -> Result<u64, NCNProgramError> {
+        let size = 8_u64
+            .checked_add(size_of::<Self>() as u64)
             .ok_or(NCNProgramError::ArithmeticOverflow)?;
 
-        let floored_precise_fee_amount = precise_fee_amount
-            .floor()
-            .ok_or(NCNProgramError::ArithmeticFloorError)?;
+        Ok(rent.minimum_balance(size as usize))
+    }
 
-        let fee_amount_u128: u128 = floored_precise_fee_amount
-            .to_imprecise()
-            .ok_or(NCNProgramError::CastToImpreciseNumberError)?;
-        let fee_amount: u64 = fee_amount_u128
-            .try_into()
-            .map_err(|_| NCNProgramError::CastToU64Error)?;
+    // ------------------ SHRINKING ---------------------
 
-        Ok(fee_amount)
+    /// Account size once shrunk down to `route_count` operator vault reward routes
+    pub fn shrunk_size(route_count: usize) -> usize {
+        let dropped_routes = MAX_OPERATORS.saturating_sub(route_count);
+        Self::SIZE - dropped_routes * size_of::<OperatorVaultRewardRoute>()
     }
 
-    /// Calculates proportional reward for an operator based on their stake weight
-    /// Formula: (operator_stake_weight / total_winning_stake_weight) * total_rewards
-    fn calculate_operator_vault_route_reward(
-        operator_stake_weight: u128,
-        winning_total_stake_weight: u128,
-        rewards_to_process: u64,
-    ) -> Result<u64, NCNProgramError> {
-        if operator_stake_weight == 0 || rewards_to_process == 0 {
-            return Ok(0);
+    /// The router is zero-copy, so it can only be shrunk once there is nothing left to route or
+    /// distribute, in SOL or any mint
+    pub fn can_shrink(&self) -> Result<bool, NCNProgramError> {
+        for rewards in self.rewards.iter() {
+            if rewards.still_routing() || rewards.total_rewards_in_transit()? > 0 {
+                return Ok(false);
+            }
         }
 
-        let precise_rewards_to_process = PreciseNumber::new(rewards_to_process as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+        Ok(true)
+    }
 
-        let precise_operator_stake_weight = PreciseNumber::new(operator_stake_weight)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+    /// Moves the recorded operator vault reward routes to the front of the routes
+    /// Returns the number of recorded routes
+    pub fn compact_operator_vault_reward_routes(&mut self) -> usize {
+        let mut route_count = 0;
+        for index in 0..self.operator_vault_reward_routes.len() {
+            if !self.operator_vault_reward_routes[index].is_empty() {
+                self.operator_vault_reward_routes.swap(route_count, index);
+                route_count += 1;
+            }
+        }
 
-        let precise_winning_total_stake_weight = PreciseNumber::new(winning_total_stake_weight)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
+        route_count
+    }
 
-        let precise_operator_reward = precise_rewards_to_process
-            .checked_mul(&precise_operator_stake_weight)
-            .and_then(|x| x.checked_div(&precise_winning_total_stake_weight))
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    /// Reads a router that may have been shrunk, the dropped routes read as empty
+    pub fn try_from_shrunk_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let mut padded_data = data.to_vec();
+        if padded_data.len() < Self::SIZE {
+            padded_data.resize(Self::SIZE, 0);
+        }
 
-        let floored_precise_operator_reward = precise_operator_reward
-            .floor()
-            .ok_or(NCNProgramError::ArithmeticFloorError)?;
+        Ok(*Self::try_from_slice_unchecked(&padded_data)?)
+    }
 
-        let operator_reward_u128: u128 = floored_precise_operator_reward
-            .to_imprecise()
-            .ok_or(NCNProgramError::CastToImpreciseNumberError)?;
+    pub fn total_rewards(&self) -> u64 {
+        self.sol_rewards().total_rewards()
+    }
 
-        let operator_reward: u64 = operator_reward_u128
-            .try_into()
-            .map_err(|_| NCNProgramError::CastToU64Error)?;
+    pub fn reward_pool(&self) -> u64 {
+        self.sol_rewards().reward_pool()
+    }
 
-        Ok(operator_reward)
+    pub fn rewards_processed(&self) -> u64 {
+        self.sol_rewards().rewards_processed()
     }
 
-    // ------------------ REWARD TALLIES ---------------------
+    pub fn protocol_rewards(&self) -> u64 {
+        self.sol_rewards().protocol_rewards()
+    }
 
-    /// Calculates total rewards currently being processed (reward pool + processed)
-    pub fn total_rewards_in_transit(&self) -> Result<u64, NCNProgramError> {
-        let total_rewards = self
-            .reward_pool()
-            .checked_add(self.rewards_processed())
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    /// Gets the SOL NCN rewards ready for distribution
+    pub fn ncn_rewards(&self) -> u64 {
+        self.sol_rewards().ncn_rewards()
+    }
 
-        Ok(total_rewards)
+    /// Gets the SOL rewards allocated to a custom fee group
+    pub fn fee_group_rewards(&self, group: usize) -> Result<u64, NCNProgramError> {
+        self.sol_rewards().fee_group_rewards(group)
     }
 
-    /// Calculates minimum rent cost for this account
-    pub fn rent_cost(&self, rent: &Rent) -> Result<u64, NCNProgramError> {
-        let size = 8_u64
-            .checked_add(size_of::<Self>() as u64)
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    /// Gets the total SOL operator vault rewards ready for distribution
+    pub fn operator_vault_rewards(&self) -> u64 {
+        self.sol_rewards().operator_vault_rewards()
+    }
 
-        Ok(rent.minimum_balance(size as usize))
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
     }
 
-    pub fn total_rewards(&self) -> u64 {
-        self.total_rewards.into()
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
     }
 
-    pub fn reward_pool(&self) -> u64 {
-        self.reward_pool.into()
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    // ------------------ DISTRIBUTION ---------------------
+
+    /// Distributes SOL NCN rewards and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_ncn_fee_rewards(&mut self) -> Result<u64, NCNProgramError> {
+        self.rewards[Self::SOL_REWARDS_INDEX].distribute_ncn_fee_rewards()
+    }
+
+    /// Distributes NCN rewards of a mint and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_ncn_fee_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.rewards[index].distribute_ncn_fee_rewards()
+    }
+
+    /// Distributes SOL Protocol rewards and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_protocol_fee_rewards(&mut self) -> Result<u64, NCNProgramError> {
+        self.rewards[Self::SOL_REWARDS_INDEX].distribute_protocol_fee_rewards()
+    }
+
+    /// Distributes Protocol rewards of a mint and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_protocol_fee_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.rewards[index].distribute_protocol_fee_rewards()
+    }
+
+    /// Distributes the SOL rewards of a custom fee group and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_fee_group_rewards(&mut self, group: usize) -> Result<u64, NCNProgramError> {
+        self.rewards[Self::SOL_REWARDS_INDEX].distribute_fee_group_rewards(group)
     }
 
-    pub const fn ncn(&self) -> &Pubkey {
-        &self.ncn
-    }
+    /// Distributes the rewards of a custom fee group in a mint and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_fee_group_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+        group: usize,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.rewards[index].distribute_fee_group_rewards(group)
+    }
+
+    // ------------------ OPERATOR VAULT REWARD ROUTES ---------------------
+
+    /// Checks if an operator has a reward route
+    pub fn has_operator_vault_reward_route(&self, operator: &Pubkey) -> bool {
+        for operator_vault_route_reward in self.iter_routes() {
+            if operator_vault_route_reward.operator.eq(operator) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Gets the reward route for a specific operator
+    pub fn oprtator_vault_reward_route(
+        &self,
+        operator: &Pubkey,
+    ) -> Result<&OperatorVaultRewardRoute, NCNProgramError> {
+        for operator_vault_route_reward in self.iter_routes() {
+            if operator_vault_route_reward.operator.eq(operator) {
+                return Ok(operator_vault_route_reward);
+            }
+        }
+
+        Err(NCNProgramError::NcnRewardRouteNotFound)
+    }
+
+    /// Routes rewards of the entry at `index` to a specific operator's reward route
+    /// Creates a new route if one doesn't exist for the operator
+    fn route_to_operator_vault_reward_route(
+        &mut self,
+        index: usize,
+        operator: &Pubkey,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        if rewards == 0 {
+            return Ok(());
+        }
+
+        // Try to find existing route and increment rewards
+        for operator_vault_route_reward in self.operator_vault_reward_routes.iter_mut() {
+            if operator_vault_route_reward.operator.eq(operator) {
+                return operator_vault_route_reward.increment_rewards_of(index, rewards);
+            } else if operator_vault_route_reward.is_empty() {
+                // Found empty slot, create new route
+                *operator_vault_route_reward = OperatorVaultRewardRoute::new(operator, 0)?;
+                return operator_vault_route_reward.increment_rewards_of(index, rewards);
+            }
+        }
+
+        Err(NCNProgramError::OperatorRewardListFull)
+    }
+
+    /// Distributes SOL rewards for a specific operator and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_operator_vault_reward_route(
+        &mut self,
+        operator: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        self.distribute_operator_vault_reward_route_of(Self::SOL_REWARDS_INDEX, operator)
+    }
+
+    /// Distributes rewards of a mint for a specific operator and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_operator_vault_mint_reward_route(
+        &mut self,
+        mint: &Pubkey,
+        operator: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.distribute_operator_vault_reward_route_of(index, operator)
+    }
+
+    /// Distributes the rewards of the entry at `index` routed to a specific operator
+    fn distribute_operator_vault_reward_route_of(
+        &mut self,
+        index: usize,
+        operator: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        for route in self.operator_vault_reward_routes.iter_mut() {
+            if route.operator.eq(operator) {
+                let rewards = route.rewards_of(index);
+                route.decrement_rewards_of(index, rewards)?;
+                self.rewards[index].decrement_rewards_processed(rewards)?;
+
+                return Ok(rewards);
+            }
+        }
+
+        Err(NCNProgramError::OperatorRewardNotFound)
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for NCNRewardRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- NCN Reward Router -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Bump:                         {}", self.bump)?;
+        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
+
+        for (index, rewards) in self.rewards_by_mint().iter().enumerate() {
+            if index == Self::SOL_REWARDS_INDEX {
+                writeln!(f, "\nSOL Rewards:")?;
+            } else if rewards.is_empty() {
+                continue;
+            } else {
+                writeln!(f, "\nMint Rewards:                   {}", rewards.mint())?;
+            }
+            writeln!(f, "  Still Routing:                {}", rewards.still_routing())?;
+            writeln!(f, "  Total Rewards:                {}", rewards.total_rewards())?;
+            writeln!(f, "  Reward Pool:                  {}", rewards.reward_pool())?;
+            writeln!(f, "  Rewards Processed:            {}", rewards.rewards_processed())?;
+            if rewards.still_routing() {
+                writeln!(f, "  Last Vote Index:              {}", rewards.last_vote_index())?;
+                writeln!(f, "  Last Rewards to Process:      {}", rewards.last_rewards_to_process())?;
+            }
+            writeln!(f, "  Protocol Rewards:             {}", rewards.protocol_rewards())?;
+            writeln!(f, "  NCN Rewards:                  {}", rewards.ncn_rewards())?;
+            for group in 0..MAX_FEE_GROUPS {
+                let group_rewards = rewards.fee_group_rewards(group).unwrap_or(0);
+                if group_rewards > 0 {
+                    writeln!(f, "  Fee Group {} Rewards:          {}", group, group_rewards)?;
+                }
+            }
+            writeln!(f, "  Operator Vault Rewards:       {}", rewards.operator_vault_rewards())?;
+        }
+
+        writeln!(f, "\nOperator Vault Reward Routes:")?;
+        for route in self.iter_routes() {
+            writeln!(f, "  Operator:                     {}", route.operator())?;
+            for (index, rewards) in self.rewards_by_mint().iter().enumerate() {
+                let route_rewards = route.rewards_of(index);
+                if route_rewards == 0 {
+                    continue;
+                }
+                if index == Self::SOL_REWARDS_INDEX {
+                    writeln!(f, "    Rewards:                      {}", route_rewards)?;
+                } else {
+                    writeln!(f, "    Rewards ({}): {}", rewards.mint(), route_rewards)?;
+                }
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+/// Individual operator reward route - tracks rewards for a specific operator
+/// This struct stores the allocation of rewards for an operator before distribution
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct OperatorVaultRewardRoute {
+    /// The operator pubkey
+    operator: Pubkey,
+    /// Reward amounts allocated to this operator, indexed like the router's rewards by mint
+    rewards: [NCNRewardRouterRewards; MAX_REWARD_ENTRIES],
+}
+
+impl Default for OperatorVaultRewardRoute {
+    fn default() -> Self {
+        Self {
+            operator: Pubkey::default(),
+            rewards: [NCNRewardRouterRewards::default(); MAX_REWARD_ENTRIES],
+        }
+    }
+}
+
+impl OperatorVaultRewardRoute {
+    /// Creates a new operator vault reward route with initial SOL reward amount
+    pub fn new(operator: &Pubkey, rewards: u64) -> Result<Self, NCNProgramError> {
+        let mut route = Self {
+            operator: *operator,
+            ..Self::default()
+        };
+
+        route.increment_rewards_of(NCNRewardRouter::SOL_REWARDS_INDEX, rewards)?;
+
+        Ok(route)
+    }
+
+    /// Gets the operator pubkey for this route
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    /// Gets the SOL reward amount for this route
+    pub fn rewards(&self) -> Result<u64, NCNProgramError> {
+        Ok(self.rewards_of(NCNRewardRouter::SOL_REWARDS_INDEX))
+    }
+
+    /// Checks if this route slot is empty (default operator)
+    pub fn is_empty(&self) -> bool {
+        self.operator.eq(&Pubkey::default())
+    }
+
+    /// Checks if this route has any SOL rewards allocated
+    pub fn has_rewards(&self) -> Result<bool, NCNProgramError> {
+        if self.rewards()? > 0 {
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Gets the reward amount for the router's rewards entry at `index`
+    pub fn rewards_of(&self, index: usize) -> u64 {
+        self.rewards
+            .get(index)
+            .map(|rewards| rewards.rewards())
+            .unwrap_or(0)
+    }
+
+    /// Adds rewards of the router's rewards entry at `index` to this route
+    pub fn increment_rewards_of(
+        &mut self,
+        index: usize,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        let route_rewards = self
+            .rewards
+            .get_mut(index)
+            .ok_or(NCNProgramError::InvalidRewardMintIndex)?;
+
+        route_rewards.rewards = PodU64::from(
+            route_rewards
+                .rewards()
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Removes rewards of the router's rewards entry at `index` from this route (used during
+    /// distribution)
+    pub fn decrement_rewards_of(
+        &mut self,
+        index: usize,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        let route_rewards = self
+            .rewards
+            .get_mut(index)
+            .ok_or(NCNProgramError::InvalidRewardMintIndex)?;
+
+        route_rewards.rewards = PodU64::from(
+            route_rewards
+                .rewards()
+                .checked_sub(rewards)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(())
+    }
+}
+
+/// Rewards routed in a single mint, SOL being tracked under the default mint
+///
+/// Incoming rewards land in the reward pool, are split between Protocol, NCN, custom fee group
+/// and operator-vault rewards and are then routed to the operators. Routing state is kept per
+/// entry so each mint can be routed and resumed on its own.
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct NCNMintRewards {
+    /// The reward mint, default for SOL and for unused entries
+    mint: Pubkey,
+    /// Total rewards routed - cumulative amount ever processed
+    total_rewards: PodU64,
+    /// Amount of rewards in the reward pool - awaiting distribution
+    reward_pool: PodU64,
+    /// Amount of rewards processed - moved out of reward pool for distribution
+    rewards_processed: PodU64,
+    /// Rewards allocated to the Protocol (ready for distribution)
+    protocol_rewards: PodU64,
+    /// Rewards allocated to the NCN (ready for distribution)
+    ncn_rewards: PodU64,
+    /// Rewards allocated to each custom fee group (ready for distribution)
+    fee_group_rewards: [PodU64; MAX_FEE_GROUPS],
+    /// Total rewards allocated to operator-vault reward receivers (before individual routing)
+    operator_vault_rewards: PodU64,
+    /// Last vote index processed during routing (for resuming partial operations)
+    last_vote_index: PodU16,
+    /// Last rewards amount being processed during routing (for resuming partial operations)
+    last_rewards_to_process: PodU64,
+}
+
+impl Default for NCNMintRewards {
+    fn default() -> Self {
+        Self::new(&Pubkey::default())
+    }
+}
+
+impl NCNMintRewards {
+    /// Creates an empty rewards entry for a mint
+    pub fn new(mint: &Pubkey) -> Self {
+        Self {
+            mint: *mint,
+            total_rewards: PodU64::from(0),
+            reward_pool: PodU64::from(0),
+            rewards_processed: PodU64::from(0),
+            protocol_rewards: PodU64::from(0),
+            ncn_rewards: PodU64::from(0),
+            fee_group_rewards: [PodU64::from(0); MAX_FEE_GROUPS],
+            operator_vault_rewards: PodU64::from(0),
+            last_vote_index: PodU16::from(NCNRewardRouter::NO_LAST_VOTE_INDEX),
+            last_rewards_to_process: PodU64::from(NCNRewardRouter::NO_LAST_REWARDS_TO_PROCESS),
+        }
+    }
+
+    pub const fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    /// Checks if this entry is unused (default mint), which the SOL entry always reads as
+    pub fn is_empty(&self) -> bool {
+        self.mint.eq(&Pubkey::default())
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn reward_pool(&self) -> u64 {
+        self.reward_pool.into()
+    }
+
+    pub fn rewards_processed(&self) -> u64 {
+        self.rewards_processed.into()
+    }
+
+    pub fn protocol_rewards(&self) -> u64 {
+        self.protocol_rewards.into()
+    }
+
+    pub fn ncn_rewards(&self) -> u64 {
+        self.ncn_rewards.into()
+    }
+
+    /// Gets the rewards allocated to a custom fee group
+    pub fn fee_group_rewards(&self, group: usize) -> Result<u64, NCNProgramError> {
+        self.fee_group_rewards
+            .get(group)
+            .map(|rewards| u64::from(*rewards))
+            .ok_or(NCNProgramError::InvalidFeeGroup)
+    }
+
+    pub fn operator_vault_rewards(&self) -> u64 {
+        self.operator_vault_rewards.into()
+    }
+
+    // ----------------- ROUTE STATE TRACKING --------------
+
+    pub fn last_vote_index(&self) -> u16 {
+        self.last_vote_index.into()
+    }
+
+    pub fn last_rewards_to_process(&self) -> u64 {
+        self.last_rewards_to_process.into()
+    }
+
+    /// Resumes routing from the last saved state if routing was interrupted
+    /// Returns (starting_vote_index, rewards_to_process)
+    pub fn resume_routing_state(&self) -> (usize, u64) {
+        if !self.still_routing() {
+            return (0, 0);
+        }
+
+        (
+            self.last_vote_index() as usize,
+            self.last_rewards_to_process(),
+        )
+    }
+
+    /// Saves the current routing state for resumption if interrupted
+    pub fn save_routing_state(&mut self, vote_index: usize, rewards_to_process: u64) {
+        self.last_vote_index = PodU16::from(vote_index as u16);
+        self.last_rewards_to_process = PodU64::from(rewards_to_process);
+    }
+
+    /// Resets routing state to indicate no partial routing is in progress
+    pub fn reset_routing_state(&mut self) {
+        self.last_vote_index = PodU16::from(NCNRewardRouter::NO_LAST_VOTE_INDEX);
+        self.last_rewards_to_process = PodU64::from(NCNRewardRouter::NO_LAST_REWARDS_TO_PROCESS);
+    }
+
+    /// Checks if routing is still in progress (was interrupted)
+    pub fn still_routing(&self) -> bool {
+        self.last_vote_index() != NCNRewardRouter::NO_LAST_VOTE_INDEX
+            || self.last_rewards_to_process() != NCNRewardRouter::NO_LAST_REWARDS_TO_PROCESS
+    }
+
+    // ----------------- ROUTE REWARDS ---------------------
+
+    /// Calculates total rewards currently being processed (reward pool + processed)
+    pub fn total_rewards_in_transit(&self) -> Result<u64, NCNProgramError> {
+        self.reward_pool()
+            .checked_add(self.rewards_processed())
+            .ok_or(NCNProgramError::ArithmeticOverflow)
+    }
+
+    /// Routes incoming rewards from the token account balance to the reward pool
+    pub fn route_incoming_rewards(
+        &mut self,
+        token_account_balance: u64,
+    ) -> Result<(), NCNProgramError> {
+        let incoming_rewards = token_account_balance
+            .checked_sub(self.total_rewards_in_transit()?)
+            .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
+
+        self.route_to_reward_pool(incoming_rewards)
+    }
+
+    /// Adds rewards to the reward pool and updates total rewards counter
+    pub fn route_to_reward_pool(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
+        if rewards == 0 {
+            return Ok(());
+        }
+
+        self.total_rewards = PodU64::from(
+            self.total_rewards()
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        self.reward_pool = PodU64::from(
+            self.reward_pool()
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Takes the cranker fee out of the reward pool before the Protocol and NCN fees are split
+    /// Returns the fee, which leaves the reward receiver right away
+    pub fn route_cranker_fee(&mut self, cranker_fee_bps: u16) -> Result<u64, NCNProgramError> {
+        let cranker_fee =
+            NCNRewardRouter::calculate_reward_split(cranker_fee_bps, self.reward_pool())?;
+
+        self.reward_pool = PodU64::from(
+            self.reward_pool()
+                .checked_sub(cranker_fee)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(cranker_fee)
+    }
+
+    /// Routes rewards from the reward pool to Protocol, NCN and the custom fee groups based on
+    /// fee structure, the rest goes to operator-vault rewards
+    pub fn route_reward_pool(&mut self, fee: &Fees) -> Result<(), NCNProgramError> {
+        let rewards_to_process: u64 = self.reward_pool();
+
+        let protocol_fee =
+            NCNRewardRouter::calculate_reward_split(fee.protocol_fee_bps()?, rewards_to_process)?;
+        self.route_from_reward_pool(protocol_fee)?;
+        self.route_to_protocol(protocol_fee)?;
+
+        let ncn_fee =
+            NCNRewardRouter::calculate_reward_split(fee.ncn_fee_bps()?, rewards_to_process)?;
+        self.route_from_reward_pool(ncn_fee)?;
+        self.route_to_ncn(ncn_fee)?;
+
+        for group in 0..MAX_FEE_GROUPS {
+            let fee_group_fee = NCNRewardRouter::calculate_reward_split(
+                fee.fee_group_bps(group)?,
+                rewards_to_process,
+            )?;
+            self.route_from_reward_pool(fee_group_fee)?;
+            self.route_to_fee_group(group, fee_group_fee)?;
+        }
 
-    pub fn epoch(&self) -> u64 {
-        self.epoch.into()
-    }
+        let operator_vault_rewards = self.reward_pool();
+        self.route_from_reward_pool(operator_vault_rewards)?;
+        self.route_to_operator_vault(operator_vault_rewards)?;
 
-    pub fn slot_created(&self) -> u64 {
-        self.slot_created.into()
+        Ok(())
     }
 
     /// Moves rewards out of the reward pool and marks them as processed
@@ -535,9 +1193,7 @@ impl NCNRewardRouter {
                 .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
         );
 
-        self.increment_rewards_processed(rewards)?;
-
-        Ok(())
+        self.increment_rewards_processed(rewards)
     }
 
     /// Moves rewards out of operator vault rewards pool
@@ -545,10 +1201,6 @@ impl NCNRewardRouter {
         &mut self,
         rewards: u64,
     ) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.operator_vault_rewards = PodU64::from(
             self.operator_vault_rewards()
                 .checked_sub(rewards)
@@ -560,10 +1212,6 @@ impl NCNRewardRouter {
 
     /// Routes rewards to Protocol allocation
     pub fn route_to_protocol(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.protocol_rewards = PodU64::from(
             self.protocol_rewards()
                 .checked_add(rewards)
@@ -575,10 +1223,6 @@ impl NCNRewardRouter {
 
     /// Routes rewards to NCN allocation
     pub fn route_to_ncn(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.ncn_rewards = PodU64::from(
             self.ncn_rewards()
                 .checked_add(rewards)
@@ -588,12 +1232,27 @@ impl NCNRewardRouter {
         Ok(())
     }
 
+    /// Routes rewards to the allocation of a custom fee group
+    pub fn route_to_fee_group(
+        &mut self,
+        group: usize,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        let fee_group_rewards = self
+            .fee_group_rewards
+            .get_mut(group)
+            .ok_or(NCNProgramError::InvalidFeeGroup)?;
+        *fee_group_rewards = PodU64::from(
+            u64::from(*fee_group_rewards)
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
     /// Routes rewards to operator vault allocation
     pub fn route_to_operator_vault(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.operator_vault_rewards = PodU64::from(
             self.operator_vault_rewards()
                 .checked_add(rewards)
@@ -603,276 +1262,59 @@ impl NCNRewardRouter {
         Ok(())
     }
 
-    // ------------------ REWARDS PROCESSED ---------------------
-
-    pub fn rewards_processed(&self) -> u64 {
-        self.rewards_processed.into()
-    }
-
     /// Increments the counter of rewards that have been processed (moved out of reward pool)
     pub fn increment_rewards_processed(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.rewards_processed = PodU64::from(
             self.rewards_processed()
                 .checked_add(rewards)
                 .ok_or(NCNProgramError::ArithmeticOverflow)?,
         );
+
         Ok(())
     }
 
     /// Decrements the counter of rewards processed (when rewards are distributed)
     pub fn decrement_rewards_processed(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
         self.rewards_processed = PodU64::from(
             self.rewards_processed()
                 .checked_sub(rewards)
                 .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
         );
-        Ok(())
-    }
-
-    /// Distributes ncn rewards and updates counters
-    /// Returns the amount of rewards distributed
-    pub fn distribute_ncn_fee_rewards(&mut self) -> Result<u64, NCNProgramError> {
-        let rewards = self.ncn_rewards();
-        self.ncn_rewards = PodU64::from(
-            rewards
-                .checked_sub(rewards)
-                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
-        );
-
-        self.decrement_rewards_processed(rewards)?;
-
-        Ok(rewards)
-    }
 
-    pub fn protocol_rewards(&self) -> u64 {
-        self.protocol_rewards.into()
+        Ok(())
     }
 
     /// Distributes Protocol rewards and updates counters
     /// Returns the amount of rewards distributed
     pub fn distribute_protocol_fee_rewards(&mut self) -> Result<u64, NCNProgramError> {
         let rewards = self.protocol_rewards();
-        self.protocol_rewards = PodU64::from(
-            rewards
-                .checked_sub(rewards)
-                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
-        );
+        self.protocol_rewards = PodU64::from(0);
 
         self.decrement_rewards_processed(rewards)?;
 
         Ok(rewards)
     }
 
-    // ------------------ OPERATOR VAULT REWARD ROUTES ---------------------
-
-    /// Checks if an operator has a reward route
-    pub fn has_operator_vault_reward_route(&self, operator: &Pubkey) -> bool {
-        for operator_vault_route_reward in self.operator_vault_reward_routes().iter() {
-            if operator_vault_route_reward.operator.eq(operator) {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    /// Gets the reward route for a specific operator
-    pub fn oprtator_vault_reward_route(
-        &self,
-        operator: &Pubkey,
-    ) -> Result<&OperatorVaultRewardRoute, NCNProgramError> {
-        for operator_vault_route_reward in self.operator_vault_reward_routes().iter() {
-            if operator_vault_route_reward.operator.eq(operator) {
-                return Ok(operator_vault_route_reward);
-            }
-        }
-
-        Err(NCNProgramError::NcnRewardRouteNotFound)
-    }
-
-    /// Routes rewards to a specific operator's reward route
-    /// Creates a new route if one doesn't exist for the operator
-    pub fn route_to_operator_vault_reward_route(
-        &mut self,
-        operator: &Pubkey,
-        rewards: u64,
-    ) -> Result<(), NCNProgramError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
-        // Try to find existing route and increment rewards
-        for operator_vault_route_reward in self.operator_vault_reward_routes.iter_mut() {
-            if operator_vault_route_reward.operator.eq(operator) {
-                operator_vault_route_reward.increment_rewards(rewards)?;
-                return Ok(());
-            } else if operator_vault_route_reward.operator.eq(&Pubkey::default()) {
-                // Found empty slot, create new route
-                *operator_vault_route_reward = OperatorVaultRewardRoute::new(operator, rewards)?;
-                return Ok(());
-            }
-        }
-
-        Err(NCNProgramError::OperatorRewardListFull)
-    }
-
-    /// Distributes rewards for a specific operator and updates counters
+    /// Distributes NCN rewards and updates counters
     /// Returns the amount of rewards distributed
-    pub fn distribute_operator_vault_reward_route(
-        &mut self,
-        operator: &Pubkey,
-    ) -> Result<u64, NCNProgramError> {
-        for route in self.operator_vault_reward_routes.iter_mut() {
-            if route.operator.eq(operator) {
-                let rewards = route.rewards()?;
-                route.decrement_rewards(rewards)?;
-                self.decrement_rewards_processed(rewards)?;
-
-                return Ok(rewards);
-            }
-        }
-
-        Err(NCNProgramError::OperatorRewardNotFound)
-    }
-}
-
-#[rustfmt::skip]
-impl fmt::Display for NCNRewardRouter {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "\n\n----------- NCN Reward Router -------------")?;
-        writeln!(f, "  NCN:                          {}", self.ncn)?;
-        writeln!(f, "  Epoch:                        {}", self.epoch())?;
-        writeln!(f, "  Bump:                         {}", self.bump)?;
-        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
-        writeln!(f, "  Still Routing:                {}", self.still_routing())?;
-        writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
-        writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
-        writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
-
-        if self.still_routing() {
-            writeln!(f, "\nRouting State:")?;
-            writeln!(f, "  Last Vote Index:              {}", self.last_vote_index())?;
-            writeln!(f, "  Last Rewards to Process:      {}", self.last_rewards_to_process())?;
-        }
-
-        writeln!(f, "\nRewards:")?;
-        writeln!(f, "  Protocol Rewards:             {}", self.protocol_rewards())?;
-        writeln!(f, "  NCN Rewards:                  {}", self.ncn_rewards())?;
-        writeln!(
-            f,
-            "  Operator Vault Rewards:       {}",
-            self.operator_vault_rewards()
-        )?;
-
-        writeln!(f, "\nOperator Vault Reward Routes:")?;
-        for route in self.operator_vault_reward_routes().iter() {
-            if !route.is_empty() {
-                writeln!(f, "  Operator:                     {}", route.operator())?;
-                if let Ok(rewards) = route.rewards() {
-                    if rewards > 0 {
-                        writeln!(f, "    Rewards:                      {}", rewards)?;
-                    }
-                }
-            }
-        }
-
-        writeln!(f, "\n")?;
-        Ok(())
-    }
-}
-
-/// Individual operator reward route - tracks rewards for a specific operator
-/// This struct stores the allocation of rewards for an operator before distribution
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
-#[repr(C)]
-pub struct OperatorVaultRewardRoute {
-    /// The operator pubkey
-    operator: Pubkey,
-    /// Reward amount allocated to this operator
-    rewards: NCNRewardRouterRewards,
-}
-
-impl Default for OperatorVaultRewardRoute {
-    fn default() -> Self {
-        Self {
-            operator: Pubkey::default(),
-            rewards: NCNRewardRouterRewards::default(),
-        }
-    }
-}
-
-impl OperatorVaultRewardRoute {
-    /// Creates a new operator vault reward route with initial reward amount
-    pub fn new(operator: &Pubkey, rewards: u64) -> Result<Self, NCNProgramError> {
-        let mut route = Self {
-            operator: *operator,
-            rewards: NCNRewardRouterRewards::default(),
-        };
-
-        route.set_rewards(rewards)?;
-
-        Ok(route)
-    }
-
-    /// Gets the operator pubkey for this route
-    pub const fn operator(&self) -> &Pubkey {
-        &self.operator
-    }
-
-    /// Gets the reward amount for this route
-    pub fn rewards(&self) -> Result<u64, NCNProgramError> {
-        Ok(self.rewards.rewards())
-    }
-
-    /// Checks if this route slot is empty (default operator)
-    pub fn is_empty(&self) -> bool {
-        self.operator.eq(&Pubkey::default())
-    }
-
-    /// Checks if this route has any rewards allocated
-    pub fn has_rewards(&self) -> Result<bool, NCNProgramError> {
-        if self.rewards()? > 0 {
-            return Ok(true);
-        }
-
-        Ok(false)
-    }
-
-    /// Sets the reward amount for this route
-    fn set_rewards(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        self.rewards.rewards = PodU64::from(rewards);
-
-        Ok(())
-    }
-
-    /// Adds rewards to this route
-    pub fn increment_rewards(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        let current_rewards = self.rewards()?;
+    pub fn distribute_ncn_fee_rewards(&mut self) -> Result<u64, NCNProgramError> {
+        let rewards = self.ncn_rewards();
+        self.ncn_rewards = PodU64::from(0);
 
-        let new_rewards = current_rewards
-            .checked_add(rewards)
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        self.decrement_rewards_processed(rewards)?;
 
-        self.set_rewards(new_rewards)
+        Ok(rewards)
     }
 
-    /// Removes rewards from this route (used during distribution)
-    pub fn decrement_rewards(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
-        let current_rewards = self.rewards()?;
+    /// Distributes the rewards of a custom fee group and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_fee_group_rewards(&mut self, group: usize) -> Result<u64, NCNProgramError> {
+        let rewards = self.fee_group_rewards(group)?;
+        self.fee_group_rewards[group] = PodU64::from(0);
 
-        let new_rewards = current_rewards
-            .checked_sub(rewards)
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        self.decrement_rewards_processed(rewards)?;
 
-        self.set_rewards(new_rewards)
+        Ok(rewards)
     }
 }
 
@@ -888,11 +1330,7 @@ pub struct NCNRewardReceiver {}
 impl NCNRewardReceiver {
     /// Generates PDA seeds for the NCN reward receiver
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        vec![
-            b"ncn_reward_receiver".to_vec(),
-            ncn.to_bytes().to_vec(),
-            epoch.to_le_bytes().to_vec(),
-        ]
+        NCNProgramPda::NCNRewardReceiver { ncn: *ncn, epoch }.seeds()
     }
 
     /// Finds the program address for the NCN reward receiver PDA
@@ -901,16 +1339,12 @@ impl NCNRewardReceiver {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let (address, bump) = Pubkey::find_program_address(
-            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
-            program_id,
-        );
-        (address, bump, seeds)
+        NCNProgramPda::NCNRewardReceiver { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
     /// Validates that the account is owned by system program and matches expected PDA
     /// NCN reward receiver accounts are owned by system program, not the NCN program
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -930,6 +1364,7 @@ impl NCNRewardReceiver {
     }
 
     /// Loads the account for closing (must be writable)
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -944,6 +1379,7 @@ impl NCNRewardReceiver {
     /// This function transfers all lamports above minimum rent to the DAO wallet,
     /// then transfers the minimum rent to the account payer (for rent recovery).
     #[inline(always)]
+    #[cfg(feature = "program")]
     pub fn close<'a, 'info>(
         program_id: &Pubkey,
         ncn: &Pubkey,
@@ -978,8 +1414,72 @@ impl NCNRewardReceiver {
         )
     }
 
+    /// Finds the associated token account of the NCN reward receiver for a reward mint
+    pub fn find_token_account_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        mint: &Pubkey,
+    ) -> Pubkey {
+        let ncn_reward_receiver = Self::find_program_address(program_id, ncn, epoch).0;
+        get_associated_token_address(&ncn_reward_receiver, mint)
+    }
+
+    /// Transfers tokens out of the NCN reward receiver's token account using PDA authority
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    #[cfg(feature = "program")]
+    pub fn transfer_tokens<'a, 'info>(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_reward_receiver: &'a AccountInfo<'info>,
+        ncn_reward_receiver_token_account: &'a AccountInfo<'info>,
+        to_token_account: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        let (ncn_reward_receiver_address, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
+            Self::find_program_address(program_id, ncn, epoch);
+        ncn_reward_receiver_seeds.push(vec![ncn_reward_receiver_bump]);
+
+        if ncn_reward_receiver_address.ne(ncn_reward_receiver.key) {
+            msg!("Incorrect NCN reward receiver PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if token_program.key.ne(&spl_token::id()) {
+            msg!("Incorrect token program");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                ncn_reward_receiver_token_account.key,
+                to_token_account.key,
+                &ncn_reward_receiver_address,
+                &[],
+                amount,
+            )?,
+            &[
+                ncn_reward_receiver_token_account.clone(),
+                to_token_account.clone(),
+                ncn_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[ncn_reward_receiver_seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+        Ok(())
+    }
+
     /// Transfers lamports from the NCN reward receiver using PDA authority
     #[inline(always)]
+    #[cfg(feature = "program")]
     pub fn transfer<'a, 'info>(
         program_id: &Pubkey,
         ncn: &Pubkey,
@@ -1029,6 +1529,7 @@ impl NCNRewardRouterRewards {
 #[cfg(test)]
 mod tests {
     use solana_program::pubkey::Pubkey;
+    use spl_math::precise_number::PreciseNumber;
 
     use super::*;
     use crate::{
@@ -1068,16 +1569,14 @@ mod tests {
 
     pub fn get_test_operators(ballot_box: &BallotBox) -> Vec<Pubkey> {
         ballot_box
-            .operator_votes()
-            .iter()
-            .filter(|vote| !vote.is_empty())
+            .iter_votes()
             .map(|votes| *votes.operator())
             .collect()
     }
 
     pub fn get_test_total_stake_weights(ballot_box: &BallotBox) -> StakeWeights {
         let mut total_stake_weights = StakeWeights::default();
-        for vote in ballot_box.operator_votes() {
+        for vote in ballot_box.iter_votes() {
             total_stake_weights.increment(vote.stake_weights()).unwrap();
         }
 
@@ -1086,26 +1585,188 @@ mod tests {
 
     #[test]
     fn test_len() {
-        use std::mem::size_of;
+        use std::mem::{offset_of, size_of};
 
         let expected_total = size_of::<Pubkey>() // ncn
             + size_of::<PodU64>() // epoch
             + 1 // bump
             + size_of::<PodU64>() // slot_created
-            + size_of::<PodU64>() // total_rewards
-            + size_of::<PodU64>() // reward_pool
-            + size_of::<PodU64>() // rewards_processed
-            + 128 // reserved
-            + size_of::<PodU16>() // last_vote_index
-            + size_of::<PodU64>() // last_rewards_to_process
-            + size_of::<PodU64>() // protocol_rewards
-            + size_of::<PodU64>() // ncn_rewards
-            + size_of::<PodU64>() // operator_vault_rewards
+            + 96 // reserved
+            + size_of::<NCNMintRewards>() * MAX_REWARD_ENTRIES // rewards
             + size_of::<OperatorVaultRewardRoute>() * 256; // operator_vault_reward_routes
 
         assert_eq!(size_of::<NCNRewardRouter>(), expected_total);
     }
 
+    #[test]
+    fn test_register_mint_rewards() {
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        let mint = Pubkey::new_unique();
+        assert_ncn_program_error(
+            router.mint_rewards_index(&mint),
+            NCNProgramError::RewardMintNotFound,
+        );
+        assert!(!router.still_routing_mint(&mint));
+
+        // SOL is the first entry, the reward mints follow it
+        assert_eq!(router.register_mint_rewards(&mint).unwrap(), 1);
+        assert_eq!(router.register_mint_rewards(&mint).unwrap(), 1);
+
+        for index in 2..MAX_REWARD_ENTRIES {
+            let index_registered = router.register_mint_rewards(&Pubkey::new_unique()).unwrap();
+            assert_eq!(index_registered, index);
+        }
+
+        assert_ncn_program_error(
+            router.register_mint_rewards(&Pubkey::new_unique()),
+            NCNProgramError::RewardMintListFull,
+        );
+        assert_ncn_program_error(
+            router.register_mint_rewards(&Pubkey::default()),
+            NCNProgramError::InvalidRewardMint,
+        );
+    }
+
+    #[test]
+    fn test_route_mint_rewards() {
+        const INCOMING_REWARDS: u64 = 100_000;
+        const NUM_OPERATORS: u64 = 10;
+
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        let (ballot_box, operators) = {
+            let mut ballot_box = get_test_ballot_box();
+            for _ in 0..NUM_OPERATORS {
+                cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
+            }
+            let operators = get_test_operators(&ballot_box);
+            let total_stake_weights = get_test_total_stake_weights(&ballot_box);
+            ballot_box
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
+                .unwrap();
+            (ballot_box, operators)
+        };
+
+        let mint = Pubkey::new_unique();
+        let fees = Fees::new(400, 1).unwrap();
+
+        router
+            .route_incoming_mint_rewards(&mint, INCOMING_REWARDS)
+            .unwrap();
+        router.route_mint_reward_pool(&mint, &fees).unwrap();
+        router
+            .route_operator_vault_mint_rewards(&mint, &ballot_box, 0, 0, 100)
+            .unwrap();
+
+        // SOL accounting is untouched
+        assert_eq!(router.total_rewards(), 0);
+        assert_eq!(router.protocol_rewards(), 0);
+
+        let mint_rewards = *router.get_mint_rewards(&mint).unwrap();
+        assert_eq!(mint_rewards.total_rewards(), INCOMING_REWARDS);
+        assert_eq!(mint_rewards.reward_pool(), 0);
+        assert_eq!(mint_rewards.rewards_processed(), INCOMING_REWARDS);
+        assert_eq!(mint_rewards.protocol_rewards(), 4_000);
+        assert_eq!(mint_rewards.ncn_rewards(), 4_000);
+        assert_eq!(mint_rewards.operator_vault_rewards(), 0);
+        assert!(!mint_rewards.still_routing());
+
+        for operator in operators.iter() {
+            let route = router.oprtator_vault_reward_route(operator).unwrap();
+            assert_eq!(route.rewards().unwrap(), 0);
+            assert_eq!(route.rewards_of(1), 92_000 / NUM_OPERATORS);
+        }
+
+        // Balance is already accounted for, nothing new to route
+        router
+            .route_incoming_mint_rewards(&mint, INCOMING_REWARDS)
+            .unwrap();
+        assert_eq!(
+            router.get_mint_rewards(&mint).unwrap().total_rewards(),
+            INCOMING_REWARDS
+        );
+
+        let rewards = router
+            .distribute_operator_vault_mint_reward_route(&mint, &operators[0])
+            .unwrap();
+        assert_eq!(rewards, 92_000 / NUM_OPERATORS);
+        assert_eq!(
+            router
+                .oprtator_vault_reward_route(&operators[0])
+                .unwrap()
+                .rewards_of(1),
+            0
+        );
+
+        assert_eq!(
+            router.distribute_protocol_fee_mint_rewards(&mint).unwrap(),
+            4_000
+        );
+        assert_eq!(
+            router.distribute_ncn_fee_mint_rewards(&mint).unwrap(),
+            4_000
+        );
+        assert_eq!(
+            router.get_mint_rewards(&mint).unwrap().rewards_processed(),
+            INCOMING_REWARDS - 8_000 - 92_000 / NUM_OPERATORS
+        );
+    }
+
+    #[test]
+    fn test_route_mint_rewards_with_interruption() {
+        const INCOMING_REWARDS: u64 = 1000;
+        const NUM_OPERATORS: u64 = 10;
+
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        let ballot_box = {
+            let mut ballot_box = get_test_ballot_box();
+            for _ in 0..NUM_OPERATORS {
+                cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
+            }
+            let total_stake_weights = get_test_total_stake_weights(&ballot_box);
+            ballot_box
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
+                .unwrap();
+            ballot_box
+        };
+
+        let mint = Pubkey::new_unique();
+        router
+            .route_incoming_mint_rewards(&mint, INCOMING_REWARDS)
+            .unwrap();
+        router
+            .route_mint_reward_pool(&mint, &Fees::new(0, 1).unwrap())
+            .unwrap();
+
+        router
+            .route_operator_vault_mint_rewards(&mint, &ballot_box, 0, 0, 5)
+            .unwrap();
+        assert!(router.still_routing_mint(&mint));
+        assert!(!router.still_routing());
+
+        router
+            .route_operator_vault_mint_rewards(&mint, &ballot_box, 0, 0, 5)
+            .unwrap();
+        assert!(!router.still_routing_mint(&mint));
+    }
+
     #[test]
     fn test_operator() {
         // Test case 1: Default operator (zero pubkey)
@@ -1132,7 +1793,8 @@ mod tests {
         let initial_rewards = router.rewards_processed();
 
         // Try to increment by 0
-        let result = router.increment_rewards_processed(0);
+        let result =
+            router.rewards[NCNRewardRouter::SOL_REWARDS_INDEX].increment_rewards_processed(0);
 
         // Verify operation succeeded
         assert!(result.is_ok());
@@ -1246,6 +1908,39 @@ mod tests {
         assert_eq!(router.ncn_rewards(), 10);
     }
 
+    #[test]
+    fn test_route_cranker_fee() {
+        const INCOMING_REWARDS: u64 = 10_000;
+
+        let mut router = NCNRewardRouter::new(&Pubkey::new_unique(), 1, 1, 100);
+        let fees = Fees::new(100, 1).unwrap();
+
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+
+        let cranker_fee = router.route_cranker_fee(50).unwrap();
+        assert_eq!(cranker_fee, 50); // 0.5% of 10_000
+        assert_eq!(router.total_rewards(), INCOMING_REWARDS);
+        assert_eq!(router.reward_pool(), INCOMING_REWARDS - cranker_fee);
+
+        // The fee leaves the receiver, so the next balance is lower by the fee
+        router
+            .route_incoming_rewards(0, INCOMING_REWARDS - cranker_fee)
+            .unwrap();
+        assert_eq!(router.total_rewards(), INCOMING_REWARDS);
+
+        router.route_reward_pool(&fees).unwrap();
+        assert_eq!(router.reward_pool(), 0);
+        assert_eq!(router.protocol_rewards(), 398); // 4% of 9_950
+        assert_eq!(router.ncn_rewards(), 99); // 1% of 9_950
+        assert_eq!(
+            router.protocol_rewards() + router.ncn_rewards() + router.operator_vault_rewards(),
+            INCOMING_REWARDS - cranker_fee
+        );
+
+        // No fee is taken when it is not configured
+        assert_eq!(router.route_cranker_fee(0).unwrap(), 0);
+    }
+
     #[test]
     fn test_route_reward_pool_remainder() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1276,6 +1971,64 @@ mod tests {
         assert_eq!(router.operator_vault_rewards(), 950);
     }
 
+    #[test]
+    fn test_route_reward_pool_with_fee_groups() {
+        const INCOMING_REWARDS: u64 = 1000;
+
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        // Fees
+        let mut fees = Fees::new(100, 1).unwrap();
+        fees.set_fee_group_bps(0, 250).unwrap();
+        fees.set_fee_group_bps(2, 50).unwrap();
+
+        // SOL rewards
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+        router.route_reward_pool(&fees).unwrap();
+
+        assert_eq!(router.reward_pool(), 0);
+        assert_eq!(router.protocol_rewards(), 40); // 4% of 1000
+        assert_eq!(router.ncn_rewards(), 10);
+        assert_eq!(router.fee_group_rewards(0).unwrap(), 25);
+        assert_eq!(router.fee_group_rewards(1).unwrap(), 0);
+        assert_eq!(router.fee_group_rewards(2).unwrap(), 5);
+        assert_eq!(router.operator_vault_rewards(), 920);
+
+        assert_eq!(router.distribute_fee_group_rewards(0).unwrap(), 25);
+        assert_eq!(router.fee_group_rewards(0).unwrap(), 0);
+        assert_eq!(router.rewards_processed(), INCOMING_REWARDS - 25);
+        assert_ncn_program_error(
+            router.distribute_fee_group_rewards(MAX_FEE_GROUPS),
+            NCNProgramError::InvalidFeeGroup,
+        );
+
+        // Mint rewards are split the same way
+        let mint = Pubkey::new_unique();
+        router
+            .route_incoming_mint_rewards(&mint, INCOMING_REWARDS)
+            .unwrap();
+        router.route_mint_reward_pool(&mint, &fees).unwrap();
+
+        let mint_rewards = *router.get_mint_rewards(&mint).unwrap();
+        assert_eq!(mint_rewards.fee_group_rewards(0).unwrap(), 25);
+        assert_eq!(mint_rewards.fee_group_rewards(2).unwrap(), 5);
+        assert_eq!(mint_rewards.operator_vault_rewards(), 920);
+
+        assert_eq!(
+            router.distribute_fee_group_mint_rewards(&mint, 2).unwrap(),
+            5
+        );
+        assert_eq!(
+            router.get_mint_rewards(&mint).unwrap().rewards_processed(),
+            INCOMING_REWARDS - 5
+        );
+    }
+
     #[test]
     fn test_rounding() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1306,6 +2059,59 @@ mod tests {
         assert_eq!(router.operator_vault_rewards(), 959);
     }
 
+    /// The `PreciseNumber` implementation the routing math used to run on
+    fn precise_proportion(amount: u64, numerator: u128, denominator: u128) -> u64 {
+        let precise_amount = PreciseNumber::new(amount as u128).unwrap();
+        let precise_numerator = PreciseNumber::new(numerator).unwrap();
+        let precise_denominator = PreciseNumber::new(denominator).unwrap();
+
+        let result: u128 = precise_amount
+            .checked_mul(&precise_numerator)
+            .and_then(|x| x.checked_div(&precise_denominator))
+            .and_then(|x| x.floor())
+            .and_then(|x| x.to_imprecise())
+            .unwrap();
+
+        result.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_calculate_reward_split_matches_precise_number() {
+        let rewards = [
+            1,
+            7,
+            999,
+            1_000,
+            123_456_789,
+            1_000_000_000_000,
+            u64::MAX / 10_000,
+        ];
+        let fee_bps = [1, 3, 19, 400, 2_500, 9_999, 10_000];
+
+        for total_rewards in rewards {
+            for fee_basis_points in fee_bps {
+                assert_eq!(
+                    NCNRewardRouter::calculate_reward_split(fee_basis_points, total_rewards)
+                        .unwrap(),
+                    precise_proportion(total_rewards, fee_basis_points as u128, 10_000),
+                    "fee_bps: {}, rewards: {}",
+                    fee_basis_points,
+                    total_rewards
+                );
+            }
+        }
+
+        assert_eq!(
+            NCNRewardRouter::calculate_reward_split(10_000, u64::MAX).unwrap(),
+            u64::MAX
+        );
+        assert_eq!(
+            NCNRewardRouter::calculate_reward_split(0, 1_000).unwrap(),
+            0
+        );
+        assert_eq!(NCNRewardRouter::calculate_reward_split(400, 0).unwrap(), 0);
+    }
+
     #[test]
     fn test_route_to_operators_consensus_not_reached() {
         let mut router = NCNRewardRouter::new(
@@ -1317,7 +2123,7 @@ mod tests {
         router.operator_vault_rewards = PodU64::from(100);
 
         let ballot_box = get_test_ballot_box();
-        let result = router.route_operator_vault_rewards(&ballot_box, 100);
+        let result = router.route_operator_vault_rewards(&ballot_box, 0, 0, 100);
 
         assert_ncn_program_error(result, NCNProgramError::ConsensusNotReached);
     }
@@ -1343,23 +2149,158 @@ mod tests {
             let operators = get_test_operators(&ballot_box);
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
                 .unwrap();
             (ballot_box, operators)
         };
 
         router
-            .route_operator_vault_rewards(&ballot_box, 100)
+            .route_operator_vault_rewards(&ballot_box, 0, 0, 100)
             .unwrap();
 
         for operator in operators.iter() {
             let route = router.oprtator_vault_reward_route(operator).unwrap();
             assert_eq!(route.rewards().unwrap(), INCOMING_REWARDS / NUM_OPERATORS);
         }
+        assert_eq!(
+            router
+                .iter_routes()
+                .map(|route| *route.operator())
+                .collect::<Vec<_>>(),
+            operators
+        );
         // remainder goes to ncn
         assert_eq!(router.ncn_rewards(), 0);
     }
 
+    #[test]
+    fn test_shrink() {
+        const INCOMING_REWARDS: u64 = 1000;
+        const NUM_OPERATORS: usize = 10;
+
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+        router
+            .route_reward_pool(&Fees::new(400, 400).unwrap())
+            .unwrap();
+
+        let (ballot_box, operators) = {
+            let mut ballot_box = get_test_ballot_box();
+            for _ in 0..NUM_OPERATORS {
+                cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
+            }
+            let operators = get_test_operators(&ballot_box);
+            let total_stake_weights = get_test_total_stake_weights(&ballot_box);
+            ballot_box
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
+                .unwrap();
+            (ballot_box, operators)
+        };
+        router
+            .route_operator_vault_rewards(&ballot_box, 0, 0, 100)
+            .unwrap();
+
+        // Rewards left to distribute
+        assert!(!router.can_shrink().unwrap());
+
+        router.distribute_protocol_fee_rewards().unwrap();
+        router.distribute_ncn_fee_rewards().unwrap();
+        for operator in operators.iter() {
+            router
+                .distribute_operator_vault_reward_route(operator)
+                .unwrap();
+        }
+        assert!(router.can_shrink().unwrap());
+
+        let route_count = router.compact_operator_vault_reward_routes();
+        assert_eq!(route_count, NUM_OPERATORS);
+
+        let shrunk_size = NCNRewardRouter::shrunk_size(route_count);
+        assert_eq!(
+            NCNRewardRouter::SIZE - shrunk_size,
+            (MAX_OPERATORS - NUM_OPERATORS) * size_of::<OperatorVaultRewardRoute>()
+        );
+
+        let mut data = vec![0; NCNRewardRouter::SIZE];
+        data[0] = NCNRewardRouter::DISCRIMINATOR;
+        data[8..].copy_from_slice(bytemuck::bytes_of(&router));
+        data.truncate(shrunk_size);
+
+        let shrunk_router = NCNRewardRouter::try_from_shrunk_slice(&data).unwrap();
+        assert_eq!(shrunk_router.total_rewards(), INCOMING_REWARDS);
+        for operator in operators.iter() {
+            assert!(shrunk_router.has_operator_vault_reward_route(operator));
+        }
+    }
+
+    #[test]
+    fn test_route_to_operators_with_timeliness_bonus() {
+        const INCOMING_REWARDS: u64 = 1000;
+        const TIMELINESS_BONUS_SLOTS: u64 = 10;
+        const TIMELINESS_BONUS_BPS: u16 = 1000;
+
+        let mut router = NCNRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+        router.operator_vault_rewards = PodU64::from(INCOMING_REWARDS);
+
+        let timely_operator = Pubkey::new_unique();
+        let late_operator = Pubkey::new_unique();
+
+        let mut ballot_box = get_test_ballot_box();
+        let ballot = Ballot::new(WeatherStatus::Sunny as u8);
+        let stake_weights = StakeWeights::snapshot(200).unwrap();
+        ballot_box
+            .cast_vote(
+                &timely_operator,
+                &ballot,
+                &stake_weights,
+                TEST_CURRENT_SLOT + TIMELINESS_BONUS_SLOTS - 1,
+                TEST_VALID_SLOTS_AFTER_CONSENSUS,
+            )
+            .unwrap();
+        ballot_box
+            .cast_vote(
+                &late_operator,
+                &ballot,
+                &stake_weights,
+                TEST_CURRENT_SLOT + TIMELINESS_BONUS_SLOTS,
+                TEST_VALID_SLOTS_AFTER_CONSENSUS,
+            )
+            .unwrap();
+        let total_stake_weights = get_test_total_stake_weights(&ballot_box);
+        ballot_box
+            .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
+            .unwrap();
+
+        router
+            .route_operator_vault_rewards(
+                &ballot_box,
+                TIMELINESS_BONUS_SLOTS,
+                TIMELINESS_BONUS_BPS,
+                100,
+            )
+            .unwrap();
+
+        // 220 / 420 and 200 / 420 of the rewards
+        let timely_route = router
+            .oprtator_vault_reward_route(&timely_operator)
+            .unwrap();
+        assert_eq!(timely_route.rewards().unwrap(), 523);
+        let late_route = router.oprtator_vault_reward_route(&late_operator).unwrap();
+        assert_eq!(late_route.rewards().unwrap(), 476);
+        // remainder goes to ncn
+        assert_eq!(router.ncn_rewards(), 1);
+    }
+
     #[test]
     fn test_route_to_operators_with_wrong_vote() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1385,13 +2326,13 @@ mod tests {
             let operators = get_test_operators(&ballot_box);
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
                 .unwrap();
             (ballot_box, operators)
         };
 
         router
-            .route_operator_vault_rewards(&ballot_box, 100)
+            .route_operator_vault_rewards(&ballot_box, 0, 0, 100)
             .unwrap();
 
         let winning_ballot = ballot_box.get_winning_ballot_tally().unwrap();
@@ -1418,7 +2359,7 @@ mod tests {
 
     #[test]
     fn test_route_to_max_operators() {
-        const INCOMING_REWARDS: u64 = 256_000;
+        const INCOMING_REWARDS: u64 = MAX_OPERATORS as u64 * 1_000;
 
         let mut router = NCNRewardRouter::new(
             &Pubkey::new_unique(), // ncn
@@ -1432,21 +2373,21 @@ mod tests {
         let (ballot_box, operators) = {
             let mut ballot_box = get_test_ballot_box();
 
-            for _ in 0..256 {
+            for _ in 0..MAX_OPERATORS {
                 cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
             }
 
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
         router
-            .route_operator_vault_rewards(&ballot_box, 1000)
+            .route_operator_vault_rewards(&ballot_box, 0, 0, MAX_OPERATORS as u16)
             .unwrap();
 
         assert!(!router.still_routing());
@@ -1460,7 +2401,7 @@ mod tests {
 
     #[test]
     fn test_route_with_interruption() {
-        const INCOMING_REWARDS: u64 = 256_000;
+        const INCOMING_REWARDS: u64 = MAX_OPERATORS as u64 * 1_000;
 
         let mut router = NCNRewardRouter::new(
             &Pubkey::new_unique(), // ncn
@@ -1473,27 +2414,29 @@ mod tests {
         let (ballot_box, operators) = {
             let mut ballot_box = get_test_ballot_box();
 
-            for _ in 0..256 {
+            for _ in 0..MAX_OPERATORS {
                 cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
             }
 
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
-        assert_eq!(operators.len(), 256);
+        assert_eq!(operators.len(), MAX_OPERATORS);
 
-        router.route_operator_vault_rewards(&ballot_box, 5).unwrap();
+        router
+            .route_operator_vault_rewards(&ballot_box, 0, 0, 5)
+            .unwrap();
 
         assert!(router.still_routing());
 
         router
-            .route_operator_vault_rewards(&ballot_box, 256 * 8)
+            .route_operator_vault_rewards(&ballot_box, 0, 0, MAX_OPERATORS as u16 * 8)
             .unwrap();
 
         assert!(!router.still_routing());
@@ -1506,7 +2449,7 @@ mod tests {
 
     #[test]
     fn test_route_with_0_iterations() {
-        const INCOMING_REWARDS: u64 = 256_000;
+        const INCOMING_REWARDS: u64 = MAX_OPERATORS as u64 * 1_000;
 
         let mut router = NCNRewardRouter::new(
             &Pubkey::new_unique(), // ncn
@@ -1519,27 +2462,31 @@ mod tests {
         let (ballot_box, operators) = {
             let mut ballot_box = get_test_ballot_box();
 
-            for _ in 0..256 {
+            for _ in 0..MAX_OPERATORS {
                 cast_test_vote(&mut ballot_box, 200, WeatherStatus::Sunny as u8);
             }
 
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(&total_stake_weights, TEST_CURRENT_SLOT)
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
-        assert_eq!(operators.len(), 256);
+        assert_eq!(operators.len(), MAX_OPERATORS);
 
-        router.route_operator_vault_rewards(&ballot_box, 0).unwrap();
+        router
+            .route_operator_vault_rewards(&ballot_box, 0, 0, 0)
+            .unwrap();
 
         assert!(router.still_routing());
 
-        for _ in 0..256 {
-            router.route_operator_vault_rewards(&ballot_box, 0).unwrap();
+        for _ in 0..MAX_OPERATORS {
+            router
+                .route_operator_vault_rewards(&ballot_box, 0, 0, 0)
+                .unwrap();
         }
 
         assert!(!router.still_routing());
@@ -0,0 +1,257 @@
+use core::fmt;
+use std::mem::{offset_of, size_of};
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
+use crate::{
+    discriminators::Discriminators,
+    error::NCNProgramError,
+    pdas::NCNProgramPda,
+    utils::{is_valid_padded_str, padded_str},
+};
+
+/// Identity an operator publishes for an NCN, set by the operator admin so clients can display the
+/// operator instead of its raw pubkey
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorMetadata {
+    /// The operator the metadata belongs to
+    operator: Pubkey,
+    /// The NCN the metadata is published for
+    ncn: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// UTF-8 display name, padded with zeros
+    name: [u8; 32],
+    /// UTF-8 website, padded with zeros
+    website: [u8; 64],
+    /// Hash of the operator's contact information, all zeros if unset
+    contact_hash: [u8; 32],
+    /// The slot the metadata was last updated in
+    slot_updated: PodU64,
+    /// Reserved space
+    reserved: [u8; 128],
+}
+
+impl Discriminator for OperatorMetadata {
+    const DISCRIMINATOR: u8 = Discriminators::OperatorMetadata as u8;
+}
+
+impl OperatorMetadata {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const OPERATOR_OFFSET: usize = 8 + offset_of!(Self, operator);
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+
+    pub const NAME_LEN: usize = 32;
+    pub const WEBSITE_LEN: usize = 64;
+    pub const CONTACT_HASH_LEN: usize = 32;
+
+    pub fn new(operator: &Pubkey, ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            operator: *operator,
+            ncn: *ncn,
+            bump,
+            name: [0; Self::NAME_LEN],
+            website: [0; Self::WEBSITE_LEN],
+            contact_hash: [0; Self::CONTACT_HASH_LEN],
+            slot_updated: PodU64::from(0),
+            reserved: [0; 128],
+        }
+    }
+
+    pub fn initialize(&mut self, operator: &Pubkey, ncn: &Pubkey, bump: u8) {
+        *self = Self::new(operator, ncn, bump);
+    }
+
+    pub fn seeds(operator: &Pubkey, ncn: &Pubkey) -> Vec<Vec<u8>> {
+        NCNProgramPda::OperatorMetadata {
+            operator: *operator,
+            ncn: *ncn,
+        }
+        .seeds()
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        NCNProgramPda::OperatorMetadata {
+            operator: *operator,
+            ncn: *ncn,
+        }
+        .find_program_address(program_id)
+    }
+
+    #[cfg(feature = "program")]
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, operator, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn name(&self) -> &[u8; 32] {
+        &self.name
+    }
+
+    /// The name without its zero padding, empty if unset
+    pub fn name_str(&self) -> &str {
+        padded_str(&self.name)
+    }
+
+    pub const fn website(&self) -> &[u8; 64] {
+        &self.website
+    }
+
+    /// The website without its zero padding, empty if unset
+    pub fn website_str(&self) -> &str {
+        padded_str(&self.website)
+    }
+
+    pub const fn contact_hash(&self) -> &[u8; 32] {
+        &self.contact_hash
+    }
+
+    pub fn has_contact_hash(&self) -> bool {
+        self.contact_hash.iter().any(|byte| *byte != 0)
+    }
+
+    pub fn slot_updated(&self) -> u64 {
+        self.slot_updated.into()
+    }
+
+    /// Replaces the metadata, the name and website have to be UTF-8 padded with zeros
+    pub fn set_metadata(
+        &mut self,
+        name: &[u8; 32],
+        website: &[u8; 64],
+        contact_hash: &[u8; 32],
+        current_slot: u64,
+    ) -> Result<(), NCNProgramError> {
+        if !is_valid_padded_str(name) || !is_valid_padded_str(website) {
+            return Err(NCNProgramError::InvalidOperatorMetadata);
+        }
+
+        self.name = *name;
+        self.website = *website;
+        self.contact_hash = *contact_hash;
+        self.slot_updated = PodU64::from(current_slot);
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for OperatorMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Operator Metadata -------------")?;
+        writeln!(f, "  Operator:                     {}", self.operator)?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Name:                         {}", self.name_str())?;
+        writeln!(f, "  Website:                      {}", self.website_str())?;
+        if self.has_contact_hash() {
+            write!(f, "  Contact Hash:                 ")?;
+            for byte in self.contact_hash.iter() {
+                write!(f, "{:02x}", byte)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "  Slot Updated:                 {}", self.slot_updated())?;
+        writeln!(f, "\n")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // operator
+            + size_of::<Pubkey>() // ncn
+            + 1 // bump
+            + 32 // name
+            + 64 // website
+            + 32 // contact_hash
+            + size_of::<PodU64>() // slot_updated
+            + 128; // reserved
+
+        assert_eq!(size_of::<OperatorMetadata>(), expected_total);
+        assert_eq!(OperatorMetadata::SIZE, expected_total + 8);
+    }
+
+    #[test]
+    fn test_set_metadata() {
+        let operator = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let mut operator_metadata = OperatorMetadata::new(&operator, &ncn, 255);
+
+        assert_eq!(operator_metadata.name_str(), "");
+        assert_eq!(operator_metadata.website_str(), "");
+        assert!(!operator_metadata.has_contact_hash());
+
+        let mut name = [0; OperatorMetadata::NAME_LEN];
+        name[..8].copy_from_slice(b"Operator");
+        let mut website = [0; OperatorMetadata::WEBSITE_LEN];
+        website[..19].copy_from_slice(b"https://example.com");
+        let contact_hash = [7; OperatorMetadata::CONTACT_HASH_LEN];
+
+        operator_metadata
+            .set_metadata(&name, &website, &contact_hash, 100)
+            .unwrap();
+
+        assert_eq!(operator_metadata.name_str(), "Operator");
+        assert_eq!(operator_metadata.website_str(), "https://example.com");
+        assert!(operator_metadata.has_contact_hash());
+        assert_eq!(operator_metadata.slot_updated(), 100);
+
+        // Zeros in the middle of a field are rejected
+        let mut bad_website = website;
+        bad_website[3] = 0;
+        assert_eq!(
+            operator_metadata.set_metadata(&name, &bad_website, &contact_hash, 101),
+            Err(NCNProgramError::InvalidOperatorMetadata)
+        );
+
+        // Invalid UTF-8 is rejected
+        let mut bad_name = name;
+        bad_name[0] = 0xff;
+        assert_eq!(
+            operator_metadata.set_metadata(&bad_name, &website, &contact_hash, 101),
+            Err(NCNProgramError::InvalidOperatorMetadata)
+        );
+
+        // Failed updates leave the metadata untouched
+        assert_eq!(operator_metadata.name_str(), "Operator");
+        assert_eq!(operator_metadata.slot_updated(), 100);
+    }
+}
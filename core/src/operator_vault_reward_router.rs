@@ -7,16 +7,23 @@ use jito_bytemuck::{
 };
 use jito_vault_core::MAX_BPS;
 use shank::{ShankAccount, ShankType};
+#[cfg(feature = "program")]
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    system_instruction, system_program, sysvar::Sysvar,
 };
-use spl_math::precise_number::PreciseNumber;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent};
+use spl_associated_token_account::get_associated_token_address;
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
-    constants::MAX_VAULTS, discriminators::Discriminators, epoch_snapshot::OperatorSnapshot,
-    error::NCNProgramError, loaders::check_load,
+    constants::{MAX_REWARD_MINTS, MAX_VAULTS},
+    discriminators::Discriminators,
+    epoch_snapshot::OperatorSnapshot,
+    error::NCNProgramError,
+    pdas::NCNProgramPda,
+    stake_weight::stake_weight_share,
 };
 
 /// Operator Vault Reward Router - Routes rewards from operators to their associated vaults
@@ -26,8 +33,12 @@ use crate::{
 /// 1. Operator takes their fee percentage first
 /// 2. Remaining rewards are distributed to vaults proportionally by stake weight
 ///
-/// The router supports partial routing through iterations to handle large numbers of vaults
-/// without hitting transaction limits.
+/// Routing uses plain u128 math, so all vaults of an operator can be routed in a single
+/// call at the default compute budget. Partial routing through iterations is still
+/// supported for callers that pass a lower `max_iterations`.
+///
+/// Rewards received in SPL token mints are tracked per mint in `OperatorVaultMintRewards`
+/// slots and routed with the same operator fee and stake weight split as SOL.
 ///
 /// PDA: ["operator_vault_reward_route", OPERATOR, NCN, EPOCH]
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
@@ -61,7 +72,10 @@ pub struct OperatorVaultRewardRouter {
     last_vault_operator_delegation_index: PodU16,
 
     /// Individual vault reward routes - tracks rewards per vault (limited to 64 vaults)
-    vault_reward_routes: [VaultRewardRoute; 64],
+    vault_reward_routes: [VaultRewardRoute; MAX_VAULTS],
+
+    /// Rewards routed in SPL token mints, one slot per mint
+    mint_rewards: [OperatorVaultMintRewards; MAX_REWARD_MINTS],
 }
 
 impl Discriminator for OperatorVaultRewardRouter {
@@ -71,14 +85,17 @@ impl Discriminator for OperatorVaultRewardRouter {
 impl OperatorVaultRewardRouter {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const OPERATOR_OFFSET: usize = 8 + offset_of!(Self, operator);
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     /// Sentinel values indicating no partial routing is in progress
     pub const NO_LAST_REWARDS_TO_PROCESS: u64 = u64::MAX;
     pub const NO_LAST_VAULT_OPERATION_DELEGATION_INDEX: u16 = u16::MAX;
 
-    /// Maximum iterations per routing call to prevent transaction timeout
-    pub const MAX_ROUTE_NCN_ITERATIONS: u16 = 30;
-
-    pub const OPERATOR_VAULT_REWARD_ROUTE_SEED: &'static [u8] = b"operator_vault_reward_route";
+    /// Maximum iterations per routing call, enough to route every vault in one call
+    pub const MAX_ROUTE_NCN_ITERATIONS: u16 = MAX_VAULTS as u16;
 
     /// Creates a new operator vault reward router
     pub fn new(
@@ -105,21 +122,18 @@ impl OperatorVaultRewardRouter {
                 Self::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX,
             ),
             vault_reward_routes: [VaultRewardRoute::default(); MAX_VAULTS],
+            mint_rewards: [OperatorVaultMintRewards::default(); MAX_REWARD_MINTS],
         }
     }
 
     /// Generates PDA seeds for the operator vault reward router
     pub fn seeds(operator: &Pubkey, ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::OPERATOR_VAULT_REWARD_ROUTE_SEED.to_vec(),
-                operator.to_bytes().to_vec(),
-                ncn.to_bytes().to_vec(),
-                epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::OperatorVaultRewardRouter {
+            operator: *operator,
+            ncn: *ncn,
+            epoch,
+        }
+        .seeds()
     }
 
     /// Finds the program address for the operator vault reward router PDA
@@ -129,13 +143,16 @@ impl OperatorVaultRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(operator, ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::OperatorVaultRewardRouter {
+            operator: *operator,
+            ncn: *ncn,
+            epoch,
+        }
+        .find_program_address(program_id)
     }
 
     /// Validates that the account matches expected PDA and discriminator
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -155,6 +172,7 @@ impl OperatorVaultRewardRouter {
     }
 
     /// Loads the account for closing by extracting operator from account data
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -270,6 +288,21 @@ impl OperatorVaultRewardRouter {
         Ok(())
     }
 
+    /// Takes the cranker fee out of the reward pool before the operator fee is split
+    /// Returns the fee, which leaves the reward receiver right away
+    pub fn route_cranker_fee(&mut self, cranker_fee_bps: u16) -> Result<u64, NCNProgramError> {
+        let cranker_fee =
+            Self::calculate_operator_reward(cranker_fee_bps as u64, self.reward_pool())?;
+
+        self.reward_pool = PodU64::from(
+            self.reward_pool()
+                .checked_sub(cranker_fee)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(cranker_fee)
+    }
+
     /// Routes operator fee rewards based on the operator's fee percentage
     /// This is the first phase of reward distribution - operator takes their fee
     pub fn route_operator_rewards(
@@ -299,7 +332,6 @@ impl OperatorVaultRewardRouter {
         max_iterations: u16,
     ) -> Result<(), NCNProgramError> {
         {
-            let operator_stake_weight = operator_snapshot.stake_weights();
             let rewards_to_process: u64 = self.reward_pool();
 
             let (rewards_to_process, starting_vault_operator_delegation_index) =
@@ -309,17 +341,24 @@ impl OperatorVaultRewardRouter {
                 return Ok(());
             }
 
+            let operator_reward_stake_weight =
+                operator_snapshot.stake_weights().stake_weight_u256();
+
+            let vault_operator_stake_weight_count =
+                operator_snapshot.vault_operator_stake_weight_count() as usize;
+
             let mut iterations: u16 = 0;
             // Always have at least 1 iteration to make progress
             let max_iterations = max_iterations.max(1);
 
             // Iterate through vault operator delegations and distribute rewards proportionally
-            for vault_operator_delegation_index in starting_vault_operator_delegation_index
-                ..operator_snapshot.vault_operator_stake_weight().len()
+            for (vault_operator_delegation_index, vault_operator_delegation) in operator_snapshot
+                .vault_operator_stake_weight()
+                .iter()
+                .enumerate()
+                .take(vault_operator_stake_weight_count)
+                .skip(starting_vault_operator_delegation_index)
             {
-                let vault_operator_delegation = operator_snapshot.vault_operator_stake_weight()
-                    [vault_operator_delegation_index];
-
                 // Track iterations to prevent transaction timeout
                 {
                     iterations = iterations
@@ -340,22 +379,17 @@ impl OperatorVaultRewardRouter {
                     }
                 }
 
-                let vault = vault_operator_delegation.vault();
-
-                let vault_reward_stake_weight =
-                    vault_operator_delegation.stake_weights().stake_weight();
-
-                let operator_reward_stake_weight = operator_stake_weight.stake_weight();
-
                 // Calculate proportional reward based on vault's stake weight
-                let vault_reward = Self::calculate_vault_reward(
-                    vault_reward_stake_weight,
-                    operator_reward_stake_weight,
+                let vault_reward = stake_weight_share(
                     rewards_to_process,
+                    vault_operator_delegation
+                        .stake_weights()
+                        .stake_weight_u256(),
+                    operator_reward_stake_weight,
                 )?;
 
                 self.route_from_reward_pool(vault_reward)?;
-                self.route_to_vault_reward_route(vault, vault_reward)?;
+                self.route_to_vault_reward_route(vault_operator_delegation.vault(), vault_reward)?;
             }
 
             self.reset_routing_state();
@@ -384,74 +418,18 @@ impl OperatorVaultRewardRouter {
             return Ok(0);
         }
 
-        let precise_operator_fee_bps =
-            PreciseNumber::new(fee_bps as u128).ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_max_bps =
-            PreciseNumber::new(MAX_BPS as u128).ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_rewards_to_process = PreciseNumber::new(rewards_to_process as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_operator_rewards = precise_rewards_to_process
-            .checked_mul(&precise_operator_fee_bps)
-            .and_then(|x| x.checked_div(&precise_max_bps))
+        let operator_rewards = (rewards_to_process as u128)
+            .checked_mul(fee_bps as u128)
+            .and_then(|x| x.checked_div(MAX_BPS as u128))
             .ok_or(NCNProgramError::ArithmeticOverflow)?;
 
-        let floored_precise_operator_rewards = precise_operator_rewards
-            .floor()
-            .ok_or(NCNProgramError::ArithmeticFloorError)?;
-
-        let operator_rewards_u128: u128 = floored_precise_operator_rewards
-            .to_imprecise()
-            .ok_or(NCNProgramError::CastToImpreciseNumberError)?;
-        let operator_rewards: u64 = operator_rewards_u128
+        let operator_rewards: u64 = operator_rewards
             .try_into()
             .map_err(|_| NCNProgramError::CastToU64Error)?;
 
         Ok(operator_rewards)
     }
 
-    /// Calculates proportional vault reward based on its stake weight
-    /// Formula: (vault_stake_weight / total_operator_stake_weight) * total_rewards
-    fn calculate_vault_reward(
-        vault_reward_stake_weight: u128,
-        operator_reward_stake_weight: u128,
-        rewards_to_process: u64,
-    ) -> Result<u64, NCNProgramError> {
-        if operator_reward_stake_weight == 0 || rewards_to_process == 0 {
-            return Ok(0);
-        }
-
-        let precise_rewards_to_process = PreciseNumber::new(rewards_to_process as u128)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_vault_reward_stake_weight = PreciseNumber::new(vault_reward_stake_weight)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_operator_reward_stake_weight = PreciseNumber::new(operator_reward_stake_weight)
-            .ok_or(NCNProgramError::NewPreciseNumberError)?;
-
-        let precise_vault_reward = precise_rewards_to_process
-            .checked_mul(&precise_vault_reward_stake_weight)
-            .and_then(|x| x.checked_div(&precise_operator_reward_stake_weight))
-            .ok_or(NCNProgramError::ArithmeticOverflow)?;
-
-        let floored_precise_vault_reward = precise_vault_reward
-            .floor()
-            .ok_or(NCNProgramError::ArithmeticFloorError)?;
-
-        let vault_reward_u128: u128 = floored_precise_vault_reward
-            .to_imprecise()
-            .ok_or(NCNProgramError::CastToImpreciseNumberError)?;
-
-        let vault_reward: u64 = vault_reward_u128
-            .try_into()
-            .map_err(|_| NCNProgramError::CastToU64Error)?;
-
-        Ok(vault_reward)
-    }
-
     // ------------------------ REWARD POOL ------------------------
 
     /// Calculates total rewards currently being processed (reward pool + processed)
@@ -611,17 +589,15 @@ impl OperatorVaultRewardRouter {
             return Ok(());
         }
 
-        // Try to find existing route and increment rewards
+        // Find the existing route, routes are filled in order so the first
+        // empty slot means the vault has no route yet
         for vault_reward in self.vault_reward_routes.iter_mut() {
             if vault_reward.vault().eq(vault) {
                 vault_reward.increment_rewards(rewards)?;
                 return Ok(());
             }
-        }
 
-        // Find empty slot and create new route
-        for vault_reward in self.vault_reward_routes.iter_mut() {
-            if vault_reward.vault().eq(&Pubkey::default()) {
+            if vault_reward.is_empty() {
                 *vault_reward = VaultRewardRoute::new(vault, rewards)?;
                 return Ok(());
             }
@@ -647,6 +623,237 @@ impl OperatorVaultRewardRouter {
         }
         Err(NCNProgramError::OperatorRewardNotFound)
     }
+
+    // ------------------------ MINT REWARDS ------------------------
+
+    /// Gets the reward slots for SPL token mints
+    pub const fn mint_rewards(&self) -> &[OperatorVaultMintRewards; MAX_REWARD_MINTS] {
+        &self.mint_rewards
+    }
+
+    /// Gets the index of the reward slot tracking the given mint
+    pub fn mint_rewards_index(&self, mint: &Pubkey) -> Result<usize, NCNProgramError> {
+        self.mint_rewards
+            .iter()
+            .position(|mint_rewards| !mint_rewards.is_empty() && mint_rewards.mint().eq(mint))
+            .ok_or(NCNProgramError::RewardMintNotFound)
+    }
+
+    /// Gets the reward slot tracking the given mint
+    pub fn get_mint_rewards(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<&OperatorVaultMintRewards, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        Ok(&self.mint_rewards[index])
+    }
+
+    /// Gets the reward slot tracking the given mint, claiming an empty slot if there is none
+    /// Returns the index of the slot
+    pub fn register_mint_rewards(&mut self, mint: &Pubkey) -> Result<usize, NCNProgramError> {
+        if mint.eq(&Pubkey::default()) {
+            return Err(NCNProgramError::InvalidRewardMint);
+        }
+
+        if let Ok(index) = self.mint_rewards_index(mint) {
+            return Ok(index);
+        }
+
+        let index = self
+            .mint_rewards
+            .iter()
+            .position(|mint_rewards| mint_rewards.is_empty())
+            .ok_or(NCNProgramError::RewardMintListFull)?;
+
+        self.mint_rewards[index] = OperatorVaultMintRewards::new(mint);
+
+        Ok(index)
+    }
+
+    /// Checks if routing of the given mint is still in progress (was interrupted)
+    pub fn still_routing_mint(&self, mint: &Pubkey) -> bool {
+        self.get_mint_rewards(mint)
+            .map(|mint_rewards| mint_rewards.still_routing())
+            .unwrap_or(false)
+    }
+
+    /// Routes incoming rewards of a mint from the receiver's token account balance
+    /// Token accounts hold no rent, so the whole untracked balance is routed
+    pub fn route_incoming_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+        token_account_balance: u64,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.register_mint_rewards(mint)?;
+
+        self.mint_rewards[index].route_incoming_rewards(token_account_balance)
+    }
+
+    /// Routes the operator fee of a mint's reward pool based on the operator's fee percentage
+    pub fn route_operator_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+        operator_snapshot: &OperatorSnapshot,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+        let mint_rewards = &mut self.mint_rewards[index];
+
+        let operator_rewards = Self::calculate_operator_reward(
+            operator_snapshot.operator_fee_bps() as u64,
+            mint_rewards.reward_pool(),
+        )?;
+
+        mint_rewards.route_from_reward_pool(operator_rewards)?;
+        mint_rewards.route_to_operator_rewards(operator_rewards)?;
+
+        Ok(())
+    }
+
+    /// Routes the remaining reward pool of a mint to vaults based on their stake weights,
+    /// the same way `route_reward_pool` does for SOL
+    pub fn route_mint_reward_pool(
+        &mut self,
+        mint: &Pubkey,
+        operator_snapshot: &OperatorSnapshot,
+        max_iterations: u16,
+    ) -> Result<(), NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        {
+            let rewards_to_process = self.mint_rewards[index].reward_pool();
+
+            let (rewards_to_process, starting_vault_operator_delegation_index) =
+                self.mint_rewards[index].resume_routing_state(rewards_to_process);
+
+            if rewards_to_process == 0 {
+                return Ok(());
+            }
+
+            let operator_reward_stake_weight =
+                operator_snapshot.stake_weights().stake_weight_u256();
+
+            let vault_operator_stake_weight_count =
+                operator_snapshot.vault_operator_stake_weight_count() as usize;
+
+            let mut iterations: u16 = 0;
+            // Always have at least 1 iteration to make progress
+            let max_iterations = max_iterations.max(1);
+
+            for (vault_operator_delegation_index, vault_operator_delegation) in operator_snapshot
+                .vault_operator_stake_weight()
+                .iter()
+                .enumerate()
+                .take(vault_operator_stake_weight_count)
+                .skip(starting_vault_operator_delegation_index)
+            {
+                {
+                    iterations = iterations
+                        .checked_add(1)
+                        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+                    if iterations > max_iterations {
+                        msg!(
+                            "Reached max iterations, saving state and exiting {}/{}",
+                            rewards_to_process,
+                            vault_operator_delegation_index
+                        );
+                        self.mint_rewards[index].save_routing_state(
+                            rewards_to_process,
+                            vault_operator_delegation_index,
+                        );
+                        return Ok(());
+                    }
+                }
+
+                let vault_reward = stake_weight_share(
+                    rewards_to_process,
+                    vault_operator_delegation
+                        .stake_weights()
+                        .stake_weight_u256(),
+                    operator_reward_stake_weight,
+                )?;
+
+                self.mint_rewards[index].route_from_reward_pool(vault_reward)?;
+                self.route_to_vault_mint_reward_route(
+                    index,
+                    vault_operator_delegation.vault(),
+                    vault_reward,
+                )?;
+            }
+
+            self.mint_rewards[index].reset_routing_state();
+        }
+
+        // Operator gets any remainder due to rounding
+        {
+            let mint_rewards = &mut self.mint_rewards[index];
+            let leftover_rewards = mint_rewards.reward_pool();
+
+            mint_rewards.route_from_reward_pool(leftover_rewards)?;
+            mint_rewards.route_to_operator_rewards(leftover_rewards)?;
+        }
+
+        Ok(())
+    }
+
+    /// Routes rewards of the mint slot at `index` to a specific vault's reward route
+    /// Creates a new route if one doesn't exist for the vault
+    fn route_to_vault_mint_reward_route(
+        &mut self,
+        index: usize,
+        vault: &Pubkey,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        if rewards == 0 {
+            return Ok(());
+        }
+
+        for vault_reward in self.vault_reward_routes.iter_mut() {
+            if vault_reward.vault().eq(vault) {
+                return vault_reward.increment_mint_rewards(index, rewards);
+            }
+
+            if vault_reward.is_empty() {
+                *vault_reward = VaultRewardRoute::new(vault, 0)?;
+                return vault_reward.increment_mint_rewards(index, rewards);
+            }
+        }
+
+        Err(NCNProgramError::OperatorRewardListFull)
+    }
+
+    /// Distributes operator rewards of a mint and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_operator_mint_rewards(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        self.mint_rewards[index].distribute_operator_rewards()
+    }
+
+    /// Distributes rewards of a mint for a specific vault and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_vault_mint_reward_route(
+        &mut self,
+        mint: &Pubkey,
+        vault: &Pubkey,
+    ) -> Result<u64, NCNProgramError> {
+        let index = self.mint_rewards_index(mint)?;
+
+        for route in self.vault_reward_routes.iter_mut() {
+            if route.vault().eq(vault) {
+                let rewards = route.mint_rewards(index);
+
+                route.decrement_mint_rewards(index, rewards)?;
+                self.mint_rewards[index].decrement_rewards_processed(rewards)?;
+                return Ok(rewards);
+            }
+        }
+        Err(NCNProgramError::OperatorRewardNotFound)
+    }
 }
 
 /// Operator Vault Reward Receiver - Uninitialized account that receives rewards for operator-vault combinations
@@ -659,17 +866,14 @@ impl OperatorVaultRewardRouter {
 pub struct OperatorVaultRewardReceiver {}
 
 impl OperatorVaultRewardReceiver {
-    pub const OPERATOR_VAULT_REWARD_RECEIVER_SEED: &'static [u8] =
-        b"operator_vault_reward_receiver";
-
     /// Generates PDA seeds for the operator vault reward receiver
     pub fn seeds(operator: &Pubkey, ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
-        vec![
-            Self::OPERATOR_VAULT_REWARD_RECEIVER_SEED.to_vec(),
-            operator.to_bytes().to_vec(),
-            ncn.to_bytes().to_vec(),
-            epoch.to_le_bytes().to_vec(),
-        ]
+        NCNProgramPda::OperatorVaultRewardReceiver {
+            operator: *operator,
+            ncn: *ncn,
+            epoch,
+        }
+        .seeds()
     }
 
     /// Finds the program address for the operator vault reward receiver PDA
@@ -679,16 +883,17 @@ impl OperatorVaultRewardReceiver {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(operator, ncn, epoch);
-        let (address, bump) = Pubkey::find_program_address(
-            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
-            program_id,
-        );
-        (address, bump, seeds)
+        NCNProgramPda::OperatorVaultRewardReceiver {
+            operator: *operator,
+            ncn: *ncn,
+            epoch,
+        }
+        .find_program_address(program_id)
     }
 
     /// Validates that the account is owned by system program and matches expected PDA
     /// Operator vault reward receiver accounts are owned by system program, not the NCN program
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -714,6 +919,7 @@ impl OperatorVaultRewardReceiver {
     /// then transfers the minimum rent to the account payer (for rent recovery).
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "program")]
     pub fn close<'a, 'info>(
         program_id: &Pubkey,
         operator: &Pubkey,
@@ -753,9 +959,79 @@ impl OperatorVaultRewardReceiver {
         )
     }
 
+    /// Finds the associated token account of the operator vault reward receiver for a reward mint
+    pub fn find_token_account_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        mint: &Pubkey,
+    ) -> Pubkey {
+        let operator_vault_reward_receiver =
+            Self::find_program_address(program_id, operator, ncn, epoch).0;
+        get_associated_token_address(&operator_vault_reward_receiver, mint)
+    }
+
+    /// Transfers tokens out of the operator vault reward receiver's token account using PDA authority
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "program")]
+    pub fn transfer_tokens<'a, 'info>(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        operator_vault_reward_receiver: &'a AccountInfo<'info>,
+        operator_vault_reward_receiver_token_account: &'a AccountInfo<'info>,
+        to_token_account: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        let (
+            operator_vault_reward_receiver_address,
+            operator_vault_reward_receiver_bump,
+            mut operator_vault_reward_receiver_seeds,
+        ) = Self::find_program_address(program_id, operator, ncn, epoch);
+        operator_vault_reward_receiver_seeds.push(vec![operator_vault_reward_receiver_bump]);
+
+        if operator_vault_reward_receiver_address.ne(operator_vault_reward_receiver.key) {
+            msg!("Incorrect operator vault reward receiver PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if token_program.key.ne(&spl_token::id()) {
+            msg!("Incorrect token program");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                operator_vault_reward_receiver_token_account.key,
+                to_token_account.key,
+                &operator_vault_reward_receiver_address,
+                &[],
+                amount,
+            )?,
+            &[
+                operator_vault_reward_receiver_token_account.clone(),
+                to_token_account.clone(),
+                operator_vault_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[operator_vault_reward_receiver_seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+        Ok(())
+    }
+
     /// Transfers lamports from the operator vault reward receiver using PDA authority
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "program")]
     pub fn transfer<'a, 'info>(
         program_id: &Pubkey,
         operator: &Pubkey,
@@ -803,6 +1079,8 @@ pub struct VaultRewardRoute {
     vault: Pubkey,
     /// The amount of rewards allocated to this vault (in lamports)
     rewards: PodU64,
+    /// The amounts of rewards allocated to this vault, indexed by the router's mint reward slots
+    mint_rewards: [PodU64; MAX_REWARD_MINTS],
 }
 
 impl VaultRewardRoute {
@@ -811,6 +1089,7 @@ impl VaultRewardRoute {
         Ok(Self {
             vault: *vault,
             rewards: PodU64::from(rewards),
+            mint_rewards: [PodU64::from(0); MAX_REWARD_MINTS],
         })
     }
 
@@ -861,29 +1140,296 @@ impl VaultRewardRoute {
 
         self.set_rewards(new_rewards)
     }
-}
 
-/// Display implementation for OperatorVaultRewardRouter - provides formatted output for debugging
-#[rustfmt::skip]
-impl fmt::Display for OperatorVaultRewardRouter {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "\n\n----------- Operator Vault Reward Route -------------")?;
-        writeln!(f, "  Operator:                     {}", self.operator)?;
-        writeln!(f, "  NCN:                          {}", self.ncn)?;
-        writeln!(f, "  Epoch:                        {}", self.epoch())?;
-        writeln!(f, "  Bump:                         {}", self.bump)?;
-        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
-        writeln!(f, "  NCN Operator Index:           {}", self.ncn_operator_index())?;
-        writeln!(f, "  Still Routing:                {}", self.still_routing())?;
-        writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
-        writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
-        writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
-        writeln!(f, "  Operator Rewards:             {}", self.operator_rewards())?;
+    /// Gets the reward amount for the router's mint reward slot at `index`
+    pub fn mint_rewards(&self, index: usize) -> u64 {
+        self.mint_rewards
+            .get(index)
+            .map(|rewards| (*rewards).into())
+            .unwrap_or(0)
+    }
 
-        if self.still_routing() {
-            writeln!(f, "\nRouting State:")?;
-            writeln!(f, "  Last Rewards to Process:      {}", self.last_rewards_to_process())?;
-            writeln!(f, "  Last Vault Op Del Index:      {}", self.last_vault_operator_delegation_index())?;
+    /// Adds rewards of the router's mint reward slot at `index` to this route
+    pub fn increment_mint_rewards(
+        &mut self,
+        index: usize,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        let mint_rewards = self
+            .mint_rewards
+            .get_mut(index)
+            .ok_or(NCNProgramError::InvalidRewardMintIndex)?;
+
+        *mint_rewards = PodU64::from(
+            u64::from(*mint_rewards)
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Removes rewards of the router's mint reward slot at `index` from this route
+    pub fn decrement_mint_rewards(
+        &mut self,
+        index: usize,
+        rewards: u64,
+    ) -> Result<(), NCNProgramError> {
+        let mint_rewards = self
+            .mint_rewards
+            .get_mut(index)
+            .ok_or(NCNProgramError::InvalidRewardMintIndex)?;
+
+        *mint_rewards = PodU64::from(
+            u64::from(*mint_rewards)
+                .checked_sub(rewards)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(())
+    }
+}
+
+/// Rewards routed in a single SPL token mint
+///
+/// Mirrors the SOL accounting of the `OperatorVaultRewardRouter`: incoming rewards land in
+/// the reward pool, the operator takes their fee and the rest is routed to the vaults.
+/// Routing state is kept per mint so each mint can be routed and resumed on its own.
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct OperatorVaultMintRewards {
+    /// The reward mint, default if the slot is unused
+    mint: Pubkey,
+    /// The total rewards that have been routed - cumulative amount ever processed
+    total_rewards: PodU64,
+    /// The rewards in the reward pool - awaiting distribution
+    reward_pool: PodU64,
+    /// The rewards that have been processed - moved out of reward pool
+    rewards_processed: PodU64,
+    /// Rewards allocated to the operator - operator's fee portion
+    operator_rewards: PodU64,
+    /// The last rewards amount being processed during routing (for resuming partial operations)
+    last_rewards_to_process: PodU64,
+    /// The last vault operator delegation index processed during routing
+    last_vault_operator_delegation_index: PodU16,
+}
+
+impl Default for OperatorVaultMintRewards {
+    fn default() -> Self {
+        Self::new(&Pubkey::default())
+    }
+}
+
+impl OperatorVaultMintRewards {
+    /// Creates an empty reward slot for a mint
+    pub fn new(mint: &Pubkey) -> Self {
+        Self {
+            mint: *mint,
+            total_rewards: PodU64::from(0),
+            reward_pool: PodU64::from(0),
+            rewards_processed: PodU64::from(0),
+            operator_rewards: PodU64::from(0),
+            last_rewards_to_process: PodU64::from(
+                OperatorVaultRewardRouter::NO_LAST_REWARDS_TO_PROCESS,
+            ),
+            last_vault_operator_delegation_index: PodU16::from(
+                OperatorVaultRewardRouter::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX,
+            ),
+        }
+    }
+
+    pub const fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    /// Checks if this slot is unused (default mint)
+    pub fn is_empty(&self) -> bool {
+        self.mint.eq(&Pubkey::default())
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn reward_pool(&self) -> u64 {
+        self.reward_pool.into()
+    }
+
+    pub fn rewards_processed(&self) -> u64 {
+        self.rewards_processed.into()
+    }
+
+    pub fn operator_rewards(&self) -> u64 {
+        self.operator_rewards.into()
+    }
+
+    // ----------------- ROUTE STATE TRACKING --------------
+
+    pub fn last_rewards_to_process(&self) -> u64 {
+        self.last_rewards_to_process.into()
+    }
+
+    pub fn last_vault_operator_delegation_index(&self) -> u16 {
+        self.last_vault_operator_delegation_index.into()
+    }
+
+    /// Resumes routing from the last saved state if routing was interrupted
+    /// Returns (rewards_to_process, starting_vault_index)
+    pub fn resume_routing_state(&self, rewards_to_process: u64) -> (u64, usize) {
+        if !self.still_routing() {
+            return (rewards_to_process, 0);
+        }
+
+        (
+            self.last_rewards_to_process(),
+            self.last_vault_operator_delegation_index() as usize,
+        )
+    }
+
+    /// Saves the current routing state for resumption if interrupted
+    pub fn save_routing_state(
+        &mut self,
+        rewards_to_process: u64,
+        vault_operator_delegation_index: usize,
+    ) {
+        self.last_rewards_to_process = PodU64::from(rewards_to_process);
+        self.last_vault_operator_delegation_index =
+            PodU16::from(vault_operator_delegation_index as u16);
+    }
+
+    /// Resets routing state to indicate no partial routing is in progress
+    pub fn reset_routing_state(&mut self) {
+        self.last_rewards_to_process =
+            PodU64::from(OperatorVaultRewardRouter::NO_LAST_REWARDS_TO_PROCESS);
+        self.last_vault_operator_delegation_index =
+            PodU16::from(OperatorVaultRewardRouter::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX);
+    }
+
+    /// Checks if routing is still in progress (was interrupted)
+    pub fn still_routing(&self) -> bool {
+        self.last_rewards_to_process() != OperatorVaultRewardRouter::NO_LAST_REWARDS_TO_PROCESS
+            || self.last_vault_operator_delegation_index()
+                != OperatorVaultRewardRouter::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX
+    }
+
+    // ------------------------ ROUTING ------------------------
+
+    /// Calculates total rewards currently being processed (reward pool + processed)
+    pub fn total_rewards_in_transit(&self) -> Result<u64, NCNProgramError> {
+        self.reward_pool()
+            .checked_add(self.rewards_processed())
+            .ok_or(NCNProgramError::ArithmeticOverflow)
+    }
+
+    /// Routes incoming rewards from the token account balance to the reward pool
+    pub fn route_incoming_rewards(
+        &mut self,
+        token_account_balance: u64,
+    ) -> Result<(), NCNProgramError> {
+        let incoming_rewards = token_account_balance
+            .checked_sub(self.total_rewards_in_transit()?)
+            .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
+
+        if incoming_rewards == 0 {
+            return Ok(());
+        }
+
+        self.total_rewards = PodU64::from(
+            self.total_rewards()
+                .checked_add(incoming_rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        self.reward_pool = PodU64::from(
+            self.reward_pool()
+                .checked_add(incoming_rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Moves rewards out of the reward pool and marks them as processed
+    pub fn route_from_reward_pool(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
+        self.reward_pool = PodU64::from(
+            self.reward_pool()
+                .checked_sub(rewards)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        self.rewards_processed = PodU64::from(
+            self.rewards_processed()
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Routes rewards to operator allocation
+    pub fn route_to_operator_rewards(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
+        self.operator_rewards = PodU64::from(
+            self.operator_rewards()
+                .checked_add(rewards)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Decrements the counter of rewards processed (when rewards are distributed)
+    pub fn decrement_rewards_processed(&mut self, rewards: u64) -> Result<(), NCNProgramError> {
+        self.rewards_processed = PodU64::from(
+            self.rewards_processed()
+                .checked_sub(rewards)
+                .ok_or(NCNProgramError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(())
+    }
+
+    /// Distributes operator rewards and updates counters
+    /// Returns the amount of rewards distributed
+    pub fn distribute_operator_rewards(&mut self) -> Result<u64, NCNProgramError> {
+        let rewards = self.operator_rewards();
+        self.operator_rewards = PodU64::from(0);
+
+        self.decrement_rewards_processed(rewards)?;
+
+        Ok(rewards)
+    }
+}
+
+/// Display implementation for OperatorVaultRewardRouter - provides formatted output for debugging
+#[rustfmt::skip]
+impl fmt::Display for OperatorVaultRewardRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Operator Vault Reward Route -------------")?;
+        writeln!(f, "  Operator:                     {}", self.operator)?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Bump:                         {}", self.bump)?;
+        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
+        writeln!(f, "  NCN Operator Index:           {}", self.ncn_operator_index())?;
+        writeln!(f, "  Still Routing:                {}", self.still_routing())?;
+        writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
+        writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
+        writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
+        writeln!(f, "  Operator Rewards:             {}", self.operator_rewards())?;
+
+        if self.still_routing() {
+            writeln!(f, "\nRouting State:")?;
+            writeln!(f, "  Last Rewards to Process:      {}", self.last_rewards_to_process())?;
+            writeln!(f, "  Last Vault Op Del Index:      {}", self.last_vault_operator_delegation_index())?;
+        }
+
+        for mint_rewards in self.mint_rewards().iter().filter(|mint_rewards| !mint_rewards.is_empty()) {
+            writeln!(f, "\nMint Rewards:                   {}", mint_rewards.mint())?;
+            writeln!(f, "  Still Routing:                {}", mint_rewards.still_routing())?;
+            writeln!(f, "  Total Rewards:                {}", mint_rewards.total_rewards())?;
+            writeln!(f, "  Reward Pool:                  {}", mint_rewards.reward_pool())?;
+            writeln!(f, "  Rewards Processed:            {}", mint_rewards.rewards_processed())?;
+            writeln!(f, "  Operator Rewards:             {}", mint_rewards.operator_rewards())?;
         }
 
         writeln!(f, "\nVault Reward Routes:")?;
@@ -891,6 +1437,12 @@ impl fmt::Display for OperatorVaultRewardRouter {
             if !route.is_empty() {
                 writeln!(f, "  Vault:                        {}", route.vault())?;
                 writeln!(f, "    Rewards:                    {}", route.rewards())?;
+                for (index, mint_rewards) in self.mint_rewards().iter().enumerate() {
+                    let rewards = route.mint_rewards(index);
+                    if rewards > 0 {
+                        writeln!(f, "    Rewards ({}): {}", mint_rewards.mint(), rewards)?;
+                    }
+                }
             }
         }
 
@@ -965,7 +1517,7 @@ mod tests {
 
     #[test]
     fn test_len() {
-        use std::mem::size_of;
+        use std::mem::{offset_of, size_of};
 
         let expected_total = size_of::<Pubkey>() // operator
             + size_of::<Pubkey>() // ncn
@@ -979,11 +1531,99 @@ mod tests {
             + size_of::<PodU64>() // operator_rewards
             + size_of::<PodU64>() // last_rewards_to_process
             + size_of::<PodU16>() // last_vault_operator_delegation_index
-            + size_of::<VaultRewardRoute>() * MAX_VAULTS; // vault_reward_routes
+            + size_of::<VaultRewardRoute>() * MAX_VAULTS // vault_reward_routes
+            + size_of::<OperatorVaultMintRewards>() * MAX_REWARD_MINTS; // mint_rewards
 
         assert_eq!(size_of::<OperatorVaultRewardRouter>(), expected_total);
     }
 
+    #[test]
+    fn test_route_mint_rewards() {
+        const INCOMING_REWARDS: u64 = 10_000;
+
+        let mut router = OperatorVaultRewardRouter::new(
+            &Pubkey::new_unique(), // operator
+            0,                     // operator_ncn_index
+            &Pubkey::new_unique(), // ncn
+            TEST_EPOCH,            // epoch
+            1,                     // bump
+            TEST_CURRENT_SLOT,     // slot_created
+        );
+
+        let operator_snapshot = {
+            let operator_fee_bps = 1000; // 10%
+            let vault_operator_delegation_count = 10;
+            let mut operator_snapshot =
+                get_test_operator_snapshot(operator_fee_bps, vault_operator_delegation_count);
+
+            for _ in 0..vault_operator_delegation_count {
+                register_test_vault_operator_delegation(&mut operator_snapshot, 1000);
+            }
+
+            operator_snapshot
+        };
+
+        let mint = Pubkey::new_unique();
+        router
+            .route_incoming_mint_rewards(&mint, INCOMING_REWARDS)
+            .unwrap();
+        router
+            .route_operator_mint_rewards(&mint, &operator_snapshot)
+            .unwrap();
+
+        router
+            .route_mint_reward_pool(&mint, &operator_snapshot, 5)
+            .unwrap();
+        assert!(router.still_routing_mint(&mint));
+        assert!(!router.still_routing());
+
+        router
+            .route_mint_reward_pool(&mint, &operator_snapshot, 5)
+            .unwrap();
+        assert!(!router.still_routing_mint(&mint));
+
+        // SOL accounting is untouched
+        assert_eq!(router.total_rewards(), 0);
+        assert_eq!(router.operator_rewards(), 0);
+
+        let mint_rewards = *router.get_mint_rewards(&mint).unwrap();
+        assert_eq!(mint_rewards.total_rewards(), INCOMING_REWARDS);
+        assert_eq!(mint_rewards.reward_pool(), 0);
+        assert_eq!(mint_rewards.operator_rewards(), 1_000);
+
+        let routes: Vec<VaultRewardRoute> = router
+            .vault_reward_routes()
+            .iter()
+            .filter(|route| !route.is_empty())
+            .copied()
+            .collect();
+        assert_eq!(routes.len(), 10);
+
+        for route in routes.iter() {
+            assert_eq!(route.rewards(), 0);
+            assert_eq!(route.mint_rewards(0), 900);
+
+            let rewards = router
+                .distribute_vault_mint_reward_route(&mint, &route.vault())
+                .unwrap();
+            assert_eq!(rewards, 900);
+        }
+
+        assert_eq!(
+            router.distribute_operator_mint_rewards(&mint).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            router.get_mint_rewards(&mint).unwrap().rewards_processed(),
+            0
+        );
+
+        assert_eq!(
+            router.distribute_operator_mint_rewards(&Pubkey::new_unique()),
+            Err(NCNProgramError::RewardMintNotFound)
+        );
+    }
+
     #[test]
     fn test_route_incoming_rewards() {
         let mut router = OperatorVaultRewardRouter::new(
@@ -1076,6 +1716,38 @@ mod tests {
         assert_eq!(router.rewards_processed(), INCOMING_REWARDS / 10);
     }
 
+    #[test]
+    fn test_route_cranker_fee() {
+        const INCOMING_REWARDS: u64 = 10_000;
+
+        let mut router = OperatorVaultRewardRouter::new(
+            &Pubkey::new_unique(), // operator
+            0,                     // operator_ncn_index
+            &Pubkey::new_unique(), // ncn
+            TEST_EPOCH,            // epoch
+            1,                     // bump
+            TEST_CURRENT_SLOT,     // slot_created
+        );
+
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+
+        let cranker_fee = router.route_cranker_fee(100).unwrap();
+        assert_eq!(cranker_fee, 100); // 1% of 10_000
+        assert_eq!(router.total_rewards(), INCOMING_REWARDS);
+        assert_eq!(router.reward_pool(), INCOMING_REWARDS - cranker_fee);
+        assert_eq!(router.rewards_processed(), 0);
+
+        let operator_snapshot = {
+            let mut operator_snapshot = get_test_operator_snapshot(1000, 1);
+            register_test_vault_operator_delegation(&mut operator_snapshot, 1000);
+            operator_snapshot
+        };
+
+        // The operator fee is taken from what is left after the cranker fee
+        router.route_operator_rewards(&operator_snapshot).unwrap();
+        assert_eq!(router.operator_rewards(), 990);
+    }
+
     #[test]
     fn test_max_iterations() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1205,6 +1877,55 @@ mod tests {
         assert_eq!(router.rewards_processed(), INCOMING_REWARDS);
     }
 
+    #[test]
+    fn test_route_max_vaults_in_one_call() {
+        const INCOMING_REWARDS: u64 = MAX_VAULTS as u64 * 1000;
+
+        let mut router = OperatorVaultRewardRouter::new(
+            &Pubkey::new_unique(), // operator
+            0,                     // operator_ncn_index
+            &Pubkey::new_unique(), // ncn
+            TEST_EPOCH,            // epoch
+            1,                     // bump
+            TEST_CURRENT_SLOT,     // slot_created
+        );
+
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+
+        let operator_snapshot = {
+            let operator_fee_bps = 0; // 0%
+            let vault_operator_delegation_count = MAX_VAULTS as u64;
+            let mut operator_snapshot =
+                get_test_operator_snapshot(operator_fee_bps, vault_operator_delegation_count);
+
+            for _ in 0..vault_operator_delegation_count {
+                register_test_vault_operator_delegation(&mut operator_snapshot, 1000);
+            }
+
+            operator_snapshot
+        };
+
+        router.route_operator_rewards(&operator_snapshot).unwrap();
+        router
+            .route_reward_pool(
+                &operator_snapshot,
+                OperatorVaultRewardRouter::MAX_ROUTE_NCN_ITERATIONS,
+            )
+            .unwrap();
+
+        assert!(!router.still_routing());
+        assert_eq!(
+            router
+                .vault_reward_routes()
+                .iter()
+                .filter(|route| !route.is_empty())
+                .count(),
+            MAX_VAULTS
+        );
+        assert_eq!(router.reward_pool(), 0);
+        assert_eq!(router.rewards_processed(), INCOMING_REWARDS);
+    }
+
     #[test]
     fn test_route_max_vaults_with_operator() {
         let expected_vault_rewards: u64 = 1000;
@@ -0,0 +1,384 @@
+//! Seeds and addresses of every NCN program account, in one place so the program, the SDK and the
+//! CLI derive them the same way.
+//!
+//! The account structs keep their `seeds` and `find_program_address` functions, they forward to
+//! [`NCNProgramPda`].
+
+use solana_program::pubkey::Pubkey;
+
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const VAULT_REGISTRY_SEED: &[u8] = b"vault_registry";
+pub const ACCOUNT_PAYER_SEED: &[u8] = b"account_payer";
+pub const OPERATOR_METADATA_SEED: &[u8] = b"operator_metadata";
+pub const KEEPER_LEASE_SEED: &[u8] = b"keeper_lease";
+pub const EPOCH_MARKER_SEED: &[u8] = b"epoch_marker";
+pub const EPOCH_STATE_SEED: &[u8] = b"epoch_state";
+pub const WEIGHT_TABLE_SEED: &[u8] = b"weight_table";
+pub const EPOCH_SNAPSHOT_SEED: &[u8] = b"epoch_snapshot";
+pub const OPERATOR_SNAPSHOT_SEED: &[u8] = b"operator_snapshot";
+pub const BALLOT_BOX_SEED: &[u8] = b"ballot_box";
+pub const CONSENSUS_RESULT_SEED: &[u8] = b"consensus-result";
+pub const NCN_REWARD_ROUTER_SEED: &[u8] = b"ncn_reward_router";
+pub const NCN_REWARD_RECEIVER_SEED: &[u8] = b"ncn_reward_receiver";
+pub const OPERATOR_VAULT_REWARD_ROUTER_SEED: &[u8] = b"operator_vault_reward_route";
+pub const OPERATOR_VAULT_REWARD_RECEIVER_SEED: &[u8] = b"operator_vault_reward_receiver";
+
+/// A program derived account of the NCN program, with the keys its address is derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NCNProgramPda {
+    Config {
+        ncn: Pubkey,
+    },
+    VaultRegistry {
+        ncn: Pubkey,
+    },
+    AccountPayer {
+        ncn: Pubkey,
+    },
+    OperatorMetadata {
+        operator: Pubkey,
+        ncn: Pubkey,
+    },
+    KeeperLease {
+        ncn: Pubkey,
+        authority: Pubkey,
+    },
+    EpochMarker {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    EpochState {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    WeightTable {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    EpochSnapshot {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    OperatorSnapshot {
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    BallotBox {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    ConsensusResult {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    NCNRewardRouter {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    NCNRewardReceiver {
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    OperatorVaultRewardRouter {
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    },
+    OperatorVaultRewardReceiver {
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    },
+}
+
+impl NCNProgramPda {
+    /// Every account of one epoch, the per operator accounts of `operators` included
+    pub fn epoch_accounts(ncn: &Pubkey, operators: &[Pubkey], epoch: u64) -> Vec<Self> {
+        let ncn = *ncn;
+        let mut accounts = vec![
+            Self::EpochState { ncn, epoch },
+            Self::WeightTable { ncn, epoch },
+            Self::EpochSnapshot { ncn, epoch },
+        ];
+        accounts.extend(operators.iter().map(|operator| Self::OperatorSnapshot {
+            operator: *operator,
+            ncn,
+            epoch,
+        }));
+        accounts.extend([
+            Self::BallotBox { ncn, epoch },
+            Self::ConsensusResult { ncn, epoch },
+            Self::NCNRewardRouter { ncn, epoch },
+            Self::NCNRewardReceiver { ncn, epoch },
+        ]);
+        for operator in operators {
+            accounts.push(Self::OperatorVaultRewardRouter {
+                operator: *operator,
+                ncn,
+                epoch,
+            });
+            accounts.push(Self::OperatorVaultRewardReceiver {
+                operator: *operator,
+                ncn,
+                epoch,
+            });
+        }
+        accounts.push(Self::EpochMarker { ncn, epoch });
+
+        accounts
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Config { .. } => "Config",
+            Self::VaultRegistry { .. } => "Vault Registry",
+            Self::AccountPayer { .. } => "Account Payer",
+            Self::OperatorMetadata { .. } => "Operator Metadata",
+            Self::KeeperLease { .. } => "Keeper Lease",
+            Self::EpochMarker { .. } => "Epoch Marker",
+            Self::EpochState { .. } => "Epoch State",
+            Self::WeightTable { .. } => "Weight Table",
+            Self::EpochSnapshot { .. } => "Epoch Snapshot",
+            Self::OperatorSnapshot { .. } => "Operator Snapshot",
+            Self::BallotBox { .. } => "Ballot Box",
+            Self::ConsensusResult { .. } => "Consensus Result",
+            Self::NCNRewardRouter { .. } => "NCN Reward Router",
+            Self::NCNRewardReceiver { .. } => "NCN Reward Receiver",
+            Self::OperatorVaultRewardRouter { .. } => "Operator Vault Reward Router",
+            Self::OperatorVaultRewardReceiver { .. } => "Operator Vault Reward Receiver",
+        }
+    }
+
+    /// The operator of the per operator accounts
+    pub const fn operator(&self) -> Option<&Pubkey> {
+        match self {
+            Self::OperatorMetadata { operator, .. }
+            | Self::OperatorSnapshot { operator, .. }
+            | Self::OperatorVaultRewardRouter { operator, .. }
+            | Self::OperatorVaultRewardReceiver { operator, .. } => Some(operator),
+            _ => None,
+        }
+    }
+
+    /// Whether `CloseEpochAccount` takes the account, the reward receivers are closed along with
+    /// their reward routers and the consensus result and epoch marker are kept for good
+    pub const fn is_closed_with_epoch(&self) -> bool {
+        matches!(
+            self,
+            Self::EpochState { .. }
+                | Self::WeightTable { .. }
+                | Self::EpochSnapshot { .. }
+                | Self::OperatorSnapshot { .. }
+                | Self::BallotBox { .. }
+                | Self::NCNRewardRouter { .. }
+                | Self::OperatorVaultRewardRouter { .. }
+        )
+    }
+
+    /// The seeds of the address, without the bump
+    pub fn seeds(&self) -> Vec<Vec<u8>> {
+        match self {
+            Self::Config { ncn } => vec![CONFIG_SEED.to_vec(), ncn.to_bytes().to_vec()],
+            Self::VaultRegistry { ncn } => {
+                vec![VAULT_REGISTRY_SEED.to_vec(), ncn.to_bytes().to_vec()]
+            }
+            // The NCN is in the seeds twice, deployed account payers are derived this way
+            Self::AccountPayer { ncn } => vec![
+                ACCOUNT_PAYER_SEED.to_vec(),
+                ncn.to_bytes().to_vec(),
+                ncn.to_bytes().to_vec(),
+            ],
+            Self::OperatorMetadata { operator, ncn } => vec![
+                OPERATOR_METADATA_SEED.to_vec(),
+                operator.to_bytes().to_vec(),
+                ncn.to_bytes().to_vec(),
+            ],
+            Self::KeeperLease { ncn, authority } => vec![
+                KEEPER_LEASE_SEED.to_vec(),
+                ncn.to_bytes().to_vec(),
+                authority.to_bytes().to_vec(),
+            ],
+            // The NCN is in the seeds twice, deployed epoch markers are derived this way
+            Self::EpochMarker { ncn, epoch } => vec![
+                EPOCH_MARKER_SEED.to_vec(),
+                ncn.to_bytes().to_vec(),
+                epoch.to_le_bytes().to_vec(),
+                ncn.to_bytes().to_vec(),
+            ],
+            Self::EpochState { ncn, epoch } => Self::ncn_epoch_seeds(EPOCH_STATE_SEED, ncn, *epoch),
+            Self::WeightTable { ncn, epoch } => {
+                Self::ncn_epoch_seeds(WEIGHT_TABLE_SEED, ncn, *epoch)
+            }
+            Self::EpochSnapshot { ncn, epoch } => {
+                Self::ncn_epoch_seeds(EPOCH_SNAPSHOT_SEED, ncn, *epoch)
+            }
+            Self::OperatorSnapshot {
+                operator,
+                ncn,
+                epoch,
+            } => Self::operator_ncn_epoch_seeds(OPERATOR_SNAPSHOT_SEED, operator, ncn, *epoch),
+            Self::BallotBox { ncn, epoch } => Self::ncn_epoch_seeds(BALLOT_BOX_SEED, ncn, *epoch),
+            Self::ConsensusResult { ncn, epoch } => {
+                Self::ncn_epoch_seeds(CONSENSUS_RESULT_SEED, ncn, *epoch)
+            }
+            Self::NCNRewardRouter { ncn, epoch } => {
+                Self::ncn_epoch_seeds(NCN_REWARD_ROUTER_SEED, ncn, *epoch)
+            }
+            Self::NCNRewardReceiver { ncn, epoch } => {
+                Self::ncn_epoch_seeds(NCN_REWARD_RECEIVER_SEED, ncn, *epoch)
+            }
+            Self::OperatorVaultRewardRouter {
+                operator,
+                ncn,
+                epoch,
+            } => Self::operator_ncn_epoch_seeds(
+                OPERATOR_VAULT_REWARD_ROUTER_SEED,
+                operator,
+                ncn,
+                *epoch,
+            ),
+            Self::OperatorVaultRewardReceiver {
+                operator,
+                ncn,
+                epoch,
+            } => Self::operator_ncn_epoch_seeds(
+                OPERATOR_VAULT_REWARD_RECEIVER_SEED,
+                operator,
+                ncn,
+                *epoch,
+            ),
+        }
+    }
+
+    /// The address, its bump and the seeds without the bump
+    pub fn find_program_address(&self, program_id: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = self.seeds();
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    pub fn address(&self, program_id: &Pubkey) -> Pubkey {
+        self.find_program_address(program_id).0
+    }
+
+    /// The seeds followed by the bump, to sign for the account with `invoke_signed`
+    pub fn signer_seeds(&self, program_id: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+        let (address, bump, mut seeds) = self.find_program_address(program_id);
+        seeds.push(vec![bump]);
+        (address, seeds)
+    }
+
+    fn ncn_epoch_seeds(seed: &[u8], ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        vec![
+            seed.to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ]
+    }
+
+    fn operator_ncn_epoch_seeds(
+        seed: &[u8],
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Vec<Vec<u8>> {
+        vec![
+            seed.to_vec(),
+            operator.to_bytes().to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeds() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+
+        assert_eq!(
+            NCNProgramPda::AccountPayer { ncn }.seeds(),
+            vec![
+                ACCOUNT_PAYER_SEED.to_vec(),
+                ncn.to_bytes().to_vec(),
+                ncn.to_bytes().to_vec()
+            ]
+        );
+        assert_eq!(
+            NCNProgramPda::BallotBox { ncn, epoch: 7 }.seeds(),
+            vec![
+                BALLOT_BOX_SEED.to_vec(),
+                ncn.to_bytes().to_vec(),
+                7_u64.to_le_bytes().to_vec()
+            ]
+        );
+        assert_eq!(
+            NCNProgramPda::OperatorSnapshot {
+                operator,
+                ncn,
+                epoch: 7
+            }
+            .seeds()[1],
+            operator.to_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_epoch_accounts() {
+        let ncn = Pubkey::new_unique();
+        let operators = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let accounts = NCNProgramPda::epoch_accounts(&ncn, &operators, 7);
+        assert_eq!(accounts.len(), 8 + 3 * operators.len());
+        assert_eq!(accounts[0], NCNProgramPda::EpochState { ncn, epoch: 7 });
+        assert_eq!(
+            accounts.last(),
+            Some(&NCNProgramPda::EpochMarker { ncn, epoch: 7 })
+        );
+
+        for operator in operators.iter() {
+            assert_eq!(
+                accounts
+                    .iter()
+                    .filter(|account| account.operator() == Some(operator))
+                    .count(),
+                3
+            );
+        }
+
+        let not_closed_directly: Vec<&NCNProgramPda> = accounts
+            .iter()
+            .filter(|account| !account.is_closed_with_epoch())
+            .collect();
+        assert_eq!(not_closed_directly.len(), 3 + operators.len());
+        assert!(!NCNProgramPda::ConsensusResult { ncn, epoch: 7 }.is_closed_with_epoch());
+    }
+
+    #[test]
+    fn test_signer_seeds() {
+        let program_id = Pubkey::new_unique();
+        let pda = NCNProgramPda::EpochState {
+            ncn: Pubkey::new_unique(),
+            epoch: 1,
+        };
+
+        let (address, bump, _) = pda.find_program_address(&program_id);
+        let (signer_address, signer_seeds) = pda.signer_seeds(&program_id);
+        assert_eq!(signer_address, address);
+        assert_eq!(signer_seeds.last(), Some(&vec![bump]));
+
+        let seeds: Vec<&[u8]> = signer_seeds.iter().map(|s| s.as_slice()).collect();
+        assert_eq!(
+            Pubkey::create_program_address(&seeds, &program_id).unwrap(),
+            address
+        );
+    }
+}
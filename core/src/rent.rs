@@ -0,0 +1,138 @@
+//! Rent the account payer spends on the accounts of one epoch, so admins can fund the
+//! `AccountPayer` for the epochs ahead instead of guessing.
+//!
+//! Every amount is the rent exempt minimum of the account at its full size: accounts created at
+//! `MAX_REALLOC_BYTES` and grown with realloc instructions end up paying the same. Closing the
+//! epoch accounts refunds the account payer, except for the consensus result and epoch marker that
+//! are kept for good.
+
+use solana_program::rent::Rent;
+
+use crate::{
+    ballot_box::BallotBox,
+    consensus_result::ConsensusResult,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    ncn_reward_router::NCNRewardRouter,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    weight_table::WeightTable,
+};
+
+/// Total rent the account payer needs for one epoch with `operator_count` operators, in lamports
+pub fn epoch_rent(rent: &Rent, operator_count: u64) -> Result<u64, NCNProgramError> {
+    EpochRentBudget::new(rent, operator_count)?.total()
+}
+
+/// Rent of every account the account payer funds during one epoch, in lamports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochRentBudget {
+    pub operator_count: u64,
+    pub epoch_state: u64,
+    pub weight_table: u64,
+    pub epoch_snapshot: u64,
+    /// All operator snapshots of the epoch
+    pub operator_snapshots: u64,
+    pub ballot_box: u64,
+    pub consensus_result: u64,
+    pub ncn_reward_router: u64,
+    /// The rent exempt minimum of the system account receiving the NCN rewards
+    pub ncn_reward_receiver: u64,
+    /// All operator vault reward routers of the epoch
+    pub operator_vault_reward_routers: u64,
+    /// All operator vault reward receivers of the epoch
+    pub operator_vault_reward_receivers: u64,
+    /// Created when the epoch is closed
+    pub epoch_marker: u64,
+}
+
+impl EpochRentBudget {
+    /// Computes the rent of one epoch with `operator_count` operators
+    pub fn new(rent: &Rent, operator_count: u64) -> Result<Self, NCNProgramError> {
+        let per_operator = |size: usize| {
+            rent.minimum_balance(size)
+                .checked_mul(operator_count)
+                .ok_or(NCNProgramError::ArithmeticOverflow)
+        };
+
+        Ok(Self {
+            operator_count,
+            epoch_state: rent.minimum_balance(EpochState::SIZE),
+            weight_table: rent.minimum_balance(WeightTable::SIZE),
+            epoch_snapshot: rent.minimum_balance(EpochSnapshot::SIZE),
+            operator_snapshots: per_operator(OperatorSnapshot::SIZE)?,
+            ballot_box: rent.minimum_balance(BallotBox::SIZE),
+            consensus_result: rent.minimum_balance(ConsensusResult::SIZE),
+            ncn_reward_router: rent.minimum_balance(NCNRewardRouter::SIZE),
+            ncn_reward_receiver: rent.minimum_balance(0),
+            operator_vault_reward_routers: per_operator(OperatorVaultRewardRouter::SIZE)?,
+            operator_vault_reward_receivers: per_operator(0)?,
+            epoch_marker: rent.minimum_balance(EpochMarker::SIZE),
+        })
+    }
+
+    /// Total rent the account payer needs to hold for the epoch
+    pub fn total(&self) -> Result<u64, NCNProgramError> {
+        [
+            self.epoch_state,
+            self.weight_table,
+            self.epoch_snapshot,
+            self.operator_snapshots,
+            self.ballot_box,
+            self.consensus_result,
+            self.ncn_reward_router,
+            self.ncn_reward_receiver,
+            self.operator_vault_reward_routers,
+            self.operator_vault_reward_receivers,
+            self.epoch_marker,
+        ]
+        .iter()
+        .try_fold(0_u64, |total, rent| total.checked_add(*rent))
+        .ok_or(NCNProgramError::ArithmeticOverflow)
+    }
+
+    /// Rent that stays locked once the epoch is closed, the rest is refunded to the account payer
+    pub fn retained(&self) -> Result<u64, NCNProgramError> {
+        self.consensus_result
+            .checked_add(self.epoch_marker)
+            .ok_or(NCNProgramError::ArithmeticOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_rent_budget() {
+        let rent = Rent::default();
+
+        let no_operators = EpochRentBudget::new(&rent, 0).unwrap();
+        assert_eq!(no_operators.operator_snapshots, 0);
+        assert_eq!(no_operators.operator_vault_reward_receivers, 0);
+
+        let budget = EpochRentBudget::new(&rent, 3).unwrap();
+        assert_eq!(
+            budget.operator_snapshots,
+            3 * rent.minimum_balance(OperatorSnapshot::SIZE)
+        );
+        assert_eq!(
+            budget.operator_vault_reward_receivers,
+            3 * rent.minimum_balance(0)
+        );
+
+        let per_operator = rent.minimum_balance(OperatorSnapshot::SIZE)
+            + rent.minimum_balance(OperatorVaultRewardRouter::SIZE)
+            + rent.minimum_balance(0);
+        assert_eq!(
+            budget.total().unwrap(),
+            no_operators.total().unwrap() + 3 * per_operator
+        );
+        assert_eq!(epoch_rent(&rent, 3).unwrap(), budget.total().unwrap());
+        assert_eq!(
+            budget.retained().unwrap(),
+            rent.minimum_balance(ConsensusResult::SIZE) + rent.minimum_balance(EpochMarker::SIZE)
+        );
+    }
+}
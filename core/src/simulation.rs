@@ -0,0 +1,308 @@
+//! Off-chain preview of the reward waterfall, so keepers and operators can check what a routing
+//! pass will pay before cranking it.
+//!
+//! The simulation drives the same routers the program uses on heap allocated copies, so the
+//! amounts match the on-chain routing to the lamport.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    ballot_box::BallotBox,
+    builders::zeroed_account,
+    config::Config,
+    constants::MAX_FEE_GROUPS,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    error::NCNProgramError,
+    ncn_reward_router::NCNRewardRouter,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+};
+
+/// What a single vault receives from an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultRewardSimulation {
+    pub vault: Pubkey,
+    pub rewards: u64,
+}
+
+/// What an operator that voted for the winning ballot receives and how it is split with its vaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorRewardSimulation {
+    pub operator: Pubkey,
+    /// Everything routed to the operator vault reward route of the operator
+    pub route_rewards: u64,
+    /// The operator fee, rounding leftovers of the vault split included. Without an operator
+    /// snapshot the whole route is counted here
+    pub operator_rewards: u64,
+    /// Empty when no operator snapshot was given for the operator
+    pub vaults: Vec<VaultRewardSimulation>,
+}
+
+/// The amounts every party receives out of the simulated rewards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardSimulation {
+    pub rewards: u64,
+    pub protocol_rewards: u64,
+    /// The NCN fee, rounding leftovers of the operator split included
+    pub ncn_rewards: u64,
+    pub fee_group_rewards: [u64; MAX_FEE_GROUPS],
+    pub operators: Vec<OperatorRewardSimulation>,
+}
+
+impl RewardSimulation {
+    /// Total of everything paid out, equal to `rewards` for a consistent simulation
+    pub fn total_distributed(&self) -> u64 {
+        let fee_group_rewards: u64 = self.fee_group_rewards.iter().sum();
+        let operator_vault_rewards: u64 = self
+            .operators
+            .iter()
+            .map(|operator| operator.route_rewards)
+            .sum();
+
+        self.protocol_rewards + self.ncn_rewards + fee_group_rewards + operator_vault_rewards
+    }
+}
+
+/// Simulates routing `rewards` lamports of the epoch through the NCN and operator vault reward
+/// routers.
+///
+/// Mirrors `RouteNCNRewards` and `RouteOperatorVaultRewards` without a cranker: the fees are the
+/// ones snapshotted in the epoch snapshot and the timeliness bonus is read from the config.
+/// Operators missing from `operator_snapshots` are reported without their vault split.
+///
+/// ### Parameters:
+/// - `config`: The NCN config
+/// - `epoch_snapshot`: The epoch snapshot of the epoch
+/// - `ballot_box`: The ballot box of the epoch, consensus has to be reached
+/// - `operator_snapshots`: Operator snapshots of the epoch to split the operator routes with
+/// - `rewards`: The rewards to route, without the rent of the reward receiver
+pub fn simulate_reward_waterfall(
+    config: &Config,
+    epoch_snapshot: &EpochSnapshot,
+    ballot_box: &BallotBox,
+    operator_snapshots: &[OperatorSnapshot],
+    rewards: u64,
+) -> Result<RewardSimulation, NCNProgramError> {
+    let mut ncn_reward_router = zeroed_account::<NCNRewardRouter>();
+    ncn_reward_router.initialize(
+        &Pubkey::default(),
+        epoch_snapshot.epoch(),
+        0,
+        ballot_box.slot_created(),
+    );
+
+    ncn_reward_router.route_to_reward_pool(rewards)?;
+    ncn_reward_router.route_reward_pool(epoch_snapshot.fees())?;
+    ncn_reward_router.route_operator_vault_rewards(
+        ballot_box,
+        config.timeliness_bonus_slots(),
+        config.timeliness_bonus_bps(),
+        u16::MAX,
+    )?;
+
+    let mut fee_group_rewards = [0; MAX_FEE_GROUPS];
+    for (group, fee_group_reward) in fee_group_rewards.iter_mut().enumerate() {
+        *fee_group_reward = ncn_reward_router.fee_group_rewards(group)?;
+    }
+
+    let mut operators = vec![];
+    for route in ncn_reward_router.iter_routes() {
+        let route_rewards = route.rewards()?;
+
+        let operator_snapshot = operator_snapshots
+            .iter()
+            .find(|snapshot| snapshot.operator().eq(route.operator()));
+
+        let operator = match operator_snapshot {
+            Some(operator_snapshot) => simulate_operator_split(operator_snapshot, route_rewards)?,
+            None => OperatorRewardSimulation {
+                operator: *route.operator(),
+                route_rewards,
+                operator_rewards: route_rewards,
+                vaults: vec![],
+            },
+        };
+
+        operators.push(operator);
+    }
+
+    Ok(RewardSimulation {
+        rewards,
+        protocol_rewards: ncn_reward_router.protocol_rewards(),
+        ncn_rewards: ncn_reward_router.ncn_rewards(),
+        fee_group_rewards,
+        operators,
+    })
+}
+
+/// Splits the rewards of an operator vault reward route between the operator and its vaults
+fn simulate_operator_split(
+    operator_snapshot: &OperatorSnapshot,
+    route_rewards: u64,
+) -> Result<OperatorRewardSimulation, NCNProgramError> {
+    let mut operator_vault_reward_router = zeroed_account::<OperatorVaultRewardRouter>();
+    *operator_vault_reward_router = OperatorVaultRewardRouter::new(
+        operator_snapshot.operator(),
+        operator_snapshot.ncn_operator_index(),
+        operator_snapshot.ncn(),
+        operator_snapshot.epoch(),
+        0,
+        0,
+    );
+
+    operator_vault_reward_router.route_to_reward_pool(route_rewards)?;
+    operator_vault_reward_router.route_operator_rewards(operator_snapshot)?;
+    operator_vault_reward_router.route_reward_pool(operator_snapshot, u16::MAX)?;
+
+    let vaults = operator_vault_reward_router
+        .vault_reward_routes()
+        .iter()
+        .filter(|route| !route.is_empty())
+        .map(|route| VaultRewardSimulation {
+            vault: route.vault(),
+            rewards: route.rewards(),
+        })
+        .collect();
+
+    Ok(OperatorRewardSimulation {
+        operator: *operator_snapshot.operator(),
+        route_rewards,
+        operator_rewards: operator_vault_reward_router.operator_rewards(),
+        vaults,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ballot_box::Ballot, fees::Fees, stake_weight::StakeWeights};
+
+    const EPOCH: u64 = 1;
+    const SLOT: u64 = 100;
+
+    fn operator_snapshot(
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        operator_fee_bps: u16,
+        vault_stake_weights: &[(Pubkey, u128)],
+    ) -> Box<OperatorSnapshot> {
+        let mut operator_snapshot = OperatorSnapshot::builder()
+            .operator(operator)
+            .ncn(ncn)
+            .epoch(EPOCH)
+            .operator_fee_bps(operator_fee_bps)
+            .vault_operator_delegation_count(vault_stake_weights.len() as u64)
+            .build()
+            .unwrap();
+
+        for (vault_index, (vault, stake_weight)) in vault_stake_weights.iter().enumerate() {
+            operator_snapshot
+                .increment_vault_operator_delegation_registration(
+                    SLOT,
+                    vault,
+                    vault_index as u64,
+                    &StakeWeights::snapshot(*stake_weight).unwrap(),
+                )
+                .unwrap();
+        }
+
+        operator_snapshot
+    }
+
+    #[test]
+    fn test_simulate_reward_waterfall() {
+        let ncn = Pubkey::new_unique();
+        let operator_1 = Pubkey::new_unique();
+        let operator_2 = Pubkey::new_unique();
+        let vault_1 = Pubkey::new_unique();
+        let vault_2 = Pubkey::new_unique();
+
+        let config = Config::new_for_test(&ncn);
+        let epoch_snapshot = EpochSnapshot::builder()
+            .ncn(&ncn)
+            .epoch(EPOCH)
+            .operator_count(2)
+            .fees(&Fees::new(100, EPOCH).unwrap())
+            .build();
+
+        let mut ballot_box = BallotBox::builder()
+            .ncn(&ncn)
+            .epoch(EPOCH)
+            .slot_created(SLOT)
+            .build();
+        let ballot = Ballot::new(0);
+        for (operator, stake_weight) in [(operator_1, 300), (operator_2, 100)] {
+            ballot_box
+                .cast_vote(
+                    &operator,
+                    &ballot,
+                    &StakeWeights::snapshot(stake_weight).unwrap(),
+                    SLOT,
+                    config.valid_slots_after_consensus(),
+                )
+                .unwrap();
+        }
+        ballot_box
+            .tally_votes(&StakeWeights::snapshot(400).unwrap(), SLOT)
+            .unwrap();
+
+        // Only the first operator has a snapshot to split its route with
+        let operator_snapshots = [*operator_snapshot(
+            &operator_1,
+            &ncn,
+            1_000,
+            &[(vault_1, 200), (vault_2, 100)],
+        )];
+
+        let simulation = simulate_reward_waterfall(
+            &config,
+            &epoch_snapshot,
+            &ballot_box,
+            &operator_snapshots,
+            10_000,
+        )
+        .unwrap();
+
+        assert_eq!(simulation.protocol_rewards, 400); // 4%
+        assert_eq!(simulation.ncn_rewards, 100); // 1%
+        assert_eq!(simulation.fee_group_rewards, [0; MAX_FEE_GROUPS]);
+        assert_eq!(simulation.total_distributed(), 10_000);
+
+        // 3/4 and 1/4 of the 9,500 operator vault rewards
+        let split_operator = &simulation.operators[0];
+        assert_eq!(split_operator.operator, operator_1);
+        assert_eq!(split_operator.route_rewards, 7_125);
+        // 10% fee of 712 plus the lamport left over by the vault split
+        assert_eq!(split_operator.operator_rewards, 713);
+        assert_eq!(
+            split_operator.vaults,
+            vec![
+                VaultRewardSimulation {
+                    vault: vault_1,
+                    rewards: 4_275,
+                },
+                VaultRewardSimulation {
+                    vault: vault_2,
+                    rewards: 2_137,
+                },
+            ]
+        );
+
+        let unsplit_operator = &simulation.operators[1];
+        assert_eq!(unsplit_operator.operator, operator_2);
+        assert_eq!(unsplit_operator.route_rewards, 2_375);
+        assert_eq!(unsplit_operator.operator_rewards, 2_375);
+        assert!(unsplit_operator.vaults.is_empty());
+    }
+
+    #[test]
+    fn test_simulate_without_consensus() {
+        let ncn = Pubkey::new_unique();
+        let config = Config::new_for_test(&ncn);
+        let epoch_snapshot = EpochSnapshot::new_for_test(&ncn, EPOCH, 1, 1);
+        let ballot_box = BallotBox::new_for_test(&ncn, EPOCH);
+
+        assert!(
+            simulate_reward_waterfall(&config, &epoch_snapshot, &ballot_box, &[], 1_000).is_err()
+        );
+    }
+}
@@ -1,52 +1,75 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::types::PodU128;
 use shank::ShankType;
+/// Stake weights are 256-bit, re-exported so users of the core crate do not need `spl-math`
+pub use spl_math::uint::U256;
 
-use crate::error::NCNProgramError;
+use crate::{constants::NORMALIZED_DECIMALS, error::NCNProgramError};
 
-#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+/// Stake weights are accumulated in 256 bits, so that large token supplies times large weights
+/// summed over every vault and operator cannot overflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, ShankType, Pod)]
 #[repr(C)]
 pub struct StakeWeights {
-    /// The total stake weight - used for voting
+    /// The lower 128 bits of the total stake weight - used for voting
     stake_weight: PodU128,
+    /// The upper 128 bits of the total stake weight
+    stake_weight_hi: PodU128,
 }
 
 impl Default for StakeWeights {
     fn default() -> Self {
         Self {
             stake_weight: PodU128::from(0),
+            stake_weight_hi: PodU128::from(0),
         }
     }
 }
 
 impl StakeWeights {
     pub fn new(stake_weight: u128) -> Self {
+        Self::new_u256(U256::from(stake_weight))
+    }
+
+    pub fn new_u256(stake_weight: U256) -> Self {
         Self {
-            stake_weight: PodU128::from(stake_weight),
+            stake_weight: PodU128::from(stake_weight.low_u128()),
+            stake_weight_hi: PodU128::from((stake_weight >> 128).low_u128()),
         }
     }
 
     pub fn snapshot(stake_weight: u128) -> Result<Self, NCNProgramError> {
         let mut stake_weights = Self::default();
 
-        stake_weights.increment_stake_weight(stake_weight)?;
+        stake_weights.increment_stake_weight(U256::from(stake_weight))?;
 
         Ok(stake_weights)
     }
 
+    /// The stake weight, saturating at `u128::MAX`. Math on stake weights should use
+    /// `stake_weight_u256`, which is exact.
     pub fn stake_weight(&self) -> u128 {
-        self.stake_weight.into()
+        if u128::from(self.stake_weight_hi) == 0 {
+            self.stake_weight.into()
+        } else {
+            u128::MAX
+        }
+    }
+
+    pub fn stake_weight_u256(&self) -> U256 {
+        (U256::from(u128::from(self.stake_weight_hi)) << 128)
+            | U256::from(u128::from(self.stake_weight))
     }
 
     pub fn increment(&mut self, stake_weight: &Self) -> Result<(), NCNProgramError> {
-        self.increment_stake_weight(stake_weight.stake_weight())?;
+        self.increment_stake_weight(stake_weight.stake_weight_u256())?;
 
         Ok(())
     }
 
-    fn increment_stake_weight(&mut self, stake_weight: u128) -> Result<(), NCNProgramError> {
-        self.stake_weight = PodU128::from(
-            self.stake_weight()
+    fn increment_stake_weight(&mut self, stake_weight: U256) -> Result<(), NCNProgramError> {
+        *self = Self::new_u256(
+            self.stake_weight_u256()
                 .checked_add(stake_weight)
                 .ok_or(NCNProgramError::ArithmeticOverflow)?,
         );
@@ -55,14 +78,14 @@ impl StakeWeights {
     }
 
     pub fn decrement(&mut self, other: &Self) -> Result<(), NCNProgramError> {
-        self.decrement_stake_weight(other.stake_weight())?;
+        self.decrement_stake_weight(other.stake_weight_u256())?;
 
         Ok(())
     }
 
-    fn decrement_stake_weight(&mut self, stake_weight: u128) -> Result<(), NCNProgramError> {
-        self.stake_weight = PodU128::from(
-            self.stake_weight()
+    fn decrement_stake_weight(&mut self, stake_weight: U256) -> Result<(), NCNProgramError> {
+        *self = Self::new_u256(
+            self.stake_weight_u256()
                 .checked_sub(stake_weight)
                 .ok_or(NCNProgramError::ArithmeticOverflow)?,
         );
@@ -71,8 +94,141 @@ impl StakeWeights {
     }
 }
 
+/// Shifts a stake weight and the total it is a share of down until the total fits in a u128.
+/// The ratio between them is kept, only precision far below the total is lost.
+pub fn scale_to_u128(
+    stake_weight: U256,
+    total_stake_weight: U256,
+) -> Result<(u128, u128), NCNProgramError> {
+    let shift = total_stake_weight.bits().saturating_sub(128);
+
+    Ok((
+        u256_to_u128(stake_weight >> shift)?,
+        u256_to_u128(total_stake_weight >> shift)?,
+    ))
+}
+
+/// `value * numerator / denominator`, rounded to the nearest integer.
+///
+/// If the product does not fit in 256 bits the value and the fraction are each shifted down to
+/// 128 bits first, which only loses precision far below the result.
+pub fn mul_div_round(
+    value: U256,
+    numerator: U256,
+    denominator: U256,
+) -> Result<U256, NCNProgramError> {
+    let (value_shift, fraction_shift) = match value.checked_mul(numerator) {
+        Some(_) => (0, 0),
+        None => (
+            value.bits().saturating_sub(128),
+            numerator.bits().saturating_sub(128),
+        ),
+    };
+
+    let value = value >> value_shift;
+    let numerator = numerator >> fraction_shift;
+    let denominator = denominator >> fraction_shift;
+
+    if denominator.is_zero() {
+        return Err(NCNProgramError::DenominatorIsZero);
+    }
+
+    let result = value
+        .checked_mul(numerator)
+        .and_then(|x| x.checked_add(denominator >> 1))
+        .and_then(|x| x.checked_div(denominator))
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+    if result.bits() + value_shift > 256 {
+        return Err(NCNProgramError::ArithmeticOverflow);
+    }
+
+    Ok(result << value_shift)
+}
+
+/// Share of `rewards` owed to `stake_weight` out of `total_stake_weight`, rounded down:
+/// `rewards * stake_weight / total_stake_weight`.
+///
+/// Both stake weights are scaled into a u128 with `scale_to_u128` and the product is computed
+/// exactly when it fits. Otherwise both are shifted down until the total fits in 64 bits, which
+/// only loses precision far below a unit of the rewards. Every reward router splits rewards
+/// by stake weight through this, so they all round the same way.
+pub fn stake_weight_share(
+    rewards: u64,
+    stake_weight: U256,
+    total_stake_weight: U256,
+) -> Result<u64, NCNProgramError> {
+    if stake_weight.is_zero() || rewards == 0 {
+        return Ok(0);
+    }
+
+    let (stake_weight, total_stake_weight) = scale_to_u128(stake_weight, total_stake_weight)?;
+
+    let share = match (rewards as u128).checked_mul(stake_weight) {
+        Some(product) => product.checked_div(total_stake_weight),
+        None => {
+            let shift = 64_u32.saturating_sub(total_stake_weight.leading_zeros());
+            (rewards as u128)
+                .checked_mul(stake_weight >> shift)
+                .and_then(|x| x.checked_div(total_stake_weight >> shift))
+        }
+    }
+    .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+    share
+        .try_into()
+        .map_err(|_| NCNProgramError::CastToU64Error)
+}
+
+/// Brings an amount of a token with `decimals` to `NORMALIZED_DECIMALS`, so that one whole token
+/// of any st-mint is the same amount. Precision below `NORMALIZED_DECIMALS` is rounded down.
+pub fn normalize_amount(amount: u64, decimals: u8) -> Result<U256, NCNProgramError> {
+    normalize(U256::from(amount), decimals)
+}
+
+/// Stake weight of `amount` tokens of a st-mint with `decimals`: `amount * weight / 10^decimals`,
+/// counted in units of `10^-NORMALIZED_DECIMALS` so that fractions of a token keep their weight
+pub fn normalized_stake_weight(
+    amount: u64,
+    decimals: u8,
+    weight: u128,
+) -> Result<U256, NCNProgramError> {
+    // A u64 times a u128 always fits in 256 bits
+    let stake_weight = U256::from(amount)
+        .checked_mul(U256::from(weight))
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+
+    normalize(stake_weight, decimals)
+}
+
+fn normalize(value: U256, decimals: u8) -> Result<U256, NCNProgramError> {
+    if decimals <= NORMALIZED_DECIMALS {
+        let factor = U256::from(10).pow(U256::from(NORMALIZED_DECIMALS - decimals));
+
+        value
+            .checked_mul(factor)
+            .ok_or(NCNProgramError::ArithmeticOverflow)
+    } else {
+        // Past 10^77 the divisor does not fit in 256 bits, and any value rounds down to zero
+        match U256::from(10).checked_pow(U256::from(decimals - NORMALIZED_DECIMALS)) {
+            Some(divisor) => Ok(value / divisor),
+            None => Ok(U256::zero()),
+        }
+    }
+}
+
+pub fn u256_to_u128(value: U256) -> Result<u128, NCNProgramError> {
+    if value.bits() > 128 {
+        return Err(NCNProgramError::ArithmeticOverflow);
+    }
+
+    Ok(value.low_u128())
+}
+
 #[cfg(test)]
 mod tests {
+    use spl_math::precise_number::PreciseNumber;
+
     use super::*;
 
     #[test]
@@ -111,24 +267,234 @@ mod tests {
     }
 
     #[test]
-    fn test_stake_weights_overflow() {
-        // Test stake weight overflow
-        let mut base_weights = StakeWeights::default();
-        let max_weight = StakeWeights::snapshot(u128::MAX).unwrap();
+    fn test_stake_weights_above_u128() {
+        let mut base_weights = StakeWeights::snapshot(u128::MAX).unwrap();
+
+        // Accumulating past a u128 carries into the upper bits instead of overflowing
+        base_weights
+            .increment(&StakeWeights::snapshot(u128::MAX).unwrap())
+            .unwrap();
+        assert_eq!(
+            base_weights.stake_weight_u256(),
+            U256::from(u128::MAX) * U256::from(2u64)
+        );
+        assert_eq!(base_weights.stake_weight(), u128::MAX);
+
+        base_weights
+            .decrement(&StakeWeights::snapshot(u128::MAX).unwrap())
+            .unwrap();
+        assert_eq!(base_weights.stake_weight_u256(), U256::from(u128::MAX));
+        assert_eq!(base_weights.stake_weight(), u128::MAX);
+
+        base_weights
+            .decrement(&StakeWeights::snapshot(1).unwrap())
+            .unwrap();
+        assert_eq!(base_weights.stake_weight(), u128::MAX - 1);
+    }
 
-        base_weights.increment(&max_weight).unwrap();
+    #[test]
+    fn test_scale_to_u128() {
+        // Totals that fit are left untouched
+        assert_eq!(
+            scale_to_u128(U256::from(1_000u64), U256::from(3_000u64)).unwrap(),
+            (1_000, 3_000)
+        );
 
-        // Adding any more should overflow
-        let additional_weight = StakeWeights::snapshot(1u128).unwrap();
+        let total_stake_weight = U256::from(u128::MAX) * U256::from(6u64);
+        let (stake_weight, total) =
+            scale_to_u128(total_stake_weight / U256::from(3u64), total_stake_weight).unwrap();
+        assert_eq!(total / stake_weight, 3);
 
-        assert!(base_weights.increment(&additional_weight).is_err());
+        // A share larger than the total cannot be scaled into a u128
+        assert!(scale_to_u128(U256::MAX, U256::from(1u64)).is_err());
+    }
+
+    /// The `PreciseNumber` implementation the routing math used to run on
+    fn precise_proportion(amount: u64, numerator: u128, denominator: u128) -> u64 {
+        let precise_amount = PreciseNumber::new(amount as u128).unwrap();
+        let precise_numerator = PreciseNumber::new(numerator).unwrap();
+        let precise_denominator = PreciseNumber::new(denominator).unwrap();
+
+        let result: u128 = precise_amount
+            .checked_mul(&precise_numerator)
+            .and_then(|x| x.checked_div(&precise_denominator))
+            .and_then(|x| x.floor())
+            .and_then(|x| x.to_imprecise())
+            .unwrap();
+
+        result.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_stake_weight_share_matches_precise_number() {
+        let rewards = [1, 3, 1_000, 123_456_789, 10_000_000_000];
+        let totals: [u128; 5] = [1, 3, 1_000, 987_654_321, 1_000_000_000_000];
+
+        for rewards_to_process in rewards {
+            for total_stake_weight in totals {
+                for stake_weight in [
+                    1,
+                    total_stake_weight / 3,
+                    total_stake_weight / 2,
+                    total_stake_weight - 1,
+                    total_stake_weight,
+                ] {
+                    if stake_weight == 0 {
+                        continue;
+                    }
+
+                    assert_eq!(
+                        stake_weight_share(
+                            rewards_to_process,
+                            U256::from(stake_weight),
+                            U256::from(total_stake_weight),
+                        )
+                        .unwrap(),
+                        precise_proportion(rewards_to_process, stake_weight, total_stake_weight),
+                        "stake weight: {}, total: {}, rewards: {}",
+                        stake_weight,
+                        total_stake_weight,
+                        rewards_to_process
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stake_weight_share_large_stake_weights() {
+        // Realistic stake weights (stake times a 9 decimal weight) overflow a u128 once multiplied
+        let total_stake_weight: u128 = 3 * 10_u128.pow(30);
+        let stake_weight = total_stake_weight / 3;
+        let rewards = u64::MAX / 2;
+
+        let share = stake_weight_share(
+            rewards,
+            U256::from(stake_weight),
+            U256::from(total_stake_weight),
+        )
+        .unwrap();
+
+        let expected_share = rewards / 3;
+        assert!(share <= expected_share);
+        assert!(expected_share - share <= 1);
+        assert_eq!(
+            share,
+            precise_proportion(rewards, stake_weight, total_stake_weight)
+        );
+
+        // Scaling only loses precision far below a unit of the expected share
+        let total_stake_weight = u128::MAX / 2;
+        let share = stake_weight_share(
+            u64::MAX,
+            U256::from(total_stake_weight / 4),
+            U256::from(total_stake_weight),
+        )
+        .unwrap();
+        assert!(share <= u64::MAX / 4);
+        assert!(u64::MAX / 4 - share <= 1);
+
+        // The whole pool goes to a stake weight holding all of the total
+        assert_eq!(
+            stake_weight_share(u64::MAX, U256::from(u128::MAX), U256::from(u128::MAX)).unwrap(),
+            u64::MAX
+        );
+
+        // Stake weights above a u128 are split by the same ratio
+        let total_stake_weight = U256::from(u128::MAX) * U256::from(1_000u64);
+        let share = stake_weight_share(
+            1_000_000,
+            total_stake_weight / U256::from(4u64),
+            total_stake_weight,
+        )
+        .unwrap();
+        assert!(share <= 250_000);
+        assert!(250_000 - share <= 1);
+    }
+
+    #[test]
+    fn test_stake_weight_share_zero() {
+        assert_eq!(
+            stake_weight_share(0, U256::from(1u64), U256::from(2u64)).unwrap(),
+            0
+        );
+        assert_eq!(
+            stake_weight_share(1_000, U256::zero(), U256::zero()).unwrap(),
+            0
+        );
+
+        // No total stake weight cannot be split against
+        assert_eq!(
+            stake_weight_share(1_000, U256::from(1u64), U256::zero()),
+            Err(NCNProgramError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_mul_div_round() {
+        assert_eq!(
+            mul_div_round(
+                U256::from(10_000u64),
+                U256::from(3_000u64),
+                U256::from(10_000u64)
+            )
+            .unwrap(),
+            U256::from(3_000u64)
+        );
+
+        // Rounds to the nearest integer
+        assert_eq!(
+            mul_div_round(U256::from(5u64), U256::from(1u64), U256::from(3u64)).unwrap(),
+            U256::from(2u64)
+        );
+        assert_eq!(
+            mul_div_round(U256::from(4u64), U256::from(1u64), U256::from(3u64)).unwrap(),
+            U256::from(1u64)
+        );
+
+        // Products above 256 bits are scaled instead of overflowing
+        let value = U256::MAX / U256::from(4u64);
+        let expected = value / U256::from(3u64);
+        let result = mul_div_round(value, expected, value).unwrap();
+        let error = if result > expected {
+            result - expected
+        } else {
+            expected - result
+        };
+        assert!(error <= expected >> 100);
+
+        assert_eq!(
+            mul_div_round(U256::from(1u64), U256::from(1u64), U256::zero()),
+            Err(NCNProgramError::DenominatorIsZero)
+        );
     }
 
     #[test]
-    fn test_stake_weights_increment_overflow() {
+    fn test_normalized_stake_weight() {
+        // One whole token weighs the same whatever the decimals of its mint
+        let nine_decimals = normalized_stake_weight(1_000_000_000, 9, 100).unwrap();
+        assert_eq!(nine_decimals, U256::from(100_000_000_000u64));
+        assert_eq!(
+            normalized_stake_weight(1_000_000, 6, 100).unwrap(),
+            nine_decimals
+        );
+        assert_eq!(
+            normalized_stake_weight(1_000_000_000_000, 12, 100).unwrap(),
+            nine_decimals
+        );
+        assert_eq!(normalized_stake_weight(1, 0, 100).unwrap(), nine_decimals);
+
+        // Precision below the normalized decimals is rounded down
+        assert_eq!(normalize_amount(1_999, 12).unwrap(), U256::from(1u64));
+        assert_eq!(normalize_amount(u64::MAX, u8::MAX).unwrap(), U256::zero());
+        assert_eq!(normalize_amount(5, 9).unwrap(), U256::from(5u64));
+    }
+
+    #[test]
+    fn test_stake_weights_overflow() {
         // Test stake weight overflow
         let mut base_weights = StakeWeights::default();
-        let max_weight = StakeWeights::snapshot(u128::MAX).unwrap();
+        let max_weight = StakeWeights::new_u256(U256::MAX);
 
         base_weights.increment(&max_weight).unwrap();
 
@@ -136,15 +502,17 @@ mod tests {
         let additional_weight = StakeWeights::snapshot(1u128).unwrap();
 
         assert!(base_weights.increment(&additional_weight).is_err());
+    }
 
-        // Test NCN fee group weight overflow
-        let mut base_weights = StakeWeights::default();
+    #[test]
+    fn test_stake_weights_decrement_underflow() {
+        let mut base_weights = StakeWeights::snapshot(1000u128).unwrap();
 
-        // Use smaller numbers that won't overflow in the initial calculation
-        // but will overflow when incremented twice
-        let max_reward = StakeWeights::snapshot(u128::MAX).unwrap();
+        assert!(base_weights
+            .decrement(&StakeWeights::snapshot(1001u128).unwrap())
+            .is_err());
 
-        base_weights.increment(&max_reward).unwrap();
-        assert!(base_weights.increment(&max_reward).is_err());
+        // Failed decrements leave the stake weight untouched
+        assert_eq!(base_weights.stake_weight(), 1000u128);
     }
 }
@@ -0,0 +1,120 @@
+//! Weights read from Switchboard on-demand pull feeds, the only price feeds the weight table can
+//! follow. Pyth price accounts are not read, a mint priced by Pyth keeps an admin-set weight.
+
+use crate::{
+    constants::{MAX_SWITCHBOARD_CONFIDENCE_BPS, MAX_SWITCHBOARD_STALE_SLOTS},
+    error::NCNProgramError,
+};
+
+/// Switchboard on-demand results are fixed point numbers with 18 decimals
+pub const SWITCHBOARD_DECIMALS: u32 = 18;
+/// Feed prices are stored in the weight table with 9 decimals
+pub const SWITCHBOARD_WEIGHT_DECIMALS: u32 = 9;
+
+/// Validates a switchboard feed result and converts it into a weight table weight.
+///
+/// ### Parameters:
+/// - `value`: The feed value, with `SWITCHBOARD_DECIMALS` decimals
+/// - `std_dev`: The standard deviation of the feed samples, with `SWITCHBOARD_DECIMALS` decimals
+/// - `result_slot`: The slot the feed result was produced in
+/// - `current_slot`: The current slot
+pub fn weight_from_switchboard_result(
+    value: i128,
+    std_dev: i128,
+    result_slot: u64,
+    current_slot: u64,
+) -> Result<u128, NCNProgramError> {
+    let stale_slot = result_slot
+        .checked_add(MAX_SWITCHBOARD_STALE_SLOTS)
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    if current_slot > stale_slot {
+        return Err(NCNProgramError::StaleSwitchboardFeed);
+    }
+
+    if value <= 0 || std_dev < 0 {
+        return Err(NCNProgramError::BadSwitchboardValue);
+    }
+
+    let value = value as u128;
+    let std_dev = std_dev as u128;
+
+    // std_dev / value <= MAX_SWITCHBOARD_CONFIDENCE_BPS / 10_000
+    let scaled_std_dev = std_dev
+        .checked_mul(10_000)
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    let max_std_dev = value
+        .checked_mul(MAX_SWITCHBOARD_CONFIDENCE_BPS)
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    if scaled_std_dev > max_std_dev {
+        return Err(NCNProgramError::SwitchboardConfidenceTooLow);
+    }
+
+    let divisor = 10u128
+        .checked_pow(SWITCHBOARD_DECIMALS - SWITCHBOARD_WEIGHT_DECIMALS)
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    let weight = value
+        .checked_div(divisor)
+        .ok_or(NCNProgramError::DenominatorIsZero)?;
+
+    if weight == 0 {
+        return Err(NCNProgramError::BadSwitchboardValue);
+    }
+
+    Ok(weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i128 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn test_weight_from_switchboard_result() {
+        let weight = weight_from_switchboard_result(150 * ONE, 0, 1_000, 1_000).unwrap();
+        assert_eq!(weight, 150_000_000_000);
+
+        // Fractional prices keep 9 decimals
+        let weight = weight_from_switchboard_result(ONE / 4, 0, 1_000, 1_000).unwrap();
+        assert_eq!(weight, 250_000_000);
+    }
+
+    #[test]
+    fn test_stale_switchboard_result() {
+        let result =
+            weight_from_switchboard_result(ONE, 0, 1_000, 1_000 + MAX_SWITCHBOARD_STALE_SLOTS);
+        assert!(result.is_ok());
+
+        let result =
+            weight_from_switchboard_result(ONE, 0, 1_000, 1_000 + MAX_SWITCHBOARD_STALE_SLOTS + 1);
+        assert_eq!(result, Err(NCNProgramError::StaleSwitchboardFeed));
+    }
+
+    #[test]
+    fn test_switchboard_confidence() {
+        let max_std_dev = ONE * MAX_SWITCHBOARD_CONFIDENCE_BPS as i128 / 10_000;
+
+        let result = weight_from_switchboard_result(ONE, max_std_dev, 1_000, 1_000);
+        assert!(result.is_ok());
+
+        let result = weight_from_switchboard_result(ONE, max_std_dev + 1, 1_000, 1_000);
+        assert_eq!(result, Err(NCNProgramError::SwitchboardConfidenceTooLow));
+    }
+
+    #[test]
+    fn test_invalid_switchboard_value() {
+        assert_eq!(
+            weight_from_switchboard_result(0, 0, 1_000, 1_000),
+            Err(NCNProgramError::BadSwitchboardValue)
+        );
+        assert_eq!(
+            weight_from_switchboard_result(-ONE, 0, 1_000, 1_000),
+            Err(NCNProgramError::BadSwitchboardValue)
+        );
+        // Too small to be represented with 9 decimals
+        assert_eq!(
+            weight_from_switchboard_result(1, 0, 1_000, 1_000),
+            Err(NCNProgramError::BadSwitchboardValue)
+        );
+    }
+}
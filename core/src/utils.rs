@@ -14,6 +14,25 @@ pub fn get_new_size(current_size: usize, target_size: usize) -> Result<usize, Pr
         .min(target_size))
 }
 
+/// Text stored in a zero padded byte field, empty if unset or not valid UTF-8
+pub fn padded_str(bytes: &[u8]) -> &str {
+    let len = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    core::str::from_utf8(&bytes[..len]).unwrap_or_default()
+}
+
+/// Checks that a zero padded byte field is UTF-8 and only padded with zeros at the end
+pub fn is_valid_padded_str(bytes: &[u8]) -> bool {
+    let len = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+
+    bytes[len..].iter().all(|byte| *byte == 0) && core::str::from_utf8(&bytes[..len]).is_ok()
+}
+
 #[inline(always)]
 #[track_caller]
 pub fn assert_ncn_program_error<T>(
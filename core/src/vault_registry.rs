@@ -1,5 +1,5 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -7,13 +7,18 @@ use jito_bytemuck::{
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
     constants::{MAX_ST_MINTS, MAX_VAULTS},
     discriminators::Discriminators,
     error::NCNProgramError,
-    loaders::check_load,
+    pdas::NCNProgramPda,
+    utils::{is_valid_padded_str, padded_str},
 };
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
@@ -23,18 +28,21 @@ pub struct StMintEntry {
     st_mint: Pubkey,
 
     // Either a switchboard feed or a weight must be set
-    /// The switchboard feed for the mint
-    reserve_switchboard_feed: [u8; 32],
+    /// The switchboard feed for the mint, `Pubkey::default()` if the weight is admin-set
+    switchboard_feed: Pubkey,
     /// The weight
     weight: PodU128,
+    /// The decimals of the mint, amounts are normalized with them before the weight is applied
+    decimals: u8,
 }
 
 impl StMintEntry {
-    pub fn new(st_mint: &Pubkey, weight: u128) -> Self {
+    pub fn new(st_mint: &Pubkey, decimals: u8, weight: u128) -> Self {
         Self {
             st_mint: *st_mint,
-            reserve_switchboard_feed: [0; 32],
+            switchboard_feed: Pubkey::default(),
             weight: PodU128::from(weight),
+            decimals,
         }
     }
 
@@ -42,10 +50,23 @@ impl StMintEntry {
         self.weight.into()
     }
 
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
     pub const fn st_mint(&self) -> &Pubkey {
         &self.st_mint
     }
 
+    pub const fn switchboard_feed(&self) -> &Pubkey {
+        &self.switchboard_feed
+    }
+
+    /// Mints with a feed get their weight from `SetWeightFromSwitchboard`
+    pub fn has_switchboard_feed(&self) -> bool {
+        self.switchboard_feed.ne(&Pubkey::default())
+    }
+
     pub fn is_empty(&self) -> bool {
         self.st_mint().eq(&Pubkey::default())
     }
@@ -53,7 +74,7 @@ impl StMintEntry {
 
 impl Default for StMintEntry {
     fn default() -> Self {
-        Self::new(&Pubkey::default(), 0)
+        Self::new(&Pubkey::default(), 0, 0)
     }
 }
 
@@ -68,11 +89,19 @@ pub struct VaultEntry {
     vault_index: PodU64,
     /// The slot the vault was registered
     slot_registered: PodU64,
+    /// Where the vault's share of rewards is sent, defaults to the vault itself
+    reward_recipient: Pubkey,
+    /// Display name of the vault, UTF-8 padded with zeros
+    name: [u8; 32],
+    /// Hash of a URI with more information about the vault, zero if unset
+    uri_hash: [u8; 32],
 }
 
 impl VaultEntry {
     pub const EMPTY_VAULT_INDEX: u64 = u64::MAX;
     pub const EMPTY_SLOT_REGISTERED: u64 = u64::MAX;
+    pub const NAME_LEN: usize = 32;
+    pub const URI_HASH_LEN: usize = 32;
 
     pub fn new(vault: &Pubkey, st_mint: &Pubkey, vault_index: u64, slot_registered: u64) -> Self {
         Self {
@@ -80,6 +109,9 @@ impl VaultEntry {
             st_mint: *st_mint,
             vault_index: PodU64::from(vault_index),
             slot_registered: PodU64::from(slot_registered),
+            reward_recipient: Pubkey::default(),
+            name: [0; Self::NAME_LEN],
+            uri_hash: [0; Self::URI_HASH_LEN],
         }
     }
 
@@ -102,6 +134,49 @@ impl VaultEntry {
     pub fn is_empty(&self) -> bool {
         self.slot_registered() == u64::MAX
     }
+
+    pub const fn reward_recipient(&self) -> &Pubkey {
+        &self.reward_recipient
+    }
+
+    pub fn has_reward_recipient(&self) -> bool {
+        self.reward_recipient.ne(&Pubkey::default())
+    }
+
+    /// The account the vault's rewards should be transferred to
+    pub fn reward_destination(&self) -> &Pubkey {
+        if self.has_reward_recipient() {
+            &self.reward_recipient
+        } else {
+            &self.vault
+        }
+    }
+
+    pub const fn name(&self) -> &[u8; 32] {
+        &self.name
+    }
+
+    /// The name without its zero padding, empty if unset
+    pub fn name_str(&self) -> &str {
+        padded_str(&self.name)
+    }
+
+    pub const fn uri_hash(&self) -> &[u8; 32] {
+        &self.uri_hash
+    }
+
+    pub fn has_uri_hash(&self) -> bool {
+        self.uri_hash.iter().any(|byte| *byte != 0)
+    }
+
+    /// Checks that the name is UTF-8 and only padded with zeros at the end
+    pub fn check_name(name: &[u8; 32]) -> Result<(), NCNProgramError> {
+        if !is_valid_padded_str(name) {
+            return Err(NCNProgramError::InvalidVaultName);
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for VaultEntry {
@@ -123,9 +198,11 @@ pub struct VaultRegistry {
     /// The bump seed for the PDA
     pub bump: u8,
     /// The list of supported token ( ST ) mints
-    pub st_mint_list: [StMintEntry; 64],
+    pub st_mint_list: [StMintEntry; MAX_ST_MINTS],
     /// The list of vaults
-    pub vault_list: [VaultEntry; 64],
+    pub vault_list: [VaultEntry; MAX_VAULTS],
+    /// Weight used for st mints without a weight or switchboard feed, 0 if there is no default
+    default_weight: PodU128,
 }
 
 impl Discriminator for VaultRegistry {
@@ -133,15 +210,18 @@ impl Discriminator for VaultRegistry {
 }
 
 impl VaultRegistry {
-    const VAULT_REGISTRY_SEED: &'static [u8] = b"vault_registry";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+
     pub fn new(ncn: &Pubkey, bump: u8) -> Self {
         Self {
             ncn: *ncn,
             bump,
             st_mint_list: [StMintEntry::default(); MAX_ST_MINTS],
             vault_list: [VaultEntry::default(); MAX_VAULTS],
+            default_weight: PodU128::from(0),
         }
     }
 
@@ -149,25 +229,20 @@ impl VaultRegistry {
         // Initializes field by field to avoid overflowing stack
         self.ncn = *ncn;
         self.bump = bump;
-        self.st_mint_list = [StMintEntry::default(); MAX_ST_MINTS];
+        self.st_mint_list.fill(StMintEntry::default());
         self.vault_list = [VaultEntry::default(); MAX_VAULTS];
+        self.default_weight = PodU128::from(0);
     }
 
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [Self::VAULT_REGISTRY_SEED.to_vec(), ncn.to_bytes().to_vec()]
-                .iter()
-                .cloned(),
-        )
+        NCNProgramPda::VaultRegistry { ncn: *ncn }.seeds()
     }
 
     pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (address, bump, seeds)
+        NCNProgramPda::VaultRegistry { ncn: *ncn }.find_program_address(program_id)
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -188,20 +263,47 @@ impl VaultRegistry {
         self.st_mint_list.iter().any(|m| m.st_mint.eq(mint))
     }
 
-    pub fn check_st_mint_entry(entry: &StMintEntry) -> Result<(), ProgramError> {
+    pub fn default_weight(&self) -> u128 {
+        self.default_weight.into()
+    }
+
+    /// Passing 0 removes the default weight
+    pub fn set_default_weight(&mut self, default_weight: u128) {
+        self.default_weight = PodU128::from(default_weight);
+    }
+
+    /// The weight of the entry, falls back to the default weight if the entry has none
+    pub fn st_mint_weight(&self, entry: &StMintEntry) -> u128 {
         if entry.weight() == 0 {
+            self.default_weight()
+        } else {
+            entry.weight()
+        }
+    }
+
+    pub fn check_st_mint_entry(&self, entry: &StMintEntry) -> Result<(), ProgramError> {
+        if self.st_mint_weight(entry) == 0 && !entry.has_switchboard_feed() {
             return Err(NCNProgramError::WeightNotSet.into());
         }
 
         Ok(())
     }
 
-    pub fn register_st_mint(&mut self, st_mint: &Pubkey, weight: u128) -> Result<(), ProgramError> {
+    pub fn register_st_mint(
+        &mut self,
+        st_mint: &Pubkey,
+        decimals: u8,
+        weight: u128,
+    ) -> Result<(), ProgramError> {
         // Check if mint is already in the list
         if self.st_mint_list.iter().any(|m| m.st_mint.eq(st_mint)) {
             return Err(NCNProgramError::MintInTable.into());
         }
 
+        let new_mint_entry = StMintEntry::new(st_mint, decimals, weight);
+
+        self.check_st_mint_entry(&new_mint_entry)?;
+
         // Insert at the first empty slot
         let mint_entry = self
             .st_mint_list
@@ -209,33 +311,35 @@ impl VaultRegistry {
             .find(|m| m.st_mint == StMintEntry::default().st_mint)
             .ok_or(NCNProgramError::VaultRegistryListFull)?;
 
-        let new_mint_entry = StMintEntry::new(st_mint, weight);
-
-        Self::check_st_mint_entry(&new_mint_entry)?;
-
         *mint_entry = new_mint_entry;
 
         Ok(())
     }
 
+    /// Passing `Pubkey::default()` as the switchboard feed removes the feed
     pub fn set_st_mint(
         &mut self,
         st_mint: &Pubkey,
         weight: Option<u128>,
+        switchboard_feed: Option<Pubkey>,
     ) -> Result<(), ProgramError> {
-        let mint_entry = self
-            .st_mint_list
-            .iter_mut()
-            .find(|m| m.st_mint.eq(st_mint))
-            .ok_or(NCNProgramError::MintEntryNotFound)?;
-
-        let mut updated_mint_entry = *mint_entry;
+        let mut updated_mint_entry = self.get_mint_entry(st_mint)?;
 
         if let Some(weight) = weight {
             updated_mint_entry.weight = PodU128::from(weight);
         }
 
-        Self::check_st_mint_entry(&updated_mint_entry)?;
+        if let Some(switchboard_feed) = switchboard_feed {
+            updated_mint_entry.switchboard_feed = switchboard_feed;
+        }
+
+        self.check_st_mint_entry(&updated_mint_entry)?;
+
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(NCNProgramError::MintEntryNotFound)?;
 
         *mint_entry = updated_mint_entry;
 
@@ -265,6 +369,59 @@ impl VaultRegistry {
         Ok(())
     }
 
+    pub fn get_vault_entry(&self, vault: &Pubkey) -> Result<VaultEntry, ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter()
+            .find(|m| m.vault().eq(vault))
+            .ok_or(NCNProgramError::VaultNotInRegistry)?;
+
+        Ok(*vault_entry)
+    }
+
+    /// Sets where the vault's rewards are sent. Passing the vault itself or the
+    /// default pubkey resets the recipient back to the vault.
+    pub fn set_vault_reward_recipient(
+        &mut self,
+        vault: &Pubkey,
+        reward_recipient: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|m| m.vault().eq(vault))
+            .ok_or(NCNProgramError::VaultNotInRegistry)?;
+
+        vault_entry.reward_recipient = if reward_recipient.eq(vault) {
+            Pubkey::default()
+        } else {
+            *reward_recipient
+        };
+
+        Ok(())
+    }
+
+    /// Sets the display name and URI hash of a vault, zeroed values clear them
+    pub fn set_vault_metadata(
+        &mut self,
+        vault: &Pubkey,
+        name: &[u8; 32],
+        uri_hash: &[u8; 32],
+    ) -> Result<(), ProgramError> {
+        VaultEntry::check_name(name)?;
+
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|m| m.vault().eq(vault))
+            .ok_or(NCNProgramError::VaultNotInRegistry)?;
+
+        vault_entry.name = *name;
+        vault_entry.uri_hash = *uri_hash;
+
+        Ok(())
+    }
+
     pub const fn get_vault_entries(&self) -> &[VaultEntry; MAX_VAULTS] {
         &self.vault_list
     }
@@ -313,6 +470,7 @@ impl fmt::Display for VaultRegistry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "\n\n----------- Vault Registry -------------")?;
         writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Default Weight:               {}", self.default_weight())?;
         writeln!(f, "  ST Mints:                     ")?;
         for mint in self.get_valid_mint_entries() {
             writeln!(f, "    Mint:                       {}", mint.st_mint())?;
@@ -321,9 +479,17 @@ impl fmt::Display for VaultRegistry {
         writeln!(f, "  Vaults:                     ")?;
         for vault in self.get_valid_vault_entries() {
             writeln!(f, "    Vault:                      {}", vault.vault())?;
+            if !vault.name_str().is_empty() {
+                writeln!(f, "      Name:                     {}", vault.name_str())?;
+            }
+            if vault.has_uri_hash() {
+                let uri_hash: String = vault.uri_hash().iter().map(|byte| format!("{:02x}", byte)).collect();
+                writeln!(f, "      URI Hash:                 {}", uri_hash)?;
+            }
             writeln!(f, "      Mint:                     {}", vault.st_mint())?;
             writeln!(f, "      Index:                    {}", vault.vault_index())?;
-            writeln!(f, "      Slot Registered:          {}\n", vault.slot_registered())?;
+            writeln!(f, "      Slot Registered:          {}", vault.slot_registered())?;
+            writeln!(f, "      Reward Recipient:         {}\n", vault.reward_destination())?;
         }
 
 
@@ -344,12 +510,14 @@ mod tests {
         let expected_total = size_of::<Pubkey>() // ncn
             + 1 // bump
             + size_of::<StMintEntry>() * MAX_ST_MINTS // st_mint_list
-            + size_of::<VaultEntry>() * MAX_VAULTS; // vault_list
+            + size_of::<VaultEntry>() * MAX_VAULTS // vault_list
+            + size_of::<PodU128>(); // default_weight
 
         assert_eq!(size_of::<VaultRegistry>(), expected_total);
 
         let vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
         assert_eq!(vault_registry.vault_list.len(), MAX_VAULTS);
+        assert_eq!(vault_registry.st_mint_list.len(), MAX_ST_MINTS);
     }
 
     #[test]
@@ -359,17 +527,17 @@ mod tests {
 
         // Test 1: Initial registration should succeed
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 0);
-        vault_registry.register_st_mint(&mint, WEIGHT).unwrap();
+        vault_registry.register_st_mint(&mint, 9, WEIGHT).unwrap();
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
 
         // Test 2: Trying to add the same mint should fail
-        let result = vault_registry.register_st_mint(&mint, WEIGHT);
+        let result = vault_registry.register_st_mint(&mint, 9, WEIGHT);
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
 
         // Test 3: Adding a different mint should succeed
         let mint2 = Pubkey::new_unique();
-        vault_registry.register_st_mint(&mint2, WEIGHT).unwrap();
+        vault_registry.register_st_mint(&mint2, 9, WEIGHT).unwrap();
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
 
         // Test 4: Verify mint entry data is stored correctly
@@ -379,19 +547,21 @@ mod tests {
 
         // Test 5: Adding a mint with weight 0 should fail
         let mint3 = Pubkey::new_unique();
-        let result = vault_registry.register_st_mint(&mint3, 0);
+        let result = vault_registry.register_st_mint(&mint3, 9, 0);
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
 
         // Test 6: Fill up the mint list
         for _ in 2..MAX_ST_MINTS {
             let new_mint = Pubkey::new_unique();
-            vault_registry.register_st_mint(&new_mint, WEIGHT).unwrap();
+            vault_registry
+                .register_st_mint(&new_mint, 9, WEIGHT)
+                .unwrap();
         }
 
         // Test 7: Attempting to add to a full list should fail
         let overflow_mint = Pubkey::new_unique();
-        let result = vault_registry.register_st_mint(&overflow_mint, WEIGHT);
+        let result = vault_registry.register_st_mint(&overflow_mint, 9, WEIGHT);
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), MAX_ST_MINTS);
 
@@ -404,11 +574,12 @@ mod tests {
         let mut fresh_registry = VaultRegistry::new(&Pubkey::default(), 0);
         let mint_with_weight = Pubkey::new_unique();
         fresh_registry
-            .register_st_mint(&mint_with_weight, WEIGHT)
+            .register_st_mint(&mint_with_weight, 6, WEIGHT)
             .unwrap();
 
         let entry = fresh_registry.get_mint_entry(&mint_with_weight).unwrap();
         assert_eq!(entry.weight(), 100);
+        assert_eq!(entry.decimals(), 6);
     }
 
     #[test]
@@ -417,7 +588,7 @@ mod tests {
         let mint = Pubkey::new_unique();
 
         // First register a mint to update
-        vault_registry.register_st_mint(&mint, WEIGHT).unwrap();
+        vault_registry.register_st_mint(&mint, 9, WEIGHT).unwrap();
 
         // Test 1: Verify initial state
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
@@ -425,30 +596,85 @@ mod tests {
         assert_eq!(entry.weight(), WEIGHT);
 
         // Test 5: Update weight
-        vault_registry.set_st_mint(&mint, Some(100)).unwrap();
+        vault_registry.set_st_mint(&mint, Some(100), None).unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.weight(), 100);
 
         // Test 6: Update multiple fields at once
-        vault_registry.set_st_mint(&mint, Some(200)).unwrap();
+        vault_registry.set_st_mint(&mint, Some(200), None).unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.weight(), 200);
 
         // Test 7: Attempt to update non-existent mint
         let nonexistent_mint = Pubkey::new_unique();
-        let result = vault_registry.set_st_mint(&nonexistent_mint, None);
+        let result = vault_registry.set_st_mint(&nonexistent_mint, None, None);
         assert_eq!(
             result.unwrap_err(),
             ProgramError::from(NCNProgramError::MintEntryNotFound)
         );
 
         // Test 8: Setting  weight to invalid values should fail
-        let result = vault_registry.set_st_mint(&mint, Some(0));
+        let result = vault_registry.set_st_mint(&mint, Some(0), None);
         assert!(result.is_err());
 
         // Test 9: Verify original values remain after failed update
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.weight(), 200);
+
+        // Test 10: A mint with a switchboard feed does not need a weight
+        let switchboard_feed = Pubkey::new_unique();
+        vault_registry
+            .set_st_mint(&mint, Some(0), Some(switchboard_feed))
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.weight(), 0);
+        assert_eq!(entry.switchboard_feed(), &switchboard_feed);
+        assert!(entry.has_switchboard_feed());
+
+        // Test 11: Removing the feed requires a weight again
+        let result = vault_registry.set_st_mint(&mint, None, Some(Pubkey::default()));
+        assert!(result.is_err());
+
+        vault_registry
+            .set_st_mint(&mint, Some(WEIGHT), Some(Pubkey::default()))
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert!(!entry.has_switchboard_feed());
+    }
+
+    #[test]
+    fn test_default_weight() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        // Without a default weight, mints need a weight
+        let result = vault_registry.register_st_mint(&mint, 9, 0);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(NCNProgramError::WeightNotSet)
+        );
+
+        vault_registry.set_default_weight(WEIGHT);
+        vault_registry.register_st_mint(&mint, 9, 0).unwrap();
+
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.weight(), 0);
+        assert_eq!(vault_registry.st_mint_weight(&entry), WEIGHT);
+
+        // An explicit weight takes precedence
+        vault_registry.set_st_mint(&mint, Some(200), None).unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(vault_registry.st_mint_weight(&entry), 200);
+
+        // Going back to the default weight
+        vault_registry.set_st_mint(&mint, Some(0), None).unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(vault_registry.st_mint_weight(&entry), WEIGHT);
+
+        // Removing the default leaves the mint without a weight
+        vault_registry.set_default_weight(0);
+        assert_eq!(vault_registry.st_mint_weight(&entry), 0);
+        assert!(vault_registry.check_st_mint_entry(&entry).is_err());
     }
 
     #[test]
@@ -470,11 +696,99 @@ mod tests {
 
         let mint1 = Pubkey::new_unique();
         let mint2 = Pubkey::new_unique();
-        vault_registry.register_st_mint(&mint1, WEIGHT).unwrap();
-        vault_registry.register_st_mint(&mint2, WEIGHT).unwrap();
+        vault_registry.register_st_mint(&mint1, 9, WEIGHT).unwrap();
+        vault_registry.register_st_mint(&mint2, 9, WEIGHT).unwrap();
 
-        let result = vault_registry.register_st_mint(&mint1, WEIGHT);
+        let result = vault_registry.register_st_mint(&mint1, 9, WEIGHT);
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_set_vault_reward_recipient() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let vault = Pubkey::new_unique();
+        vault_registry
+            .register_vault(&vault, &Pubkey::new_unique(), 0, 0)
+            .unwrap();
+
+        // Rewards go to the vault by default
+        let entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert!(!entry.has_reward_recipient());
+        assert_eq!(entry.reward_destination(), &vault);
+
+        // Set a recipient
+        let recipient = Pubkey::new_unique();
+        vault_registry
+            .set_vault_reward_recipient(&vault, &recipient)
+            .unwrap();
+        let entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(entry.reward_recipient(), &recipient);
+        assert_eq!(entry.reward_destination(), &recipient);
+
+        // Setting the vault itself resets the recipient
+        vault_registry
+            .set_vault_reward_recipient(&vault, &vault)
+            .unwrap();
+        let entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert!(!entry.has_reward_recipient());
+        assert_eq!(entry.reward_destination(), &vault);
+
+        // Unknown vaults fail
+        let result = vault_registry.set_vault_reward_recipient(&Pubkey::new_unique(), &recipient);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(NCNProgramError::VaultNotInRegistry)
+        );
+    }
+
+    #[test]
+    fn test_set_vault_metadata() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let vault = Pubkey::new_unique();
+        vault_registry
+            .register_vault(&vault, &Pubkey::new_unique(), 0, 0)
+            .unwrap();
+
+        let entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(entry.name_str(), "");
+        assert!(!entry.has_uri_hash());
+
+        let mut name = [0; VaultEntry::NAME_LEN];
+        name[..9].copy_from_slice(b"Jito SOL ");
+        let uri_hash = [7; VaultEntry::URI_HASH_LEN];
+        vault_registry
+            .set_vault_metadata(&vault, &name, &uri_hash)
+            .unwrap();
+
+        let entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(entry.name_str(), "Jito SOL ");
+        assert_eq!(entry.uri_hash(), &uri_hash);
+        assert!(entry.has_uri_hash());
+
+        // Names must be UTF-8 and only padded at the end
+        let mut invalid_name = [0; VaultEntry::NAME_LEN];
+        invalid_name[0] = 0xff;
+        let result = vault_registry.set_vault_metadata(&vault, &invalid_name, &uri_hash);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(NCNProgramError::InvalidVaultName)
+        );
+
+        let mut gap_name = [0; VaultEntry::NAME_LEN];
+        gap_name[0] = b'a';
+        gap_name[2] = b'b';
+        let result = vault_registry.set_vault_metadata(&vault, &gap_name, &uri_hash);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(NCNProgramError::InvalidVaultName)
+        );
+
+        // Unknown vaults fail
+        let result = vault_registry.set_vault_metadata(&Pubkey::new_unique(), &name, &uri_hash);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(NCNProgramError::VaultNotInRegistry)
+        );
+    }
 }
@@ -0,0 +1,270 @@
+//! Independent verification of a published consensus result against the raw votes of its ballot
+//! box, for off-chain auditors that do not want to trust the tallies kept by the program.
+
+use spl_math::{precise_number::PreciseNumber, uint::U256};
+
+use crate::{
+    ballot_box::{Ballot, BallotBox},
+    consensus_result::ConsensusResult,
+    constants::{precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
+    error::NCNProgramError,
+};
+
+/// A ballot tally rebuilt from the operator votes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecomputedTally {
+    pub ballot: Ballot,
+    pub votes: u64,
+    pub stake_weight: U256,
+}
+
+/// Rebuilds the ballot tallies of the ballot box from its operator votes, indexed like
+/// `BallotBox::ballot_tallies`. Fails with `BallotTallyMismatch` when a vote points at no ballot.
+pub fn recompute_tallies(
+    ballot_box: &BallotBox,
+) -> Result<Vec<Option<RecomputedTally>>, NCNProgramError> {
+    let mut tallies: Vec<Option<RecomputedTally>> = vec![None; MAX_OPERATORS];
+
+    for vote in ballot_box.iter_votes() {
+        let index = vote.ballot_index() as usize;
+        let ballot_tally = ballot_box
+            .ballot_tallies()
+            .get(index)
+            .filter(|tally| tally.is_valid())
+            .ok_or(NCNProgramError::BallotTallyMismatch)?;
+
+        let tally = tallies[index].get_or_insert(RecomputedTally {
+            ballot: *ballot_tally.ballot(),
+            votes: 0,
+            stake_weight: U256::zero(),
+        });
+
+        tally.votes = tally
+            .votes
+            .checked_add(1)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        tally.stake_weight = tally
+            .stake_weight
+            .checked_add(vote.stake_weights().stake_weight_u256())
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    }
+
+    Ok(tallies)
+}
+
+/// Checks that the consensus result published for the epoch follows from the votes of its ballot
+/// box.
+///
+/// The tallies are recomputed from the operator votes and compared with the tallies of the ballot
+/// box. Once consensus was reached by vote, the published weather status and vote weight have to
+/// be the ones of the recomputed winning tally, holding at least two thirds of the published total
+/// vote weight, and the consensus slot has to match the ballot box. A winning ballot set by the
+/// tie breaker admin is not published in the consensus result, there is nothing to verify then.
+///
+/// ### Errors:
+/// - `IncorrectNcn`: The accounts belong to different NCNs
+/// - `BallotTallyMismatch`: The tallies of the ballot box do not match its votes
+/// - `ConsensusResultMismatch`: The consensus result does not follow from the votes
+pub fn verify_consensus(
+    ballot_box: &BallotBox,
+    consensus_result: &ConsensusResult,
+) -> Result<(), NCNProgramError> {
+    if ballot_box.ncn().ne(consensus_result.ncn()) {
+        return Err(NCNProgramError::IncorrectNcn);
+    }
+
+    if ballot_box.epoch() != consensus_result.epoch() {
+        return Err(NCNProgramError::ConsensusResultMismatch);
+    }
+
+    let tallies = recompute_tallies(ballot_box)?;
+    for (ballot_tally, tally) in ballot_box.ballot_tallies().iter().zip(tallies.iter()) {
+        let (votes, stake_weight) =
+            tally.map_or((0, U256::zero()), |tally| (tally.votes, tally.stake_weight));
+
+        if ballot_tally.is_valid()
+            && (ballot_tally.tally() != votes
+                || ballot_tally.stake_weights().stake_weight_u256() != stake_weight)
+        {
+            return Err(NCNProgramError::BallotTallyMismatch);
+        }
+    }
+
+    let consensus_reached_by_vote =
+        ballot_box.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT;
+
+    if !consensus_result.is_consensus_reached() {
+        if consensus_reached_by_vote {
+            return Err(NCNProgramError::ConsensusResultMismatch);
+        }
+
+        return Ok(());
+    }
+
+    if !consensus_reached_by_vote
+        || consensus_result.consensus_slot() != ballot_box.slot_consensus_reached()
+    {
+        return Err(NCNProgramError::ConsensusResultMismatch);
+    }
+
+    let winning_ballot = ballot_box
+        .get_winning_ballot()
+        .map_err(|_| NCNProgramError::ConsensusResultMismatch)?;
+    let winning_tally = tallies
+        .iter()
+        .flatten()
+        .find(|tally| tally.ballot.eq(winning_ballot))
+        .ok_or(NCNProgramError::ConsensusResultMismatch)?;
+
+    // The program publishes the vote weights truncated to u64
+    let winning_stake_weight = if winning_tally.stake_weight > U256::from(u128::MAX) {
+        u128::MAX
+    } else {
+        winning_tally.stake_weight.as_u128()
+    };
+
+    if consensus_result.weather_status() != winning_ballot.weather_status()
+        || consensus_result.vote_weight() != winning_stake_weight as u64
+    {
+        return Err(NCNProgramError::ConsensusResultMismatch);
+    }
+
+    let total_vote_weight = PreciseNumber::new(consensus_result.total_vote_weight() as u128)
+        .ok_or(NCNProgramError::NewPreciseNumberError)?;
+    let vote_weight = PreciseNumber::new(consensus_result.vote_weight() as u128)
+        .ok_or(NCNProgramError::NewPreciseNumberError)?;
+    let share = vote_weight
+        .checked_div(&total_vote_weight)
+        .ok_or(NCNProgramError::ConsensusResultMismatch)?;
+
+    if !share.greater_than_or_equal(&precise_consensus()?) {
+        return Err(NCNProgramError::ConsensusResultMismatch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::{ballot_box::WeatherStatus, stake_weight::StakeWeights};
+
+    const EPOCH: u64 = 1;
+    const SLOT: u64 = 100;
+
+    /// A ballot box where operators voted the given stake weights for the given weather status,
+    /// with the consensus result the program would publish
+    fn vote(ncn: &Pubkey, votes: &[(u8, u128)]) -> (Box<BallotBox>, Box<ConsensusResult>) {
+        let mut ballot_box = BallotBox::new_for_test(ncn, EPOCH);
+        let mut consensus_result = ConsensusResult::new_for_test(ncn, EPOCH);
+
+        let total: u128 = votes.iter().map(|(_, stake_weight)| stake_weight).sum();
+        let total_stake_weights = StakeWeights::snapshot(total).unwrap();
+
+        for (weather_status, stake_weight) in votes {
+            ballot_box
+                .cast_vote(
+                    &Pubkey::new_unique(),
+                    &Ballot::new(*weather_status),
+                    &StakeWeights::snapshot(*stake_weight).unwrap(),
+                    SLOT,
+                    1_000,
+                )
+                .unwrap();
+            ballot_box.tally_votes(&total_stake_weights, SLOT).unwrap();
+
+            if ballot_box.is_consensus_reached() {
+                let winning_tally = ballot_box.get_winning_ballot_tally().unwrap();
+                consensus_result
+                    .record_consensus(
+                        winning_tally.ballot().weather_status(),
+                        winning_tally.stake_weights().stake_weight() as u64,
+                        total as u64,
+                        SLOT,
+                    )
+                    .unwrap();
+            }
+        }
+
+        (ballot_box, consensus_result)
+    }
+
+    #[test]
+    fn test_verify_consensus() {
+        let ncn = Pubkey::new_unique();
+        let sunny = WeatherStatus::Sunny as u8;
+        let cloudy = WeatherStatus::Cloudy as u8;
+
+        let (ballot_box, consensus_result) =
+            vote(&ncn, &[(sunny, 500), (cloudy, 100), (sunny, 300)]);
+        assert!(consensus_result.is_consensus_reached());
+        verify_consensus(&ballot_box, &consensus_result).unwrap();
+
+        let tallies = recompute_tallies(&ballot_box).unwrap();
+        let sunny_tally = tallies
+            .iter()
+            .flatten()
+            .find(|tally| tally.ballot.weather_status() == sunny)
+            .unwrap();
+        assert_eq!(sunny_tally.votes, 2);
+        assert_eq!(sunny_tally.stake_weight, U256::from(800));
+
+        // Nothing published without consensus
+        let (ballot_box, consensus_result) = vote(&ncn, &[(sunny, 100), (cloudy, 100)]);
+        assert!(!consensus_result.is_consensus_reached());
+        verify_consensus(&ballot_box, &consensus_result).unwrap();
+    }
+
+    #[test]
+    fn test_verify_consensus_mismatch() {
+        let ncn = Pubkey::new_unique();
+        let sunny = WeatherStatus::Sunny as u8;
+        let (ballot_box, consensus_result) = vote(&ncn, &[(sunny, 900), (sunny, 100)]);
+
+        // Another weather status published
+        let mut tampered = ConsensusResult::new_for_test(&ncn, EPOCH);
+        tampered
+            .record_consensus(WeatherStatus::Rainy as u8, 1_000, 1_000, SLOT)
+            .unwrap();
+        assert_eq!(
+            verify_consensus(&ballot_box, &tampered),
+            Err(NCNProgramError::ConsensusResultMismatch)
+        );
+
+        // Another vote weight published
+        let mut tampered = *consensus_result;
+        tampered.record_consensus(sunny, 999, 1_000, SLOT).unwrap();
+        assert_eq!(
+            verify_consensus(&ballot_box, &tampered),
+            Err(NCNProgramError::ConsensusResultMismatch)
+        );
+
+        // Below two thirds of the published total
+        let mut tampered = ConsensusResult::new_for_test(&ncn, EPOCH);
+        tampered
+            .record_consensus(sunny, 1_000, 2_000, SLOT)
+            .unwrap();
+        assert_eq!(
+            verify_consensus(&ballot_box, &tampered),
+            Err(NCNProgramError::ConsensusResultMismatch)
+        );
+
+        // Consensus reached but not published
+        assert_eq!(
+            verify_consensus(&ballot_box, &ConsensusResult::new_for_test(&ncn, EPOCH)),
+            Err(NCNProgramError::ConsensusResultMismatch)
+        );
+
+        // Another NCN
+        let other_ncn = Pubkey::new_unique();
+        assert_eq!(
+            verify_consensus(
+                &ballot_box,
+                &ConsensusResult::new_for_test(&other_ncn, EPOCH)
+            ),
+            Err(NCNProgramError::IncorrectNcn)
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Answers of the getter instructions, written as return data so other programs and simulators can
+//! query computed state without deserializing the accounts behind it.
+//!
+//! A caller reads the answer with `get_return_data` after a CPI, or from the return data of a
+//! simulated transaction, and parses it with `ReturnData::from_return_data`.
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::{PodBool, PodU64};
+#[cfg(feature = "program")]
+use solana_program::program::set_return_data;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    ballot_box::BallotBox, consensus_result::ConsensusResult, ncn_reward_router::NCNRewardRouter,
+    stake_weight::StakeWeights,
+};
+
+/// A getter answer, returned as its raw bytes
+pub trait ReturnData: Pod {
+    const SIZE: usize = std::mem::size_of::<Self>();
+
+    #[cfg(feature = "program")]
+    fn set_return_data(&self) {
+        set_return_data(bytemuck::bytes_of(self));
+    }
+
+    /// Parses the return data of a getter instruction, `None` for any other data
+    fn from_return_data(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::SIZE {
+            return None;
+        }
+
+        Some(bytemuck::pod_read_unaligned(data))
+    }
+}
+
+/// Answer of `GetConsensusResult`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct ConsensusResultView {
+    /// The NCN of the consensus result
+    ncn: Pubkey,
+    /// The epoch of the consensus result
+    epoch: PodU64,
+    /// The vote weight that supported the winning status
+    vote_weight: PodU64,
+    /// The total vote weight of the epoch
+    total_vote_weight: PodU64,
+    /// The slot consensus was reached in, zero before consensus
+    consensus_slot: PodU64,
+    /// The winning weather status
+    weather_status: u8,
+    /// Whether consensus was reached
+    consensus_reached: PodBool,
+}
+
+impl ReturnData for ConsensusResultView {}
+
+impl ConsensusResultView {
+    pub fn new(consensus_result: &ConsensusResult) -> Self {
+        Self {
+            ncn: *consensus_result.ncn(),
+            epoch: PodU64::from(consensus_result.epoch()),
+            vote_weight: PodU64::from(consensus_result.vote_weight()),
+            total_vote_weight: PodU64::from(consensus_result.total_vote_weight()),
+            consensus_slot: PodU64::from(consensus_result.consensus_slot()),
+            weather_status: consensus_result.weather_status(),
+            consensus_reached: PodBool::from(consensus_result.is_consensus_reached()),
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn vote_weight(&self) -> u64 {
+        self.vote_weight.into()
+    }
+
+    pub fn total_vote_weight(&self) -> u64 {
+        self.total_vote_weight.into()
+    }
+
+    pub fn consensus_slot(&self) -> u64 {
+        self.consensus_slot.into()
+    }
+
+    pub const fn weather_status(&self) -> u8 {
+        self.weather_status
+    }
+
+    pub fn consensus_reached(&self) -> bool {
+        self.consensus_reached.into()
+    }
+}
+
+/// Answer of `GetWinningBallot`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct WinningBallotView {
+    /// The NCN of the ballot box
+    ncn: Pubkey,
+    /// The epoch of the ballot box
+    epoch: PodU64,
+    /// The slot consensus was reached in, `u64::MAX` until then or when set by the tie breaker
+    slot_consensus_reached: PodU64,
+    /// The stake weights of the winning ballot, zero when set by the tie breaker admin
+    stake_weights: StakeWeights,
+    /// The number of votes for the winning ballot
+    tally: PodU64,
+    /// The winning weather status
+    weather_status: u8,
+    /// Whether the ballot box has a winning ballot
+    has_winning_ballot: PodBool,
+    /// Whether the tie breaker admin set the winning ballot
+    tie_breaker_set: PodBool,
+}
+
+impl ReturnData for WinningBallotView {}
+
+impl WinningBallotView {
+    pub fn new(ballot_box: &BallotBox) -> Self {
+        let winning_ballot_tally = ballot_box.get_winning_ballot_tally().ok();
+
+        Self {
+            ncn: *ballot_box.ncn(),
+            epoch: PodU64::from(ballot_box.epoch()),
+            slot_consensus_reached: PodU64::from(ballot_box.slot_consensus_reached()),
+            stake_weights: winning_ballot_tally
+                .map(|tally| *tally.stake_weights())
+                .unwrap_or_default(),
+            tally: PodU64::from(winning_ballot_tally.map_or(0, |tally| tally.tally())),
+            weather_status: ballot_box
+                .get_winning_ballot()
+                .map_or(0, |ballot| ballot.weather_status()),
+            has_winning_ballot: PodBool::from(ballot_box.has_winning_ballot()),
+            tie_breaker_set: PodBool::from(ballot_box.tie_breaker_set()),
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn slot_consensus_reached(&self) -> u64 {
+        self.slot_consensus_reached.into()
+    }
+
+    pub const fn stake_weights(&self) -> &StakeWeights {
+        &self.stake_weights
+    }
+
+    pub fn tally(&self) -> u64 {
+        self.tally.into()
+    }
+
+    pub const fn weather_status(&self) -> u8 {
+        self.weather_status
+    }
+
+    pub fn has_winning_ballot(&self) -> bool {
+        self.has_winning_ballot.into()
+    }
+
+    pub fn tie_breaker_set(&self) -> bool {
+        self.tie_breaker_set.into()
+    }
+}
+
+/// Answer of `GetOperatorRewardRoute`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct OperatorRewardRouteView {
+    /// The NCN of the reward router
+    ncn: Pubkey,
+    /// The epoch of the reward router
+    epoch: PodU64,
+    /// The operator of the route
+    operator: Pubkey,
+    /// The SOL rewards routed to the operator and not yet distributed
+    rewards: PodU64,
+    /// Whether the operator has a route in the reward router
+    has_route: PodBool,
+    /// Whether routing of the reward router was interrupted and has to be resumed
+    still_routing: PodBool,
+}
+
+impl ReturnData for OperatorRewardRouteView {}
+
+impl OperatorRewardRouteView {
+    pub fn new(ncn_reward_router: &NCNRewardRouter, operator: &Pubkey) -> Self {
+        let route = ncn_reward_router.operator_vault_reward_route(operator);
+
+        Self {
+            ncn: *ncn_reward_router.ncn(),
+            epoch: PodU64::from(ncn_reward_router.epoch()),
+            operator: *operator,
+            rewards: PodU64::from(route.rewards().unwrap_or_default()),
+            has_route: PodBool::from(ncn_reward_router.has_operator_vault_reward_route(operator)),
+            still_routing: PodBool::from(ncn_reward_router.still_routing()),
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub fn rewards(&self) -> u64 {
+        self.rewards.into()
+    }
+
+    pub fn has_route(&self) -> bool {
+        self.has_route.into()
+    }
+
+    pub fn still_routing(&self) -> bool {
+        self.still_routing.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ballot_box::Ballot;
+
+    #[test]
+    fn test_consensus_result_view() {
+        let ncn = Pubkey::new_unique();
+        let mut consensus_result = ConsensusResult::new(&ncn, 5, 0);
+
+        let view = ConsensusResultView::new(&consensus_result);
+        assert!(!view.consensus_reached());
+
+        consensus_result.record_consensus(1, 300, 400, 100).unwrap();
+        let view = ConsensusResultView::new(&consensus_result);
+        assert_eq!(view.ncn(), &ncn);
+        assert_eq!(view.epoch(), 5);
+        assert_eq!(view.weather_status(), 1);
+        assert_eq!(view.vote_weight(), 300);
+        assert_eq!(view.total_vote_weight(), 400);
+        assert_eq!(view.consensus_slot(), 100);
+        assert!(view.consensus_reached());
+
+        let data = bytemuck::bytes_of(&view);
+        assert_eq!(ConsensusResultView::from_return_data(data), Some(view));
+        assert_eq!(ConsensusResultView::from_return_data(&data[1..]), None);
+    }
+
+    #[test]
+    fn test_winning_ballot_view() {
+        let ncn = Pubkey::new_unique();
+        let mut ballot_box = BallotBox::new_for_test(&ncn, 1);
+
+        let view = WinningBallotView::new(&ballot_box);
+        assert!(!view.has_winning_ballot());
+        assert_eq!(view.tally(), 0);
+
+        let stake_weights = StakeWeights::snapshot(400).unwrap();
+        ballot_box
+            .cast_vote(
+                &Pubkey::new_unique(),
+                &Ballot::new(2),
+                &stake_weights,
+                10,
+                100,
+            )
+            .unwrap();
+        ballot_box.tally_votes(&stake_weights, 10).unwrap();
+
+        let view = WinningBallotView::new(&ballot_box);
+        assert!(view.has_winning_ballot());
+        assert!(!view.tie_breaker_set());
+        assert_eq!(view.weather_status(), 2);
+        assert_eq!(view.tally(), 1);
+        assert_eq!(view.stake_weights().stake_weight(), 400);
+        assert_eq!(view.slot_consensus_reached(), 10);
+    }
+
+    #[test]
+    fn test_operator_reward_route_view() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let router = NCNRewardRouter::new_for_test(&ncn, 1);
+
+        let view = OperatorRewardRouteView::new(&router, &operator);
+        assert_eq!(view.operator(), &operator);
+        assert!(!view.has_route());
+        assert!(!view.still_routing());
+        assert_eq!(view.rewards(), 0);
+    }
+}
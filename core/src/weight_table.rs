@@ -1,21 +1,35 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use shank::{ShankAccount, ShankType};
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
+#[cfg(feature = "program")]
+use crate::loaders::check_load;
 use crate::{
     constants::{MAX_ST_MINTS, MAX_VAULTS},
     discriminators::Discriminators,
     error::NCNProgramError,
-    loaders::check_load,
+    pdas::NCNProgramPda,
     vault_registry::{StMintEntry, VaultEntry},
     weight_entry::WeightEntry,
 };
 
+/// A weight for a staked token mint, as passed to `AdminSetWeights`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct StMintWeight {
+    /// The staked token mint
+    pub st_mint: Pubkey,
+    /// The weight of the mint
+    pub weight: u128,
+}
+
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct WeightTable {
@@ -30,9 +44,9 @@ pub struct WeightTable {
     /// Bump seed for the PDA
     bump: u8,
     /// A snapshot of the Vault Registry
-    vault_registry: [VaultEntry; 64],
+    vault_registry: [VaultEntry; MAX_VAULTS],
     /// The weight table
-    table: [WeightEntry; 64],
+    table: [WeightEntry; MAX_ST_MINTS],
 }
 
 impl Discriminator for WeightTable {
@@ -40,9 +54,12 @@ impl Discriminator for WeightTable {
 }
 
 impl WeightTable {
-    const WEIGHT_TABLE_SEED: &'static [u8] = b"weight_table";
     pub const SIZE: usize = 8 + size_of::<Self>();
 
+    /// Byte offsets of the fields used to filter program accounts, the discriminator included
+    pub const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+    pub const EPOCH_OFFSET: usize = 8 + offset_of!(Self, epoch);
+
     pub fn new(ncn: &Pubkey, epoch: u64, slot_created: u64, vault_count: u64, bump: u8) -> Self {
         Self {
             ncn: *ncn,
@@ -56,15 +73,11 @@ impl WeightTable {
     }
 
     pub fn seeds(ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                Self::WEIGHT_TABLE_SEED.to_vec(),
-                ncn.to_bytes().to_vec(),
-                ncn_epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        NCNProgramPda::WeightTable {
+            ncn: *ncn,
+            epoch: ncn_epoch,
+        }
+        .seeds()
     }
 
     pub fn find_program_address(
@@ -72,10 +85,7 @@ impl WeightTable {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        NCNProgramPda::WeightTable { ncn: *ncn, epoch }.find_program_address(program_id)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -96,7 +106,7 @@ impl WeightTable {
         self.vault_count = PodU64::from(vault_count);
         self.bump = bump;
         self.vault_registry = [VaultEntry::default(); MAX_VAULTS];
-        self.table = [WeightEntry::default(); MAX_ST_MINTS];
+        self.table.fill(WeightEntry::default());
         self.set_vault_entries(vault_entries)?;
         self.set_mint_entries(mint_entries)?;
         Ok(())
@@ -153,6 +163,59 @@ impl WeightTable {
             })
     }
 
+    /// Sets the weights of several mints, fails if any of the mints is not in the table
+    pub fn set_weights(
+        &mut self,
+        weights: &[StMintWeight],
+        current_slot: u64,
+    ) -> Result<(), NCNProgramError> {
+        for st_mint_weight in weights {
+            self.set_weight(&st_mint_weight.st_mint, st_mint_weight.weight, current_slot)?;
+        }
+
+        Ok(())
+    }
+
+    /// Copies the weights of the previous epoch's table. Only possible if the previous table is
+    /// finalized and was created from the same vault registry, vaults and st mints included.
+    /// Weights of mints with a switchboard feed are not copied, they are set from the feed.
+    pub fn copy_weights(
+        &mut self,
+        previous_weight_table: &Self,
+        current_slot: u64,
+    ) -> Result<(), NCNProgramError> {
+        if !previous_weight_table.finalized() {
+            return Err(NCNProgramError::WeightTableNotFinalized);
+        }
+
+        let vaults_changed = self.vault_count() != previous_weight_table.vault_count()
+            || bytemuck::cast_slice::<VaultEntry, u8>(&self.vault_registry)
+                != bytemuck::cast_slice::<VaultEntry, u8>(&previous_weight_table.vault_registry);
+        let mints_changed = self
+            .table
+            .iter()
+            .zip(previous_weight_table.table.iter())
+            .any(|(entry, previous_entry)| {
+                bytemuck::bytes_of(entry.st_mint_entry())
+                    != bytemuck::bytes_of(previous_entry.st_mint_entry())
+            });
+
+        if vaults_changed || mints_changed {
+            return Err(NCNProgramError::VaultRegistryChanged);
+        }
+
+        for (entry, previous_entry) in self
+            .table
+            .iter_mut()
+            .zip(previous_weight_table.table.iter())
+            .filter(|(entry, _)| !entry.is_empty() && !entry.st_mint_entry().has_switchboard_feed())
+        {
+            entry.set_weight(previous_entry.weight(), current_slot);
+        }
+
+        Ok(())
+    }
+
     pub fn get_weight(&self, mint: &Pubkey) -> Result<u128, NCNProgramError> {
         self.table
             .iter()
@@ -218,6 +281,15 @@ impl WeightTable {
         self.vault_count.into()
     }
 
+    /// Registered vaults, in registry order
+    pub fn vault_entries(&self) -> Vec<VaultEntry> {
+        self.vault_registry
+            .iter()
+            .filter(|entry| !entry.is_empty())
+            .copied()
+            .collect()
+    }
+
     pub fn vault_entry_count(&self) -> usize {
         self.vault_registry
             .iter()
@@ -268,6 +340,7 @@ impl WeightTable {
         Ok(())
     }
 
+    #[cfg(feature = "program")]
     pub fn load(
         program_id: &Pubkey,
         account: &AccountInfo,
@@ -285,6 +358,7 @@ impl WeightTable {
         )
     }
 
+    #[cfg(feature = "program")]
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -343,11 +417,11 @@ mod tests {
 
     use super::*;
 
-    fn get_test_mint_entries(count: usize) -> [StMintEntry; 64] {
+    fn get_test_mint_entries(count: usize) -> [StMintEntry; MAX_ST_MINTS] {
         let mut mints = [StMintEntry::default(); MAX_ST_MINTS];
 
         for i in 0..count {
-            mints[i] = StMintEntry::new(&Pubkey::new_unique(), 0);
+            mints[i] = StMintEntry::new(&Pubkey::new_unique(), 9, 0);
         }
 
         mints
@@ -364,6 +438,10 @@ mod tests {
             + size_of::<[WeightEntry; MAX_ST_MINTS]>(); // weight table
 
         assert_eq!(size_of::<WeightTable>(), expected_total);
+
+        // The table has to hold every st mint of the vault registry
+        let table = WeightTable::new(&Pubkey::default(), 0, 0, 0, 0);
+        assert_eq!(table.table().len(), MAX_ST_MINTS);
     }
 
     #[test]
@@ -519,6 +597,119 @@ mod tests {
         assert_eq!(table.get_weight(&mint2.st_mint()).unwrap(), 200);
     }
 
+    #[test]
+    fn test_set_weights() {
+        let ncn = Pubkey::new_unique();
+        let mints = get_test_mint_entries(3);
+
+        let mut table = WeightTable::new(&ncn, 0, 0, 0, 0);
+        table.set_mint_entries(&mints).unwrap();
+
+        table
+            .set_weights(
+                &[
+                    StMintWeight {
+                        st_mint: *mints[0].st_mint(),
+                        weight: 100,
+                    },
+                    StMintWeight {
+                        st_mint: *mints[1].st_mint(),
+                        weight: 200,
+                    },
+                ],
+                1,
+            )
+            .unwrap();
+
+        assert_eq!(table.get_weight(&mints[0].st_mint()).unwrap(), 100);
+        assert_eq!(table.get_weight(&mints[1].st_mint()).unwrap(), 200);
+        assert!(!table.finalized());
+
+        // Unknown mints are rejected
+        let result = table.set_weights(
+            &[StMintWeight {
+                st_mint: Pubkey::new_unique(),
+                weight: 300,
+            }],
+            2,
+        );
+        assert_eq!(result, Err(NCNProgramError::InvalidMintForWeightTable));
+    }
+
+    #[test]
+    fn test_copy_weights() {
+        let ncn = Pubkey::new_unique();
+        let mints = get_test_mint_entries(2);
+
+        let mut previous_table = WeightTable::new(&ncn, 0, 0, 0, 0);
+        previous_table.set_mint_entries(&mints).unwrap();
+
+        let mut table = WeightTable::new(&ncn, 1, 0, 0, 0);
+        table.set_mint_entries(&mints).unwrap();
+
+        // Previous table has to be finalized
+        assert_eq!(
+            table.copy_weights(&previous_table, 10),
+            Err(NCNProgramError::WeightTableNotFinalized)
+        );
+
+        previous_table
+            .set_weight(&mints[0].st_mint(), 100, 1)
+            .unwrap();
+        previous_table
+            .set_weight(&mints[1].st_mint(), 200, 1)
+            .unwrap();
+
+        table.copy_weights(&previous_table, 10).unwrap();
+        assert!(table.finalized());
+        assert_eq!(table.get_weight(&mints[0].st_mint()).unwrap(), 100);
+        assert_eq!(table.get_weight(&mints[1].st_mint()).unwrap(), 200);
+        assert_eq!(
+            table
+                .get_weight_entry(&mints[0].st_mint())
+                .unwrap()
+                .slot_set(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_copy_weights_registry_changed() {
+        let ncn = Pubkey::new_unique();
+        let mints = get_test_mint_entries(2);
+
+        let mut previous_table = WeightTable::new(&ncn, 0, 0, 0, 0);
+        previous_table.set_mint_entries(&mints).unwrap();
+        previous_table
+            .set_weight(&mints[0].st_mint(), 100, 1)
+            .unwrap();
+        previous_table
+            .set_weight(&mints[1].st_mint(), 200, 1)
+            .unwrap();
+
+        // A new mint was registered
+        let mut table = WeightTable::new(&ncn, 1, 0, 0, 0);
+        let mut new_mints = mints;
+        new_mints[2] = StMintEntry::new(&Pubkey::new_unique(), 9, 0);
+        table.set_mint_entries(&new_mints).unwrap();
+        assert_eq!(
+            table.copy_weights(&previous_table, 10),
+            Err(NCNProgramError::VaultRegistryChanged)
+        );
+
+        // A vault was registered
+        let mut table = WeightTable::new(&ncn, 1, 0, 1, 0);
+        let mut vault_registry = [VaultEntry::default(); MAX_VAULTS];
+        vault_registry[0] = VaultEntry::new(&Pubkey::new_unique(), mints[0].st_mint(), 0, 1);
+        table.set_vault_entries(&vault_registry).unwrap();
+        table.set_mint_entries(&mints).unwrap();
+        assert_eq!(
+            table.copy_weights(&previous_table, 10),
+            Err(NCNProgramError::VaultRegistryChanged)
+        );
+        assert_eq!(table.weight_count(), 0);
+    }
+
     #[test]
     fn test_set_weight_different_slots() {
         let ncn = Pubkey::new_unique();
@@ -0,0 +1,135 @@
+//! Example downstream program gating its own logic on the outcome of an NCN epoch.
+//!
+//! The consensus result account is written by the NCN program once operators reach consensus on
+//! a weather status. Consumers do not need a CPI to trust it: `ConsensusResult::load_and_read`
+//! checks that the account is owned by the NCN program and sits at the PDA of the NCN and epoch,
+//! so its contents can only have been written by the NCN program.
+//!
+//! Anyone can create an NCN on the restaking program, so a real consumer pins the NCN it trusts
+//! instead of taking whichever NCN account is passed in.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ncn_program_core::{ballot_box::WeatherStatus, consensus_result::ConsensusResult};
+use solana_program::{
+    account_info::AccountInfo,
+    declare_id,
+    decode_error::DecodeError,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use thiserror::Error;
+
+declare_id!("DQLj1tYjDTUTKTLhi5ZLEd1xTSdoqqFk4QTe3ZfD6ubj");
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub enum ConsensusConsumerInstruction {
+    /// Runs the gated logic, fails unless the NCN reached consensus on `expected_weather_status`
+    /// for `epoch`
+    ///
+    /// ### Accounts:
+    /// 1. `[]` ncn: The NCN account
+    /// 2. `[]` consensus_result: The consensus result of the epoch, owned by the NCN program
+    ActOnConsensus {
+        epoch: u64,
+        expected_weather_status: u8,
+    },
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConsensusConsumerError {
+    #[error("Consensus was reached on a different weather status")]
+    UnexpectedWeatherStatus = 0x3000,
+}
+
+impl<T> DecodeError<T> for ConsensusConsumerError {
+    fn type_of() -> &'static str {
+        "ncn::consensus_consumer"
+    }
+}
+
+impl From<ConsensusConsumerError> for ProgramError {
+    fn from(e: ConsensusConsumerError) -> Self {
+        Self::Custom(e as u32)
+    }
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let instruction = ConsensusConsumerInstruction::try_from_slice(instruction_data)?;
+
+    match instruction {
+        ConsensusConsumerInstruction::ActOnConsensus {
+            epoch,
+            expected_weather_status,
+        } => {
+            msg!("Instruction: ActOnConsensus");
+            process_act_on_consensus(accounts, epoch, expected_weather_status)
+        }
+    }
+}
+
+pub fn process_act_on_consensus(
+    accounts: &[AccountInfo],
+    epoch: u64,
+    expected_weather_status: u8,
+) -> ProgramResult {
+    let [ncn, consensus_result] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Fails with `ConsensusNotReached` until the epoch has an outcome
+    let consensus_result =
+        ConsensusResult::load_and_read(&ncn_program::id(), consensus_result, ncn.key, epoch)?;
+
+    if consensus_result.weather_status() != expected_weather_status {
+        msg!(
+            "Consensus was reached on {}, expected {}",
+            WeatherStatus::from_u8(consensus_result.weather_status()).unwrap_or("Unknown"),
+            WeatherStatus::from_u8(expected_weather_status).unwrap_or("Unknown")
+        );
+        return Err(ConsensusConsumerError::UnexpectedWeatherStatus.into());
+    }
+
+    // The logic of the consumer goes here
+    msg!(
+        "Consensus reached at slot {} with {} of {} vote weight",
+        consensus_result.consensus_slot(),
+        consensus_result.vote_weight(),
+        consensus_result.total_vote_weight()
+    );
+
+    Ok(())
+}
+
+/// Builds an `ActOnConsensus` instruction for the consensus result of `ncn` at `epoch`
+pub fn act_on_consensus(ncn: &Pubkey, epoch: u64, expected_weather_status: u8) -> Instruction {
+    let consensus_result = ConsensusResult::find_program_address(&ncn_program::id(), ncn, epoch).0;
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*ncn, false),
+            AccountMeta::new_readonly(consensus_result, false),
+        ],
+        data: ConsensusConsumerInstruction::ActOnConsensus {
+            epoch,
+            expected_weather_status,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
@@ -25,6 +25,14 @@ fi
 print_executing "cargo clippy --all-features"
 cargo clippy --all-features -- -D warnings -D clippy::all -D clippy::nursery -D clippy::integer_division -D clippy::arithmetic_side_effects -D clippy::style -D clippy::perf
 
+# The account types of core have to build without the program feature, for wasm32 clients
+print_executing "cargo check -p ncn-program-core --no-default-features"
+cargo check -p ncn-program-core --no-default-features
+
+print_executing "cargo build -p ncn-program-core --no-default-features --target wasm32-unknown-unknown"
+rustup target add wasm32-unknown-unknown
+cargo build -p ncn-program-core --no-default-features --target wasm32-unknown-unknown
+
 print_executing "cargo b && ./target/debug/ncn-program-shank-cli && yarn install && yarn generate-clients && cargo b"
 cargo b && ./target/debug/ncn-program-shank-cli && yarn install && yarn generate-clients && cargo b
 
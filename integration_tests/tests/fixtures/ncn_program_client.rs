@@ -3,24 +3,34 @@ use jito_restaking_core::{
     config::Config, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
 };
 use jito_vault_core::{
-    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+    vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
 };
 use ncn_program_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetNewAdminBuilder, AdminSetParametersBuilder,
-        AdminSetStMintBuilder, AdminSetTieBreakerBuilder, AdminSetWeightBuilder, CastVoteBuilder,
-        CloseEpochAccountBuilder, DistributeNCNRewardsBuilder, DistributeOperatorRewardsBuilder,
+        AcquireKeeperLeaseBuilder, AdminRegisterStMintBuilder, AdminSetAdminGovernanceBuilder,
+        AdminSetAdminMultisigBuilder, AdminSetDefaultWeightBuilder, AdminSetFeeGroupBuilder,
+        AdminSetNewAdminBuilder, AdminSetParametersBuilder, AdminSetRewardMintBuilder,
+        AdminSetStMintBuilder, AdminSetTieBreakerBuilder, AdminSetVaultMetadataBuilder,
+        AdminSetVaultRewardRecipientBuilder, AdminSetWeightBuilder, AdminSetWeightsBuilder,
+        ApplyParametersBuilder, BatchInitializeOperatorSnapshotBuilder, CastVoteBuilder,
+        CloseAllEpochAccountsBuilder, CloseEpochAccountBuilder, CloseRewardReceiverBuilder,
+        CopyPreviousEpochWeightsBuilder, DistributeFeeGroupRewardsBuilder,
+        DistributeNCNRewardsBuilder, DistributeOperatorRewardsBuilder,
         DistributeOperatorVaultRewardRouteBuilder, DistributeProtocolRewardsBuilder,
-        DistributeVaultRewardsBuilder, InitializeBallotBoxBuilder, InitializeConfigBuilder,
-        InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNCNRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeOperatorVaultRewardRouterBuilder, InitializeVaultRegistryBuilder,
-        InitializeWeightTableBuilder, ReallocBallotBoxBuilder, ReallocNCNRewardRouterBuilder,
-        ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
+        DistributeVaultRewardsBuilder, GetConsensusResultBuilder, GetOperatorRewardRouteBuilder,
+        GetWinningBallotBuilder, InitializeBallotBoxBuilder, InitializeConfigBuilder,
+        InitializeEpochAccountsBuilder, InitializeEpochSnapshotBuilder,
+        InitializeEpochStateBuilder, InitializeNCNRewardRouterBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeOperatorVaultRewardRouterBuilder,
+        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, MarkEpochSkippedBuilder,
+        ReallocBallotBoxBuilder, ReallocNCNRewardRouterBuilder, ReallocVaultRegistryBuilder,
+        ReallocWeightTableBuilder, RegisterVaultBuilder, RouteAndDistributeNCNRewardsBuilder,
         RouteNCNRewardsBuilder, RouteOperatorVaultRewardsBuilder, SetEpochWeightsBuilder,
+        SetOperatorMetadataBuilder, SetWeightFromSwitchboardBuilder, ShrinkNCNRewardRouterBuilder,
         SnapshotVaultOperatorDelegationBuilder,
     },
-    types::ConfigAdminRole,
+    types::{ConfigAdminRole, StMintWeight},
 };
 use ncn_program_core::{
     account_payer::AccountPayer,
@@ -33,16 +43,22 @@ use ncn_program_core::{
     epoch_state::EpochState,
     error::NCNProgramError,
     fees::FeeConfig,
+    keeper_lease::KeeperLease,
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+    operator_metadata::OperatorMetadata,
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
     vault_registry::VaultRegistry,
+    views::{ConsensusResultView, OperatorRewardRouteView, ReturnData, WinningBallotView},
     weight_table::WeightTable,
 };
 use solana_program::{
-    instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::sol_to_lamports,
+    program_error::ProgramError,
+    pubkey::Pubkey,
     system_instruction::transfer,
 };
-use solana_program_test::{BanksClient, ProgramTestBanksClientExt};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestBanksClientExt};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     compute_budget::ComputeBudgetInstruction,
@@ -83,6 +99,26 @@ impl NCNProgramClient {
         Ok(())
     }
 
+    /// Sends instructions paid for by the payer and signed by the given signers, the way a Squads
+    /// vault signs its instructions without paying for them when a vault transaction is executed.
+    pub async fn process_signed_by(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TestResult<()> {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.process_transaction(&tx).await
+    }
+
     /// Airdrops SOL to a specified public key.
     pub async fn airdrop(&mut self, to: &Pubkey, sol: f64) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -152,6 +188,34 @@ impl NCNProgramClient {
         Ok(*VaultRegistry::try_from_slice_unchecked(vault_registry.data.as_slice()).unwrap())
     }
 
+    /// Fetches the OperatorMetadata account for a given operator and NCN.
+    pub async fn get_operator_metadata(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+    ) -> TestResult<OperatorMetadata> {
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&ncn_program::id(), &operator, &ncn).0;
+        let raw_account = self
+            .banks_client
+            .get_account(operator_metadata)
+            .await?
+            .unwrap();
+        Ok(*OperatorMetadata::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    /// Fetches the KeeperLease account for a given NCN and authority.
+    pub async fn get_keeper_lease(
+        &mut self,
+        ncn: Pubkey,
+        authority: Pubkey,
+    ) -> TestResult<KeeperLease> {
+        let keeper_lease =
+            KeeperLease::find_program_address(&ncn_program::id(), &ncn, &authority).0;
+        let raw_account = self.banks_client.get_account(keeper_lease).await?.unwrap();
+        Ok(*KeeperLease::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
     /// Fetches the EpochState account for a given NCN and epoch.
     pub async fn get_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<EpochState> {
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
@@ -315,17 +379,19 @@ impl NCNProgramClient {
         let config_pda =
             NcnConfig::find_program_address(&ncn_program::id(), &ncn_root.ncn_pubkey).0;
         self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
-        self.set_new_admin(config_pda, role, new_admin, ncn_root)
+        self.set_new_admin(config_pda, role, new_admin, ncn_root, &[])
             .await
     }
 
-    /// Sends a transaction to set a new admin in the NCN config.
+    /// Sends a transaction to set a new admin in the NCN config, signed by the given admin
+    /// approvers next to the NCN admin.
     pub async fn set_new_admin(
         &mut self,
         config_pda: Pubkey,
         role: ConfigAdminRole,
         new_admin: Pubkey,
         ncn_root: &NcnRoot,
+        approvers: &[&Keypair],
     ) -> TestResult<()> {
         let ix = AdminSetNewAdminBuilder::new()
             .config(config_pda)
@@ -333,13 +399,98 @@ impl NCNProgramClient {
             .ncn_admin(ncn_root.ncn_admin.pubkey())
             .new_admin(new_admin)
             .role(role)
+            .add_remaining_accounts(&approver_metas(approvers))
             .instruction();
 
+        let mut signers = vec![&ncn_root.ncn_admin];
+        signers.extend_from_slice(approvers);
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&ncn_root.ncn_admin.pubkey()),
-            &[&ncn_root.ncn_admin],
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets the admin multisig of the NCN config, signed by the NCN admin and the current approvers.
+    pub async fn do_admin_set_admin_multisig(
+        &mut self,
+        approvers: &[Pubkey],
+        threshold: u8,
+        ncn_root: &NcnRoot,
+        current_approvers: &[&Keypair],
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&ncn_program::id(), &ncn_root.ncn_pubkey).0;
+        self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
+        self.admin_set_admin_multisig(
+            config_pda,
+            approvers,
+            threshold,
+            ncn_root,
+            current_approvers,
+        )
+        .await
+    }
+
+    /// Sends a transaction to set the admin governance of the NCN config, with `ncn_admin` signing
+    /// as the NCN admin or, once an admin governance is set, as its governance.
+    pub async fn admin_set_admin_governance(
+        &mut self,
+        ncn: Pubkey,
+        governance_program: Pubkey,
+        realm: Pubkey,
+        ncn_admin: &Keypair,
+    ) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let ix = AdminSetAdminGovernanceBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .ncn_admin(ncn_admin.pubkey())
+            .governance_program(governance_program)
+            .realm(realm)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sends a transaction to set the admin multisig of the NCN config.
+    pub async fn admin_set_admin_multisig(
+        &mut self,
+        config_pda: Pubkey,
+        approvers: &[Pubkey],
+        threshold: u8,
+        ncn_root: &NcnRoot,
+        current_approvers: &[&Keypair],
+    ) -> TestResult<()> {
+        let ix = AdminSetAdminMultisigBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .approvers(approvers.to_vec())
+            .threshold(threshold)
+            .add_remaining_accounts(&approver_metas(current_approvers))
+            .instruction();
+
+        let mut signers = vec![&ncn_root.ncn_admin];
+        signers.extend_from_slice(current_approvers);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &signers,
             blockhash,
         ))
         .await
@@ -347,11 +498,25 @@ impl NCNProgramClient {
 
     /// Initializes the epoch state account for a given NCN and epoch.
     pub async fn do_intialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        self.initialize_epoch_state(ncn, epoch).await
+        self.initialize_epoch_state(ncn, epoch, false).await
+    }
+
+    /// Initializes the epoch state account, succeeding without changes if it already exists.
+    pub async fn do_initialize_epoch_state_if_needed(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.initialize_epoch_state(ncn, epoch, true).await
     }
 
     /// Sends a transaction to initialize the epoch state account.
-    pub async fn initialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+    pub async fn initialize_epoch_state(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        init_if_needed: bool,
+    ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
@@ -361,6 +526,37 @@ impl NCNProgramClient {
         let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
 
         let ix = InitializeEpochStateBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .init_if_needed(init_if_needed)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Marks an epoch without an epoch state as skipped.
+    pub async fn do_mark_epoch_skipped(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let ix = MarkEpochSkippedBuilder::new()
             .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
             .config(config)
@@ -380,6 +576,62 @@ impl NCNProgramClient {
         .await
     }
 
+    /// Initializes the epoch state, weight table, ballot box and NCN reward router in one transaction.
+    pub async fn do_initialize_epoch_accounts(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.initialize_epoch_accounts(ncn, epoch).await
+    }
+
+    /// Sends a transaction to bootstrap the accounts of an epoch.
+    pub async fn initialize_epoch_accounts(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        let weight_table = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ballot_box = BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let consensus_result =
+            ConsensusResult::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let ix = InitializeEpochAccountsBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .vault_registry(vault_registry)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .ballot_box(ballot_box)
+            .consensus_result(consensus_result)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     /// Initializes and fully reallocates the weight table account for a given NCN and epoch.
     pub async fn do_full_initialize_weight_table(
         &mut self,
@@ -395,11 +647,25 @@ impl NCNProgramClient {
 
     /// Initializes the weight table account for a given NCN and epoch.
     pub async fn do_initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        self.initialize_weight_table(ncn, epoch).await
+        self.initialize_weight_table(ncn, epoch, false).await
+    }
+
+    /// Initializes the weight table account, succeeding without changes if it already exists.
+    pub async fn do_initialize_weight_table_if_needed(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.initialize_weight_table(ncn, epoch, true).await
     }
 
     /// Sends a transaction to initialize the weight table account.
-    pub async fn initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+    pub async fn initialize_weight_table(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        init_if_needed: bool,
+    ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
@@ -417,6 +683,7 @@ impl NCNProgramClient {
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -439,13 +706,31 @@ impl NCNProgramClient {
         let weight_table = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
         let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        // Vaults are only needed to check their last update when weight decay is enabled
+        let vault_accounts: Vec<AccountMeta> =
+            if self.get_ncn_config(ncn).await?.weight_decay_enabled() {
+                self.get_vault_registry(ncn)
+                    .await?
+                    .get_valid_vault_entries()
+                    .iter()
+                    .map(|vault_entry| AccountMeta::new_readonly(*vault_entry.vault(), false))
+                    .collect()
+            } else {
+                vec![]
+            };
 
         let ix = SetEpochWeightsBuilder::new()
             .epoch_state(epoch_state)
             .ncn(ncn)
             .weight_table(weight_table)
             .vault_registry(vault_registry)
+            .config(config)
+            .restaking_config(restaking_config)
             .epoch(epoch)
+            .add_remaining_accounts(&vault_accounts)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -500,38 +785,33 @@ impl NCNProgramClient {
         .await
     }
 
-    /// Initializes and fully reallocates the vault registry account for a given NCN.
-    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        self.do_initialize_vault_registry(ncn).await?;
-        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
-        Ok(())
-    }
-
-    /// Initializes the vault registry account for a given NCN.
-    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
-        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
-
-        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
-            .await
+    /// Sets the weights for several st_mints in the weight table in one call (admin operation).
+    pub async fn do_admin_set_weights(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        weights: Vec<StMintWeight>,
+    ) -> TestResult<()> {
+        self.admin_set_weights(ncn, epoch, weights).await
     }
 
-    /// Sends a transaction to initialize the vault registry account.
-    pub async fn initialize_vault_registry(
+    /// Sends a transaction to set the weights for several st_mints in the weight table (admin operation).
+    pub async fn admin_set_weights(
         &mut self,
-        ncn_config: &Pubkey,
-        vault_registry: &Pubkey,
-        ncn: &Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        weights: Vec<StMintWeight>,
     ) -> TestResult<()> {
-        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), ncn);
+        let weight_table = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
 
-        let ix = InitializeVaultRegistryBuilder::new()
-            .config(*ncn_config)
-            .vault_registry(*vault_registry)
-            .ncn(*ncn)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
+        let ix = AdminSetWeightsBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .weight_table_admin(self.payer.pubkey())
+            .weights(weights)
+            .epoch(epoch)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -544,41 +824,43 @@ impl NCNProgramClient {
         .await
     }
 
-    /// Reallocates the vault registry account multiple times.
-    pub async fn do_realloc_vault_registry(
+    /// Sets the weight for an st_mint from its registered switchboard feed.
+    pub async fn do_set_weight_from_switchboard(
         &mut self,
         ncn: Pubkey,
-        num_reallocations: u64,
+        epoch: u64,
+        st_mint: Pubkey,
+        switchboard_feed: Pubkey,
     ) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
-        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
-        self.realloc_vault_registry(&ncn, &ncn_config, &vault_registry, num_reallocations)
+        self.set_weight_from_switchboard(ncn, epoch, st_mint, switchboard_feed)
             .await
     }
 
-    /// Sends transactions to reallocate the vault registry account.
-    pub async fn realloc_vault_registry(
+    /// Sends a transaction to set the weight for an st_mint from a switchboard feed.
+    pub async fn set_weight_from_switchboard(
         &mut self,
-        ncn: &Pubkey,
-        config: &Pubkey,
-        vault_registry: &Pubkey,
-        num_reallocations: u64,
+        ncn: Pubkey,
+        epoch: u64,
+        st_mint: Pubkey,
+        switchboard_feed: Pubkey,
     ) -> TestResult<()> {
-        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), ncn);
+        let weight_table = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
 
-        let ix = ReallocVaultRegistryBuilder::new()
-            .ncn(*ncn)
-            .account_payer(account_payer)
-            .config(*config)
-            .vault_registry(*vault_registry)
-            .system_program(system_program::id())
+        let ix = SetWeightFromSwitchboardBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .weight_table(weight_table)
+            .switchboard_feed(switchboard_feed)
+            .st_mint(st_mint)
+            .epoch(epoch)
             .instruction();
 
-        let ixs = vec![ix; num_reallocations as usize];
-
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &ixs,
+            &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
@@ -586,21 +868,155 @@ impl NCNProgramClient {
         .await
     }
 
-    /// Registers a vault with the NCN program.
-    pub async fn do_register_vault(
+    /// Copies the weights of the previous epoch into the epoch's weight table.
+    pub async fn do_copy_previous_epoch_weights(
         &mut self,
         ncn: Pubkey,
-        vault: Pubkey,
-        ncn_vault_ticket: Pubkey,
+        epoch: u64,
     ) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        self.copy_previous_epoch_weights(ncn, epoch).await
+    }
+
+    /// Sends a transaction to copy the weights of the previous epoch into the weight table.
+    pub async fn copy_previous_epoch_weights(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let weight_table = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let previous_weight_table =
+            WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch - 1).0;
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let ix = CopyPreviousEpochWeightsBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .previous_weight_table(previous_weight_table)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Initializes and fully reallocates the vault registry account for a given NCN.
+    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
+        self.do_initialize_vault_registry(ncn).await?;
+        let num_reallocs =
+            (VaultRegistry::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
+        Ok(())
+    }
 
+    /// Initializes the vault registry account for a given NCN.
+    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
         let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
 
-        self.register_vault(ncn_config, vault_registry, ncn, vault, ncn_vault_ticket)
+        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
+            .await
+    }
+
+    /// Sends a transaction to initialize the vault registry account.
+    pub async fn initialize_vault_registry(
+        &mut self,
+        ncn_config: &Pubkey,
+        vault_registry: &Pubkey,
+        ncn: &Pubkey,
+    ) -> TestResult<()> {
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), ncn);
+
+        let ix = InitializeVaultRegistryBuilder::new()
+            .config(*ncn_config)
+            .vault_registry(*vault_registry)
+            .ncn(*ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Reallocates the vault registry account multiple times.
+    pub async fn do_realloc_vault_registry(
+        &mut self,
+        ncn: Pubkey,
+        num_reallocations: u64,
+    ) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        self.realloc_vault_registry(&ncn, &ncn_config, &vault_registry, num_reallocations)
             .await
     }
 
+    /// Sends transactions to reallocate the vault registry account.
+    pub async fn realloc_vault_registry(
+        &mut self,
+        ncn: &Pubkey,
+        config: &Pubkey,
+        vault_registry: &Pubkey,
+        num_reallocations: u64,
+    ) -> TestResult<()> {
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), ncn);
+
+        let ix = ReallocVaultRegistryBuilder::new()
+            .ncn(*ncn)
+            .account_payer(account_payer)
+            .config(*config)
+            .vault_registry(*vault_registry)
+            .system_program(system_program::id())
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Registers a vault with the NCN program.
+    pub async fn do_register_vault(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        ncn_vault_ticket: Pubkey,
+    ) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let raw_vault = self.banks_client.get_account(vault).await?.unwrap();
+        let st_mint = Vault::try_from_slice_unchecked(raw_vault.data.as_slice())?.supported_mint;
+
+        self.register_vault(
+            ncn_config,
+            vault_registry,
+            ncn,
+            vault,
+            ncn_vault_ticket,
+            restaking_config,
+            st_mint,
+        )
+        .await
+    }
+
     /// Sends a transaction to register a vault.
     pub async fn register_vault(
         &mut self,
@@ -609,6 +1025,8 @@ impl NCNProgramClient {
         ncn: Pubkey,
         vault: Pubkey,
         ncn_vault_ticket: Pubkey,
+        restaking_config: Pubkey,
+        st_mint: Pubkey,
     ) -> TestResult<()> {
         let ix = RegisterVaultBuilder::new()
             .config(config)
@@ -616,6 +1034,8 @@ impl NCNProgramClient {
             .ncn(ncn)
             .vault(vault)
             .ncn_vault_ticket(ncn_vault_ticket)
+            .restaking_config(restaking_config)
+            .st_mint(st_mint)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -641,11 +1061,12 @@ impl NCNProgramClient {
 
         let admin = self.payer.pubkey();
 
-        self.admin_register_st_mint(ncn, ncn_config, vault_registry, admin, st_mint, weight)
+        self.admin_register_st_mint(ncn, ncn_config, vault_registry, admin, st_mint, weight, &[])
             .await
     }
 
-    /// Sends a transaction to register an st_mint in the vault registry (admin operation).
+    /// Sends a transaction to register an st_mint in the vault registry (admin operation), signed
+    /// by the given admin approvers next to the payer.
     #[allow(clippy::too_many_arguments)]
     pub async fn admin_register_st_mint(
         &mut self,
@@ -655,6 +1076,7 @@ impl NCNProgramClient {
         admin: Pubkey,
         st_mint: Pubkey,
         weight: u128,
+        approvers: &[&Keypair],
     ) -> TestResult<()> {
         let ix = {
             let mut builder = AdminRegisterStMintBuilder::new();
@@ -664,16 +1086,20 @@ impl NCNProgramClient {
                 .vault_registry(vault_registry)
                 .admin(admin)
                 .st_mint(st_mint)
-                .weight(weight);
+                .weight(weight)
+                .add_remaining_accounts(&approver_metas(approvers));
 
             builder.instruction()
         };
 
+        let mut signers = vec![&self.payer];
+        signers.extend_from_slice(approvers);
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &signers,
             blockhash,
         ))
         .await
@@ -692,11 +1118,47 @@ impl NCNProgramClient {
 
         let admin = self.payer.pubkey();
 
-        self.admin_set_st_mint(ncn, ncn_config, vault_registry, admin, st_mint, weight)
-            .await
+        self.admin_set_st_mint(
+            ncn,
+            ncn_config,
+            vault_registry,
+            admin,
+            st_mint,
+            Some(weight),
+            None,
+            &[],
+        )
+        .await
     }
 
-    /// Sends a transaction to set the weight for an st_mint in the vault registry (admin operation).
+    /// Sets the switchboard feed for an existing st_mint in the vault registry (admin operation).
+    pub async fn do_admin_set_st_mint_switchboard_feed(
+        &mut self,
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        switchboard_feed: Pubkey,
+    ) -> TestResult<()> {
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let admin = self.payer.pubkey();
+
+        self.admin_set_st_mint(
+            ncn,
+            ncn_config,
+            vault_registry,
+            admin,
+            st_mint,
+            None,
+            Some(switchboard_feed),
+            &[],
+        )
+        .await
+    }
+
+    /// Sends a transaction to set the weight for an st_mint in the vault registry (admin operation),
+    /// signed by the given admin approvers next to the payer.
     #[allow(clippy::too_many_arguments)]
     pub async fn admin_set_st_mint(
         &mut self,
@@ -705,7 +1167,9 @@ impl NCNProgramClient {
         vault_registry: Pubkey,
         admin: Pubkey,
         st_mint: Pubkey,
-        weight: u128,
+        weight: Option<u128>,
+        switchboard_feed: Option<Pubkey>,
+        approvers: &[&Keypair],
     ) -> TestResult<()> {
         let ix = {
             let mut builder = AdminSetStMintBuilder::new();
@@ -715,16 +1179,377 @@ impl NCNProgramClient {
                 .vault_registry(vault_registry)
                 .admin(admin)
                 .st_mint(st_mint)
-                .weight(weight);
+                .add_remaining_accounts(&approver_metas(approvers));
+
+            if let Some(weight) = weight {
+                builder.weight(weight);
+            }
+
+            if let Some(switchboard_feed) = switchboard_feed {
+                builder.switchboard_feed(switchboard_feed);
+            }
 
             builder.instruction()
         };
 
+        let mut signers = vec![&self.payer];
+        signers.extend_from_slice(approvers);
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets the default weight of the vault registry (admin operation).
+    pub async fn do_admin_set_default_weight(
+        &mut self,
+        ncn: Pubkey,
+        default_weight: u128,
+    ) -> TestResult<()> {
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let admin = self.payer.pubkey();
+
+        self.admin_set_default_weight(ncn, ncn_config, vault_registry, admin, default_weight, &[])
+            .await
+    }
+
+    /// Sends a transaction to set the default weight of the vault registry (admin operation),
+    /// signed by the given admin approvers next to the payer.
+    pub async fn admin_set_default_weight(
+        &mut self,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        vault_registry: Pubkey,
+        admin: Pubkey,
+        default_weight: u128,
+        approvers: &[&Keypair],
+    ) -> TestResult<()> {
+        let ix = AdminSetDefaultWeightBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .admin(admin)
+            .default_weight(default_weight)
+            .add_remaining_accounts(&approver_metas(approvers))
+            .instruction();
+
+        let mut signers = vec![&self.payer];
+        signers.extend_from_slice(approvers);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets the display name and URI hash of a vault in the vault registry (admin operation).
+    pub async fn do_admin_set_vault_metadata(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        name: [u8; 32],
+        uri_hash: [u8; 32],
+    ) -> TestResult<()> {
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let admin = self.payer.insecure_clone();
+
+        self.admin_set_vault_metadata(
+            ncn,
+            ncn_config,
+            vault_registry,
+            vault,
+            &admin,
+            name,
+            uri_hash,
+            &[],
+        )
+        .await
+    }
+
+    /// Sends a transaction to set the display name and URI hash of a vault in the vault registry,
+    /// signed by the given admin approvers next to the admin.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn admin_set_vault_metadata(
+        &mut self,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        vault_registry: Pubkey,
+        vault: Pubkey,
+        admin: &Keypair,
+        name: [u8; 32],
+        uri_hash: [u8; 32],
+        approvers: &[&Keypair],
+    ) -> TestResult<()> {
+        let ix = AdminSetVaultMetadataBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .vault(vault)
+            .admin(admin.pubkey())
+            .name(name)
+            .uri_hash(uri_hash)
+            .add_remaining_accounts(&approver_metas(approvers))
+            .instruction();
+
+        let mut signers = vec![&self.payer, admin];
+        signers.extend_from_slice(approvers);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Takes or renews the keeper lease of the authority for the given keeper instance.
+    pub async fn do_acquire_keeper_lease(
+        &mut self,
+        ncn: Pubkey,
+        authority: &Keypair,
+        holder: Pubkey,
+        lease_slots: u64,
+    ) -> TestResult<()> {
+        let keeper_lease =
+            KeeperLease::find_program_address(&ncn_program::id(), &ncn, &authority.pubkey()).0;
+
+        self.acquire_keeper_lease(ncn, keeper_lease, authority, holder, lease_slots)
+            .await
+    }
+
+    /// Sends a transaction to take or renew a keeper lease.
+    pub async fn acquire_keeper_lease(
+        &mut self,
+        ncn: Pubkey,
+        keeper_lease: Pubkey,
+        authority: &Keypair,
+        holder: Pubkey,
+        lease_slots: u64,
+    ) -> TestResult<()> {
+        let ix = AcquireKeeperLeaseBuilder::new()
+            .ncn(ncn)
+            .keeper_lease(keeper_lease)
+            .authority(authority.pubkey())
+            .system_program(system_program::id())
+            .holder(holder)
+            .lease_slots(lease_slots)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, authority],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Creates or updates the metadata an operator publishes for the NCN, signed by the operator admin.
+    pub async fn do_set_operator_metadata(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        name: [u8; 32],
+        website: [u8; 64],
+        contact_hash: [u8; 32],
+    ) -> TestResult<()> {
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&ncn_program::id(), &operator, &ncn).0;
+
+        self.set_operator_metadata(
+            ncn,
+            operator,
+            ncn_operator_state,
+            operator_metadata,
+            operator_admin,
+            name,
+            website,
+            contact_hash,
+        )
+        .await
+    }
+
+    /// Sends a transaction to create or update the metadata of an operator.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_operator_metadata(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        ncn_operator_state: Pubkey,
+        operator_metadata: Pubkey,
+        operator_admin: &Keypair,
+        name: [u8; 32],
+        website: [u8; 64],
+        contact_hash: [u8; 32],
+    ) -> TestResult<()> {
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let ix = SetOperatorMetadataBuilder::new()
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .operator_metadata(operator_metadata)
+            .operator_admin(operator_admin.pubkey())
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .name(name)
+            .website(website)
+            .contact_hash(contact_hash)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets where a vault's rewards are sent, signed by the vault admin.
+    pub async fn do_admin_set_vault_reward_recipient(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        vault_admin: &Keypair,
+        reward_recipient: Pubkey,
+    ) -> TestResult<()> {
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        self.admin_set_vault_reward_recipient(
+            ncn,
+            ncn_config,
+            vault_registry,
+            vault,
+            vault_admin,
+            reward_recipient,
+        )
+        .await
+    }
+
+    /// Sends a transaction to set the reward recipient of a vault in the vault registry.
+    pub async fn admin_set_vault_reward_recipient(
+        &mut self,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        vault_registry: Pubkey,
+        vault: Pubkey,
+        vault_admin: &Keypair,
+        reward_recipient: Pubkey,
+    ) -> TestResult<()> {
+        let ix = AdminSetVaultRewardRecipientBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .vault(vault)
+            .reward_recipient(reward_recipient)
+            .vault_admin(vault_admin.pubkey())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets a reward mint in the config, signed by the NCN admin.
+    pub async fn do_admin_set_reward_mint(
+        &mut self,
+        ncn: Pubkey,
+        ncn_admin: &Keypair,
+        index: u8,
+        mint: Pubkey,
+    ) -> TestResult<()> {
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        self.admin_set_reward_mint(ncn, ncn_config, ncn_admin, index, mint)
+            .await
+    }
+
+    /// Sends a transaction to set a reward mint slot in the config.
+    pub async fn admin_set_reward_mint(
+        &mut self,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        ncn_admin: &Keypair,
+        index: u8,
+        mint: Pubkey,
+    ) -> TestResult<()> {
+        let ix = AdminSetRewardMintBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .ncn_admin(ncn_admin.pubkey())
+            .index(index)
+            .mint(mint)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sets up a custom fee group in the config, signed by the NCN admin.
+    pub async fn do_admin_set_fee_group(
+        &mut self,
+        ncn: Pubkey,
+        ncn_admin: &Keypair,
+        group: u8,
+        name: [u8; 32],
+        fee_wallet: Pubkey,
+        fee_bps: Option<u16>,
+    ) -> TestResult<()> {
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let mut ix = AdminSetFeeGroupBuilder::new();
+        ix.config(ncn_config)
+            .ncn(ncn)
+            .fee_wallet(fee_wallet)
+            .ncn_admin(ncn_admin.pubkey())
+            .group(group)
+            .name(name);
+        if let Some(fee_bps) = fee_bps {
+            ix.fee_bps(fee_bps);
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix.instruction()],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, ncn_admin],
             blockhash,
         ))
         .await
@@ -736,11 +1561,16 @@ impl NCNProgramClient {
         ncn: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
-        self.initialize_epoch_snapshot(ncn, epoch).await
+        self.initialize_epoch_snapshot(ncn, epoch, false).await
     }
 
     /// Sends a transaction to initialize the epoch snapshot account.
-    pub async fn initialize_epoch_snapshot(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+    pub async fn initialize_epoch_snapshot(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        init_if_needed: bool,
+    ) -> TestResult<()> {
         let config_pda = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
@@ -750,6 +1580,22 @@ impl NCNProgramClient {
 
         let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
 
+        // Vaults are only needed to compute the stake weight cap
+        let vault_accounts: Vec<AccountMeta> = if self
+            .get_ncn_config(ncn)
+            .await?
+            .vault_stake_weight_cap_enabled()
+        {
+            self.get_weight_table(ncn, epoch)
+                .await?
+                .vault_entries()
+                .iter()
+                .map(|vault_entry| AccountMeta::new_readonly(*vault_entry.vault(), false))
+                .collect()
+        } else {
+            vec![]
+        };
+
         let ix = InitializeEpochSnapshotBuilder::new()
             .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
@@ -760,6 +1606,8 @@ impl NCNProgramClient {
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
+            .add_remaining_accounts(&vault_accounts)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -779,7 +1627,18 @@ impl NCNProgramClient {
         ncn: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
-        self.initialize_operator_snapshot(operator, ncn, epoch)
+        self.initialize_operator_snapshot(operator, ncn, epoch, false)
+            .await
+    }
+
+    /// Initializes the operator snapshot account, succeeding without changes if it already exists.
+    pub async fn do_initialize_operator_snapshot_if_needed(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.initialize_operator_snapshot(operator, ncn, epoch, true)
             .await
     }
 
@@ -789,6 +1648,7 @@ impl NCNProgramClient {
         operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
+        init_if_needed: bool,
     ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
@@ -818,6 +1678,7 @@ impl NCNProgramClient {
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -830,6 +1691,85 @@ impl NCNProgramClient {
         .await
     }
 
+    /// Initializes the operator snapshot accounts of several operators in one transaction.
+    pub async fn do_batch_initialize_operator_snapshot(
+        &mut self,
+        operators: &[Pubkey],
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.batch_initialize_operator_snapshot(operators, ncn, epoch)
+            .await
+    }
+
+    /// Sends a transaction to initialize the operator snapshot accounts of several operators.
+    pub async fn batch_initialize_operator_snapshot(
+        &mut self,
+        operators: &[Pubkey],
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let config_pda = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let epoch_snapshot = EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let operator_snapshot_accounts: Vec<AccountMeta> = operators
+            .iter()
+            .flat_map(|operator| {
+                let ncn_operator_state = NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn,
+                    operator,
+                )
+                .0;
+                let operator_snapshot = OperatorSnapshot::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch,
+                )
+                .0;
+
+                [
+                    AccountMeta::new_readonly(*operator, false),
+                    AccountMeta::new_readonly(ncn_operator_state, false),
+                    AccountMeta::new(operator_snapshot, false),
+                ]
+            })
+            .collect();
+
+        let ix = BatchInitializeOperatorSnapshotBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config_pda)
+            .restaking_config(restaking_config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .add_remaining_accounts(&operator_snapshot_accounts)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     /// Snapshots the delegation information from a vault to an operator for a given NCN and epoch.
     pub async fn do_snapshot_vault_operator_delegation(
         &mut self,
@@ -927,7 +1867,7 @@ impl NCNProgramClient {
         )
         .0;
 
-        self.initialize_ballot_box(ncn_config, ballot_box, ncn, epoch)
+        self.initialize_ballot_box(ncn_config, ballot_box, ncn, epoch, false)
             .await
     }
 
@@ -938,6 +1878,7 @@ impl NCNProgramClient {
         ballot_box: Pubkey,
         ncn: Pubkey,
         epoch: u64,
+        init_if_needed: bool,
     ) -> Result<(), TestError> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
@@ -955,6 +1896,7 @@ impl NCNProgramClient {
             .ballot_box(ballot_box)
             .ncn(ncn)
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
             .account_payer(account_payer)
             .consensus_result(consensus_result)
             .instruction();
@@ -1117,6 +2059,103 @@ impl NCNProgramClient {
         .await
     }
 
+    /// Runs the example consensus consumer, gated on the consensus result of the epoch.
+    pub async fn do_act_on_consensus(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        expected_weather_status: u8,
+    ) -> Result<(), TestError> {
+        let ix = ncn_consensus_consumer::act_on_consensus(&ncn, epoch, expected_weather_status);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Simulates a getter instruction and parses its return data.
+    async fn simulate_getter<T: ReturnData>(&mut self, ix: Instruction) -> TestResult<T> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        let simulation = self.banks_client.simulate_transaction(tx).await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err).into());
+        }
+
+        let return_data = simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(T::from_return_data(&return_data.data).ok_or(ProgramError::InvalidAccountData)?)
+    }
+
+    /// Queries the consensus result of an epoch through `GetConsensusResult`.
+    pub async fn get_consensus_result_view(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<ConsensusResultView> {
+        let consensus_result =
+            ConsensusResult::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let ix = GetConsensusResultBuilder::new()
+            .ncn(ncn)
+            .consensus_result(consensus_result)
+            .epoch(epoch)
+            .instruction();
+
+        self.simulate_getter(ix).await
+    }
+
+    /// Queries the winning ballot of an epoch through `GetWinningBallot`.
+    pub async fn get_winning_ballot_view(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<WinningBallotView> {
+        let ballot_box = BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let ix = GetWinningBallotBuilder::new()
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .epoch(epoch)
+            .instruction();
+
+        self.simulate_getter(ix).await
+    }
+
+    /// Queries the reward route of an operator through `GetOperatorRewardRoute`.
+    pub async fn get_operator_reward_route_view(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<OperatorRewardRouteView> {
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let ix = GetOperatorRewardRouteBuilder::new()
+            .ncn(ncn)
+            .ncn_reward_router(ncn_reward_router)
+            .operator(operator)
+            .epoch(epoch)
+            .instruction();
+
+        self.simulate_getter(ix).await
+    }
+
     /// Sets the tie-breaker weather status for an epoch (admin operation).
     pub async fn do_admin_set_tie_breaker(
         &mut self,
@@ -1270,70 +2309,260 @@ impl NCNProgramClient {
     ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
-
+
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let (config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let config_account = self.get_ncn_config(ncn).await?;
+        let ncn_fee_wallet = *config_account.fee_config.ncn_fee_wallet();
+
+        self.close_epoch_account(
+            epoch_marker,
+            epoch_state,
+            ncn,
+            config,
+            account_to_close,
+            account_payer,
+            Some(receiver_to_close),
+            Some(ncn_fee_wallet),
+            epoch,
+        )
+        .await
+    }
+
+    /// Sends a transaction to close an epoch-specific account.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_epoch_account(
+        &mut self,
+        epoch_marker: Pubkey,
+        epoch_state: Pubkey,
+        ncn: Pubkey,
+        config: Pubkey,
+        account_to_close: Pubkey,
+        account_payer: Pubkey,
+
+        receiver_to_close: Option<Pubkey>,
+        ncn_fee_wallet: Option<Pubkey>,
+
+        epoch: u64,
+    ) -> TestResult<()> {
+        let mut ix = CloseEpochAccountBuilder::new();
+
+        ix.account_payer(account_payer)
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .account_to_close(account_to_close)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .system_program(system_program::id())
+            .epoch(epoch);
+
+        if let Some(receiver_to_close) = receiver_to_close {
+            ix.receiver_to_close(Some(receiver_to_close));
+        }
+
+        if let Some(ncn_fee_wallet) = ncn_fee_wallet {
+            ix.ncn_fee_wallet(Some(ncn_fee_wallet));
+        }
+
+        let ix = ix.instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    /// Sends the lamports left in the NCN reward receiver, or the operator vault reward receiver
+    /// of `operator`, to the NCN fee wallet once its router is closed
+    pub async fn do_close_reward_receiver(
+        &mut self,
+        ncn: Pubkey,
+        operator: Option<Pubkey>,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let config_account = self.get_ncn_config(ncn).await?;
+        let ncn_fee_wallet = *config_account.fee_config.ncn_fee_wallet();
+
+        self.close_reward_receiver(ncn, operator, epoch, ncn_fee_wallet)
+            .await
+    }
+
+    /// Sends a transaction sending the lamports left in a reward receiver to `ncn_fee_wallet`
+    pub async fn close_reward_receiver(
+        &mut self,
+        ncn: Pubkey,
+        operator: Option<Pubkey>,
+        epoch: u64,
+        ncn_fee_wallet: Pubkey,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
+
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let (config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let (reward_router, reward_receiver) = match operator {
+            Some(operator) => (
+                OperatorVaultRewardRouter::find_program_address(
+                    &ncn_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+                OperatorVaultRewardReceiver::find_program_address(
+                    &ncn_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            ),
+            None => (
+                NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            ),
+        };
+
+        let mut ix = CloseRewardReceiverBuilder::new();
+        ix.epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .reward_router(reward_router)
+            .reward_receiver(reward_receiver)
+            .ncn_fee_wallet(ncn_fee_wallet)
+            .system_program(system_program::id())
+            .epoch(epoch);
+
+        if let Some(operator) = operator {
+            ix.operator(operator);
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix.instruction()],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    /// Closes all accounts of the epoch for the given operators in one transaction, in the same
+    /// order as they are closed one by one.
+    pub async fn do_close_all_epoch_accounts(
+        &mut self,
+        ncn: Pubkey,
+        operators: &[Pubkey],
+        epoch: u64,
+    ) -> TestResult<()> {
+        let mut accounts_to_close: Vec<AccountMeta> = vec![];
+
+        for operator in operators.iter() {
+            accounts_to_close.push(AccountMeta::new(
+                OperatorVaultRewardRouter::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+                false,
+            ));
+            accounts_to_close.push(AccountMeta::new(
+                OperatorVaultRewardReceiver::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+                false,
+            ));
+        }
+
+        accounts_to_close.push(AccountMeta::new(
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+        accounts_to_close.push(AccountMeta::new(
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+        accounts_to_close.push(AccountMeta::new(
+            BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+
+        for operator in operators.iter() {
+            accounts_to_close.push(AccountMeta::new(
+                OperatorSnapshot::find_program_address(&ncn_program::id(), operator, &ncn, epoch).0,
+                false,
+            ));
+        }
+
+        accounts_to_close.push(AccountMeta::new(
+            EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+        accounts_to_close.push(AccountMeta::new(
+            WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+        accounts_to_close.push(AccountMeta::new(
+            EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            false,
+        ));
+
+        self.close_all_epoch_accounts(ncn, &accounts_to_close, epoch)
+            .await
+    }
+
+    pub async fn close_all_epoch_accounts(
+        &mut self,
+        ncn: Pubkey,
+        accounts_to_close: &[AccountMeta],
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
-
         let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
-
         let (config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
 
         let config_account = self.get_ncn_config(ncn).await?;
         let ncn_fee_wallet = *config_account.fee_config.ncn_fee_wallet();
 
-        self.close_epoch_account(
-            epoch_marker,
-            epoch_state,
-            ncn,
-            config,
-            account_to_close,
-            account_payer,
-            Some(receiver_to_close),
-            Some(ncn_fee_wallet),
-            epoch,
-        )
-        .await
-    }
-
-    /// Sends a transaction to close an epoch-specific account.
-    #[allow(clippy::too_many_arguments)]
-    pub async fn close_epoch_account(
-        &mut self,
-        epoch_marker: Pubkey,
-        epoch_state: Pubkey,
-        ncn: Pubkey,
-        config: Pubkey,
-        account_to_close: Pubkey,
-        account_payer: Pubkey,
-
-        receiver_to_close: Option<Pubkey>,
-        ncn_fee_wallet: Option<Pubkey>,
-
-        epoch: u64,
-    ) -> TestResult<()> {
-        let mut ix = CloseEpochAccountBuilder::new();
-
-        ix.account_payer(account_payer)
+        let ix = CloseAllEpochAccountsBuilder::new()
             .epoch_marker(epoch_marker)
-            .config(config)
-            .account_to_close(account_to_close)
             .epoch_state(epoch_state)
+            .config(config)
             .ncn(ncn)
+            .account_payer(account_payer)
             .system_program(system_program::id())
-            .epoch(epoch);
-
-        if let Some(receiver_to_close) = receiver_to_close {
-            ix.receiver_to_close(Some(receiver_to_close));
-        }
-
-        if let Some(ncn_fee_wallet) = ncn_fee_wallet {
-            ix.ncn_fee_wallet(Some(ncn_fee_wallet));
-        }
-
-        let ix = ix.instruction();
+            .ncn_fee_wallet(ncn_fee_wallet)
+            .epoch(epoch)
+            .add_remaining_accounts(accounts_to_close)
+            .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         let tx = Transaction::new_signed_with_payer(
-            &[ix],
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
@@ -1343,12 +2572,21 @@ impl NCNProgramClient {
     }
 
     /// Sets various parameters in the NCN config (admin operation).
+    #[allow(clippy::too_many_arguments)]
     pub async fn do_set_parameters(
         &mut self,
         starting_valid_epoch: Option<u64>,
         epochs_before_stall: Option<u64>,
         epochs_after_consensus_before_close: Option<u64>,
         valid_slots_after_consensus: Option<u64>,
+        cranker_fee_bps: Option<u16>,
+        timeliness_bonus_slots: Option<u64>,
+        timeliness_bonus_bps: Option<u16>,
+        weight_decay_epochs: Option<u64>,
+        weight_decay_bps: Option<u16>,
+        max_vault_stake_weight_bps: Option<u16>,
+        permissionless_vault_registration: Option<bool>,
+        parameters_timelock_slots: Option<u64>,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
         let config_pda =
@@ -1375,6 +2613,38 @@ impl NCNProgramClient {
             ix.valid_slots_after_consensus(slots);
         }
 
+        if let Some(fee_bps) = cranker_fee_bps {
+            ix.cranker_fee_bps(fee_bps);
+        }
+
+        if let Some(slots) = timeliness_bonus_slots {
+            ix.timeliness_bonus_slots(slots);
+        }
+
+        if let Some(bonus_bps) = timeliness_bonus_bps {
+            ix.timeliness_bonus_bps(bonus_bps);
+        }
+
+        if let Some(epochs) = weight_decay_epochs {
+            ix.weight_decay_epochs(epochs);
+        }
+
+        if let Some(decay_bps) = weight_decay_bps {
+            ix.weight_decay_bps(decay_bps);
+        }
+
+        if let Some(cap_bps) = max_vault_stake_weight_bps {
+            ix.max_vault_stake_weight_bps(cap_bps);
+        }
+
+        if let Some(permissionless) = permissionless_vault_registration {
+            ix.permissionless_vault_registration(permissionless);
+        }
+
+        if let Some(slots) = parameters_timelock_slots {
+            ix.parameters_timelock_slots(slots);
+        }
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix.instruction()],
@@ -1385,6 +2655,25 @@ impl NCNProgramClient {
         .await
     }
 
+    /// Applies the parameter changes queued for the NCN config.
+    pub async fn do_apply_parameters(&mut self, ncn: Pubkey) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let ix = ApplyParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn get_ncn_reward_router(
         &mut self,
         ncn: Pubkey,
@@ -1394,9 +2683,8 @@ impl NCNProgramClient {
 
         let raw_account = self.banks_client.get_account(address).await?.unwrap();
 
-        let account =
-            NCNRewardRouter::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
-        Ok(*account)
+        let account = NCNRewardRouter::try_from_shrunk_slice(raw_account.data.as_slice()).unwrap();
+        Ok(account)
     }
 
     pub async fn get_operator_vault_reward_router(
@@ -1471,7 +2759,7 @@ impl NCNProgramClient {
         let (ncn_reward_receiver, _, _) =
             NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch);
 
-        self.initialize_ncn_reward_router(ncn, ncn_reward_router, ncn_reward_receiver, epoch)
+        self.initialize_ncn_reward_router(ncn, ncn_reward_router, ncn_reward_receiver, epoch, false)
             .await
     }
 
@@ -1481,6 +2769,7 @@ impl NCNProgramClient {
         ncn_reward_router: Pubkey,
         ncn_reward_receiver: Pubkey,
         epoch: u64,
+        init_if_needed: bool,
     ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
@@ -1497,6 +2786,7 @@ impl NCNProgramClient {
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1557,6 +2847,39 @@ impl NCNProgramClient {
         .await
     }
 
+    pub async fn do_shrink_ncn_reward_router(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        self.shrink_ncn_reward_router(ncn, ncn_reward_router, epoch)
+            .await
+    }
+
+    pub async fn shrink_ncn_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        ncn_reward_router: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (account_payer, _, _) = AccountPayer::find_program_address(&ncn_program::id(), &ncn);
+
+        let ix = ShrinkNCNRewardRouterBuilder::new()
+            .ncn(ncn)
+            .ncn_reward_router(ncn_reward_router)
+            .account_payer(account_payer)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_operator_vault_reward_router(
         &mut self,
         ncn: Pubkey,
@@ -1588,6 +2911,7 @@ impl NCNProgramClient {
             operator_vault_reward_router,
             operator_vault_reward_receiver,
             epoch,
+            false,
         )
         .await
     }
@@ -1600,6 +2924,7 @@ impl NCNProgramClient {
         operator_vault_reward_router: Pubkey,
         operator_vault_reward_receiver: Pubkey,
         epoch: u64,
+        init_if_needed: bool,
     ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch);
@@ -1619,6 +2944,7 @@ impl NCNProgramClient {
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
+            .init_if_needed(init_if_needed)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1654,6 +2980,7 @@ impl NCNProgramClient {
                 ballot_box,
                 ncn_reward_router,
                 ncn_reward_receiver,
+                None,
                 max_iterations,
                 epoch,
             )
@@ -1675,6 +3002,7 @@ impl NCNProgramClient {
         ballot_box: Pubkey,
         ncn_reward_router: Pubkey,
         ncn_reward_receiver: Pubkey,
+        cranker: Option<&Keypair>,
         max_iterations: u16,
         epoch: u64,
     ) -> TestResult<()> {
@@ -1682,7 +3010,160 @@ impl NCNProgramClient {
 
         let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
 
-        let ix = RouteNCNRewardsBuilder::new()
+        let mut ix = RouteNCNRewardsBuilder::new();
+        ix.epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .ballot_box(ballot_box)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .max_iterations(max_iterations)
+            .epoch(epoch);
+
+        let mut signers = vec![&self.payer];
+        if let Some(cranker) = cranker {
+            ix.cranker(Some(cranker.pubkey()))
+                .system_program(Some(system_program::id()));
+            signers.push(cranker);
+        }
+
+        let blockhash = self.get_best_latest_blockhash().await?;
+        let tx = &Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix.instruction(),
+            ],
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        self.process_transaction(tx).await
+    }
+
+    /// Routes the rewards held in the NCN reward receiver's token account for a reward mint.
+    pub async fn route_ncn_mint_rewards(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        cranker: Option<&Keypair>,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let epoch_snapshot = EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ballot_box = BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_receiver_token_account =
+            NCNRewardReceiver::find_token_account_address(&ncn_program::id(), &ncn, epoch, &mint);
+
+        let mut ix = RouteNCNRewardsBuilder::new();
+        ix.epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .ballot_box(ballot_box)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .ncn_reward_receiver_token_account(Some(ncn_reward_receiver_token_account))
+            .max_iterations(NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS)
+            .mint(mint)
+            .epoch(epoch);
+
+        let mut signers = vec![&self.payer];
+        if let Some(cranker) = cranker {
+            ix.cranker(Some(cranker.pubkey()))
+                .system_program(Some(system_program::id()));
+            signers.push(cranker);
+        }
+
+        let blockhash = self.get_best_latest_blockhash().await?;
+        let tx = &Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix.instruction(),
+            ],
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        self.process_transaction(tx).await
+    }
+
+    /// Routes the NCN rewards and distributes the Protocol, NCN and given operator routes in one call.
+    pub async fn do_route_and_distribute_ncn_rewards(
+        &mut self,
+        ncn: Pubkey,
+        operators: &[Pubkey],
+        epoch: u64,
+    ) -> TestResult<()> {
+        let ncn_config_account = self.get_ncn_config(ncn).await?;
+        let protocol_fee_wallet = *ncn_config_account.fee_config.protocol_fee_wallet();
+        let ncn_fee_wallet = *ncn_config_account.fee_config.ncn_fee_wallet();
+
+        let operator_route_accounts: Vec<AccountMeta> = operators
+            .iter()
+            .flat_map(|operator| {
+                let operator_vault_reward_router = OperatorVaultRewardRouter::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch,
+                )
+                .0;
+                let operator_vault_reward_receiver =
+                    OperatorVaultRewardReceiver::find_program_address(
+                        &ncn_program::id(),
+                        operator,
+                        &ncn,
+                        epoch,
+                    )
+                    .0;
+
+                [
+                    AccountMeta::new_readonly(*operator, false),
+                    AccountMeta::new_readonly(operator_vault_reward_router, false),
+                    AccountMeta::new(operator_vault_reward_receiver, false),
+                ]
+            })
+            .collect();
+
+        self.route_and_distribute_ncn_rewards(
+            ncn,
+            protocol_fee_wallet,
+            ncn_fee_wallet,
+            &operator_route_accounts,
+            NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS,
+            epoch,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn route_and_distribute_ncn_rewards(
+        &mut self,
+        ncn: Pubkey,
+        protocol_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
+        operator_route_accounts: &[AccountMeta],
+        max_iterations: u16,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let epoch_snapshot = EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ballot_box = BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let ix = RouteAndDistributeNCNRewardsBuilder::new()
             .epoch_state(epoch_state)
             .config(config)
             .ncn(ncn)
@@ -1690,8 +3171,12 @@ impl NCNProgramClient {
             .ballot_box(ballot_box)
             .ncn_reward_router(ncn_reward_router)
             .ncn_reward_receiver(ncn_reward_receiver)
+            .protocol_fee_wallet(protocol_fee_wallet)
+            .ncn_fee_wallet(ncn_fee_wallet)
+            .system_program(system_program::id())
             .max_iterations(max_iterations)
             .epoch(epoch)
+            .add_remaining_accounts(operator_route_accounts)
             .instruction();
 
         let blockhash = self.get_best_latest_blockhash().await?;
@@ -1786,6 +3271,52 @@ impl NCNProgramClient {
         self.process_transaction(&transaction).await
     }
 
+    pub async fn do_distribute_fee_group_rewards(
+        &mut self,
+        ncn: Pubkey,
+        group: u8,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+
+        let (ncn_config, _, _) = NcnConfig::find_program_address(&ncn_program::id(), &ncn);
+
+        let (ncn_reward_router, _, _) =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch);
+
+        let ncn_config_account = self.get_ncn_config(ncn).await?;
+        let fee_group_wallet = *ncn_config_account
+            .fee_config
+            .fee_group_wallet(group as usize)
+            .map_err(ProgramError::from)?;
+
+        let (ncn_reward_receiver, _, _) =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch);
+
+        let ix = DistributeFeeGroupRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .fee_group_wallet(fee_group_wallet)
+            .system_program(system_program::id())
+            .group(group)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&transaction).await
+    }
+
     pub async fn do_distribute_operator_vault_reward_route(
         &mut self,
         operator: Pubkey,
@@ -1897,6 +3428,7 @@ impl NCNProgramClient {
                 operator_snapshot,
                 operator_vault_reward_router,
                 operator_vault_reward_receiver,
+                None,
                 max_iterations,
                 epoch,
             )
@@ -1920,31 +3452,40 @@ impl NCNProgramClient {
         operator_snapshot: Pubkey,
         operator_vault_reward_router: Pubkey,
         operator_vault_reward_receiver: Pubkey,
+        cranker: Option<&Keypair>,
         max_iterations: u16,
         epoch: u64,
     ) -> TestResult<()> {
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
 
-        let ix = RouteOperatorVaultRewardsBuilder::new()
-            .epoch_state(epoch_state)
+        let mut ix = RouteOperatorVaultRewardsBuilder::new();
+        ix.epoch_state(epoch_state)
             .ncn(ncn)
             .operator(operator)
             .operator_snapshot(operator_snapshot)
             .operator_vault_reward_router(operator_vault_reward_router)
             .operator_vault_reward_receiver(operator_vault_reward_receiver)
             .max_iterations(max_iterations)
-            .epoch(epoch)
-            .instruction();
+            .epoch(epoch);
+
+        let mut signers = vec![&self.payer];
+        if let Some(cranker) = cranker {
+            let config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+            ix.config(Some(config))
+                .cranker(Some(cranker.pubkey()))
+                .system_program(Some(system_program::id()));
+            signers.push(cranker);
+        }
 
         let blockhash = self.get_best_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[
                 // TODO: should make this instruction much more efficient
                 ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-                ix,
+                ix.instruction(),
             ],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &signers,
             blockhash,
         ))
         .await
@@ -2028,6 +3569,13 @@ impl NCNProgramClient {
 
         let epoch_state = EpochState::find_program_address(&ncn_program::id(), &ncn, epoch).0;
 
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_reward_recipient = *self
+            .get_vault_registry(ncn)
+            .await?
+            .get_vault_entry(&vault)?
+            .reward_destination();
+
         let ix = DistributeVaultRewardsBuilder::new()
             .epoch_state(epoch_state)
             .config(ncn_config)
@@ -2038,6 +3586,9 @@ impl NCNProgramClient {
             .operator_vault_reward_router(operator_vault_reward_router)
             .operator_vault_reward_receiver(operator_vault_reward_receiver)
             .system_program(system_program::id())
+            .vault_registry(vault_registry)
+            .vault_reward_recipient(vault_reward_recipient)
+            .token_program(spl_token::id())
             .epoch(epoch)
             .instruction();
 
@@ -2052,6 +3603,14 @@ impl NCNProgramClient {
     }
 }
 
+/// Signing remaining accounts for the admin approvers of an admin instruction.
+fn approver_metas(approvers: &[&Keypair]) -> Vec<AccountMeta> {
+    approvers
+        .iter()
+        .map(|approver| AccountMeta::new_readonly(approver.pubkey(), true))
+        .collect()
+}
+
 /// Asserts that a TestResult contains a specific NCNProgramError.
 #[inline(always)]
 #[track_caller]
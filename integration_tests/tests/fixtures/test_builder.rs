@@ -14,7 +14,7 @@ use ncn_program_core::{
 use solana_program::{clock::Clock, native_token::sol_to_lamports, pubkey::Pubkey};
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    account::Account,
+    account::{Account, AccountSharedData},
     clock::DEFAULT_SLOTS_PER_EPOCH,
     epoch_schedule::EpochSchedule,
     native_token::lamports_to_sol,
@@ -63,7 +63,8 @@ impl Debug for TestBuilder {
 
 impl TestBuilder {
     /// Creates a new TestBuilder, initializing the ProgramTest environment.
-    /// It adds the NCN, Vault, and Restaking programs to the test context.
+    /// It adds the NCN, Vault, and Restaking programs and the example consensus consumer to the
+    /// test context.
     pub async fn new() -> Self {
         let run_as_bpf = std::env::vars().any(|(key, _)| key.eq("SBF_OUT_DIR"));
 
@@ -71,6 +72,7 @@ impl TestBuilder {
             let mut program_test = ProgramTest::new("ncn_program", ncn_program::id(), None);
             program_test.add_program("jito_vault_program", jito_vault_program::id(), None);
             program_test.add_program("jito_restaking_program", jito_restaking_program::id(), None);
+            program_test.add_program("ncn_consensus_consumer", ncn_consensus_consumer::id(), None);
 
             program_test
         } else {
@@ -89,6 +91,11 @@ impl TestBuilder {
                 jito_restaking_program::id(),
                 processor!(jito_restaking_program::process_instruction),
             );
+            program_test.add_program(
+                "ncn_consensus_consumer",
+                ncn_consensus_consumer::id(),
+                processor!(ncn_consensus_consumer::process_instruction),
+            );
             program_test
         };
 
@@ -105,6 +112,13 @@ impl TestBuilder {
         self.context.banks_client.get_account(*address).await
     }
 
+    /// Writes an account directly into the test bank, e.g. to stand in for accounts of programs
+    /// that are not loaded.
+    pub fn set_account(&mut self, address: &Pubkey, account: &Account) {
+        self.context
+            .set_account(address, &AccountSharedData::from(account.clone()));
+    }
+
     /// Advances the test clock by a specified number of slots.
     pub async fn warp_slot_incremental(
         &mut self,
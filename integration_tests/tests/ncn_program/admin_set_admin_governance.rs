@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_client::types::ConfigAdminRole;
+    use ncn_program_core::error::NCNProgramError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{
+        account::Account,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    /// Header of a `GovernanceV2` account, the account type followed by the realm
+    fn governance_account(governance_program: &Pubkey, realm: &Pubkey) -> Account {
+        let mut data = vec![18];
+        data.extend_from_slice(realm.as_ref());
+        data.resize(256, 0);
+
+        Account {
+            lamports: 10_000_000,
+            data,
+            owner: *governance_program,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_admin_governance() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        ncn_program_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        // A keypair stands in for the governance PDA signing through an executed proposal
+        let governance_program = Pubkey::new_unique();
+        let realm = Pubkey::new_unique();
+        let governance = Keypair::new();
+        fixture.set_account(
+            &governance.pubkey(),
+            &governance_account(&governance_program, &realm),
+        );
+
+        // Both keys are required
+        let result = ncn_program_client
+            .admin_set_admin_governance(
+                ncn,
+                governance_program,
+                Pubkey::default(),
+                &ncn_root.ncn_admin,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidAdminGovernance, None);
+
+        ncn_program_client
+            .admin_set_admin_governance(ncn, governance_program, realm, &ncn_root.ncn_admin)
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        assert!(config.has_admin_governance());
+        assert_eq!(config.admin_governance_program(), &governance_program);
+        assert_eq!(config.admin_governance_realm(), &realm);
+
+        // The NCN admin no longer approves admin instructions
+        let result = ncn_program_client
+            .do_set_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                Pubkey::new_unique(),
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::NotAdminGovernance, None);
+
+        // Governances of other realms are rejected
+        let other_governance = Keypair::new();
+        fixture.set_account(
+            &other_governance.pubkey(),
+            &governance_account(&governance_program, &Pubkey::new_unique()),
+        );
+        let result = ncn_program_client
+            .admin_set_admin_governance(
+                ncn,
+                Pubkey::default(),
+                Pubkey::default(),
+                &other_governance,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::NotAdminGovernance, None);
+
+        // The governance hands the admin role back
+        ncn_program_client
+            .admin_set_admin_governance(ncn, Pubkey::default(), Pubkey::default(), &governance)
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        assert!(!config.has_admin_governance());
+
+        ncn_program_client
+            .do_set_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                Pubkey::new_unique(),
+                &ncn_root,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
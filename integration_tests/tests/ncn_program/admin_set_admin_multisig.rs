@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_client::types::ConfigAdminRole;
+    use ncn_program_core::{config::Config as NcnConfig, error::NCNProgramError};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_admin_set_admin_multisig() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+
+        ncn_program_client
+            .do_admin_set_admin_multisig(&approver_keys, 2, &ncn_root, &[])
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.admin_threshold(), 2);
+        assert_eq!(
+            config.admin_approvers().copied().collect::<Vec<_>>(),
+            approver_keys
+        );
+
+        // The NCN admin alone can no longer change the config
+        fixture.warp_slot_incremental(1).await?;
+        let result = ncn_program_client
+            .do_set_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                Pubkey::new_unique(),
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let result = ncn_program_client
+            .do_set_parameters(
+                Some(5),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let config_pda =
+            NcnConfig::find_program_address(&ncn_program::id(), &ncn_root.ncn_pubkey).0;
+
+        // One approver is not enough
+        let result = ncn_program_client
+            .set_new_admin(
+                config_pda,
+                ConfigAdminRole::TieBreakerAdmin,
+                Pubkey::new_unique(),
+                &ncn_root,
+                &[&approvers[0]],
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let new_tie_breaker = Pubkey::new_unique();
+        ncn_program_client
+            .set_new_admin(
+                config_pda,
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker,
+                &ncn_root,
+                &[&approvers[0], &approvers[2]],
+            )
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_admin_multisig_remove() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let approvers = [Keypair::new(), Keypair::new()];
+        let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+
+        ncn_program_client
+            .do_admin_set_admin_multisig(&approver_keys, 2, &ncn_root, &[])
+            .await?;
+
+        // Removing the multisig needs the approval of the multisig
+        fixture.warp_slot_incremental(1).await?;
+        let result = ncn_program_client
+            .do_admin_set_admin_multisig(&[], 0, &ncn_root, &[])
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        ncn_program_client
+            .do_admin_set_admin_multisig(&[], 0, &ncn_root, &[&approvers[0], &approvers[1]])
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert!(!config.has_admin_multisig());
+
+        // The NCN admin approves alone again
+        let new_tie_breaker = Pubkey::new_unique();
+        ncn_program_client
+            .do_set_new_admin(ConfigAdminRole::TieBreakerAdmin, new_tie_breaker, &ncn_root)
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_admin_multisig_invalid_threshold_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let result = ncn_program_client
+            .do_admin_set_admin_multisig(&[Pubkey::new_unique()], 2, &ncn_root, &[])
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidAdminMultisig, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        constants::MAX_FEE_GROUPS, error::NCNProgramError, fees::FeeGroup,
+        ncn_reward_router::NCNRewardReceiver,
+    };
+    use solana_program::native_token::lamports_to_sol;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestError,
+        TestResult,
+    };
+
+    const REWARD_AMOUNT: u64 = 1_000_000_000;
+    const FEE_GROUP_FEE_BPS: u16 = 150;
+
+    fn fee_group_name(name: &str) -> [u8; 32] {
+        let mut padded_name = [0; FeeGroup::NAME_LEN];
+        padded_name[..name.len()].copy_from_slice(name.as_bytes());
+        padded_name
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_fee_group() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+        let fee_wallet = Pubkey::new_unique();
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_admin_set_fee_group(
+                ncn,
+                &ncn_root.ncn_admin,
+                2,
+                fee_group_name("Insurance"),
+                fee_wallet,
+                Some(FEE_GROUP_FEE_BPS),
+            )
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        let fee_group = config.fee_config.fee_group(2).unwrap();
+        assert_eq!(fee_group.name_str(), "Insurance");
+        assert_eq!(*fee_group.wallet(), fee_wallet);
+
+        // The fee applies from the next epoch on
+        assert_eq!(config.fee_config.fee_group_bps(2, epoch).unwrap(), 0);
+        assert_eq!(
+            config.fee_config.fee_group_bps(2, epoch + 1).unwrap(),
+            FEE_GROUP_FEE_BPS
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_fee_group_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+
+        let result = ncn_program_client
+            .do_admin_set_fee_group(
+                ncn,
+                &ncn_root.ncn_admin,
+                MAX_FEE_GROUPS as u8,
+                fee_group_name("Insurance"),
+                Pubkey::new_unique(),
+                None,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidFeeGroup, None);
+
+        let result = ncn_program_client
+            .do_admin_set_fee_group(
+                ncn,
+                &ncn_root.ncn_admin,
+                0,
+                fee_group_name("Insurance"),
+                Pubkey::default(),
+                None,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::DefaultFeeGroupWallet, None);
+
+        // Only the NCN admin can set fee groups
+        let result = ncn_program_client
+            .do_admin_set_fee_group(
+                ncn,
+                &Keypair::new(),
+                0,
+                fee_group_name("Insurance"),
+                Pubkey::new_unique(),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(TestError::BanksClientError(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_distribute_fee_group_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let fee_wallet = Keypair::new().pubkey();
+        ncn_program_client.airdrop(&fee_wallet, 1.0).await?;
+
+        ncn_program_client
+            .do_admin_set_fee_group(
+                ncn,
+                &test_ncn.ncn_root.ncn_admin,
+                1,
+                fee_group_name("Insurance"),
+                fee_wallet,
+                Some(FEE_GROUP_FEE_BPS),
+            )
+            .await?;
+
+        // The fee is picked up by the epochs snapshotted after it activated
+        fixture.warp_epoch_incremental(1).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, lamports_to_sol(REWARD_AMOUNT))
+            .await?;
+        ncn_program_client.do_route_ncn_rewards(ncn, epoch).await?;
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        let rewards = ncn_reward_router.fee_group_rewards(1).unwrap();
+        assert!(rewards > 0);
+        assert_eq!(
+            rewards,
+            ncn_reward_router.total_rewards() * FEE_GROUP_FEE_BPS as u64 / 10_000
+        );
+        assert_eq!(ncn_reward_router.fee_group_rewards(0).unwrap(), 0);
+
+        let balance_before = fixture.get_account(&fee_wallet).await?.unwrap().lamports;
+
+        ncn_program_client
+            .do_distribute_fee_group_rewards(ncn, 1, epoch)
+            .await?;
+
+        let balance_after = fixture.get_account(&fee_wallet).await?.unwrap().lamports;
+        assert_eq!(balance_after, balance_before + rewards);
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        assert_eq!(ncn_reward_router.fee_group_rewards(1).unwrap(), 0);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use ncn_program_core::error::NCNProgramError;
+    use ncn_program_core::{
+        constants::{
+            MAX_CRANKER_FEE_BPS, MAX_FEE_BPS, MAX_TIMELINESS_BONUS_BPS, MAX_WEIGHT_DECAY_BPS,
+        },
+        error::NCNProgramError,
+    };
 
     use crate::fixtures::{
         ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
@@ -22,6 +27,14 @@ mod tests {
                 Some(5),    // epochs_before_stall
                 Some(10),   // epochs_after_consensus_before_close
                 Some(1000), // valid_slots_after_consensus
+                Some(50),   // cranker_fee_bps
+                Some(20),   // timeliness_bonus_slots
+                Some(500),  // timeliness_bonus_bps
+                Some(3),    // weight_decay_epochs
+                Some(2000), // weight_decay_bps
+                Some(3000), // max_vault_stake_weight_bps
+                None,
+                None,
                 &ncn_root,
             )
             .await?;
@@ -33,6 +46,12 @@ mod tests {
         assert_eq!(config.epochs_before_stall(), 5);
         assert_eq!(config.epochs_after_consensus_before_close(), 10);
         assert_eq!(config.valid_slots_after_consensus(), 1000);
+        assert_eq!(config.cranker_fee_bps(), 50);
+        assert_eq!(config.timeliness_bonus_slots(), 20);
+        assert_eq!(config.timeliness_bonus_bps(), 500);
+        assert_eq!(config.weight_decay_epochs(), 3);
+        assert_eq!(config.weight_decay_bps(), 2000);
+        assert_eq!(config.max_vault_stake_weight_bps(), 3000);
 
         // Test invalid epochs_before_stall
         let result = ncn_program_client
@@ -41,6 +60,14 @@ mod tests {
                 Some(0), // Invalid - too low
                 None,
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
@@ -53,6 +80,14 @@ mod tests {
                 None,
                 Some(0), // Invalid - too low
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
@@ -65,11 +100,99 @@ mod tests {
                 None,
                 None,
                 Some(99), // Invalid - too low
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
         assert_ncn_program_error(result, NCNProgramError::InvalidSlotsAfterConsensus, None);
 
+        // Test invalid cranker_fee_bps
+        let result = ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_CRANKER_FEE_BPS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidCrankerFeeBps, None);
+
+        // Test invalid timeliness_bonus_bps
+        let result = ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_TIMELINESS_BONUS_BPS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidTimelinessBonusBps, None);
+
+        // Test invalid weight_decay_bps
+        let result = ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_WEIGHT_DECAY_BPS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidWeightDecayBps, None);
+
+        // Test invalid max_vault_stake_weight_bps
+        let result = ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_FEE_BPS as u16 + 1), // Invalid - too high
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidMaxVaultStakeWeightBps, None);
+
         Ok(())
     }
 }
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::error::NCNProgramError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestError,
+        TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_admin_set_reward_mint() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+        let mint = Pubkey::new_unique();
+
+        ncn_program_client
+            .do_admin_set_reward_mint(ncn, &ncn_root.ncn_admin, 1, mint)
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.reward_mints()[1], mint);
+        assert!(config.is_reward_mint(&mint));
+
+        // Setting the default pubkey clears the slot
+        ncn_program_client
+            .do_admin_set_reward_mint(ncn, &ncn_root.ncn_admin, 1, Pubkey::default())
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        assert!(!config.is_reward_mint(&mint));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_reward_mint_duplicate_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+        let mint = Pubkey::new_unique();
+
+        ncn_program_client
+            .do_admin_set_reward_mint(ncn, &ncn_root.ncn_admin, 0, mint)
+            .await?;
+
+        let result = ncn_program_client
+            .do_admin_set_reward_mint(ncn, &ncn_root.ncn_admin, 1, mint)
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidRewardMint, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_reward_mint_invalid_index_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn_root = &test_ncn.ncn_root;
+
+        let result = ncn_program_client
+            .do_admin_set_reward_mint(
+                ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                u8::MAX,
+                Pubkey::new_unique(),
+            )
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidRewardMintIndex, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_reward_mint_not_ncn_admin_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let result = ncn_program_client
+            .do_admin_set_reward_mint(
+                test_ncn.ncn_root.ncn_pubkey,
+                &Keypair::new(),
+                0,
+                Pubkey::new_unique(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(TestError::BanksClientError(_))));
+
+        Ok(())
+    }
+}
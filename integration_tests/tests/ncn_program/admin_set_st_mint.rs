@@ -1,9 +1,16 @@
 #[cfg(test)]
 mod tests {
 
-    use ncn_program_core::constants::WEIGHT;
+    use ncn_program_core::{
+        config::Config as NcnConfig, constants::WEIGHT, error::NCNProgramError,
+        vault_registry::VaultRegistry,
+    };
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
 
     #[tokio::test]
     async fn test_admin_set_st_mint() -> TestResult<()> {
@@ -47,4 +54,106 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_vault_registry_admin_needs_admin_multisig_threshold() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let mut vault_client = fixture.vault_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        // The NCN admin of the test NCN is the payer, which is also its NCN program admin
+        let admin = test_ncn.ncn_root.ncn_admin.pubkey();
+        let st_mint = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?
+            .supported_mint;
+
+        let approvers = [Keypair::new(), Keypair::new()];
+        let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+
+        ncn_program_client
+            .do_admin_set_admin_multisig(&approver_keys, 2, &test_ncn.ncn_root, &[])
+            .await?;
+
+        // The NCN program admin alone can no longer change the vault registry
+        let result = ncn_program_client
+            .do_admin_set_st_mint(ncn, st_mint, WEIGHT + 1)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let result = ncn_program_client
+            .do_admin_set_default_weight(ncn, WEIGHT)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let new_st_mint = Keypair::new();
+        vault_client
+            .create_token_mint(&new_st_mint, &spl_token::id())
+            .await?;
+
+        let result = ncn_program_client
+            .admin_register_st_mint(
+                ncn,
+                ncn_config,
+                vault_registry,
+                admin,
+                new_st_mint.pubkey(),
+                WEIGHT,
+                &[&approvers[0]],
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        // The threshold of approvers is enough
+        ncn_program_client
+            .admin_set_st_mint(
+                ncn,
+                ncn_config,
+                vault_registry,
+                admin,
+                st_mint,
+                Some(WEIGHT + 1),
+                None,
+                &[&approvers[0], &approvers[1]],
+            )
+            .await?;
+
+        ncn_program_client
+            .admin_set_default_weight(
+                ncn,
+                ncn_config,
+                vault_registry,
+                admin,
+                WEIGHT,
+                &[&approvers[0], &approvers[1]],
+            )
+            .await?;
+
+        ncn_program_client
+            .admin_register_st_mint(
+                ncn,
+                ncn_config,
+                vault_registry,
+                admin,
+                new_st_mint.pubkey(),
+                WEIGHT,
+                &[&approvers[0], &approvers[1]],
+            )
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        assert_eq!(
+            vault_registry.get_mint_entry(&st_mint).unwrap().weight(),
+            WEIGHT + 1
+        );
+        assert_eq!(vault_registry.default_weight(), WEIGHT);
+        assert!(vault_registry.get_mint_entry(&new_st_mint.pubkey()).is_ok());
+
+        Ok(())
+    }
 }
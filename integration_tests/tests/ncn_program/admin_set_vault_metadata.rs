@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        config::Config as NcnConfig,
+        error::NCNProgramError,
+        vault_registry::{VaultEntry, VaultRegistry},
+    };
+    use solana_program::{hash::hash, pubkey::Pubkey};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    fn vault_name(name: &str) -> [u8; VaultEntry::NAME_LEN] {
+        let mut padded_name = [0; VaultEntry::NAME_LEN];
+        padded_name[..name.len()].copy_from_slice(name.as_bytes());
+        padded_name
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_metadata() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+        let uri_hash = hash(b"https://example.com/vault.json").to_bytes();
+
+        ncn_program_client
+            .do_admin_set_vault_metadata(ncn, vault, vault_name("Test Vault"), uri_hash)
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let vault_entry = vault_registry.get_vault_entry(&vault)?;
+        assert_eq!(vault_entry.name_str(), "Test Vault");
+        assert_eq!(vault_entry.uri_hash(), &uri_hash);
+
+        // Zeroed metadata clears it
+        ncn_program_client
+            .do_admin_set_vault_metadata(
+                ncn,
+                vault,
+                [0; VaultEntry::NAME_LEN],
+                [0; VaultEntry::URI_HASH_LEN],
+            )
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let vault_entry = vault_registry.get_vault_entry(&vault)?;
+        assert_eq!(vault_entry.name_str(), "");
+        assert!(!vault_entry.has_uri_hash());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_metadata_invalid_name_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let mut name = vault_name("Test Vault");
+        name[0] = 0xff;
+
+        let result = ncn_program_client
+            .do_admin_set_vault_metadata(ncn, vault, name, [0; VaultEntry::URI_HASH_LEN])
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidVaultName, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_metadata_not_admin_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let result = ncn_program_client
+            .admin_set_vault_metadata(
+                ncn,
+                ncn_config,
+                vault_registry,
+                vault,
+                &Keypair::new(),
+                vault_name("Test Vault"),
+                [0; VaultEntry::URI_HASH_LEN],
+                &[],
+            )
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::IncorrectNcnAdmin, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_metadata_needs_admin_multisig_threshold() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let ncn_config = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+        let vault_registry = VaultRegistry::find_program_address(&ncn_program::id(), &ncn).0;
+        // The NCN admin of the test NCN is also its NCN program admin
+        let admin = test_ncn.ncn_root.ncn_admin.insecure_clone();
+
+        let approvers = [Keypair::new(), Keypair::new()];
+        let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+
+        ncn_program_client
+            .do_admin_set_admin_multisig(&approver_keys, 2, &test_ncn.ncn_root, &[])
+            .await?;
+
+        // The NCN program admin signing alone is no longer enough
+        let result = ncn_program_client
+            .do_admin_set_vault_metadata(
+                ncn,
+                vault,
+                vault_name("Test Vault"),
+                [0; VaultEntry::URI_HASH_LEN],
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        let result = ncn_program_client
+            .admin_set_vault_metadata(
+                ncn,
+                ncn_config,
+                vault_registry,
+                vault,
+                &admin,
+                vault_name("Test Vault"),
+                [0; VaultEntry::URI_HASH_LEN],
+                &[&approvers[0]],
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::AdminThresholdNotMet, None);
+
+        // The threshold of approvers is enough
+        ncn_program_client
+            .admin_set_vault_metadata(
+                ncn,
+                ncn_config,
+                vault_registry,
+                vault,
+                &admin,
+                vault_name("Test Vault"),
+                [0; VaultEntry::URI_HASH_LEN],
+                &[&approvers[0], &approvers[1]],
+            )
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let vault_entry = vault_registry.get_vault_entry(&vault)?;
+        assert_eq!(vault_entry.name_str(), "Test Vault");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::error::NCNProgramError;
+    use solana_program::program_pack::Pack;
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+    use spl_token::state::Account as TokenAccount;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestError,
+        TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_admin_set_vault_reward_recipient() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        const OPERATOR_COUNT: usize = 1;
+        const VAULT_COUNT: usize = 1;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, VAULT_COUNT, None)
+            .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault_root = &test_ncn.vaults[0];
+        let vault = vault_root.vault_pubkey;
+        let reward_recipient = Pubkey::new_unique();
+
+        ncn_program_client
+            .do_admin_set_vault_reward_recipient(
+                ncn,
+                vault,
+                &vault_root.vault_admin,
+                reward_recipient,
+            )
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let vault_entry = vault_registry.get_vault_entry(&vault)?;
+        assert_eq!(*vault_entry.reward_recipient(), reward_recipient);
+        assert_eq!(*vault_entry.reward_destination(), reward_recipient);
+
+        // Passing the vault resets the recipient
+        ncn_program_client
+            .do_admin_set_vault_reward_recipient(ncn, vault, &vault_root.vault_admin, vault)
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let vault_entry = vault_registry.get_vault_entry(&vault)?;
+        assert!(!vault_entry.has_reward_recipient());
+        assert_eq!(*vault_entry.reward_destination(), vault);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_reward_recipient_not_vault_admin_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let result = ncn_program_client
+            .do_admin_set_vault_reward_recipient(ncn, vault, &Keypair::new(), Pubkey::new_unique())
+            .await;
+
+        assert!(matches!(result, Err(TestError::BanksClientError(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_vault_reward_recipient_non_native_token_account_fails() -> TestResult<()>
+    {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let mut vault_client = fixture.vault_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault_root = &test_ncn.vaults[0];
+        let vault = vault_root.vault_pubkey;
+        let st_mint = vault_client.get_vault(&vault).await?.supported_mint;
+
+        let owner = Pubkey::new_unique();
+        vault_client.create_ata(&st_mint, &owner).await?;
+        let st_mint_ata = get_associated_token_address(&owner, &st_mint);
+
+        let result = ncn_program_client
+            .do_admin_set_vault_reward_recipient(ncn, vault, &vault_root.vault_admin, st_mint_ata)
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidVaultRewardRecipient, None);
+
+        // Token accounts of a reward mint neither, the wallet owning them is the recipient
+        let reward_mint = Keypair::new();
+        vault_client
+            .create_token_mint(&reward_mint, &spl_token::id())
+            .await?;
+        ncn_program_client
+            .do_admin_set_reward_mint(ncn, &test_ncn.ncn_root.ncn_admin, 0, reward_mint.pubkey())
+            .await?;
+
+        vault_client
+            .create_ata(&reward_mint.pubkey(), &owner)
+            .await?;
+        let reward_mint_ata = get_associated_token_address(&owner, &reward_mint.pubkey());
+
+        let result = ncn_program_client
+            .do_admin_set_vault_reward_recipient(
+                ncn,
+                vault,
+                &vault_root.vault_admin,
+                reward_mint_ata,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidVaultRewardRecipient, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vault_rewards_sent_to_recipient() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault_root = &test_ncn.vaults[0];
+        let vault = vault_root.vault_pubkey;
+
+        // The recipient needs to be rent exempt before it can receive small amounts
+        let reward_recipient = Pubkey::new_unique();
+        ncn_program_client.airdrop(&reward_recipient, 1.0).await?;
+
+        ncn_program_client
+            .do_admin_set_vault_reward_recipient(
+                ncn,
+                vault,
+                &vault_root.vault_admin,
+                reward_recipient,
+            )
+            .await?;
+
+        let vault_lamports_before = fixture.get_account(&vault).await?.unwrap().lamports;
+        let recipient_lamports_before = fixture
+            .get_account(&reward_recipient)
+            .await?
+            .unwrap()
+            .lamports;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 1_000_000).await?;
+
+        let vault_lamports_after = fixture.get_account(&vault).await?.unwrap().lamports;
+        let recipient_lamports_after = fixture
+            .get_account(&reward_recipient)
+            .await?
+            .unwrap()
+            .lamports;
+
+        assert_eq!(vault_lamports_after, vault_lamports_before);
+        assert!(recipient_lamports_after > recipient_lamports_before);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vault_rewards_sent_to_wrapped_sol_ata() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let mut vault_client = fixture.vault_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault_root = &test_ncn.vaults[0];
+        let vault = vault_root.vault_pubkey;
+
+        let native_mint = spl_token::native_mint::id();
+        vault_client.create_ata(&native_mint, &vault).await?;
+        let wsol_ata = get_associated_token_address(&vault, &native_mint);
+
+        ncn_program_client
+            .do_admin_set_vault_reward_recipient(ncn, vault, &vault_root.vault_admin, wsol_ata)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 1_000_000).await?;
+
+        // Lamports are synced into the token balance
+        let wsol_account = fixture.get_account(&wsol_ata).await?.unwrap();
+        let token_account = TokenAccount::unpack(&wsol_account.data).unwrap();
+        assert!(token_account.amount > 0);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 #[cfg(test)]
 mod tests {
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use ncn_program_client::types::StMintWeight;
+    use ncn_program_core::error::NCNProgramError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
 
     #[tokio::test]
     async fn test_admin_update_weight_table() -> TestResult<()> {
@@ -36,4 +42,99 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_admin_set_weights() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let mut weights = vec![];
+        for (i, vault_root) in test_ncn.vaults.iter().enumerate() {
+            let vault = vault_client.get_vault(&vault_root.vault_pubkey).await?;
+            weights.push(StMintWeight {
+                st_mint: vault.supported_mint,
+                weight: 100 * (i as u128 + 1),
+            });
+        }
+
+        ncn_program_client
+            .do_admin_set_weights(ncn, epoch, weights.clone())
+            .await?;
+
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+        for st_mint_weight in weights.iter() {
+            assert_eq!(
+                weight_table.get_weight(&st_mint_weight.st_mint).unwrap(),
+                st_mint_weight.weight
+            );
+        }
+        assert!(weight_table.finalized());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_weights_unknown_mint_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+
+        let result = ncn_program_client
+            .do_admin_set_weights(
+                ncn,
+                epoch,
+                vec![
+                    StMintWeight {
+                        st_mint: vault.supported_mint,
+                        weight: 100,
+                    },
+                    StMintWeight {
+                        st_mint: Pubkey::new_unique(),
+                        weight: 100,
+                    },
+                ],
+            )
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidMintForWeightTable, None);
+
+        // The whole batch is rejected
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(weight_table.get_weight(&vault.supported_mint).unwrap(), 0);
+
+        Ok(())
+    }
 }
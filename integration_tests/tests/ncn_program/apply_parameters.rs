@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_core::error::NCNProgramError;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    const TIMELOCK_SLOTS: u64 = 100;
+
+    #[tokio::test]
+    async fn test_apply_parameters_after_timelock() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        // Without a timelock the timelock itself applies right away
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(TIMELOCK_SLOTS),
+                &ncn_root,
+            )
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.parameters_timelock_slots(), TIMELOCK_SLOTS);
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                Some(5), // epochs_before_stall
+                None,
+                None,
+                Some(50), // cranker_fee_bps
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        // The changes are only queued
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert!(config.pending_parameters().is_pending());
+        assert_ne!(u64::from(config.epochs_before_stall), 5);
+        assert_eq!(config.cranker_fee_bps(), 0);
+
+        let result = ncn_program_client
+            .do_apply_parameters(ncn_root.ncn_pubkey)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::ParametersTimelockNotElapsed, None);
+
+        fixture.warp_slot_incremental(TIMELOCK_SLOTS).await?;
+
+        ncn_program_client
+            .do_apply_parameters(ncn_root.ncn_pubkey)
+            .await?;
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert!(!config.pending_parameters().is_pending());
+        assert_eq!(u64::from(config.epochs_before_stall), 5);
+        assert_eq!(config.cranker_fee_bps(), 50);
+
+        // The changes can only be applied once
+        let result = ncn_program_client
+            .do_apply_parameters(ncn_root.ncn_pubkey)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::NoPendingParameters, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_parameters() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(TIMELOCK_SLOTS),
+                &ncn_root,
+            )
+            .await?;
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(50), // cranker_fee_bps
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        // Setting no parameters cancels the queued changes
+        ncn_program_client
+            .do_set_parameters(
+                None, None, None, None, None, None, None, None, None, None, None, None, &ncn_root,
+            )
+            .await?;
+
+        fixture.warp_slot_incremental(TIMELOCK_SLOTS).await?;
+
+        let result = ncn_program_client
+            .do_apply_parameters(ncn_root.ncn_pubkey)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::NoPendingParameters, None);
+
+        let config = ncn_program_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.cranker_fee_bps(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_parameters_timelock() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let result = ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(u64::MAX),
+                &ncn_root,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidParametersTimelock, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{epoch_snapshot::OperatorSnapshot, error::NCNProgramError};
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_batch_initialize_operator_snapshot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        const OPERATOR_COUNT: usize = 3;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, 1, None)
+            .await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operators: Vec<Pubkey> = test_ncn
+            .operators
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        ncn_program_client
+            .do_batch_initialize_operator_snapshot(&operators, ncn, epoch)
+            .await?;
+
+        for operator in operators.iter() {
+            let address =
+                OperatorSnapshot::find_program_address(&ncn_program::id(), operator, &ncn, epoch).0;
+            let raw_account = fixture.get_account(&address).await?.unwrap();
+            assert_eq!(raw_account.owner, ncn_program::id());
+
+            let operator_snapshot = ncn_program_client
+                .get_operator_snapshot(*operator, ncn, epoch)
+                .await?;
+            assert_eq!(operator_snapshot.operator(), operator);
+            assert_eq!(*operator_snapshot.ncn(), ncn);
+        }
+
+        // The snapshots behave like individually initialized ones
+        fixture
+            .add_vault_operator_delegation_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+
+        let epoch_snapshot = ncn_program_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert!(epoch_snapshot.finalized());
+        assert_eq!(epoch_snapshot.operators_registered(), OPERATOR_COUNT as u64);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_initialize_operator_snapshot_operator_not_in_snapshot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let mut test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+
+        // Add an operator after the epoch snapshot
+        fixture
+            .add_operators_to_test_ncn(&mut test_ncn, 1, None)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operators = [
+            test_ncn.operators[0].operator_pubkey,
+            test_ncn.operators[1].operator_pubkey,
+        ];
+
+        let result = ncn_program_client
+            .do_batch_initialize_operator_snapshot(&operators, ncn, epoch)
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::OperatorIsNotInSnapshot, Some(1));
+
+        // Nothing was created
+        let address =
+            OperatorSnapshot::find_program_address(&ncn_program::id(), &operators[0], &ncn, epoch)
+                .0;
+        assert!(fixture.get_account(&address).await?.is_none());
+
+        Ok(())
+    }
+}
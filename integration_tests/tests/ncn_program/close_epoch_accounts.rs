@@ -2,6 +2,7 @@
 mod tests {
 
     use ncn_program_core::ballot_box::BallotBox;
+    use ncn_program_core::epoch_marker::EpochMarker;
     use ncn_program_core::epoch_snapshot::{EpochSnapshot, OperatorSnapshot};
     use ncn_program_core::ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter};
     use ncn_program_core::operator_vault_reward_router::{
@@ -10,6 +11,8 @@ mod tests {
     use ncn_program_core::weight_table::WeightTable;
     use ncn_program_core::{epoch_state::EpochState, error::NCNProgramError};
 
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
     use crate::fixtures::TestResult;
     use crate::fixtures::{
         ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder,
@@ -761,4 +764,146 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn close_all_epoch_accounts_in_one_transaction() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        const OPERATOR_COUNT: usize = 2;
+        const VAULT_COUNT: usize = 1;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, VAULT_COUNT, None)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch_to_close = fixture.clock().await.epoch;
+        let operators: Vec<Pubkey> = test_ncn
+            .operators
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        // Warp to epoch to close
+        {
+            let config: ncn_program_core::config::Config =
+                fixture.ncn_program_client().get_ncn_config(ncn).await?;
+            let epochs_after_consensus_before_close = config.epochs_after_consensus_before_close();
+
+            fixture
+                .warp_epoch_incremental(epochs_after_consensus_before_close + 1)
+                .await?;
+        }
+
+        ncn_program_client
+            .do_close_all_epoch_accounts(ncn, &operators, epoch_to_close)
+            .await?;
+
+        let mut closed_accounts = vec![
+            EpochState::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+            WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+            EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+            BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch_to_close).0,
+        ];
+        for operator in operators.iter() {
+            closed_accounts.push(
+                OperatorSnapshot::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch_to_close,
+                )
+                .0,
+            );
+            closed_accounts.push(
+                OperatorVaultRewardRouter::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch_to_close,
+                )
+                .0,
+            );
+            closed_accounts.push(
+                OperatorVaultRewardReceiver::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch_to_close,
+                )
+                .0,
+            );
+        }
+
+        for closed_account in closed_accounts.iter() {
+            let result = fixture.get_account(closed_account).await?;
+            assert!(result.is_none());
+        }
+
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&ncn_program::id(), &ncn, epoch_to_close);
+        let result = fixture.get_account(&epoch_marker).await?;
+        assert!(result.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_all_epoch_accounts_epoch_state_first_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch_to_close = fixture.clock().await.epoch;
+
+        // Warp to epoch to close
+        {
+            let config: ncn_program_core::config::Config =
+                fixture.ncn_program_client().get_ncn_config(ncn).await?;
+            let epochs_after_consensus_before_close = config.epochs_after_consensus_before_close();
+
+            fixture
+                .warp_epoch_incremental(epochs_after_consensus_before_close + 1)
+                .await?;
+        }
+
+        let (epoch_state, _, _) =
+            EpochState::find_program_address(&ncn_program::id(), &ncn, epoch_to_close);
+        let (weight_table, _, _) =
+            WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch_to_close);
+
+        let result = ncn_program_client
+            .close_all_epoch_accounts(
+                ncn,
+                &[
+                    AccountMeta::new(epoch_state, false),
+                    AccountMeta::new(weight_table, false),
+                ],
+                epoch_to_close,
+            )
+            .await;
+
+        assert_ncn_program_error(
+            result,
+            NCNProgramError::CannotCloseEpochStateAccount,
+            Some(1),
+        );
+
+        // The whole transaction is rolled back
+        let result = fixture.get_account(&weight_table).await?;
+        assert!(result.is_some());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        error::NCNProgramError, ncn_reward_router::NCNRewardReceiver,
+        operator_vault_reward_router::OperatorVaultRewardReceiver,
+    };
+    use solana_program::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn close_reward_receivers_of_closed_epoch() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        // Rewards sent after the routers are closed
+        let (ncn_reward_receiver, _, _) =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch);
+        let (operator_vault_reward_receiver, _, _) =
+            OperatorVaultRewardReceiver::find_program_address(
+                &ncn_program::id(),
+                &operator,
+                &ncn,
+                epoch,
+            );
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, 0.25)
+            .await?;
+        ncn_program_client
+            .airdrop(&operator_vault_reward_receiver, 0.25)
+            .await?;
+
+        let ncn_fee_wallet = *ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .fee_config
+            .ncn_fee_wallet();
+        let ncn_fee_wallet_balance_before = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        let receiver_lamports = fixture
+            .get_account(&ncn_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports
+            + fixture
+                .get_account(&operator_vault_reward_receiver)
+                .await?
+                .unwrap()
+                .lamports;
+
+        ncn_program_client
+            .do_close_reward_receiver(ncn, None, epoch)
+            .await?;
+        ncn_program_client
+            .do_close_reward_receiver(ncn, Some(operator), epoch)
+            .await?;
+
+        assert!(fixture.get_account(&ncn_reward_receiver).await?.is_none());
+        assert!(fixture
+            .get_account(&operator_vault_reward_receiver)
+            .await?
+            .is_none());
+
+        // Every lamport of the receivers, rent included, goes to the NCN fee wallet, which does
+        // not pay for the transactions
+        let ncn_fee_wallet_balance_after = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            ncn_fee_wallet_balance_after - ncn_fee_wallet_balance_before,
+            receiver_lamports
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_empty_reward_receiver() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        let ncn_fee_wallet = *ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .fee_config
+            .ncn_fee_wallet();
+        let ncn_fee_wallet_balance_before = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+
+        // Nothing arrived after the router was closed
+        ncn_program_client
+            .do_close_reward_receiver(ncn, None, epoch)
+            .await?;
+
+        let ncn_fee_wallet_balance_after = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(ncn_fee_wallet_balance_after, ncn_fee_wallet_balance_before);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cannot_close_reward_receiver_to_another_wallet() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        let (ncn_reward_receiver, _, _) =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch);
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, 0.25)
+            .await?;
+
+        let result = ncn_program_client
+            .close_reward_receiver(ncn, None, epoch, Pubkey::new_unique())
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidNCNFeeWallet, None);
+
+        // The lamports stay in the receiver
+        assert!(fixture.get_account(&ncn_reward_receiver).await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cannot_close_reward_receiver_of_open_router() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let result = ncn_program_client
+            .do_close_reward_receiver(ncn, None, epoch)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::RewardRouterNotClosed, None);
+
+        let result = ncn_program_client
+            .do_close_reward_receiver(ncn, Some(operator), epoch)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::RewardRouterNotClosed, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use ncn_consensus_consumer::ConsensusConsumerError;
+    use ncn_program_core::{ballot_box::WeatherStatus, error::NCNProgramError};
+    use solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError,
+    };
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_consumer_gated_on_consensus_result() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// NCNProgram Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let weather_status = WeatherStatus::Rainy as u8;
+
+        ncn_program_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        // No outcome before the operators vote
+        let result = ncn_program_client
+            .do_act_on_consensus(ncn, epoch, weather_status)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::ConsensusNotReached, None);
+
+        ncn_program_client
+            .do_cast_vote(ncn, operator, operator_admin, weather_status, epoch)
+            .await?;
+
+        ncn_program_client
+            .do_act_on_consensus(ncn, epoch, weather_status)
+            .await?;
+
+        // Gated on the winning weather status
+        let result = ncn_program_client
+            .do_act_on_consensus(ncn, epoch, WeatherStatus::Sunny as u8)
+            .await;
+        assert_eq!(
+            result.err().unwrap().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(ConsensusConsumerError::UnexpectedWeatherStatus as u32)
+            )
+        );
+
+        // The consensus result is looked up at the PDA of the NCN passed in
+        let result = ncn_program_client
+            .do_act_on_consensus(Pubkey::new_unique(), epoch, weather_status)
+            .await;
+        assert_eq!(
+            result.err().unwrap().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        );
+
+        Ok(())
+    }
+}
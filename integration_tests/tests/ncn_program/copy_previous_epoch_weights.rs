@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::error::NCNProgramError;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_copy_previous_epoch_weights() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let previous_epoch = fixture.clock().await.epoch;
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, previous_epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, previous_epoch)
+            .await?;
+        ncn_program_client
+            .do_set_epoch_weights(ncn, previous_epoch)
+            .await?;
+
+        fixture.warp_epoch_incremental(1).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        assert_eq!(epoch, previous_epoch + 1);
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_copy_previous_epoch_weights(ncn, epoch)
+            .await?;
+
+        let previous_weight_table = ncn_program_client
+            .get_weight_table(ncn, previous_epoch)
+            .await?;
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+        assert!(weight_table.finalized());
+
+        for st_mint in previous_weight_table.get_mints() {
+            assert_eq!(
+                weight_table.get_weight(&st_mint).unwrap(),
+                previous_weight_table.get_weight(&st_mint).unwrap()
+            );
+        }
+
+        let epoch_state = ncn_program_client.get_epoch_state(ncn, epoch).await?;
+        assert!(epoch_state.set_weight_progress().is_complete());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_previous_epoch_weights_registry_changed_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let previous_epoch = fixture.clock().await.epoch;
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, previous_epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, previous_epoch)
+            .await?;
+        ncn_program_client
+            .do_set_epoch_weights(ncn, previous_epoch)
+            .await?;
+
+        // The admin changes the weight of the mint between the epochs
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        ncn_program_client
+            .do_admin_set_st_mint(ncn, vault.supported_mint, 2)
+            .await?;
+
+        fixture.warp_epoch_incremental(1).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let result = ncn_program_client
+            .do_copy_previous_epoch_weights(ncn, epoch)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::VaultRegistryChanged, None);
+
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+        assert!(!weight_table.finalized());
+
+        Ok(())
+    }
+}
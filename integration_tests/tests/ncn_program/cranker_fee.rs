@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        ballot_box::BallotBox,
+        epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+        ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+        operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
+    };
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    const CRANKER_FEE_BPS: u16 = 100;
+
+    async fn lamports(fixture: &mut TestBuilder, address: &Pubkey) -> TestResult<u64> {
+        Ok(fixture
+            .get_account(address)
+            .await?
+            .map(|account| account.lamports)
+            .unwrap_or(0))
+    }
+
+    #[tokio::test]
+    async fn test_cranker_fee_is_paid_for_routing() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(CRANKER_FEE_BPS),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let cranker = Keypair::new();
+        ncn_program_client.airdrop(&cranker.pubkey(), 1.0).await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, 1.0)
+            .await?;
+
+        // NCN reward router
+        let cranker_lamports_before = lamports(&mut fixture, &cranker.pubkey()).await?;
+        ncn_program_client
+            .route_ncn_rewards(
+                ncn,
+                EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                ncn_reward_receiver,
+                Some(&cranker),
+                NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS,
+                epoch,
+            )
+            .await?;
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        let ncn_cranker_fee = ncn_reward_router.total_rewards() * CRANKER_FEE_BPS as u64 / 10_000;
+        assert!(ncn_cranker_fee > 0);
+        assert_eq!(
+            lamports(&mut fixture, &cranker.pubkey()).await?,
+            cranker_lamports_before + ncn_cranker_fee
+        );
+
+        // The fee is no longer in transit
+        assert_eq!(
+            ncn_reward_router.total_rewards_in_transit().unwrap(),
+            ncn_reward_router.total_rewards() - ncn_cranker_fee
+        );
+
+        // Routing again without new rewards pays no fee
+        let cranker_lamports_before = lamports(&mut fixture, &cranker.pubkey()).await?;
+        ncn_program_client
+            .route_ncn_rewards(
+                ncn,
+                EpochSnapshot::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+                ncn_reward_receiver,
+                Some(&cranker),
+                NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS,
+                epoch,
+            )
+            .await?;
+        assert_eq!(
+            lamports(&mut fixture, &cranker.pubkey()).await?,
+            cranker_lamports_before
+        );
+
+        ncn_program_client
+            .do_distribute_operator_vault_reward_route(operator, ncn, epoch)
+            .await?;
+
+        // Operator vault reward router
+        let operator_vault_reward_receiver = OperatorVaultRewardReceiver::find_program_address(
+            &ncn_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+        let cranker_lamports_before = lamports(&mut fixture, &cranker.pubkey()).await?;
+        ncn_program_client
+            .route_operator_vault_rewards(
+                ncn,
+                operator,
+                OperatorSnapshot::find_program_address(&ncn_program::id(), &operator, &ncn, epoch)
+                    .0,
+                OperatorVaultRewardRouter::find_program_address(
+                    &ncn_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+                operator_vault_reward_receiver,
+                Some(&cranker),
+                OperatorVaultRewardRouter::MAX_ROUTE_NCN_ITERATIONS,
+                epoch,
+            )
+            .await?;
+
+        let operator_vault_reward_router = ncn_program_client
+            .get_operator_vault_reward_router(operator, ncn, epoch)
+            .await?;
+        let operator_cranker_fee =
+            operator_vault_reward_router.total_rewards() * CRANKER_FEE_BPS as u64 / 10_000;
+        assert!(operator_cranker_fee > 0);
+        assert_eq!(
+            lamports(&mut fixture, &cranker.pubkey()).await?,
+            cranker_lamports_before + operator_cranker_fee
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_no_cranker_fee_without_cranker() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(CRANKER_FEE_BPS),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, 1.0)
+            .await?;
+
+        ncn_program_client.do_route_ncn_rewards(ncn, epoch).await?;
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        assert!(ncn_reward_router.total_rewards() > 0);
+        assert_eq!(
+            ncn_reward_router.total_rewards_in_transit().unwrap(),
+            ncn_reward_router.total_rewards()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_no_cranker_fee_for_routing_mint_rewards() -> TestResult<()> {
+        const INCOMING_REWARDS: u64 = 1_000_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let mut vault_client = fixture.vault_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(CRANKER_FEE_BPS),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let mint = Keypair::new();
+        vault_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await?;
+        ncn_program_client
+            .do_admin_set_reward_mint(ncn, &test_ncn.ncn_root.ncn_admin, 1, mint.pubkey())
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        vault_client
+            .mint_spl_to(&mint.pubkey(), &ncn_reward_receiver, INCOMING_REWARDS)
+            .await?;
+
+        let cranker = Keypair::new();
+        ncn_program_client.airdrop(&cranker.pubkey(), 1.0).await?;
+
+        let cranker_lamports_before = lamports(&mut fixture, &cranker.pubkey()).await?;
+        ncn_program_client
+            .route_ncn_mint_rewards(ncn, mint.pubkey(), Some(&cranker), epoch)
+            .await?;
+
+        // The cranker fee is SOL only, the cranker is paid neither lamports nor tokens
+        assert_eq!(
+            lamports(&mut fixture, &cranker.pubkey()).await?,
+            cranker_lamports_before
+        );
+        assert!(fixture
+            .get_account(&get_associated_token_address(
+                &cranker.pubkey(),
+                &mint.pubkey()
+            ))
+            .await?
+            .is_none());
+
+        // All the routed rewards are still in transit
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        let mint_rewards = ncn_reward_router.get_mint_rewards(&mint.pubkey()).unwrap();
+        assert_eq!(mint_rewards.total_rewards(), INCOMING_REWARDS);
+        assert_eq!(
+            mint_rewards.total_rewards_in_transit().unwrap(),
+            INCOMING_REWARDS
+        );
+
+        Ok(())
+    }
+}
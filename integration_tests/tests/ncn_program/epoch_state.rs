@@ -52,6 +52,7 @@ mod tests {
 
         let epoch_marker = ncn_program_client.get_epoch_marker(ncn, epoch).await?;
         assert_eq!(epoch_marker.epoch(), epoch);
+        assert!(!epoch_marker.is_skipped());
 
         let result = ncn_program_client
             .do_intialize_epoch_state(ncn, epoch)
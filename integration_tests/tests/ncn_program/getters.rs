@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_core::{ballot_box::WeatherStatus, ncn_reward_router::NCNRewardReceiver};
+    use solana_sdk::native_token::lamports_to_sol;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_getters_return_computed_state() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// NCNProgram Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let weather_status = WeatherStatus::Rainy as u8;
+
+        ncn_program_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let consensus_result = ncn_program_client
+            .get_consensus_result_view(ncn, epoch)
+            .await?;
+        assert!(!consensus_result.consensus_reached());
+
+        let winning_ballot = ncn_program_client
+            .get_winning_ballot_view(ncn, epoch)
+            .await?;
+        assert!(!winning_ballot.has_winning_ballot());
+
+        ncn_program_client
+            .do_cast_vote(ncn, operator, operator_admin, weather_status, epoch)
+            .await?;
+
+        let consensus_result = ncn_program_client
+            .get_consensus_result_view(ncn, epoch)
+            .await?;
+        assert!(consensus_result.consensus_reached());
+        assert_eq!(consensus_result.ncn(), &ncn);
+        assert_eq!(consensus_result.epoch(), epoch);
+        assert_eq!(consensus_result.weather_status(), weather_status);
+
+        let winning_ballot = ncn_program_client
+            .get_winning_ballot_view(ncn, epoch)
+            .await?;
+        let ballot_box = ncn_program_client.get_ballot_box(ncn, epoch).await?;
+        assert!(winning_ballot.has_winning_ballot());
+        assert!(!winning_ballot.tie_breaker_set());
+        assert_eq!(winning_ballot.weather_status(), weather_status);
+        assert_eq!(winning_ballot.tally(), 1);
+        assert_eq!(
+            winning_ballot.slot_consensus_reached(),
+            ballot_box.slot_consensus_reached()
+        );
+
+        // Route rewards to the operator
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, lamports_to_sol(1_000_000))
+            .await?;
+        ncn_program_client.do_route_ncn_rewards(ncn, epoch).await?;
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        let route = ncn_program_client
+            .get_operator_reward_route_view(ncn, operator, epoch)
+            .await?;
+        assert_eq!(route.operator(), &operator);
+        assert!(route.has_route());
+        assert!(!route.still_routing());
+        assert!(route.rewards() > 0);
+        assert_eq!(
+            route.rewards(),
+            ncn_reward_router
+                .operator_vault_reward_route(&operator)
+                .rewards()
+                .unwrap()
+        );
+
+        Ok(())
+    }
+}
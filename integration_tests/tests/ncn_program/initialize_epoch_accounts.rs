@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        ballot_box::BallotBox,
+        consensus_result::ConsensusResult,
+        constants::MAX_REALLOC_BYTES,
+        ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+        weight_table::WeightTable,
+    };
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    fn num_reallocs(size: usize) -> u64 {
+        (size as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1
+    }
+
+    #[tokio::test]
+    async fn test_initialize_epoch_accounts() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_initialize_epoch_accounts(ncn, epoch)
+            .await?;
+
+        let epoch_state = ncn_program_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.epoch(), epoch);
+
+        // Accounts are created at their minimal size
+        for address in [
+            WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            BallotBox::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0,
+        ] {
+            let raw_account = fixture.get_account(&address).await?.unwrap();
+            assert_eq!(raw_account.owner, ncn_program::id());
+            assert_eq!(raw_account.data.len(), MAX_REALLOC_BYTES as usize);
+        }
+
+        let consensus_result =
+            ConsensusResult::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        assert!(fixture.get_account(&consensus_result).await?.is_some());
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        assert!(fixture.get_account(&ncn_reward_receiver).await?.is_some());
+
+        // Reallocs work as with individually initialized accounts
+        ncn_program_client
+            .do_realloc_weight_table(ncn, epoch, num_reallocs(WeightTable::SIZE))
+            .await?;
+        ncn_program_client.do_set_epoch_weights(ncn, epoch).await?;
+
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture
+            .add_vault_operator_delegation_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+
+        ncn_program_client
+            .do_realloc_ballot_box(ncn, epoch, num_reallocs(BallotBox::SIZE))
+            .await?;
+        fixture.cast_votes_for_test_ncn(&test_ncn).await?;
+
+        let ballot_box = ncn_program_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+
+        ncn_program_client
+            .do_realloc_ncn_reward_router(ncn, epoch, num_reallocs(NCNRewardRouter::SIZE))
+            .await?;
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        assert_eq!(*ncn_reward_router.ncn(), ncn);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_initialize_epoch_accounts_twice_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_initialize_epoch_accounts(ncn, epoch)
+            .await?;
+
+        fixture.warp_slot_incremental(1).await?;
+        let result = ncn_program_client
+            .do_initialize_epoch_accounts(ncn, epoch)
+            .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
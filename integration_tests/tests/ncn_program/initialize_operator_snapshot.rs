@@ -48,6 +48,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_initialize_operator_snapshot_if_needed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+
+        ncn_program_client
+            .do_initialize_operator_snapshot_if_needed(operator, ncn, epoch)
+            .await?;
+
+        let address =
+            OperatorSnapshot::find_program_address(&ncn_program::id(), &operator, &ncn, epoch).0;
+        let operator_snapshot_data = fixture.get_account(&address).await?.unwrap().data;
+        let epoch_snapshot = ncn_program_client.get_epoch_snapshot(ncn, epoch).await?;
+
+        // A second crank racing the first one succeeds without changing anything
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_initialize_operator_snapshot_if_needed(operator, ncn, epoch)
+            .await?;
+
+        assert_eq!(
+            fixture.get_account(&address).await?.unwrap().data,
+            operator_snapshot_data
+        );
+        assert_eq!(
+            ncn_program_client
+                .get_epoch_snapshot(ncn, epoch)
+                .await?
+                .operators_registered(),
+            epoch_snapshot.operators_registered()
+        );
+
+        // Without the flag the second initialization still fails
+        fixture.warp_slot_incremental(1).await?;
+        let result = ncn_program_client
+            .do_initialize_operator_snapshot(operator, ncn, epoch)
+            .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_add_operator_after_epoch_snapshot() -> TestResult<()> {
         let mut fixture = TestBuilder::new().await;
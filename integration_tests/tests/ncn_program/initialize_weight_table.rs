@@ -93,4 +93,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_initialize_weight_table_if_needed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // Creates the account when it does not exist yet
+        ncn_program_client
+            .do_initialize_weight_table_if_needed(ncn, epoch)
+            .await?;
+
+        let address = WeightTable::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let raw_account = fixture.get_account(&address).await?.unwrap();
+        assert_eq!(raw_account.data.len(), MAX_REALLOC_BYTES as usize);
+        assert_eq!(raw_account.owner, ncn_program::id());
+
+        // No-op while the account is waiting to be reallocated
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_initialize_weight_table_if_needed(ncn, epoch)
+            .await?;
+
+        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        ncn_program_client
+            .do_realloc_weight_table(ncn, epoch, num_reallocs)
+            .await?;
+
+        // No-op once the account is fully initialized
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_initialize_weight_table_if_needed(ncn, epoch)
+            .await?;
+
+        let raw_account = fixture.get_account(&address).await?.unwrap();
+        assert_eq!(raw_account.data.len(), { WeightTable::SIZE });
+        assert_eq!(raw_account.data[0], WeightTable::DISCRIMINATOR);
+
+        // Still fails without the flag
+        fixture.warp_slot_incremental(1).await?;
+        let result = ncn_program_client
+            .do_initialize_weight_table(ncn, epoch)
+            .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{constants::MAX_KEEPER_LEASE_SLOTS, error::NCNProgramError};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_acquire_keeper_lease() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let authority = Keypair::new();
+        ncn_program_client.airdrop(&authority.pubkey(), 1.0).await?;
+        let leader = Pubkey::new_unique();
+        let standby = Pubkey::new_unique();
+
+        // Creates the lease on first use
+        ncn_program_client
+            .do_acquire_keeper_lease(ncn, &authority, leader, 100)
+            .await?;
+
+        let slot = fixture.clock().await.slot;
+        let keeper_lease = ncn_program_client
+            .get_keeper_lease(ncn, authority.pubkey())
+            .await?;
+        assert_eq!(keeper_lease.ncn(), &ncn);
+        assert_eq!(keeper_lease.authority(), &authority.pubkey());
+        assert!(keeper_lease.is_held_by(&leader, slot));
+        assert_eq!(
+            keeper_lease.slot_expires(),
+            keeper_lease.slot_acquired() + 100
+        );
+
+        // The standby instance waits for the lease to expire
+        let result = ncn_program_client
+            .do_acquire_keeper_lease(ncn, &authority, standby, 100)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::KeeperLeaseHeld, None);
+
+        fixture.warp_slot_incremental(100).await?;
+        ncn_program_client
+            .do_acquire_keeper_lease(ncn, &authority, standby, 100)
+            .await?;
+
+        let slot = fixture.clock().await.slot;
+        let keeper_lease = ncn_program_client
+            .get_keeper_lease(ncn, authority.pubkey())
+            .await?;
+        assert!(keeper_lease.is_held_by(&standby, slot));
+        assert!(!keeper_lease.is_held_by(&leader, slot));
+
+        // Released leases are taken over right away
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_acquire_keeper_lease(ncn, &authority, standby, 0)
+            .await?;
+        ncn_program_client
+            .do_acquire_keeper_lease(ncn, &authority, leader, 100)
+            .await?;
+
+        let slot = fixture.clock().await.slot;
+        let keeper_lease = ncn_program_client
+            .get_keeper_lease(ncn, authority.pubkey())
+            .await?;
+        assert!(keeper_lease.is_held_by(&leader, slot));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_acquire_keeper_lease_too_long_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let authority = Keypair::new();
+        ncn_program_client.airdrop(&authority.pubkey(), 1.0).await?;
+
+        let result = ncn_program_client
+            .do_acquire_keeper_lease(
+                ncn,
+                &authority,
+                Pubkey::new_unique(),
+                MAX_KEEPER_LEASE_SLOTS + 1,
+            )
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::InvalidKeeperLeaseSlots, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_core::error::NCNProgramError;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_mark_epoch_skipped() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let ncn = ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+        let epochs_before_stall = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .epochs_before_stall();
+
+        fixture.warp_epoch_incremental(epochs_before_stall).await?;
+
+        ncn_program_client.do_mark_epoch_skipped(ncn, epoch).await?;
+
+        let epoch_marker = ncn_program_client.get_epoch_marker(ncn, epoch).await?;
+        assert_eq!(epoch_marker.epoch(), epoch);
+        assert!(epoch_marker.is_skipped());
+
+        // A skipped epoch can no longer be started
+        let result = ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::MarkerExists, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_epoch_skipped_before_stall_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let ncn = ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let result = ncn_program_client.do_mark_epoch_skipped(ncn, epoch).await;
+        assert_ncn_program_error(result, NCNProgramError::EpochNotSkippable, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_started_epoch_skipped_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        ncn_program_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let ncn = ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+
+        let epochs_before_stall = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .epochs_before_stall();
+        fixture.warp_epoch_incremental(epochs_before_stall).await?;
+
+        let result = ncn_program_client.do_mark_epoch_skipped(ncn, epoch).await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
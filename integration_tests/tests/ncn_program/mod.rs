@@ -1,20 +1,42 @@
+mod admin_set_admin_governance;
+mod admin_set_admin_multisig;
+mod admin_set_fee_group;
 mod admin_set_parameters;
+mod admin_set_reward_mint;
 mod admin_set_st_mint;
+mod admin_set_vault_metadata;
+mod admin_set_vault_reward_recipient;
 mod admin_update_weight_table;
+mod apply_parameters;
+mod batch_initialize_operator_snapshot;
 mod cast_vote;
 mod close_epoch_accounts;
+mod close_reward_receiver;
+mod consensus_consumer;
+mod copy_previous_epoch_weights;
+mod cranker_fee;
 mod epoch_state;
 mod fuzz_simulation_tests;
+mod getters;
 mod initialize_ballot_box;
 mod initialize_config;
+mod initialize_epoch_accounts;
 mod initialize_epoch_snapshot;
 mod initialize_operator_snapshot;
 mod initialize_vault_registry;
 mod initialize_weight_table;
+mod keeper_lease;
+mod mark_epoch_skipped;
 mod meta_tests;
 mod register_vault;
 mod restaking_variations;
+mod route_and_distribute_ncn_rewards;
+mod set_epoch_weights;
 mod set_new_admin;
+mod set_operator_metadata;
 mod set_tie_breaker;
+mod set_weight_from_switchboard;
+mod shrink_ncn_reward_router;
 mod simulation_test;
 mod snapshot_vault_operator_delegation;
+mod squads_admin;
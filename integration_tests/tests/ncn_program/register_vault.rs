@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests {
     use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
-    use ncn_program_core::constants::WEIGHT;
+    use ncn_program_core::{constants::WEIGHT, error::NCNProgramError};
     use solana_sdk::{signature::Keypair, signer::Signer};
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
 
     #[tokio::test]
     async fn test_register_vault_success() -> TestResult<()> {
@@ -68,6 +70,7 @@ mod tests {
             .get_vault_registry(ncn_root.ncn_pubkey)
             .await?;
         assert_eq!(vault_registry.vault_count(), 1);
+        assert_eq!(vault_registry.get_mint_entry(&st_mint)?.decimals(), 9);
 
         Ok(())
     }
@@ -169,4 +172,101 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_register_vault_permissionless() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let mut vault_client = fixture.vault_client();
+        let mut restaking_client = fixture.restaking_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        // Setup initial state
+        ncn_program_client.setup_ncn_program(&ncn_root).await?;
+        ncn_program_client
+            .do_admin_set_default_weight(ncn_root.ncn_pubkey, WEIGHT)
+            .await?;
+
+        // Setup vault and tickets
+        let vault_root = vault_client
+            .do_initialize_vault(0, 0, 0, 9, &ncn_root.ncn_pubkey, None)
+            .await?;
+        restaking_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await?;
+        vault_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+
+        let vault = vault_root.vault_pubkey;
+        let ncn_vault_ticket = NcnVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        fixture.warp_slot_incremental(2).await?;
+
+        vault_client
+            .do_warmup_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+        restaking_client
+            .do_warmup_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await?;
+
+        // Without permissionless registration the mint has to be registered first
+        let result = ncn_program_client
+            .do_register_vault(ncn_root.ncn_pubkey, vault, ncn_vault_ticket)
+            .await;
+        assert!(result.is_err());
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(true), // permissionless_vault_registration
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        // The ticket is still warming up
+        let result = ncn_program_client
+            .do_register_vault(ncn_root.ncn_pubkey, vault, ncn_vault_ticket)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::NcnVaultTicketNotActive, None);
+
+        let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        let epoch_length = restaking_client
+            .get_config(&restaking_config_pubkey)
+            .await?
+            .epoch_length();
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        ncn_program_client
+            .do_register_vault(ncn_root.ncn_pubkey, vault, ncn_vault_ticket)
+            .await?;
+
+        let st_mint = vault_client.get_vault(&vault).await?.supported_mint;
+        let vault_registry = ncn_program_client
+            .get_vault_registry(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(vault_registry.vault_count(), 1);
+
+        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
+        assert_eq!(mint_entry.weight(), 0);
+        assert_eq!(mint_entry.decimals(), 9);
+        assert_eq!(vault_registry.st_mint_weight(&mint_entry), WEIGHT);
+
+        Ok(())
+    }
 }
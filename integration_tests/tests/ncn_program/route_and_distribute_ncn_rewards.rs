@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        error::NCNProgramError, ncn_reward_router::NCNRewardReceiver,
+        operator_vault_reward_router::OperatorVaultRewardReceiver,
+    };
+    use solana_program::native_token::lamports_to_sol;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error,
+        test_builder::{TestBuilder, TestNcn},
+        TestResult,
+    };
+
+    const REWARD_AMOUNT: u64 = 1_000_000;
+
+    async fn fund_ncn_reward_receiver(
+        fixture: &mut TestBuilder,
+        test_ncn: &TestNcn,
+        warp_past_voting: bool,
+    ) -> TestResult<()> {
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        if warp_past_voting {
+            let valid_slots_after_consensus = ncn_program_client
+                .get_ncn_config(ncn)
+                .await?
+                .valid_slots_after_consensus();
+            fixture
+                .warp_slot_incremental(valid_slots_after_consensus + 1)
+                .await?;
+        }
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, lamports_to_sol(REWARD_AMOUNT))
+            .await
+    }
+
+    async fn lamports(fixture: &mut TestBuilder, address: &Pubkey) -> TestResult<u64> {
+        Ok(fixture
+            .get_account(address)
+            .await?
+            .map(|account| account.lamports)
+            .unwrap_or(0))
+    }
+
+    #[tokio::test]
+    async fn test_route_and_distribute_ncn_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+        fund_ncn_reward_receiver(&mut fixture, &test_ncn, true).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        let protocol_fee_wallet = *config.fee_config.protocol_fee_wallet();
+        let ncn_fee_wallet = *config.fee_config.ncn_fee_wallet();
+
+        let operators: Vec<Pubkey> = test_ncn
+            .operators
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+        let operator_vault_reward_receivers: Vec<Pubkey> = operators
+            .iter()
+            .map(|operator| {
+                OperatorVaultRewardReceiver::find_program_address(
+                    &ncn_program::id(),
+                    operator,
+                    &ncn,
+                    epoch,
+                )
+                .0
+            })
+            .collect();
+
+        let protocol_lamports_before = lamports(&mut fixture, &protocol_fee_wallet).await?;
+        let ncn_lamports_before = lamports(&mut fixture, &ncn_fee_wallet).await?;
+        let mut receiver_lamports_before = vec![];
+        for receiver in operator_vault_reward_receivers.iter() {
+            receiver_lamports_before.push(lamports(&mut fixture, receiver).await?);
+        }
+
+        ncn_program_client
+            .do_route_and_distribute_ncn_rewards(ncn, &operators, epoch)
+            .await?;
+
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        assert!(!ncn_reward_router.still_routing());
+        assert_eq!(ncn_reward_router.protocol_rewards(), 0);
+        assert_eq!(ncn_reward_router.ncn_rewards(), 0);
+        for operator in operators.iter() {
+            // A missing route reads as an empty default one, it has to exist to have been routed
+            let route = ncn_reward_router.operator_vault_reward_route(operator);
+            assert_eq!(route.operator(), operator);
+            assert_eq!(route.rewards().unwrap(), 0);
+        }
+
+        let protocol_lamports_after = lamports(&mut fixture, &protocol_fee_wallet).await?;
+        let ncn_lamports_after = lamports(&mut fixture, &ncn_fee_wallet).await?;
+        assert!(protocol_lamports_after > protocol_lamports_before);
+        assert!(ncn_lamports_after > ncn_lamports_before);
+
+        for (receiver, before) in operator_vault_reward_receivers
+            .iter()
+            .zip(receiver_lamports_before)
+        {
+            assert!(lamports(&mut fixture, receiver).await? > before);
+        }
+
+        // Operator vault reward routers continue from the distributed receivers
+        fixture
+            .route_in_operator_vault_rewards_for_test_ncn(&test_ncn)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_route_and_distribute_ncn_rewards_without_operators() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+        fund_ncn_reward_receiver(&mut fixture, &test_ncn, true).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_route_and_distribute_ncn_rewards(ncn, &[], epoch)
+            .await?;
+
+        // The operator routes are left for DistributeOperatorVaultRewardRoute
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let ncn_reward_router = ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        assert_eq!(ncn_reward_router.protocol_rewards(), 0);
+        assert_eq!(ncn_reward_router.ncn_rewards(), 0);
+        let route = ncn_reward_router.operator_vault_reward_route(&operator);
+        assert_eq!(*route.operator(), operator);
+        assert!(route.rewards().unwrap() > 0);
+
+        ncn_program_client
+            .do_distribute_operator_vault_reward_route(operator, ncn, epoch)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_route_and_distribute_ncn_rewards_voting_not_over_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+        fund_ncn_reward_receiver(&mut fixture, &test_ncn, false).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let result = ncn_program_client
+            .do_route_and_distribute_ncn_rewards(ncn, &[], epoch)
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::VotingIsNotOver, Some(1));
+
+        Ok(())
+    }
+}
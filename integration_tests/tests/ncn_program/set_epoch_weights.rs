@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::constants::WEIGHT;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_set_epoch_weights_decays_stale_vaults() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1),    // weight_decay_epochs
+                Some(2500), // weight_decay_bps
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        // The vault is not updated while the epochs pass
+        fixture.warp_epoch_incremental(3).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+        ncn_program_client.do_set_epoch_weights(ncn, epoch).await?;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+
+        assert!(weight_table.finalized());
+        assert_eq!(
+            weight_table.get_weight(&vault.supported_mint).unwrap(),
+            WEIGHT * 7500 / 10000
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_epoch_weights_without_decay() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_epoch_incremental(3).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+        ncn_program_client.do_set_epoch_weights(ncn, epoch).await?;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+
+        assert_eq!(
+            weight_table.get_weight(&vault.supported_mint).unwrap(),
+            WEIGHT
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_epoch_weights_uses_default_weight() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let default_weight = WEIGHT * 2;
+        ncn_program_client
+            .do_admin_set_default_weight(ncn, default_weight)
+            .await?;
+
+        // The first mint falls back to the default weight
+        let default_vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        ncn_program_client
+            .do_admin_set_st_mint(ncn, default_vault.supported_mint, 0)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+        ncn_program_client.do_set_epoch_weights(ncn, epoch).await?;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[1].vault_pubkey)
+            .await?;
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+
+        assert!(weight_table.finalized());
+        assert_eq!(
+            weight_table
+                .get_weight(&default_vault.supported_mint)
+                .unwrap(),
+            default_weight
+        );
+        assert_eq!(
+            weight_table.get_weight(&vault.supported_mint).unwrap(),
+            WEIGHT
+        );
+
+        Ok(())
+    }
+}
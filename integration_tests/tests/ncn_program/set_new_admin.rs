@@ -53,6 +53,7 @@ mod tests {
                 ConfigAdminRole::TieBreakerAdmin,
                 Pubkey::new_unique(),
                 &wrong_ncn_root,
+                &[],
             )
             .await;
 
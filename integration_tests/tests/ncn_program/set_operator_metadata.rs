@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{error::NCNProgramError, operator_metadata::OperatorMetadata};
+    use solana_program::hash::hash;
+    use solana_sdk::{instruction::InstructionError, signature::Keypair};
+
+    use crate::fixtures::{
+        assert_ix_error, ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder,
+        TestResult,
+    };
+
+    fn padded<const N: usize>(value: &str) -> [u8; N] {
+        let mut padded_value = [0; N];
+        padded_value[..value.len()].copy_from_slice(value.as_bytes());
+        padded_value
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_metadata() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let contact_hash = hash(b"ops@example.com").to_bytes();
+
+        // Creates the account on first use
+        ncn_program_client
+            .do_set_operator_metadata(
+                ncn,
+                operator,
+                operator_admin,
+                padded("Test Operator"),
+                padded("https://example.com"),
+                contact_hash,
+            )
+            .await?;
+
+        let operator_metadata = ncn_program_client
+            .get_operator_metadata(operator, ncn)
+            .await?;
+        assert_eq!(operator_metadata.operator(), &operator);
+        assert_eq!(operator_metadata.ncn(), &ncn);
+        assert_eq!(operator_metadata.name_str(), "Test Operator");
+        assert_eq!(operator_metadata.website_str(), "https://example.com");
+        assert_eq!(operator_metadata.contact_hash(), &contact_hash);
+
+        // Updates the existing account
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_set_operator_metadata(
+                ncn,
+                operator,
+                operator_admin,
+                padded("Renamed Operator"),
+                [0; OperatorMetadata::WEBSITE_LEN],
+                [0; OperatorMetadata::CONTACT_HASH_LEN],
+            )
+            .await?;
+
+        let updated_operator_metadata = ncn_program_client
+            .get_operator_metadata(operator, ncn)
+            .await?;
+        assert_eq!(updated_operator_metadata.name_str(), "Renamed Operator");
+        assert_eq!(updated_operator_metadata.website_str(), "");
+        assert!(!updated_operator_metadata.has_contact_hash());
+        assert!(updated_operator_metadata.slot_updated() > operator_metadata.slot_updated());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_metadata_invalid_name_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        let mut name = padded("Test Operator");
+        name[0] = 0xff;
+
+        let result = ncn_program_client
+            .do_set_operator_metadata(
+                ncn,
+                operator,
+                operator_admin,
+                name,
+                [0; OperatorMetadata::WEBSITE_LEN],
+                [0; OperatorMetadata::CONTACT_HASH_LEN],
+            )
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::InvalidOperatorMetadata, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_metadata_not_operator_admin_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let not_operator_admin = Keypair::new();
+
+        let result = ncn_program_client
+            .do_set_operator_metadata(
+                ncn,
+                operator,
+                &not_operator_admin,
+                padded("Test Operator"),
+                [0; OperatorMetadata::WEBSITE_LEN],
+                [0; OperatorMetadata::CONTACT_HASH_LEN],
+            )
+            .await;
+
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::error::NCNProgramError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_set_weight_from_switchboard_without_feed_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        let st_mint = vault.supported_mint;
+
+        let result = ncn_program_client
+            .do_set_weight_from_switchboard(ncn, epoch, st_mint, Pubkey::new_unique())
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::NoSwitchboardFeed, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_weight_from_switchboard_checks_feed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_client = fixture.vault_program_client();
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let vault = vault_client
+            .get_vault(&test_ncn.vaults[0].vault_pubkey)
+            .await?;
+        let st_mint = vault.supported_mint;
+        let switchboard_feed = Pubkey::new_unique();
+
+        ncn_program_client
+            .do_admin_set_st_mint_switchboard_feed(ncn, st_mint, switchboard_feed)
+            .await?;
+
+        let vault_registry = ncn_program_client.get_vault_registry(ncn).await?;
+        let mint_entry = vault_registry.get_mint_entry(&st_mint).unwrap();
+        assert_eq!(*mint_entry.switchboard_feed(), switchboard_feed);
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        ncn_program_client
+            .do_intialize_epoch_state(ncn, epoch)
+            .await?;
+        ncn_program_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        // Mints with a feed are left for their switchboard feed
+        ncn_program_client.do_set_epoch_weights(ncn, epoch).await?;
+        let weight_table = ncn_program_client.get_weight_table(ncn, epoch).await?;
+        assert!(!weight_table.finalized());
+
+        // Only the registered feed is accepted
+        let result = ncn_program_client
+            .do_set_weight_from_switchboard(ncn, epoch, st_mint, Pubkey::new_unique())
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::IncorrectSwitchboardFeed, None);
+
+        // The registered feed has to be a switchboard pull feed
+        let result = ncn_program_client
+            .do_set_weight_from_switchboard(ncn, epoch, st_mint, switchboard_feed)
+            .await;
+        assert_ncn_program_error(result, NCNProgramError::BadSwitchboardFeed, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+
+    use ncn_program_core::{
+        account_payer::AccountPayer,
+        error::NCNProgramError,
+        ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+    };
+
+    use crate::fixtures::{
+        ncn_program_client::assert_ncn_program_error, test_builder::TestBuilder, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_shrink_ncn_reward_router() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        const OPERATOR_COUNT: usize = 2;
+        const VAULT_COUNT: usize = 1;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, VAULT_COUNT, None)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.reward_test_ncn(&test_ncn, 10_000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let ncn_reward_router =
+            NCNRewardRouter::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        let account_payer = AccountPayer::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let router_lamports_before = fixture
+            .get_account(&ncn_reward_router)
+            .await?
+            .unwrap()
+            .lamports;
+        let account_payer_lamports_before =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+
+        ncn_program_client
+            .do_shrink_ncn_reward_router(ncn, epoch)
+            .await?;
+
+        let router_account = fixture.get_account(&ncn_reward_router).await?.unwrap();
+        assert_eq!(
+            router_account.data.len(),
+            NCNRewardRouter::shrunk_size(OPERATOR_COUNT)
+        );
+
+        let refund = router_lamports_before - router_account.lamports;
+        assert!(refund > 0);
+        assert_eq!(
+            fixture.get_account(&account_payer).await?.unwrap().lamports,
+            account_payer_lamports_before + refund
+        );
+
+        // The recorded routes are kept
+        let ncn_reward_router_account =
+            ncn_program_client.get_ncn_reward_router(ncn, epoch).await?;
+        for operator_root in test_ncn.operators.iter() {
+            assert!(ncn_reward_router_account
+                .has_operator_vault_reward_route(&operator_root.operator_pubkey));
+        }
+
+        // Shrinking again is a no-op
+        fixture.warp_slot_incremental(1).await?;
+        ncn_program_client
+            .do_shrink_ncn_reward_router(ncn, epoch)
+            .await?;
+
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shrink_ncn_reward_router_before_distribution_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = ncn_program_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let ncn_reward_receiver =
+            NCNRewardReceiver::find_program_address(&ncn_program::id(), &ncn, epoch).0;
+        ncn_program_client
+            .airdrop(&ncn_reward_receiver, 1.0)
+            .await?;
+        ncn_program_client.do_route_ncn_rewards(ncn, epoch).await?;
+
+        let result = ncn_program_client
+            .do_shrink_ncn_reward_router(ncn, epoch)
+            .await;
+
+        assert_ncn_program_error(result, NCNProgramError::RouterRewardsInTransit, None);
+
+        Ok(())
+    }
+}
@@ -49,4 +49,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_with_stake_weight_cap() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+
+        // Two vaults with the same delegation, each holding half of the stake weight
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        ncn_program_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(3000), // max_vault_stake_weight_bps
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let epoch_snapshot = ncn_program_client.get_epoch_snapshot(ncn, epoch).await?;
+        let max_vault_stake_weight = epoch_snapshot.max_vault_stake_weight();
+
+        assert!(epoch_snapshot.finalized());
+        assert!(!max_vault_stake_weight.is_zero());
+
+        // Both vaults are capped at 30% of the uncapped total
+        assert_eq!(
+            epoch_snapshot.stake_weights().stake_weight_u256(),
+            max_vault_stake_weight + max_vault_stake_weight
+        );
+
+        Ok(())
+    }
 }
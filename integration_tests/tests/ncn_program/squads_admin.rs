@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use ncn_program_client::{
+        instructions::{AdminSetNewAdminBuilder, AdminSetParametersBuilder},
+        types::ConfigAdminRole,
+    };
+    use ncn_program_core::config::Config as NcnConfig;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::Signer;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    /// A Squads vault signs the instructions of an executed vault transaction as a read-only,
+    /// non fee paying signer, admin instructions must accept it as the NCN admin
+    #[tokio::test]
+    async fn test_admin_instructions_signed_by_vault() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut ncn_program_client = fixture.ncn_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        ncn_program_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        fixture.warp_slot_incremental(1).await?;
+
+        let config_pda = NcnConfig::find_program_address(&ncn_program::id(), &ncn).0;
+
+        let set_parameters_ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .epochs_before_stall(5)
+            .instruction();
+
+        let new_tie_breaker = Pubkey::new_unique();
+        let set_new_admin_ix = AdminSetNewAdminBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .new_admin(new_tie_breaker)
+            .role(ConfigAdminRole::TieBreakerAdmin)
+            .instruction();
+
+        for ix in [&set_parameters_ix, &set_new_admin_ix] {
+            let ncn_admin_meta = ix
+                .accounts
+                .iter()
+                .find(|meta| meta.pubkey.eq(&ncn_root.ncn_admin.pubkey()))
+                .unwrap();
+            assert!(ncn_admin_meta.is_signer && !ncn_admin_meta.is_writable);
+        }
+
+        ncn_program_client
+            .process_signed_by(
+                &[set_parameters_ix, set_new_admin_ix],
+                &[&ncn_root.ncn_admin],
+            )
+            .await?;
+
+        let config = ncn_program_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.epochs_before_stall(), 5);
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker);
+
+        Ok(())
+    }
+}
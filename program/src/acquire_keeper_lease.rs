@@ -0,0 +1,85 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::keeper_lease::KeeperLease;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Takes or renews the keeper lease of the authority. The lease is created on first use, paid by
+/// the authority so anyone creating leases pays for them.
+///
+/// ### Parameters:
+/// - `holder`: The keeper instance taking the lease
+/// - `lease_slots`: Slots the lease is held for, `0` releases it
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[writable]` keeper_lease: The keeper lease of the authority, created if it does not exist
+/// 3. `[writable, signer]` authority: The wallet the keeper instances sign with
+/// 4. `[]` system_program: Solana System Program
+pub fn process_acquire_keeper_lease(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    holder: &Pubkey,
+    lease_slots: u64,
+) -> ProgramResult {
+    let [ncn, keeper_lease, authority, system_program] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    load_signer(authority, true)?;
+
+    let (keeper_lease_pda, keeper_lease_bump, mut keeper_lease_seeds) =
+        KeeperLease::find_program_address(program_id, ncn.key, authority.key);
+    keeper_lease_seeds.push(vec![keeper_lease_bump]);
+
+    if keeper_lease_pda.ne(keeper_lease.key) {
+        msg!("Error: Invalid keeper lease PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if keeper_lease.owner.ne(program_id) {
+        load_system_account(keeper_lease, true)?;
+        load_system_program(system_program)?;
+
+        msg!("Initializing keeper lease {}", keeper_lease.key);
+        create_account(
+            authority,
+            keeper_lease,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            KeeperLease::SIZE as u64,
+            &keeper_lease_seeds,
+        )?;
+
+        let mut keeper_lease_data = keeper_lease.try_borrow_mut_data()?;
+        keeper_lease_data[0] = KeeperLease::DISCRIMINATOR;
+        let keeper_lease_account =
+            KeeperLease::try_from_slice_unchecked_mut(&mut keeper_lease_data)?;
+        keeper_lease_account.initialize(ncn.key, authority.key, keeper_lease_bump);
+    } else {
+        KeeperLease::load(program_id, keeper_lease, ncn.key, authority.key, true)?;
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    let mut keeper_lease_data = keeper_lease.try_borrow_mut_data()?;
+    let keeper_lease_account = KeeperLease::try_from_slice_unchecked_mut(&mut keeper_lease_data)?;
+
+    keeper_lease_account.acquire(holder, current_slot, lease_slots)?;
+    msg!(
+        "Keeper lease held by {} until slot {}",
+        holder,
+        keeper_lease_account.slot_expires()
+    );
+
+    Ok(())
+}
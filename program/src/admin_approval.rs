@@ -0,0 +1,81 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{config::Config, governance::load_governance};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// Checks that an admin instruction on the config is approved, by the NCN admin alone or, once an
+/// admin multisig is set, by at least its threshold of approvers
+///
+/// `approvers` are the accounts passed after the required accounts of the instruction, all of them
+/// have to sign. The `ncn_admin` signer counts as an approver when it is one.
+///
+/// Once the config has an admin governance, `ncn_admin` has to be a governance of its realm and
+/// approves alone, the multisig and the NCN admin no longer do.
+pub fn check_admin_approval(
+    config: &AccountInfo,
+    ncn: &AccountInfo,
+    ncn_admin: &AccountInfo,
+    approvers: &[AccountInfo],
+) -> ProgramResult {
+    let ncn_admin_key = {
+        let ncn_data = ncn.data.borrow();
+        Ncn::try_from_slice_unchecked(&ncn_data)?.admin
+    };
+
+    check_approval(config, &ncn_admin_key, ncn_admin, approvers)
+}
+
+/// Checks that an admin instruction on the vault registry is approved, the same way as
+/// `check_admin_approval` but by the `ncn_program_admin` of the NCN rather than its admin when the
+/// config has neither an admin multisig nor an admin governance
+pub fn check_ncn_program_admin_approval(
+    config: &AccountInfo,
+    ncn: &AccountInfo,
+    admin: &AccountInfo,
+    approvers: &[AccountInfo],
+) -> ProgramResult {
+    let ncn_program_admin_key = {
+        let ncn_data = ncn.data.borrow();
+        Ncn::try_from_slice_unchecked(&ncn_data)?.ncn_program_admin
+    };
+
+    check_approval(config, &ncn_program_admin_key, admin, approvers)
+}
+
+fn check_approval(
+    config: &AccountInfo,
+    admin_key: &Pubkey,
+    admin: &AccountInfo,
+    approvers: &[AccountInfo],
+) -> ProgramResult {
+    {
+        let config_data = config.data.borrow();
+        let config_account = Config::try_from_slice_unchecked(&config_data)?;
+
+        if config_account.has_admin_governance() {
+            return load_governance(
+                config_account.admin_governance_program(),
+                admin,
+                config_account.admin_governance_realm(),
+            );
+        }
+    }
+
+    let mut signers = Vec::with_capacity(approvers.len() + 1);
+    signers.push(*admin.key);
+    for approver in approvers {
+        load_signer(approver, false)?;
+        signers.push(*approver.key);
+    }
+
+    let config_data = config.data.borrow();
+    let config_account = Config::try_from_slice_unchecked(&config_data)?;
+
+    if let Err(error) = config_account.check_admin_approval(admin_key, &signers) {
+        msg!("Error: {}", error);
+        return Err(error.into());
+    }
+
+    Ok(())
+}
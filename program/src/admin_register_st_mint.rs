@@ -4,10 +4,13 @@ use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{config::Config, vault_registry::VaultRegistry};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    program_pack::Pack, pubkey::Pubkey,
 };
+use spl_token::state::Mint;
 
-/// Registers a new staked token mint in the vault registry.
+use crate::admin_approval::check_ncn_program_admin_approval;
+
+/// Registers a new staked token mint in the vault registry, with the decimals of the mint.
 ///
 /// ### Parameters:
 /// - `weight`: Optional initial weight for the token
@@ -18,12 +21,15 @@ use solana_program::{
 /// 3. `[]` ncn: The NCN account
 /// 4. `[]` st_mint: The stake token mint to register
 /// 5. `[signer]` weight_table_admin: Admin authorized to register tokens
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
 pub fn process_admin_register_st_mint(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     weight: Option<u128>,
 ) -> ProgramResult {
-    let [config, ncn, st_mint, vault_registry, admin] = accounts else {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(5));
+    let [config, ncn, st_mint, vault_registry, admin] = required_accounts else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -34,24 +40,22 @@ pub fn process_admin_register_st_mint(
     load_token_mint(st_mint)?;
     load_signer(admin, false)?;
 
-    {
-        let ncn_data = ncn.data.borrow();
-        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
-
-        if ncn_account.ncn_program_admin.ne(admin.key) {
-            msg!("Error: Admin is not the NCN program admin");
-            return Err(ProgramError::InvalidAccountData);
-        }
-    }
+    check_ncn_program_admin_approval(config, ncn, admin, approvers)?;
 
     let mut vault_registry_data = vault_registry.data.borrow_mut();
     let vault_registry_account =
         VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
 
+    let decimals = Mint::unpack(&st_mint.data.borrow())?.decimals;
+
     let weight = weight.unwrap_or_default();
-    msg!("Registering ST mint with weight: {}", weight);
+    msg!(
+        "Registering ST mint with weight: {}, decimals: {}",
+        weight,
+        decimals
+    );
 
-    vault_registry_account.register_st_mint(st_mint.key, weight)?;
+    vault_registry_account.register_st_mint(st_mint.key, decimals, weight)?;
 
     Ok(())
 }
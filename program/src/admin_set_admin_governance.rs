@@ -0,0 +1,56 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::admin_approval::check_admin_approval;
+
+/// Hands the admin role of the config to the governances of an spl-governance realm, so admin
+/// instructions are executed from DAO proposals. While set, the governance signing a proposal
+/// is the `ncn_admin` of admin instructions, including the one handing the role back.
+///
+/// ### Parameters:
+/// - `governance_program`: The spl-governance program the realm lives in
+/// - `realm`: The realm whose governances approve admin instructions, default with a default
+///   `governance_program` removes the admin governance
+///
+/// ### Accounts:
+/// 1. `[writable]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[signer]` ncn_admin: Admin authority for the NCN, the governance once one is set
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+pub fn process_admin_set_admin_governance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    governance_program: &Pubkey,
+    realm: &Pubkey,
+) -> ProgramResult {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(3));
+    let [config, ncn_account, ncn_admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    check_admin_approval(config, ncn_account, ncn_admin, approvers)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    msg!(
+        "Setting admin governance to realm {} of {}",
+        realm,
+        governance_program
+    );
+    config.set_admin_governance(governance_program, realm)?;
+
+    Ok(())
+}
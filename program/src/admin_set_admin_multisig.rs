@@ -0,0 +1,54 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::admin_approval::check_admin_approval;
+
+/// Sets the approvers and threshold that admin instructions on the config require. While a
+/// multisig is set the NCN admin alone can no longer change the config, including the multisig.
+///
+/// ### Parameters:
+/// - `approvers`: The keys that can approve admin instructions
+/// - `threshold`: Number of distinct approvers that have to sign, 0 without approvers removes the multisig
+///
+/// ### Accounts:
+/// 1. `[writable]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[signer]` ncn_admin: Admin authority for the NCN
+///
+/// Followed by the `[signer]` current admin approvers when the config has an admin multisig.
+pub fn process_admin_set_admin_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approvers: &[Pubkey],
+    threshold: u8,
+) -> ProgramResult {
+    let (required_accounts, current_approvers) = accounts.split_at(accounts.len().min(3));
+    let [config, ncn_account, ncn_admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    check_admin_approval(config, ncn_account, ncn_admin, current_approvers)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    msg!(
+        "Setting admin multisig to {} of {} approvers",
+        threshold,
+        approvers.len()
+    );
+    config.set_admin_multisig(approvers, threshold)?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{config::Config, vault_registry::VaultRegistry};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::admin_approval::check_ncn_program_admin_approval;
+
+/// Sets the default weight of the vault registry, used for staked token mints that have neither a
+/// weight nor a switchboard feed.
+///
+/// ### Parameters:
+/// - `default_weight`: The default weight, 0 removes the default
+///
+/// ### Accounts:
+/// 1. `[]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[writable]` vault_registry: The vault registry to update
+/// 4. `[signer]` admin: The NCN program admin
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+pub fn process_admin_set_default_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    default_weight: u128,
+) -> ProgramResult {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(4));
+    let [config, ncn, vault_registry, admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    load_signer(admin, false)?;
+
+    check_ncn_program_admin_approval(config, ncn, admin, approvers)?;
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    msg!(
+        "Setting default weight from {} to {}",
+        vault_registry_account.default_weight(),
+        default_weight
+    );
+    vault_registry_account.set_default_weight(default_weight);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::admin_approval::check_admin_approval;
+
+/// Sets up or updates a custom fee group that receives a share of the rewards next to the
+/// Protocol and the NCN.
+///
+/// ### Parameters:
+/// - `group`: Index of the fee group in the fee config
+/// - `name`: UTF-8 display name of the group, padded with zeros
+/// - `fee_bps`: The new fee of the group, takes effect in the next epoch
+///
+/// ### Accounts:
+/// 1. `[writable]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[]` fee_wallet: Wallet receiving the fees of the group, takes effect immediately
+/// 4. `[signer]` ncn_admin: Admin authority for the NCN
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+pub fn process_admin_set_fee_group(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    group: u8,
+    name: &[u8; 32],
+    fee_bps: Option<u16>,
+) -> ProgramResult {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(4));
+    let [config, ncn_account, fee_wallet, ncn_admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    check_admin_approval(config, ncn_account, ncn_admin, approvers)?;
+
+    let epoch = Clock::get()?.epoch;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    msg!(
+        "Setting fee group {} wallet to {}, fee bps: {:?}",
+        group,
+        fee_wallet.key,
+        fee_bps
+    );
+    config
+        .fee_config
+        .update_fee_group(group as usize, name, fee_wallet.key, fee_bps, epoch)?;
+
+    Ok(())
+}
@@ -10,6 +10,8 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+use crate::admin_approval::check_admin_approval;
+
 /// Sets a new admin for a specific role.
 ///
 /// ### Parameters:
@@ -20,20 +22,25 @@ use solana_program::{
 /// 2. `[]` ncn: The NCN account (named `ncn_account` in code)
 /// 3. `[signer]` ncn_admin: Current admin authority for the NCN
 /// 4. `[]` new_admin: The new admin address
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
 pub fn process_admin_set_new_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     role: ConfigAdminRole,
 ) -> ProgramResult {
-    let [config, ncn_account, ncn_admin, new_admin] = accounts else {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(4));
+    let [config, ncn_account, ncn_admin, new_admin] = required_accounts else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    load_signer(ncn_admin, true)?;
+    load_signer(ncn_admin, false)?;
     NcnConfig::load(program_id, config, ncn_account.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
 
+    check_admin_approval(config, ncn_account, ncn_admin, approvers)?;
+
     let mut config_data = config.try_borrow_mut_data()?;
     let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
 
@@ -42,14 +49,6 @@ pub fn process_admin_set_new_admin(
         return Err(NCNProgramError::IncorrectNcn.into());
     }
 
-    let ncn_data = ncn_account.data.borrow();
-    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-
-    if ncn.admin != *ncn_admin.key {
-        msg!("Error: Incorrect NCN admin");
-        return Err(NCNProgramError::IncorrectNcnAdmin.into());
-    }
-
     match role {
         ConfigAdminRole::TieBreakerAdmin => {
             msg!(
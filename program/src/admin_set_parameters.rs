@@ -1,32 +1,52 @@
-use jito_bytemuck::{types::PodU64, AccountDeserialize};
+use jito_bytemuck::{
+    types::{PodBool, PodU16, PodU64},
+    AccountDeserialize,
+};
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
-    config::Config,
+    config::{Config, ParameterChanges},
     constants::{
-        MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL,
-        MAX_VALID_SLOTS_AFTER_CONSENSUS, MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
-        MIN_EPOCHS_BEFORE_STALL, MIN_VALID_SLOTS_AFTER_CONSENSUS,
+        MAX_CRANKER_FEE_BPS, MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL,
+        MAX_FEE_BPS, MAX_PARAMETERS_TIMELOCK_SLOTS, MAX_TIMELINESS_BONUS_BPS,
+        MAX_VALID_SLOTS_AFTER_CONSENSUS, MAX_WEIGHT_DECAY_BPS,
+        MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MIN_EPOCHS_BEFORE_STALL,
+        MIN_VALID_SLOTS_AFTER_CONSENSUS,
     },
     error::NCNProgramError,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
-/// Updates program configuration parameters after initialization.
+use crate::admin_approval::check_admin_approval;
+
+/// Updates program configuration parameters after initialization. When the config has a parameters
+/// timelock the changes are queued instead and activated by `ApplyParameters` once the timelock
+/// elapsed, replacing any earlier queued changes. Passing no parameters cancels the queued changes.
 ///
 /// ### Parameters:
 /// - `starting_valid_epoch`: Optional starting epoch
 /// - `epochs_before_stall`: Optional number of epochs before stall
 /// - `epochs_after_consensus_before_close`: Optional number of epochs after consensus before close
 /// - `valid_slots_after_consensus`: Optional number of valid slots after consensus
+/// - `cranker_fee_bps`: Optional share of routed rewards paid to crankers
+/// - `timeliness_bonus_slots`: Optional number of slots after voting opens that count as timely
+/// - `timeliness_bonus_bps`: Optional stake weight boost for timely voters, 0 disables it
+/// - `weight_decay_epochs`: Optional number of epochs a vault can go without an update before decay
+/// - `weight_decay_bps`: Optional weight reduction for st mints of stale vaults, 0 disables it
+/// - `max_vault_stake_weight_bps`: Optional cap on the share of stake weight per vault, 0 disables it
+/// - `permissionless_vault_registration`: Optionally lets anyone register vaults and their st mints
+/// - `parameters_timelock_slots`: Optional delay before parameter changes can be applied, 0 disables it
 ///
 /// ### Accounts:
 /// 1. `[writable]` config: NCN configuration account
 /// 2. `[]` ncn: The NCN account
 /// 3. `[signer]` ncn_admin: Admin authority for the NCN
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+#[allow(clippy::too_many_arguments)]
 pub fn process_admin_set_parameters(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -34,24 +54,26 @@ pub fn process_admin_set_parameters(
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    cranker_fee_bps: Option<u16>,
+    timeliness_bonus_slots: Option<u64>,
+    timeliness_bonus_bps: Option<u16>,
+    weight_decay_epochs: Option<u64>,
+    weight_decay_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    permissionless_vault_registration: Option<bool>,
+    parameters_timelock_slots: Option<u64>,
 ) -> ProgramResult {
-    let [config, ncn_account, ncn_admin] = accounts else {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(3));
+    let [config, ncn_account, ncn_admin] = required_accounts else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    load_signer(ncn_admin, true)?;
+    load_signer(ncn_admin, false)?;
     Config::load(program_id, config, ncn_account.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
 
-    {
-        let ncn_data = ncn_account.data.borrow();
-        let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-        if ncn.admin != *ncn_admin.key {
-            msg!("Error: Incorrect NCN admin");
-            return Err(NCNProgramError::IncorrectNcnAdmin.into());
-        }
-    }
+    check_admin_approval(config, ncn_account, ncn_admin, approvers)?;
 
     let mut config_data = config.try_borrow_mut_data()?;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
@@ -61,35 +83,128 @@ pub fn process_admin_set_parameters(
         return Err(NCNProgramError::IncorrectNcn.into());
     }
 
-    if let Some(epoch) = starting_valid_epoch {
+    let changes = ParameterChanges {
+        starting_valid_epoch,
+        epochs_before_stall,
+        epochs_after_consensus_before_close,
+        valid_slots_after_consensus,
+        cranker_fee_bps,
+        timeliness_bonus_slots,
+        timeliness_bonus_bps,
+        weight_decay_epochs,
+        weight_decay_bps,
+        max_vault_stake_weight_bps,
+        permissionless_vault_registration,
+        parameters_timelock_slots,
+    };
+
+    check_parameter_changes(&changes)?;
+
+    if !config.has_parameters_timelock() {
+        apply_parameter_changes(config, &changes);
+        return Ok(());
+    }
+
+    if changes.is_empty() {
+        msg!("Cancelling pending parameter changes");
+    }
+
+    let current_slot = Clock::get()?.slot;
+    config.queue_parameters(&changes, current_slot)?;
+
+    if config.pending_parameters().is_pending() {
         msg!(
-            "Updating valid_starting_epoch from {} to {}",
-            u64::from(config.starting_valid_epoch),
-            epoch
+            "Queued parameter changes, applicable from slot {}",
+            config.pending_parameters().effective_slot()
         );
-        config.starting_valid_epoch = PodU64::from(epoch);
     }
 
-    if let Some(epochs) = epochs_before_stall {
+    Ok(())
+}
+
+fn check_parameter_changes(changes: &ParameterChanges) -> ProgramResult {
+    if let Some(epochs) = changes.epochs_before_stall {
         if !(MIN_EPOCHS_BEFORE_STALL..=MAX_EPOCHS_BEFORE_STALL).contains(&epochs) {
             msg!("Error: Invalid epochs_before_stall value");
             return Err(NCNProgramError::InvalidEpochsBeforeStall.into());
         }
-        msg!(
-            "Updating epochs_before_stall from {} to {}",
-            u64::from(config.epochs_before_stall),
-            epochs
-        );
-        config.epochs_before_stall = PodU64::from(epochs);
     }
 
-    if let Some(epochs) = epochs_after_consensus_before_close {
+    if let Some(epochs) = changes.epochs_after_consensus_before_close {
         if !(MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE..=MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE)
             .contains(&epochs)
         {
             msg!("Error: Invalid epochs_after_consensus_before_close value");
             return Err(NCNProgramError::InvalidEpochsBeforeClose.into());
         }
+    }
+
+    if let Some(slots) = changes.valid_slots_after_consensus {
+        if !(MIN_VALID_SLOTS_AFTER_CONSENSUS..=MAX_VALID_SLOTS_AFTER_CONSENSUS).contains(&slots) {
+            msg!("Error: Invalid valid_slots_after_consensus value");
+            return Err(NCNProgramError::InvalidSlotsAfterConsensus.into());
+        }
+    }
+
+    if let Some(fee_bps) = changes.cranker_fee_bps {
+        if fee_bps > MAX_CRANKER_FEE_BPS {
+            msg!("Error: Invalid cranker_fee_bps value");
+            return Err(NCNProgramError::InvalidCrankerFeeBps.into());
+        }
+    }
+
+    if let Some(bonus_bps) = changes.timeliness_bonus_bps {
+        if bonus_bps > MAX_TIMELINESS_BONUS_BPS {
+            msg!("Error: Invalid timeliness_bonus_bps value");
+            return Err(NCNProgramError::InvalidTimelinessBonusBps.into());
+        }
+    }
+
+    if let Some(decay_bps) = changes.weight_decay_bps {
+        if decay_bps > MAX_WEIGHT_DECAY_BPS {
+            msg!("Error: Invalid weight_decay_bps value");
+            return Err(NCNProgramError::InvalidWeightDecayBps.into());
+        }
+    }
+
+    if let Some(cap_bps) = changes.max_vault_stake_weight_bps {
+        if cap_bps as u64 > MAX_FEE_BPS {
+            msg!("Error: Invalid max_vault_stake_weight_bps value");
+            return Err(NCNProgramError::InvalidMaxVaultStakeWeightBps.into());
+        }
+    }
+
+    if let Some(slots) = changes.parameters_timelock_slots {
+        if slots > MAX_PARAMETERS_TIMELOCK_SLOTS {
+            msg!("Error: Invalid parameters_timelock_slots value");
+            return Err(NCNProgramError::InvalidParametersTimelock.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes already checked parameter changes to the config
+pub fn apply_parameter_changes(config: &mut Config, changes: &ParameterChanges) {
+    if let Some(epoch) = changes.starting_valid_epoch {
+        msg!(
+            "Updating valid_starting_epoch from {} to {}",
+            u64::from(config.starting_valid_epoch),
+            epoch
+        );
+        config.starting_valid_epoch = PodU64::from(epoch);
+    }
+
+    if let Some(epochs) = changes.epochs_before_stall {
+        msg!(
+            "Updating epochs_before_stall from {} to {}",
+            u64::from(config.epochs_before_stall),
+            epochs
+        );
+        config.epochs_before_stall = PodU64::from(epochs);
+    }
+
+    if let Some(epochs) = changes.epochs_after_consensus_before_close {
         msg!(
             "Updating epochs_after_consensus_before_close from {} to {}",
             u64::from(config.epochs_after_consensus_before_close),
@@ -98,11 +213,7 @@ pub fn process_admin_set_parameters(
         config.epochs_after_consensus_before_close = PodU64::from(epochs);
     }
 
-    if let Some(slots) = valid_slots_after_consensus {
-        if !(MIN_VALID_SLOTS_AFTER_CONSENSUS..=MAX_VALID_SLOTS_AFTER_CONSENSUS).contains(&slots) {
-            msg!("Error: Invalid valid_slots_after_consensus value");
-            return Err(NCNProgramError::InvalidSlotsAfterConsensus.into());
-        }
+    if let Some(slots) = changes.valid_slots_after_consensus {
         msg!(
             "Updating valid_slots_after_consensus from {} to {}",
             u64::from(config.valid_slots_after_consensus),
@@ -111,5 +222,75 @@ pub fn process_admin_set_parameters(
         config.valid_slots_after_consensus = PodU64::from(slots);
     }
 
-    Ok(())
+    if let Some(fee_bps) = changes.cranker_fee_bps {
+        msg!(
+            "Updating cranker_fee_bps from {} to {}",
+            config.cranker_fee_bps(),
+            fee_bps
+        );
+        config.cranker_fee_bps = PodU16::from(fee_bps);
+    }
+
+    if let Some(slots) = changes.timeliness_bonus_slots {
+        msg!(
+            "Updating timeliness_bonus_slots from {} to {}",
+            config.timeliness_bonus_slots(),
+            slots
+        );
+        config.timeliness_bonus_slots = PodU64::from(slots);
+    }
+
+    if let Some(bonus_bps) = changes.timeliness_bonus_bps {
+        msg!(
+            "Updating timeliness_bonus_bps from {} to {}",
+            config.timeliness_bonus_bps(),
+            bonus_bps
+        );
+        config.timeliness_bonus_bps = PodU16::from(bonus_bps);
+    }
+
+    if let Some(epochs) = changes.weight_decay_epochs {
+        msg!(
+            "Updating weight_decay_epochs from {} to {}",
+            config.weight_decay_epochs(),
+            epochs
+        );
+        config.weight_decay_epochs = PodU64::from(epochs);
+    }
+
+    if let Some(decay_bps) = changes.weight_decay_bps {
+        msg!(
+            "Updating weight_decay_bps from {} to {}",
+            config.weight_decay_bps(),
+            decay_bps
+        );
+        config.weight_decay_bps = PodU16::from(decay_bps);
+    }
+
+    if let Some(cap_bps) = changes.max_vault_stake_weight_bps {
+        msg!(
+            "Updating max_vault_stake_weight_bps from {} to {}",
+            config.max_vault_stake_weight_bps(),
+            cap_bps
+        );
+        config.max_vault_stake_weight_bps = PodU16::from(cap_bps);
+    }
+
+    if let Some(permissionless) = changes.permissionless_vault_registration {
+        msg!(
+            "Updating permissionless_vault_registration from {} to {}",
+            config.permissionless_vault_registration(),
+            permissionless
+        );
+        config.permissionless_vault_registration = PodBool::from(permissionless);
+    }
+
+    if let Some(slots) = changes.parameters_timelock_slots {
+        msg!(
+            "Updating parameters_timelock_slots from {} to {}",
+            config.parameters_timelock_slots(),
+            slots
+        );
+        config.parameters_timelock_slots = PodU64::from(slots);
+    }
 }
@@ -0,0 +1,49 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::admin_approval::check_admin_approval;
+
+/// Sets an SPL token mint that rewards can be routed in next to SOL.
+///
+/// ### Parameters:
+/// - `index`: Slot of the reward mint in the config
+/// - `mint`: The reward mint, the default pubkey clears the slot
+///
+/// ### Accounts:
+/// 1. `[writable]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[signer]` ncn_admin: Admin authority for the NCN
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+pub fn process_admin_set_reward_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    index: u8,
+    mint: &Pubkey,
+) -> ProgramResult {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(3));
+    let [config, ncn_account, ncn_admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    check_admin_approval(config, ncn_account, ncn_admin, approvers)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    msg!("Setting reward mint {} to {}", index, mint);
+    config.set_reward_mint(index as usize, mint)?;
+
+    Ok(())
+}
@@ -7,24 +7,31 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+use crate::admin_approval::check_ncn_program_admin_approval;
+
 /// Updates an existing staked token mint in the vault registry.
 ///
 /// ### Parameters:
 /// - `st_mint`: Public key of the staked token mint
 /// - `weight`: Optional new weight for the token
+/// - `switchboard_feed`: Optional new switchboard feed for the token, `Pubkey::default()` removes it
 ///
 /// ### Accounts:
 /// 1. `[]` config: NCN configuration account
 /// 2. `[writable]` vault_registry: The vault registry to update
 /// 3. `[]` ncn: The NCN account
 /// 4. `[signer]` weight_table_admin: Admin authorized to update token weights
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
 pub fn process_admin_set_st_mint(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     st_mint: &Pubkey,
     weight: Option<u128>,
+    switchboard_feed: Option<Pubkey>,
 ) -> ProgramResult {
-    let [config, ncn, vault_registry, admin] = accounts else {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(4));
+    let [config, ncn, vault_registry, admin] = required_accounts else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -34,22 +41,19 @@ pub fn process_admin_set_st_mint(
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     load_signer(admin, false)?;
 
-    {
-        let ncn_data = ncn.data.borrow();
-        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
-
-        if ncn_account.ncn_program_admin.ne(admin.key) {
-            msg!("Error: Admin is not the NCN program admin");
-            return Err(ProgramError::InvalidAccountData);
-        }
-    }
+    check_ncn_program_admin_approval(config, ncn, admin, approvers)?;
 
     let mut vault_registry_data = vault_registry.data.borrow_mut();
     let vault_registry_account =
         VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
 
-    msg!("Setting ST mint to {:?} with weight {:?}", st_mint, weight);
-    vault_registry_account.set_st_mint(st_mint, weight)?;
+    msg!(
+        "Setting ST mint to {:?} with weight {:?} and switchboard feed {:?}",
+        st_mint,
+        weight,
+        switchboard_feed
+    );
+    vault_registry_account.set_st_mint(st_mint, weight, switchboard_feed)?;
 
     Ok(())
 }
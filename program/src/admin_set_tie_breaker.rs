@@ -2,8 +2,12 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
-    ballot_box::BallotBox, config::Config as NcnConfig, epoch_state::EpochState,
+    ballot_box::BallotBox,
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
     error::NCNProgramError,
+    events::{ConsensusReached, Event},
+    stake_weight::StakeWeights,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -70,6 +74,21 @@ pub fn process_admin_set_tie_breaker(
         let consensus_reached = ballot_box_account.is_consensus_reached();
         msg!("Consensus reached: {}", consensus_reached);
         epoch_state_account.update_set_tie_breaker(consensus_reached, slot)?;
+
+        if consensus_reached {
+            let winning_ballot_tally = ballot_box_account.get_winning_ballot_tally()?;
+
+            ConsensusReached::new(
+                ncn.key,
+                epoch,
+                winning_ballot_tally.ballot().weather_status(),
+                winning_ballot_tally.stake_weights(),
+                &StakeWeights::default(),
+                true,
+                slot,
+            )
+            .log();
+        }
     }
 
     Ok(())
@@ -0,0 +1,54 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{config::Config, vault_registry::VaultRegistry};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::admin_approval::check_ncn_program_admin_approval;
+
+/// Sets the display name and URI hash of a vault in the vault registry, so clients can show a
+/// human readable identity for the vault.
+///
+/// ### Parameters:
+/// - `name`: UTF-8 name padded with zeros, all zeros clears the name
+/// - `uri_hash`: Hash of a URI describing the vault, all zeros clears it
+///
+/// ### Accounts:
+/// 1. `[]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[writable]` vault_registry: The vault registry to update
+/// 4. `[]` vault: The vault whose metadata is being set
+/// 5. `[signer]` admin: The NCN program admin
+///
+/// Followed by the `[signer]` admin approvers when the config has an admin multisig.
+pub fn process_admin_set_vault_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: &[u8; 32],
+    uri_hash: &[u8; 32],
+) -> ProgramResult {
+    let (required_accounts, approvers) = accounts.split_at(accounts.len().min(5));
+    let [config, ncn, vault_registry, vault, admin] = required_accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    load_signer(admin, false)?;
+
+    check_ncn_program_admin_approval(config, ncn, admin, approvers)?;
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    msg!("Setting metadata of vault {}", vault.key);
+    vault_registry_account.set_vault_metadata(vault.key, name, uri_hash)?;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_vault_core::vault::Vault;
+use ncn_program_core::{config::Config, error::NCNProgramError, vault_registry::VaultRegistry};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey, system_program,
+};
+use spl_token::state::Account as TokenAccount;
+
+/// Sets the wallet or wrapped SOL account a vault's share of the rewards is sent to.
+///
+/// The recipient must either be a system account or a wrapped SOL token account,
+/// such as the vault's own wSOL ATA. Passing the vault itself resets the recipient.
+///
+/// Rewards in a reward mint are sent to a token account of the mint owned by the recipient, or by
+/// the owner of the wrapped SOL account. Token accounts of other mints cannot be the recipient,
+/// their owner is set instead.
+///
+/// ### Accounts:
+/// 1. `[]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+/// 3. `[writable]` vault_registry: The vault registry to update
+/// 4. `[]` vault: The vault whose recipient is being set
+/// 5. `[]` reward_recipient: The account rewards will be sent to
+/// 6. `[signer]` vault_admin: The admin of the vault
+pub fn process_admin_set_vault_reward_recipient(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, ncn, vault_registry, vault, reward_recipient, vault_admin] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Vault::load(&jito_vault_program::id(), vault, false)?;
+    load_signer(vault_admin, false)?;
+
+    {
+        let vault_data = vault.data.borrow();
+        let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+
+        if vault_account.admin.ne(vault_admin.key) {
+            msg!("Error: Admin is not the vault admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    if reward_recipient.key.ne(vault.key) {
+        check_vault_reward_recipient(reward_recipient)?;
+    }
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    msg!(
+        "Setting reward recipient of vault {} to {}",
+        vault.key,
+        reward_recipient.key
+    );
+    vault_registry_account.set_vault_reward_recipient(vault.key, reward_recipient.key)?;
+
+    Ok(())
+}
+
+/// Checks that lamports sent to the recipient end up spendable: either a plain
+/// system account or a wrapped SOL token account that can be synced.
+pub fn check_vault_reward_recipient(reward_recipient: &AccountInfo) -> ProgramResult {
+    if reward_recipient.owner.eq(&system_program::id()) {
+        return Ok(());
+    }
+
+    if reward_recipient.owner.eq(&spl_token::id()) {
+        let token_account = TokenAccount::unpack(&reward_recipient.data.borrow())?;
+        if token_account.is_native() {
+            return Ok(());
+        }
+
+        msg!(
+            "Error: Vault reward recipient {} is a token account of {}, which cannot receive SOL. Set its owner {} as the recipient, reward mint rewards are sent to its token accounts",
+            reward_recipient.key,
+            token_account.mint,
+            token_account.owner
+        );
+        return Err(NCNProgramError::InvalidVaultRewardRecipient.into());
+    }
+
+    msg!(
+        "Error: Vault reward recipient {} is owned by {}, it must be a system account or a wrapped SOL token account",
+        reward_recipient.key,
+        reward_recipient.owner
+    );
+    Err(NCNProgramError::InvalidVaultRewardRecipient.into())
+}
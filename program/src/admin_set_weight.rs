@@ -21,6 +21,9 @@ use solana_program::{
 /// 2. `[]` ncn: The NCN account.
 /// 3. `[writable]` weight_table: The weight table to update.
 /// 4. `[signer]` weight_table_admin: Admin authorized to update weights.
+///
+/// Not gated by the admin multisig: the weight table admin sets the weights of every epoch as part
+/// of the epoch cycle, like a keeper, and the weight table of an epoch only lives until it is closed.
 pub fn process_admin_set_weight(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{config::Config, error::NCNProgramError};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::admin_set_parameters::apply_parameter_changes;
+
+/// Applies the parameter changes queued by `AdminSetParameters` once the parameters timelock of the
+/// config elapsed. Permissionless, the changes were authorized by the admin when they were queued.
+///
+/// ### Accounts:
+/// 1. `[writable]` config: NCN configuration account
+/// 2. `[]` ncn: The NCN account
+pub fn process_apply_parameters(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, ncn_account] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        msg!("Error: Incorrect NCN account");
+        return Err(NCNProgramError::IncorrectNcn.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let changes = config
+        .take_pending_parameters(current_slot)
+        .map_err(|error| {
+            msg!("Error: {}", error);
+            error
+        })?;
+
+    apply_parameter_changes(config, &changes);
+
+    Ok(())
+}
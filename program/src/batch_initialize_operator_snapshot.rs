@@ -0,0 +1,116 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker,
+    epoch_snapshot::EpochSnapshot, epoch_state::EpochState, error::NCNProgramError,
+    loaders::load_ncn_epoch,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::initialize_operator_snapshot::create_operator_snapshot;
+
+/// Accounts passed per operator after the required accounts:
+/// `operator`, `ncn_operator_state` and `operator_snapshot`
+const OPERATOR_SNAPSHOT_ACCOUNTS: usize = 3;
+
+/// Initializes the snapshots of several operators in one call. Behaves like
+/// `InitializeOperatorSnapshot` for every operator, but registers all inactive operators in the
+/// epoch snapshot at once.
+///
+/// ### Parameters:
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
+/// 2. `[writable]` epoch_state: The epoch state account for the target epoch
+/// 3. `[]` config: NCN configuration account
+/// 4. `[]` restaking_config: Restaking configuration account
+/// 5. `[]` ncn: The NCN account
+/// 6. `[writable]` epoch_snapshot: Epoch snapshot account
+/// 7. `[writable]` account_payer: Account paying for initialization
+/// 8. `[]` system_program: Solana System Program
+///
+/// Followed by `operator`, `ncn_operator_state` and `[writable]` `operator_snapshot` for every
+/// operator to snapshot.
+pub fn process_batch_initialize_operator_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let (required_accounts, operator_accounts) = accounts.split_at(accounts.len().min(8));
+    let [epoch_marker, epoch_state, config, restaking_config, ncn, epoch_snapshot, account_payer, system_program] =
+        required_accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if operator_accounts.is_empty() || operator_accounts.len() % OPERATOR_SNAPSHOT_ACCOUNTS != 0 {
+        msg!(
+            "Error: Expected operator, ncn operator state and operator snapshot for every operator"
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, false)?;
+    Config::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+    load_system_program(system_program)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    let current_slot = Clock::get()?.slot;
+    let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
+
+    let mut inactive_operators: u64 = 0;
+    for operator_snapshot_accounts in operator_accounts.chunks(OPERATOR_SNAPSHOT_ACCOUNTS) {
+        let [operator, ncn_operator_state, operator_snapshot] = operator_snapshot_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        msg!("Initializing operator snapshot for {}", operator.key);
+
+        let is_active = create_operator_snapshot(
+            program_id,
+            epoch,
+            epoch_state,
+            ncn,
+            operator,
+            ncn_operator_state,
+            epoch_snapshot,
+            operator_snapshot,
+            account_payer,
+            system_program,
+            current_slot,
+            ncn_epoch_length,
+        )?;
+
+        if !is_active {
+            inactive_operators = inactive_operators
+                .checked_add(1)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        }
+    }
+
+    msg!(
+        "Initialized {} operator snapshots, {} inactive",
+        operator_accounts.len() / OPERATOR_SNAPSHOT_ACCOUNTS,
+        inactive_operators
+    );
+
+    {
+        let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
+        let epoch_snapshot_account =
+            EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+
+        epoch_snapshot_account
+            .increment_inactive_operator_registrations(current_slot, inactive_operators)?;
+    }
+
+    Ok(())
+}
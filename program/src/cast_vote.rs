@@ -8,6 +8,7 @@ use ncn_program_core::{
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::NCNProgramError,
+    events::{ConsensusReached, Event, VoteCast},
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -100,7 +101,10 @@ pub fn process_cast_vote(
 
         *epoch_snapshot.stake_weights()
     };
-    msg!("Total stake weight: {}", total_stake_weights.stake_weight());
+    msg!(
+        "Total stake weight: {}",
+        total_stake_weights.stake_weight_u256()
+    );
 
     let operator_stake_weights = {
         let operator_snapshot_data = operator_snapshot.data.borrow();
@@ -123,6 +127,7 @@ pub fn process_cast_vote(
     msg!("Current slot: {}", slot);
 
     let ballot = Ballot::new(weather_status);
+    let consensus_was_reached = ballot_box.is_consensus_reached();
 
     ballot_box.cast_vote(
         operator.key,
@@ -132,12 +137,22 @@ pub fn process_cast_vote(
         valid_slots_after_consensus,
     )?;
 
+    VoteCast::new(
+        ncn.key,
+        epoch,
+        operator.key,
+        weather_status,
+        &operator_stake_weights,
+        slot,
+    )
+    .log();
+
     msg!(
         "Tallying votes with total stake weight: {}, current slot: {}",
         total_stake_weights.stake_weight(),
         slot
     );
-    ballot_box.tally_votes(total_stake_weights.stake_weight(), slot)?;
+    ballot_box.tally_votes(&total_stake_weights, slot)?;
 
     // If consensus is reached, update the consensus result account
     if ballot_box.is_consensus_reached() {
@@ -160,6 +175,19 @@ pub fn process_cast_vote(
             total_stake_weights.stake_weight() as u64,
             slot,
         )?;
+
+        if !consensus_was_reached {
+            ConsensusReached::new(
+                ncn.key,
+                epoch,
+                winning_ballot_tally.ballot().weather_status(),
+                winning_ballot_tally.stake_weights(),
+                &total_stake_weights,
+                false,
+                slot,
+            )
+            .log();
+        }
     } else {
         msg!("Consensus not yet reached for epoch: {}", epoch);
     }
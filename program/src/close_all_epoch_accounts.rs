@@ -0,0 +1,96 @@
+use jito_bytemuck::Discriminator;
+use ncn_program_core::{
+    error::NCNProgramError, ncn_reward_router::NCNRewardRouter,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::close_epoch_account::process_close_epoch_account;
+
+/// Closes several epoch accounts in one call. Every account is closed exactly like
+/// `CloseEpochAccount` would close it, in the order it is passed, so the same ordering rules apply
+/// and the `EpochState` can only come last.
+///
+/// ### Parameters:
+/// - `epoch`: The epoch associated with the accounts being closed.
+///
+/// ### Accounts:
+/// 1. `[writable]` epoch_marker: Marker account, created once the `EpochState` is closed
+/// 2. `[writable]` epoch_state: The epoch state account for the target epoch
+/// 3. `[]` config: NCN configuration account
+/// 4. `[]` ncn: The NCN account
+/// 5. `[writable]` account_payer: Receives the reclaimed rent lamports
+/// 6. `[]` system_program: Solana System Program
+/// 7. `[writable]` ncn_fee_wallet: Receives the leftovers of closed reward receivers
+///
+/// Followed by the `[writable]` accounts to close. Reward routers have to be followed by their
+/// `[writable]` reward receiver.
+pub fn process_close_all_epoch_accounts(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let (required_accounts, mut accounts_to_close) = accounts.split_at(accounts.len().min(7));
+    let [epoch_marker, epoch_state, config, ncn, account_payer, system_program, ncn_fee_wallet] =
+        required_accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if accounts_to_close.is_empty() {
+        msg!("Error: No accounts to close provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut closed_accounts: usize = 0;
+    while let Some((account_to_close, rest)) = accounts_to_close.split_first() {
+        let is_reward_router = {
+            let account_to_close_data = account_to_close.try_borrow_data()?;
+            account_to_close_data.first().is_some_and(|discriminator| {
+                *discriminator == NCNRewardRouter::DISCRIMINATOR
+                    || *discriminator == OperatorVaultRewardRouter::DISCRIMINATOR
+            })
+        };
+
+        let mut close_accounts = vec![
+            epoch_marker.clone(),
+            epoch_state.clone(),
+            config.clone(),
+            ncn.clone(),
+            account_to_close.clone(),
+            account_payer.clone(),
+            system_program.clone(),
+        ];
+
+        let rest = if is_reward_router {
+            let Some((receiver_to_close, rest)) = rest.split_first() else {
+                msg!(
+                    "Error: Reward router {} is not followed by its receiver",
+                    account_to_close.key
+                );
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            close_accounts.push(ncn_fee_wallet.clone());
+            close_accounts.push(receiver_to_close.clone());
+            rest
+        } else {
+            rest
+        };
+
+        process_close_epoch_account(program_id, &close_accounts, epoch)?;
+
+        closed_accounts = closed_accounts
+            .checked_add(1)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        accounts_to_close = rest;
+    }
+
+    msg!("Closed {} epoch accounts", closed_accounts);
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use ncn_program_core::{
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::NCNProgramError,
+    events::{EpochClosed, Event},
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
     weight_table::WeightTable,
@@ -303,7 +304,9 @@ pub fn process_close_epoch_account(
             epoch,
             slot_closed
         );
-        *epoch_marker = EpochMarker::new(ncn.key, epoch, slot_closed);
+        *epoch_marker = EpochMarker::new(ncn.key, epoch, slot_closed, false);
+
+        EpochClosed::new(ncn.key, epoch, slot_closed).log();
     }
 
     msg!("Closing account: {}", account_to_close.key);
@@ -0,0 +1,132 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    config::Config as NcnConfig,
+    epoch_marker::EpochMarker,
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+    operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Sends the lamports left in a reward receiver whose reward router was closed to the NCN fee
+/// wallet. Routers are closed along with their receiver, rewards arriving at the receiver after
+/// that can not be routed anymore.
+///
+/// ### Parameters:
+/// - `operator`: The operator of the operator vault reward receiver, `None` for the NCN reward receiver.
+/// - `epoch`: The epoch of the reward receiver.
+///
+/// ### Accounts:
+/// 1. `[]` epoch_marker: Marker of the epoch, the epoch is closed for good when it exists.
+/// 2. `[]` epoch_state: The epoch state, it has to be closing when the epoch marker does not exist.
+/// 3. `[]` config: NCN configuration account (used to check the NCN fee wallet).
+/// 4. `[]` ncn: The NCN account.
+/// 5. `[]` reward_router: The closed NCN or operator vault reward router of the receiver.
+/// 6. `[writable]` reward_receiver: The NCN or operator vault reward receiver to empty.
+/// 7. `[writable]` ncn_fee_wallet: The NCN fee wallet of the config, receiving the lamports.
+/// 8. `[]` system_program: Solana System Program.
+pub fn process_close_reward_receiver(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    operator: Option<Pubkey>,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, config, ncn, reward_router, reward_receiver, ncn_fee_wallet, system_program] =
+        accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+
+    {
+        let config_data = config.try_borrow_data()?;
+        let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+        if config_account
+            .fee_config
+            .ncn_fee_wallet()
+            .ne(ncn_fee_wallet.key)
+        {
+            return Err(NCNProgramError::InvalidNCNFeeWallet.into());
+        }
+    }
+
+    // Routers can not be created anymore once the epoch is closing
+    let epoch_marker_pda = EpochMarker::find_program_address(program_id, ncn.key, epoch).0;
+    if epoch_marker_pda.ne(epoch_marker.key) {
+        msg!("Error: Invalid epoch marker PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let epoch_closed = epoch_marker.owner.eq(program_id) && !epoch_marker.data_is_empty();
+    if !epoch_closed {
+        EpochState::load(program_id, epoch_state, ncn.key, epoch, false)?;
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+        if !epoch_state_account.is_closing() {
+            msg!("Error: Epoch is not closing, the reward router can still be created");
+            return Err(NCNProgramError::RewardRouterNotClosed.into());
+        }
+    }
+
+    let reward_router_pda = match operator {
+        Some(operator) => {
+            OperatorVaultRewardRouter::find_program_address(program_id, &operator, ncn.key, epoch).0
+        }
+        None => NCNRewardRouter::find_program_address(program_id, ncn.key, epoch).0,
+    };
+    if reward_router_pda.ne(reward_router.key) {
+        msg!("Error: Invalid reward router PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !reward_router.data_is_empty() {
+        msg!("Error: Reward router is open");
+        return Err(NCNProgramError::RewardRouterNotClosed.into());
+    }
+
+    match operator {
+        Some(operator) => OperatorVaultRewardReceiver::load(
+            program_id,
+            reward_receiver,
+            &operator,
+            ncn.key,
+            epoch,
+            true,
+        )?,
+        None => NCNRewardReceiver::load(program_id, reward_receiver, ncn.key, epoch, true)?,
+    }
+
+    let lamports = reward_receiver.lamports();
+    msg!("Sending {} lamports to the NCN fee wallet", lamports);
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    match operator {
+        Some(operator) => OperatorVaultRewardReceiver::transfer(
+            program_id,
+            &operator,
+            ncn.key,
+            epoch,
+            reward_receiver,
+            ncn_fee_wallet,
+            lamports,
+        ),
+        None => NCNRewardReceiver::transfer(
+            program_id,
+            ncn.key,
+            epoch,
+            reward_receiver,
+            ncn_fee_wallet,
+            lamports,
+        ),
+    }
+}
@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    epoch_state::EpochState, error::NCNProgramError, weight_table::WeightTable,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Seeds the weights of the epoch from the previous epoch's weight table. Permissionless, only
+/// possible if the vault registry did not change between the two epochs, otherwise the weights
+/// have to be set again. Weights of mints with a switchboard feed are left to `SetWeightFromSwitchboard`.
+///
+/// ### Parameters:
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[writable]` epoch_state: The epoch state account for the target epoch
+/// 2. `[]` ncn: The NCN account
+/// 3. `[writable]` weight_table: The weight table to update
+/// 4. `[]` previous_weight_table: The finalized weight table of the previous epoch
+pub fn process_copy_previous_epoch_weights(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn, weight_table, previous_weight_table] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let previous_epoch = epoch
+        .checked_sub(1)
+        .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+    WeightTable::load(
+        program_id,
+        previous_weight_table,
+        ncn.key,
+        previous_epoch,
+        false,
+    )?;
+
+    let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+    let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+    weight_table_account.check_table_initialized()?;
+
+    if weight_table_account.finalized() {
+        msg!("Error: Weight table is already finalized");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        let previous_weight_table_data = previous_weight_table.data.borrow();
+        let previous_weight_table_account =
+            WeightTable::try_from_slice_unchecked(&previous_weight_table_data)?;
+
+        msg!("Copying weights from epoch {}", previous_epoch);
+        weight_table_account.copy_weights(previous_weight_table_account, Clock::get()?.slot)?;
+    }
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_weight(
+            weight_table_account.weight_count() as u64,
+            weight_table_account.st_mint_count() as u64,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use jito_jsm_core::loader::{load_signer, load_system_program};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey, system_instruction,
+};
+
+/// Loads the optional cranker of a routing instruction
+///
+/// Clients fill skipped optional accounts with the program id, so those are treated as no cranker
+pub fn load_cranker<'a, 'info>(
+    program_id: &Pubkey,
+    cranker: Option<&'a AccountInfo<'info>>,
+) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+    match cranker {
+        Some(cranker) if cranker.key.ne(program_id) => {
+            load_signer(cranker, true)?;
+            Ok(Some(cranker))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Pays the cranker fee out of a reward receiver, signed with the receiver's seeds
+pub fn pay_cranker_fee<'a, 'info>(
+    reward_receiver: &'a AccountInfo<'info>,
+    mut reward_receiver_seeds: Vec<Vec<u8>>,
+    reward_receiver_bump: u8,
+    cranker: &'a AccountInfo<'info>,
+    system_program: Option<&'a AccountInfo<'info>>,
+    cranker_fee: u64,
+) -> ProgramResult {
+    if cranker_fee == 0 {
+        return Ok(());
+    }
+
+    let Some(system_program) = system_program else {
+        msg!("Error: System program is required to pay the cranker fee");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_system_program(system_program)?;
+
+    msg!(
+        "Paying {} lamports cranker fee to {}",
+        cranker_fee,
+        cranker.key
+    );
+
+    reward_receiver_seeds.push(vec![reward_receiver_bump]);
+
+    invoke_signed(
+        &system_instruction::transfer(reward_receiver.key, cranker.key, cranker_fee),
+        &[
+            reward_receiver.clone(),
+            cranker.clone(),
+            system_program.clone(),
+        ],
+        &[reward_receiver_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )
+}
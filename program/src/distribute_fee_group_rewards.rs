@@ -0,0 +1,210 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    config::Config,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    loaders::load_reward_token_account,
+    ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    sysvar::Sysvar,
+};
+
+/// Distributes the rewards of a custom fee group to the wallet of the group. Distributes SOL, or
+/// the rewards of the given reward mint when `mint` is set
+pub fn process_distribute_fee_group_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    group: u8,
+    mint: Option<Pubkey>,
+    epoch: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(7));
+    let [epoch_state, ncn_config, ncn, ncn_reward_router, ncn_reward_receiver, fee_group_wallet, system_program] =
+        required_accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Config::load(program_id, ncn_config, ncn.key, false)?;
+    NCNRewardRouter::load(program_id, ncn_reward_router, ncn.key, epoch, true)?;
+    NCNRewardReceiver::load(program_id, ncn_reward_receiver, ncn.key, epoch, true)?;
+
+    let group = group as usize;
+
+    {
+        let ncn_config_data = ncn_config.try_borrow_data()?;
+        let ncn_config_account = Config::try_from_slice_unchecked(&ncn_config_data)?;
+        let fee_wallet = ncn_config_account.fee_config.fee_group_wallet(group)?;
+
+        if fee_wallet.ne(fee_group_wallet.key) {
+            msg!("Error: Incorrect fee group {} wallet provided", group);
+            return Err(NCNProgramError::InvalidFeeGroupWallet.into());
+        }
+    }
+
+    if let Some(mint) = mint {
+        let [token_program, ncn_reward_receiver_token_account, fee_group_wallet_token_account] =
+            optional_accounts
+        else {
+            msg!("Error: Token accounts are required to distribute mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        load_reward_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+        load_reward_token_account(
+            fee_group_wallet_token_account,
+            fee_group_wallet.key,
+            &mint,
+            true,
+        )?;
+
+        let rewards = {
+            let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+            let ncn_reward_router_account =
+                NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+            if ncn_reward_router_account.still_routing_mint(&mint) {
+                msg!("Error: Rewards still routing, cannot distribute yet");
+                return Err(NCNProgramError::RouterStillRouting.into());
+            }
+
+            ncn_reward_router_account.distribute_fee_group_mint_rewards(&mint, group)?
+        };
+
+        if rewards > 0 {
+            msg!(
+                "Distributing {} of {} to fee group {} wallet",
+                rewards,
+                mint,
+                group
+            );
+
+            NCNRewardReceiver::transfer_tokens(
+                program_id,
+                ncn.key,
+                epoch,
+                ncn_reward_receiver,
+                ncn_reward_receiver_token_account,
+                fee_group_wallet_token_account,
+                token_program,
+                rewards,
+            )?;
+
+            DistributionReceipt::new(
+                DistributionKind::FeeGroup,
+                ncn.key,
+                epoch,
+                fee_group_wallet_token_account.key,
+                Some(&mint),
+                rewards,
+                Clock::get()?.slot,
+            )
+            .log();
+        } else {
+            msg!("No rewards to distribute (0 of {})", mint);
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
+    // Get rewards and update state
+    let rewards = {
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        if ncn_reward_router_account.still_routing() {
+            msg!("Error: Rewards still routing, cannot distribute yet");
+            return Err(NCNProgramError::RouterStillRouting.into());
+        }
+
+        let rewards = ncn_reward_router_account.distribute_fee_group_rewards(group)?;
+        msg!(
+            "Calculated fee group {} rewards: {} lamports",
+            group,
+            rewards
+        );
+        rewards
+    };
+
+    if rewards > 0 {
+        msg!(
+            "Distributing {} lamports to fee group {} wallet",
+            rewards,
+            group
+        );
+
+        let (_, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
+            NCNRewardReceiver::find_program_address(program_id, ncn.key, epoch);
+        ncn_reward_receiver_seeds.push(vec![ncn_reward_receiver_bump]);
+
+        let ncn_reward_receiver_balance = **ncn_reward_receiver.try_borrow_lamports()?;
+        msg!(
+            "NCN reward receiver balance: {} lamports",
+            ncn_reward_receiver_balance
+        );
+
+        // Transfer rewards from receiver to the fee group wallet
+        let transfer_instruction =
+            system_instruction::transfer(ncn_reward_receiver.key, fee_group_wallet.key, rewards);
+
+        invoke_signed(
+            &transfer_instruction,
+            &[
+                ncn_reward_receiver.clone(),
+                fee_group_wallet.clone(),
+                system_program.clone(),
+            ],
+            &[ncn_reward_receiver_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+
+        msg!(
+            "Successfully transferred {} lamports to fee group {} wallet",
+            rewards,
+            group
+        );
+
+        DistributionReceipt::new(
+            DistributionKind::FeeGroup,
+            ncn.key,
+            epoch,
+            fee_group_wallet.key,
+            None,
+            rewards,
+            Clock::get()?.slot,
+        )
+        .log();
+    } else {
+        msg!("No rewards to distribute (0 lamports)");
+    }
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_distribute_fee_group_rewards(rewards);
+        msg!(
+            "Updated epoch state with distributed fee group rewards: {} lamports",
+            rewards
+        );
+    }
+
+    Ok(())
+}
@@ -2,24 +2,32 @@ use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use ncn_program_core::{
     config::Config as NcnConfig,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
     error::NCNProgramError,
+    loaders::load_reward_token_account,
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    sysvar::Sysvar,
 };
 
 /// Can be backfilled for previous epochs
+///
+/// When `mint` is set, the operator's fee of that reward mint is sent to the operator's
+/// token account instead of SOL.
 pub fn process_distribute_operator_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(8));
     let [epoch_state, ncn_config, ncn, operator, operator_snapshot, operator_vault_reward_router, operator_vault_reward_receiver, system_program] =
-        accounts
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -54,6 +62,76 @@ pub fn process_distribute_operator_rewards(
         true,
     )?;
 
+    if let Some(mint) = mint {
+        let [token_program, operator_vault_reward_receiver_token_account, operator_token_account] =
+            optional_accounts
+        else {
+            msg!("Error: Token accounts are required to distribute mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        load_reward_token_account(
+            operator_vault_reward_receiver_token_account,
+            operator_vault_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+        load_reward_token_account(operator_token_account, operator.key, &mint, true)?;
+
+        let rewards = {
+            let mut operator_vault_reward_router_data =
+                operator_vault_reward_router.try_borrow_mut_data()?;
+            let operator_vault_reward_router_account =
+                OperatorVaultRewardRouter::try_from_slice_unchecked_mut(
+                    &mut operator_vault_reward_router_data,
+                )?;
+
+            if operator_vault_reward_router_account.still_routing_mint(&mint) {
+                msg!("Error: Rewards still routing, cannot distribute yet");
+                return Err(NCNProgramError::RouterStillRouting.into());
+            }
+
+            operator_vault_reward_router_account.distribute_operator_mint_rewards(&mint)?
+        };
+
+        if rewards > 0 {
+            msg!(
+                "Distributing {} of {} to operator {}",
+                rewards,
+                mint,
+                operator.key
+            );
+
+            OperatorVaultRewardReceiver::transfer_tokens(
+                program_id,
+                operator.key,
+                ncn.key,
+                epoch,
+                operator_vault_reward_receiver,
+                operator_vault_reward_receiver_token_account,
+                operator_token_account,
+                token_program,
+                rewards,
+            )?;
+
+            DistributionReceipt::new(
+                DistributionKind::Operator,
+                ncn.key,
+                epoch,
+                operator_token_account.key,
+                Some(&mint),
+                rewards,
+                Clock::get()?.slot,
+            )
+            .log();
+        } else {
+            msg!("No rewards to distribute (0 of {})", mint);
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
     // Get rewards and update state
     let rewards = {
         let mut operator_vault_reward_router_data =
@@ -111,6 +189,17 @@ pub fn process_distribute_operator_rewards(
             rewards,
             operator.key
         );
+
+        DistributionReceipt::new(
+            DistributionKind::Operator,
+            ncn.key,
+            epoch,
+            operator.key,
+            None,
+            rewards,
+            Clock::get()?.slot,
+        )
+        .log();
     } else {
         msg!("No rewards to distribute (0 lamports)");
     }
@@ -3,24 +3,31 @@ use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use ncn_program_core::{
     config::Config as NcnConfig,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
     epoch_state::EpochState,
     error::NCNProgramError,
+    loaders::load_reward_token_account,
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 /// Can be backfilled for previous epochs
+///
+/// When `mint` is set, the operator's rewards of that reward mint are sent to the
+/// operator vault reward receiver's token account instead of SOL.
 pub fn process_distribute_operator_vault_reward_route(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(9));
     let [epoch_state, ncn_config, ncn, operator, ncn_reward_router, ncn_reward_receiver, operator_vault_reward_router, operator_vault_reward_receiver, system_program] =
-        accounts
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -51,6 +58,76 @@ pub fn process_distribute_operator_vault_reward_route(
 
     load_system_program(system_program)?;
 
+    if let Some(mint) = mint {
+        let [token_program, ncn_reward_receiver_token_account, operator_vault_reward_receiver_token_account] =
+            optional_accounts
+        else {
+            msg!("Error: Token accounts are required to distribute mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        load_reward_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+        load_reward_token_account(
+            operator_vault_reward_receiver_token_account,
+            operator_vault_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+
+        let rewards = {
+            let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+            let ncn_reward_router_account =
+                NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+            if ncn_reward_router_account.still_routing_mint(&mint) {
+                return Err(NCNProgramError::RouterStillRouting.into());
+            }
+
+            ncn_reward_router_account
+                .distribute_operator_vault_mint_reward_route(&mint, operator.key)?
+        };
+
+        if rewards > 0 {
+            msg!(
+                "Distributing {} of {} to operator vault reward receiver",
+                rewards,
+                mint
+            );
+
+            NCNRewardReceiver::transfer_tokens(
+                program_id,
+                ncn.key,
+                epoch,
+                ncn_reward_receiver,
+                ncn_reward_receiver_token_account,
+                operator_vault_reward_receiver_token_account,
+                token_program,
+                rewards,
+            )?;
+
+            DistributionReceipt::new(
+                DistributionKind::OperatorVaultRoute,
+                ncn.key,
+                epoch,
+                operator_vault_reward_receiver_token_account.key,
+                Some(&mint),
+                rewards,
+                Clock::get()?.slot,
+            )
+            .log();
+        } else {
+            msg!("No rewards to distribute (0 of {})", mint);
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
     // Get rewards and update state
     let rewards = {
         let mut epoch_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
@@ -97,6 +174,17 @@ pub fn process_distribute_operator_vault_reward_route(
                 .collect::<Vec<&[u8]>>()
                 .as_slice()],
         )?;
+
+        DistributionReceipt::new(
+            DistributionKind::OperatorVaultRoute,
+            ncn.key,
+            epoch,
+            operator_vault_reward_receiver.key,
+            None,
+            rewards,
+            Clock::get()?.slot,
+        )
+        .log();
     } else {
         msg!("No rewards to distribute (0 lamports)");
     }
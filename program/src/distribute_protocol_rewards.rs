@@ -2,22 +2,28 @@ use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
     config::Config,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
     epoch_state::EpochState,
     error::NCNProgramError,
+    loaders::load_reward_token_account,
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    sysvar::Sysvar,
 };
 
+/// Distributes SOL, or the rewards of the given reward mint when `mint` is set
 pub fn process_distribute_protocol_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(7));
     let [epoch_state, ncn_config, ncn, ncn_reward_router, ncn_reward_receiver, protocol_fee_wallet, system_program] =
-        accounts
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -40,6 +46,76 @@ pub fn process_distribute_protocol_rewards(
         }
     }
 
+    if let Some(mint) = mint {
+        let [token_program, ncn_reward_receiver_token_account, protocol_fee_wallet_token_account] =
+            optional_accounts
+        else {
+            msg!("Error: Token accounts are required to distribute mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        load_reward_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+        load_reward_token_account(
+            protocol_fee_wallet_token_account,
+            protocol_fee_wallet.key,
+            &mint,
+            true,
+        )?;
+
+        let rewards = {
+            let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+            let ncn_reward_router_account =
+                NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+            if ncn_reward_router_account.still_routing_mint(&mint) {
+                msg!("Error: Rewards still routing, cannot distribute yet");
+                return Err(NCNProgramError::RouterStillRouting.into());
+            }
+
+            ncn_reward_router_account.distribute_protocol_fee_mint_rewards(&mint)?
+        };
+
+        if rewards > 0 {
+            msg!(
+                "Distributing {} of {} to Protocol fee wallet",
+                rewards,
+                mint
+            );
+
+            NCNRewardReceiver::transfer_tokens(
+                program_id,
+                ncn.key,
+                epoch,
+                ncn_reward_receiver,
+                ncn_reward_receiver_token_account,
+                protocol_fee_wallet_token_account,
+                token_program,
+                rewards,
+            )?;
+
+            DistributionReceipt::new(
+                DistributionKind::Protocol,
+                ncn.key,
+                epoch,
+                protocol_fee_wallet_token_account.key,
+                Some(&mint),
+                rewards,
+                Clock::get()?.slot,
+            )
+            .log();
+        } else {
+            msg!("No rewards to distribute (0 of {})", mint);
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
     // Get rewards and update state
     let rewards = {
         let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
@@ -83,6 +159,17 @@ pub fn process_distribute_protocol_rewards(
             "Successfully transferred {} lamports to Protocol fee wallet",
             rewards
         );
+
+        DistributionReceipt::new(
+            DistributionKind::Protocol,
+            ncn.key,
+            epoch,
+            protocol_fee_wallet.key,
+            None,
+            rewards,
+            Clock::get()?.slot,
+        )
+        .log();
     } else {
         msg!("No rewards to distribute (0 lamports)");
     }
@@ -3,24 +3,46 @@ use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_vault_core::vault::Vault;
 use ncn_program_core::{
     config::Config as NcnConfig,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
     error::NCNProgramError,
+    loaders::load_reward_token_account,
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
+    vault_registry::VaultRegistry,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey, system_instruction,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::Sysvar,
 };
 
+use crate::admin_set_vault_reward_recipient::check_vault_reward_recipient;
+
 /// Can be backfilled for previous epochs
+///
+/// Rewards are sent to the vault unless a reward recipient has been set for it in the
+/// vault registry. Wrapped SOL recipients are synced after the transfer.
+///
+/// When `mint` is set, the vault's rewards of that reward mint are sent to the token account
+/// of the reward recipient instead of SOL. For wrapped SOL recipients the token account of
+/// their owner is used.
 pub fn process_distribute_vault_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, operator, vault, operator_snapshot, operator_vault_reward_router, operator_vault_reward_receiver, system_program] =
-        accounts
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(12));
+    let [epoch_state, ncn_config, ncn, operator, vault, operator_snapshot, operator_vault_reward_router, operator_vault_reward_receiver, system_program, vault_registry, vault_reward_recipient, token_program] =
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -56,6 +78,119 @@ pub fn process_distribute_vault_rewards(
         true,
     )?;
 
+    VaultRegistry::load(program_id, vault_registry, ncn.key, false)?;
+
+    let expected_reward_recipient = {
+        let vault_registry_data = vault_registry.try_borrow_data()?;
+        let vault_registry_account = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
+
+        *vault_registry_account
+            .get_vault_entry(vault.key)?
+            .reward_destination()
+    };
+
+    if vault_reward_recipient.key.ne(&expected_reward_recipient) {
+        msg!("Error: Vault reward recipient does not match the vault registry");
+        return Err(NCNProgramError::DestinationMismatch.into());
+    }
+
+    if let Some(mint) = mint {
+        let [operator_vault_reward_receiver_token_account, vault_reward_recipient_token_account] =
+            optional_accounts
+        else {
+            msg!("Error: Token accounts are required to distribute mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Wrapped SOL recipients cannot hold other mints, their owner receives the tokens
+        let reward_token_owner = if vault_reward_recipient.owner.eq(&spl_token::id()) {
+            let recipient_data = vault_reward_recipient.try_borrow_data()?;
+            spl_token::state::Account::unpack(&recipient_data)?.owner
+        } else {
+            *vault_reward_recipient.key
+        };
+
+        load_reward_token_account(
+            operator_vault_reward_receiver_token_account,
+            operator_vault_reward_receiver.key,
+            &mint,
+            true,
+        )?;
+        load_reward_token_account(
+            vault_reward_recipient_token_account,
+            &reward_token_owner,
+            &mint,
+            true,
+        )?;
+
+        let rewards = {
+            let mut operator_vault_reward_router_data =
+                operator_vault_reward_router.try_borrow_mut_data()?;
+            let operator_vault_reward_router_account =
+                OperatorVaultRewardRouter::try_from_slice_unchecked_mut(
+                    &mut operator_vault_reward_router_data,
+                )?;
+
+            if operator_vault_reward_router_account.still_routing_mint(&mint) {
+                msg!("Error: Rewards still routing, cannot distribute yet");
+                return Err(NCNProgramError::RouterStillRouting.into());
+            }
+
+            operator_vault_reward_router_account
+                .distribute_vault_mint_reward_route(&mint, vault.key)?
+        };
+
+        if rewards > 0 {
+            msg!(
+                "Transferring {} of {} from operator vault reward receiver to {}",
+                rewards,
+                mint,
+                vault_reward_recipient_token_account.key
+            );
+
+            OperatorVaultRewardReceiver::transfer_tokens(
+                program_id,
+                operator.key,
+                ncn.key,
+                epoch,
+                operator_vault_reward_receiver,
+                operator_vault_reward_receiver_token_account,
+                vault_reward_recipient_token_account,
+                token_program,
+                rewards,
+            )?;
+
+            DistributionReceipt::new(
+                DistributionKind::Vault,
+                ncn.key,
+                epoch,
+                vault_reward_recipient_token_account.key,
+                Some(&mint),
+                rewards,
+                Clock::get()?.slot,
+            )
+            .log();
+        } else {
+            msg!("No rewards to distribute (0 of {})", mint);
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
+    // Wrapped SOL recipients need to be synced once the lamports land
+    let sync_native = if vault_reward_recipient.key.ne(vault.key) {
+        check_vault_reward_recipient(vault_reward_recipient)?;
+        vault_reward_recipient.owner.eq(&spl_token::id())
+    } else {
+        false
+    };
+
+    if sync_native && token_program.key.ne(&spl_token::id()) {
+        msg!("Error: Incorrect token program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
     // Get rewards and update state
     let rewards = {
         let mut operator_vault_reward_router_data =
@@ -75,8 +210,9 @@ pub fn process_distribute_vault_rewards(
 
     if rewards > 0 {
         msg!(
-            "Transferring {} lamports from operator vault reward receiver to vault",
-            rewards
+            "Transferring {} lamports from operator vault reward receiver to {}",
+            rewards,
+            vault_reward_recipient.key
         );
 
         let (_, operator_vault_reward_receiver_bump, mut operator_vault_reward_receiver_seeds) =
@@ -89,15 +225,18 @@ pub fn process_distribute_vault_rewards(
 
         operator_vault_reward_receiver_seeds.push(vec![operator_vault_reward_receiver_bump]);
 
-        // Transfer rewards from receiver to NCN fee wallet
-        let transfer_instruction =
-            system_instruction::transfer(operator_vault_reward_receiver.key, vault.key, rewards);
+        // Transfer rewards from receiver to the vault or its reward recipient
+        let transfer_instruction = system_instruction::transfer(
+            operator_vault_reward_receiver.key,
+            vault_reward_recipient.key,
+            rewards,
+        );
 
         invoke_signed(
             &transfer_instruction,
             &[
                 operator_vault_reward_receiver.clone(),
-                vault.clone(),
+                vault_reward_recipient.clone(),
                 system_program.clone(),
             ],
             &[operator_vault_reward_receiver_seeds
@@ -106,6 +245,27 @@ pub fn process_distribute_vault_rewards(
                 .collect::<Vec<&[u8]>>()
                 .as_slice()],
         )?;
+
+        if sync_native {
+            let sync_native_instruction =
+                spl_token::instruction::sync_native(token_program.key, vault_reward_recipient.key)?;
+
+            invoke(
+                &sync_native_instruction,
+                &[vault_reward_recipient.clone(), token_program.clone()],
+            )?;
+        }
+
+        DistributionReceipt::new(
+            DistributionKind::Vault,
+            ncn.key,
+            epoch,
+            vault_reward_recipient.key,
+            None,
+            rewards,
+            Clock::get()?.slot,
+        )
+        .log();
     } else {
         msg!("No rewards to distribute (0 lamports)");
     }
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    consensus_result::ConsensusResult,
+    views::{ConsensusResultView, ReturnData},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Returns the consensus result of the epoch as a `ConsensusResultView` in the return data, before
+/// consensus too, with `consensus_reached` unset.
+///
+/// ### Parameters:
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[]` consensus_result: The consensus result account of the epoch
+pub fn process_get_consensus_result(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn, consensus_result] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    ConsensusResult::load(program_id, consensus_result, ncn.key, epoch, false)?;
+
+    let consensus_result_data = consensus_result.try_borrow_data()?;
+    let consensus_result = ConsensusResult::try_from_slice_unchecked(&consensus_result_data)?;
+
+    ConsensusResultView::new(consensus_result).set_return_data();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    ncn_reward_router::NCNRewardRouter,
+    views::{OperatorRewardRouteView, ReturnData},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Returns the route of the operator in the NCN reward router as an `OperatorRewardRouteView` in
+/// the return data, along with whether routing still has to be resumed.
+///
+/// ### Parameters:
+/// - `operator`: The operator of the route
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[]` ncn_reward_router: The NCN reward router of the epoch
+pub fn process_get_operator_reward_route(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    operator: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn, ncn_reward_router] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NCNRewardRouter::load(program_id, ncn_reward_router, ncn.key, epoch, false)?;
+
+    let ncn_reward_router_data = ncn_reward_router.try_borrow_data()?;
+    let ncn_reward_router = NCNRewardRouter::try_from_slice_unchecked(&ncn_reward_router_data)?;
+
+    OperatorRewardRouteView::new(ncn_reward_router, operator).set_return_data();
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    views::{ReturnData, WinningBallotView},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Returns the winning ballot of the ballot box as a `WinningBallotView` in the return data, so
+/// callers do not have to deserialize the ballot box. Before consensus `has_winning_ballot` is
+/// unset.
+///
+/// ### Parameters:
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[]` ballot_box: The ballot box of the epoch
+pub fn process_get_winning_ballot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn, ballot_box] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
+
+    let ballot_box_data = ballot_box.try_borrow_data()?;
+    let ballot_box = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+
+    WinningBallotView::new(ballot_box).set_return_data();
+
+    Ok(())
+}
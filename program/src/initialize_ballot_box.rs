@@ -4,7 +4,7 @@ use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
     account_payer::AccountPayer, ballot_box::BallotBox, config::Config as NcnConfig,
     consensus_result::ConsensusResult, constants::MAX_REALLOC_BYTES, epoch_marker::EpochMarker,
-    epoch_state::EpochState,
+    epoch_state::EpochState, loaders::check_already_initialized,
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -18,6 +18,7 @@ use solana_program::{
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
+/// - `init_if_needed`: Succeed without changes if the ballot box is already initialized
 ///
 /// ### Accounts:
 /// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
@@ -31,6 +32,7 @@ pub fn process_initialize_ballot_box(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let epoch_marker = next_account_info(account_info_iter)?;
@@ -42,6 +44,19 @@ pub fn process_initialize_ballot_box(
     let system_program = next_account_info(account_info_iter)?;
     let consensus_result = next_account_info(account_info_iter)?;
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            ballot_box,
+            &BallotBox::find_program_address(program_id, ncn.key, epoch).0,
+            BallotBox::DISCRIMINATOR,
+            true,
+        )?
+    {
+        msg!("Ballot box already initialized");
+        return Ok(());
+    }
+
     load_system_account(ballot_box, true)?;
     load_system_program(system_program)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    initialize_ballot_box::process_initialize_ballot_box,
+    initialize_epoch_state::process_initialize_epoch_state,
+    initialize_ncn_reward_router::process_initialize_ncn_reward_router,
+    initialize_weight_table::process_initialize_weight_table,
+};
+
+/// Initializes the epoch state, weight table, ballot box and NCN reward router of an epoch in a
+/// single call. Every account is created the same way as by its own initialize instruction, the
+/// weight table, ballot box and NCN reward router still have to be reallocated afterwards.
+///
+/// The epoch snapshot is not part of the bootstrap, it can only be created once the weight table
+/// is finalized.
+///
+/// ### Parameters:
+/// - `epoch`: The target epoch
+///
+/// ### Accounts:
+/// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
+/// 2. `[writable]` epoch_state: The epoch state account to initialize
+/// 3. `[]` config: NCN configuration account
+/// 4. `[]` vault_registry: The vault registry of the NCN
+/// 5. `[]` ncn: The NCN account
+/// 6. `[writable]` weight_table: The weight table account to initialize
+/// 7. `[writable]` ballot_box: The ballot box account to initialize
+/// 8. `[writable]` consensus_result: The consensus result account, created if it does not exist
+/// 9. `[writable]` ncn_reward_router: The NCN reward router account to initialize
+/// 10. `[writable]` ncn_reward_receiver: The NCN reward receiver, funded with its minimum rent
+/// 11. `[writable]` account_payer: Account paying for initialization
+/// 12. `[]` system_program: Solana System Program
+pub fn process_initialize_epoch_accounts(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, config, vault_registry, ncn, weight_table, ballot_box, consensus_result, ncn_reward_router, ncn_reward_receiver, account_payer, system_program] =
+        accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    msg!("Initializing epoch state");
+    process_initialize_epoch_state(
+        program_id,
+        &[
+            epoch_marker.clone(),
+            epoch_state.clone(),
+            config.clone(),
+            ncn.clone(),
+            account_payer.clone(),
+            system_program.clone(),
+        ],
+        epoch,
+        false,
+    )?;
+
+    msg!("Initializing weight table");
+    process_initialize_weight_table(
+        program_id,
+        &[
+            epoch_marker.clone(),
+            epoch_state.clone(),
+            vault_registry.clone(),
+            ncn.clone(),
+            weight_table.clone(),
+            account_payer.clone(),
+            system_program.clone(),
+        ],
+        epoch,
+        false,
+    )?;
+
+    msg!("Initializing ballot box");
+    process_initialize_ballot_box(
+        program_id,
+        &[
+            epoch_marker.clone(),
+            epoch_state.clone(),
+            config.clone(),
+            ballot_box.clone(),
+            ncn.clone(),
+            account_payer.clone(),
+            system_program.clone(),
+            consensus_result.clone(),
+        ],
+        epoch,
+        false,
+    )?;
+
+    msg!("Initializing NCN reward router");
+    process_initialize_ncn_reward_router(
+        program_id,
+        &[
+            epoch_marker.clone(),
+            epoch_state.clone(),
+            ncn.clone(),
+            ncn_reward_router.clone(),
+            ncn_reward_receiver.clone(),
+            account_payer.clone(),
+            system_program.clone(),
+        ],
+        epoch,
+        false,
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,17 @@
 use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
+use jito_vault_core::vault::Vault;
 use ncn_program_core::{
-    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker,
-    epoch_snapshot::EpochSnapshot, epoch_state::EpochState, error::NCNProgramError, fees::Fees,
+    account_payer::AccountPayer,
+    config::Config,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    fees::Fees,
+    loaders::check_already_initialized,
+    stake_weight::U256,
     weight_table::WeightTable,
 };
 use solana_program::{
@@ -15,6 +23,7 @@ use solana_program::{
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
+/// - `init_if_needed`: Succeed without changes if the epoch snapshot is already initialized
 ///
 /// ### Accounts:
 /// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
@@ -25,13 +34,18 @@ use solana_program::{
 /// 6. `[writable]` epoch_snapshot: The epoch snapshot account to initialize
 /// 7. `[writable, signer]` account_payer: Account paying for initialization
 /// 8. `[]` system_program: Solana System Program
+///
+/// Followed by the registered vaults, in registry order, when the vault stake weight cap is
+/// enabled. The cap is a share of the stake weight of all of their delegated security.
 pub fn process_initialize_epoch_snapshot(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
+    let (required_accounts, vaults) = accounts.split_at(accounts.len().min(8));
     let [epoch_marker, epoch_state, config, ncn, weight_table, epoch_snapshot, account_payer, system_program] =
-        accounts
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -43,6 +57,19 @@ pub fn process_initialize_epoch_snapshot(
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
     EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            epoch_snapshot,
+            &EpochSnapshot::find_program_address(program_id, ncn.key, epoch).0,
+            EpochSnapshot::DISCRIMINATOR,
+            false,
+        )?
+    {
+        msg!("Epoch snapshot already initialized");
+        return Ok(());
+    }
+
     load_system_account(epoch_snapshot, true)?;
     load_system_program(system_program)?;
 
@@ -65,6 +92,52 @@ pub fn process_initialize_epoch_snapshot(
         count
     };
 
+    let max_vault_stake_weight_bps = {
+        let ncn_config_data = config.data.borrow();
+        let ncn_config_account = Config::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config_account.max_vault_stake_weight_bps()
+    };
+
+    let mut total_vault_stake_weight = U256::zero();
+    if max_vault_stake_weight_bps > 0 {
+        let weight_table_data = weight_table.data.borrow();
+        let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
+
+        let vault_entries = weight_table_account.vault_entries();
+        if vaults.len() != vault_entries.len() {
+            msg!(
+                "Error: Expected {} vaults for the stake weight cap, got {}",
+                vault_entries.len(),
+                vaults.len()
+            );
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        for (vault, vault_entry) in vaults.iter().zip(vault_entries.iter()) {
+            if vault.key.ne(vault_entry.vault()) {
+                msg!(
+                    "Error: Expected vault {}, got {}",
+                    vault_entry.vault(),
+                    vault.key
+                );
+                return Err(NCNProgramError::VaultNotInRegistry.into());
+            }
+            Vault::load(&jito_vault_program::id(), vault, false)?;
+
+            let vault_data = vault.data.borrow();
+            let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+            let vault_stake_weight = OperatorSnapshot::calculate_stake_weight(
+                vault_account.delegation_state.total_security()?,
+                weight_table_account,
+                vault_entry.st_mint(),
+            )?;
+
+            total_vault_stake_weight = total_vault_stake_weight
+                .checked_add(vault_stake_weight)
+                .ok_or(NCNProgramError::ArithmeticOverflow)?;
+        }
+    }
+
     let (epoch_snapshot_pubkey, epoch_snapshot_bump, mut epoch_snapshot_seeds) =
         EpochSnapshot::find_program_address(program_id, ncn.key, ncn_epoch);
     epoch_snapshot_seeds.push(vec![epoch_snapshot_bump]);
@@ -125,6 +198,15 @@ pub fn process_initialize_epoch_snapshot(
         ncn_fees,
     );
 
+    if max_vault_stake_weight_bps > 0 {
+        epoch_snapshot_account
+            .set_max_vault_stake_weight(total_vault_stake_weight, max_vault_stake_weight_bps)?;
+        msg!(
+            "Capping vault stake weight at {}",
+            epoch_snapshot_account.max_vault_stake_weight()
+        );
+    }
+
     {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
@@ -2,7 +2,8 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
-    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker, epoch_state::EpochState,
+    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker,
+    epoch_state::EpochState, loaders::check_already_initialized,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -13,6 +14,7 @@ use solana_program::{
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
+/// - `init_if_needed`: Succeed without changes if the epoch state is already initialized
 ///
 /// ### Accounts:
 /// 1. `[writable]` epoch_marker: Marker account to prevent duplicate initialization
@@ -24,6 +26,7 @@ pub fn process_initialize_epoch_state(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let [epoch_marker, epoch_state, config, ncn, account_payer, system_program] = accounts else {
         msg!("Error: Not enough account keys provided");
@@ -36,6 +39,19 @@ pub fn process_initialize_epoch_state(
         return Err(ProgramError::InvalidArgument);
     }
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            epoch_state,
+            &EpochState::find_program_address(program_id, ncn.key, epoch).0,
+            EpochState::DISCRIMINATOR,
+            false,
+        )?
+    {
+        msg!("Epoch state already initialized");
+        return Ok(());
+    }
+
     load_system_account(epoch_state, true)?;
     load_system_program(system_program)?;
 
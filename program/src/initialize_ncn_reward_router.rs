@@ -1,3 +1,4 @@
+use jito_bytemuck::Discriminator;
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
@@ -5,6 +6,7 @@ use ncn_program_core::{
     constants::MAX_REALLOC_BYTES,
     epoch_marker::EpochMarker,
     epoch_state::EpochState,
+    loaders::check_already_initialized,
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
 };
 use solana_program::{
@@ -17,6 +19,7 @@ pub fn process_initialize_ncn_reward_router(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let [epoch_marker, epoch_state, ncn, ncn_reward_router, ncn_reward_receiver, account_payer, system_program] =
         accounts
@@ -31,6 +34,19 @@ pub fn process_initialize_ncn_reward_router(
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
     EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            ncn_reward_router,
+            &NCNRewardRouter::find_program_address(program_id, ncn.key, epoch).0,
+            NCNRewardRouter::DISCRIMINATOR,
+            true,
+        )?
+    {
+        msg!("NCN reward router already initialized");
+        return Ok(());
+    }
+
     load_system_account(ncn_reward_router, true)?;
     load_system_program(system_program)?;
 
@@ -8,7 +8,7 @@ use ncn_program_core::{
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::NCNProgramError,
-    loaders::load_ncn_epoch,
+    loaders::{check_already_initialized, load_ncn_epoch},
     stake_weight::StakeWeights,
 };
 use solana_program::{
@@ -20,6 +20,7 @@ use solana_program::{
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
+/// - `init_if_needed`: Succeed without changes if the operator snapshot is already initialized
 ///
 /// ### Accounts:
 /// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
@@ -35,6 +36,7 @@ pub fn process_initialize_operator_snapshot(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let [epoch_marker, epoch_state, config, restaking_config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program] =
         accounts
@@ -46,6 +48,77 @@ pub fn process_initialize_operator_snapshot(
     EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, false)?;
     Config::load(program_id, config, ncn.key, false)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+    load_system_program(system_program)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            operator_snapshot,
+            &OperatorSnapshot::find_program_address(program_id, operator.key, ncn.key, epoch).0,
+            OperatorSnapshot::DISCRIMINATOR,
+            false,
+        )?
+    {
+        msg!("Operator snapshot already initialized");
+        return Ok(());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
+
+    let is_active = create_operator_snapshot(
+        program_id,
+        epoch,
+        epoch_state,
+        ncn,
+        operator,
+        ncn_operator_state,
+        epoch_snapshot,
+        operator_snapshot,
+        account_payer,
+        system_program,
+        current_slot,
+        ncn_epoch_length,
+    )?;
+
+    // Increment operator registration for an inactive operator
+    if !is_active {
+        let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
+        let epoch_snapshot_account =
+            EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+
+        epoch_snapshot_account.increment_operator_registration(
+            current_slot,
+            0,
+            &StakeWeights::default(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates and initializes the snapshot of a single operator and records it in the epoch state.
+///
+/// Returns whether the operator is active. Inactive operators are done once their snapshot
+/// exists, the caller still has to register them in the epoch snapshot.
+#[allow(clippy::too_many_arguments)]
+pub fn create_operator_snapshot<'a, 'info>(
+    program_id: &Pubkey,
+    epoch: u64,
+    epoch_state: &'a AccountInfo<'info>,
+    ncn: &'a AccountInfo<'info>,
+    operator: &'a AccountInfo<'info>,
+    ncn_operator_state: &'a AccountInfo<'info>,
+    epoch_snapshot: &'a AccountInfo<'info>,
+    operator_snapshot: &'a AccountInfo<'info>,
+    account_payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    current_slot: u64,
+    ncn_epoch_length: u64,
+) -> Result<bool, ProgramError> {
     Operator::load(&jito_restaking_program::id(), operator, false)?;
     NcnOperatorState::load(
         &jito_restaking_program::id(),
@@ -54,11 +127,7 @@ pub fn process_initialize_operator_snapshot(
         operator,
         false,
     )?;
-    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
     load_system_account(operator_snapshot, true)?;
-    load_system_program(system_program)?;
-    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
-    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
 
     let (operator_snapshot_pubkey, operator_snapshot_bump, mut operator_snapshot_seeds) =
         OperatorSnapshot::find_program_address(program_id, operator.key, ncn.key, epoch);
@@ -106,10 +175,6 @@ pub fn process_initialize_operator_snapshot(
         &operator_snapshot_seeds,
     )?;
 
-    let current_slot = Clock::get()?.slot;
-
-    let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
-
     let (is_active, ncn_operator_index): (bool, u64) = {
         let ncn_operator_state_data = ncn_operator_state.data.borrow();
         let ncn_operator_state_account =
@@ -153,34 +218,23 @@ pub fn process_initialize_operator_snapshot(
         operator_index
     );
 
-    let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
-    operator_snapshot_data[0] = OperatorSnapshot::DISCRIMINATOR;
-    let operator_snapshot_account =
-        OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
-
-    operator_snapshot_account.initialize(
-        operator.key,
-        ncn.key,
-        epoch,
-        operator_snapshot_bump,
-        current_slot,
-        is_active,
-        ncn_operator_index,
-        operator_index,
-        operator_fee_bps,
-        vault_count,
-    )?;
-
-    // Increment operator registration for an inactive operator
-    if !is_active {
-        let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
-        let epoch_snapshot_account =
-            EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
-
-        epoch_snapshot_account.increment_operator_registration(
+    {
+        let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+        operator_snapshot_data[0] = OperatorSnapshot::DISCRIMINATOR;
+        let operator_snapshot_account =
+            OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+
+        operator_snapshot_account.initialize(
+            operator.key,
+            ncn.key,
+            epoch,
+            operator_snapshot_bump,
             current_slot,
-            0,
-            &StakeWeights::default(),
+            is_active,
+            ncn_operator_index,
+            operator_index,
+            operator_fee_bps,
+            vault_count,
         )?;
     }
 
@@ -192,5 +246,5 @@ pub fn process_initialize_operator_snapshot(
             .update_realloc_operator_snapshot(ncn_operator_index as usize, is_active)?;
     }
 
-    Ok(())
+    Ok(is_active)
 }
@@ -6,6 +6,7 @@ use ncn_program_core::{
     epoch_marker::EpochMarker,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    loaders::check_already_initialized,
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
 };
 use solana_program::{
@@ -18,6 +19,7 @@ pub fn process_initialize_operator_vault_reward_router(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let [epoch_marker, epoch_state, ncn, operator, operator_snapshot, operator_vault_reward_router, operator_vault_reward_receiver, account_payer, system_program] =
         accounts
@@ -46,6 +48,25 @@ pub fn process_initialize_operator_vault_reward_router(
         true,
     )?;
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            operator_vault_reward_router,
+            &OperatorVaultRewardRouter::find_program_address(
+                program_id,
+                operator.key,
+                ncn.key,
+                epoch,
+            )
+            .0,
+            OperatorVaultRewardRouter::DISCRIMINATOR,
+            false,
+        )?
+    {
+        msg!("Operator vault reward router already initialized");
+        return Ok(());
+    }
+
     load_system_account(operator_vault_reward_router, true)?;
     load_system_program(system_program)?;
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
@@ -1,9 +1,10 @@
-use jito_bytemuck::AccountDeserialize;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use ncn_program_core::{
     account_payer::AccountPayer, constants::MAX_REALLOC_BYTES, epoch_marker::EpochMarker,
-    epoch_state::EpochState, vault_registry::VaultRegistry, weight_table::WeightTable,
+    epoch_state::EpochState, loaders::check_already_initialized, vault_registry::VaultRegistry,
+    weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -14,6 +15,7 @@ use solana_program::{
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
+/// - `init_if_needed`: Succeed without changes if the weight table is already initialized
 ///
 /// ### Accounts:
 /// 1. `[]` epoch_marker: Marker account to prevent duplicate initialization
@@ -27,6 +29,7 @@ pub fn process_initialize_weight_table(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
+    init_if_needed: bool,
 ) -> ProgramResult {
     let [epoch_marker, epoch_state, vault_registry, ncn, weight_table, account_payer, system_program] =
         accounts
@@ -41,6 +44,19 @@ pub fn process_initialize_weight_table(
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
     EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
 
+    if init_if_needed
+        && check_already_initialized(
+            program_id,
+            weight_table,
+            &WeightTable::find_program_address(program_id, ncn.key, epoch).0,
+            WeightTable::DISCRIMINATOR,
+            true,
+        )?
+    {
+        msg!("Weight table already initialized");
+        return Ok(());
+    }
+
     load_system_account(weight_table, true)?;
     load_system_program(system_program)?;
 
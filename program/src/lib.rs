@@ -1,18 +1,39 @@
+mod acquire_keeper_lease;
+mod admin_approval;
 mod admin_initialize_config;
 mod admin_register_st_mint;
+mod admin_set_admin_governance;
+mod admin_set_admin_multisig;
+mod admin_set_default_weight;
+mod admin_set_fee_group;
 mod admin_set_new_admin;
 mod admin_set_parameters;
+mod admin_set_reward_mint;
 mod admin_set_st_mint;
 mod admin_set_tie_breaker;
+mod admin_set_vault_metadata;
+mod admin_set_vault_reward_recipient;
 mod admin_set_weight;
+mod admin_set_weights;
+mod apply_parameters;
+mod batch_initialize_operator_snapshot;
 mod cast_vote;
+mod close_all_epoch_accounts;
 mod close_epoch_account;
+mod close_reward_receiver;
+mod copy_previous_epoch_weights;
+mod cranker_fee;
+mod distribute_fee_group_rewards;
 mod distribute_ncn_rewards;
 mod distribute_operator_rewards;
 mod distribute_operator_vault_reward_route;
 mod distribute_protocol_rewards;
 mod distribute_vault_rewards;
+mod get_consensus_result;
+mod get_operator_reward_route;
+mod get_winning_ballot;
 mod initialize_ballot_box;
+mod initialize_epoch_accounts;
 mod initialize_epoch_snapshot;
 mod initialize_epoch_state;
 mod initialize_ncn_reward_router;
@@ -20,14 +41,19 @@ mod initialize_operator_snapshot;
 mod initialize_operator_vault_reward_router;
 mod initialize_vault_registry;
 mod initialize_weight_table;
+mod mark_epoch_skipped;
 mod realloc_ballot_box;
 mod realloc_ncn_reward_router;
 mod realloc_vault_registry;
 mod realloc_weight_table;
 mod register_vault;
+mod route_and_distribute_ncn_rewards;
 mod route_ncn_rewards;
 mod route_operator_vault_rewards;
 mod set_epoch_weights;
+mod set_operator_metadata;
+mod set_weight_from_switchboard;
+mod shrink_ncn_reward_router;
 mod snapshot_vault_operator_delegation;
 
 use admin_set_new_admin::process_admin_set_new_admin;
@@ -42,32 +68,54 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
+    acquire_keeper_lease::process_acquire_keeper_lease,
     admin_initialize_config::process_admin_initialize_config,
     admin_register_st_mint::process_admin_register_st_mint,
+    admin_set_admin_governance::process_admin_set_admin_governance,
+    admin_set_admin_multisig::process_admin_set_admin_multisig,
+    admin_set_default_weight::process_admin_set_default_weight,
+    admin_set_fee_group::process_admin_set_fee_group,
     admin_set_parameters::process_admin_set_parameters,
+    admin_set_reward_mint::process_admin_set_reward_mint,
     admin_set_st_mint::process_admin_set_st_mint,
     admin_set_tie_breaker::process_admin_set_tie_breaker,
-    admin_set_weight::process_admin_set_weight, cast_vote::process_cast_vote,
+    admin_set_vault_metadata::process_admin_set_vault_metadata,
+    admin_set_vault_reward_recipient::process_admin_set_vault_reward_recipient,
+    admin_set_weight::process_admin_set_weight, admin_set_weights::process_admin_set_weights,
+    apply_parameters::process_apply_parameters,
+    batch_initialize_operator_snapshot::process_batch_initialize_operator_snapshot,
+    cast_vote::process_cast_vote, close_all_epoch_accounts::process_close_all_epoch_accounts,
     close_epoch_account::process_close_epoch_account,
+    close_reward_receiver::process_close_reward_receiver,
+    copy_previous_epoch_weights::process_copy_previous_epoch_weights,
+    distribute_fee_group_rewards::process_distribute_fee_group_rewards,
     distribute_ncn_rewards::process_distribute_ncn_rewards,
     distribute_operator_rewards::process_distribute_operator_rewards,
     distribute_operator_vault_reward_route::process_distribute_operator_vault_reward_route,
     distribute_protocol_rewards::process_distribute_protocol_rewards,
     distribute_vault_rewards::process_distribute_vault_rewards,
+    get_consensus_result::process_get_consensus_result,
+    get_operator_reward_route::process_get_operator_reward_route,
+    get_winning_ballot::process_get_winning_ballot,
     initialize_ballot_box::process_initialize_ballot_box,
+    initialize_epoch_accounts::process_initialize_epoch_accounts,
     initialize_epoch_snapshot::process_initialize_epoch_snapshot,
     initialize_ncn_reward_router::process_initialize_ncn_reward_router,
     initialize_operator_snapshot::process_initialize_operator_snapshot,
     initialize_operator_vault_reward_router::process_initialize_operator_vault_reward_router,
     initialize_vault_registry::process_initialize_vault_registry,
     initialize_weight_table::process_initialize_weight_table,
-    realloc_ballot_box::process_realloc_ballot_box,
+    mark_epoch_skipped::process_mark_epoch_skipped, realloc_ballot_box::process_realloc_ballot_box,
     realloc_ncn_reward_router::process_realloc_ncn_reward_router,
     realloc_vault_registry::process_realloc_vault_registry,
     realloc_weight_table::process_realloc_weight_table, register_vault::process_register_vault,
+    route_and_distribute_ncn_rewards::process_route_and_distribute_ncn_rewards,
     route_ncn_rewards::process_route_ncn_rewards,
     route_operator_vault_rewards::process_route_operator_vault_rewards,
     set_epoch_weights::process_set_epoch_weights,
+    set_operator_metadata::process_set_operator_metadata,
+    set_weight_from_switchboard::process_set_weight_from_switchboard,
+    shrink_ncn_reward_router::process_shrink_ncn_reward_router,
     snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
 };
 
@@ -131,17 +179,35 @@ pub fn process_instruction(
             msg!("Instruction: RegisterVault");
             process_register_vault(program_id, accounts)
         }
+        NCNProgramInstruction::SetOperatorMetadata {
+            name,
+            website,
+            contact_hash,
+        } => {
+            msg!("Instruction: SetOperatorMetadata");
+            process_set_operator_metadata(program_id, accounts, &name, &website, &contact_hash)
+        }
 
         // ---------------------------------------------------- //
         //                       SNAPSHOT                       //
         // ---------------------------------------------------- //
-        NCNProgramInstruction::InitializeEpochState { epoch } => {
+        NCNProgramInstruction::InitializeEpochState {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeEpochState");
-            process_initialize_epoch_state(program_id, accounts, epoch)
+            process_initialize_epoch_state(program_id, accounts, epoch, init_if_needed)
         }
-        NCNProgramInstruction::InitializeWeightTable { epoch } => {
+        NCNProgramInstruction::MarkEpochSkipped { epoch } => {
+            msg!("Instruction: MarkEpochSkipped");
+            process_mark_epoch_skipped(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::InitializeWeightTable {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeWeightTable");
-            process_initialize_weight_table(program_id, accounts, epoch)
+            process_initialize_weight_table(program_id, accounts, epoch, init_if_needed)
         }
         NCNProgramInstruction::ReallocWeightTable { epoch } => {
             msg!("Instruction: ReallocWeightTable");
@@ -151,13 +217,39 @@ pub fn process_instruction(
             msg!("Instruction: SetEpochWeights");
             process_set_epoch_weights(program_id, accounts, epoch)
         }
-        NCNProgramInstruction::InitializeEpochSnapshot { epoch } => {
+        NCNProgramInstruction::SetWeightFromSwitchboard { st_mint, epoch } => {
+            msg!("Instruction: SetWeightFromSwitchboard");
+            process_set_weight_from_switchboard(program_id, accounts, &st_mint, epoch)
+        }
+        NCNProgramInstruction::CopyPreviousEpochWeights { epoch } => {
+            msg!("Instruction: CopyPreviousEpochWeights");
+            process_copy_previous_epoch_weights(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::InitializeEpochSnapshot {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeEpochSnapshot");
-            process_initialize_epoch_snapshot(program_id, accounts, epoch)
+            process_initialize_epoch_snapshot(program_id, accounts, epoch, init_if_needed)
         }
-        NCNProgramInstruction::InitializeOperatorSnapshot { epoch } => {
+        NCNProgramInstruction::InitializeOperatorSnapshot {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeOperatorSnapshot");
-            process_initialize_operator_snapshot(program_id, accounts, epoch)
+            process_initialize_operator_snapshot(program_id, accounts, epoch, init_if_needed)
+        }
+        NCNProgramInstruction::BatchInitializeOperatorSnapshot { epoch } => {
+            msg!("Instruction: BatchInitializeOperatorSnapshot");
+            process_batch_initialize_operator_snapshot(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::InitializeEpochAccounts { epoch } => {
+            msg!("Instruction: InitializeEpochAccounts");
+            process_initialize_epoch_accounts(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::CloseAllEpochAccounts { epoch } => {
+            msg!("Instruction: CloseAllEpochAccounts");
+            process_close_all_epoch_accounts(program_id, accounts, epoch)
         }
         NCNProgramInstruction::SnapshotVaultOperatorDelegation { epoch } => {
             msg!("Instruction: SnapshotVaultOperatorDelegation");
@@ -167,9 +259,12 @@ pub fn process_instruction(
         // ---------------------------------------------------- //
         //                         VOTE                         //
         // ---------------------------------------------------- //
-        NCNProgramInstruction::InitializeBallotBox { epoch } => {
+        NCNProgramInstruction::InitializeBallotBox {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeBallotBox");
-            process_initialize_ballot_box(program_id, accounts, epoch)
+            process_initialize_ballot_box(program_id, accounts, epoch, init_if_needed)
         }
         NCNProgramInstruction::ReallocBallotBox { epoch } => {
             msg!("Instruction: ReallocBallotBox");
@@ -199,6 +294,14 @@ pub fn process_instruction(
             epochs_before_stall,
             epochs_after_consensus_before_close,
             valid_slots_after_consensus,
+            cranker_fee_bps,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+            weight_decay_epochs,
+            weight_decay_bps,
+            max_vault_stake_weight_bps,
+            permissionless_vault_registration,
+            parameters_timelock_slots,
         } => {
             msg!("Instruction: AdminSetParameters");
             process_admin_set_parameters(
@@ -208,12 +311,31 @@ pub fn process_instruction(
                 epochs_before_stall,
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
+                cranker_fee_bps,
+                timeliness_bonus_slots,
+                timeliness_bonus_bps,
+                weight_decay_epochs,
+                weight_decay_bps,
+                max_vault_stake_weight_bps,
+                permissionless_vault_registration,
+                parameters_timelock_slots,
             )
         }
+        NCNProgramInstruction::ApplyParameters => {
+            msg!("Instruction: ApplyParameters");
+            process_apply_parameters(program_id, accounts)
+        }
         NCNProgramInstruction::AdminSetNewAdmin { role } => {
             msg!("Instruction: AdminSetNewAdmin");
             process_admin_set_new_admin(program_id, accounts, role)
         }
+        NCNProgramInstruction::AdminSetAdminMultisig {
+            approvers,
+            threshold,
+        } => {
+            msg!("Instruction: AdminSetAdminMultisig");
+            process_admin_set_admin_multisig(program_id, accounts, &approvers, threshold)
+        }
         NCNProgramInstruction::AdminSetTieBreaker {
             weather_status,
             epoch,
@@ -229,21 +351,63 @@ pub fn process_instruction(
             msg!("Instruction: AdminSetWeight");
             process_admin_set_weight(program_id, accounts, &st_mint, epoch, weight)
         }
+        NCNProgramInstruction::AdminSetWeights { weights, epoch } => {
+            msg!("Instruction: AdminSetWeights");
+            process_admin_set_weights(program_id, accounts, &weights, epoch)
+        }
         NCNProgramInstruction::AdminRegisterStMint { weight } => {
             msg!("Instruction: AdminRegisterStMint");
             process_admin_register_st_mint(program_id, accounts, weight)
         }
-        NCNProgramInstruction::AdminSetStMint { st_mint, weight } => {
+        NCNProgramInstruction::AdminSetStMint {
+            st_mint,
+            weight,
+            switchboard_feed,
+        } => {
             msg!("Instruction: AdminSetStMint");
-            process_admin_set_st_mint(program_id, accounts, &st_mint, weight)
+            process_admin_set_st_mint(program_id, accounts, &st_mint, weight, switchboard_feed)
+        }
+        NCNProgramInstruction::AdminSetDefaultWeight { default_weight } => {
+            msg!("Instruction: AdminSetDefaultWeight");
+            process_admin_set_default_weight(program_id, accounts, default_weight)
+        }
+        NCNProgramInstruction::AdminSetVaultMetadata { name, uri_hash } => {
+            msg!("Instruction: AdminSetVaultMetadata");
+            process_admin_set_vault_metadata(program_id, accounts, &name, &uri_hash)
+        }
+        NCNProgramInstruction::AdminSetVaultRewardRecipient => {
+            msg!("Instruction: AdminSetVaultRewardRecipient");
+            process_admin_set_vault_reward_recipient(program_id, accounts)
+        }
+        NCNProgramInstruction::AdminSetRewardMint { index, mint } => {
+            msg!("Instruction: AdminSetRewardMint");
+            process_admin_set_reward_mint(program_id, accounts, index, &mint)
+        }
+        NCNProgramInstruction::AdminSetFeeGroup {
+            group,
+            name,
+            fee_bps,
+        } => {
+            msg!("Instruction: AdminSetFeeGroup");
+            process_admin_set_fee_group(program_id, accounts, group, &name, fee_bps)
+        }
+        NCNProgramInstruction::AdminSetAdminGovernance {
+            governance_program,
+            realm,
+        } => {
+            msg!("Instruction: AdminSetAdminGovernance");
+            process_admin_set_admin_governance(program_id, accounts, &governance_program, &realm)
         }
 
         // ---------------------------------------------------- //
         //                ROUTE AND DISTRIBUTE                  //
         // ---------------------------------------------------- //
-        NCNProgramInstruction::InitializeNCNRewardRouter { epoch } => {
+        NCNProgramInstruction::InitializeNCNRewardRouter {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeNCNRewardRouter");
-            process_initialize_ncn_reward_router(program_id, accounts, epoch)
+            process_initialize_ncn_reward_router(program_id, accounts, epoch, init_if_needed)
         }
         NCNProgramInstruction::ReallocNCNRewardRouter { epoch } => {
             msg!("Instruction: ReallocNCNRewardRouter");
@@ -251,41 +415,101 @@ pub fn process_instruction(
         }
         NCNProgramInstruction::RouteNCNRewards {
             max_iterations,
+            mint,
             epoch,
         } => {
             msg!("Instruction: RouteNCNRewards");
-            process_route_ncn_rewards(program_id, accounts, max_iterations, epoch)
+            process_route_ncn_rewards(program_id, accounts, max_iterations, mint, epoch)
         }
-        NCNProgramInstruction::DistributeProtocolRewards { epoch } => {
+        NCNProgramInstruction::DistributeProtocolRewards { mint, epoch } => {
             msg!("Instruction: DistributeProtocolRewards");
-            process_distribute_protocol_rewards(program_id, accounts, epoch)
+            process_distribute_protocol_rewards(program_id, accounts, mint, epoch)
         }
-        NCNProgramInstruction::DistributeNCNRewards { epoch } => {
+        NCNProgramInstruction::DistributeNCNRewards { mint, epoch } => {
             msg!("Instruction: DistributeNCNRewards");
-            process_distribute_ncn_rewards(program_id, accounts, epoch)
+            process_distribute_ncn_rewards(program_id, accounts, mint, epoch)
         }
-        NCNProgramInstruction::InitializeOperatorVaultRewardRouter { epoch } => {
+        NCNProgramInstruction::DistributeFeeGroupRewards { group, mint, epoch } => {
+            msg!("Instruction: DistributeFeeGroupRewards");
+            process_distribute_fee_group_rewards(program_id, accounts, group, mint, epoch)
+        }
+        NCNProgramInstruction::RouteAndDistributeNCNRewards {
+            max_iterations,
+            epoch,
+        } => {
+            msg!("Instruction: RouteAndDistributeNCNRewards");
+            process_route_and_distribute_ncn_rewards(program_id, accounts, max_iterations, epoch)
+        }
+        NCNProgramInstruction::ShrinkNCNRewardRouter { epoch } => {
+            msg!("Instruction: ShrinkNCNRewardRouter");
+            process_shrink_ncn_reward_router(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::InitializeOperatorVaultRewardRouter {
+            epoch,
+            init_if_needed,
+        } => {
             msg!("Instruction: InitializeOperatorVaultRewardRouter");
-            process_initialize_operator_vault_reward_router(program_id, accounts, epoch)
+            process_initialize_operator_vault_reward_router(
+                program_id,
+                accounts,
+                epoch,
+                init_if_needed,
+            )
         }
-        NCNProgramInstruction::DistributeOperatorVaultRewardRoute { epoch } => {
+        NCNProgramInstruction::DistributeOperatorVaultRewardRoute { mint, epoch } => {
             msg!("Instruction: DistributeOperatorVaultRewardRoute");
-            process_distribute_operator_vault_reward_route(program_id, accounts, epoch)
+            process_distribute_operator_vault_reward_route(program_id, accounts, mint, epoch)
         }
         NCNProgramInstruction::RouteOperatorVaultRewards {
             max_iterations,
+            mint,
             epoch,
         } => {
             msg!("Instruction: RouteOperatorVaultRewards");
-            process_route_operator_vault_rewards(program_id, accounts, max_iterations, epoch)
+            process_route_operator_vault_rewards(program_id, accounts, max_iterations, mint, epoch)
         }
-        NCNProgramInstruction::DistributeOperatorRewards { epoch } => {
+        NCNProgramInstruction::DistributeOperatorRewards { mint, epoch } => {
             msg!("Instruction: DistributeOperatorRewards");
-            process_distribute_operator_rewards(program_id, accounts, epoch)
+            process_distribute_operator_rewards(program_id, accounts, mint, epoch)
         }
-        NCNProgramInstruction::DistributeVaultRewards { epoch } => {
+        NCNProgramInstruction::DistributeVaultRewards { mint, epoch } => {
             msg!("Instruction: DistributeVaultRewards");
-            process_distribute_vault_rewards(program_id, accounts, epoch)
+            process_distribute_vault_rewards(program_id, accounts, mint, epoch)
+        }
+
+        // ---------------------------------------------------- //
+        //                        GETTERS                       //
+        // ---------------------------------------------------- //
+        NCNProgramInstruction::GetConsensusResult { epoch } => {
+            msg!("Instruction: GetConsensusResult");
+            process_get_consensus_result(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::GetWinningBallot { epoch } => {
+            msg!("Instruction: GetWinningBallot");
+            process_get_winning_ballot(program_id, accounts, epoch)
+        }
+        NCNProgramInstruction::GetOperatorRewardRoute { operator, epoch } => {
+            msg!("Instruction: GetOperatorRewardRoute");
+            process_get_operator_reward_route(program_id, accounts, &operator, epoch)
+        }
+
+        // ---------------------------------------------------- //
+        //                        KEEPERS                       //
+        // ---------------------------------------------------- //
+        NCNProgramInstruction::AcquireKeeperLease {
+            holder,
+            lease_slots,
+        } => {
+            msg!("Instruction: AcquireKeeperLease");
+            process_acquire_keeper_lease(program_id, accounts, &holder, lease_slots)
+        }
+
+        // ---------------------------------------------------- //
+        //                       RECOVERY                       //
+        // ---------------------------------------------------- //
+        NCNProgramInstruction::CloseRewardReceiver { operator, epoch } => {
+            msg!("Instruction: CloseRewardReceiver");
+            process_close_reward_receiver(program_id, accounts, operator, epoch)
         }
     }
 }
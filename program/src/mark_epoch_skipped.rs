@@ -0,0 +1,112 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker, epoch_state::EpochState,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Marks an epoch that was never started as skipped by creating its epoch marker. Permissionless,
+/// the epoch has to be `epochs_before_stall` epochs old and must not have an epoch state. Once
+/// marked, none of the epoch accounts can be initialized, so the epoch is treated like a closed
+/// epoch from then on.
+///
+/// ### Parameters:
+/// - `epoch`: The epoch to skip
+///
+/// ### Accounts:
+/// 1. `[writable]` epoch_marker: The epoch marker account to create
+/// 2. `[]` epoch_state: The epoch state account of the epoch, must not exist
+/// 3. `[]` config: NCN configuration account
+/// 4. `[]` ncn: The NCN account
+/// 5. `[writable]` account_payer: Account paying for the epoch marker
+/// 6. `[]` system_program: Solana System Program
+pub fn process_mark_epoch_skipped(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, config, ncn, account_payer, system_program] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Config::load(program_id, config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    let epoch_state_pda = EpochState::find_program_address(program_id, ncn.key, epoch).0;
+    if epoch_state_pda != *epoch_state.key {
+        msg!("Error: Invalid epoch state PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if !epoch_state.data_is_empty() {
+        msg!(
+            "Error: Epoch {} has an epoch state and cannot be skipped",
+            epoch
+        );
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    {
+        let config_data = config.try_borrow_data()?;
+        let config_account = Config::try_from_slice_unchecked(&config_data)?;
+
+        EpochMarker::check_can_skip(
+            epoch,
+            current_epoch,
+            config_account.starting_valid_epoch(),
+            config_account.epochs_before_stall(),
+        )
+        .map_err(|error| {
+            msg!(
+                "Error: Epoch {} cannot be skipped in epoch {}",
+                epoch,
+                current_epoch
+            );
+            error
+        })?;
+    }
+
+    let (epoch_marker_pda, epoch_marker_bump, mut epoch_marker_seeds) =
+        EpochMarker::find_program_address(program_id, ncn.key, epoch);
+    epoch_marker_seeds.push(vec![epoch_marker_bump]);
+
+    if epoch_marker_pda != *epoch_marker.key {
+        msg!(
+            "Error: Invalid epoch marker PDA. Expected: {}, got: {}",
+            epoch_marker_pda,
+            epoch_marker.key
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        epoch_marker,
+        system_program,
+        program_id,
+        EpochMarker::SIZE,
+        &epoch_marker_seeds,
+    )?;
+
+    let mut epoch_marker_data = epoch_marker.try_borrow_mut_data()?;
+    epoch_marker_data[0] = EpochMarker::DISCRIMINATOR;
+    let epoch_marker = EpochMarker::try_from_slice_unchecked_mut(&mut epoch_marker_data)?;
+
+    let current_slot = Clock::get()?.slot;
+
+    msg!("Marking epoch {} as skipped", epoch);
+    *epoch_marker = EpochMarker::new(ncn.key, epoch, current_slot, true);
+
+    Ok(())
+}
@@ -1,26 +1,42 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
+use jito_jsm_core::loader::load_token_mint;
+use jito_restaking_core::{
+    config::Config as RestakingConfig, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket,
+};
 use jito_vault_core::vault::Vault;
-use ncn_program_core::{config::Config, vault_registry::VaultRegistry};
+use ncn_program_core::{
+    config::Config, error::NCNProgramError, loaders::load_ncn_epoch, vault_registry::VaultRegistry,
+};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use spl_token::state::Mint;
 
 /// Registers a vault in the vault registry to participate in the consensus mechanism.
 ///
+/// If permissionless vault registration is enabled in the config, the NCN vault ticket has to be
+/// active and a supported mint that is not registered yet is registered with the default weight
+/// of the vault registry and the decimals of the mint. Otherwise the supported mint has to be
+/// registered by the admin first.
+///
 /// ### Accounts:
 /// 1. `[]` config: NCN configuration account
 /// 2. `[writable]` vault_registry: The vault registry to update
 /// 3. `[]` ncn: The NCN account
 /// 4. `[]` vault: The vault to register
 /// 5. `[]` ncn_vault_ticket: The connection between NCN and vault from the restaking program
+/// 6. `[]` restaking_config: Restaking configuration account
+/// 7. `[]` st_mint: The supported mint of the vault
 pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, vault_registry, ncn, vault, ncn_vault_ticket] = accounts else {
+    let [config, vault_registry, ncn, vault, ncn_vault_ticket, restaking_config, st_mint] =
+        accounts
+    else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -36,21 +52,64 @@ pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         vault,
         false,
     )?;
+    RestakingConfig::load(&jito_restaking_program::id(), restaking_config, false)?;
 
     let clock = Clock::get()?;
     let slot = clock.slot;
 
+    let permissionless_vault_registration = {
+        let config_data = config.data.borrow();
+        let config_account = Config::try_from_slice_unchecked(&config_data)?;
+        config_account.permissionless_vault_registration()
+    };
+
+    if permissionless_vault_registration {
+        let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, slot, None)?;
+
+        let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+        let ncn_vault_ticket_account =
+            NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+
+        if !ncn_vault_ticket_account
+            .state
+            .is_active(slot, ncn_epoch_length)?
+        {
+            msg!(
+                "Error: NCN vault ticket {} is not active",
+                ncn_vault_ticket.key
+            );
+            return Err(NCNProgramError::NcnVaultTicketNotActive.into());
+        }
+    }
+
     let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
     let vault_registry = VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
 
     let vault_data = vault.data.borrow();
     let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
 
-    if !vault_registry.has_st_mint(&vault_account.supported_mint) {
-        msg!("Error: Supported mint not registered");
+    if vault_account.supported_mint.ne(st_mint.key) {
+        msg!("Error: St mint is not the supported mint of the vault");
         return Err(ProgramError::InvalidAccountData);
     }
 
+    if !vault_registry.has_st_mint(&vault_account.supported_mint) {
+        if !permissionless_vault_registration {
+            msg!("Error: Supported mint not registered");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        load_token_mint(st_mint)?;
+        let decimals = Mint::unpack(&st_mint.data.borrow())?.decimals;
+
+        msg!(
+            "Registering supported mint {} with default weight {}",
+            vault_account.supported_mint,
+            vault_registry.default_weight()
+        );
+        vault_registry.register_st_mint(&vault_account.supported_mint, decimals, 0)?;
+    }
+
     vault_registry.register_vault(
         vault.key,
         &vault_account.supported_mint,
@@ -0,0 +1,294 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    config::Config as NcnConfig,
+    distribution_receipt::{DistributionKind, DistributionReceipt},
+    epoch_snapshot::EpochSnapshot,
+    epoch_state::EpochState,
+    error::NCNProgramError,
+    events::{Event, RewardsRouted},
+    ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
+    operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    system_instruction, sysvar::Sysvar,
+};
+
+/// Accounts passed per operator after the required accounts:
+/// `operator`, `operator_vault_reward_router` and `operator_vault_reward_receiver`
+const OPERATOR_ROUTE_ACCOUNTS: usize = 3;
+
+/// Routes the NCN reward router and distributes the protocol and NCN shares in one call.
+///
+/// Operator vault reward routes are distributed for every operator passed in the remaining
+/// accounts. Routing has to finish within `max_iterations`, larger operator sets should use
+/// `RouteNCNRewards` and the distribute instructions instead.
+pub fn process_route_and_distribute_ncn_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_iterations: u16,
+    epoch: u64,
+) -> ProgramResult {
+    let (required_accounts, operator_accounts) = accounts.split_at(accounts.len().min(10));
+    let [epoch_state, config, ncn, epoch_snapshot, ballot_box, ncn_reward_router, ncn_reward_receiver, protocol_fee_wallet, ncn_fee_wallet, system_program] =
+        required_accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if operator_accounts.len() % OPERATOR_ROUTE_ACCOUNTS != 0 {
+        msg!("Error: Operator routes need an operator, reward router and reward receiver each");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+    NCNRewardRouter::load(program_id, ncn_reward_router, ncn.key, epoch, true)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
+    NCNRewardReceiver::load(program_id, ncn_reward_receiver, ncn.key, epoch, true)?;
+    load_system_program(system_program)?;
+
+    let (valid_slots_after_consensus, timeliness_bonus_slots, timeliness_bonus_bps) = {
+        let ncn_config_data = config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+        if ncn_config
+            .fee_config
+            .protocol_fee_wallet()
+            .ne(protocol_fee_wallet.key)
+        {
+            msg!("Error: Incorrect Protocol fee wallet provided");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ncn_config
+            .fee_config
+            .ncn_fee_wallet()
+            .ne(ncn_fee_wallet.key)
+        {
+            msg!("Error: Incorrect NCN fee wallet provided");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        (
+            ncn_config.valid_slots_after_consensus(),
+            ncn_config.timeliness_bonus_slots(),
+            ncn_config.timeliness_bonus_bps(),
+        )
+    };
+
+    {
+        let ballot_box_data = ballot_box.try_borrow_data()?;
+        let ballot_box_account = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+
+        // Do not route if voting is still ongoing
+        let current_slot = Clock::get()?.slot;
+        if ballot_box_account.is_voting_valid(current_slot, valid_slots_after_consensus)? {
+            msg!("Voting is still ongoing - cannot route rewards yet");
+            return Err(NCNProgramError::VotingIsNotOver.into());
+        }
+
+        let epoch_snapshot_data = epoch_snapshot.try_borrow_data()?;
+        let epoch_snapshot_account = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+        let ncn_reward_receiver_balance = **ncn_reward_receiver.try_borrow_lamports()?;
+        let rent_cost = Rent::get()?.minimum_balance(0);
+
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        if !ncn_reward_router_account.still_routing() {
+            ncn_reward_router_account
+                .route_incoming_rewards(rent_cost, ncn_reward_receiver_balance)?;
+            ncn_reward_router_account.route_reward_pool(epoch_snapshot_account.fees())?;
+        } else {
+            msg!("Skipping incoming rewards and reward pool routing since routing is already in progress");
+        }
+
+        ncn_reward_router_account.route_operator_vault_rewards(
+            ballot_box_account,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+            max_iterations,
+        )?;
+
+        if ncn_reward_router_account.still_routing() {
+            msg!("Error: Rewards still routing, use RouteNCNRewards for large operator sets");
+            return Err(NCNProgramError::RouterStillRouting.into());
+        }
+
+        let total_rewards = ncn_reward_router_account.total_rewards();
+        let ncn_rewards = ncn_reward_router_account.ncn_rewards();
+        let protocol_rewards = ncn_reward_router_account.protocol_rewards();
+
+        msg!("Total rewards processed: {} lamports", total_rewards);
+        msg!("NCN rewards: {} lamports", ncn_rewards);
+        msg!("Protocol rewards: {} lamports", protocol_rewards);
+
+        RewardsRouted::from_router(ncn_reward_router_account, current_slot)?.log();
+
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_route_ncn_rewards(ncn_rewards);
+        epoch_state_account.update_route_protocol_rewards(protocol_rewards);
+        epoch_state_account.update_route_total_rewards(total_rewards);
+    }
+
+    let (protocol_rewards, ncn_rewards) = {
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        (
+            ncn_reward_router_account.distribute_protocol_fee_rewards()?,
+            ncn_reward_router_account.distribute_ncn_fee_rewards()?,
+        )
+    };
+
+    msg!(
+        "Distributing {} lamports to Protocol fee wallet",
+        protocol_rewards
+    );
+    transfer_from_ncn_reward_receiver(
+        program_id,
+        DistributionKind::Protocol,
+        ncn.key,
+        epoch,
+        ncn_reward_receiver,
+        protocol_fee_wallet,
+        system_program,
+        protocol_rewards,
+    )?;
+
+    msg!("Distributing {} lamports to NCN fee wallet", ncn_rewards);
+    transfer_from_ncn_reward_receiver(
+        program_id,
+        DistributionKind::Ncn,
+        ncn.key,
+        epoch,
+        ncn_reward_receiver,
+        ncn_fee_wallet,
+        system_program,
+        ncn_rewards,
+    )?;
+
+    let mut operator_vault_rewards: u64 = 0;
+    for operator_route_accounts in operator_accounts.chunks(OPERATOR_ROUTE_ACCOUNTS) {
+        let [operator, operator_vault_reward_router, operator_vault_reward_receiver] =
+            operator_route_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Operator::load(&jito_restaking_program::id(), operator, false)?;
+        OperatorVaultRewardRouter::load(
+            program_id,
+            operator_vault_reward_router,
+            operator.key,
+            ncn.key,
+            epoch,
+            false,
+        )?;
+        OperatorVaultRewardReceiver::load(
+            program_id,
+            operator_vault_reward_receiver,
+            operator.key,
+            ncn.key,
+            epoch,
+            true,
+        )?;
+
+        let rewards = {
+            let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+            let ncn_reward_router_account =
+                NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+            ncn_reward_router_account.distribute_operator_vault_reward_route(operator.key)?
+        };
+
+        msg!(
+            "Distributing {} lamports to operator vault reward receiver of {}",
+            rewards,
+            operator.key
+        );
+        transfer_from_ncn_reward_receiver(
+            program_id,
+            DistributionKind::OperatorVaultRoute,
+            ncn.key,
+            epoch,
+            ncn_reward_receiver,
+            operator_vault_reward_receiver,
+            system_program,
+            rewards,
+        )?;
+
+        operator_vault_rewards = operator_vault_rewards
+            .checked_add(rewards)
+            .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    }
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_distribute_protocol_rewards(protocol_rewards);
+        epoch_state_account.update_distribute_ncn_rewards(ncn_rewards);
+        epoch_state_account.update_distribute_operator_vault_rewards(operator_vault_rewards);
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_ncn_reward_receiver<'a, 'info>(
+    program_id: &Pubkey,
+    kind: DistributionKind,
+    ncn: &Pubkey,
+    epoch: u64,
+    ncn_reward_receiver: &'a AccountInfo<'info>,
+    destination: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    rewards: u64,
+) -> ProgramResult {
+    if rewards == 0 {
+        return Ok(());
+    }
+
+    let (_, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
+        NCNRewardReceiver::find_program_address(program_id, ncn, epoch);
+    ncn_reward_receiver_seeds.push(vec![ncn_reward_receiver_bump]);
+
+    invoke_signed(
+        &system_instruction::transfer(ncn_reward_receiver.key, destination.key, rewards),
+        &[
+            ncn_reward_receiver.clone(),
+            destination.clone(),
+            system_program.clone(),
+        ],
+        &[ncn_reward_receiver_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+
+    DistributionReceipt::new(
+        kind,
+        ncn,
+        epoch,
+        destination.key,
+        None,
+        rewards,
+        Clock::get()?.slot,
+    )
+    .log();
+
+    Ok(())
+}
@@ -6,6 +6,8 @@ use ncn_program_core::{
     epoch_snapshot::EpochSnapshot,
     epoch_state::EpochState,
     error::NCNProgramError,
+    events::{Event, RewardsRouted},
+    loaders::{load_reward_token_account, reward_token_account_balance},
     ncn_reward_router::{NCNRewardReceiver, NCNRewardRouter},
 };
 use solana_program::{
@@ -13,15 +15,25 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::cranker_fee::{load_cranker, pay_cranker_fee};
+
 /// Can be backfilled for previous epochs
+///
+/// When `mint` is set, the rewards held in the NCN reward receiver's token account for that
+/// reward mint are routed instead of SOL.
+///
+/// When a cranker signs, the configured cranker fee is taken out of newly routed SOL rewards
+/// and paid to it. The cranker fee is SOL only: routing a reward mint pays no fee.
 pub fn process_route_ncn_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     max_iterations: u16,
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(7));
     let [epoch_state, config, ncn, epoch_snapshot, ballot_box, ncn_reward_router, ncn_reward_receiver] =
-        accounts
+        required_accounts
     else {
         msg!("ERROR: Incorrect number of accounts provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -43,12 +55,24 @@ pub fn process_route_ncn_rewards(
 
     let current_slot = Clock::get()?.slot;
 
-    let valid_slots_after_consensus = {
+    let cranker = load_cranker(program_id, optional_accounts.get(1))?;
+
+    let (
+        valid_slots_after_consensus,
+        cranker_fee_bps,
+        timeliness_bonus_slots,
+        timeliness_bonus_bps,
+    ) = {
         let ncn_config_data = config.data.borrow();
         let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
         let valid_slots = ncn_config.valid_slots_after_consensus();
         msg!("Valid slots after consensus: {}", valid_slots);
-        valid_slots
+        (
+            valid_slots,
+            ncn_config.cranker_fee_bps(),
+            ncn_config.timeliness_bonus_slots(),
+            ncn_config.timeliness_bonus_bps(),
+        )
     };
 
     // Do not route if voting is still ongoing
@@ -57,6 +81,77 @@ pub fn process_route_ncn_rewards(
         return Err(NCNProgramError::VotingIsNotOver.into());
     }
 
+    if let Some(mint) = mint {
+        let Some(ncn_reward_receiver_token_account) = optional_accounts.first() else {
+            msg!("Error: Reward token account is required to route mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        {
+            let ncn_config_data = config.data.borrow();
+            let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            if !ncn_config.is_reward_mint(&mint) {
+                msg!("Error: {} is not a reward mint", mint);
+                return Err(NCNProgramError::RewardMintNotRegistered.into());
+            }
+        }
+
+        load_reward_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint,
+            false,
+        )?;
+
+        let token_account_balance =
+            reward_token_account_balance(ncn_reward_receiver_token_account)?;
+
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        if !ncn_reward_router_account.still_routing_mint(&mint) {
+            ncn_reward_router_account.route_incoming_mint_rewards(&mint, token_account_balance)?;
+            ncn_reward_router_account
+                .route_mint_reward_pool(&mint, epoch_snapshot_account.fees())?;
+        } else {
+            msg!("Skipping incoming rewards and reward pool routing since routing is already in progress");
+        }
+
+        ncn_reward_router_account.route_operator_vault_mint_rewards(
+            &mint,
+            ballot_box_account,
+            timeliness_bonus_slots,
+            timeliness_bonus_bps,
+            max_iterations,
+        )?;
+
+        let mint_rewards = ncn_reward_router_account.get_mint_rewards(&mint)?;
+        msg!(
+            "Total rewards processed: {} of {}",
+            mint_rewards.total_rewards(),
+            mint
+        );
+        msg!("NCN rewards: {} of {}", mint_rewards.ncn_rewards(), mint);
+        msg!(
+            "Protocol rewards: {} of {}",
+            mint_rewards.protocol_rewards(),
+            mint
+        );
+
+        RewardsRouted::from_mint_router(ncn_reward_router_account, &mint, current_slot)?.log();
+
+        if cranker.is_some() {
+            msg!(
+                "No cranker fee is paid for routing {}, the fee is SOL only",
+                mint
+            );
+        }
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
     let ncn_reward_receiver_balance = **ncn_reward_receiver.try_borrow_lamports()?;
 
     let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
@@ -65,9 +160,14 @@ pub fn process_route_ncn_rewards(
 
     let rent_cost = Rent::get()?.minimum_balance(0);
 
+    let mut cranker_fee = 0;
     if !ncn_reward_router_account.still_routing() {
         ncn_reward_router_account.route_incoming_rewards(rent_cost, ncn_reward_receiver_balance)?;
 
+        if cranker.is_some() {
+            cranker_fee = ncn_reward_router_account.route_cranker_fee(cranker_fee_bps)?;
+        }
+
         let epoch_fees = epoch_snapshot_account.fees();
         msg!("Routing reward pool with epoch fees: {:?}", epoch_fees);
         ncn_reward_router_account.route_reward_pool(epoch_fees)?;
@@ -75,7 +175,12 @@ pub fn process_route_ncn_rewards(
         msg!("Skipping incoming rewards and reward pool routing since routing is already in progress");
     }
 
-    ncn_reward_router_account.route_operator_vault_rewards(ballot_box_account, max_iterations)?;
+    ncn_reward_router_account.route_operator_vault_rewards(
+        ballot_box_account,
+        timeliness_bonus_slots,
+        timeliness_bonus_bps,
+        max_iterations,
+    )?;
 
     let total_rewards = ncn_reward_router_account.total_rewards();
     let ncn_rewards = ncn_reward_router_account.ncn_rewards();
@@ -85,6 +190,8 @@ pub fn process_route_ncn_rewards(
     msg!("NCN rewards: {} lamports", ncn_rewards);
     msg!("Protocol rewards: {} lamports", protocol_rewards);
 
+    RewardsRouted::from_router(ncn_reward_router_account, current_slot)?.log();
+
     {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
@@ -92,6 +199,21 @@ pub fn process_route_ncn_rewards(
         epoch_state_account.update_route_ncn_rewards(ncn_rewards);
         epoch_state_account.update_route_protocol_rewards(protocol_rewards);
         epoch_state_account.update_route_total_rewards(total_rewards);
+        epoch_state_account.update_distribute_cranker_rewards(cranker_fee);
+    }
+
+    if let Some(cranker) = cranker {
+        let (_, ncn_reward_receiver_bump, ncn_reward_receiver_seeds) =
+            NCNRewardReceiver::find_program_address(program_id, ncn.key, epoch);
+
+        pay_cranker_fee(
+            ncn_reward_receiver,
+            ncn_reward_receiver_seeds,
+            ncn_reward_receiver_bump,
+            cranker,
+            optional_accounts.get(2),
+            cranker_fee,
+        )?;
     }
 
     Ok(())
@@ -1,8 +1,11 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use ncn_program_core::{
+    config::Config as NcnConfig,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    error::NCNProgramError,
+    loaders::{load_reward_token_account, reward_token_account_balance},
     operator_vault_reward_router::{OperatorVaultRewardReceiver, OperatorVaultRewardRouter},
 };
 use solana_program::{
@@ -10,15 +13,25 @@ use solana_program::{
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::cranker_fee::{load_cranker, pay_cranker_fee};
+
 /// Can be backfilled for previous epochs
+///
+/// When `mint` is set, the rewards held in the operator vault reward receiver's token account
+/// for that reward mint are routed instead of SOL.
+///
+/// When a cranker signs, the configured cranker fee is taken out of newly routed SOL rewards
+/// and paid to it. The config is then required to read the fee.
 pub fn process_route_operator_vault_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     max_iterations: u16,
+    mint: Option<Pubkey>,
     epoch: u64,
 ) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(accounts.len().min(6));
     let [epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver] =
-        accounts
+        required_accounts
     else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -56,6 +69,61 @@ pub fn process_route_operator_vault_rewards(
     let operator_snapshot_account =
         OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
 
+    let cranker = load_cranker(program_id, optional_accounts.get(2))?;
+
+    if let Some(mint) = mint {
+        let [config, operator_vault_reward_receiver_token_account, ..] = optional_accounts else {
+            msg!("Error: Config and reward token account are required to route mint rewards");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        NcnConfig::load(program_id, config, ncn.key, false)?;
+        {
+            let ncn_config_data = config.data.borrow();
+            let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            if !ncn_config.is_reward_mint(&mint) {
+                msg!("Error: {} is not a reward mint", mint);
+                return Err(NCNProgramError::RewardMintNotRegistered.into());
+            }
+        }
+
+        load_reward_token_account(
+            operator_vault_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint,
+            false,
+        )?;
+
+        let token_account_balance =
+            reward_token_account_balance(operator_vault_reward_receiver_token_account)?;
+        msg!(
+            "Token account balance: {} of {}",
+            token_account_balance,
+            mint
+        );
+
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            OperatorVaultRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        if !ncn_reward_router_account.still_routing_mint(&mint) {
+            ncn_reward_router_account.route_incoming_mint_rewards(&mint, token_account_balance)?;
+            ncn_reward_router_account
+                .route_operator_mint_rewards(&mint, operator_snapshot_account)?;
+        } else {
+            msg!("Routing already in progress, continuing existing process");
+        }
+
+        ncn_reward_router_account.route_mint_reward_pool(
+            &mint,
+            operator_snapshot_account,
+            max_iterations,
+        )?;
+
+        // The epoch state only tracks SOL rewards
+        return Ok(());
+    }
+
     let account_balance = **ncn_reward_receiver.try_borrow_lamports()?;
     msg!("Account balance: {} lamports", account_balance);
 
@@ -66,8 +134,27 @@ pub fn process_route_operator_vault_rewards(
     let rent_cost = Rent::get()?.minimum_balance(0);
     msg!("Rent cost: {} lamports", rent_cost);
 
+    let cranker_fee_bps = match (cranker, optional_accounts.first()) {
+        (Some(_), Some(config)) => {
+            NcnConfig::load(program_id, config, ncn.key, false)?;
+            let ncn_config_data = config.data.borrow();
+            let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            ncn_config.cranker_fee_bps()
+        }
+        (Some(_), None) => {
+            msg!("Error: Config is required to pay the cranker fee");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        (None, _) => 0,
+    };
+
+    let mut cranker_fee = 0;
     if !ncn_reward_router_account.still_routing() {
         ncn_reward_router_account.route_incoming_rewards(rent_cost, account_balance)?;
+
+        if cranker.is_some() {
+            cranker_fee = ncn_reward_router_account.route_cranker_fee(cranker_fee_bps)?;
+        }
         ncn_reward_router_account.route_operator_rewards(operator_snapshot_account)?;
     } else {
         msg!("Routing already in progress, continuing existing process");
@@ -88,6 +175,27 @@ pub fn process_route_operator_vault_rewards(
         );
 
         epoch_state_account.update_route_operator_vault_rewards(ncn_operator_index, total_rewards);
+        epoch_state_account
+            .update_distribute_operator_vault_route_rewards(ncn_operator_index, cranker_fee);
+    }
+
+    if let Some(cranker) = cranker {
+        let (_, operator_vault_reward_receiver_bump, operator_vault_reward_receiver_seeds) =
+            OperatorVaultRewardReceiver::find_program_address(
+                program_id,
+                operator.key,
+                ncn.key,
+                epoch,
+            );
+
+        pay_cranker_fee(
+            ncn_reward_receiver,
+            operator_vault_reward_receiver_seeds,
+            operator_vault_reward_receiver_bump,
+            cranker,
+            optional_accounts.get(3),
+            cranker_fee,
+        )?;
     }
 
     Ok(())
@@ -1,8 +1,9 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_restaking_core::ncn::Ncn;
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_vault_core::vault::Vault;
 use ncn_program_core::{
-    epoch_state::EpochState, error::NCNProgramError, vault_registry::VaultRegistry,
-    weight_table::WeightTable,
+    config::Config as NcnConfig, epoch_state::EpochState, error::NCNProgramError,
+    loaders::load_ncn_epoch, vault_registry::VaultRegistry, weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -10,6 +11,12 @@ use solana_program::{
 };
 
 /// Sets weights for the epoch using the vault registry data, establishing the relative importance of each token type.
+/// Mints with a switchboard feed are skipped, their weight is set with `SetWeightFromSwitchboard`.
+/// Mints without a weight get the default weight of the vault registry.
+///
+/// If weight decay is enabled in the config, every registered vault has to be passed in registry
+/// order. St mints of vaults that were not updated for more than `weight_decay_epochs` epochs
+/// before the target epoch get their weight reduced by `weight_decay_bps`.
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
@@ -19,12 +26,19 @@ use solana_program::{
 /// 2. `[]` ncn: The NCN account
 /// 3. `[]` vault_registry: The vault registry containing registered vaults and mint weights
 /// 4. `[writable]` weight_table: The weight table to update
+/// 5. `[]` config: NCN configuration account
+/// 6. `[]` restaking_config: Restaking configuration account
+///
+/// Followed by the registered vaults when weight decay is enabled.
 pub fn process_set_epoch_weights(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn, vault_registry, weight_table] = accounts else {
+    let (required_accounts, vaults) = accounts.split_at(accounts.len().min(6));
+    let [epoch_state, ncn, vault_registry, weight_table, config, restaking_config] =
+        required_accounts
+    else {
         msg!("Error: Not enough account keys provided");
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -33,6 +47,10 @@ pub fn process_set_epoch_weights(
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
     VaultRegistry::load(program_id, vault_registry, ncn.key, false)?;
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    Config::load(&jito_restaking_program::id(), restaking_config, false)?;
+
+    let current_slot = Clock::get()?.slot;
 
     let mut weight_table_data = weight_table.try_borrow_mut_data()?;
     let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
@@ -47,18 +65,86 @@ pub fn process_set_epoch_weights(
     let vault_registry_account =
         VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
 
+    let config_account = {
+        let config_data = config.data.borrow();
+        *NcnConfig::try_from_slice_unchecked(&config_data)?
+    };
+
+    let mut stale_st_mints: Vec<Pubkey> = Vec::new();
+    if config_account.weight_decay_enabled() {
+        let vault_entries = vault_registry_account.get_valid_vault_entries();
+        if vaults.len() != vault_entries.len() {
+            msg!(
+                "Error: Expected {} vaults for weight decay, got {}",
+                vault_entries.len(),
+                vaults.len()
+            );
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
+
+        for (vault, vault_entry) in vaults.iter().zip(vault_entries.iter()) {
+            if vault.key.ne(vault_entry.vault()) {
+                msg!(
+                    "Error: Expected vault {}, got {}",
+                    vault_entry.vault(),
+                    vault.key
+                );
+                return Err(NCNProgramError::VaultNotInRegistry.into());
+            }
+            Vault::load(&jito_vault_program::id(), vault, false)?;
+
+            let vault_data = vault.data.borrow();
+            let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+            let vault_update_epoch = vault_account
+                .last_full_state_update_slot()
+                .checked_div(ncn_epoch_length)
+                .ok_or(NCNProgramError::DenominatorIsZero)?;
+
+            if config_account.is_vault_stale(vault_update_epoch, epoch) {
+                msg!(
+                    "Vault {} was last updated in epoch {}, decaying st_mint {}",
+                    vault.key,
+                    vault_update_epoch,
+                    vault_entry.st_mint()
+                );
+                stale_st_mints.push(*vault_entry.st_mint());
+            }
+        }
+    }
+
     for mint_entry in vault_registry_account.get_valid_mint_entries() {
-        let weight_from_mint_entry = mint_entry.weight();
+        // Weights of mints with a feed are set by `SetWeightFromSwitchboard`
+        if mint_entry.has_switchboard_feed() {
+            msg!(
+                "Skipping st_mint {} with a switchboard feed",
+                mint_entry.st_mint()
+            );
+            continue;
+        }
+
+        let weight_from_mint_entry = vault_registry_account.st_mint_weight(&mint_entry);
         if weight_from_mint_entry == 0 {
             msg!("Error: Weight is not set for mint entry");
             return Err(NCNProgramError::WeightNotSet.into());
         }
 
-        weight_table_account.set_weight(
-            mint_entry.st_mint(),
-            weight_from_mint_entry,
-            Clock::get()?.slot,
-        )?;
+        if mint_entry.weight() == 0 {
+            msg!(
+                "Using default weight {} for st_mint {}",
+                weight_from_mint_entry,
+                mint_entry.st_mint()
+            );
+        }
+
+        let weight = if stale_st_mints.contains(mint_entry.st_mint()) {
+            config_account.decay_weight(weight_from_mint_entry)?
+        } else {
+            weight_from_mint_entry
+        };
+
+        weight_table_account.set_weight(mint_entry.st_mint(), weight, current_slot)?;
     }
 
     // Update Epoch State
@@ -0,0 +1,106 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_signer, load_system_account, load_system_program};
+use jito_restaking_core::{ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator};
+use ncn_program_core::{account_payer::AccountPayer, operator_metadata::OperatorMetadata};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Creates or updates the metadata an operator publishes for an NCN. The operator has to be
+/// registered with the NCN, the account is created by the account payer on first use.
+///
+/// ### Parameters:
+/// - `name`: UTF-8 name padded with zeros
+/// - `website`: UTF-8 website padded with zeros
+/// - `contact_hash`: Hash of the operator's contact information, all zeros if unset
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[]` operator: The operator the metadata belongs to
+/// 3. `[]` ncn_operator_state: The connection between the NCN and the operator
+/// 4. `[writable]` operator_metadata: The operator metadata account, created if it does not exist
+/// 5. `[signer]` operator_admin: The admin of the operator
+/// 6. `[writable]` account_payer: Account paying for the initialization
+/// 7. `[]` system_program: Solana System Program
+pub fn process_set_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: &[u8; 32],
+    website: &[u8; 64],
+    contact_hash: &[u8; 32],
+) -> ProgramResult {
+    let [ncn, operator, ncn_operator_state, operator_metadata, operator_admin, account_payer, system_program] =
+        accounts
+    else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    NcnOperatorState::load(
+        &jito_restaking_program::id(),
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+    load_signer(operator_admin, false)?;
+
+    {
+        let operator_data = operator.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+
+        if operator_account.admin.ne(operator_admin.key) {
+            msg!("Error: Signer is not the operator admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let (operator_metadata_pda, operator_metadata_bump, mut operator_metadata_seeds) =
+        OperatorMetadata::find_program_address(program_id, operator.key, ncn.key);
+    operator_metadata_seeds.push(vec![operator_metadata_bump]);
+
+    if operator_metadata_pda.ne(operator_metadata.key) {
+        msg!("Error: Invalid operator metadata PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if operator_metadata.owner.ne(program_id) {
+        load_system_account(operator_metadata, true)?;
+        load_system_program(system_program)?;
+        AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+        msg!("Initializing operator metadata {}", operator_metadata.key);
+        AccountPayer::pay_and_create_account(
+            program_id,
+            ncn.key,
+            account_payer,
+            operator_metadata,
+            system_program,
+            program_id,
+            OperatorMetadata::SIZE,
+            &operator_metadata_seeds,
+        )?;
+
+        let mut operator_metadata_data = operator_metadata.try_borrow_mut_data()?;
+        operator_metadata_data[0] = OperatorMetadata::DISCRIMINATOR;
+        let operator_metadata_account =
+            OperatorMetadata::try_from_slice_unchecked_mut(&mut operator_metadata_data)?;
+        operator_metadata_account.initialize(operator.key, ncn.key, operator_metadata_bump);
+    } else {
+        OperatorMetadata::load(program_id, operator_metadata, operator.key, ncn.key, true)?;
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    let mut operator_metadata_data = operator_metadata.try_borrow_mut_data()?;
+    let operator_metadata_account =
+        OperatorMetadata::try_from_slice_unchecked_mut(&mut operator_metadata_data)?;
+
+    msg!("Setting metadata of operator {}", operator.key);
+    operator_metadata_account.set_metadata(name, website, contact_hash, current_slot)?;
+
+    Ok(())
+}
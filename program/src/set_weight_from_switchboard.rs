@@ -0,0 +1,121 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    epoch_state::EpochState, error::NCNProgramError, switchboard::weight_from_switchboard_result,
+    vault_registry::VaultRegistry, weight_table::WeightTable,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+use switchboard_on_demand::PullFeedAccountData;
+
+/// Sets the weight of a staked token mint in a given epoch's WeightTable from the switchboard feed
+/// registered for the mint in the vault registry. Permissionless, the feed result has to be recent
+/// and its samples have to agree within `MAX_SWITCHBOARD_CONFIDENCE_BPS`.
+///
+/// ### Parameters:
+/// - `st_mint`: Pubkey of the staked token mint.
+/// - `epoch`: Target epoch.
+///
+/// ### Accounts:
+/// 1. `[writable]` epoch_state: Epoch state for the target epoch.
+/// 2. `[]` ncn: The NCN account.
+/// 3. `[]` vault_registry: The vault registry holding the feed of the mint.
+/// 4. `[writable]` weight_table: The weight table to update.
+/// 5. `[]` switchboard_feed: The switchboard pull feed registered for the mint.
+pub fn process_set_weight_from_switchboard(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    st_mint: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn, vault_registry, weight_table, switchboard_feed] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, false)?;
+    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+
+    let mint_entry = {
+        let vault_registry_data = vault_registry.data.borrow();
+        let vault_registry_account = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
+        vault_registry_account.get_mint_entry(st_mint)?
+    };
+
+    if !mint_entry.has_switchboard_feed() {
+        msg!(
+            "Error: No switchboard feed registered for st_mint: {}",
+            st_mint
+        );
+        return Err(NCNProgramError::NoSwitchboardFeed.into());
+    }
+
+    if mint_entry.switchboard_feed().ne(switchboard_feed.key) {
+        msg!(
+            "Error: Incorrect switchboard feed. Expected: {}, got: {}",
+            mint_entry.switchboard_feed(),
+            switchboard_feed.key
+        );
+        return Err(NCNProgramError::IncorrectSwitchboardFeed.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    let weight = {
+        let feed = PullFeedAccountData::parse(switchboard_feed.data.borrow()).map_err(|_| {
+            msg!(
+                "Error: Could not parse switchboard feed {}",
+                switchboard_feed.key
+            );
+            NCNProgramError::BadSwitchboardFeed
+        })?;
+
+        msg!(
+            "Switchboard feed value: {}, std dev: {}, slot: {}",
+            feed.result.value,
+            feed.result.std_dev,
+            feed.result.slot
+        );
+
+        weight_from_switchboard_result(
+            feed.result.value,
+            feed.result.std_dev,
+            feed.result.slot,
+            current_slot,
+        )?
+    };
+
+    let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+    let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+
+    weight_table_account.check_table_initialized()?;
+
+    if weight_table_account.finalized() {
+        msg!("Error: Weight table is already finalized");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Setting weight for st_mint: {}, weight: {}",
+        st_mint,
+        weight
+    );
+    weight_table_account.set_weight(st_mint, weight, current_slot)?;
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+
+        epoch_state_account.update_set_weight(
+            weight_table_account.weight_count() as u64,
+            weight_table_account.st_mint_count() as u64,
+        );
+    }
+
+    Ok(())
+}
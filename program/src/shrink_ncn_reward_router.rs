@@ -0,0 +1,81 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use ncn_program_core::{
+    account_payer::AccountPayer, error::NCNProgramError, ncn_reward_router::NCNRewardRouter,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Shrinks the NCN reward router down to its recorded operator vault reward routes and refunds
+/// the freed rent to the account payer. Can be called by anyone once all rewards of the epoch
+/// have been routed and distributed, the router is closed as usual afterwards.
+///
+/// Rewards arriving after the shrink can only be routed once `ReallocNCNRewardRouter` has grown
+/// the router back to its full size.
+///
+/// ### Parameters:
+/// - `epoch`: The epoch of the NCN reward router
+///
+/// ### Accounts:
+/// 1. `[]` ncn: The NCN account
+/// 2. `[writable]` ncn_reward_router: The NCN reward router to shrink
+/// 3. `[writable]` account_payer: Receives the freed rent
+pub fn process_shrink_ncn_reward_router(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn, ncn_reward_router, account_payer] = accounts else {
+        msg!("Error: Not enough account keys provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NCNRewardRouter::load(program_id, ncn_reward_router, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    if ncn_reward_router.data_len() < NCNRewardRouter::SIZE {
+        msg!("NCN reward router is already shrunk");
+        return Ok(());
+    }
+
+    let route_count = {
+        let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+        let ncn_reward_router_account =
+            NCNRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+
+        if !ncn_reward_router_account.can_shrink()? {
+            msg!("Error: NCN reward router still has rewards to route or distribute");
+            return Err(NCNProgramError::RouterRewardsInTransit.into());
+        }
+
+        ncn_reward_router_account.compact_operator_vault_reward_routes()
+    };
+
+    let new_size = NCNRewardRouter::shrunk_size(route_count);
+    msg!(
+        "Shrinking NCN reward router to {} routes ({} bytes)",
+        route_count,
+        new_size
+    );
+    ncn_reward_router.realloc(new_size, false)?;
+
+    let refund = ncn_reward_router
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(new_size));
+
+    **account_payer.lamports.borrow_mut() = account_payer
+        .lamports()
+        .checked_add(refund)
+        .ok_or(NCNProgramError::ArithmeticOverflow)?;
+    **ncn_reward_router.lamports.borrow_mut() = ncn_reward_router
+        .lamports()
+        .checked_sub(refund)
+        .ok_or(NCNProgramError::ArithmeticUnderflowError)?;
+
+    msg!("Refunded {} lamports to the account payer", refund);
+
+    Ok(())
+}
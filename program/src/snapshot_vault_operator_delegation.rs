@@ -12,7 +12,7 @@ use ncn_program_core::{
     epoch_state::EpochState,
     error::NCNProgramError,
     loaders::load_ncn_epoch,
-    stake_weight::StakeWeights,
+    stake_weight::{StakeWeights, U256},
     weight_table::WeightTable,
 };
 use solana_program::{
@@ -21,6 +21,7 @@ use solana_program::{
 };
 
 /// Records the delegation between a vault and an operator at a specific epoch.
+/// Delegations of vaults above the stake weight cap of the epoch snapshot are scaled down.
 ///
 /// ### Parameters:
 /// - `epoch`: The target epoch
@@ -154,18 +155,38 @@ pub fn process_snapshot_vault_operator_delegation(
 
         weight_table_account.check_registry_for_vault(vault_index)?;
 
-        let total_stake_weight: u128 = if is_active {
+        let total_stake_weight: U256 = if is_active {
             let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
             let vault_operator_delegation_account =
                 VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
 
-            OperatorSnapshot::calculate_total_stake_weight(
+            let delegation_stake_weight = OperatorSnapshot::calculate_total_stake_weight(
                 vault_operator_delegation_account,
                 weight_table_account,
                 &st_mint,
-            )?
+            )?;
+
+            // Delegations of vaults above the cap are scaled down
+            let epoch_snapshot_data = epoch_snapshot.data.borrow();
+            let epoch_snapshot_account =
+                EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+            if !epoch_snapshot_account.max_vault_stake_weight().is_zero() {
+                let vault_data = vault.data.borrow();
+                let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+                let vault_stake_weight = OperatorSnapshot::calculate_stake_weight(
+                    vault_account.delegation_state.total_security()?,
+                    weight_table_account,
+                    &st_mint,
+                )?;
+
+                epoch_snapshot_account
+                    .cap_vault_stake_weight(delegation_stake_weight, vault_stake_weight)?
+            } else {
+                delegation_stake_weight
+            }
         } else {
-            0u128
+            U256::zero()
         };
 
         total_stake_weight
@@ -176,7 +197,7 @@ pub fn process_snapshot_vault_operator_delegation(
     let operator_snapshot_account =
         OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
 
-    let stake_weights = StakeWeights::snapshot(total_stake_weight)?;
+    let stake_weights = StakeWeights::new_u256(total_stake_weight);
 
     operator_snapshot_account.increment_vault_operator_delegation_registration(
         current_slot,
@@ -0,0 +1,142 @@
+//! Conversions between the zero copy NCN program accounts and their Borsh mirrors, the account
+//! structs of `ncn-program-client`, for services persisting state in Borsh or using Anchor style
+//! clients:
+//!
+//! ```ignore
+//! let mirror = ballot_box.to_mirror()?;
+//! let stored = mirror.try_to_vec()?;
+//! let ballot_box = BallotBox::from_mirror(&accounts::BallotBox::try_from_slice(&stored)?)?;
+//! ```
+//!
+//! A mirror serializes to the account data as stored on chain, discriminator included.
+use std::io::{Error, ErrorKind, Result};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::Pod;
+use jito_bytemuck::Discriminator;
+use ncn_program_client::accounts;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    builders::{account_data, zeroed_account},
+    config::Config,
+    consensus_result::ConsensusResult,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    keeper_lease::KeeperLease,
+    ncn_reward_router::NCNRewardRouter,
+    operator_metadata::OperatorMetadata,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+
+/// A zero copy account with a Borsh serializable mirror of the same layout
+pub trait BorshMirror: Pod + Discriminator {
+    type Mirror: BorshSerialize + BorshDeserialize;
+
+    fn to_mirror(&self) -> Result<Self::Mirror> {
+        Self::Mirror::try_from_slice(&account_data(self))
+    }
+
+    /// Boxed, some accounts are too large for the stack
+    fn from_mirror(mirror: &Self::Mirror) -> Result<Box<Self>> {
+        let data = mirror.try_to_vec()?;
+
+        if data.first() != Some(&Self::DISCRIMINATOR) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Mirror discriminator does not match the account",
+            ));
+        }
+
+        let mut account = zeroed_account::<Self>();
+        let bytes = bytemuck::bytes_of_mut(account.as_mut());
+        if data.len() != bytes.len() + 8 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Mirror size does not match the account",
+            ));
+        }
+
+        bytes.copy_from_slice(&data[8..]);
+        Ok(account)
+    }
+}
+
+macro_rules! borsh_mirrors {
+    ($($account:ident),* $(,)?) => {
+        $(
+            impl BorshMirror for $account {
+                type Mirror = accounts::$account;
+            }
+        )*
+    };
+}
+
+borsh_mirrors!(
+    Config,
+    VaultRegistry,
+    WeightTable,
+    EpochSnapshot,
+    OperatorSnapshot,
+    BallotBox,
+    ConsensusResult,
+    NCNRewardRouter,
+    OperatorVaultRewardRouter,
+    EpochState,
+    EpochMarker,
+    OperatorMetadata,
+    KeeperLease,
+);
+
+#[cfg(test)]
+mod tests {
+    use ncn_program_core::{
+        ballot_box::{Ballot, WeatherStatus},
+        stake_weight::StakeWeights,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let mut ballot_box = BallotBox::new_for_test(&ncn, 3);
+        ballot_box
+            .cast_vote(
+                &operator,
+                &Ballot::new(WeatherStatus::Cloudy as u8),
+                &StakeWeights::snapshot(100).unwrap(),
+                10,
+                1_000,
+            )
+            .unwrap();
+
+        let mirror = ballot_box.to_mirror().unwrap();
+        assert_eq!(mirror.discriminator, BallotBox::DISCRIMINATOR as u64);
+        assert_eq!(mirror.ncn, ncn);
+        assert_eq!(mirror.epoch, 3);
+        assert_eq!(mirror.operator_votes[0].operator, operator);
+
+        let stored = mirror.try_to_vec().unwrap();
+        assert_eq!(stored, account_data(ballot_box.as_ref()));
+
+        let restored =
+            BallotBox::from_mirror(&accounts::BallotBox::try_from_slice(&stored).unwrap()).unwrap();
+        assert_eq!(account_data(restored.as_ref()), stored);
+    }
+
+    #[test]
+    fn test_from_mirror_wrong_discriminator() {
+        let mut mirror = ConsensusResult::new_for_test(&Pubkey::new_unique(), 1)
+            .to_mirror()
+            .unwrap();
+        mirror.discriminator = EpochMarker::DISCRIMINATOR as u64;
+
+        let error = ConsensusResult::from_mirror(&mirror).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,101 @@
+pub use ncn_program_core::error::{
+    describe_error_code, error_registry, NCNProgramError, NCNProgramErrorInfo,
+};
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::{
+    instruction::InstructionError, message::VersionedMessage, pubkey::Pubkey,
+    transaction::TransactionError,
+};
+
+/// The custom error code a transaction failed with, `None` for any other failure
+pub fn custom_error_code(error: &TransactionError) -> Option<u32> {
+    match error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+        _ => None,
+    }
+}
+
+/// Describes the custom error a transaction sent through the RPC client failed with, e.g.
+/// `OperatorRewardListFull (0x2235): Operator reward list full`. The code is looked up as an NCN
+/// program error, whichever program returned it.
+pub fn describe_client_error(error: &ClientError) -> Option<String> {
+    let transaction_error = error.get_transaction_error()?;
+    custom_error_code(&transaction_error).map(describe_error_code)
+}
+
+/// The error of a transaction failing because its work was already done, e.g. the account was
+/// created or the operator voted, see [`NCNProgramError::is_already_processed`]. Only errors of the
+/// instructions of `program_id` count, other programs return the same codes for other errors.
+pub fn already_processed_error(
+    message: &VersionedMessage,
+    program_id: &Pubkey,
+    error: &TransactionError,
+) -> Option<NCNProgramError> {
+    let TransactionError::InstructionError(index, InstructionError::Custom(code)) = error else {
+        return None;
+    };
+
+    // Programs are never loaded from lookup tables
+    let instruction = message.instructions().get(*index as usize)?;
+    if message
+        .static_account_keys()
+        .get(instruction.program_id_index as usize)?
+        .ne(program_id)
+    {
+        return None;
+    }
+
+    NCNProgramError::from_u32(*code).filter(|error| error.is_already_processed())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        instruction::Instruction, system_instruction::transfer, transaction::Transaction,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_already_processed_error() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let transaction = Transaction::new_with_payer(
+            &[
+                transfer(&payer, &Pubkey::new_unique(), 1),
+                Instruction::new_with_bytes(program_id, &[], vec![]),
+            ],
+            Some(&payer),
+        );
+        let message = VersionedMessage::Legacy(transaction.message);
+        let error = |index: u8, error: NCNProgramError| {
+            TransactionError::InstructionError(index, InstructionError::Custom(error.code()))
+        };
+
+        assert_eq!(
+            already_processed_error(
+                &message,
+                &program_id,
+                &error(1, NCNProgramError::OperatorAlreadyVoted)
+            ),
+            Some(NCNProgramError::OperatorAlreadyVoted)
+        );
+        assert_eq!(
+            already_processed_error(
+                &message,
+                &program_id,
+                &error(1, NCNProgramError::WeightTableNotFinalized)
+            ),
+            None
+        );
+        // Returned by another program
+        assert_eq!(
+            already_processed_error(
+                &message,
+                &program_id,
+                &error(0, NCNProgramError::OperatorAlreadyVoted)
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use anyhow::{anyhow, Result};
+use jito_bytemuck::AccountDeserialize;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    config::Config,
+    consensus_result::ConsensusResult,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    keeper_lease::KeeperLease,
+    ncn_reward_router::NCNRewardRouter,
+    operator_metadata::OperatorMetadata,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::pda;
+
+// ---------------------- HELPERS ----------------------
+/// Fetches an account with the commitment of the client, `None` if it does not exist
+pub async fn fetch_raw_account(rpc: &RpcClient, address: &Pubkey) -> Result<Option<Account>> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())
+        .await?;
+
+    Ok(account.value)
+}
+
+/// Fetches and deserializes a zero copy NCN program account
+pub async fn fetch_account<T: AccountDeserialize + Copy>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> Result<T> {
+    let account = fetch_raw_account(rpc, address)
+        .await?
+        .ok_or_else(|| anyhow!("Account not found: {}", address))?;
+
+    let account = T::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+/// Fetches and deserializes every program account matching the filters, see [`crate::filters`].
+/// Shrunk NCN reward routers do not deserialize, fetch them with [`fetch_ncn_reward_router`].
+pub async fn fetch_program_accounts<T: AccountDeserialize + Copy>(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, T)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: None,
+            commitment: Some(rpc.commitment()),
+            min_context_slot: None,
+        },
+        with_context: Some(false),
+        sort_results: Some(false),
+    };
+
+    let accounts = rpc
+        .get_program_accounts_with_config(program_id, config)
+        .await?;
+
+    accounts
+        .into_iter()
+        .map(|(address, account)| {
+            let account = T::try_from_slice_unchecked(account.data.as_slice())?;
+            Ok((address, *account))
+        })
+        .collect()
+}
+
+/// Whether an account exists at the address
+pub async fn account_exists(rpc: &RpcClient, address: &Pubkey) -> Result<bool> {
+    Ok(fetch_raw_account(rpc, address).await?.is_some())
+}
+
+// ---------------------- NCN ----------------------
+pub async fn fetch_config(rpc: &RpcClient, program_id: &Pubkey, ncn: &Pubkey) -> Result<Config> {
+    fetch_account(rpc, &pda::config_address(program_id, ncn)).await
+}
+
+pub async fn fetch_vault_registry(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+) -> Result<VaultRegistry> {
+    fetch_account(rpc, &pda::vault_registry_address(program_id, ncn)).await
+}
+
+pub async fn fetch_account_payer(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+) -> Result<Account> {
+    let address = pda::account_payer_address(program_id, ncn);
+    fetch_raw_account(rpc, &address)
+        .await?
+        .ok_or_else(|| anyhow!("Account not found: {}", address))
+}
+
+pub async fn fetch_operator_metadata(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+) -> Result<OperatorMetadata> {
+    fetch_account(
+        rpc,
+        &pda::operator_metadata_address(program_id, operator, ncn),
+    )
+    .await
+}
+
+pub async fn fetch_keeper_lease(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    authority: &Pubkey,
+) -> Result<KeeperLease> {
+    fetch_account(rpc, &pda::keeper_lease_address(program_id, ncn, authority)).await
+}
+
+// ---------------------- EPOCH ----------------------
+pub async fn fetch_epoch_marker(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<EpochMarker> {
+    fetch_account(rpc, &pda::epoch_marker_address(program_id, ncn, epoch)).await
+}
+
+/// An epoch is completed once all of its accounts are closed, which leaves the epoch marker
+pub async fn fetch_is_epoch_completed(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<bool> {
+    account_exists(rpc, &pda::epoch_marker_address(program_id, ncn, epoch)).await
+}
+
+pub async fn fetch_epoch_state(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<EpochState> {
+    fetch_account(rpc, &pda::epoch_state_address(program_id, ncn, epoch)).await
+}
+
+pub async fn fetch_weight_table(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<WeightTable> {
+    fetch_account(rpc, &pda::weight_table_address(program_id, ncn, epoch)).await
+}
+
+pub async fn fetch_epoch_snapshot(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<EpochSnapshot> {
+    fetch_account(rpc, &pda::epoch_snapshot_address(program_id, ncn, epoch)).await
+}
+
+pub async fn fetch_operator_snapshot(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<OperatorSnapshot> {
+    fetch_account(
+        rpc,
+        &pda::operator_snapshot_address(program_id, operator, ncn, epoch),
+    )
+    .await
+}
+
+pub async fn fetch_ballot_box(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<BallotBox> {
+    fetch_account(rpc, &pda::ballot_box_address(program_id, ncn, epoch)).await
+}
+
+pub async fn fetch_consensus_result(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<ConsensusResult> {
+    fetch_account(rpc, &pda::consensus_result_address(program_id, ncn, epoch)).await
+}
+
+// ---------------------- REWARDS ----------------------
+/// The NCN reward router can be shrunk once routing is done, it is padded back to its full size
+pub async fn fetch_ncn_reward_router(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<NCNRewardRouter> {
+    let address = pda::ncn_reward_router_address(program_id, ncn, epoch);
+    let account = fetch_raw_account(rpc, &address)
+        .await?
+        .ok_or_else(|| anyhow!("Account not found: {}", address))?;
+
+    Ok(NCNRewardRouter::try_from_shrunk_slice(
+        account.data.as_slice(),
+    )?)
+}
+
+pub async fn fetch_operator_vault_reward_router(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Result<OperatorVaultRewardRouter> {
+    fetch_account(
+        rpc,
+        &pda::operator_vault_reward_router_address(program_id, operator, ncn, epoch),
+    )
+    .await
+}
+
+/// Lamports held by a reward receiver above its rent exempt minimum, which are the rewards left
+/// to route
+pub async fn fetch_receiver_rewards(rpc: &RpcClient, receiver: &Pubkey) -> Result<u64> {
+    let account = fetch_raw_account(rpc, receiver)
+        .await?
+        .ok_or_else(|| anyhow!("Account not found: {}", receiver))?;
+
+    let rent = rpc.get_minimum_balance_for_rent_exemption(0).await?;
+
+    Ok(account.lamports.saturating_sub(rent))
+}
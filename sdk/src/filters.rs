@@ -0,0 +1,220 @@
+//! `getProgramAccounts` filters for the NCN program accounts. Every filter set matches the account
+//! discriminator, the optional fields narrow it down, e.g. all operator snapshots of an epoch:
+//!
+//! ```ignore
+//! let filters = operator_snapshot_filters(&ncn, None, Some(epoch));
+//! let snapshots = fetch_program_accounts::<OperatorSnapshot>(&rpc, &program_id, filters).await?;
+//! ```
+use jito_bytemuck::Discriminator;
+use ncn_program_core::{
+    ballot_box::BallotBox,
+    config::Config,
+    consensus_result::ConsensusResult,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    ncn_reward_router::NCNRewardRouter,
+    operator_metadata::OperatorMetadata,
+    operator_vault_reward_router::OperatorVaultRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+use solana_rpc_client_api::filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+
+/// The discriminator takes the first 8 bytes of every account, only the first one is set
+pub const DISCRIMINATOR_OFFSET: usize = 0;
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+pub fn memcmp_filter(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new(
+        offset,
+        MemcmpEncodedBytes::Bytes(bytes.to_vec()),
+    ))
+}
+
+pub fn discriminator_filter<T: Discriminator>() -> RpcFilterType {
+    let mut discriminator = [0; DISCRIMINATOR_LEN];
+    discriminator[0] = T::DISCRIMINATOR;
+    memcmp_filter(DISCRIMINATOR_OFFSET, &discriminator)
+}
+
+pub fn pubkey_filter(offset: usize, pubkey: &Pubkey) -> RpcFilterType {
+    memcmp_filter(offset, pubkey.as_ref())
+}
+
+/// Epochs are stored as little endian `u64`s
+pub fn epoch_filter(offset: usize, epoch: u64) -> RpcFilterType {
+    memcmp_filter(offset, &epoch.to_le_bytes())
+}
+
+fn ncn_filters<T: Discriminator>(ncn_offset: usize, ncn: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator_filter::<T>(), pubkey_filter(ncn_offset, ncn)]
+}
+
+fn epoch_account_filters<T: Discriminator>(
+    ncn_offset: usize,
+    epoch_offset: usize,
+    ncn: &Pubkey,
+    epoch: Option<u64>,
+) -> Vec<RpcFilterType> {
+    let mut filters = ncn_filters::<T>(ncn_offset, ncn);
+    if let Some(epoch) = epoch {
+        filters.push(epoch_filter(epoch_offset, epoch));
+    }
+    filters
+}
+
+fn operator_epoch_account_filters<T: Discriminator>(
+    operator_offset: usize,
+    ncn_offset: usize,
+    epoch_offset: usize,
+    ncn: &Pubkey,
+    operator: Option<&Pubkey>,
+    epoch: Option<u64>,
+) -> Vec<RpcFilterType> {
+    let mut filters = epoch_account_filters::<T>(ncn_offset, epoch_offset, ncn, epoch);
+    if let Some(operator) = operator {
+        filters.push(pubkey_filter(operator_offset, operator));
+    }
+    filters
+}
+
+// ---------------------- NCN ----------------------
+pub fn config_filters(ncn: &Pubkey) -> Vec<RpcFilterType> {
+    ncn_filters::<Config>(Config::NCN_OFFSET, ncn)
+}
+
+pub fn vault_registry_filters(ncn: &Pubkey) -> Vec<RpcFilterType> {
+    ncn_filters::<VaultRegistry>(VaultRegistry::NCN_OFFSET, ncn)
+}
+
+pub fn operator_metadata_filters(ncn: &Pubkey, operator: Option<&Pubkey>) -> Vec<RpcFilterType> {
+    let mut filters = ncn_filters::<OperatorMetadata>(OperatorMetadata::NCN_OFFSET, ncn);
+    if let Some(operator) = operator {
+        filters.push(pubkey_filter(OperatorMetadata::OPERATOR_OFFSET, operator));
+    }
+    filters
+}
+
+// ---------------------- EPOCH ----------------------
+pub fn epoch_state_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<EpochState>(
+        EpochState::NCN_OFFSET,
+        EpochState::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+pub fn epoch_marker_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<EpochMarker>(
+        EpochMarker::NCN_OFFSET,
+        EpochMarker::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+pub fn weight_table_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<WeightTable>(
+        WeightTable::NCN_OFFSET,
+        WeightTable::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+pub fn epoch_snapshot_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<EpochSnapshot>(
+        EpochSnapshot::NCN_OFFSET,
+        EpochSnapshot::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+pub fn operator_snapshot_filters(
+    ncn: &Pubkey,
+    operator: Option<&Pubkey>,
+    epoch: Option<u64>,
+) -> Vec<RpcFilterType> {
+    operator_epoch_account_filters::<OperatorSnapshot>(
+        OperatorSnapshot::OPERATOR_OFFSET,
+        OperatorSnapshot::NCN_OFFSET,
+        OperatorSnapshot::EPOCH_OFFSET,
+        ncn,
+        operator,
+        epoch,
+    )
+}
+
+pub fn ballot_box_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<BallotBox>(BallotBox::NCN_OFFSET, BallotBox::EPOCH_OFFSET, ncn, epoch)
+}
+
+pub fn consensus_result_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<ConsensusResult>(
+        ConsensusResult::NCN_OFFSET,
+        ConsensusResult::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+// ---------------------- REWARDS ----------------------
+pub fn ncn_reward_router_filters(ncn: &Pubkey, epoch: Option<u64>) -> Vec<RpcFilterType> {
+    epoch_account_filters::<NCNRewardRouter>(
+        NCNRewardRouter::NCN_OFFSET,
+        NCNRewardRouter::EPOCH_OFFSET,
+        ncn,
+        epoch,
+    )
+}
+
+pub fn operator_vault_reward_router_filters(
+    ncn: &Pubkey,
+    operator: Option<&Pubkey>,
+    epoch: Option<u64>,
+) -> Vec<RpcFilterType> {
+    operator_epoch_account_filters::<OperatorVaultRewardRouter>(
+        OperatorVaultRewardRouter::OPERATOR_OFFSET,
+        OperatorVaultRewardRouter::NCN_OFFSET,
+        OperatorVaultRewardRouter::EPOCH_OFFSET,
+        ncn,
+        operator,
+        epoch,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(filters: &[RpcFilterType], data: &[u8]) -> bool {
+        filters.iter().all(|filter| match filter {
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn test_epoch_account_filters() {
+        let ncn = Pubkey::new_unique();
+        let mut data = vec![0; BallotBox::SIZE];
+        data[0] = BallotBox::DISCRIMINATOR;
+        data[BallotBox::NCN_OFFSET..BallotBox::NCN_OFFSET + 32].copy_from_slice(ncn.as_ref());
+        data[BallotBox::EPOCH_OFFSET..BallotBox::EPOCH_OFFSET + 8]
+            .copy_from_slice(&7u64.to_le_bytes());
+
+        assert!(matches(&ballot_box_filters(&ncn, None), &data));
+        assert!(matches(&ballot_box_filters(&ncn, Some(7)), &data));
+        assert!(!matches(&ballot_box_filters(&ncn, Some(8)), &data));
+        assert!(!matches(
+            &ballot_box_filters(&Pubkey::new_unique(), Some(7)),
+            &data
+        ));
+        // Same layout, other account type
+        assert!(!matches(&consensus_result_filters(&ncn, Some(7)), &data));
+    }
+}
@@ -0,0 +1,77 @@
+//! Helpers to hand admin instructions to spl-governance, for NCNs whose admin is a governance of a
+//! realm.
+//!
+//! Proposal transactions of spl-governance store their instructions as `InstructionData`, the
+//! serialized instructions can be added to a proposal from the Realms UI or the governance CLI.
+//! The governance signs the instructions when the proposal is executed.
+use borsh::BorshSerialize;
+use solana_sdk::instruction::Instruction;
+
+/// `AccountMetaData` of spl-governance
+#[derive(BorshSerialize)]
+struct GovernanceAccountMeta {
+    pubkey: [u8; 32],
+    is_signer: bool,
+    is_writable: bool,
+}
+
+/// `InstructionData` of spl-governance
+#[derive(BorshSerialize)]
+struct GovernanceInstruction {
+    program_id: [u8; 32],
+    accounts: Vec<GovernanceAccountMeta>,
+    data: Vec<u8>,
+}
+
+/// The instruction serialized as the `InstructionData` of an spl-governance proposal transaction
+pub fn governance_instruction_data(instruction: &Instruction) -> Vec<u8> {
+    GovernanceInstruction {
+        program_id: instruction.program_id.to_bytes(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|account| GovernanceAccountMeta {
+                pubkey: account.pubkey.to_bytes(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: instruction.data.clone(),
+    }
+    .try_to_vec()
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+    use super::*;
+
+    #[test]
+    fn test_governance_instruction_data() {
+        let program_id = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let governance = Pubkey::new_unique();
+
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(governance, true),
+            ],
+            data: vec![1, 2, 3],
+        };
+
+        let data = governance_instruction_data(&instruction);
+
+        assert_eq!(&data[..32], program_id.as_ref());
+        assert_eq!(&data[32..36], &2u32.to_le_bytes());
+        assert_eq!(&data[36..68], config.as_ref());
+        assert_eq!(&data[68..70], &[0, 1]);
+        assert_eq!(&data[70..102], governance.as_ref());
+        assert_eq!(&data[102..104], &[1, 0]);
+        assert_eq!(&data[104..108], &3u32.to_le_bytes());
+        assert_eq!(&data[108..], &[1, 2, 3]);
+    }
+}
@@ -0,0 +1,322 @@
+//! Instruction builders for the permissionless epoch flow, operator voting and the keeper lease.
+//! Every account is derived from the NCN, epoch and operator, the builders only take what can not
+//! be derived.
+use jito_restaking_core::{
+    config::Config as RestakingConfig, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket,
+};
+use jito_vault_core::{
+    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+};
+use ncn_program_client::instructions::{
+    AcquireKeeperLeaseBuilder, CastVoteBuilder, InitializeEpochSnapshotBuilder,
+    InitializeEpochStateBuilder, InitializeOperatorSnapshotBuilder, InitializeWeightTableBuilder,
+    RouteNCNRewardsBuilder, SetEpochWeightsBuilder, SnapshotVaultOperatorDelegationBuilder,
+};
+use ncn_program_core::ncn_reward_router::NCNRewardRouter;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::pda;
+
+/// The generated builders target the default program id, forks deploy under their own
+fn with_program_id(mut instruction: Instruction, program_id: &Pubkey) -> Instruction {
+    instruction.program_id = *program_id;
+    instruction
+}
+
+fn readonly_accounts(addresses: &[Pubkey]) -> Vec<AccountMeta> {
+    addresses
+        .iter()
+        .map(|address| AccountMeta::new_readonly(*address, false))
+        .collect()
+}
+
+pub fn initialize_epoch_state(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Instruction {
+    let ix = InitializeEpochStateBuilder::new()
+        .epoch_marker(pda::epoch_marker_address(program_id, ncn, epoch))
+        .config(pda::config_address(program_id, ncn))
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .ncn(*ncn)
+        .account_payer(pda::account_payer_address(program_id, ncn))
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .init_if_needed(true)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+pub fn initialize_weight_table(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Instruction {
+    let ix = InitializeWeightTableBuilder::new()
+        .epoch_marker(pda::epoch_marker_address(program_id, ncn, epoch))
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .vault_registry(pda::vault_registry_address(program_id, ncn))
+        .ncn(*ncn)
+        .weight_table(pda::weight_table_address(program_id, ncn, epoch))
+        .account_payer(pda::account_payer_address(program_id, ncn))
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .init_if_needed(true)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+/// `vaults` are only read when weight decay is enabled, pass the valid vaults of the registry
+pub fn set_epoch_weights(
+    program_id: &Pubkey,
+    restaking_program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    vaults: &[Pubkey],
+) -> Instruction {
+    let ix = SetEpochWeightsBuilder::new()
+        .ncn(*ncn)
+        .weight_table(pda::weight_table_address(program_id, ncn, epoch))
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .vault_registry(pda::vault_registry_address(program_id, ncn))
+        .config(pda::config_address(program_id, ncn))
+        .restaking_config(RestakingConfig::find_program_address(restaking_program_id).0)
+        .epoch(epoch)
+        .add_remaining_accounts(&readonly_accounts(vaults))
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+/// `vaults` are only read when the vault stake weight cap is enabled, pass the vaults of the
+/// weight table
+pub fn initialize_epoch_snapshot(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    vaults: &[Pubkey],
+) -> Instruction {
+    let ix = InitializeEpochSnapshotBuilder::new()
+        .epoch_marker(pda::epoch_marker_address(program_id, ncn, epoch))
+        .config(pda::config_address(program_id, ncn))
+        .ncn(*ncn)
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .weight_table(pda::weight_table_address(program_id, ncn, epoch))
+        .epoch_snapshot(pda::epoch_snapshot_address(program_id, ncn, epoch))
+        .account_payer(pda::account_payer_address(program_id, ncn))
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .init_if_needed(true)
+        .add_remaining_accounts(&readonly_accounts(vaults))
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+pub fn initialize_operator_snapshot(
+    program_id: &Pubkey,
+    restaking_program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let ix = InitializeOperatorSnapshotBuilder::new()
+        .epoch_marker(pda::epoch_marker_address(program_id, ncn, epoch))
+        .config(pda::config_address(program_id, ncn))
+        .restaking_config(RestakingConfig::find_program_address(restaking_program_id).0)
+        .ncn(*ncn)
+        .operator(*operator)
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .ncn_operator_state(
+            NcnOperatorState::find_program_address(restaking_program_id, ncn, operator).0,
+        )
+        .epoch_snapshot(pda::epoch_snapshot_address(program_id, ncn, epoch))
+        .operator_snapshot(pda::operator_snapshot_address(
+            program_id, operator, ncn, epoch,
+        ))
+        .account_payer(pda::account_payer_address(program_id, ncn))
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .init_if_needed(true)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+pub fn snapshot_vault_operator_delegation(
+    program_id: &Pubkey,
+    restaking_program_id: &Pubkey,
+    vault_program_id: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let ix = SnapshotVaultOperatorDelegationBuilder::new()
+        .config(pda::config_address(program_id, ncn))
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .restaking_config(RestakingConfig::find_program_address(restaking_program_id).0)
+        .ncn(*ncn)
+        .operator(*operator)
+        .vault(*vault)
+        .vault_ncn_ticket(VaultNcnTicket::find_program_address(vault_program_id, vault, ncn).0)
+        .ncn_vault_ticket(NcnVaultTicket::find_program_address(restaking_program_id, ncn, vault).0)
+        .vault_operator_delegation(
+            VaultOperatorDelegation::find_program_address(vault_program_id, vault, operator).0,
+        )
+        .weight_table(pda::weight_table_address(program_id, ncn, epoch))
+        .epoch_snapshot(pda::epoch_snapshot_address(program_id, ncn, epoch))
+        .operator_snapshot(pda::operator_snapshot_address(
+            program_id, operator, ncn, epoch,
+        ))
+        .epoch(epoch)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+/// `operator_voter` is the voter set on the operator, it has to sign the transaction
+pub fn cast_vote(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_voter: &Pubkey,
+    weather_status: u8,
+    epoch: u64,
+) -> Instruction {
+    let ix = CastVoteBuilder::new()
+        .config(pda::config_address(program_id, ncn))
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .ballot_box(pda::ballot_box_address(program_id, ncn, epoch))
+        .ncn(*ncn)
+        .epoch_snapshot(pda::epoch_snapshot_address(program_id, ncn, epoch))
+        .operator_snapshot(pda::operator_snapshot_address(
+            program_id, operator, ncn, epoch,
+        ))
+        .operator(*operator)
+        .operator_voter(*operator_voter)
+        .consensus_result(pda::consensus_result_address(program_id, ncn, epoch))
+        .weather_status(weather_status)
+        .epoch(epoch)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+/// Routes SOL rewards, resend until the router is no longer `still_routing`. The `cranker` has to
+/// sign, it is paid the configured cranker fee out of the newly routed rewards.
+pub fn route_ncn_rewards(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    cranker: &Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let ix = RouteNCNRewardsBuilder::new()
+        .epoch_state(pda::epoch_state_address(program_id, ncn, epoch))
+        .config(pda::config_address(program_id, ncn))
+        .ncn(*ncn)
+        .epoch_snapshot(pda::epoch_snapshot_address(program_id, ncn, epoch))
+        .ballot_box(pda::ballot_box_address(program_id, ncn, epoch))
+        .ncn_reward_router(pda::ncn_reward_router_address(program_id, ncn, epoch))
+        .ncn_reward_receiver(pda::ncn_reward_receiver_address(program_id, ncn, epoch))
+        .cranker(Some(*cranker))
+        .system_program(Some(system_program::id()))
+        .max_iterations(NCNRewardRouter::MAX_ROUTE_BASE_ITERATIONS)
+        .epoch(epoch)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+/// Takes or renews the keeper lease of `authority` for `holder`, `lease_slots` of `0` releases it.
+/// The `authority` has to sign, it pays for the lease the first time.
+pub fn acquire_keeper_lease(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    authority: &Pubkey,
+    holder: &Pubkey,
+    lease_slots: u64,
+) -> Instruction {
+    let ix = AcquireKeeperLeaseBuilder::new()
+        .ncn(*ncn)
+        .keeper_lease(pda::keeper_lease_address(program_id, ncn, authority))
+        .authority(*authority)
+        .system_program(system_program::id())
+        .holder(*holder)
+        .lease_slots(lease_slots)
+        .instruction();
+
+    with_program_id(ix, program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instructions_use_program_id() {
+        let program_id = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let epoch = 100;
+
+        let ix = initialize_epoch_state(&program_id, &ncn, epoch);
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(
+            ix.accounts[0].pubkey,
+            pda::epoch_marker_address(&program_id, &ncn, epoch)
+        );
+        assert_ne!(
+            ix.accounts[0].pubkey,
+            pda::epoch_marker_address(&crate::NCN_PROGRAM_ID, &ncn, epoch)
+        );
+    }
+
+    #[test]
+    fn test_cast_vote_accounts() {
+        let program_id = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let operator_voter = Pubkey::new_unique();
+        let epoch = 100;
+
+        let ix = cast_vote(&program_id, &ncn, &operator, &operator_voter, 1, epoch);
+
+        let voter = ix
+            .accounts
+            .iter()
+            .find(|account| account.pubkey == operator_voter)
+            .unwrap();
+        assert!(voter.is_signer);
+
+        let ballot_box = pda::ballot_box_address(&program_id, &ncn, epoch);
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == ballot_box && account.is_writable));
+        assert!(ix.accounts.iter().any(|account| account.pubkey
+            == pda::operator_snapshot_address(&program_id, &operator, &ncn, epoch)));
+    }
+
+    #[test]
+    fn test_remaining_accounts() {
+        let program_id = Pubkey::new_unique();
+        let restaking_program_id = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let vaults = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let without_vaults = set_epoch_weights(&program_id, &restaking_program_id, &ncn, 1, &[]);
+        let with_vaults = set_epoch_weights(&program_id, &restaking_program_id, &ncn, 1, &vaults);
+
+        assert_eq!(
+            with_vaults.accounts.len(),
+            without_vaults.accounts.len() + vaults.len()
+        );
+        assert_eq!(
+            with_vaults.accounts[without_vaults.accounts.len()..]
+                .iter()
+                .map(|account| account.pubkey)
+                .collect::<Vec<_>>(),
+            vaults
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Client SDK for the NCN program, for services that read the program accounts and build its
+//! instructions without going through the CLI.
+//!
+//! - [`pda`]: addresses of the NCN program accounts, derived with `ncn_program_core::pdas`
+//! - [`borsh_mirror`]: conversions of the zero copy accounts to and from their Borsh structs
+//! - [`errors`]: the NCN program error registry, to show failed transactions by error name
+//! - [`fetch`]: async fetchers returning the deserialized accounts
+//! - [`filters`]: `getProgramAccounts` filters to list accounts by NCN, operator and epoch
+//! - [`governance`]: serializing admin instructions for spl-governance proposals
+//! - [`instructions`]: instruction builders deriving every account from the NCN and epoch
+//! - [`lookup_tables`]: address lookup tables of the epoch accounts cranked for every operator
+//! - [`packing`]: packing many instructions into as few transactions as size and compute allow
+//! - [`squads`]: wrapping admin instructions in Squads vault transactions for multisig NCN admins
+
+pub mod borsh_mirror;
+pub mod errors;
+pub mod fetch;
+pub mod filters;
+pub mod governance;
+pub mod instructions;
+pub mod lookup_tables;
+pub mod packing;
+pub mod pda;
+pub mod squads;
+
+pub use ncn_program_client::programs::NCN_PROGRAM_ID;
@@ -0,0 +1,126 @@
+//! Address lookup tables of the epoch accounts, so transactions cranking many operators load them
+//! by index instead of by address. Every epoch lookup table starts with the epoch state, which
+//! is how it is found again among the tables of its authority:
+//!
+//! ```ignore
+//! let addresses = epoch_lookup_table_addresses(&program_id, &ncn, epoch, &operators);
+//! let filters = epoch_lookup_table_filters(&program_id, &ncn, epoch, &authority);
+//! ```
+use solana_rpc_client_api::filter::RpcFilterType;
+use solana_sdk::{address_lookup_table::state::LOOKUP_TABLE_META_SIZE, pubkey::Pubkey};
+
+use crate::{
+    filters::{memcmp_filter, pubkey_filter},
+    pda::{
+        ballot_box_address, consensus_result_address, epoch_snapshot_address, epoch_state_address,
+        ncn_reward_receiver_address, ncn_reward_router_address, operator_snapshot_address,
+        operator_vault_reward_receiver_address, operator_vault_reward_router_address,
+        weight_table_address,
+    },
+};
+
+/// The `Option<Pubkey>` authority of the lookup table, after its type and slots
+pub const LOOKUP_TABLE_AUTHORITY_OFFSET: usize = 21;
+
+/// The epoch accounts cranked for every operator or every epoch, the epoch state first
+pub fn epoch_lookup_table_addresses(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    operators: &[Pubkey],
+) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        epoch_state_address(program_id, ncn, epoch),
+        weight_table_address(program_id, ncn, epoch),
+        epoch_snapshot_address(program_id, ncn, epoch),
+        ballot_box_address(program_id, ncn, epoch),
+        consensus_result_address(program_id, ncn, epoch),
+        ncn_reward_router_address(program_id, ncn, epoch),
+        ncn_reward_receiver_address(program_id, ncn, epoch),
+    ];
+
+    for operator in operators {
+        addresses.push(operator_snapshot_address(program_id, operator, ncn, epoch));
+        addresses.push(operator_vault_reward_router_address(
+            program_id, operator, ncn, epoch,
+        ));
+        addresses.push(operator_vault_reward_receiver_address(
+            program_id, operator, ncn, epoch,
+        ));
+    }
+
+    addresses
+}
+
+/// `getProgramAccounts` filters of the address lookup table program matching the epoch lookup
+/// tables of `authority`
+pub fn epoch_lookup_table_filters(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    authority: &Pubkey,
+) -> Vec<RpcFilterType> {
+    let mut authority_bytes = vec![1];
+    authority_bytes.extend_from_slice(authority.as_ref());
+
+    vec![
+        memcmp_filter(LOOKUP_TABLE_AUTHORITY_OFFSET, &authority_bytes),
+        pubkey_filter(
+            LOOKUP_TABLE_META_SIZE,
+            &epoch_state_address(program_id, ncn, epoch),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::address_lookup_table::state::{
+        AddressLookupTable, LookupTableMeta, ProgramState,
+    };
+
+    use super::*;
+
+    fn matches(filters: &[RpcFilterType], data: &[u8]) -> bool {
+        filters.iter().all(|filter| match filter {
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn test_epoch_lookup_table_filters() {
+        let program_id = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let operators = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let addresses = epoch_lookup_table_addresses(&program_id, &ncn, 7, &operators);
+        assert_eq!(addresses.len(), 13);
+
+        let meta = LookupTableMeta {
+            authority: Some(authority),
+            ..LookupTableMeta::default()
+        };
+        let mut data = bincode::serialize(&ProgramState::LookupTable(meta)).unwrap();
+        data.resize(LOOKUP_TABLE_META_SIZE, 0);
+        for address in addresses.iter() {
+            data.extend_from_slice(address.as_ref());
+        }
+
+        let lookup_table = AddressLookupTable::deserialize(&data).unwrap();
+        assert_eq!(lookup_table.addresses.to_vec(), addresses);
+
+        assert!(matches(
+            &epoch_lookup_table_filters(&program_id, &ncn, 7, &authority),
+            &data
+        ));
+        assert!(!matches(
+            &epoch_lookup_table_filters(&program_id, &ncn, 8, &authority),
+            &data
+        ));
+        assert!(!matches(
+            &epoch_lookup_table_filters(&program_id, &ncn, 7, &Pubkey::new_unique()),
+            &data
+        ));
+    }
+}
@@ -0,0 +1,377 @@
+//! Packing of independent instructions into as few transactions as their serialized size and
+//! compute budget allow, for cranks sending many of them, e.g. reallocations or snapshots.
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, Message, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+};
+
+pub const MAX_COMPUTE_UNITS_PER_TRANSACTION: u32 = 1_400_000;
+
+const SIGNATURE_SIZE: usize = 64;
+
+/// An instruction with the compute units it is expected to consume
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetedInstruction {
+    pub instruction: Instruction,
+    pub compute_units: u32,
+}
+
+impl BudgetedInstruction {
+    pub const fn new(instruction: Instruction, compute_units: u32) -> Self {
+        Self {
+            instruction,
+            compute_units,
+        }
+    }
+}
+
+/// Packs instructions, in order, into transactions paid by one payer
+///
+/// Every packed transaction starts with a compute unit limit covering its instructions. Room is
+/// left for the instructions the sender adds to every transaction: a compute unit price, and any
+/// other given with [`TransactionPacker::reserve`], e.g. a Jito tip.
+#[derive(Debug, Clone)]
+pub struct TransactionPacker {
+    payer: Pubkey,
+    max_transaction_size: usize,
+    max_compute_units: u32,
+    reserved_instructions: Vec<Instruction>,
+    /// Lookup tables of the v0 transactions, `None` for legacy transactions
+    lookup_tables: Option<Vec<AddressLookupTableAccount>>,
+}
+
+impl TransactionPacker {
+    pub fn new(payer: &Pubkey) -> Self {
+        Self {
+            payer: *payer,
+            max_transaction_size: PACKET_DATA_SIZE,
+            max_compute_units: MAX_COMPUTE_UNITS_PER_TRANSACTION,
+            reserved_instructions: vec![ComputeBudgetInstruction::set_compute_unit_price(0)],
+            lookup_tables: None,
+        }
+    }
+
+    pub fn max_transaction_size(mut self, max_transaction_size: usize) -> Self {
+        self.max_transaction_size = max_transaction_size;
+        self
+    }
+
+    pub fn max_compute_units(mut self, max_compute_units: u32) -> Self {
+        self.max_compute_units = max_compute_units;
+        self
+    }
+
+    /// Leaves room in every transaction for an instruction the sender adds
+    pub fn reserve(mut self, instruction: Instruction) -> Self {
+        self.reserved_instructions.push(instruction);
+        self
+    }
+
+    /// Packs v0 transactions, whose accounts found in the lookup tables take one byte instead of
+    /// their 32 bytes address
+    pub fn versioned(mut self, lookup_tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = Some(lookup_tables);
+        self
+    }
+
+    /// Size of the signed transaction holding the instructions and the reserved ones
+    pub fn transaction_size(&self, instructions: &[Instruction]) -> usize {
+        let mut all_instructions = self.reserved_instructions.clone();
+        all_instructions.extend_from_slice(instructions);
+
+        let message = match &self.lookup_tables {
+            Some(lookup_tables) => v0::Message::try_compile(
+                &self.payer,
+                &all_instructions,
+                lookup_tables,
+                Hash::default(),
+            )
+            .map(VersionedMessage::V0),
+            None => Ok(VersionedMessage::Legacy(Message::new(
+                &all_instructions,
+                Some(&self.payer),
+            ))),
+        };
+
+        // Too many accounts to compile, a transaction can not hold them either
+        let Ok(message) = message else {
+            return usize::MAX;
+        };
+        let num_signatures = message.header().num_required_signatures as usize;
+
+        short_vec_size(num_signatures) + num_signatures * SIGNATURE_SIZE + message.serialize().len()
+    }
+
+    /// Splits the instructions into transactions, keeping their order
+    ///
+    /// Fails when a single instruction does not fit in a transaction, by size or compute units.
+    pub fn pack(&self, instructions: &[BudgetedInstruction]) -> Result<Vec<Vec<Instruction>>> {
+        let mut transactions = vec![];
+        let mut current: Vec<Instruction> = vec![];
+        let mut current_compute_units: u32 = 0;
+
+        for budgeted in instructions {
+            if !current.is_empty() && !self.fits(&current, current_compute_units, budgeted) {
+                transactions.push(with_compute_unit_limit(
+                    current_compute_units,
+                    std::mem::take(&mut current),
+                ));
+                current_compute_units = 0;
+            }
+
+            if current.is_empty() && !self.fits(&current, 0, budgeted) {
+                return Err(anyhow!(
+                    "Instruction of program {} does not fit in a transaction: {} compute units, {} of {} bytes",
+                    budgeted.instruction.program_id,
+                    budgeted.compute_units,
+                    self.transaction_size(&with_compute_unit_limit(
+                        budgeted.compute_units,
+                        vec![budgeted.instruction.clone()]
+                    )),
+                    self.max_transaction_size
+                ));
+            }
+
+            current.push(budgeted.instruction.clone());
+            current_compute_units = current_compute_units.saturating_add(budgeted.compute_units);
+        }
+
+        if !current.is_empty() {
+            transactions.push(with_compute_unit_limit(current_compute_units, current));
+        }
+
+        Ok(transactions)
+    }
+
+    fn fits(
+        &self,
+        current: &[Instruction],
+        current_compute_units: u32,
+        budgeted: &BudgetedInstruction,
+    ) -> bool {
+        let compute_units = current_compute_units.saturating_add(budgeted.compute_units);
+        if compute_units > self.max_compute_units {
+            return false;
+        }
+
+        let mut instructions = current.to_vec();
+        instructions.push(budgeted.instruction.clone());
+
+        self.transaction_size(&with_compute_unit_limit(compute_units, instructions))
+            <= self.max_transaction_size
+    }
+}
+
+fn with_compute_unit_limit(compute_units: u32, instructions: Vec<Instruction>) -> Vec<Instruction> {
+    let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+        compute_units,
+    )];
+    all_instructions.extend(instructions);
+    all_instructions
+}
+
+/// Size of the compact-u16 length prefix of serialized vectors
+const fn short_vec_size(len: usize) -> usize {
+    if len < 0x80 {
+        1
+    } else if len < 0x4000 {
+        2
+    } else {
+        3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        instruction::AccountMeta,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, VersionedTransaction},
+    };
+
+    use super::*;
+
+    fn instruction(program_id: &Pubkey, accounts: usize, data_len: usize) -> Instruction {
+        Instruction {
+            program_id: *program_id,
+            accounts: (0..accounts)
+                .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+                .collect(),
+            data: vec![1; data_len],
+        }
+    }
+
+    #[test]
+    fn test_transaction_size() {
+        let payer = Keypair::new();
+        let packer = TransactionPacker::new(&payer.pubkey());
+        let instructions = vec![instruction(&Pubkey::new_unique(), 3, 10)];
+
+        let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(1_000)];
+        all_instructions.extend_from_slice(&instructions);
+        let tx = Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::default(),
+        );
+
+        let serialized = bincode::serialize(&tx).unwrap();
+        assert_eq!(packer.transaction_size(&instructions), serialized.len());
+    }
+
+    #[test]
+    fn test_pack_same_instructions() {
+        let payer = Pubkey::new_unique();
+        let realloc = instruction(&Pubkey::new_unique(), 5, 1);
+
+        let transactions = TransactionPacker::new(&payer)
+            .pack(&vec![BudgetedInstruction::new(realloc.clone(), 20_000); 10])
+            .unwrap();
+
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].len(), 11);
+        assert_eq!(
+            transactions[0][0],
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000)
+        );
+        assert!(transactions[0][1..].iter().all(|ix| ix.eq(&realloc)));
+    }
+
+    #[test]
+    fn test_pack_by_compute_units() {
+        let payer = Pubkey::new_unique();
+        let realloc = instruction(&Pubkey::new_unique(), 5, 1);
+
+        let transactions = TransactionPacker::new(&payer)
+            .max_compute_units(100_000)
+            .pack(&vec![BudgetedInstruction::new(realloc, 30_000); 7])
+            .unwrap();
+
+        let sizes: Vec<usize> = transactions.iter().map(|tx| tx.len() - 1).collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+        assert_eq!(
+            transactions[2][0],
+            ComputeBudgetInstruction::set_compute_unit_limit(30_000)
+        );
+    }
+
+    #[test]
+    fn test_pack_by_size() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let packer = TransactionPacker::new(&payer);
+
+        // Every instruction brings its own accounts, few fit in a packet
+        let instructions: Vec<BudgetedInstruction> = (0..20)
+            .map(|_| BudgetedInstruction::new(instruction(&program_id, 6, 8), 10_000))
+            .collect();
+
+        let transactions = packer.pack(&instructions).unwrap();
+        assert!(transactions.len() > 1);
+
+        let packed: Vec<&Instruction> = transactions.iter().flat_map(|tx| &tx[1..]).collect();
+        let expected: Vec<&Instruction> = instructions.iter().map(|ix| &ix.instruction).collect();
+        assert_eq!(packed, expected);
+
+        for transaction in transactions.iter() {
+            assert!(packer.transaction_size(transaction) <= PACKET_DATA_SIZE);
+        }
+
+        // A Jito tip takes room from every transaction
+        let tipped = packer
+            .clone()
+            .reserve(instruction(&program_id, 2, 12))
+            .pack(&instructions)
+            .unwrap();
+        assert!(tipped.len() >= transactions.len());
+    }
+
+    #[test]
+    fn test_pack_instruction_too_large() {
+        let payer = Pubkey::new_unique();
+        let packer = TransactionPacker::new(&payer);
+
+        let large = instruction(&Pubkey::new_unique(), 1, PACKET_DATA_SIZE);
+        assert!(packer
+            .pack(&[BudgetedInstruction::new(large, 10_000)])
+            .is_err());
+
+        let expensive = instruction(&Pubkey::new_unique(), 1, 1);
+        assert!(packer
+            .pack(&[BudgetedInstruction::new(
+                expensive,
+                MAX_COMPUTE_UNITS_PER_TRANSACTION + 1
+            )])
+            .is_err());
+
+        assert!(packer.pack(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_versioned_transaction_size() {
+        let payer = Keypair::new();
+        let instructions = vec![instruction(&Pubkey::new_unique(), 3, 10)];
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: instructions[0]
+                .accounts
+                .iter()
+                .map(|account| account.pubkey)
+                .collect(),
+        };
+        let packer = TransactionPacker::new(&payer.pubkey()).versioned(vec![lookup_table.clone()]);
+
+        let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(1_000)];
+        all_instructions.extend_from_slice(&instructions);
+        let message = v0::Message::try_compile(
+            &payer.pubkey(),
+            &all_instructions,
+            &[lookup_table],
+            Hash::default(),
+        )
+        .unwrap();
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap();
+
+        let serialized = bincode::serialize(&tx).unwrap();
+        assert_eq!(packer.transaction_size(&instructions), serialized.len());
+        assert!(
+            packer.transaction_size(&instructions)
+                < TransactionPacker::new(&payer.pubkey()).transaction_size(&instructions)
+        );
+    }
+
+    #[test]
+    fn test_pack_with_lookup_tables() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+
+        let instructions: Vec<BudgetedInstruction> = (0..20)
+            .map(|_| BudgetedInstruction::new(instruction(&program_id, 6, 8), 10_000))
+            .collect();
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: instructions
+                .iter()
+                .flat_map(|budgeted| &budgeted.instruction.accounts)
+                .map(|account| account.pubkey)
+                .collect(),
+        };
+
+        let legacy = TransactionPacker::new(&payer).pack(&instructions).unwrap();
+        let packer = TransactionPacker::new(&payer).versioned(vec![lookup_table]);
+        let versioned = packer.pack(&instructions).unwrap();
+
+        assert!(versioned.len() < legacy.len());
+        for transaction in versioned.iter() {
+            assert!(packer.transaction_size(transaction) <= PACKET_DATA_SIZE);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Addresses of the NCN program accounts, derived with [`NCNProgramPda`]
+
+pub use ncn_program_core::pdas::NCNProgramPda;
+use solana_sdk::pubkey::Pubkey;
+
+// ---------------------- NCN ----------------------
+pub fn config_address(program_id: &Pubkey, ncn: &Pubkey) -> Pubkey {
+    NCNProgramPda::Config { ncn: *ncn }.address(program_id)
+}
+
+pub fn vault_registry_address(program_id: &Pubkey, ncn: &Pubkey) -> Pubkey {
+    NCNProgramPda::VaultRegistry { ncn: *ncn }.address(program_id)
+}
+
+pub fn account_payer_address(program_id: &Pubkey, ncn: &Pubkey) -> Pubkey {
+    NCNProgramPda::AccountPayer { ncn: *ncn }.address(program_id)
+}
+
+pub fn operator_metadata_address(program_id: &Pubkey, operator: &Pubkey, ncn: &Pubkey) -> Pubkey {
+    NCNProgramPda::OperatorMetadata {
+        operator: *operator,
+        ncn: *ncn,
+    }
+    .address(program_id)
+}
+
+pub fn keeper_lease_address(program_id: &Pubkey, ncn: &Pubkey, authority: &Pubkey) -> Pubkey {
+    NCNProgramPda::KeeperLease {
+        ncn: *ncn,
+        authority: *authority,
+    }
+    .address(program_id)
+}
+
+// ---------------------- EPOCH ----------------------
+pub fn epoch_marker_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::EpochMarker { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn epoch_state_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::EpochState { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn weight_table_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::WeightTable { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn epoch_snapshot_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::EpochSnapshot { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn operator_snapshot_address(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    NCNProgramPda::OperatorSnapshot {
+        operator: *operator,
+        ncn: *ncn,
+        epoch,
+    }
+    .address(program_id)
+}
+
+pub fn ballot_box_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::BallotBox { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn consensus_result_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::ConsensusResult { ncn: *ncn, epoch }.address(program_id)
+}
+
+// ---------------------- REWARDS ----------------------
+pub fn ncn_reward_router_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::NCNRewardRouter { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn ncn_reward_receiver_address(program_id: &Pubkey, ncn: &Pubkey, epoch: u64) -> Pubkey {
+    NCNProgramPda::NCNRewardReceiver { ncn: *ncn, epoch }.address(program_id)
+}
+
+pub fn operator_vault_reward_router_address(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    NCNProgramPda::OperatorVaultRewardRouter {
+        operator: *operator,
+        ncn: *ncn,
+        epoch,
+    }
+    .address(program_id)
+}
+
+pub fn operator_vault_reward_receiver_address(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    NCNProgramPda::OperatorVaultRewardReceiver {
+        operator: *operator,
+        ncn: *ncn,
+        epoch,
+    }
+    .address(program_id)
+}
@@ -0,0 +1,368 @@
+//! Helpers to run admin instructions from a Squads v4 multisig, whose vault PDA is the NCN admin.
+//!
+//! Admin instructions are wrapped in a vault transaction and proposed to the multisig, the members
+//! approve and execute it from their usual Squads tooling. The vault signs the wrapped instructions
+//! when the transaction is executed, so it has to be their only signer.
+use anyhow::{anyhow, Result};
+use borsh::BorshSerialize;
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+    system_program,
+};
+
+pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+/// Offset of `transaction_index` in a multisig account: discriminator, create key, config
+/// authority, threshold and time lock
+const MULTISIG_TRANSACTION_INDEX_OFFSET: usize = 8 + 32 + 32 + 2 + 4;
+
+pub fn vault_address(multisig: &Pubkey, vault_index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn transaction_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            SEED_TRANSACTION,
+            &transaction_index.to_le_bytes(),
+        ],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn proposal_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            SEED_TRANSACTION,
+            &transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Index of the last transaction of a multisig account, the next transaction uses the one after
+pub fn multisig_transaction_index(multisig_data: &[u8]) -> Result<u64> {
+    let bytes = multisig_data
+        .get(MULTISIG_TRANSACTION_INDEX_OFFSET..MULTISIG_TRANSACTION_INDEX_OFFSET + 8)
+        .ok_or_else(|| anyhow!("Account is too small to be a Squads multisig"))?;
+
+    Ok(u64::from_le_bytes(bytes.try_into()?))
+}
+
+/// Serializes instructions into the `TransactionMessage` of a vault transaction, with the vault
+/// as its payer. The account keys are ordered like a legacy message: writable signers, read-only
+/// signers, writable and then read-only non-signers.
+pub fn compile_vault_transaction_message(
+    vault: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<Vec<u8>> {
+    // (key, is_signer, is_writable) in order of appearance, the vault first
+    let mut keys: Vec<(Pubkey, bool, bool)> = vec![(*vault, true, true)];
+    let mut add_key = |pubkey: &Pubkey, is_signer: bool, is_writable: bool| match keys
+        .iter_mut()
+        .find(|(key, _, _)| key.eq(pubkey))
+    {
+        Some((_, signer, writable)) => {
+            *signer |= is_signer;
+            *writable |= is_writable;
+        }
+        None => keys.push((*pubkey, is_signer, is_writable)),
+    };
+
+    for instruction in instructions {
+        add_key(&instruction.program_id, false, false);
+        for account in instruction.accounts.iter() {
+            add_key(&account.pubkey, account.is_signer, account.is_writable);
+        }
+    }
+
+    if let Some((signer, _, _)) = keys
+        .iter()
+        .find(|(key, is_signer, _)| *is_signer && key.ne(vault))
+    {
+        return Err(anyhow!(
+            "Only the vault can sign a vault transaction, {} is a signer",
+            signer
+        ));
+    }
+
+    let group_len =
+        |is_signer: bool, is_writable: bool| keys_in_group(&keys, is_signer, is_writable).count();
+    let account_keys: Vec<Pubkey> = keys_in_group(&keys, true, true)
+        .chain(keys_in_group(&keys, true, false))
+        .chain(keys_in_group(&keys, false, true))
+        .chain(keys_in_group(&keys, false, false))
+        .collect();
+
+    let index_of = |pubkey: &Pubkey| -> Result<u8> {
+        let index = account_keys
+            .iter()
+            .position(|key| key.eq(pubkey))
+            .ok_or_else(|| anyhow!("Missing account key {}", pubkey))?;
+        u8::try_from(index).map_err(|_| anyhow!("Too many accounts for a vault transaction"))
+    };
+
+    let count = |len: usize| -> Result<u8> {
+        u8::try_from(len).map_err(|_| anyhow!("Too many entries for a vault transaction"))
+    };
+
+    let mut message = vec![
+        count(group_len(true, true) + group_len(true, false))?,
+        count(group_len(true, true))?,
+        count(group_len(false, true))?,
+        count(account_keys.len())?,
+    ];
+    for key in account_keys.iter() {
+        message.extend_from_slice(key.as_ref());
+    }
+
+    message.push(count(instructions.len())?);
+    for instruction in instructions {
+        message.push(index_of(&instruction.program_id)?);
+
+        message.push(count(instruction.accounts.len())?);
+        for account in instruction.accounts.iter() {
+            message.push(index_of(&account.pubkey)?);
+        }
+
+        let data_len = u16::try_from(instruction.data.len())
+            .map_err(|_| anyhow!("Instruction data too large for a vault transaction"))?;
+        message.extend_from_slice(&data_len.to_le_bytes());
+        message.extend_from_slice(&instruction.data);
+    }
+
+    // No address lookup tables
+    message.push(0);
+
+    Ok(message)
+}
+
+fn keys_in_group(
+    keys: &[(Pubkey, bool, bool)],
+    is_signer: bool,
+    is_writable: bool,
+) -> impl Iterator<Item = Pubkey> + '_ {
+    keys.iter()
+        .filter(move |(_, signer, writable)| *signer == is_signer && *writable == is_writable)
+        .map(|(key, _, _)| *key)
+}
+
+fn anchor_instruction(
+    name: &str,
+    args: &impl BorshSerialize,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&args.try_to_vec().unwrap());
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+#[derive(BorshSerialize)]
+struct VaultTransactionCreateArgs {
+    vault_index: u8,
+    ephemeral_signers: u8,
+    transaction_message: Vec<u8>,
+    memo: Option<String>,
+}
+
+#[derive(BorshSerialize)]
+struct ProposalCreateArgs {
+    transaction_index: u64,
+    draft: bool,
+}
+
+#[derive(BorshSerialize)]
+struct ProposalVoteArgs {
+    memo: Option<String>,
+}
+
+/// Creates the vault transaction `transaction_index` running `instructions` as the vault
+pub fn vault_transaction_create(
+    multisig: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    creator: &Pubkey,
+    instructions: &[Instruction],
+    memo: Option<String>,
+) -> Result<Instruction> {
+    let vault = vault_address(multisig, vault_index);
+    let transaction_message = compile_vault_transaction_message(&vault, instructions)?;
+
+    Ok(anchor_instruction(
+        "vault_transaction_create",
+        &VaultTransactionCreateArgs {
+            vault_index,
+            ephemeral_signers: 0,
+            transaction_message,
+            memo,
+        },
+        vec![
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(transaction_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    ))
+}
+
+/// Opens the proposal members vote on for the vault transaction `transaction_index`
+pub fn proposal_create(multisig: &Pubkey, transaction_index: u64, creator: &Pubkey) -> Instruction {
+    anchor_instruction(
+        "proposal_create",
+        &ProposalCreateArgs {
+            transaction_index,
+            draft: false,
+        },
+        vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn proposal_approve(multisig: &Pubkey, transaction_index: u64, member: &Pubkey) -> Instruction {
+    anchor_instruction(
+        "proposal_approve",
+        &ProposalVoteArgs { memo: None },
+        vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new_readonly(*member, true),
+            AccountMeta::new(proposal_address(multisig, transaction_index), false),
+        ],
+    )
+}
+
+/// Instructions proposing `instructions` to the multisig as its next vault transaction and
+/// approving it as `member`, who pays the rent of the transaction and proposal accounts
+pub fn propose_vault_transaction(
+    multisig: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    member: &Pubkey,
+    instructions: &[Instruction],
+    memo: Option<String>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        vault_transaction_create(
+            multisig,
+            transaction_index,
+            vault_index,
+            member,
+            instructions,
+            memo,
+        )?,
+        proposal_create(multisig, transaction_index, member),
+        proposal_approve(multisig, transaction_index, member),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_vault_transaction_message() {
+        let multisig = Pubkey::new_unique();
+        let vault = vault_address(&multisig, 0);
+        let program_id = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(ncn, false),
+                AccountMeta::new_readonly(vault, true),
+            ],
+            data: vec![1, 2, 3],
+        };
+
+        let message = compile_vault_transaction_message(&vault, &[instruction.clone()]).unwrap();
+
+        // One writable signer, the vault, and one writable non-signer
+        assert_eq!(&message[..4], &[1, 1, 1, 4]);
+        let keys: Vec<&[u8]> = message[4..4 + 4 * 32].chunks(32).collect();
+        assert_eq!(keys[0], vault.as_ref());
+        assert_eq!(keys[1], config.as_ref());
+        assert_eq!(keys[2], program_id.as_ref());
+        assert_eq!(keys[3], ncn.as_ref());
+
+        // One instruction: program index, account indexes, u16 data length and data, then no
+        // lookup tables
+        assert_eq!(
+            &message[4 + 4 * 32..],
+            &[1, 2, 3, 1, 3, 0, 3, 0, 1, 2, 3, 0]
+        );
+
+        // Other signers can not be provided by the vault
+        let mut instruction = instruction;
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(Pubkey::new_unique(), true));
+        assert!(compile_vault_transaction_message(&vault, &[instruction]).is_err());
+    }
+
+    #[test]
+    fn test_multisig_transaction_index() {
+        let mut data = vec![0; MULTISIG_TRANSACTION_INDEX_OFFSET + 32];
+        data[MULTISIG_TRANSACTION_INDEX_OFFSET..MULTISIG_TRANSACTION_INDEX_OFFSET + 8]
+            .copy_from_slice(&7u64.to_le_bytes());
+
+        assert_eq!(multisig_transaction_index(&data).unwrap(), 7);
+        assert!(multisig_transaction_index(&data[..10]).is_err());
+    }
+
+    #[test]
+    fn test_propose_vault_transaction() {
+        let multisig = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+
+        let instructions = propose_vault_transaction(&multisig, 8, 0, &member, &[], None).unwrap();
+
+        assert_eq!(instructions.len(), 3);
+        assert!(instructions
+            .iter()
+            .all(|instruction| instruction.program_id == SQUADS_PROGRAM_ID));
+        assert_eq!(
+            instructions[0].accounts[1].pubkey,
+            transaction_address(&multisig, 8)
+        );
+        assert_eq!(
+            instructions[1].accounts[1].pubkey,
+            proposal_address(&multisig, 8)
+        );
+        assert_eq!(
+            instructions[2].accounts[2].pubkey,
+            proposal_address(&multisig, 8)
+        );
+    }
+}
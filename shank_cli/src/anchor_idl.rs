@@ -0,0 +1,473 @@
+//! Converts the Shank IDL into the Anchor IDL format (spec 0.1.0), read by Anchor and Codama
+//! clients.
+//!
+//! Shank already describes the instructions, accounts and errors, the conversion adds what Anchor
+//! expects on top of it:
+//! - instruction discriminators, the `u8` Shank discriminant
+//! - 8 byte account discriminators, the account discriminator byte followed by zeros
+//! - snake case names for instructions, accounts, arguments and fields
+//! - `Pod*` wrappers replaced by the primitive they store
+//! - account structs and the types they use marked as packed `bytemuck` types
+
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Map, Value};
+
+const ANCHOR_IDL_SPEC: &str = "0.1.0";
+
+/// Length of the account discriminator, only the first byte is used by the program
+const ACCOUNT_DISCRIMINATOR_LEN: usize = 8;
+
+/// Builds the Anchor IDL of a program from its Shank IDL.
+///
+/// ### Arguments
+/// * `shank_idl` - The Shank IDL as JSON
+/// * `account_discriminators` - The discriminator byte of every account in the IDL
+pub fn anchor_idl_from_shank(
+    shank_idl: &Value,
+    account_discriminators: &[(&str, u8)],
+) -> Result<Value> {
+    let name = str_field(shank_idl, "name")?;
+    let version = str_field(shank_idl, "version")?;
+    let address = shank_idl
+        .get("metadata")
+        .and_then(|metadata| metadata.get("address"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Shank IDL has no program address"))?;
+
+    let instructions = array_field(shank_idl, "instructions")?
+        .iter()
+        .map(convert_instruction)
+        .collect::<Result<Vec<_>>>()?;
+
+    let shank_accounts = array_field(shank_idl, "accounts")?;
+    let shank_types = array_field(shank_idl, "types")?;
+
+    let accounts = shank_accounts
+        .iter()
+        .map(|account| {
+            let account_name = str_field(account, "name")?;
+            let discriminator = account_discriminators
+                .iter()
+                .find(|(name, _)| *name == account_name)
+                .map(|(_, discriminator)| *discriminator)
+                .ok_or_else(|| anyhow!("No discriminator for account {}", account_name))?;
+
+            let mut discriminator_bytes = vec![0u8; ACCOUNT_DISCRIMINATOR_LEN];
+            discriminator_bytes[0] = discriminator;
+
+            Ok(json!({
+                "name": account_name,
+                "discriminator": discriminator_bytes,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Accounts are zero copy, so are all the types stored in them. The remaining types are only
+    // used by instruction arguments and are borsh encoded.
+    let zero_copy_types = zero_copy_type_names(shank_accounts, shank_types);
+
+    let mut types = Vec::with_capacity(shank_accounts.len() + shank_types.len());
+    for account in shank_accounts {
+        types.push(convert_type_def(account, true)?);
+    }
+    for type_def in shank_types {
+        let is_zero_copy = zero_copy_types.contains(str_field(type_def, "name")?);
+        types.push(convert_type_def(type_def, is_zero_copy)?);
+    }
+
+    let errors = shank_idl
+        .get("errors")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    Ok(json!({
+        "address": address,
+        "metadata": {
+            "name": name,
+            "version": version,
+            "spec": ANCHOR_IDL_SPEC,
+        },
+        "instructions": instructions,
+        "accounts": accounts,
+        "errors": errors,
+        "types": types,
+    }))
+}
+
+fn convert_instruction(instruction: &Value) -> Result<Value> {
+    let name = str_field(instruction, "name")?;
+    let discriminant = instruction
+        .get("discriminant")
+        .and_then(|discriminant| discriminant.get("value"))
+        .and_then(Value::as_u64)
+        .ok_or_else(|| anyhow!("Instruction {} has no discriminant", name))?;
+
+    let accounts = array_field(instruction, "accounts")?
+        .iter()
+        .map(|account| {
+            let mut converted = Map::new();
+            converted.insert(
+                "name".to_string(),
+                Value::from(snake_case(str_field(account, "name")?)),
+            );
+            copy_docs(account, &mut converted);
+            for (shank_flag, anchor_flag) in [
+                ("isMut", "writable"),
+                ("isSigner", "signer"),
+                ("isOptional", "optional"),
+            ] {
+                if account.get(shank_flag).and_then(Value::as_bool) == Some(true) {
+                    converted.insert(anchor_flag.to_string(), Value::Bool(true));
+                }
+            }
+            Ok(Value::Object(converted))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let args = array_field(instruction, "args")?
+        .iter()
+        .map(convert_field)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut converted = Map::new();
+    converted.insert("name".to_string(), Value::from(snake_case(name)));
+    copy_docs(instruction, &mut converted);
+    converted.insert("discriminator".to_string(), json!([discriminant]));
+    converted.insert("accounts".to_string(), Value::Array(accounts));
+    converted.insert("args".to_string(), Value::Array(args));
+
+    Ok(Value::Object(converted))
+}
+
+fn convert_type_def(type_def: &Value, is_zero_copy: bool) -> Result<Value> {
+    let name = str_field(type_def, "name")?;
+    let shank_type = type_def
+        .get("type")
+        .ok_or_else(|| anyhow!("Type {} has no definition", name))?;
+
+    let converted_type = match str_field(shank_type, "kind")? {
+        "struct" => {
+            let fields = array_field(shank_type, "fields")?
+                .iter()
+                .map(convert_field)
+                .collect::<Result<Vec<_>>>()?;
+            json!({ "kind": "struct", "fields": fields })
+        }
+        "enum" => {
+            let variants = array_field(shank_type, "variants")?
+                .iter()
+                .map(convert_variant)
+                .collect::<Result<Vec<_>>>()?;
+            json!({ "kind": "enum", "variants": variants })
+        }
+        kind => return Err(anyhow!("Unsupported kind {} of type {}", kind, name)),
+    };
+
+    let mut converted = Map::new();
+    converted.insert("name".to_string(), Value::from(name));
+    copy_docs(type_def, &mut converted);
+    if is_zero_copy {
+        // Pod types have no padding, every field is read right after the previous one
+        converted.insert("serialization".to_string(), Value::from("bytemuck"));
+        converted.insert("repr".to_string(), json!({ "kind": "c", "packed": true }));
+    }
+    converted.insert("type".to_string(), converted_type);
+
+    Ok(Value::Object(converted))
+}
+
+fn convert_variant(variant: &Value) -> Result<Value> {
+    let mut converted = Map::new();
+    converted.insert("name".to_string(), Value::from(str_field(variant, "name")?));
+
+    if let Some(fields) = variant.get("fields").and_then(Value::as_array) {
+        let fields = fields
+            .iter()
+            .map(|field| {
+                if field.get("name").is_some() {
+                    convert_field(field)
+                } else {
+                    convert_type(field)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        converted.insert("fields".to_string(), Value::Array(fields));
+    }
+
+    Ok(Value::Object(converted))
+}
+
+fn convert_field(field: &Value) -> Result<Value> {
+    let name = str_field(field, "name")?;
+    let field_type = field
+        .get("type")
+        .ok_or_else(|| anyhow!("Field {} has no type", name))?;
+
+    let mut converted = Map::new();
+    converted.insert("name".to_string(), Value::from(snake_case(name)));
+    copy_docs(field, &mut converted);
+    converted.insert("type".to_string(), convert_type(field_type)?);
+
+    Ok(Value::Object(converted))
+}
+
+fn convert_type(shank_type: &Value) -> Result<Value> {
+    if let Some(primitive) = shank_type.as_str() {
+        return Ok(Value::from(match primitive {
+            "publicKey" => "pubkey",
+            primitive => primitive,
+        }));
+    }
+
+    if let Some(defined) = shank_type.get("defined").and_then(Value::as_str) {
+        return Ok(match pod_primitive(defined) {
+            Some(primitive) => Value::from(primitive),
+            None => json!({ "defined": { "name": defined } }),
+        });
+    }
+
+    if let Some([element, len]) = shank_type
+        .get("array")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+    {
+        return Ok(json!({ "array": [convert_type(element)?, len] }));
+    }
+
+    for wrapper in ["option", "vec"] {
+        if let Some(inner) = shank_type.get(wrapper) {
+            let mut converted = Map::new();
+            converted.insert(wrapper.to_string(), convert_type(inner)?);
+            return Ok(Value::Object(converted));
+        }
+    }
+
+    Err(anyhow!("Unsupported type {}", shank_type))
+}
+
+/// The primitive stored by a `jito_bytemuck` Pod wrapper, they share the little endian layout
+fn pod_primitive(defined: &str) -> Option<&'static str> {
+    match defined {
+        "PodBool" => Some("bool"),
+        "PodU16" => Some("u16"),
+        "PodU32" => Some("u32"),
+        "PodU64" => Some("u64"),
+        "PodU128" => Some("u128"),
+        _ => None,
+    }
+}
+
+/// Names of the types reachable from the account structs
+fn zero_copy_type_names<'a>(
+    shank_accounts: &'a [Value],
+    shank_types: &'a [Value],
+) -> HashSet<&'a str> {
+    let mut names = HashSet::new();
+    let mut queue: VecDeque<&Value> = shank_accounts.iter().collect();
+
+    while let Some(type_def) = queue.pop_front() {
+        let mut referenced = Vec::new();
+        collect_defined_names(type_def, &mut referenced);
+
+        for name in referenced {
+            if !names.insert(name) {
+                continue;
+            }
+
+            let definition = shank_types
+                .iter()
+                .find(|shank_type| shank_type.get("name").and_then(Value::as_str) == Some(name));
+            if let Some(definition) = definition {
+                queue.push_back(definition);
+            }
+        }
+    }
+
+    names
+}
+
+fn collect_defined_names<'a>(value: &'a Value, names: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, inner) in map {
+                match (key.as_str(), inner.as_str()) {
+                    ("defined", Some(name)) => names.push(name),
+                    _ => collect_defined_names(inner, names),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for inner in values {
+                collect_defined_names(inner, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn copy_docs(from: &Value, to: &mut Map<String, Value>) {
+    if let Some(docs) = from.get("docs") {
+        to.insert("docs".to_string(), docs.clone());
+    }
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> Result<&'a str> {
+    value
+        .get(field)
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Missing string field {} in {}", field, value))
+}
+
+fn array_field<'a>(value: &'a Value, field: &str) -> Result<&'a [Value]> {
+    value
+        .get(field)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .ok_or_else(|| anyhow!("Missing array field {} in {}", field, value))
+}
+
+/// Converts camel and pascal case to snake case, keeping acronyms like `NCN` together
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars
+                .get(index + 1)
+                .is_some_and(|next| next.is_ascii_lowercase());
+
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lowercase)
+            {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("InitializeConfig"), "initialize_config");
+        assert_eq!(snake_case("ncnFeeBps"), "ncn_fee_bps");
+        assert_eq!(
+            snake_case("InitializeNCNRewardRouter"),
+            "initialize_ncn_reward_router"
+        );
+        assert_eq!(snake_case("epoch"), "epoch");
+    }
+
+    #[test]
+    fn test_anchor_idl_from_shank() {
+        let shank_idl = json!({
+            "version": "0.0.1",
+            "name": "ncn_program",
+            "instructions": [{
+                "name": "SetWeight",
+                "accounts": [
+                    { "name": "weightTable", "isMut": true, "isSigner": false },
+                    { "name": "weightTableAdmin", "isMut": false, "isSigner": true }
+                ],
+                "args": [
+                    { "name": "stMint", "type": "publicKey" },
+                    { "name": "weight", "type": { "option": "u128" } },
+                    { "name": "role", "type": { "defined": "Role" } }
+                ],
+                "discriminant": { "type": "u8", "value": 7 }
+            }],
+            "accounts": [{
+                "name": "WeightTable",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "slotCreated", "type": { "defined": "PodU64" } },
+                        { "name": "table", "type": { "array": [{ "defined": "WeightEntry" }, 2] } }
+                    ]
+                }
+            }],
+            "types": [
+                {
+                    "name": "WeightEntry",
+                    "type": {
+                        "kind": "struct",
+                        "fields": [{ "name": "weight", "type": { "defined": "PodU128" } }]
+                    }
+                },
+                {
+                    "name": "Role",
+                    "type": { "kind": "enum", "variants": [{ "name": "TieBreakerAdmin" }] }
+                }
+            ],
+            "errors": [{ "code": 0, "name": "NoValidBallots", "msg": "No valid Ballot" }],
+            "metadata": { "origin": "shank", "address": "11111111111111111111111111111111" }
+        });
+
+        let anchor_idl = anchor_idl_from_shank(&shank_idl, &[("WeightTable", 0x10)]).unwrap();
+
+        assert_eq!(anchor_idl["address"], "11111111111111111111111111111111");
+        assert_eq!(anchor_idl["metadata"]["spec"], ANCHOR_IDL_SPEC);
+
+        let instruction = &anchor_idl["instructions"][0];
+        assert_eq!(instruction["name"], "set_weight");
+        assert_eq!(instruction["discriminator"], json!([7]));
+        assert_eq!(
+            instruction["accounts"],
+            json!([
+                { "name": "weight_table", "writable": true },
+                { "name": "weight_table_admin", "signer": true }
+            ])
+        );
+        assert_eq!(instruction["args"][0]["type"], "pubkey");
+        assert_eq!(instruction["args"][1]["type"], json!({ "option": "u128" }));
+        assert_eq!(
+            instruction["args"][2]["type"],
+            json!({ "defined": { "name": "Role" } })
+        );
+
+        assert_eq!(
+            anchor_idl["accounts"],
+            json!([{ "name": "WeightTable", "discriminator": [0x10, 0, 0, 0, 0, 0, 0, 0] }])
+        );
+
+        let types = anchor_idl["types"].as_array().unwrap();
+        assert_eq!(types.len(), 3);
+        assert_eq!(types[0]["name"], "WeightTable");
+        assert_eq!(types[0]["serialization"], "bytemuck");
+        assert_eq!(types[0]["type"]["fields"][0]["name"], "slot_created");
+        assert_eq!(types[0]["type"]["fields"][0]["type"], "u64");
+        // Types stored in accounts are zero copy, argument only types are borsh
+        assert_eq!(types[1]["name"], "WeightEntry");
+        assert_eq!(types[1]["serialization"], "bytemuck");
+        assert_eq!(types[1]["type"]["fields"][0]["type"], "u128");
+        assert_eq!(types[2]["name"], "Role");
+        assert!(types[2].get("serialization").is_none());
+
+        assert_eq!(anchor_idl["errors"], shank_idl["errors"]);
+    }
+
+    #[test]
+    fn test_missing_account_discriminator() {
+        let shank_idl = json!({
+            "version": "0.0.1",
+            "name": "ncn_program",
+            "instructions": [],
+            "accounts": [{ "name": "Config", "type": { "kind": "struct", "fields": [] } }],
+            "types": [],
+            "metadata": { "origin": "shank", "address": "11111111111111111111111111111111" }
+        });
+
+        assert!(anchor_idl_from_shank(&shank_idl, &[]).is_err());
+    }
+}
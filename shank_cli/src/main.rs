@@ -1,14 +1,78 @@
-use std::{fs::File, io::Write};
+mod anchor_idl;
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
 
 use anyhow::{anyhow, Result};
 use env_logger::Env;
 use log::{debug, info};
+use ncn_program_core::{constants, discriminators::Discriminators};
 use shank_idl::{extract_idl, manifest::Manifest, ParseIdlOpts};
 
+use crate::anchor_idl::anchor_idl_from_shank;
+
 struct IdlConfiguration {
     program_id: String,
     name: &'static str,
     paths: Vec<&'static str>,
+    account_discriminators: &'static [(&'static str, u8)],
+}
+
+/// Discriminator byte of every account in the NCN program IDL, needed for the Anchor IDL
+const NCN_PROGRAM_ACCOUNT_DISCRIMINATORS: &[(&str, u8)] = &[
+    ("Config", Discriminators::Config as u8),
+    ("VaultRegistry", Discriminators::VaultRegistry as u8),
+    ("WeightTable", Discriminators::WeightTable as u8),
+    ("EpochSnapshot", Discriminators::EpochSnapshot as u8),
+    ("OperatorSnapshot", Discriminators::OperatorSnapshot as u8),
+    ("BallotBox", Discriminators::BallotBox as u8),
+    ("ConsensusResult", Discriminators::ConsensusResult as u8),
+    ("NCNRewardRouter", Discriminators::NCNRewardRouter as u8),
+    (
+        "OperatorVaultRewardRouter",
+        Discriminators::OperatorVaultRewardRouter as u8,
+    ),
+    ("EpochState", Discriminators::EpochState as u8),
+    ("EpochMarker", Discriminators::EpochMarker as u8),
+    ("OperatorMetadata", Discriminators::OperatorMetadata as u8),
+];
+
+/// Capacities the account arrays are sized with. Shank only reads literal array lengths, so they are
+/// replaced by their configured values in a copy of the sources before the IDL is extracted
+const ARRAY_LENGTHS: &[(&str, usize)] = &[
+    ("MAX_ST_MINTS", constants::MAX_ST_MINTS),
+    ("MAX_VAULTS", constants::MAX_VAULTS),
+    ("MAX_OPERATORS", constants::MAX_OPERATORS),
+    ("MAX_REWARD_MINTS", constants::MAX_REWARD_MINTS),
+    ("MAX_FEE_GROUPS", constants::MAX_FEE_GROUPS),
+    ("MAX_ADMIN_APPROVERS", constants::MAX_ADMIN_APPROVERS),
+];
+
+/// Copies the sources in `from` to `to`, resolving the array lengths of `ARRAY_LENGTHS`
+fn copy_with_resolved_array_lengths(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_with_resolved_array_lengths(&entry.path(), &target)?;
+        } else if entry.path().extension().is_some_and(|ext| ext == "rs") {
+            let mut source = fs::read_to_string(entry.path())?;
+            for (name, length) in ARRAY_LENGTHS {
+                source = source.replace(&format!("; {}]", name), &format!("; {}]", length));
+            }
+            fs::write(target, source)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
 }
 
 fn main() -> Result<()> {
@@ -25,10 +89,15 @@ fn main() -> Result<()> {
         program_id: ncn_program_id,
         name: "ncn_program",
         paths: vec!["core", "program"],
+        account_discriminators: NCN_PROGRAM_ACCOUNT_DISCRIMINATORS,
     }];
 
     let crate_root = std::env::current_dir().unwrap();
     let out_dir = crate_root.join("idl");
+    let resolved_dir = std::env::temp_dir().join("ncn-program-idl");
+    if resolved_dir.exists() {
+        fs::remove_dir_all(&resolved_dir)?;
+    }
     for idl in idl_configs {
         let mut idls = Vec::new();
         for path in idl.paths {
@@ -45,7 +114,12 @@ fn main() -> Result<()> {
                 .lib_rel_path()
                 .ok_or_else(|| anyhow!("Program needs to be a lib"))?;
             debug!("lib_rel_path: {:?}", lib_rel_path);
-            let lib_full_path_str = crate_root.join(path).join(lib_rel_path);
+            let resolved_crate = resolved_dir.join(path);
+            copy_with_resolved_array_lengths(
+                &crate_root.join(path).join("src"),
+                &resolved_crate.join("src"),
+            )?;
+            let lib_full_path_str = resolved_crate.join(lib_rel_path);
             let lib_full_path = lib_full_path_str
                 .to_str()
                 .ok_or_else(|| anyhow!("Invalid Path"))?;
@@ -90,6 +164,18 @@ fn main() -> Result<()> {
         info!("Writing IDL to {:?}", idl_path);
         let mut idl_json_file = File::create(idl_path)?;
         idl_json_file.write_all(idl_json.as_bytes())?;
+
+        // Anchor and Codama clients read the Anchor IDL format
+        let anchor_idl = anchor_idl_from_shank(
+            &serde_json::from_str(&idl_json)?,
+            idl.account_discriminators,
+        )?;
+        let anchor_idl_json = serde_json::to_string_pretty(&anchor_idl)?;
+        let anchor_idl_path = out_dir.join(format!("{}_anchor.json", idl.name));
+
+        info!("Writing Anchor IDL to {:?}", anchor_idl_path);
+        let mut anchor_idl_json_file = File::create(anchor_idl_path)?;
+        anchor_idl_json_file.write_all(anchor_idl_json.as_bytes())?;
     }
 
     Ok(())